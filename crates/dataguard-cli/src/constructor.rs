@@ -1,13 +1,35 @@
 use crate::{
     errors::CliError,
-    parser::{ConfigTable, Relation, Rule},
+    parser::{
+        is_glob_pattern, is_multi_file, Column, ConditionalThen, ConfigTable, LabeledRule,
+        Relation, Rule,
+    },
 };
 use anyhow::{Context, Result};
+use chrono::NaiveDate;
 use dataguard_core::{
-    columns::{date_builder::DateColumnBuilder, relation_builder::RelationBuilder, ColumnBuilder},
-    utils::operator::CompOperator,
-    CsvTable, NumericColumnBuilder, ParquetTable, StringColumnBuilder, Table,
+    columns::{
+        boolean_builder::BooleanColumnBuilder, date_builder::DateColumnBuilder,
+        group_builder::GroupBuilder, relation_builder::RelationBuilder,
+        timestamp_builder::TimestampColumnBuilder, ColumnBuilder, ConditionalRule,
+    },
+    readers::{
+        avro_column_names, csv_column_names, download_object, ipc_column_names, json_column_names,
+        orc_column_names, parquet_column_names, FileFormat, FixedWidthField, ReadMode,
+        ReaderConfig, ReaderConfigBuilder, DEFAULT_MEMORY_BUDGET,
+    },
+    rules::{parse_normalize_mode, NormalizeMode},
+    tables::{FileTable, FormatReader},
+    utils::{lookup::load_lookup, operator::CompOperator},
+    AvroTable, CsvTable, FixedWidthTable, IpcTable, JsonTable, NumericColumnBuilder, OrcTable,
+    ParquetTable, StringColumnBuilder, Table,
 };
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+use tempfile::TempPath;
 use toml::Value;
 
 fn apply_string_rule(
@@ -22,9 +44,43 @@ fn apply_string_rule(
             builder.is_not_null(t);
             Ok(())
         }
-        Rule::IsUnique { threshold } => {
+        Rule::NotAllNull => {
+            builder.not_all_null();
+            Ok(())
+        }
+        Rule::NotAllSame => {
+            builder.not_all_same();
+            Ok(())
+        }
+        Rule::NullRatioBetween {
+            min_ratio,
+            max_ratio,
+        } => {
+            builder.null_ratio_between(min_ratio, max_ratio)?;
+            Ok(())
+        }
+        Rule::DistinctCountBetween { min, max } => {
+            builder.distinct_count_between(min, max)?;
+            Ok(())
+        }
+        Rule::IsUnique {
+            threshold,
+            normalize,
+        } => {
             let t = threshold.unwrap_or(rule_threshold);
-            builder.is_unique(t);
+            let normalize = match normalize {
+                Some(name) => parse_normalize_mode(&name)?,
+                None => NormalizeMode::None,
+            };
+            builder.is_unique(normalize, t);
+            Ok(())
+        }
+        Rule::UniqueWithinGroup {
+            threshold,
+            group_by,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_unique_within(&group_by, t);
             Ok(())
         }
         Rule::WithLengthBetween {
@@ -36,20 +92,31 @@ fn apply_string_rule(
             builder.with_length_between(min_length, max_length, t);
             Ok(())
         }
+        Rule::WithUtf16LengthBetween {
+            threshold,
+            min_length,
+            max_length,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.with_utf16_length_between(min_length, max_length, t);
+            Ok(())
+        }
         Rule::WithMinLength {
             threshold,
             min_length,
+            count_bytes,
         } => {
             let t = threshold.unwrap_or(rule_threshold);
-            builder.with_min_length(min_length, t);
+            builder.with_min_length(min_length, count_bytes.unwrap_or(false), t);
             Ok(())
         }
         Rule::WithMaxLength {
             threshold,
             max_length,
+            count_bytes,
         } => {
             let t = threshold.unwrap_or(rule_threshold);
-            builder.with_max_length(max_length, t);
+            builder.with_max_length(max_length, count_bytes.unwrap_or(false), t);
             Ok(())
         }
         Rule::IsExactLength { threshold, length } => {
@@ -57,11 +124,67 @@ fn apply_string_rule(
             builder.is_exact_length(length, t);
             Ok(())
         }
-        Rule::IsIn { threshold, members } => {
+        Rule::Equals {
+            threshold,
+            ref value,
+        } => {
+            let value = extract_string(value, rule.to_string(), column_name.clone())?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.equals(value, t);
+            Ok(())
+        }
+        Rule::IsIn {
+            threshold,
+            ref members,
+        } => {
+            let members = members
+                .iter()
+                .map(|m| extract_string(m, rule.to_string(), column_name.clone()))
+                .collect::<Result<_, _>>()?;
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_in(members, t);
             Ok(())
         }
+        Rule::NotIn {
+            threshold,
+            ref members,
+        } => {
+            let members = members
+                .iter()
+                .map(|m| extract_string(m, rule.to_string(), column_name.clone()))
+                .collect::<Result<_, _>>()?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_not_in(members, t);
+            Ok(())
+        }
+        Rule::StartsWith { threshold, prefix } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.with_prefix(prefix, t)?;
+            Ok(())
+        }
+        Rule::EndsWith { threshold, suffix } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.with_suffix(suffix, t)?;
+            Ok(())
+        }
+        Rule::Contains {
+            threshold,
+            substring,
+            flag,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.contains(substring, flag, t)?;
+            Ok(())
+        }
+        Rule::NotContains {
+            threshold,
+            substring,
+            flag,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.not_contains(substring, flag, t)?;
+            Ok(())
+        }
         Rule::WithRegex {
             threshold,
             pattern,
@@ -71,6 +194,15 @@ fn apply_string_rule(
             builder.with_regex(pattern.to_owned(), flag, t)?;
             Ok(())
         }
+        Rule::MatchesAnyOf {
+            threshold,
+            patterns,
+            flag,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.matches_any_of(patterns, flag, t)?;
+            Ok(())
+        }
         Rule::IsNumeric { threshold } => {
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_numeric(t)?;
@@ -86,14 +218,37 @@ fn apply_string_rule(
             builder.is_alphanumeric(t)?;
             Ok(())
         }
-        Rule::IsUpperCase { threshold } => {
+        Rule::IsUpperCase { threshold, unicode } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_uppercase(unicode.unwrap_or(false), t);
+            Ok(())
+        }
+        Rule::IsLowerCase { threshold, unicode } => {
             let t = threshold.unwrap_or(rule_threshold);
-            builder.is_uppercase(t)?;
+            builder.is_lowercase(unicode.unwrap_or(false), t);
             Ok(())
         }
-        Rule::IsLowerCase { threshold } => {
+        Rule::NoSurroundingWhitespace { threshold } => {
             let t = threshold.unwrap_or(rule_threshold);
-            builder.is_lowercase(t)?;
+            builder.has_no_surrounding_whitespace(t);
+            Ok(())
+        }
+        Rule::NoWhitespace { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.has_no_whitespace(t);
+            Ok(())
+        }
+        Rule::ContainsOnly { threshold, allowed } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.contains_only(allowed, t)?;
+            Ok(())
+        }
+        Rule::ConsistentLength {
+            threshold,
+            tolerance,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.consistent_length(tolerance.unwrap_or(0), t);
             Ok(())
         }
         Rule::IsUrl { threshold } => {
@@ -101,9 +256,9 @@ fn apply_string_rule(
             builder.is_url(t)?;
             Ok(())
         }
-        Rule::IsEmail { threshold } => {
+        Rule::IsEmail { threshold, strict } => {
             let t = threshold.unwrap_or(rule_threshold);
-            builder.is_email(t)?;
+            builder.is_email(strict.unwrap_or(false), t)?;
             Ok(())
         }
         Rule::IsUuid { threshold } => {
@@ -111,6 +266,72 @@ fn apply_string_rule(
             builder.is_uuid(t)?;
             Ok(())
         }
+        Rule::IsIp { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_ip(t);
+            Ok(())
+        }
+        Rule::IsIpv4 { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_ipv4(t);
+            Ok(())
+        }
+        Rule::IsIpv6 { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_ipv6(t);
+            Ok(())
+        }
+        Rule::IpInCidr { threshold, cidrs } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.ip_in_cidr(cidrs, t)?;
+            Ok(())
+        }
+        Rule::IsJson { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_json(t);
+            Ok(())
+        }
+        Rule::IsJsonObject { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_json_object(t);
+            Ok(())
+        }
+        Rule::IsJsonArray { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_json_array(t);
+            Ok(())
+        }
+        Rule::IsCreditCard { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_credit_card(t);
+            Ok(())
+        }
+        Rule::IsIban { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_iban(t);
+            Ok(())
+        }
+        Rule::IsBase64 {
+            threshold,
+            strict_padding,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_base64(strict_padding.unwrap_or(true), t);
+            Ok(())
+        }
+        Rule::IsHex {
+            threshold,
+            allow_prefix,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_hex(allow_prefix.unwrap_or(false), t);
+            Ok(())
+        }
+        Rule::IsPhone { threshold, region } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_phone(region.as_deref(), t)?;
+            Ok(())
+        }
         _ => Err(CliError::UnknownRule {
             rule_name: rule.to_string(),
             column_type: "string".to_string(),
@@ -133,6 +354,21 @@ fn extract_integer(value: &Value, rule_name: String, column_name: String) -> Res
     Ok(*i)
 }
 
+fn extract_string(
+    value: &Value,
+    rule_name: String,
+    column_name: String,
+) -> Result<String, CliError> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        _ => Err(CliError::WrongRuleData {
+            rule_name,
+            column_name,
+            field_type: "string".to_string(),
+        }),
+    }
+}
+
 fn extract_float(value: &Value, rule_name: String, column_name: String) -> Result<f64, CliError> {
     let f = match value {
         Value::Float(f) => f,
@@ -159,11 +395,38 @@ fn apply_integer_rule(
             builder.is_not_null(t);
             Ok(())
         }
-        Rule::IsUnique { threshold } => {
+        Rule::NotAllNull => {
+            builder.not_all_null();
+            Ok(())
+        }
+        Rule::NotAllSame => {
+            builder.not_all_same();
+            Ok(())
+        }
+        Rule::NullRatioBetween {
+            min_ratio,
+            max_ratio,
+        } => {
+            builder.null_ratio_between(min_ratio, max_ratio)?;
+            Ok(())
+        }
+        Rule::DistinctCountBetween { min, max } => {
+            builder.distinct_count_between(min, max)?;
+            Ok(())
+        }
+        Rule::IsUnique { threshold, .. } => {
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_unique(t);
             Ok(())
         }
+        Rule::UniqueWithinGroup {
+            threshold,
+            group_by,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_unique_within(&group_by, t);
+            Ok(())
+        }
         Rule::Between {
             threshold,
             ref min,
@@ -175,6 +438,17 @@ fn apply_integer_rule(
             builder.between(i_min, i_max, t);
             Ok(())
         }
+        Rule::BetweenExclusive {
+            threshold,
+            ref min,
+            ref max,
+        } => {
+            let i_min = extract_integer(min, rule.to_string(), column_name.clone())?;
+            let i_max = extract_integer(max, rule.to_string(), column_name.clone())?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.between_exclusive(i_min, i_max, t);
+            Ok(())
+        }
         Rule::Min { threshold, ref min } => {
             let i_min = extract_integer(min, rule.to_string(), column_name.clone())?;
             let t = threshold.unwrap_or(rule_threshold);
@@ -187,6 +461,39 @@ fn apply_integer_rule(
             builder.max(i_max, t);
             Ok(())
         }
+        Rule::Equals {
+            threshold,
+            ref value,
+        } => {
+            let value = extract_integer(value, rule.to_string(), column_name.clone())?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.equals(value, t);
+            Ok(())
+        }
+        Rule::IsIn {
+            threshold,
+            ref members,
+        } => {
+            let members = members
+                .iter()
+                .map(|m| extract_integer(m, rule.to_string(), column_name.clone()))
+                .collect::<Result<_, _>>()?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_in(members, t);
+            Ok(())
+        }
+        Rule::NotIn {
+            threshold,
+            ref members,
+        } => {
+            let members = members
+                .iter()
+                .map(|m| extract_integer(m, rule.to_string(), column_name.clone()))
+                .collect::<Result<_, _>>()?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.not_in(members, t);
+            Ok(())
+        }
         Rule::IsPositive { threshold } => {
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_positive(t);
@@ -207,6 +514,11 @@ fn apply_integer_rule(
             builder.is_non_negative(t);
             Ok(())
         }
+        Rule::MultipleOf { threshold, divisor } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_multiple_of(divisor, t)?;
+            Ok(())
+        }
         Rule::IsIncreasing { threshold } => {
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_monotonically_increasing(t);
@@ -233,6 +545,20 @@ fn apply_integer_rule(
             builder.mean_variance(t, max_variance_percent);
             Ok(())
         }
+        Rule::PercentileBelow {
+            threshold,
+            quantile,
+            bound,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.percentile_below(quantile, bound, t)?;
+            Ok(())
+        }
+        Rule::NoOutliersBeyond { threshold, num_std } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.no_outliers_beyond(num_std, t)?;
+            Ok(())
+        }
         _ => Err(CliError::UnknownRule {
             rule_name: rule.to_string(),
             column_type: "integer".to_string(),
@@ -253,11 +579,38 @@ fn apply_float_rule(
             builder.is_not_null(t);
             Ok(())
         }
-        Rule::IsUnique { threshold } => {
+        Rule::NotAllNull => {
+            builder.not_all_null();
+            Ok(())
+        }
+        Rule::NotAllSame => {
+            builder.not_all_same();
+            Ok(())
+        }
+        Rule::NullRatioBetween {
+            min_ratio,
+            max_ratio,
+        } => {
+            builder.null_ratio_between(min_ratio, max_ratio)?;
+            Ok(())
+        }
+        Rule::DistinctCountBetween { min, max } => {
+            builder.distinct_count_between(min, max)?;
+            Ok(())
+        }
+        Rule::IsUnique { threshold, .. } => {
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_unique(t);
             Ok(())
         }
+        Rule::UniqueWithinGroup {
+            threshold,
+            group_by,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_unique_within(&group_by, t);
+            Ok(())
+        }
         Rule::Between {
             threshold,
             ref min,
@@ -269,6 +622,17 @@ fn apply_float_rule(
             builder.between(f_min, f_max, t);
             Ok(())
         }
+        Rule::BetweenExclusive {
+            threshold,
+            ref min,
+            ref max,
+        } => {
+            let f_min = extract_float(min, rule.to_string(), column_name.clone())?;
+            let f_max = extract_float(max, rule.to_string(), column_name.clone())?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.between_exclusive(f_min, f_max, t);
+            Ok(())
+        }
         Rule::Min { threshold, ref min } => {
             let f_min = extract_float(min, rule.to_string(), column_name.clone())?;
             let t = threshold.unwrap_or(rule_threshold);
@@ -281,6 +645,39 @@ fn apply_float_rule(
             builder.max(f_max, t);
             Ok(())
         }
+        Rule::Equals {
+            threshold,
+            ref value,
+        } => {
+            let value = extract_float(value, rule.to_string(), column_name.clone())?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.equals(value, t);
+            Ok(())
+        }
+        Rule::IsIn {
+            threshold,
+            ref members,
+        } => {
+            let members = members
+                .iter()
+                .map(|m| extract_float(m, rule.to_string(), column_name.clone()))
+                .collect::<Result<_, _>>()?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_in(members, t);
+            Ok(())
+        }
+        Rule::NotIn {
+            threshold,
+            ref members,
+        } => {
+            let members = members
+                .iter()
+                .map(|m| extract_float(m, rule.to_string(), column_name.clone()))
+                .collect::<Result<_, _>>()?;
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.not_in(members, t);
+            Ok(())
+        }
         Rule::IsPositive { threshold } => {
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_positive(t);
@@ -301,6 +698,16 @@ fn apply_float_rule(
             builder.is_non_negative(t);
             Ok(())
         }
+        Rule::IsFinite { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_finite(t);
+            Ok(())
+        }
+        Rule::MaxDecimalPlaces { threshold, places } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.max_decimal_places(places, t)?;
+            Ok(())
+        }
         Rule::IsIncreasing { threshold } => {
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_monotonically_increasing(t);
@@ -327,6 +734,20 @@ fn apply_float_rule(
             builder.mean_variance(t, max_variance_percent);
             Ok(())
         }
+        Rule::PercentileBelow {
+            threshold,
+            quantile,
+            bound,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.percentile_below(quantile, bound, t)?;
+            Ok(())
+        }
+        Rule::NoOutliersBeyond { threshold, num_std } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.no_outliers_beyond(num_std, t)?;
+            Ok(())
+        }
         _ => Err(CliError::UnknownRule {
             rule_name: rule.to_string(),
             column_type: "float".to_string(),
@@ -340,6 +761,7 @@ fn apply_date_rule(
     rule: Rule,
     column_name: String,
     rule_threshold: f64,
+    reference_date: Option<NaiveDate>,
 ) -> Result<(), CliError> {
     match rule {
         Rule::IsNotNull { threshold } => {
@@ -347,11 +769,38 @@ fn apply_date_rule(
             builder.is_not_null(t);
             Ok(())
         }
-        Rule::IsUnique { threshold } => {
+        Rule::NotAllNull => {
+            builder.not_all_null();
+            Ok(())
+        }
+        Rule::NotAllSame => {
+            builder.not_all_same();
+            Ok(())
+        }
+        Rule::NullRatioBetween {
+            min_ratio,
+            max_ratio,
+        } => {
+            builder.null_ratio_between(min_ratio, max_ratio)?;
+            Ok(())
+        }
+        Rule::DistinctCountBetween { min, max } => {
+            builder.distinct_count_between(min, max)?;
+            Ok(())
+        }
+        Rule::IsUnique { threshold, .. } => {
             let t = threshold.unwrap_or(rule_threshold);
             builder.is_unique(t);
             Ok(())
         }
+        Rule::UniqueWithinGroup {
+            threshold,
+            group_by,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_unique_within(&group_by, t);
+            Ok(())
+        }
         Rule::IsAfter {
             threshold,
             year,
@@ -374,12 +823,12 @@ fn apply_date_rule(
         }
         Rule::IsNotFutur { threshold } => {
             let t = threshold.unwrap_or(rule_threshold);
-            builder.is_not_futur(t);
+            builder.is_not_futur(reference_date, t);
             Ok(())
         }
         Rule::IsNotPast { threshold } => {
             let t = threshold.unwrap_or(rule_threshold);
-            builder.is_not_past(t);
+            builder.is_not_past(reference_date, t);
             Ok(())
         }
         Rule::IsWeekday { threshold } => {
@@ -392,9 +841,188 @@ fn apply_date_rule(
             builder.is_weekend(t);
             Ok(())
         }
+        Rule::IsMonthStart { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_month_start(t);
+            Ok(())
+        }
+        Rule::IsMonthEnd { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_month_end(t);
+            Ok(())
+        }
+        Rule::IsDayOfMonth { threshold, day } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_day_of_month(day, t);
+            Ok(())
+        }
+        Rule::AgeBetween {
+            threshold,
+            min_years,
+            max_years,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.age_between(min_years, max_years, reference_date, t)?;
+            Ok(())
+        }
+        Rule::IsBetween {
+            threshold,
+            start,
+            end,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.between(start, end, t)?;
+            Ok(())
+        }
         _ => Err(CliError::UnknownRule {
             rule_name: rule.to_string(),
-            column_type: "string".to_string(),
+            column_type: "date".to_string(),
+            column_name: column_name.to_string(),
+        }),
+    }
+}
+
+fn apply_timestamp_rule(
+    builder: &mut TimestampColumnBuilder,
+    rule: Rule,
+    column_name: String,
+    rule_threshold: f64,
+    reference_date: Option<NaiveDate>,
+) -> Result<(), CliError> {
+    match rule {
+        Rule::IsNotNull { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_not_null(t);
+            Ok(())
+        }
+        Rule::NotAllNull => {
+            builder.not_all_null();
+            Ok(())
+        }
+        Rule::NotAllSame => {
+            builder.not_all_same();
+            Ok(())
+        }
+        Rule::NullRatioBetween {
+            min_ratio,
+            max_ratio,
+        } => {
+            builder.null_ratio_between(min_ratio, max_ratio)?;
+            Ok(())
+        }
+        Rule::DistinctCountBetween { min, max } => {
+            builder.distinct_count_between(min, max)?;
+            Ok(())
+        }
+        Rule::IsUnique { threshold, .. } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_unique(t);
+            Ok(())
+        }
+        Rule::UniqueWithinGroup {
+            threshold,
+            group_by,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_unique_within(&group_by, t);
+            Ok(())
+        }
+        Rule::TimestampAfter {
+            threshold,
+            timestamp,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_after(&timestamp, t)?;
+            Ok(())
+        }
+        Rule::TimestampBefore {
+            threshold,
+            timestamp,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_before(&timestamp, t)?;
+            Ok(())
+        }
+        Rule::IsNotFutur { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_not_futur(reference_date, t);
+            Ok(())
+        }
+        Rule::IsNotPast { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_not_past(reference_date, t);
+            Ok(())
+        }
+        Rule::TimeBetween {
+            threshold,
+            start_hour,
+            end_hour,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.time_between(start_hour, end_hour, t)?;
+            Ok(())
+        }
+        _ => Err(CliError::UnknownRule {
+            rule_name: rule.to_string(),
+            column_type: "timestamp".to_string(),
+            column_name: column_name.to_string(),
+        }),
+    }
+}
+
+fn apply_boolean_rule(
+    builder: &mut BooleanColumnBuilder,
+    rule: Rule,
+    column_name: String,
+    rule_threshold: f64,
+) -> Result<(), CliError> {
+    match rule {
+        Rule::IsNotNull { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_not_null(t);
+            Ok(())
+        }
+        Rule::NotAllNull => {
+            builder.not_all_null();
+            Ok(())
+        }
+        Rule::NotAllSame => {
+            builder.not_all_same();
+            Ok(())
+        }
+        Rule::NullRatioBetween {
+            min_ratio,
+            max_ratio,
+        } => {
+            builder.null_ratio_between(min_ratio, max_ratio)?;
+            Ok(())
+        }
+        Rule::DistinctCountBetween { min, max } => {
+            builder.distinct_count_between(min, max)?;
+            Ok(())
+        }
+        Rule::IsTrue { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_true(t);
+            Ok(())
+        }
+        Rule::IsFalse { threshold } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.is_false(t);
+            Ok(())
+        }
+        Rule::TrueRatioBetween {
+            threshold,
+            min,
+            max,
+        } => {
+            let t = threshold.unwrap_or(rule_threshold);
+            builder.true_ratio_between(min, max, t)?;
+            Ok(())
+        }
+        _ => Err(CliError::UnknownRule {
+            rule_name: rule.to_string(),
+            column_type: "boolean".to_string(),
             column_name: column_name.to_string(),
         }),
     }
@@ -405,14 +1033,16 @@ fn apply_relation_rule(
     rule: Relation,
     relation_threshold: f64,
 ) -> Result<(), CliError> {
+    let rule_name = rule.to_string();
     match rule {
         Relation::DateComparaison {
             threshold,
             operator,
+            max_gap_days,
         } => {
             let op = CompOperator::try_from(operator.as_str())?;
             let t = threshold.unwrap_or(relation_threshold);
-            builder.date_comparaison(op, t);
+            builder.date_comparaison(op, max_gap_days, t);
             Ok(())
         }
         Relation::NumericComparaison {
@@ -424,16 +1054,331 @@ fn apply_relation_rule(
             builder.numeric_comparaison(op, t);
             Ok(())
         }
+        Relation::StringComparaison {
+            threshold,
+            operator,
+        } => {
+            let op = CompOperator::try_from(operator.as_str())?;
+            let t = threshold.unwrap_or(relation_threshold);
+            builder.string_comparaison(op, t);
+            Ok(())
+        }
+        Relation::SumEquals {
+            threshold,
+            addends,
+            tolerance,
+        } => {
+            let t = threshold.unwrap_or(relation_threshold);
+            builder.sum_equals(addends, tolerance.unwrap_or(0.0), t)?;
+            Ok(())
+        }
+        Relation::Conditional {
+            threshold,
+            when_equals,
+            then,
+        } => {
+            // The condition is matched on text, integers and booleans are written as such
+            let when_equals = match when_equals {
+                Value::String(s) => s,
+                Value::Integer(i) => i.to_string(),
+                Value::Boolean(b) => b.to_string(),
+                _ => {
+                    return Err(CliError::WrongRuleData {
+                        rule_name,
+                        column_name: builder.names[0].clone(),
+                        field_type: "string, integer or boolean".to_string(),
+                    })
+                }
+            };
+            let then_rule = match then {
+                ConditionalThen::IsNotNull => ConditionalRule::NotNull,
+                ConditionalThen::IsNull => ConditionalRule::IsNull,
+                ConditionalThen::Between { min, max } => ConditionalRule::Between { min, max },
+            };
+            let t = threshold.unwrap_or(relation_threshold);
+            builder.conditional(when_equals, then_rule, t)?;
+            Ok(())
+        }
+        Relation::Correlation {
+            threshold,
+            min_coefficient,
+        } => {
+            let t = threshold.unwrap_or(relation_threshold);
+            builder.correlation(min_coefficient, t)?;
+            Ok(())
+        }
+        Relation::DurationBetween {
+            threshold,
+            min_secs,
+            max_secs,
+        } => {
+            let t = threshold.unwrap_or(relation_threshold);
+            builder.duration_between(min_secs, max_secs, t)?;
+            Ok(())
+        }
+        Relation::IsSubsetOf { threshold } => {
+            builder.is_subset_of(threshold.unwrap_or(relation_threshold));
+            Ok(())
+        }
+    }
+}
+
+/// Files matched by a table path: the path itself, the files of a directory or
+/// the matches of a glob pattern. Hidden files are skipped and matches are sorted
+/// so reports keep the same order between runs.
+pub fn expand_table_path(path: &str) -> Result<Vec<String>> {
+    let mut files: Vec<PathBuf> = if is_glob_pattern(path) {
+        glob::glob(path)
+            .with_context(|| format!("Invalid glob pattern '{}'", path))?
+            .filter_map(|entry| entry.ok())
+            .collect()
+    } else if Path::new(path).is_dir() {
+        fs::read_dir(path)
+            .with_context(|| format!("Failed to read directory '{}'", path))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .collect()
+    } else {
+        return Ok(vec![path.to_string()]);
+    };
+    files.retain(|f| f.is_file() && !is_hidden(f));
+    if files.is_empty() {
+        return Err(CliError::NoMatchingFile {
+            path: path.to_string(),
+        }
+        .into());
+    }
+    files.sort();
+    Ok(files
+        .into_iter()
+        .map(|f| f.to_string_lossy().to_string())
+        .collect())
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+}
+
+/// Name and file of each table validated for a config table.
+///
+/// A single file keeps the table name, each file matched by a glob or directory
+/// is named `table/file_name`, or `table/path` when file names collide.
+pub fn expand_table(table: &ConfigTable) -> Result<Vec<(String, String)>> {
+    if !is_multi_file(&table.path) {
+        return Ok(vec![(table.name.clone(), table.path.clone())]);
+    }
+    let files = expand_table_path(&table.path)?;
+    if table.format.is_none() {
+        let first = FileFormat::from_path(&files[0]);
+        if let Some(other) = files.iter().find(|f| FileFormat::from_path(f) != first) {
+            return Err(CliError::MixedFormats {
+                path: table.path.clone(),
+                first: files[0].clone(),
+                other: other.clone(),
+            }
+            .into());
+        }
+    }
+    let file_names: Vec<String> = files
+        .iter()
+        .map(|f| {
+            Path::new(f)
+                .file_name()
+                .map_or_else(|| f.clone(), |n| n.to_string_lossy().to_string())
+        })
+        .collect();
+    let unique = file_names.iter().collect::<HashSet<_>>().len() == file_names.len();
+    Ok(files
+        .into_iter()
+        .zip(file_names)
+        .map(|(file, file_name)| {
+            let suffix = if unique { &file_name } else { &file };
+            (format!("{}/{}", table.name, suffix), file)
+        })
+        .collect())
+}
+
+/// How the files of every table are read, set from the command line
+#[derive(Debug, Clone)]
+pub struct ReadOptions {
+    /// Only validate the first rows of each file
+    pub row_limit: Option<usize>,
+    pub read_mode: ReadMode,
+    /// File size, in bytes, above which `ReadMode::Auto` streams the file
+    pub memory_budget: u64,
+    /// Summarize the values of every configured column
+    pub profile: bool,
+    /// Columns whose most frequent values are counted
+    pub value_counts: Vec<String>,
+    /// Stop validating a table at its first rule failing beyond its threshold
+    pub fail_fast: bool,
+    /// Stop validating a table once this many errors were counted
+    pub max_errors: Option<usize>,
+}
+
+impl Default for ReadOptions {
+    fn default() -> Self {
+        Self {
+            row_limit: None,
+            read_mode: ReadMode::Auto,
+            memory_budget: DEFAULT_MEMORY_BUDGET,
+            profile: false,
+            value_counts: Vec::new(),
+            fail_fast: false,
+            max_errors: None,
+        }
+    }
+}
+
+/// Tables built for a config table, along with their names
+pub type NamedTables = Vec<(String, Box<dyn Table>)>;
+
+/// Build one table per file matched by the table path, see [`expand_table`].
+pub fn construct_tables(table: &ConfigTable, options: &ReadOptions) -> Result<NamedTables> {
+    expand_table(table)?
+        .into_iter()
+        .map(|(name, path)| {
+            let t = construct_csv_table(table, &path, &name, options)?;
+            Ok((name, t))
+        })
+        .collect()
+}
+
+/// Download the object of an `s3://` or `gs://` table path to a temporary file and build
+/// the table reading it. The file is removed once the returned path is dropped, it must
+/// outlive the validation of the table.
+pub fn download_table(
+    table: &ConfigTable,
+    options: &ReadOptions,
+) -> Result<(NamedTables, TempPath)> {
+    // Keep the object name, the format is detected from its extension
+    let object_name = table.path.rsplit('/').next().unwrap_or_default();
+    let file = tempfile::Builder::new()
+        .prefix("dataguard-")
+        .suffix(&format!("-{}", object_name))
+        .tempfile()
+        .with_context(|| "Failed to create a temporary file")?
+        .into_temp_path();
+    download_object(&table.path, &file)
+        .with_context(|| format!("Failed to download '{}'", table.path))?;
+    let local = file.to_string_lossy().to_string();
+    let t = construct_csv_table(table, &local, &table.name, options)?;
+    Ok((vec![(table.name.clone(), t)], file))
+}
+
+/// Reader options of a CSV table
+fn csv_reader_config(table: &ConfigTable) -> ReaderConfig {
+    let mut reader_config = ReaderConfigBuilder::new();
+    if let Some(delimiter) = table.delimiter {
+        reader_config = reader_config.with_delimiter(delimiter as u8);
+    }
+    if let Some(quote) = table.quote {
+        reader_config = reader_config.with_quote(quote as u8);
+    }
+    if let Some(has_header) = table.has_header {
+        reader_config = reader_config.with_header(has_header);
+    }
+    if let Some(null_values) = &table.null_values {
+        reader_config = reader_config.with_null_values(null_values.clone());
+    }
+    reader_config.build()
+}
+
+/// Repeat the columns configured with a `name_pattern` for every matching file column.
+///
+/// Columns configured by name take precedence over patterns, and a file column is only
+/// matched by the first pattern. The file header is only read when a pattern is set.
+fn expand_name_patterns(
+    columns: &[Column],
+    path: &str,
+    format: Option<FileFormat>,
+    reader_config: &ReaderConfig,
+) -> Result<Vec<Column>> {
+    if columns.iter().all(|c| c.name_pattern.is_none()) {
+        return Ok(columns.to_vec());
+    }
+    let file_columns = match format {
+        Some(FileFormat::Csv) => csv_column_names(path, reader_config),
+        Some(FileFormat::Parquet) => parquet_column_names(path),
+        Some(FileFormat::Json) => json_column_names(path),
+        Some(FileFormat::Avro) => avro_column_names(path),
+        Some(FileFormat::Arrow) => ipc_column_names(path),
+        Some(FileFormat::Orc) => orc_column_names(path),
+        // Fixed-width files have no header, patterns are rejected by the config validation
+        Some(FileFormat::FixedWidth) => return Ok(columns.to_vec()),
+        // Reported when the table is built
+        None => return Ok(columns.to_vec()),
+    }
+    .with_context(|| format!("Failed to read the columns of '{}'", path))?;
+
+    // File columns already read by a named column
+    let mut taken: HashSet<&str> = columns
+        .iter()
+        .filter(|c| c.name_pattern.is_none())
+        .map(|c| c.source_name.as_deref().unwrap_or(&c.name))
+        .collect();
+    let mut expanded = Vec::new();
+    for column in columns {
+        let Some(pattern) = &column.name_pattern else {
+            expanded.push(column.clone());
+            continue;
+        };
+        let matching: Vec<&String> = file_columns.iter().filter(|c| column.matches(c)).collect();
+        if matching.is_empty() {
+            return Err(CliError::NoMatchingColumn {
+                pattern: pattern.clone(),
+                path: path.to_string(),
+            }
+            .into());
+        }
+        for name in matching {
+            if taken.insert(name.as_str()) {
+                expanded.push(Column {
+                    name: name.clone(),
+                    name_pattern: None,
+                    ..column.clone()
+                });
+            }
+        }
+    }
+    Ok(expanded)
+}
+
+/// Format of a table file: the `format` of the table, else the one of its extension.
+///
+/// A declared format takes precedence over a different extension, with a warning.
+fn table_format(table: &ConfigTable, path: &str) -> Result<Option<FileFormat>, CliError> {
+    let Some(name) = &table.format else {
+        return Ok(FileFormat::from_path(path));
+    };
+    let format = FileFormat::from_name(name).ok_or_else(|| CliError::UnknownFormat {
+        format: name.clone(),
+    })?;
+    if let Some(detected) = FileFormat::from_path(path).filter(|d| *d != format) {
+        eprintln!(
+            "Warning: reading '{}' as {} although its extension is {}",
+            path, format, detected
+        );
     }
+    Ok(Some(format))
 }
 
-pub fn construct_csv_table(table: &ConfigTable) -> Result<Box<dyn Table>> {
-    let path = &table.path;
+/// Build the table validating a single file of a config table
+pub fn construct_csv_table(
+    table: &ConfigTable,
+    path: &str,
+    name: &str,
+    options: &ReadOptions,
+) -> Result<Box<dyn Table>> {
     let global_type_threshold = &table.type_checking_threshold.unwrap_or(0.);
     let global_rule_threshold = &table.rule_threshold.unwrap_or(0.);
+    let reference_date = table.reference_date()?;
     let mut all_column_builder: Vec<Box<dyn ColumnBuilder>> = Vec::new();
     let mut all_relation_builder: Vec<RelationBuilder> = Vec::new();
-    for column in &table.column {
+    let format = table_format(table, path)?;
+    let reader_config = csv_reader_config(table);
+    let columns = expand_name_patterns(&table.column, path, format, &reader_config)?;
+    for column in &columns {
         let column_type_threshold = column
             .type_checking_threshold
             .unwrap_or(*global_type_threshold);
@@ -441,8 +1386,10 @@ pub fn construct_csv_table(table: &ConfigTable) -> Result<Box<dyn Table>> {
         match column.datatype.as_str() {
             "float" => {
                 let mut builder = NumericColumnBuilder::<f64>::new(column.name.clone())
-                    .with_type_threshold(column_type_threshold);
-                for rule in &column.rule {
+                    .with_type_threshold(column_type_threshold)
+                    .with_optional(column.optional)
+                    .with_source_name(column.source_name.clone());
+                for LabeledRule { rule, label } in &column.rule {
                     apply_float_rule(
                         &mut builder,
                         rule.clone(),
@@ -451,14 +1398,19 @@ pub fn construct_csv_table(table: &ConfigTable) -> Result<Box<dyn Table>> {
                     )
                     .with_context(|| {
                         format!("Failed to apply rule to column '{}'", column.name.clone())
-                    })?
+                    })?;
+                    if let Some(label) = label {
+                        builder.with_label(label.clone())?;
+                    }
                 }
                 all_column_builder.push(Box::new(builder));
             }
             "integer" => {
                 let mut builder = NumericColumnBuilder::<i64>::new(column.name.clone())
-                    .with_type_threshold(column_type_threshold);
-                for rule in &column.rule {
+                    .with_type_threshold(column_type_threshold)
+                    .with_optional(column.optional)
+                    .with_source_name(column.source_name.clone());
+                for LabeledRule { rule, label } in &column.rule {
                     apply_integer_rule(
                         &mut builder,
                         rule.clone(),
@@ -467,36 +1419,106 @@ pub fn construct_csv_table(table: &ConfigTable) -> Result<Box<dyn Table>> {
                     )
                     .with_context(|| {
                         format!("Failed to apply rule to column '{}'", column.name.clone())
-                    })?
+                    })?;
+                    if let Some(label) = label {
+                        builder.with_label(label.clone())?;
+                    }
                 }
                 all_column_builder.push(Box::new(builder));
             }
             "string" => {
                 let mut builder = StringColumnBuilder::new(column.name.clone())
-                    .with_type_threshold(column_type_threshold);
-                for rule in &column.rule {
+                    .with_type_threshold(column_type_threshold)
+                    .with_optional(column.optional)
+                    .with_source_name(column.source_name.clone());
+                for LabeledRule { rule, label } in &column.rule {
                     apply_string_rule(
                         &mut builder,
                         rule.clone(),
                         column.name.clone(),
                         column_rule_threshold,
                     )
-                    .with_context(|| format!("Failed to apply rule to column '{}'", column.name))?
+                    .with_context(|| format!("Failed to apply rule to column '{}'", column.name))?;
+                    if let Some(label) = label {
+                        builder.with_label(label.clone())?;
+                    }
                 }
                 all_column_builder.push(Box::new(builder));
             }
             "date" => {
                 let mut builder =
                     DateColumnBuilder::new(column.name.clone(), column.format.clone().unwrap())
-                        .with_type_threshold(column_type_threshold);
-                for rule in &column.rule {
+                        .with_type_threshold(column_type_threshold)
+                        .with_optional(column.optional)
+                        .with_source_name(column.source_name.clone());
+                for LabeledRule { rule, label } in &column.rule {
                     apply_date_rule(
                         &mut builder,
                         rule.clone(),
                         column.name.clone(),
                         column_rule_threshold,
+                        reference_date,
+                    )
+                    .with_context(|| format!("Failed to apply rule to column '{}'", column.name))?;
+                    if let Some(label) = label {
+                        builder.with_label(label.clone())?;
+                    }
+                }
+                all_column_builder.push(Box::new(builder));
+            }
+            "timestamp" => {
+                let mut builder = TimestampColumnBuilder::new(
+                    column.name.clone(),
+                    column.format.clone().unwrap(),
+                )
+                .with_type_threshold(column_type_threshold)
+                .with_optional(column.optional)
+                .with_source_name(column.source_name.clone());
+                for LabeledRule { rule, label } in &column.rule {
+                    apply_timestamp_rule(
+                        &mut builder,
+                        rule.clone(),
+                        column.name.clone(),
+                        column_rule_threshold,
+                        reference_date,
+                    )
+                    .with_context(|| format!("Failed to apply rule to column '{}'", column.name))?;
+                    if let Some(label) = label {
+                        builder.with_label(label.clone())?;
+                    }
+                }
+                all_column_builder.push(Box::new(builder));
+            }
+            "boolean" => {
+                let mut builder = BooleanColumnBuilder::new(column.name.clone())
+                    .with_type_threshold(column_type_threshold)
+                    .with_optional(column.optional)
+                    .with_source_name(column.source_name.clone());
+                if column.true_values.is_some() || column.false_values.is_some() {
+                    let (default_true, default_false) = builder
+                        .boolean_tokens()
+                        .map(|(t, f)| (t.to_vec(), f.to_vec()))
+                        .unwrap_or_default();
+                    builder = builder
+                        .with_tokens(
+                            column.true_values.clone().unwrap_or(default_true),
+                            column.false_values.clone().unwrap_or(default_false),
+                        )
+                        .with_context(|| {
+                            format!("Invalid boolean tokens for column '{}'", column.name)
+                        })?;
+                }
+                for LabeledRule { rule, label } in &column.rule {
+                    apply_boolean_rule(
+                        &mut builder,
+                        rule.clone(),
+                        column.name.clone(),
+                        column_rule_threshold,
                     )
-                    .with_context(|| format!("Failed to apply rule to column '{}'", column.name))?
+                    .with_context(|| format!("Failed to apply rule to column '{}'", column.name))?;
+                    if let Some(label) = label {
+                        builder.with_label(label.clone())?;
+                    }
                 }
                 all_column_builder.push(Box::new(builder));
             }
@@ -528,38 +1550,133 @@ pub fn construct_csv_table(table: &ConfigTable) -> Result<Box<dyn Table>> {
             all_relation_builder.push(builder);
         }
     }
-    match table.path.split_once(".") {
-        Some((_, format)) => match format {
-            "csv" => {
-                let mut t = CsvTable::new(path.clone(), table.name.clone()).with_context(|| {
-                    format!("Failed to create validation table: {}", table.name)
-                })?;
-                t.prepare(all_column_builder, all_relation_builder).unwrap();
-                Ok(Box::new(t))
-            }
-            "parquet" => {
-                let mut t =
-                    ParquetTable::new(path.clone(), table.name.clone()).with_context(|| {
-                        format!("Failed to create validation table: {}", table.name)
-                    })?;
-                t.prepare(all_column_builder, all_relation_builder).unwrap();
-                Ok(Box::new(t))
-            }
-            _ => Err(CliError::UnknownFormat {
-                format: format.to_string(),
+
+    let mut all_group_builder: Vec<GroupBuilder> = Vec::new();
+    if let Some(group_asserts) = &table.group_assert {
+        for group in group_asserts {
+            let expected = load_lookup(&group.lookup_file)
+                .with_context(|| format!("Failed to load lookup file '{}'", group.lookup_file))?;
+            let mut builder = GroupBuilder::new(group.group_by.clone(), group.value.clone());
+            builder.sum_equals(
+                expected,
+                group.tolerance.unwrap_or(0.),
+                group.threshold.unwrap_or(*global_rule_threshold),
+            );
+            all_group_builder.push(builder);
+        }
+    }
+    for group in table.group_count.iter().flatten() {
+        let (min, max) = match group.count {
+            Some(count) => (Some(count), Some(count)),
+            None => (group.min, group.max),
+        };
+        let mut builder = GroupBuilder::count_by(group.group_by.clone());
+        builder
+            .count_between(min, max, group.threshold.unwrap_or(*global_rule_threshold))
+            .map_err(CliError::from)
+            .with_context(|| format!("Invalid group count on '{}'", group.group_by))?;
+        all_group_builder.push(builder);
+    }
+    let table_error = || format!("Failed to create validation table: {}", name);
+    let mut t = match format {
+        Some(FileFormat::Csv) => with_options(
+            CsvTable::new(path.to_string(), name.to_string())
+                .with_context(table_error)?
+                .with_reader_config(reader_config),
+            table,
+            options,
+        ),
+        Some(FileFormat::Parquet) => with_options(
+            ParquetTable::new(path.to_string(), name.to_string()).with_context(table_error)?,
+            table,
+            options,
+        ),
+        Some(FileFormat::Json) => with_options(
+            JsonTable::new(path.to_string(), name.to_string()).with_context(table_error)?,
+            table,
+            options,
+        ),
+        Some(FileFormat::Avro) => with_options(
+            AvroTable::new(path.to_string(), name.to_string()).with_context(table_error)?,
+            table,
+            options,
+        ),
+        Some(FileFormat::Arrow) => with_options(
+            IpcTable::new(path.to_string(), name.to_string()).with_context(table_error)?,
+            table,
+            options,
+        ),
+        Some(FileFormat::FixedWidth) => {
+            // Positions are checked by the config validation
+            let fields = columns
+                .iter()
+                .map(|c| {
+                    FixedWidthField::new(
+                        c.source_name.clone().unwrap_or_else(|| c.name.clone()),
+                        c.offset.unwrap_or_default(),
+                        c.width.unwrap_or_default(),
+                    )
+                })
+                .collect();
+            with_options(
+                FixedWidthTable::new(path.to_string(), name.to_string(), fields)
+                    .with_context(table_error)?,
+                table,
+                options,
+            )
+        }
+        Some(FileFormat::Orc) => with_options(
+            OrcTable::new(path.to_string(), name.to_string()).with_context(table_error)?,
+            table,
+            options,
+        ),
+        None => {
+            return match Path::new(path).extension() {
+                Some(format) => Err(CliError::UnknownFormat {
+                    format: format.to_string_lossy().to_string(),
+                }
+                .into()),
+                None => Err(CliError::UnknownFilePath {
+                    path: path.to_string(),
+                }
+                .into()),
             }
-            .into()),
-        },
-        None => Err(CliError::UnknownFilePath {
-            path: table.path.to_string(),
         }
-        .into()),
+    };
+    t.prepare(all_column_builder, all_relation_builder)
+        .with_context(|| format!("Failed to prepare columns: {}", name))?;
+    t.prepare_groups(all_group_builder)
+        .with_context(|| format!("Failed to prepare group assertions: {}", name))?;
+    Ok(t)
+}
+
+/// Apply the options shared by every format to a file table
+fn with_options<R: FormatReader + 'static>(
+    t: FileTable<R>,
+    table: &ConfigTable,
+    options: &ReadOptions,
+) -> Box<dyn Table> {
+    let t = t
+        .with_strict_schema(table.strict_schema.unwrap_or(false))
+        .with_duplicate_rows(table.detect_duplicate_rows.unwrap_or(false))
+        .with_row_count(table.min_rows, table.max_rows)
+        .with_read_mode(options.read_mode)
+        .with_memory_budget(options.memory_budget)
+        .with_profile(options.profile)
+        .with_value_counts(options.value_counts.clone())
+        .with_fail_fast(options.fail_fast)
+        .with_max_errors(options.max_errors);
+    match options.row_limit {
+        Some(limit) => Box::new(t.with_row_limit(limit)),
+        None => Box::new(t),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::{Column, GroupCount};
+    use dataguard_core::columns::TableConstraint;
 
     // ==================== STRING RULE TESTS ====================
 
@@ -567,11 +1684,37 @@ mod tests {
     #[test]
     fn test_apply_string_rule_is_unique() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
-        let rule = Rule::IsUnique { threshold: None };
+        let rule = Rule::IsUnique {
+            threshold: None,
+            normalize: None,
+        };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_apply_string_rule_is_unique_normalize() {
+        let mut builder = StringColumnBuilder::new("email".to_string());
+        let rule = Rule::IsUnique {
+            threshold: None,
+            normalize: Some("trim_lowercase".to_string()),
+        };
+        apply_string_rule(&mut builder, rule, "email".to_string(), 0.0).unwrap();
+        match &builder.rules()[0] {
+            dataguard_core::ColumnRule::Unicity { normalize, .. } => {
+                assert_eq!(*normalize, NormalizeMode::TrimLowercase)
+            }
+            _ => panic!("Expected Unicity rule"),
+        }
+
+        let rule = Rule::IsUnique {
+            threshold: None,
+            normalize: Some("upper".to_string()),
+        };
+        let result = apply_string_rule(&mut builder, rule, "email".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
     #[test]
     fn test_apply_string_rule_with_length_between() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
@@ -584,12 +1727,25 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_apply_string_rule_with_utf16_length_between() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::WithUtf16LengthBetween {
+            threshold: None,
+            min_length: 1,
+            max_length: 10,
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_apply_string_rule_with_min_length() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
         let rule = Rule::WithMinLength {
             threshold: None,
             min_length: 5,
+            count_bytes: None,
         };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
@@ -601,9 +1757,29 @@ mod tests {
         let rule = Rule::WithMaxLength {
             threshold: None,
             max_length: 10,
+            count_bytes: None,
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_string_rule_with_max_length_bytes() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::WithMaxLength {
+            threshold: None,
+            max_length: 10,
+            count_bytes: Some(true),
         };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
+        assert!(matches!(
+            builder.rules()[0],
+            dataguard_core::ColumnRule::StringLength {
+                mode: dataguard_core::rules::LengthMode::Bytes,
+                ..
+            }
+        ));
     }
 
     #[test]
@@ -622,7 +1798,11 @@ mod tests {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
         let rule = Rule::IsIn {
             threshold: None,
-            members: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            members: vec![
+                Value::String("a".to_string()),
+                Value::String("b".to_string()),
+                Value::String("c".to_string()),
+            ],
         };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
@@ -641,25 +1821,93 @@ mod tests {
     }
 
     #[test]
-    fn test_apply_string_rule_is_numeric() {
+    fn test_apply_string_rule_matches_any_of() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
-        let rule = Rule::IsNumeric { threshold: None };
+        let rule = Rule::MatchesAnyOf {
+            threshold: None,
+            patterns: vec![r"^AB-\d{4}$".to_string(), r"^PRD_\d{3}$".to_string()],
+            flag: None,
+        };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
+
+        let rule = Rule::MatchesAnyOf {
+            threshold: None,
+            patterns: vec!["^ok$".to_string(), "[unclosed".to_string()],
+            flag: None,
+        };
+        match apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0) {
+            Err(CliError::ValidationError(e)) => assert!(e.to_string().contains("[unclosed")),
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
     }
 
     #[test]
-    fn test_apply_string_rule_is_alpha() {
+    fn test_apply_string_rule_starts_with() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
-        let rule = Rule::IsAlpha { threshold: None };
+        let rule = Rule::StartsWith {
+            threshold: None,
+            prefix: "FR".to_string(),
+        };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
     }
 
     #[test]
-    fn test_apply_string_rule_is_alphanumeric() {
+    fn test_apply_string_rule_ends_with_empty_suffix() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
-        let rule = Rule::IsAlphaNumeric { threshold: None };
+        let rule = Rule::EndsWith {
+            threshold: None,
+            suffix: String::new(),
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_apply_string_rule_contains() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::Contains {
+            threshold: None,
+            substring: "#ref".to_string(),
+            flag: None,
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_string_rule_not_contains_case_insensitive() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::NotContains {
+            threshold: None,
+            substring: "secret".to_string(),
+            flag: Some("i".to_string()),
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_string_rule_is_numeric() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::IsNumeric { threshold: None };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_string_rule_is_alpha() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::IsAlpha { threshold: None };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_string_rule_is_alphanumeric() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::IsAlphaNumeric { threshold: None };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
     }
@@ -667,7 +1915,10 @@ mod tests {
     #[test]
     fn test_apply_string_rule_is_uppercase() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
-        let rule = Rule::IsUpperCase { threshold: None };
+        let rule = Rule::IsUpperCase {
+            threshold: None,
+            unicode: None,
+        };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
     }
@@ -675,9 +1926,77 @@ mod tests {
     #[test]
     fn test_apply_string_rule_is_lowercase() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
-        let rule = Rule::IsLowerCase { threshold: None };
+        let rule = Rule::IsLowerCase {
+            threshold: None,
+            unicode: None,
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_string_rule_is_uppercase_unicode() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::IsUpperCase {
+            threshold: None,
+            unicode: Some(true),
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+        assert!(matches!(
+            builder.rules()[0],
+            dataguard_core::ColumnRule::StringCase {
+                upper: true,
+                unicode: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_apply_string_rule_no_surrounding_whitespace() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::NoSurroundingWhitespace { threshold: None };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_string_rule_no_whitespace() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::NoWhitespace { threshold: None };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_string_rule_contains_only() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::ContainsOnly {
+            threshold: None,
+            allowed: "ACGT".to_string(),
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+
+        let rule = Rule::ContainsOnly {
+            threshold: None,
+            allowed: String::new(),
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_apply_string_rule_consistent_length() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::ConsistentLength {
+            threshold: Some(5.0),
+            tolerance: Some(1),
+        };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
+        assert_eq!(builder.rules().len(), 1);
     }
 
     #[test]
@@ -691,11 +2010,32 @@ mod tests {
     #[test]
     fn test_apply_string_rule_is_email() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
-        let rule = Rule::IsEmail { threshold: None };
+        let rule = Rule::IsEmail {
+            threshold: None,
+            strict: None,
+        };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_apply_string_rule_is_email_strict() {
+        let mut lenient = StringColumnBuilder::new("test_col".to_string());
+        let mut strict = StringColumnBuilder::new("test_col".to_string());
+        for (builder, strict) in [(&mut lenient, None), (&mut strict, Some(true))] {
+            let rule = Rule::IsEmail {
+                threshold: None,
+                strict,
+            };
+            assert!(apply_string_rule(builder, rule, "test_col".to_string(), 0.0).is_ok());
+        }
+        let pattern = |builder: &StringColumnBuilder| match &builder.rules()[0] {
+            dataguard_core::ColumnRule::StringRegex { pattern, .. } => pattern.clone(),
+            _ => panic!("Expected StringRegex rule"),
+        };
+        assert_ne!(pattern(&lenient), pattern(&strict));
+    }
+
     #[test]
     fn test_apply_string_rule_is_uuid() {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
@@ -704,6 +2044,114 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_apply_string_rule_is_ip() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        for rule in [
+            Rule::IsIp { threshold: None },
+            Rule::IsIpv4 { threshold: None },
+            Rule::IsIpv6 { threshold: None },
+        ] {
+            let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_apply_string_rule_ip_in_cidr() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::IpInCidr {
+            threshold: None,
+            cidrs: vec!["10.0.0.0/8".to_string()],
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+
+        let rule = Rule::IpInCidr {
+            threshold: None,
+            cidrs: vec!["10.0.0.0/80".to_string()],
+        };
+        match apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0) {
+            Err(CliError::ValidationError(e)) => assert!(e.to_string().contains("10.0.0.0/80")),
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_apply_string_rule_is_json() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        for rule in [
+            Rule::IsJson { threshold: None },
+            Rule::IsJsonObject { threshold: None },
+            Rule::IsJsonArray { threshold: None },
+        ] {
+            let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_apply_string_rule_checksums() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        for rule in [
+            Rule::IsCreditCard { threshold: None },
+            Rule::IsIban { threshold: None },
+        ] {
+            let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn test_apply_string_rule_encodings() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        for rule in [
+            Rule::IsBase64 {
+                threshold: None,
+                strict_padding: None,
+            },
+            Rule::IsHex {
+                threshold: Some(1.0),
+                allow_prefix: Some(true),
+            },
+        ] {
+            apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0).unwrap();
+        }
+        match &builder.rules()[0] {
+            dataguard_core::ColumnRule::Base64 { strict_padding, .. } => assert!(strict_padding),
+            _ => panic!("Expected Base64 rule"),
+        }
+        match &builder.rules()[1] {
+            dataguard_core::ColumnRule::Hex {
+                threshold,
+                allow_prefix,
+                ..
+            } => {
+                assert_eq!(*threshold, 1.0);
+                assert!(allow_prefix);
+            }
+            _ => panic!("Expected Hex rule"),
+        }
+    }
+
+    #[test]
+    fn test_apply_string_rule_is_phone() {
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let rule = Rule::IsPhone {
+            threshold: None,
+            region: Some("US".to_string()),
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+
+        let rule = Rule::IsPhone {
+            threshold: None,
+            region: Some("USA".to_string()),
+        };
+        let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
     // Error cases
     #[test]
     fn test_apply_string_rule_unknown_rule() {
@@ -744,6 +2192,55 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_apply_string_rule_not_in() {
+        let mut builder = StringColumnBuilder::new("category".to_string());
+        let rule: Rule = toml::from_str(
+            r#"
+            name = "is_not_in"
+            members = ["legacy", "retired"]
+            "#,
+        )
+        .unwrap();
+        assert!(apply_string_rule(&mut builder, rule, "category".to_string(), 0.0).is_ok());
+        let rule = Rule::NotIn {
+            threshold: None,
+            members: vec![Value::Integer(1)],
+        };
+        assert!(apply_string_rule(&mut builder, rule, "category".to_string(), 0.0).is_err());
+    }
+
+    #[test]
+    fn test_apply_rule_equals() {
+        let rule = |value: Value| Rule::Equals {
+            threshold: None,
+            value,
+        };
+        let mut builder = StringColumnBuilder::new("test_col".to_string());
+        let value = Value::String("v3".to_string());
+        assert!(apply_string_rule(&mut builder, rule(value), "test_col".to_string(), 0.0).is_ok());
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let result = apply_integer_rule(
+            &mut builder,
+            rule(Value::Integer(1)),
+            "test_col".to_string(),
+            0.0,
+        );
+        assert!(result.is_ok());
+        // The literal must match the column type
+        let value = Value::String("1".to_string());
+        let result = apply_integer_rule(&mut builder, rule(value), "test_col".to_string(), 0.0);
+        assert!(result.is_err());
+        let mut builder = NumericColumnBuilder::<f64>::new("test_col".to_string());
+        let result = apply_float_rule(
+            &mut builder,
+            rule(Value::Float(0.5)),
+            "test_col".to_string(),
+            0.0,
+        );
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn test_apply_integer_rule_min() {
         let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
@@ -814,7 +2311,108 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_apply_integer_rule_is_in() {
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let rule = Rule::IsIn {
+            threshold: None,
+            members: vec![Value::Integer(200), Value::Integer(404)],
+        };
+        let result = apply_integer_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_integer_rule_not_in() {
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let rule = Rule::NotIn {
+            threshold: None,
+            members: vec![Value::Integer(500)],
+        };
+        let result = apply_integer_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_integer_rule_multiple_of() {
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let rule = Rule::MultipleOf {
+            threshold: None,
+            divisor: 12,
+        };
+        let result = apply_integer_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_integer_rule_percentile_below() {
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let rule = Rule::PercentileBelow {
+            threshold: None,
+            quantile: 0.99,
+            bound: 250.0,
+        };
+        let result = apply_integer_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_float_rule_no_outliers_beyond() {
+        let mut builder = NumericColumnBuilder::<f64>::new("test_col".to_string());
+        let rule = Rule::NoOutliersBeyond {
+            threshold: Some(1.0),
+            num_std: 3.0,
+        };
+        let result = apply_float_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
     // Error cases
+    #[test]
+    fn test_apply_integer_rule_multiple_of_zero() {
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let rule = Rule::MultipleOf {
+            threshold: None,
+            divisor: 0,
+        };
+        let result = apply_integer_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_apply_float_rule_percentile_out_of_range() {
+        let mut builder = NumericColumnBuilder::<f64>::new("test_col".to_string());
+        let rule = Rule::PercentileBelow {
+            threshold: None,
+            quantile: 99.0,
+            bound: 250.0,
+        };
+        let result = apply_float_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_apply_integer_rule_no_outliers_negative_std() {
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let rule = Rule::NoOutliersBeyond {
+            threshold: None,
+            num_std: -1.0,
+        };
+        let result = apply_integer_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_apply_integer_rule_is_in_wrong_member_type() {
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let rule = Rule::IsIn {
+            threshold: None,
+            members: vec![Value::Integer(200), Value::String("404".to_string())],
+        };
+        let result = apply_integer_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::WrongRuleData { .. })));
+    }
+
     #[test]
     fn test_apply_integer_rule_unknown_rule() {
         let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
@@ -978,6 +2576,40 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_apply_float_rule_is_finite() {
+        let mut builder = NumericColumnBuilder::<f64>::new("test_col".to_string());
+        let rule = Rule::IsFinite { threshold: None };
+        let result = apply_float_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_float_rule_max_decimal_places() {
+        let mut builder = NumericColumnBuilder::<f64>::new("test_col".to_string());
+        let rule = Rule::MaxDecimalPlaces {
+            threshold: None,
+            places: 2,
+        };
+        let result = apply_float_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+
+        let rule = Rule::MaxDecimalPlaces {
+            threshold: None,
+            places: 20,
+        };
+        let result = apply_float_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_apply_integer_rule_is_finite_unknown() {
+        let mut builder = NumericColumnBuilder::<i64>::new("test_col".to_string());
+        let rule = Rule::IsFinite { threshold: None };
+        let result = apply_integer_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::UnknownRule { .. })));
+    }
+
     // Error cases
     #[test]
     fn test_apply_float_rule_unknown_rule() {
@@ -1057,6 +2689,372 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // ==================== DATE RULE TESTS ====================
+
+    #[test]
+    fn test_apply_date_rule_is_month_end() {
+        let mut builder = DateColumnBuilder::new("test_col".to_string(), "%Y-%m-%d".to_string());
+        let rule = Rule::IsMonthEnd { threshold: None };
+        let result = apply_date_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_date_rule_is_day_of_month() {
+        let mut builder = DateColumnBuilder::new("test_col".to_string(), "%Y-%m-%d".to_string());
+        let rule = Rule::IsDayOfMonth {
+            threshold: None,
+            day: 15,
+        };
+        let result = apply_date_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_date_rule_age_between() {
+        let mut builder = DateColumnBuilder::new("test_col".to_string(), "%Y-%m-%d".to_string());
+        let rule = Rule::AgeBetween {
+            threshold: None,
+            min_years: 18,
+            max_years: 65,
+        };
+        let result = apply_date_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_date_rule_is_between() {
+        let mut builder = DateColumnBuilder::new("test_col".to_string(), "%Y-%m-%d".to_string());
+        let rule = Rule::IsBetween {
+            threshold: None,
+            start: (2020, 1, 1),
+            end: (2024, 12, 31),
+        };
+        let result = apply_date_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_date_rule_is_between_invalid_date() {
+        let mut builder = DateColumnBuilder::new("test_col".to_string(), "%Y-%m-%d".to_string());
+        let rule = Rule::IsBetween {
+            threshold: None,
+            start: (2020, 13, 1),
+            end: (2024, 12, 31),
+        };
+        let result = apply_date_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(matches!(
+            result,
+            Err(CliError::ValidationError(
+                dataguard_core::RuleError::IncorrectDateError(2020, 13, 1)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_apply_date_rule_age_between_inverted() {
+        let mut builder = DateColumnBuilder::new("test_col".to_string(), "%Y-%m-%d".to_string());
+        let rule = Rule::AgeBetween {
+            threshold: None,
+            min_years: 65,
+            max_years: 18,
+        };
+        let result = apply_date_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_apply_date_rule_unknown_rule() {
+        let mut builder = DateColumnBuilder::new("test_col".to_string(), "%Y-%m-%d".to_string());
+        let rule = Rule::IsAlphaNumeric { threshold: None };
+        let result = apply_date_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        match result.unwrap_err() {
+            CliError::UnknownRule { column_type, .. } => assert_eq!(column_type, "date"),
+            _ => panic!("Expected UnknownRule error"),
+        }
+    }
+
+    // ==================== TIMESTAMP RULE TESTS ====================
+
+    #[test]
+    fn test_apply_timestamp_rule_time_between() {
+        let mut builder =
+            TimestampColumnBuilder::new("test_col".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+        let rule = Rule::TimeBetween {
+            threshold: None,
+            start_hour: 22,
+            end_hour: 6,
+        };
+        let result = apply_timestamp_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_timestamp_rule_timestamp_before() {
+        let mut builder =
+            TimestampColumnBuilder::new("test_col".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+        let rule = Rule::TimestampBefore {
+            threshold: None,
+            timestamp: "2024-01-31T18:00:00".to_string(),
+        };
+        let result = apply_timestamp_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(result.is_ok());
+    }
+
+    // Error cases
+
+    #[test]
+    fn test_apply_timestamp_rule_invalid_timestamp() {
+        let mut builder =
+            TimestampColumnBuilder::new("test_col".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+        let rule = Rule::TimestampAfter {
+            threshold: None,
+            timestamp: "31/01/2024".to_string(),
+        };
+        let result = apply_timestamp_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_apply_timestamp_rule_unsupported() {
+        let mut builder =
+            TimestampColumnBuilder::new("test_col".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+        let rule = Rule::IsWeekday { threshold: None };
+        let result = apply_timestamp_rule(&mut builder, rule, "test_col".to_string(), 0.0, None);
+        assert!(matches!(result, Err(CliError::UnknownRule { .. })));
+    }
+
+    // ==================== RELATION RULE TESTS ====================
+
+    #[test]
+    fn test_apply_relation_rule_conditional() {
+        let mut builder = RelationBuilder::new(["is_active".to_string(), "closed_at".to_string()]);
+        let rule = Relation::Conditional {
+            threshold: None,
+            when_equals: Value::Boolean(true),
+            then: ConditionalThen::IsNull,
+        };
+        let result = apply_relation_rule(&mut builder, rule, 0.0);
+        assert!(result.is_ok());
+        assert!(matches!(
+            &builder.rules()[0],
+            TableConstraint::Conditional { when_equals, .. } if when_equals == "true"
+        ));
+    }
+
+    #[test]
+    fn test_apply_relation_rule_correlation() {
+        let mut builder = RelationBuilder::new(["inside".to_string(), "outside".to_string()]);
+        let rule = Relation::Correlation {
+            threshold: None,
+            min_coefficient: 0.8,
+        };
+        let result = apply_relation_rule(&mut builder, rule, 0.0);
+        assert!(result.is_ok());
+        assert_eq!(
+            builder.rules()[0],
+            TableConstraint::Correlation {
+                min_coefficient: 0.8,
+                threshold: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_relation_rule_duration_between() {
+        let mut builder = RelationBuilder::new(["start_ts".to_string(), "end_ts".to_string()]);
+        let rule = Relation::DurationBetween {
+            threshold: None,
+            min_secs: 1,
+            max_secs: 3600,
+        };
+        let result = apply_relation_rule(&mut builder, rule, 0.0);
+        assert!(result.is_ok());
+        assert_eq!(
+            builder.rules()[0],
+            TableConstraint::Duration {
+                min_secs: 1,
+                max_secs: 3600,
+                threshold: 0.0
+            }
+        );
+
+        let rule = Relation::DurationBetween {
+            threshold: None,
+            min_secs: 60,
+            max_secs: 1,
+        };
+        assert!(apply_relation_rule(&mut builder, rule, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_apply_relation_rule_is_subset_of() {
+        let mut builder =
+            RelationBuilder::new(["child_category".to_string(), "parent_category".to_string()]);
+        let rule = Relation::IsSubsetOf { threshold: None };
+        apply_relation_rule(&mut builder, rule, 1.0).unwrap();
+        assert_eq!(
+            builder.rules()[0],
+            TableConstraint::Subset { threshold: 1.0 }
+        );
+    }
+
+    #[test]
+    fn test_apply_relation_rule_date_comparaison_max_gap() {
+        let mut builder = RelationBuilder::new(["order_date".to_string(), "ship_date".to_string()]);
+        let rule = Relation::DateComparaison {
+            threshold: None,
+            operator: "<=".to_string(),
+            max_gap_days: Some(30),
+        };
+        let result = apply_relation_rule(&mut builder, rule, 0.0);
+        assert!(result.is_ok());
+        assert_eq!(
+            builder.rules()[0],
+            TableConstraint::DateComparaison {
+                op: CompOperator::Lte,
+                threshold: 0.0,
+                max_gap_days: Some(30)
+            }
+        );
+    }
+
+    // Error cases
+
+    #[test]
+    fn test_apply_relation_rule_conditional_float_condition() {
+        let mut builder = RelationBuilder::new(["ratio".to_string(), "note".to_string()]);
+        let rule = Relation::Conditional {
+            threshold: None,
+            when_equals: Value::Float(0.5),
+            then: ConditionalThen::IsNotNull,
+        };
+        let result = apply_relation_rule(&mut builder, rule, 0.0);
+        assert!(matches!(result, Err(CliError::WrongRuleData { .. })));
+    }
+
+    #[test]
+    fn test_apply_relation_rule_conditional_inverted_range() {
+        let mut builder = RelationBuilder::new(["status".to_string(), "weight".to_string()]);
+        let rule = Relation::Conditional {
+            threshold: None,
+            when_equals: Value::String("shipped".to_string()),
+            then: ConditionalThen::Between {
+                min: 50.0,
+                max: 0.0,
+            },
+        };
+        let result = apply_relation_rule(&mut builder, rule, 0.0);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
+    // ==================== BOOLEAN RULE TESTS ====================
+
+    #[test]
+    fn test_apply_boolean_rule_is_true() {
+        let mut builder = BooleanColumnBuilder::new("test_col".to_string());
+        let rule = Rule::IsTrue { threshold: None };
+        let result = apply_boolean_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_boolean_rule_true_ratio_between() {
+        let mut builder = BooleanColumnBuilder::new("test_col".to_string());
+        let rule = Rule::TrueRatioBetween {
+            threshold: None,
+            min: 0.2,
+            max: 0.8,
+        };
+        let result = apply_boolean_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_apply_boolean_rule_true_ratio_invalid_bounds() {
+        let mut builder = BooleanColumnBuilder::new("test_col".to_string());
+        let rule = Rule::TrueRatioBetween {
+            threshold: None,
+            min: 0.8,
+            max: 0.2,
+        };
+        let result = apply_boolean_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_apply_rule_null_ratio_between() {
+        let rule = Rule::NullRatioBetween {
+            min_ratio: 0.0,
+            max_ratio: 0.05,
+        };
+        let mut builder = StringColumnBuilder::new("email".to_string());
+        apply_string_rule(&mut builder, rule, "email".to_string(), 0.0).unwrap();
+        assert_eq!(
+            builder.rules()[0],
+            dataguard_core::ColumnRule::NullRatioBetween {
+                min_ratio: 0.0,
+                max_ratio: 0.05
+            }
+        );
+
+        let rule = Rule::NullRatioBetween {
+            min_ratio: 0.5,
+            max_ratio: 0.1,
+        };
+        let mut builder = BooleanColumnBuilder::new("active".to_string());
+        let result = apply_boolean_rule(&mut builder, rule, "active".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_apply_rule_distinct_count_between() {
+        let rule = Rule::DistinctCountBetween { min: 1, max: 250 };
+        let mut builder = StringColumnBuilder::new("country_code".to_string());
+        apply_string_rule(&mut builder, rule, "country_code".to_string(), 0.0).unwrap();
+        assert_eq!(
+            builder.rules()[0],
+            dataguard_core::ColumnRule::DistinctCountBetween { min: 1, max: 250 }
+        );
+
+        let rule = Rule::DistinctCountBetween { min: 250, max: 1 };
+        let mut builder = NumericColumnBuilder::<i64>::new("id".to_string());
+        let result = apply_integer_rule(&mut builder, rule, "id".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_apply_rule_unique_within_group() {
+        let rule = Rule::UniqueWithinGroup {
+            threshold: None,
+            group_by: "tenant_id".to_string(),
+        };
+        let mut builder = StringColumnBuilder::new("email".to_string());
+        apply_string_rule(&mut builder, rule.clone(), "email".to_string(), 0.01).unwrap();
+        assert_eq!(
+            builder.rules()[0],
+            dataguard_core::ColumnRule::UniqueWithinGroup {
+                threshold: 0.01,
+                group_by: "tenant_id".to_string()
+            }
+        );
+
+        let mut builder = BooleanColumnBuilder::new("active".to_string());
+        let result = apply_boolean_rule(&mut builder, rule, "active".to_string(), 0.0);
+        assert!(matches!(result, Err(CliError::UnknownRule { .. })));
+    }
+
+    #[test]
+    fn test_apply_boolean_rule_unknown_rule() {
+        let mut builder = BooleanColumnBuilder::new("test_col".to_string());
+        let rule = Rule::IsUnique {
+            threshold: None,
+            normalize: None,
+        };
+        let result = apply_boolean_rule(&mut builder, rule, "test_col".to_string(), 0.0);
+        assert!(result.is_err());
+    }
+
     // ============================================================================
     // Threshold Propagation Tests
     // ============================================================================
@@ -1068,6 +3066,7 @@ mod tests {
         let rule = Rule::WithMinLength {
             threshold: Some(5.0), // Rule-level threshold
             min_length: 3,
+            count_bytes: None,
         };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 10.0);
         assert!(result.is_ok());
@@ -1090,6 +3089,7 @@ mod tests {
         let rule = Rule::WithMinLength {
             threshold: None, // No rule-level threshold
             min_length: 3,
+            count_bytes: None,
         };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 15.0);
         assert!(result.is_ok());
@@ -1150,6 +3150,7 @@ mod tests {
         let mut builder = StringColumnBuilder::new("test_col".to_string());
         let rule = Rule::IsUnique {
             threshold: Some(3.5),
+            normalize: None,
         };
         let result = apply_string_rule(&mut builder, rule, "test_col".to_string(), 10.0);
         assert!(result.is_ok());
@@ -1157,10 +3158,716 @@ mod tests {
         let rules = builder.rules();
         assert_eq!(rules.len(), 1);
         match &rules[0] {
-            dataguard_core::ColumnRule::Unicity { threshold } => {
+            dataguard_core::ColumnRule::Unicity { threshold, .. } => {
                 assert_eq!(*threshold, 3.5);
             }
             _ => panic!("Expected Unicity rule"),
         }
     }
+
+    #[test]
+    fn test_apply_float_rule_between_exclusive() {
+        let mut builder = NumericColumnBuilder::<f64>::new("probability".to_string());
+        let rule = Rule::BetweenExclusive {
+            threshold: None,
+            min: Value::Float(0.0),
+            max: Value::Float(1.0),
+        };
+        apply_float_rule(&mut builder, rule, "probability".to_string(), 0.0).unwrap();
+        match &builder.rules()[0] {
+            dataguard_core::ColumnRule::NumericRange {
+                min,
+                max,
+                min_inclusive,
+                max_inclusive,
+                ..
+            } => {
+                assert_eq!((*min, *max), (Some(0.0), Some(1.0)));
+                assert!(!min_inclusive && !max_inclusive);
+            }
+            _ => panic!("Expected NumericRange rule"),
+        }
+    }
+
+    #[test]
+    fn test_apply_sanity_rules() {
+        let mut builder = BooleanColumnBuilder::new("active".to_string());
+        apply_boolean_rule(&mut builder, Rule::NotAllNull, "active".to_string(), 0.0).unwrap();
+        apply_boolean_rule(&mut builder, Rule::NotAllSame, "active".to_string(), 0.0).unwrap();
+        assert!(matches!(
+            builder.rules(),
+            [
+                dataguard_core::ColumnRule::NotAllNull,
+                dataguard_core::ColumnRule::NotAllSame
+            ]
+        ));
+    }
+
+    // ==================== TABLE FORMAT TESTS ====================
+
+    fn construct_single(table: &ConfigTable) -> Result<Box<dyn Table>> {
+        construct_csv_table(table, &table.path, &table.name, &ReadOptions::default())
+    }
+
+    fn table_config(path: &str, format: Option<&str>) -> ConfigTable {
+        ConfigTable {
+            name: "events".to_string(),
+            path: path.to_string(),
+            format: format.map(str::to_string),
+            delimiter: None,
+            quote: None,
+            has_header: None,
+            null_values: None,
+            strict_schema: None,
+            detect_duplicate_rows: None,
+            min_rows: None,
+            max_rows: None,
+            reference_date: None,
+            type_checking_threshold: None,
+            rule_threshold: None,
+            relations: None,
+            group_assert: None,
+            group_count: None,
+            column: vec![],
+        }
+    }
+
+    #[test]
+    fn test_construct_table_format_override() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.dat");
+        std::fs::write(&path, "{\"user\": \"alice\"}\n").unwrap();
+        let path = path.to_str().unwrap();
+
+        assert!(construct_single(&table_config(path, None)).is_err());
+        let mut table = construct_single(&table_config(path, Some("json"))).unwrap();
+        assert!(table.validate().is_ok());
+    }
+
+    #[test]
+    fn test_table_format_precedence() {
+        let format = |path: &str, declared: Option<&str>| {
+            table_format(&table_config(path, declared), path).unwrap()
+        };
+        assert_eq!(format("events.jsonl", None), Some(FileFormat::Json));
+        assert_eq!(format("events.dat", None), None);
+        assert_eq!(format("events.dat", Some("csv")), Some(FileFormat::Csv));
+        // The declared format wins over a different extension
+        assert_eq!(format("events.parquet", Some("csv")), Some(FileFormat::Csv));
+    }
+
+    #[test]
+    fn test_construct_table_row_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+        let lines: String = (0..20).map(|i| format!("{{\"id\": {}}}\n", i)).collect();
+        std::fs::write(&path, lines).unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = vec![Column {
+            name: "id".to_string(),
+            name_pattern: None,
+            datatype: "integer".to_string(),
+            format: None,
+            true_values: None,
+            false_values: None,
+            type_checking_threshold: None,
+            rule_threshold: None,
+            optional: false,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
+            rule: vec![Rule::IsNotNull { threshold: None }.into()],
+        }];
+        let options = ReadOptions {
+            row_limit: Some(5),
+            ..Default::default()
+        };
+        let mut table = construct_csv_table(&config, &config.path, &config.name, &options).unwrap();
+        let result = table.validate().unwrap();
+        assert_eq!(result.total_rows, 5);
+        assert!(result.sampled);
+    }
+
+    #[test]
+    fn test_construct_table_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.csv");
+        std::fs::write(&path, "id,kind\n1,click\n2,\n4,view\n").unwrap();
+
+        // A column without rules is still profiled
+        let column: Column = toml::from_str("name = \"id\"\ndatatype = \"integer\"").unwrap();
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = vec![column];
+        let options = ReadOptions {
+            profile: true,
+            ..Default::default()
+        };
+        let mut table = construct_csv_table(&config, &config.path, &config.name, &options).unwrap();
+        let result = table.validate().unwrap();
+        let profiles = result.get_profiles();
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].name, "id");
+        assert_eq!(profiles[0].distinct, 3);
+        assert_eq!(profiles[0].mean, Some(7.0 / 3.0));
+    }
+
+    #[test]
+    fn test_construct_table_value_counts() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.csv");
+        std::fs::write(&path, "id,kind\n1,click\n2,\n3,view\n4,click\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = ["id", "kind"]
+            .iter()
+            .map(|name| toml::from_str(&format!("name = \"{name}\"\ndatatype = \"string\"")))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let options = ReadOptions {
+            value_counts: vec!["kind".to_string()],
+            ..Default::default()
+        };
+        let mut table = construct_csv_table(&config, &config.path, &config.name, &options).unwrap();
+        let result = table.validate().unwrap();
+        let counts = result.get_value_counts();
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[0].name, "kind");
+        assert_eq!(
+            counts[0].values,
+            vec![("click".to_string(), 2), ("view".to_string(), 1)]
+        );
+        assert_eq!(counts[0].null_count, 1);
+    }
+
+    #[test]
+    fn test_construct_table_duplicate_rows() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.csv");
+        std::fs::write(
+            &path,
+            "id,kind\n1,click\n2,view\n1,click\n1,view\n1,click\n",
+        )
+        .unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = ["id", "kind"]
+            .iter()
+            .map(|name| toml::from_str(&format!("name = \"{name}\"\ndatatype = \"string\"")))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        config.detect_duplicate_rows = Some(true);
+        let mut table = construct_single(&config).unwrap();
+        let result = table.validate().unwrap();
+        let duplicates = &result.get_table_results()[0];
+        assert_eq!(duplicates.rule_name, "DuplicateRows");
+        assert_eq!(duplicates.error_count, 2);
+        assert!(!duplicates.pass);
+    }
+
+    #[test]
+    fn test_construct_table_name_pattern() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metrics.csv");
+        std::fs::write(
+            &path,
+            "id,metric_a,metric_b,metric_c\n1,3,-2,-1\n2,4,5,-6\n",
+        )
+        .unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = toml::from_str::<ConfigTable>(
+            r#"
+            name = "metrics"
+            path = "metrics.csv"
+            column = [
+                { name_pattern = "^metric_", datatype = "integer", rule = [{ name = "is_non_negative" }] },
+                { name = "metric_c", datatype = "integer" },
+            ]
+            "#,
+        )
+        .unwrap()
+        .column;
+        let mut table =
+            construct_csv_table(&config, &config.path, &config.name, &ReadOptions::default())
+                .unwrap();
+        let rules = table.get_rules();
+        let mut names: Vec<&String> = rules.keys().collect();
+        names.sort();
+        // The column configured by name is not repeated from the pattern
+        assert_eq!(names, ["metric_a", "metric_b", "metric_c"]);
+        assert_eq!(rules["metric_c"].len(), 1);
+        let result = table.validate().unwrap();
+        let errors = |column: &str| {
+            result.get_column_results()[column]
+                .iter()
+                .map(|r| r.error_count)
+                .sum::<usize>()
+        };
+        assert_eq!((errors("metric_a"), errors("metric_b")), (0, 1));
+
+        config.column[0].name_pattern = Some("^score_".to_string());
+        let err = construct_csv_table(&config, &config.path, &config.name, &ReadOptions::default())
+            .err()
+            .unwrap();
+        assert!(err
+            .to_string()
+            .contains("Column pattern '^score_' matches no column"));
+    }
+
+    #[test]
+    fn test_construct_table_source_name() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("customers.csv");
+        std::fs::write(&path, "id,cust_id\n1,4\n2,4\n3,5\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = toml::from_str::<ConfigTable>(
+            r#"
+            name = "customers"
+            path = "customers.csv"
+            column = [
+                { name = "customer_id", source_name = "cust_id", datatype = "integer", rule = [{ name = "is_unique" }] },
+            ]
+            "#,
+        )
+        .unwrap()
+        .column;
+        let mut table =
+            construct_csv_table(&config, &config.path, &config.name, &ReadOptions::default())
+                .unwrap();
+        let result = table.validate().unwrap();
+        // Results are reported under the configured name
+        let unicity = result.get_column_results()["customer_id"]
+            .iter()
+            .find(|r| r.rule_name == "Unicity")
+            .unwrap()
+            .error_count;
+        assert_eq!(unicity, 1);
+        assert!(result.get_missing_columns().is_empty());
+
+        // Two columns can not read the same file column
+        let mut other = config.column[0].clone();
+        other.name = "client_id".to_string();
+        config.column.push(other);
+        let err = construct_csv_table(&config, &config.path, &config.name, &ReadOptions::default())
+            .err()
+            .unwrap();
+        assert!(format!("{:#}", err).contains("both read the file column 'cust_id'"));
+    }
+
+    #[test]
+    fn test_construct_table_orc() {
+        // The file is only opened on validation
+        assert!(construct_single(&table_config("warehouse/part-0.orc", None)).is_ok());
+        assert!(construct_single(&table_config("warehouse/part-0", Some("orc"))).is_ok());
+    }
+
+    #[test]
+    fn test_construct_table_avro() {
+        // The file is only opened on validation
+        assert!(construct_single(&table_config("events/2024-01-31.avro", None)).is_ok());
+        assert!(construct_single(&table_config("events.bin", Some("avro"))).is_ok());
+    }
+
+    #[test]
+    fn test_construct_table_arrow() {
+        assert!(construct_single(&table_config("staging/events.feather", None)).is_ok());
+        assert!(construct_single(&table_config("staging/events.arrow", None)).is_ok());
+        assert!(construct_single(&table_config("events.bin", Some("arrow"))).is_ok());
+    }
+
+    #[test]
+    fn test_construct_table_fixed_width() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("accounts.dat");
+        std::fs::write(&path, "A00001  1200.50\nA00002    -3.00\nA00003\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), Some("fixed_width"));
+        config.column = toml::from_str::<ConfigTable>(
+            r#"
+            name = "accounts"
+            path = "accounts.dat"
+            column = [
+                { name = "account", datatype = "string", offset = 0, width = 6 },
+                { name = "balance", datatype = "float", offset = 6, width = 9, rule = [{ name = "is_positive" }] },
+            ]
+            "#,
+        )
+        .unwrap()
+        .column;
+        let mut table = construct_single(&config).unwrap();
+        let result = table.validate().unwrap();
+        assert_eq!(result.total_rows, 3);
+        let balance = &result.get_column_results()["balance"];
+        let is_positive = balance
+            .iter()
+            .find(|r| r.rule_name == "IsPositive")
+            .unwrap();
+        assert_eq!(is_positive.error_count, 1);
+    }
+
+    #[test]
+    fn test_construct_table_read_mode() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.csv");
+        std::fs::write(&path, "id\n1\n2\n3\n").unwrap();
+        let config = table_config(path.to_str().unwrap(), None);
+
+        let validate = |options: ReadOptions| {
+            let mut table =
+                construct_csv_table(&config, &config.path, &config.name, &options).unwrap();
+            table.validate().unwrap()
+        };
+        let result = validate(ReadOptions::default());
+        assert_eq!(result.read_mode, Some(ReadMode::Parallel));
+        // A budget below the file size streams it
+        let result = validate(ReadOptions {
+            memory_budget: 4,
+            ..Default::default()
+        });
+        assert_eq!(result.read_mode, Some(ReadMode::Streaming));
+        assert_eq!(result.total_rows, 3);
+        let result = validate(ReadOptions {
+            read_mode: ReadMode::Sequential,
+            ..Default::default()
+        });
+        assert_eq!(result.read_mode, Some(ReadMode::Sequential));
+    }
+
+    #[test]
+    fn test_construct_table_row_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("export.csv");
+        std::fs::write(&path, "id\n1\n2\n3\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.min_rows = Some(10);
+        let mut table = construct_single(&config).unwrap();
+        let result = table.validate().unwrap();
+        assert_eq!(result.get_table_results()[0].rule_name, "MinRows");
+        assert!(!result.get_table_results()[0].pass);
+    }
+
+    #[test]
+    fn test_construct_table_optional_column() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("users.csv");
+        std::fs::write(&path, "id\n1\n2\n").unwrap();
+
+        let column = |name: &str, optional: bool| Column {
+            name: name.to_string(),
+            name_pattern: None,
+            datatype: "string".to_string(),
+            format: None,
+            true_values: None,
+            false_values: None,
+            type_checking_threshold: None,
+            rule_threshold: None,
+            optional,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
+            rule: vec![Rule::IsNotNull { threshold: None }.into()],
+        };
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = vec![column("id", false), column("nickname", true)];
+        let result = construct_single(&config).unwrap().validate().unwrap();
+        assert!(result.get_missing_columns().is_empty());
+        assert!(!result.get_column_results().contains_key("nickname"));
+
+        config.column[1].optional = false;
+        let result = construct_single(&config).unwrap().validate().unwrap();
+        assert_eq!(result.get_missing_columns(), &["nickname".to_string()]);
+    }
+
+    #[test]
+    fn test_construct_table_group_count() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sales.csv");
+        std::fs::write(&path, "region\nnorth\nnorth\nsouth\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.group_count = Some(vec![GroupCount {
+            group_by: "region".to_string(),
+            count: Some(2),
+            min: None,
+            max: None,
+            threshold: None,
+        }]);
+        let result = construct_single(&config).unwrap().validate().unwrap();
+        let groups = result.get_group_results();
+        assert_eq!(groups[0].result.error_count, 1);
+        assert_eq!(groups[0].mismatches[0].group, "south");
+    }
+
+    #[test]
+    fn test_construct_table_reference_date() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("orders.csv");
+        std::fs::write(&path, "day\n2024-06-14\n2024-06-20\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.reference_date = Some("2024-06-15".to_string());
+        config.column = vec![Column {
+            name: "day".to_string(),
+            name_pattern: None,
+            datatype: "date".to_string(),
+            format: Some("%Y-%m-%d".to_string()),
+            true_values: None,
+            false_values: None,
+            type_checking_threshold: None,
+            rule_threshold: None,
+            optional: false,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
+            rule: vec![Rule::IsNotFutur { threshold: None }.into()],
+        }];
+        let result = construct_single(&config).unwrap().validate().unwrap();
+        let rules = &result.get_column_results()["day"];
+        let not_futur = rules.iter().find(|r| r.rule_name == "IsNotFutur").unwrap();
+        assert_eq!(not_futur.error_count, 1);
+
+        config.reference_date = Some("15/06/2024".to_string());
+        let err = construct_single(&config).err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("Invalid reference_date '15/06/2024'"));
+    }
+
+    #[test]
+    fn test_construct_table_unknown_format() {
+        let err = construct_single(&table_config("events.csv", Some("xml")))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "File format 'xml' is not recognized, \
+             expected csv, parquet, json, avro, arrow, fixed_width, orc"
+        );
+    }
+
+    #[test]
+    fn test_construct_table_csv_reader_options() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("users.tsv");
+        std::fs::write(&path, "alice\t30\nbob\t\ncarol\tNA\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), Some("csv"));
+        config.delimiter = Some('\t');
+        config.has_header = Some(false);
+        config.null_values = Some(vec!["NA".to_string()]);
+        config.column = vec![Column {
+            name: "col_1".to_string(),
+            name_pattern: None,
+            datatype: "integer".to_string(),
+            format: None,
+            true_values: None,
+            false_values: None,
+            type_checking_threshold: None,
+            rule_threshold: None,
+            optional: false,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
+            rule: vec![Rule::IsNotNull { threshold: None }.into()],
+        }];
+        let mut table = construct_single(&config).unwrap();
+        let result = table.validate().unwrap();
+        assert_eq!(result.total_rows, 3);
+        let columns = result.get_column_results();
+        let null_check = columns["col_1"]
+            .iter()
+            .find(|r| r.rule_name == "NullCheck")
+            .unwrap();
+        // `NA` is a null token, not a type check failure
+        assert_eq!(null_check.error_count, 2);
+        let type_check = columns["col_1"]
+            .iter()
+            .find(|r| r.rule_name == "TypeCheck")
+            .unwrap();
+        assert_eq!(type_check.error_count, 0);
+    }
+
+    #[test]
+    fn test_construct_table_boolean_column() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("users.csv");
+        std::fs::write(&path, "consent\noui\nnon\noui\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = vec![Column {
+            name: "consent".to_string(),
+            name_pattern: None,
+            datatype: "boolean".to_string(),
+            format: None,
+            true_values: Some(vec!["oui".to_string()]),
+            false_values: Some(vec!["non".to_string()]),
+            type_checking_threshold: None,
+            rule_threshold: None,
+            optional: false,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
+            rule: vec![Rule::IsTrue { threshold: None }.into()],
+        }];
+        let mut table = construct_single(&config).unwrap();
+        let result = table.validate().unwrap();
+        let columns = result.get_column_results();
+        let error_count = |rule: &str| {
+            columns["consent"]
+                .iter()
+                .find(|r| r.rule_name == rule)
+                .unwrap()
+                .error_count
+        };
+        assert_eq!(error_count("TypeCheck"), 0);
+        assert_eq!(error_count("IsTrue"), 1);
+    }
+
+    #[test]
+    fn test_construct_table_rule_labels() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("customers.csv");
+        std::fs::write(&path, "name\nAl\nBob\nAl\n").unwrap();
+
+        let mut config = table_config(path.to_str().unwrap(), None);
+        config.column = toml::from_str::<ConfigTable>(&format!(
+            r#"
+            name = "customers"
+            path = "{}"
+            [[column]]
+            name = "name"
+            datatype = "string"
+            rule = [
+                {{ name = "with_min_length", min_length = 3, label = "Customer name too short" }},
+                {{ name = "is_unique", message = "Duplicate customer" }},
+                {{ name = "is_not_null" }},
+            ]
+            "#,
+            path.display()
+        ))
+        .unwrap()
+        .column;
+        let mut table = construct_single(&config).unwrap();
+        let result = table.validate().unwrap();
+        let columns = result.get_column_results();
+        let label = |rule: &str| {
+            columns["name"]
+                .iter()
+                .find(|r| r.rule_name == rule)
+                .unwrap()
+                .display_name()
+                .to_string()
+        };
+        assert_eq!(label("WithMinLength"), "Customer name too short");
+        assert_eq!(label("Unicity"), "Duplicate customer");
+        assert_eq!(label("NullCheck"), "NullCheck");
+    }
+
+    // ==================== MULTI-FILE TABLE TESTS ====================
+
+    fn write_files(dir: &Path, files: &[(&str, &str)]) {
+        for (name, content) in files {
+            std::fs::write(dir.join(name), content).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_construct_tables_glob() {
+        let dir = tempfile::tempdir().unwrap();
+        write_files(
+            dir.path(),
+            &[
+                ("2024-02.csv", "id,name\n1,a\n,b\n"),
+                ("2024-01.csv", "id\n1\n2\n"),
+                ("2023-12.csv", "id\n1\n"),
+            ],
+        );
+        let pattern = dir.path().join("2024-*.csv");
+        let mut config = table_config(pattern.to_str().unwrap(), None);
+        config.column = vec![Column {
+            name: "id".to_string(),
+            name_pattern: None,
+            datatype: "integer".to_string(),
+            format: None,
+            true_values: None,
+            false_values: None,
+            type_checking_threshold: None,
+            rule_threshold: None,
+            optional: false,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
+            rule: vec![Rule::IsNotNull { threshold: None }.into()],
+        }];
+
+        let tables = construct_tables(&config, &ReadOptions::default()).unwrap();
+        let names: Vec<&str> = tables.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["events/2024-01.csv", "events/2024-02.csv"]);
+
+        // Each file is validated on its own with the same rules
+        let (_, mut table) = tables.into_iter().nth(1).unwrap();
+        let result = table.validate().unwrap();
+        assert_eq!(result.table_name, "events/2024-02.csv");
+        let columns = result.get_column_results();
+        let null_check = columns["id"]
+            .iter()
+            .find(|r| r.rule_name == "NullCheck")
+            .unwrap();
+        assert_eq!(null_check.error_count, 1);
+    }
+
+    #[test]
+    fn test_construct_tables_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        write_files(
+            dir.path(),
+            &[
+                ("b.csv", "id\n1\n"),
+                ("a.csv", "id\n1\n"),
+                (".a.csv.crc", ""),
+            ],
+        );
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+
+        let config = table_config(dir.path().to_str().unwrap(), None);
+        let tables = expand_table(&config).unwrap();
+        let names: Vec<&str> = tables.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["events/a.csv", "events/b.csv"]);
+
+        // A single file keeps the table name
+        let file = dir.path().join("a.csv");
+        let tables = expand_table(&table_config(file.to_str().unwrap(), None)).unwrap();
+        assert_eq!(tables[0].0, "events");
+    }
+
+    #[test]
+    fn test_construct_tables_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        write_files(
+            dir.path(),
+            &[("a.csv", "id\n1\n"), ("b.json", "{\"id\": 1}\n")],
+        );
+
+        let err = expand_table(&table_config(dir.path().to_str().unwrap(), None))
+            .err()
+            .unwrap();
+        assert!(err
+            .to_string()
+            .contains("matches files of different formats"));
+
+        let pattern = dir.path().join("*.parquet");
+        let err = expand_table(&table_config(pattern.to_str().unwrap(), None))
+            .err()
+            .unwrap();
+        assert!(err.to_string().starts_with("No file matches path"));
+    }
 }
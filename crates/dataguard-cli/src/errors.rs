@@ -1,4 +1,4 @@
-use dataguard_core::RuleError;
+use dataguard_core::{readers::FileFormat, RuleError};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -21,15 +21,33 @@ pub enum CliError {
         datatype: String,
         column_name: String,
     },
-    #[error("File format '{format}' is not recognized")]
+    #[error(
+        "File format '{format}' is not recognized, expected {}",
+        format_names()
+    )]
     UnknownFormat { format: String },
     #[error("Path format '{path}' is not valid")]
     UnknownFilePath { path: String },
+    #[error("No file matches path '{path}'")]
+    NoMatchingFile { path: String },
+    #[error("Column pattern '{pattern}' matches no column of '{path}'")]
+    NoMatchingColumn { pattern: String, path: String },
+    #[error("Path '{path}' matches files of different formats: '{first}' and '{other}'")]
+    MixedFormats {
+        path: String,
+        first: String,
+        other: String,
+    },
     // Validation errors from core
     #[error("Validation error")]
     ValidationError(#[from] RuleError),
 }
 
+/// Names of the supported formats, as listed in format errors
+fn format_names() -> String {
+    FileFormat::ALL.map(|format| format.name()).join(", ")
+}
+
 #[derive(Error, Debug)]
 pub enum ConfigError {
     #[error("File not found: '{table_path}'")]
@@ -44,12 +62,56 @@ pub enum ConfigError {
         "Table relation error: column '{missing_column}' need to be provided in 'table.column'"
     )]
     RelationError { missing_column: String },
+    #[error("Cross-table relation error: table '{table_name}' is not defined in the config")]
+    CrossTableRelationError { table_name: String },
+    #[error("Cross-table relation error: table '{table_name}' matches several files, relations need a single file")]
+    MultiFileRelationError { table_name: String },
     #[error("Rule logic error: rule '{rule_name}' for '{column_name}' - {message}")]
     RuleError {
         rule_name: String,
         column_name: String,
         message: String,
     },
+    #[error("Invalid mostly for rule '{rule_name}': {message}")]
+    MostlyError { rule_name: String, message: String },
+    #[error("Invalid reader option '{option}' for table '{table_name}': {message}")]
+    ReaderOptionError {
+        table_name: String,
+        option: String,
+        message: String,
+    },
+    #[error("Invalid row count for table '{table_name}': min_rows ({min_rows}) is greater than max_rows ({max_rows})")]
+    RowCountError {
+        table_name: String,
+        min_rows: usize,
+        max_rows: usize,
+    },
+    #[error("Invalid reference_date '{date}' for table '{table_name}', expected YYYY-MM-DD")]
+    ReferenceDateError { table_name: String, date: String },
+    #[error("Table '{table_name}' reads the standard input: {message}")]
+    StdinError { table_name: String, message: String },
+    #[error("Column '{column_name}' given to {option} is not configured in any table")]
+    UnknownSelectedColumn { column_name: String, option: String },
+    #[error("Configuration file contains no table")]
+    NoTable,
+    #[error("Table '{table_name}' is defined in both '{first}' and '{second}'")]
+    DuplicateTable {
+        table_name: String,
+        first: String,
+        second: String,
+    },
+    #[error("Profile '{profile}' is defined in both '{first}' and '{second}'")]
+    DuplicateProfile {
+        profile: String,
+        first: String,
+        second: String,
+    },
+    #[error("Column '{column_name}' of table '{table_name}' uses profile '{profile}', which is not defined")]
+    UnknownProfile {
+        profile: String,
+        column_name: String,
+        table_name: String,
+    },
     #[error("Watch mode can only be use on one table. Found {n_table} in config")]
     TooMuchTable { n_table: usize },
 }
@@ -1,11 +1,27 @@
 mod constructor;
 mod errors;
 mod parser;
+mod progress;
 mod writer;
+use std::error::Error;
+
+use crate::errors::{CliError, ConfigError};
 use crate::runner::{run, watch_run};
-use clap::{Parser, ValueEnum};
+use clap::{ArgGroup, Parser, ValueEnum};
+use dataguard_core::ReadMode;
 mod runner;
 
+/// At least one table failed its validation
+const EXIT_VALIDATION_FAILED: i32 = 1;
+/// Any error not covered by the codes below
+const EXIT_INTERNAL_ERROR: i32 = 2;
+/// The configuration can not be parsed, or holds an invalid rule
+const EXIT_CONFIG_ERROR: i32 = 3;
+/// The configuration file or a table file does not exist
+const EXIT_FILE_NOT_FOUND: i32 = 4;
+/// A table file is in a format that can not be read
+const EXIT_UNKNOWN_FORMAT: i32 = 5;
+
 /// Output format for validation results
 #[derive(Debug, Clone, ValueEnum)]
 enum OutputFormat {
@@ -13,6 +29,40 @@ enum OutputFormat {
     Stdout,
     /// Output results in JSON format
     Json,
+    /// Output results as JUnit XML, for CI test reports
+    Junit,
+    /// Output results as a Markdown summary, printed when no path is given
+    Markdown,
+    /// Output results as a self-contained HTML dashboard
+    Html,
+    /// Output results as SARIF, for code-scanning dashboards
+    Sarif,
+    /// Print one line per table with its error count, and the overall total
+    Summary,
+}
+
+/// How table files are read
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Mode {
+    /// Stream files larger than the memory budget, load the others in parallel
+    Auto,
+    /// Load the whole file in memory, split between threads
+    Parallel,
+    /// Validate batches as they are read, memory stays bounded on large files
+    Streaming,
+    /// Load the whole file in memory from a single thread
+    Sequential,
+}
+
+impl From<Mode> for ReadMode {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Auto => ReadMode::Auto,
+            Mode::Parallel => ReadMode::Parallel,
+            Mode::Streaming => ReadMode::Streaming,
+            Mode::Sequential => ReadMode::Sequential,
+        }
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -25,21 +75,34 @@ enum OutputFormat {
                   based on configurable rules. It supports various data types and validation rules \
                   for numeric, string, and generic columns.\n\n\
                   Example usage:\n  \
-                  dataguard --config validation.toml --output stdout"
+                  dataguard --config validation.toml --output stdout",
+    after_help = "Exit codes:\n  \
+                  0  every table passed\n  \
+                  1  validation failed\n  \
+                  2  internal error\n  \
+                  3  configuration error\n  \
+                  4  file not found\n  \
+                  5  unknown or unsupported file format"
 )]
+#[command(group(ArgGroup::new("configuration").required(true).args(["config", "config_dir"])))]
 struct Args {
     /// Path to the TOML configuration file that defines validation rules
     #[arg(short, long, value_name = "FILE")]
-    config: String,
+    config: Option<String>,
+
+    /// Directory of TOML configuration files, merged into one configuration.
+    /// A table can only be defined in one of them
+    #[arg(long, value_name = "DIR")]
+    config_dir: Option<String>,
 
     /// Output format for validation results
     #[arg(short, long, value_enum, default_value = "stdout")]
     output: OutputFormat,
 
-    /// Path to directory or file for JSON output
-    /// - If directory (e.g., ".", "/results/"): generates validation_{timestamp}.json
+    /// Path to directory or file for JSON, JUnit, Markdown, HTML or SARIF output
+    /// - If directory (e.g., ".", "/results/"): generates validation_{timestamp}.json (.xml, .md, .html, .sarif)
     /// - If file path: uses the exact filename provided
-    /// - Defaults to current directory if not specified
+    /// - Defaults to current directory if not specified, Markdown is printed instead
     #[arg(short, long)]
     path: Option<String>,
 
@@ -54,6 +117,157 @@ struct Args {
     /// Enable running validation automatically on file changes
     #[arg(short, long)]
     watch: bool,
+
+    /// Succeed as long as the aggregate error rate across all tables stays at or under
+    /// this percentage of rows, instead of failing on any rule
+    #[arg(long, value_name = "PERCENT", value_parser = parse_percent)]
+    fail_under: Option<f64>,
+
+    /// Only validate the first N rows of each table, for a quick check of large files
+    #[arg(long, value_name = "N", value_parser = parse_sample)]
+    sample: Option<usize>,
+
+    /// How table files are read, the mode picked for each table is printed with its results
+    #[arg(long, value_enum, default_value = "auto")]
+    mode: Mode,
+
+    /// File size in MB above which `--mode auto` streams a file instead of loading it in memory
+    #[arg(long, value_name = "MB", default_value_t = 500)]
+    memory_budget: u64,
+
+    /// Read every table as this format whatever its file extension, e.g. `--force-format csv`
+    /// for `.dat` files holding CSV. Takes precedence over the `format` of the tables
+    #[arg(long, value_name = "FORMAT")]
+    force_format: Option<String>,
+
+    /// Stop validating a table at its first rule failing beyond its threshold, for a quick
+    /// pass or fail. Only that rule is reported, with the errors counted until then
+    #[arg(long)]
+    fail_fast: bool,
+
+    /// Stop reading a table once N errors were counted over all its rules, on hopelessly
+    /// broken files. The table fails and its counts only cover the rows validated until then
+    #[arg(long, value_name = "N", value_parser = parse_max_errors)]
+    max_errors: Option<usize>,
+
+    /// Keep validating the other tables when one can not be read or validated,
+    /// it is reported as failed with its error
+    #[arg(long)]
+    continue_on_error: bool,
+
+    /// Do not draw the progress bar on stderr while tables are validated
+    #[arg(long)]
+    no_progress: bool,
+
+    /// Number of threads reading and validating files, 0 uses every available core
+    #[arg(long, value_name = "N", default_value_t = 0)]
+    threads: usize,
+
+    /// Also report a summary of each configured column: nulls, distinct values,
+    /// min, max and mean of numbers, lengths of strings. Columns need no rules
+    #[arg(long)]
+    profile: bool,
+
+    /// Only validate these columns, e.g. `--only id,email`. Tables configuring none of
+    /// them are skipped, relations and group assertions using other columns are dropped
+    #[arg(long, value_name = "COLUMNS", value_delimiter = ',')]
+    only: Vec<String>,
+
+    /// Also report the most frequent values of these columns, e.g. `--value-counts status`,
+    /// with their counts and percentages of the rows
+    #[arg(long, value_name = "COLUMNS", value_delimiter = ',')]
+    value_counts: Vec<String>,
+
+    /// Append a one-line JSON summary per table to this file after each run: timestamp,
+    /// table, total rows, total errors and pass. Written whatever the `--output` format
+    #[arg(long, value_name = "FILE")]
+    history: Option<String>,
+
+    /// Save the error count of every rule to this file, to be accepted by `--baseline`
+    #[arg(long, value_name = "FILE")]
+    write_baseline: Option<String>,
+
+    /// Only fail on the rules whose error count exceeds the one saved in this file by
+    /// `--write-baseline`, known issues are accepted until they get worse
+    #[arg(long, value_name = "FILE", conflicts_with = "fail_under")]
+    baseline: Option<String>,
+
+    /// Check the configuration and the columns of every table without reading their rows:
+    /// rules are compiled and only file headers or footers are read. Every table is
+    /// checked and reported, as with `--continue-on-error`
+    #[arg(long, conflicts_with = "watch")]
+    dry_run: bool,
+}
+
+fn parse_percent(s: &str) -> Result<f64, String> {
+    let value: f64 = s.parse().map_err(|_| format!("'{}' is not a number", s))?;
+    if !(0.0..=100.0).contains(&value) {
+        return Err(format!("{} is not between 0 and 100", value));
+    }
+    Ok(value)
+}
+
+fn parse_sample(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("the sample must hold at least one row".to_string()),
+        Ok(rows) => Ok(rows),
+        Err(_) => Err(format!("'{}' is not a number of rows", s)),
+    }
+}
+
+fn parse_max_errors(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("the error limit must be at least 1".to_string()),
+        Ok(errors) => Ok(errors),
+        Err(_) => Err(format!("'{}' is not a number of errors", s)),
+    }
+}
+
+fn is_not_found(err: &(dyn Error + 'static)) -> bool {
+    matches!(
+        err.downcast_ref::<ConfigError>(),
+        Some(ConfigError::FileNotFound { .. })
+    ) || matches!(
+        err.downcast_ref::<CliError>(),
+        Some(CliError::NoMatchingFile { .. })
+    ) || err
+        .downcast_ref::<std::io::Error>()
+        .is_some_and(|e| e.kind() == std::io::ErrorKind::NotFound)
+}
+
+fn is_unknown_format(err: &(dyn Error + 'static)) -> bool {
+    matches!(
+        err.downcast_ref::<CliError>(),
+        Some(CliError::UnknownFormat { .. } | CliError::MixedFormats { .. })
+    )
+}
+
+fn is_config_error(err: &(dyn Error + 'static)) -> bool {
+    err.is::<ConfigError>() || err.is::<CliError>() || err.is::<toml::de::Error>()
+}
+
+/// Exit code of an error stopping the run, from the most specific cause found in its chain
+fn exit_code(err: &anyhow::Error) -> i32 {
+    let found = |class: fn(&(dyn Error + 'static)) -> bool| err.chain().any(class);
+    if found(is_not_found) {
+        EXIT_FILE_NOT_FOUND
+    } else if found(is_unknown_format) {
+        EXIT_UNKNOWN_FORMAT
+    } else if found(is_config_error) {
+        EXIT_CONFIG_ERROR
+    } else {
+        EXIT_INTERNAL_ERROR
+    }
+}
+
+fn exit_on_error(err: anyhow::Error) -> ! {
+    if std::env::var("RUST_BACKTRACE").is_ok() {
+        eprintln!("Error: {:?}", err);
+    } else {
+        eprintln!("Error: {:#}", err);
+        eprintln!("\nHint: Run with --debug flag for detailed stack traces");
+    }
+    std::process::exit(exit_code(&err));
 }
 
 fn main() {
@@ -64,33 +278,18 @@ fn main() {
         std::env::set_var("RUST_BACKTRACE", "1");
     }
     match args.watch {
-        true => match watch_run(args) {
-            Ok(_) => {}
-            Err(err) => {
-                if std::env::var("RUST_BACKTRACE").is_ok() {
-                    eprintln!("Error: {:?}", err);
-                } else {
-                    eprintln!("Error: {:#}", err);
-                    eprintln!("\nHint: Run with --debug flag for detailed stack traces");
-                }
-                std::process::exit(2);
+        true => {
+            if let Err(err) = watch_run(args) {
+                exit_on_error(err);
             }
-        },
+        }
         false => match run(args) {
             Ok(all_passed) => {
                 if !all_passed {
-                    std::process::exit(1)
-                }
-            }
-            Err(err) => {
-                if std::env::var("RUST_BACKTRACE").is_ok() {
-                    eprintln!("Error: {:?}", err);
-                } else {
-                    eprintln!("Error: {:#}", err);
-                    eprintln!("\nHint: Run with --debug flag for detailed stack traces");
+                    std::process::exit(EXIT_VALIDATION_FAILED)
                 }
-                std::process::exit(2);
             }
+            Err(err) => exit_on_error(err),
         },
     }
 }
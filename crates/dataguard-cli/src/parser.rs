@@ -1,34 +1,114 @@
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use chrono::NaiveDate;
+use regex::Regex;
 use serde::Deserialize;
 use toml::Value;
 
+use dataguard_core::readers::{is_object_store_url, FileFormat, STDIN_PATH};
+
 use crate::errors::ConfigError;
 
 #[derive(Debug, Deserialize)]
 pub struct Config {
+    // A file of `--config-dir` may only hold cross-table relations
+    #[serde(default)]
     pub table: Vec<ConfigTable>,
+    pub relation: Option<Vec<CrossTableRelation>>,
+    /// Named rule lists, referenced by columns through `profiles`
+    #[serde(default)]
+    pub profile: HashMap<String, Profile>,
+}
+
+/// `[profile.<name>]`: rules shared by every column referencing the profile
+#[derive(Debug, Deserialize)]
+pub struct Profile {
+    #[serde(default)]
+    pub rule: Vec<LabeledRule>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ConfigTable {
     pub name: String,
+    /// File, directory or glob pattern, `-` reads the standard input
     pub path: String,
+    /// File format, detected from the extension when omitted
+    pub format: Option<String>,
+    /// CSV field delimiter, defaults to ','
+    pub delimiter: Option<char>,
+    /// CSV quote character, defaults to '"'
+    pub quote: Option<char>,
+    /// Whether the CSV file starts with a header line, defaults to true
+    pub has_header: Option<bool>,
+    /// CSV tokens read as null on top of the empty field, e.g. `["NA", "NULL", "\\N"]`
+    pub null_values: Option<Vec<String>>,
+    /// Report file columns that are not configured, defaults to false
+    pub strict_schema: Option<bool>,
+    /// Count the rows duplicated across every configured column, defaults to false
+    pub detect_duplicate_rows: Option<bool>,
+    /// Fail the table when it holds fewer rows, catches truncated exports
+    pub min_rows: Option<usize>,
+    /// Fail the table when it holds more rows
+    pub max_rows: Option<usize>,
+    /// As-of date (`YYYY-MM-DD`) of relative date rules such as `is_not_futur`, defaults to today
+    pub reference_date: Option<String>,
     pub type_checking_threshold: Option<f64>,
     pub rule_threshold: Option<f64>,
     pub relations: Option<Vec<TableRelation>>,
+    pub group_assert: Option<Vec<GroupAssert>>,
+    /// Expected number of rows per group, e.g. one row per month for each region
+    pub group_count: Option<Vec<GroupCount>>,
     pub column: Vec<Column>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct Column {
+    #[serde(default)]
     pub name: String,
+    /// Regex set instead of `name`, the column is repeated for every file column matching it
+    pub name_pattern: Option<String>,
     pub datatype: String,
     pub format: Option<String>,
+    /// Tokens parsed as `true` for boolean columns
+    pub true_values: Option<Vec<String>>,
+    /// Tokens parsed as `false` for boolean columns
+    pub false_values: Option<Vec<String>>,
     pub type_checking_threshold: Option<f64>,
     pub rule_threshold: Option<f64>,
-    pub rule: Vec<Rule>,
+    /// Skip the column when the file does not have it, required columns fail the table
+    #[serde(default)]
+    pub optional: bool,
+    /// Profiles whose rules are added before the column's own rules
+    #[serde(default)]
+    pub profiles: Vec<String>,
+    /// File column read for this column, when the file names it differently. Rules and
+    /// reports keep using `name`
+    pub source_name: Option<String>,
+    /// Byte offset of the column in the lines of a fixed-width file
+    pub offset: Option<usize>,
+    /// Number of bytes of the column in the lines of a fixed-width file
+    pub width: Option<usize>,
+    /// Columns without rules are still type checked, and profiled with `--profile`
+    #[serde(default)]
+    pub rule: Vec<LabeledRule>,
+}
+
+/// A column rule, with the human readable name its results are reported under
+#[derive(Debug, Deserialize, Clone)]
+pub struct LabeledRule {
+    #[serde(flatten)]
+    pub rule: Rule,
+    /// e.g. "Customer name too short", reports show the rule name when unset
+    #[serde(alias = "message")]
+    pub label: Option<String>,
+}
+
+impl From<Rule> for LabeledRule {
+    fn from(rule: Rule) -> Self {
+        Self { rule, label: None }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -39,17 +119,92 @@ pub struct TableRelation {
     pub rule: Vec<Relation>,
 }
 
+/// Top-level `[[relation]]`: `child` values must exist in `parent`
+#[derive(Debug, Deserialize)]
+pub struct CrossTableRelation {
+    pub child: TableColumn,
+    pub parent: TableColumn,
+    pub threshold: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TableColumn {
+    pub table: String,
+    pub column: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GroupAssert {
+    pub group_by: String,
+    pub value: String,
+    pub lookup_file: String,
+    pub tolerance: Option<f64>,
+    pub threshold: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GroupCount {
+    pub group_by: String,
+    /// Exact number of rows, can not be combined with `min` or `max`
+    pub count: Option<usize>,
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+    pub threshold: Option<f64>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(tag = "name", rename_all = "snake_case")]
 pub enum Relation {
     DateComparaison {
         threshold: Option<f64>,
         operator: String,
+        /// Largest number of days allowed between both dates
+        max_gap_days: Option<u32>,
     },
     NumericComparaison {
         threshold: Option<f64>,
         operator: String,
     },
+    StringComparaison {
+        threshold: Option<f64>,
+        operator: String,
+    },
+    /// `column_one + sum(addends)` must equal `column_two` within `tolerance`
+    SumEquals {
+        threshold: Option<f64>,
+        #[serde(default)]
+        addends: Vec<String>,
+        tolerance: Option<f64>,
+    },
+    /// `then` must hold on `column_two` for rows where `column_one` equals `when_equals`
+    Conditional {
+        threshold: Option<f64>,
+        when_equals: Value,
+        then: ConditionalThen,
+    },
+    /// The Pearson correlation of the two columns must be at least `min_coefficient`
+    Correlation {
+        threshold: Option<f64>,
+        min_coefficient: f64,
+    },
+    /// Seconds from the `column_one` timestamp to the `column_two` one must be within
+    /// `min_secs` and `max_secs`
+    DurationBetween {
+        threshold: Option<f64>,
+        min_secs: i64,
+        max_secs: i64,
+    },
+    /// Every value of `column_one` must be found among the values of `column_two`
+    IsSubsetOf { threshold: Option<f64> },
+}
+
+/// Check applied to `column_two` of a conditional relation
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "name", rename_all = "snake_case")]
+pub enum ConditionalThen {
+    IsNotNull,
+    IsNull,
+    Between { min: f64, max: f64 },
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -58,10 +213,30 @@ pub enum Rule {
     // Generic rules
     IsUnique {
         threshold: Option<f64>,
+        /// String columns only: `lowercase`, `trim` or `trim_lowercase` values before
+        /// comparing them
+        normalize: Option<String>,
+    },
+    /// Unique among the rows sharing the same value of the `group_by` column
+    UniqueWithinGroup {
+        threshold: Option<f64>,
+        group_by: String,
     },
     IsNotNull {
         threshold: Option<f64>,
     },
+    NotAllNull,
+    NotAllSame,
+    /// Share of null values, reported with the result
+    NullRatioBetween {
+        min_ratio: f64,
+        max_ratio: f64,
+    },
+    /// Estimated number of distinct values, reported with the result
+    DistinctCountBetween {
+        min: usize,
+        max: usize,
+    },
 
     //String rules
     WithLengthBetween {
@@ -69,13 +244,22 @@ pub enum Rule {
         min_length: usize,
         max_length: usize,
     },
+    WithUtf16LengthBetween {
+        threshold: Option<f64>,
+        min_length: usize,
+        max_length: usize,
+    },
     WithMinLength {
         threshold: Option<f64>,
         min_length: usize,
+        /// Count UTF-8 bytes instead of characters
+        count_bytes: Option<bool>,
     },
     WithMaxLength {
         threshold: Option<f64>,
         max_length: usize,
+        /// Count UTF-8 bytes instead of characters
+        count_bytes: Option<bool>,
     },
     IsExactLength {
         threshold: Option<f64>,
@@ -83,13 +267,45 @@ pub enum Rule {
     },
     IsIn {
         threshold: Option<f64>,
-        members: Vec<String>,
+        members: Vec<Value>,
+    },
+    #[serde(alias = "is_not_in")]
+    NotIn {
+        threshold: Option<f64>,
+        members: Vec<Value>,
+    },
+    Equals {
+        threshold: Option<f64>,
+        value: Value,
+    },
+    StartsWith {
+        threshold: Option<f64>,
+        prefix: String,
+    },
+    EndsWith {
+        threshold: Option<f64>,
+        suffix: String,
+    },
+    Contains {
+        threshold: Option<f64>,
+        substring: String,
+        flag: Option<String>,
+    },
+    NotContains {
+        threshold: Option<f64>,
+        substring: String,
+        flag: Option<String>,
     },
     WithRegex {
         threshold: Option<f64>,
         pattern: String,
         flag: Option<String>,
     },
+    MatchesAnyOf {
+        threshold: Option<f64>,
+        patterns: Vec<String>,
+        flag: Option<String>,
+    },
     IsNumeric {
         threshold: Option<f64>,
     },
@@ -103,20 +319,85 @@ pub enum Rule {
     #[serde(rename = "is_uppercase")]
     IsUpperCase {
         threshold: Option<f64>,
+        /// Accept any uppercase letter, not only ASCII ones
+        unicode: Option<bool>,
     },
     #[serde(rename = "is_lowercase")]
     IsLowerCase {
         threshold: Option<f64>,
+        /// Accept any lowercase letter, not only ASCII ones
+        unicode: Option<bool>,
+    },
+    NoSurroundingWhitespace {
+        threshold: Option<f64>,
+    },
+    NoWhitespace {
+        threshold: Option<f64>,
+    },
+    ContainsOnly {
+        threshold: Option<f64>,
+        /// Characters values may use, e.g. "ACGT"
+        allowed: String,
+    },
+    ConsistentLength {
+        threshold: Option<f64>,
+        /// Accepted distance in characters from the dominant length, defaults to 0
+        tolerance: Option<usize>,
     },
     IsUrl {
         threshold: Option<f64>,
     },
     IsEmail {
         threshold: Option<f64>,
+        /// Also check the shape of the domain labels and dots of the local part
+        strict: Option<bool>,
     },
     IsUuid {
         threshold: Option<f64>,
     },
+    IsIp {
+        threshold: Option<f64>,
+    },
+    IsIpv4 {
+        threshold: Option<f64>,
+    },
+    IsIpv6 {
+        threshold: Option<f64>,
+    },
+    /// IP addresses within one of the CIDR blocks, e.g. `["10.0.0.0/8"]`
+    IpInCidr {
+        threshold: Option<f64>,
+        cidrs: Vec<String>,
+    },
+    IsJson {
+        threshold: Option<f64>,
+    },
+    IsJsonObject {
+        threshold: Option<f64>,
+    },
+    IsJsonArray {
+        threshold: Option<f64>,
+    },
+    IsCreditCard {
+        threshold: Option<f64>,
+    },
+    IsIban {
+        threshold: Option<f64>,
+    },
+    IsBase64 {
+        threshold: Option<f64>,
+        /// Require the `=` padding, defaults to true
+        strict_padding: Option<bool>,
+    },
+    IsHex {
+        threshold: Option<f64>,
+        /// Accept a leading `0x`, defaults to false
+        allow_prefix: Option<bool>,
+    },
+    IsPhone {
+        threshold: Option<f64>,
+        region: Option<String>,
+    },
 
     // Numeric Rule
     Between {
@@ -124,6 +405,11 @@ pub enum Rule {
         min: Value,
         max: Value,
     },
+    BetweenExclusive {
+        threshold: Option<f64>,
+        min: Value,
+        max: Value,
+    },
     Min {
         threshold: Option<f64>,
         min: Value,
@@ -144,6 +430,17 @@ pub enum Rule {
     IsNonNegative {
         threshold: Option<f64>,
     },
+    MultipleOf {
+        threshold: Option<f64>,
+        divisor: i64,
+    },
+    IsFinite {
+        threshold: Option<f64>,
+    },
+    MaxDecimalPlaces {
+        threshold: Option<f64>,
+        places: u32,
+    },
     IsIncreasing {
         threshold: Option<f64>,
     },
@@ -159,6 +456,15 @@ pub enum Rule {
         threshold: Option<f64>,
         max_variance_percent: f64,
     },
+    PercentileBelow {
+        threshold: Option<f64>,
+        quantile: f64,
+        bound: f64,
+    },
+    NoOutliersBeyond {
+        threshold: Option<f64>,
+        num_std: f64,
+    },
     // Date Rule
     IsAfter {
         threshold: Option<f64>,
@@ -184,6 +490,52 @@ pub enum Rule {
     IsWeekend {
         threshold: Option<f64>,
     },
+    IsMonthStart {
+        threshold: Option<f64>,
+    },
+    IsMonthEnd {
+        threshold: Option<f64>,
+    },
+    IsDayOfMonth {
+        threshold: Option<f64>,
+        day: u32,
+    },
+    AgeBetween {
+        threshold: Option<f64>,
+        min_years: u32,
+        max_years: u32,
+    },
+    IsBetween {
+        threshold: Option<f64>,
+        start: (usize, u32, u32),
+        end: (usize, u32, u32),
+    },
+    // Timestamp Rule
+    TimestampAfter {
+        threshold: Option<f64>,
+        timestamp: String,
+    },
+    TimestampBefore {
+        threshold: Option<f64>,
+        timestamp: String,
+    },
+    TimeBetween {
+        threshold: Option<f64>,
+        start_hour: u32,
+        end_hour: u32,
+    },
+    // Boolean Rule
+    IsTrue {
+        threshold: Option<f64>,
+    },
+    IsFalse {
+        threshold: Option<f64>,
+    },
+    TrueRatioBetween {
+        threshold: Option<f64>,
+        min: f64,
+        max: f64,
+    },
 }
 
 impl std::fmt::Display for Relation {
@@ -191,6 +543,12 @@ impl std::fmt::Display for Relation {
         match self {
             Relation::DateComparaison { .. } => write!(f, "date_comparaison"),
             Relation::NumericComparaison { .. } => write!(f, "numeric_comparaison"),
+            Relation::StringComparaison { .. } => write!(f, "string_comparaison"),
+            Relation::SumEquals { .. } => write!(f, "sum_equals"),
+            Relation::Conditional { .. } => write!(f, "conditional"),
+            Relation::Correlation { .. } => write!(f, "correlation"),
+            Relation::DurationBetween { .. } => write!(f, "duration_between"),
+            Relation::IsSubsetOf { .. } => write!(f, "is_subset_of"),
         }
     }
 }
@@ -200,27 +558,60 @@ impl std::fmt::Display for Rule {
         match self {
             Rule::IsNotNull { .. } => write!(f, "is_not_null"),
             Rule::IsUnique { .. } => write!(f, "is_unique"),
+            Rule::UniqueWithinGroup { .. } => write!(f, "unique_within_group"),
+            Rule::NotAllNull => write!(f, "not_all_null"),
+            Rule::NotAllSame => write!(f, "not_all_same"),
+            Rule::NullRatioBetween { .. } => write!(f, "null_ratio_between"),
+            Rule::DistinctCountBetween { .. } => write!(f, "distinct_count_between"),
             Rule::WithLengthBetween { .. } => write!(f, "with_length_between"),
+            Rule::WithUtf16LengthBetween { .. } => write!(f, "with_utf16_length_between"),
             Rule::WithMinLength { .. } => write!(f, "with_min_length"),
             Rule::WithMaxLength { .. } => write!(f, "with_max_length"),
             Rule::IsExactLength { .. } => write!(f, "is_exact_length"),
             Rule::IsIn { .. } => write!(f, "is_in"),
+            Rule::NotIn { .. } => write!(f, "not_in"),
+            Rule::Equals { .. } => write!(f, "equals"),
+            Rule::StartsWith { .. } => write!(f, "starts_with"),
+            Rule::EndsWith { .. } => write!(f, "ends_with"),
+            Rule::Contains { .. } => write!(f, "contains"),
+            Rule::NotContains { .. } => write!(f, "not_contains"),
             Rule::WithRegex { .. } => write!(f, "with_regex"),
+            Rule::MatchesAnyOf { .. } => write!(f, "matches_any_of"),
             Rule::IsNumeric { .. } => write!(f, "is_numeric"),
             Rule::IsAlpha { .. } => write!(f, "is_alpha"),
             Rule::IsAlphaNumeric { .. } => write!(f, "is_alphanumeric"),
             Rule::IsUpperCase { .. } => write!(f, "is_uppercase"),
             Rule::IsLowerCase { .. } => write!(f, "is_lowercase"),
+            Rule::NoSurroundingWhitespace { .. } => write!(f, "no_surrounding_whitespace"),
+            Rule::NoWhitespace { .. } => write!(f, "no_whitespace"),
+            Rule::ContainsOnly { .. } => write!(f, "contains_only"),
+            Rule::ConsistentLength { .. } => write!(f, "consistent_length"),
             Rule::IsUrl { .. } => write!(f, "is_url"),
             Rule::IsEmail { .. } => write!(f, "is_email"),
             Rule::IsUuid { .. } => write!(f, "is_uuid"),
+            Rule::IsIp { .. } => write!(f, "is_ip"),
+            Rule::IsIpv4 { .. } => write!(f, "is_ipv4"),
+            Rule::IsIpv6 { .. } => write!(f, "is_ipv6"),
+            Rule::IpInCidr { .. } => write!(f, "ip_in_cidr"),
+            Rule::IsJson { .. } => write!(f, "is_json"),
+            Rule::IsJsonObject { .. } => write!(f, "is_json_object"),
+            Rule::IsJsonArray { .. } => write!(f, "is_json_array"),
+            Rule::IsCreditCard { .. } => write!(f, "is_credit_card"),
+            Rule::IsIban { .. } => write!(f, "is_iban"),
+            Rule::IsBase64 { .. } => write!(f, "is_base64"),
+            Rule::IsHex { .. } => write!(f, "is_hex"),
+            Rule::IsPhone { .. } => write!(f, "is_phone"),
             Rule::Between { .. } => write!(f, "between"),
+            Rule::BetweenExclusive { .. } => write!(f, "between_exclusive"),
             Rule::Min { .. } => write!(f, "min"),
             Rule::Max { .. } => write!(f, "max"),
             Rule::IsPositive { .. } => write!(f, "is_positive"),
             Rule::IsNonPositive { .. } => write!(f, "is_non_positive"),
             Rule::IsNegative { .. } => write!(f, "is_negative"),
             Rule::IsNonNegative { .. } => write!(f, "is_non_negative"),
+            Rule::MultipleOf { .. } => write!(f, "multiple_of"),
+            Rule::IsFinite { .. } => write!(f, "is_finite"),
+            Rule::MaxDecimalPlaces { .. } => write!(f, "max_decimal_places"),
             Rule::IsIncreasing { .. } => write!(f, "is_increasing"),
             Rule::IsDecreasing { .. } => write!(f, "is_decreasing"),
             Rule::IsAfter { .. } => write!(f, "is_after"),
@@ -229,74 +620,508 @@ impl std::fmt::Display for Rule {
             Rule::IsNotPast { .. } => write!(f, "is_not_past"),
             Rule::IsWeekday { .. } => write!(f, "is_weekday"),
             Rule::IsWeekend { .. } => write!(f, "is_weekend"),
+            Rule::IsMonthStart { .. } => write!(f, "is_month_start"),
+            Rule::IsMonthEnd { .. } => write!(f, "is_month_end"),
+            Rule::IsDayOfMonth { .. } => write!(f, "is_day_of_month"),
+            Rule::AgeBetween { .. } => write!(f, "age_between"),
+            Rule::IsBetween { .. } => write!(f, "is_between"),
+            Rule::TimestampAfter { .. } => write!(f, "timestamp_after"),
+            Rule::TimestampBefore { .. } => write!(f, "timestamp_before"),
+            Rule::TimeBetween { .. } => write!(f, "time_between"),
+            Rule::IsTrue { .. } => write!(f, "is_true"),
+            Rule::IsFalse { .. } => write!(f, "is_false"),
+            Rule::TrueRatioBetween { .. } => write!(f, "true_ratio_between"),
             Rule::StdDev { .. } => write!(f, "std_dev"),
             Rule::MeanVariance { .. } => write!(f, "mean_variance"),
+            Rule::PercentileBelow { .. } => write!(f, "percentile_below"),
+            Rule::NoOutliersBeyond { .. } => write!(f, "no_outliers_beyond"),
         }
     }
 }
 
+impl Column {
+    /// Whether the file column `name` is configured by this column, by name or pattern
+    pub fn matches(&self, name: &str) -> bool {
+        match &self.name_pattern {
+            Some(pattern) => Regex::new(pattern).is_ok_and(|re| re.is_match(name)),
+            None => self.name == name,
+        }
+    }
+}
+
+impl ConfigTable {
+    /// Parse the table reference date, shared by all relative date rules of the table
+    pub fn reference_date(&self) -> Result<Option<NaiveDate>, ConfigError> {
+        self.reference_date
+            .as_deref()
+            .map(|date| {
+                NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                    ConfigError::ReferenceDateError {
+                        table_name: self.name.clone(),
+                        date: date.to_string(),
+                    }
+                })
+            })
+            .transpose()
+    }
+}
+
+/// Whether a table path is a glob pattern such as `data/2024-*.csv`
+pub fn is_glob_pattern(path: &str) -> bool {
+    path.contains(['*', '?', '['])
+}
+
+/// Whether a table path can match several files, a glob pattern or a directory
+pub fn is_multi_file(path: &str) -> bool {
+    is_glob_pattern(path) || Path::new(path).is_dir()
+}
+
 pub fn validate_config(config: &Config) -> Result<(), ConfigError> {
+    validate_cross_table(config)?;
     for table in &config.table {
-        if !Path::new(&table.path).exists() {
-            return Err(ConfigError::FileNotFound {
-                table_path: table.path.clone(),
+        validate_table(table)?;
+    }
+    Ok(())
+}
+
+/// Check the settings spanning several tables: a single table reading the standard input
+/// and the tables of the cross-table relations.
+pub fn validate_cross_table(config: &Config) -> Result<(), ConfigError> {
+    let mut stdin_tables = config.table.iter().filter(|t| t.path == STDIN_PATH);
+    if let (Some(_), Some(second)) = (stdin_tables.next(), stdin_tables.next()) {
+        return Err(ConfigError::StdinError {
+            table_name: second.name.clone(),
+            message: "only one table can read the standard input".to_string(),
+        });
+    }
+    if let Some(relations) = &config.relation {
+        for relation in relations {
+            for side in [&relation.child, &relation.parent] {
+                match config.table.iter().find(|t| t.name == side.table) {
+                    None => {
+                        return Err(ConfigError::CrossTableRelationError {
+                            table_name: side.table.clone(),
+                        })
+                    }
+                    Some(t) if is_multi_file(&t.path) => {
+                        return Err(ConfigError::MultiFileRelationError {
+                            table_name: side.table.clone(),
+                        })
+                    }
+                    Some(t) if t.path == STDIN_PATH => {
+                        return Err(ConfigError::StdinError {
+                            table_name: side.table.clone(),
+                            message: "the standard input can not be read again for relations"
+                                .to_string(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check the settings of a single table and of its columns.
+pub fn validate_table(table: &ConfigTable) -> Result<(), ConfigError> {
+    if table.path == STDIN_PATH {
+        validate_stdin_table(table)?;
+    // Glob patterns are expanded and objects downloaded when the table is built
+    } else if !is_glob_pattern(&table.path)
+        && !is_object_store_url(&table.path)
+        && !Path::new(&table.path).exists()
+    {
+        return Err(ConfigError::FileNotFound {
+            table_path: table.path.clone(),
+        });
+    }
+    for (option, value) in [("delimiter", table.delimiter), ("quote", table.quote)] {
+        if value.is_some_and(|c| !c.is_ascii()) {
+            return Err(ConfigError::ReaderOptionError {
+                table_name: table.name.clone(),
+                option: option.to_string(),
+                message: "expected a single ASCII character".to_string(),
             });
         }
-        let mut columns: Vec<&str> = Vec::new();
-        for column in &table.column {
-            columns.push(column.name.as_str());
-            validate_column(column)?
+    }
+    table.reference_date()?;
+    if let (Some(min_rows), Some(max_rows)) = (table.min_rows, table.max_rows) {
+        if min_rows > max_rows {
+            return Err(ConfigError::RowCountError {
+                table_name: table.name.clone(),
+                min_rows,
+                max_rows,
+            });
         }
-        if let Some(relations) = &table.relations {
-            for r in relations {
-                if !columns.contains(&r.column_one.as_str()) {
-                    return Err(ConfigError::RelationError {
-                        missing_column: r.column_one.clone(),
-                    });
-                }
-                if !columns.contains(&r.column_two.as_str()) {
-                    return Err(ConfigError::RelationError {
-                        missing_column: r.column_two.clone(),
-                    });
+    }
+    for column in &table.column {
+        validate_column(column)?
+    }
+    validate_fixed_width_table(table)?;
+    // Columns matched by a pattern are only known once the file is read
+    let is_configured = |name: &str| table.column.iter().any(|c| c.matches(name));
+    if let Some(relations) = &table.relations {
+        for r in relations {
+            if !is_configured(&r.column_one) {
+                return Err(ConfigError::RelationError {
+                    missing_column: r.column_one.clone(),
+                });
+            }
+            if !is_configured(&r.column_two) {
+                return Err(ConfigError::RelationError {
+                    missing_column: r.column_two.clone(),
+                });
+            }
+            for rule in &r.rule {
+                if let Relation::SumEquals { addends, .. } = rule {
+                    if let Some(missing) = addends.iter().find(|c| !is_configured(c)) {
+                        return Err(ConfigError::RelationError {
+                            missing_column: missing.clone(),
+                        });
+                    }
                 }
             }
         }
     }
+    if let Some(group_asserts) = &table.group_assert {
+        for group in group_asserts {
+            if !Path::new(&group.lookup_file).exists() {
+                return Err(ConfigError::FileNotFound {
+                    table_path: group.lookup_file.clone(),
+                });
+            }
+        }
+    }
+    for group in table.group_count.iter().flatten() {
+        let message = match (group.count, group.min, group.max) {
+            (Some(_), None, None) => continue,
+            (Some(_), _, _) => "count can not be combined with min or max",
+            (None, None, None) => "one of count, min or max is required",
+            (None, Some(min), Some(max)) if min > max => "min is greater than max",
+            _ => continue,
+        };
+        return Err(ConfigError::RuleError {
+            rule_name: "group_count".to_string(),
+            column_name: group.group_by.clone(),
+            message: message.to_string(),
+        });
+    }
     Ok(())
 }
 
-pub fn parse_config(path: String) -> Result<Config> {
-    let config_path = std::path::PathBuf::from(path);
-    let config_str = std::fs::read_to_string(config_path.clone())
-        .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
-    let config: Config = toml::from_str(config_str.as_str())
-        .with_context(|| format!("Failed to parse config file: {}", config_path.display()))?;
-    if config.table.is_empty() {
-        anyhow::bail!("Configuration file contains no table");
+/// Check that every column given to a command line `option`, such as `--only`, is
+/// configured in at least one table.
+pub fn validate_selected_columns(
+    config: &Config,
+    columns: &[String],
+    option: &str,
+) -> Result<(), ConfigError> {
+    for name in columns {
+        if !config
+            .table
+            .iter()
+            .any(|table| table.column.iter().any(|col| col.matches(name)))
+        {
+            return Err(ConfigError::UnknownSelectedColumn {
+                column_name: name.clone(),
+                option: option.to_string(),
+            });
+        }
     }
+    Ok(())
+}
+
+/// Fixed-width files have no header, every column is read from its position
+fn validate_fixed_width_table(table: &ConfigTable) -> Result<(), ConfigError> {
+    let format = match &table.format {
+        Some(name) => FileFormat::from_name(name),
+        None => FileFormat::from_path(&table.path),
+    };
+    if format != Some(FileFormat::FixedWidth) {
+        return Ok(());
+    }
+    for column in &table.column {
+        let message = match (column.offset, column.width) {
+            _ if column.name_pattern.is_some() => "name_pattern can not be used without header",
+            (None, _) | (_, None) => "offset and width are required in fixed-width tables",
+            (_, Some(0)) => "width must be greater than 0",
+            _ => continue,
+        };
+        return Err(ConfigError::ColumnError {
+            column_name: column.name_pattern.clone().unwrap_or(column.name.clone()),
+            type_name: column.datatype.clone(),
+            message: message.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// The standard input has no extension, its format must be declared
+fn validate_stdin_table(table: &ConfigTable) -> Result<(), ConfigError> {
+    let format = match &table.format {
+        Some(name) => FileFormat::from_name(name),
+        None => {
+            return Err(ConfigError::StdinError {
+                table_name: table.name.clone(),
+                message: "a format is required".to_string(),
+            })
+        }
+    };
+    if table.column.iter().any(|c| c.name_pattern.is_some()) {
+        return Err(ConfigError::StdinError {
+            table_name: table.name.clone(),
+            message: "name_pattern needs the file header before reading".to_string(),
+        });
+    }
+    if matches!(format, Some(FileFormat::Parquet | FileFormat::Orc)) {
+        return Err(ConfigError::StdinError {
+            table_name: table.name.clone(),
+            message: format!(
+                "{} files can not be streamed, only csv, json, avro, arrow and fixed_width are supported",
+                table.format.as_deref().unwrap_or_default()
+            ),
+        });
+    }
+    Ok(())
+}
+
+pub fn parse_config(path: String, force_format: Option<&str>) -> Result<Config> {
+    parse_configs(&[PathBuf::from(path)], force_format)
+}
+
+/// Parse every `*.toml` file of `dir`, in name order, into a single configuration.
+pub fn parse_config_dir(dir: &str, force_format: Option<&str>) -> Result<Config> {
+    parse_configs(&config_dir_paths(dir)?, force_format)
+}
+
+/// The `*.toml` files of `dir`, in name order.
+pub fn config_dir_paths(dir: &str) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read config directory: {}", dir))?
+    {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "toml") {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Parse the config files and merge their tables and cross-table relations.
+///
+/// A table or profile name can only be defined by one file, a profile can be used by the
+/// tables of any file. `force_format` replaces the format of every table.
+pub fn parse_configs(paths: &[PathBuf], force_format: Option<&str>) -> Result<Config> {
+    let config = merge_configs(paths, force_format)?;
     validate_config(&config)?;
     Ok(config)
 }
 
+/// Merge the config files like [`parse_configs`], without validating the tables.
+pub fn merge_configs(paths: &[PathBuf], force_format: Option<&str>) -> Result<Config> {
+    let mut config = Config {
+        table: Vec::new(),
+        relation: None,
+        profile: HashMap::new(),
+    };
+    let mut table_files: HashMap<String, &PathBuf> = HashMap::new();
+    let mut profile_files: HashMap<String, (Profile, &PathBuf)> = HashMap::new();
+    for config_path in paths {
+        let config_str = std::fs::read_to_string(config_path)
+            .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
+        let file_config = config_from_str(&config_str)
+            .with_context(|| format!("Failed to parse config file: {}", config_path.display()))?;
+        for table in &file_config.table {
+            match table_files.get(&table.name) {
+                Some(first) if *first != config_path => {
+                    return Err(ConfigError::DuplicateTable {
+                        table_name: table.name.clone(),
+                        first: first.display().to_string(),
+                        second: config_path.display().to_string(),
+                    }
+                    .into())
+                }
+                _ => {
+                    table_files.insert(table.name.clone(), config_path);
+                }
+            }
+        }
+        for (name, profile) in file_config.profile {
+            if let Some((_, first)) = profile_files.get(&name) {
+                return Err(ConfigError::DuplicateProfile {
+                    profile: name,
+                    first: first.display().to_string(),
+                    second: config_path.display().to_string(),
+                }
+                .into());
+            }
+            profile_files.insert(name, (profile, config_path));
+        }
+        config.table.extend(file_config.table);
+        if let Some(relations) = file_config.relation {
+            config.relation.get_or_insert_default().extend(relations);
+        }
+    }
+    if config.table.is_empty() {
+        return Err(ConfigError::NoTable.into());
+    }
+    if let Some(format) = force_format {
+        for table in &mut config.table {
+            table.format = Some(format.to_string());
+        }
+    }
+    config.profile = profile_files
+        .into_iter()
+        .map(|(name, (profile, _))| (name, profile))
+        .collect();
+    expand_profiles(&mut config)?;
+    Ok(config)
+}
+
+/// Deserialize a config file, turning the `mostly` of its rules into thresholds.
+fn config_from_str(config_str: &str) -> Result<Config> {
+    // Deserialized from the text first so errors point to their line
+    let config: Config = toml::from_str(config_str)?;
+    let mut document: toml::Table = config_str.parse()?;
+    if !resolve_mostly(&mut document)? {
+        return Ok(config);
+    }
+    Ok(document.try_into()?)
+}
+
+/// Arrays whose entries accept a `threshold`, and so a `mostly`
+const THRESHOLD_ENTRIES: [&str; 4] = ["rule", "relation", "group_assert", "group_count"];
+
+/// Replace every `mostly` of a config document by the matching `threshold`, returns
+/// whether any was found.
+fn resolve_mostly(document: &mut toml::Table) -> Result<bool, ConfigError> {
+    let mut resolved = false;
+    for (key, value) in document.iter_mut() {
+        match value {
+            Value::Table(table) => resolved |= resolve_mostly(table)?,
+            Value::Array(entries) => {
+                for table in entries.iter_mut().filter_map(Value::as_table_mut) {
+                    if THRESHOLD_ENTRIES.contains(&key.as_str()) {
+                        resolved |= mostly_to_threshold(key, table)?;
+                    }
+                    resolved |= resolve_mostly(table)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(resolved)
+}
+
+/// `mostly` is the fraction of values expected to pass, where `threshold` is the
+/// percentage of values allowed to fail: `mostly = 0.95` is `threshold = 5.0`.
+fn mostly_to_threshold(key: &str, entry: &mut toml::Table) -> Result<bool, ConfigError> {
+    let Some(mostly) = entry.remove("mostly") else {
+        return Ok(false);
+    };
+    let rule_name = entry
+        .get("name")
+        .and_then(Value::as_str)
+        .unwrap_or(key)
+        .to_string();
+    if entry.contains_key("threshold") {
+        return Err(ConfigError::MostlyError {
+            rule_name,
+            message: "mostly and threshold can not be set together".to_string(),
+        });
+    }
+    let mostly = match mostly {
+        Value::Float(f) => f,
+        Value::Integer(i) => i as f64,
+        _ => f64::NAN,
+    };
+    if !(0.0..=1.0).contains(&mostly) {
+        return Err(ConfigError::MostlyError {
+            rule_name,
+            message: "expected a number between 0 and 1".to_string(),
+        });
+    }
+    entry.insert(
+        "threshold".to_string(),
+        Value::Float(100.0 - mostly * 100.0),
+    );
+    Ok(true)
+}
+
+/// Add the rules of the profiles referenced by each column before its own rules.
+fn expand_profiles(config: &mut Config) -> Result<(), ConfigError> {
+    for table in &mut config.table {
+        for col in &mut table.column {
+            let mut rules = Vec::new();
+            for name in &col.profiles {
+                let profile =
+                    config
+                        .profile
+                        .get(name)
+                        .ok_or_else(|| ConfigError::UnknownProfile {
+                            profile: name.clone(),
+                            column_name: col.name_pattern.clone().unwrap_or(col.name.clone()),
+                            table_name: table.name.clone(),
+                        })?;
+                rules.extend(profile.rule.iter().cloned());
+            }
+            rules.append(&mut col.rule);
+            col.rule = rules;
+        }
+    }
+    Ok(())
+}
+
 fn validate_column(col: &Column) -> Result<(), ConfigError> {
-    // We validate that the format is added for date column.
-    if col.datatype == "date" && col.format.is_none() {
+    if let Some(pattern) = &col.name_pattern {
+        let message = match Regex::new(pattern) {
+            _ if !col.name.is_empty() => Some("name and name_pattern can not be combined".into()),
+            _ if col.source_name.is_some() => {
+                Some("source_name and name_pattern can not be combined".into())
+            }
+            Err(e) => Some(format!("invalid name_pattern: {}", e)),
+            Ok(_) => None,
+        };
+        if let Some(message) = message {
+            return Err(ConfigError::ColumnError {
+                column_name: pattern.clone(),
+                type_name: col.datatype.clone(),
+                message,
+            });
+        }
+    } else if col.name.is_empty() {
+        return Err(ConfigError::ColumnError {
+            column_name: col.name.clone(),
+            type_name: col.datatype.clone(),
+            message: "name or name_pattern is required".to_string(),
+        });
+    }
+    // We validate that the format is added for date and timestamp column.
+    if (col.datatype == "date" || col.datatype == "timestamp") && col.format.is_none() {
         return Err(ConfigError::ColumnError {
             column_name: col.name.clone(),
             type_name: col.datatype.clone(),
             message: "Date format is required but none was provided".to_string(),
         });
     }
-    for rule in &col.rule {
+    for LabeledRule { rule, .. } in &col.rule {
         match rule {
             Rule::WithLengthBetween {
                 min_length,
                 max_length,
                 ..
+            }
+            | Rule::WithUtf16LengthBetween {
+                min_length,
+                max_length,
+                ..
             } => {
                 if min_length > max_length {
                     return Err(ConfigError::RuleError {
-                        rule_name: "with_length_between".to_string(),
+                        rule_name: rule.to_string(),
                         column_name: col.name.clone(),
                         message: format!(
                             "min length ({}) must be less than max length ({})",
@@ -306,7 +1131,7 @@ fn validate_column(col: &Column) -> Result<(), ConfigError> {
                 }
                 if min_length == max_length {
                     return Err(ConfigError::RuleError {
-                        rule_name: "with_length_between".to_string(),
+                        rule_name: rule.to_string(),
                         column_name: col.name.clone(),
                         message: format!(
                         "min length ({}) is equal max length ({})\n Hint: use 'is_exact_length'",
@@ -369,6 +1194,15 @@ fn validate_column(col: &Column) -> Result<(), ConfigError> {
                 });
                 }
             },
+            Rule::IsUnique {
+                normalize: Some(_), ..
+            } if col.datatype != "string" => {
+                return Err(ConfigError::RuleError {
+                    rule_name: rule.to_string(),
+                    column_name: col.name.clone(),
+                    message: "normalize only applies to string columns".to_string(),
+                });
+            }
             _ => {}
         }
     }
@@ -384,13 +1218,49 @@ mod test {
         Column {
             type_checking_threshold: None,
             rule_threshold: None,
+            optional: false,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
             name: name.to_string(),
+            name_pattern: None,
             datatype: "string".to_string(),
             format: None,
-            rule: rules,
+            true_values: None,
+            false_values: None,
+            rule: rules.into_iter().map(LabeledRule::from).collect(),
         }
     }
 
+    #[test]
+    fn test_validate_column_name_pattern() {
+        let mut column = create_column("", vec![]);
+        column.name_pattern = Some("^metric_".to_string());
+        assert!(validate_column(&column).is_ok());
+        assert!(column.matches("metric_a"));
+        assert!(!column.matches("id"));
+
+        column.name_pattern = Some("metric_(".to_string());
+        match validate_column(&column) {
+            Err(ConfigError::ColumnError { message, .. }) => {
+                assert!(message.starts_with("invalid name_pattern"));
+            }
+            _ => panic!("Expected ColumnError"),
+        }
+
+        column.source_name = Some("metric_b".to_string());
+        column.name_pattern = Some("^metric_".to_string());
+        assert!(validate_column(&column).is_err());
+        column.source_name = None;
+
+        column.name = "metric_a".to_string();
+        assert!(validate_column(&column).is_err());
+        column.name_pattern = None;
+        column.name = String::new();
+        assert!(validate_column(&column).is_err());
+    }
+
     #[test]
     fn test_validate_column_min_max_length_valid() {
         let rule = Rule::WithLengthBetween {
@@ -734,13 +1604,34 @@ mod test {
 
     #[test]
     fn test_validate_column_no_constraints() {
-        let rule = Rule::IsUnique { threshold: None };
+        let rule = Rule::IsUnique {
+            threshold: None,
+            normalize: None,
+        };
         let column = create_column("test_col", vec![rule]);
 
         let result = validate_column(&column);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_column_unique_normalize() {
+        let rule = Rule::IsUnique {
+            threshold: None,
+            normalize: Some("lowercase".to_string()),
+        };
+        let mut column = create_column("email", vec![rule]);
+        assert!(validate_column(&column).is_ok());
+
+        column.datatype = "integer".to_string();
+        match validate_column(&column) {
+            Err(ConfigError::RuleError { message, .. }) => {
+                assert_eq!(message, "normalize only applies to string columns")
+            }
+            other => panic!("Expected RuleError, got {:?}", other),
+        }
+    }
+
     // ============================================================================
     // Threshold Parsing Tests
     // ============================================================================
@@ -775,10 +1666,18 @@ mod test {
     fn test_column_type_threshold_explicit() {
         let column = Column {
             name: "test".to_string(),
+            name_pattern: None,
             datatype: "string".to_string(),
             format: None,
+            true_values: None,
+            false_values: None,
             type_checking_threshold: Some(10.0),
             rule_threshold: None,
+            optional: false,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
             rule: vec![],
         };
 
@@ -789,10 +1688,18 @@ mod test {
     fn test_column_rule_threshold_explicit() {
         let column = Column {
             name: "test".to_string(),
+            name_pattern: None,
             datatype: "string".to_string(),
             format: None,
+            true_values: None,
+            false_values: None,
             type_checking_threshold: None,
             rule_threshold: Some(15.0),
+            optional: false,
+            profiles: Vec::new(),
+            source_name: None,
+            offset: None,
+            width: None,
             rule: vec![],
         };
 
@@ -804,9 +1711,21 @@ mod test {
         let table = ConfigTable {
             name: "test_table".to_string(),
             path: "test.csv".to_string(),
+            format: None,
+            delimiter: None,
+            quote: None,
+            has_header: None,
+            null_values: None,
+            strict_schema: None,
+            detect_duplicate_rows: None,
+            min_rows: None,
+            max_rows: None,
+            reference_date: None,
             type_checking_threshold: Some(5.0),
             rule_threshold: Some(10.0),
             relations: None,
+            group_assert: None,
+            group_count: None,
             column: vec![],
         };
 
@@ -819,12 +1738,14 @@ mod test {
         let rule = Rule::WithMinLength {
             threshold: Some(7.5),
             min_length: 5,
+            count_bytes: None,
         };
 
         match rule {
             Rule::WithMinLength {
                 threshold,
                 min_length,
+                ..
             } => {
                 assert_eq!(threshold, Some(7.5));
                 assert_eq!(min_length, 5);
@@ -868,4 +1789,619 @@ mod test {
             _ => panic!("Expected IsAfter rule"),
         }
     }
+
+    #[test]
+    fn test_mostly_converted_to_threshold() {
+        let config = config_from_str(
+            r#"
+            [[table]]
+            name = "orders"
+            path = "orders.csv"
+            group_count = [{ group_by = "region", count = 12, mostly = 0.9 }]
+            [[table.column]]
+            name = "id"
+            datatype = "integer"
+            rule = [{ name = "is_unique", mostly = 0.95 }, { name = "min", min = 0, mostly = 1 }]
+            [[table.column]]
+            name = "status"
+            datatype = "string"
+            profiles = ["code"]
+            [profile.code]
+            rule = [{ name = "is_uppercase", mostly = 0.99 }]
+            [[relation]]
+            child = { table = "orders", column = "id" }
+            parent = { table = "orders", column = "id" }
+            mostly = 0.5
+            "#,
+        )
+        .unwrap();
+        let table = &config.table[0];
+        assert!(matches!(
+            table.column[0].rule[0].rule,
+            Rule::IsUnique { threshold: Some(t), .. } if t == 5.0
+        ));
+        assert!(matches!(
+            table.column[0].rule[1].rule,
+            Rule::Min { threshold: Some(t), .. } if t == 0.0
+        ));
+        assert!(matches!(
+            config.profile["code"].rule[0].rule,
+            Rule::IsUpperCase { threshold: Some(t), .. } if t == 1.0
+        ));
+        assert_eq!(table.group_count.as_ref().unwrap()[0].threshold, Some(10.0));
+        assert_eq!(config.relation.as_ref().unwrap()[0].threshold, Some(50.0));
+    }
+
+    #[test]
+    fn test_mostly_errors() {
+        let config = |rule: &str| {
+            format!(
+                "[[table]]\nname = \"orders\"\npath = \"orders.csv\"\n[[table.column]]\n\
+                 name = \"id\"\ndatatype = \"integer\"\nrule = [{}]\n",
+                rule
+            )
+        };
+        for (rule, message) in [
+            (
+                r#"{ name = "is_unique", mostly = 0.9, threshold = 5.0 }"#,
+                "Invalid mostly for rule 'is_unique': mostly and threshold can not be set together",
+            ),
+            (
+                r#"{ name = "min", min = 0, mostly = 95 }"#,
+                "Invalid mostly for rule 'min': expected a number between 0 and 1",
+            ),
+            (
+                r#"{ name = "min", min = 0, mostly = "most" }"#,
+                "Invalid mostly for rule 'min': expected a number between 0 and 1",
+            ),
+        ] {
+            let err = config_from_str(&config(rule)).unwrap_err();
+            assert_eq!(err.to_string(), message);
+        }
+
+        // Other errors still point to their line
+        let err = config_from_str(&config(r#"{ name = "is_foo", mostly = 0.9 }"#)).unwrap_err();
+        assert!(err.to_string().contains("line 7"), "{}", err);
+    }
+
+    #[test]
+    fn test_date_between_rule_from_toml() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            rule: Vec<Rule>,
+        }
+        let wrapper: Wrapper = toml::from_str(
+            r#"rule = [{ name = "is_between", start = [2020, 1, 1], end = [2024, 12, 31] }]"#,
+        )
+        .unwrap();
+        match &wrapper.rule[0] {
+            Rule::IsBetween {
+                threshold,
+                start,
+                end,
+            } => {
+                assert_eq!(*threshold, None);
+                assert_eq!(*start, (2020, 1, 1));
+                assert_eq!(*end, (2024, 12, 31));
+            }
+            _ => panic!("Expected IsBetween rule"),
+        }
+    }
+
+    #[test]
+    fn test_timestamp_rules_from_toml() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            rule: Vec<Rule>,
+        }
+        let wrapper: Wrapper = toml::from_str(
+            r#"rule = [
+                { name = "time_between", start_hour = 9, end_hour = 17 },
+                { name = "timestamp_after", timestamp = "2024-01-01 08:30:00", threshold = 1.0 },
+            ]"#,
+        )
+        .unwrap();
+        match &wrapper.rule[0] {
+            Rule::TimeBetween {
+                threshold,
+                start_hour,
+                end_hour,
+            } => {
+                assert_eq!(*threshold, None);
+                assert_eq!(*start_hour, 9);
+                assert_eq!(*end_hour, 17);
+            }
+            _ => panic!("Expected TimeBetween rule"),
+        }
+        match &wrapper.rule[1] {
+            Rule::TimestampAfter {
+                threshold,
+                timestamp,
+            } => {
+                assert_eq!(*threshold, Some(1.0));
+                assert_eq!(timestamp, "2024-01-01 08:30:00");
+            }
+            _ => panic!("Expected TimestampAfter rule"),
+        }
+    }
+
+    fn cross_table_config(parent_table: &str) -> Config {
+        let config = format!(
+            r#"
+            [[table]]
+            name = "customers"
+            path = "Cargo.toml"
+            column = []
+
+            [[table]]
+            name = "orders"
+            path = "Cargo.toml"
+            column = []
+
+            [[relation]]
+            child = {{ table = "orders", column = "customer_id" }}
+            parent = {{ table = "{}", column = "id" }}
+            "#,
+            parent_table
+        );
+        toml::from_str(&config).unwrap()
+    }
+
+    #[test]
+    fn test_validate_config_cross_table_relation() {
+        let config = cross_table_config("customers");
+        let relations = config.relation.as_ref().unwrap();
+        assert_eq!(relations.len(), 1);
+        assert_eq!(relations[0].child.column, "customer_id");
+        assert_eq!(relations[0].threshold, None);
+        assert!(validate_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_validate_config_cross_table_relation_unknown_table() {
+        let config = cross_table_config("clients");
+        match validate_config(&config) {
+            Err(ConfigError::CrossTableRelationError { table_name }) => {
+                assert_eq!(table_name, "clients");
+            }
+            _ => panic!("Expected CrossTableRelationError"),
+        }
+    }
+
+    #[test]
+    fn test_validate_config_glob_path() {
+        let mut config = cross_table_config("customers");
+        // Patterns are not checked for existence, they are expanded when building the table
+        config.table[0].path = "data/customers-*.csv".to_string();
+        config.relation = None;
+        assert!(validate_config(&config).is_ok());
+
+        // Foreign keys need a single file on each side
+        let mut config = cross_table_config("customers");
+        config.table[0].path = "src".to_string();
+        match validate_config(&config) {
+            Err(ConfigError::MultiFileRelationError { table_name }) => {
+                assert_eq!(table_name, "customers");
+            }
+            _ => panic!("Expected MultiFileRelationError"),
+        }
+    }
+
+    #[test]
+    fn test_validate_config_object_store_path() {
+        // Objects are downloaded when building the table
+        let mut config = cross_table_config("customers");
+        config.table[0].path = "s3://bucket/customers.csv".to_string();
+        config.relation = None;
+        assert!(validate_config(&config).is_ok());
+
+        config.table[0].path = "gs://bucket/customers.csv".to_string();
+        assert!(validate_config(&config).is_ok());
+
+        config.table[0].path = "missing/customers.csv".to_string();
+        assert!(matches!(
+            validate_config(&config),
+            Err(ConfigError::FileNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_selected_columns() {
+        let mut config = cross_table_config("customers");
+        config.table[0].column = toml::from_str::<Config>(
+            r#"
+            [[table]]
+            name = "customers"
+            path = "Cargo.toml"
+            column = [{ name = "id", datatype = "integer" }]
+            "#,
+        )
+        .unwrap()
+        .table
+        .remove(0)
+        .column;
+        assert!(validate_selected_columns(&config, &["id".to_string()], "--only").is_ok());
+        match validate_selected_columns(&config, &["id".to_string(), "email".to_string()], "--only")
+        {
+            Err(ConfigError::UnknownSelectedColumn {
+                column_name,
+                option,
+            }) => {
+                assert_eq!(option, "--only");
+                assert_eq!(column_name, "email");
+            }
+            _ => panic!("Expected UnknownSelectedColumn"),
+        }
+    }
+
+    #[test]
+    fn test_validate_config_row_count() {
+        let mut config = cross_table_config("customers");
+        config.table[0].min_rows = Some(1_000);
+        config.table[0].max_rows = Some(2_000);
+        assert!(validate_config(&config).is_ok());
+
+        config.table[0].max_rows = Some(10);
+        match validate_config(&config) {
+            Err(ConfigError::RowCountError {
+                table_name,
+                min_rows,
+                max_rows,
+            }) => {
+                assert_eq!(table_name, "customers");
+                assert_eq!((min_rows, max_rows), (1_000, 10));
+            }
+            _ => panic!("Expected RowCountError"),
+        }
+    }
+
+    #[test]
+    fn test_validate_config_group_count() {
+        let mut config = cross_table_config("customers");
+        let group_count = |count, min, max| {
+            Some(vec![GroupCount {
+                group_by: "region".to_string(),
+                count,
+                min,
+                max,
+                threshold: None,
+            }])
+        };
+        for (count, min, max) in [(Some(12), None, None), (None, Some(1), None)] {
+            config.table[0].group_count = group_count(count, min, max);
+            assert!(validate_config(&config).is_ok());
+        }
+        for (count, min, max) in [
+            (None, None, None),
+            (Some(12), Some(1), None),
+            (None, Some(5), Some(1)),
+        ] {
+            config.table[0].group_count = group_count(count, min, max);
+            match validate_config(&config) {
+                Err(ConfigError::RuleError { rule_name, .. }) => {
+                    assert_eq!(rule_name, "group_count")
+                }
+                _ => panic!("Expected RuleError"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_config_reference_date() {
+        let mut config = cross_table_config("customers");
+        config.table[0].reference_date = Some("2024-06-15".to_string());
+        assert!(validate_config(&config).is_ok());
+
+        config.table[0].reference_date = Some("2024-02-30".to_string());
+        match validate_config(&config) {
+            Err(ConfigError::ReferenceDateError { table_name, date }) => {
+                assert_eq!(table_name, "customers");
+                assert_eq!(date, "2024-02-30");
+            }
+            _ => panic!("Expected ReferenceDateError"),
+        }
+    }
+
+    #[test]
+    fn test_validate_config_stdin() {
+        let mut config = cross_table_config("customers");
+        config.relation = None;
+        config.table[0].path = "-".to_string();
+        config.table[0].format = Some("csv".to_string());
+        assert!(validate_config(&config).is_ok());
+
+        let expect_stdin_error = |config: &Config, expected: &str| match validate_config(config) {
+            Err(ConfigError::StdinError { table_name, .. }) => assert_eq!(table_name, expected),
+            _ => panic!("Expected StdinError"),
+        };
+        config.table[0].format = Some("parquet".to_string());
+        expect_stdin_error(&config, "customers");
+        config.table[0].format = None;
+        expect_stdin_error(&config, "customers");
+
+        // The standard input can only be read once
+        config.table[0].format = Some("csv".to_string());
+        config.table[1].path = "-".to_string();
+        config.table[1].format = Some("json".to_string());
+        expect_stdin_error(&config, "orders");
+
+        let mut config = cross_table_config("customers");
+        config.table[0].path = "-".to_string();
+        config.table[0].format = Some("csv".to_string());
+        expect_stdin_error(&config, "customers");
+    }
+
+    fn sum_relation_config(addend: &str) -> Config {
+        let config = format!(
+            r#"
+            [[table]]
+            name = "orders"
+            path = "Cargo.toml"
+
+            [[table.column]]
+            name = "subtotal"
+            datatype = "float"
+            rule = []
+
+            [[table.column]]
+            name = "total"
+            datatype = "float"
+            rule = []
+
+            [[table.relations]]
+            column_one = "subtotal"
+            column_two = "total"
+            rule = [{{ name = "sum_equals", addends = ["{}"], tolerance = 0.01 }}]
+            "#,
+            addend
+        );
+        toml::from_str(&config).unwrap()
+    }
+
+    #[test]
+    fn test_validate_config_sum_relation_unknown_addend() {
+        let config = sum_relation_config("tax");
+        match validate_config(&config) {
+            Err(ConfigError::RelationError { missing_column }) => {
+                assert_eq!(missing_column, "tax");
+            }
+            _ => panic!("Expected RelationError"),
+        }
+        assert!(validate_config(&sum_relation_config("subtotal")).is_ok());
+    }
+
+    #[test]
+    fn test_unique_within_group_from_toml() {
+        let column: Column = toml::from_str(
+            r#"
+            name = "email"
+            datatype = "string"
+            rule = [{ name = "unique_within_group", group_by = "tenant_id" }]
+            "#,
+        )
+        .unwrap();
+        match &column.rule[0].rule {
+            Rule::UniqueWithinGroup {
+                threshold,
+                group_by,
+            } => {
+                assert_eq!(*threshold, None);
+                assert_eq!(group_by, "tenant_id");
+            }
+            _ => panic!("Expected UniqueWithinGroup rule"),
+        }
+        assert!(toml::from_str::<Column>(
+            r#"
+            name = "email"
+            datatype = "string"
+            rule = [{ name = "unique_within_group" }]
+            "#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_conditional_relation_from_toml() {
+        let relation: TableRelation = toml::from_str(
+            r#"
+            column_one = "status"
+            column_two = "shipped_date"
+            rule = [
+                { name = "conditional", when_equals = "shipped", then = { name = "is_not_null" } },
+                { name = "conditional", when_equals = 2, then = { name = "between", min = 0.0, max = 50.0 } },
+            ]
+            "#,
+        )
+        .unwrap();
+        match &relation.rule[0] {
+            Relation::Conditional {
+                threshold,
+                when_equals,
+                then,
+            } => {
+                assert_eq!(*threshold, None);
+                assert_eq!(when_equals.as_str(), Some("shipped"));
+                assert!(matches!(then, ConditionalThen::IsNotNull));
+            }
+            _ => panic!("Expected Conditional relation"),
+        }
+        match &relation.rule[1] {
+            Relation::Conditional {
+                when_equals, then, ..
+            } => {
+                assert_eq!(when_equals.as_integer(), Some(2));
+                assert!(matches!(
+                    then,
+                    ConditionalThen::Between {
+                        min: 0.0,
+                        max: 50.0
+                    }
+                ));
+            }
+            _ => panic!("Expected Conditional relation"),
+        }
+    }
+
+    #[test]
+    fn test_parse_config_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("data.csv");
+        std::fs::write(&data, "id\n1\n").unwrap();
+        let table = |name: &str| {
+            format!(
+                "[[table]]\nname = \"{}\"\npath = {:?}\n\n\
+                 [[table.column]]\nname = \"id\"\ndatatype = \"integer\"\n",
+                name, data
+            )
+        };
+        let configs = dir.path().join("configs");
+        std::fs::create_dir(&configs).unwrap();
+        std::fs::write(configs.join("orders.toml"), table("orders")).unwrap();
+        std::fs::write(configs.join("users.toml"), table("users")).unwrap();
+        // A file may only hold cross-table relations
+        std::fs::write(
+            configs.join("relations.toml"),
+            "[[relation]]\nchild = { table = \"orders\", column = \"id\" }\n\
+             parent = { table = \"users\", column = \"id\" }\n",
+        )
+        .unwrap();
+        std::fs::write(configs.join("notes.txt"), "not a config").unwrap();
+
+        let config = parse_config_dir(configs.to_str().unwrap(), None).unwrap();
+        let names: Vec<&str> = config.table.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["orders", "users"]);
+        assert_eq!(config.relation.unwrap().len(), 1);
+
+        std::fs::write(configs.join("more_users.toml"), table("users")).unwrap();
+        let err = parse_config_dir(configs.to_str().unwrap(), None).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ConfigError>(),
+            Some(ConfigError::DuplicateTable { table_name, .. }) if table_name == "users"
+        ));
+
+        // Profiles are shared between files, but only defined once
+        std::fs::remove_file(configs.join("more_users.toml")).unwrap();
+        let profile = "[profile.ids]\nrule = [{ name = \"is_unique\" }]\n";
+        std::fs::write(configs.join("profiles.toml"), profile).unwrap();
+        assert!(parse_config_dir(configs.to_str().unwrap(), None).is_ok());
+        std::fs::write(configs.join("profiles_copy.toml"), profile).unwrap();
+        let err = parse_config_dir(configs.to_str().unwrap(), None).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ConfigError>(),
+            Some(ConfigError::DuplicateProfile { profile, .. }) if profile == "ids"
+        ));
+    }
+
+    #[test]
+    fn test_parse_config_force_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("events.dat");
+        std::fs::write(&data, "id\n1\n").unwrap();
+        let config_path = dir.path().join("config.toml");
+        std::fs::write(
+            &config_path,
+            format!(
+                "[[table]]\nname = \"events\"\npath = {:?}\nformat = \"json\"\ncolumn = []\n\n\
+                 [[table]]\nname = \"users\"\npath = \"-\"\ncolumn = []\n",
+                data
+            ),
+        )
+        .unwrap();
+        let path = config_path.to_str().unwrap().to_string();
+
+        // The standard input has no extension to detect its format from
+        let err = parse_config(path.clone(), None).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ConfigError>(),
+            Some(ConfigError::StdinError { table_name, .. }) if table_name == "users"
+        ));
+
+        // The forced format replaces the one of every table
+        let config = parse_config(path, Some("csv")).unwrap();
+        for table in &config.table {
+            assert_eq!(table.format.as_deref(), Some("csv"));
+        }
+    }
+
+    #[test]
+    fn test_column_profiles() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("users.csv");
+        std::fs::write(&data, "email\na@b.c\n").unwrap();
+        let config_path = dir.path().join("config.toml");
+        let write_config = |profiles: &str| {
+            let config = format!(
+                "[profile.email]\n\
+                 rule = [{{ name = \"is_not_null\" }}, {{ name = \"is_email\" }}]\n\n\
+                 [profile.short]\n\
+                 rule = [{{ name = \"with_max_length\", max_length = 64 }}]\n\n\
+                 [[table]]\nname = \"users\"\npath = {:?}\n\n\
+                 [[table.column]]\nname = \"email\"\ndatatype = \"string\"\n\
+                 profiles = {}\n\
+                 rule = [{{ name = \"ends_with\", suffix = \".com\" }}]\n",
+                data, profiles
+            );
+            std::fs::write(&config_path, config).unwrap();
+        };
+
+        // Profile rules come first, in order, then the column's own rules
+        write_config("[\"email\", \"short\"]");
+        let config = parse_config(config_path.to_str().unwrap().to_string(), None).unwrap();
+        let rules = &config.table[0].column[0].rule;
+        assert_eq!(rules.len(), 4);
+        assert!(matches!(rules[0].rule, Rule::IsNotNull { .. }));
+        assert!(matches!(rules[1].rule, Rule::IsEmail { .. }));
+        assert!(matches!(
+            rules[2].rule,
+            Rule::WithMaxLength { max_length: 64, .. }
+        ));
+        assert!(matches!(rules[3].rule, Rule::EndsWith { .. }));
+
+        write_config("[\"emails\"]");
+        let err = parse_config(config_path.to_str().unwrap().to_string(), None).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ConfigError>(),
+            Some(ConfigError::UnknownProfile { profile, column_name, .. })
+                if profile == "emails" && column_name == "email"
+        ));
+    }
+
+    #[test]
+    fn test_fixed_width_positions() {
+        let dir = tempfile::tempdir().unwrap();
+        let data = dir.path().join("accounts.fwf");
+        std::fs::write(&data, "A00001  1200.50\n").unwrap();
+        let config_path = dir.path().join("config.toml");
+        let write_config = |balance: &str| {
+            let config = format!(
+                "[[table]]\nname = \"accounts\"\npath = {:?}\n\n\
+                 [[table.column]]\nname = \"account\"\ndatatype = \"string\"\n\
+                 offset = 0\nwidth = 6\n\n\
+                 [[table.column]]\nname = \"balance\"\ndatatype = \"float\"\n{}\n",
+                data, balance
+            );
+            std::fs::write(&config_path, config).unwrap();
+        };
+
+        write_config("offset = 6\nwidth = 9");
+        let config = parse_config(config_path.to_str().unwrap().to_string(), None).unwrap();
+        assert_eq!(config.table[0].column[1].offset, Some(6));
+        assert_eq!(config.table[0].column[1].width, Some(9));
+
+        for (balance, expected) in [
+            (
+                "offset = 6",
+                "offset and width are required in fixed-width tables",
+            ),
+            ("offset = 6\nwidth = 0", "width must be greater than 0"),
+        ] {
+            write_config(balance);
+            let err = parse_config(config_path.to_str().unwrap().to_string(), None).unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<ConfigError>(),
+                Some(ConfigError::ColumnError { column_name, message, .. })
+                    if column_name == "balance" && message == expected
+            ));
+        }
+    }
 }
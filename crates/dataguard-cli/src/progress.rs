@@ -0,0 +1,83 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use dataguard_core::Progress;
+use dataguard_reports::utils::numbers::format_numbers;
+use indicatif::{ProgressBar, ProgressStyle};
+
+const REFRESH: Duration = Duration::from_millis(100);
+
+/// Progress bar on stderr, polling the rows counted in a [`Progress`].
+///
+/// A spinner is shown while a table is read, a bar with an ETA once its row count is
+/// known. Streamed tables only show the rows validated so far and the throughput.
+/// Nothing is drawn when stderr is not a terminal.
+pub struct ProgressDisplay {
+    done: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+impl ProgressDisplay {
+    pub fn start(progress: Arc<Progress>) -> Self {
+        let done = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let done = done.clone();
+            thread::spawn(move || draw(&progress, &done))
+        };
+        Self { done, handle }
+    }
+
+    /// Stop polling and clear the bar.
+    pub fn finish(self) {
+        self.done.store(true, Ordering::Relaxed);
+        let _ = self.handle.join();
+    }
+}
+
+fn draw(progress: &Progress, done: &AtomicBool) {
+    let reading = ProgressStyle::with_template("{spinner} {prefix}: reading...").unwrap();
+    let streaming =
+        ProgressStyle::with_template("{spinner} {prefix}: {human_pos} rows ({msg})").unwrap();
+    let known = ProgressStyle::with_template(
+        "{spinner} {prefix}: [{bar:30}] {human_pos}/{human_len} rows (ETA {eta})",
+    )
+    .unwrap()
+    .progress_chars("=> ");
+
+    let bar = ProgressBar::new_spinner();
+    let mut current: Option<String> = None;
+    while !done.load(Ordering::Relaxed) {
+        let table = progress.table();
+        if table != current {
+            // Each table gets its own rate and ETA
+            bar.reset();
+            bar.set_prefix(table.clone().unwrap_or_default());
+            current = table;
+        }
+        if current.is_some() {
+            let rows = progress.rows();
+            bar.set_position(rows as u64);
+            match progress.total_rows() {
+                Some(total) => {
+                    bar.set_style(known.clone());
+                    bar.set_length(total as u64);
+                }
+                None if rows == 0 => bar.set_style(reading.clone()),
+                None => {
+                    bar.set_style(streaming.clone());
+                    let rate = format_numbers(bar.per_sec() as usize);
+                    bar.set_message(format!("{} rows/s", rate));
+                }
+            }
+        }
+        bar.tick();
+        thread::sleep(REFRESH);
+    }
+    bar.finish_and_clear();
+}
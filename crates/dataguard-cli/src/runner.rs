@@ -1,22 +1,76 @@
 use std::{
+    collections::HashMap,
     fs::{self},
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use anyhow::{Context, Result};
-use dataguard_core::Validator;
-use dataguard_reports::{JsonFormatter, Reporter, StdOutFormatter};
+use dataguard_core::{
+    readers::{is_object_store_url, FileFormat, STDIN_PATH},
+    ForeignKey, Progress, ValidationResult, Validator,
+};
+use dataguard_reports::{
+    history_lines, Baseline, HtmlFormatter, JUnitFormatter, JsonFormatter, MarkdownFormatter,
+    Reporter, SarifFormatter, StdOutFormatter, SummaryFormatter,
+};
 use notify::{
     event::{AccessKind, ModifyKind},
     EventKind, Watcher,
 };
 
 use crate::{
-    constructor::construct_csv_table, errors::ConfigError, parser::parse_config,
-    writer::resolve_file_path, Args, OutputFormat,
+    constructor::{construct_tables, download_table, expand_table, ReadOptions},
+    errors::{CliError, ConfigError},
+    parser::{
+        config_dir_paths, is_multi_file, merge_configs, parse_config, parse_config_dir,
+        validate_cross_table, validate_selected_columns, validate_table, Config,
+    },
+    progress::ProgressDisplay,
+    writer::{append_history, resolve_file_path},
+    Args, OutputFormat,
 };
 
+/// Build the thread pool reading and validating files, 0 threads uses every available core
+fn thread_pool(threads: usize) -> Result<rayon::ThreadPool> {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .with_context(|| format!("Failed to start {} threads", threads))
+}
+
+/// Parse the `--config` file, or every file of `--config-dir`
+///
+/// With `--dry-run` the tables are not validated here, their problems are reported with
+/// the schema checks instead of stopping at the first one.
+fn load_config(args: &Args) -> Result<Config> {
+    let format = args.force_format.as_deref();
+    match (&args.config, &args.config_dir) {
+        (_, Some(dir)) if args.dry_run => merge_configs(&config_dir_paths(dir)?, format),
+        (_, Some(dir)) => parse_config_dir(dir, format),
+        (Some(path), None) if args.dry_run => merge_configs(&[PathBuf::from(path)], format),
+        (Some(path), None) => parse_config(path.clone(), format),
+        (None, None) => anyhow::bail!("A configuration is required, use --config or --config-dir"),
+    }
+}
+
+/// Reject an unknown `--force-format` before reading any configuration
+fn check_force_format(args: &Args) -> Result<()> {
+    match &args.force_format {
+        Some(format) if FileFormat::from_name(format).is_none() => Err(CliError::UnknownFormat {
+            format: format.clone(),
+        }
+        .into()),
+        _ => Ok(()),
+    }
+}
+
 pub fn run(args: Args) -> Result<bool> {
+    check_force_format(&args)?;
+    thread_pool(args.threads)?.install(|| report(&args))
+}
+
+fn report(args: &Args) -> Result<bool> {
     let version = env!("CARGO_PKG_VERSION");
 
     // Process validation based on output format
@@ -24,25 +78,92 @@ pub fn run(args: Args) -> Result<bool> {
         OutputFormat::Stdout => {
             let mut formatter = StdOutFormatter::new(version.to_string(), args.brief);
             formatter.on_start();
-            execute_validation(&args, &mut formatter)
+            execute_validation(args, &mut formatter)
+        }
+        OutputFormat::Summary => {
+            let mut formatter = SummaryFormatter::new();
+            formatter.on_start();
+            execute_validation(args, &mut formatter)
         }
         OutputFormat::Json => {
             // JSON output format - placeholder for future implementation
             let mut formatter = JsonFormatter::new(version.to_string(), args.brief);
             formatter.on_start();
-            let res = execute_validation(&args, &mut formatter)?;
+            let res = execute_validation(args, &mut formatter)?;
             let output = formatter
                 .to_json()
                 .with_context(|| "Failed to serialize validation results to JSON")?;
-            let output_path = resolve_file_path(&args.path, formatter.get_timestamp_compact())?;
+            let output_path =
+                resolve_file_path(&args.path, formatter.get_timestamp_compact(), "json")?;
             fs::write(&output_path, output)
                 .with_context(|| format!("Failed to write JSON to: {}", output_path.display()))?;
             Ok(res)
         }
+        OutputFormat::Junit => {
+            let mut formatter = JUnitFormatter::new(version.to_string());
+            formatter.on_start();
+            let res = execute_validation(args, &mut formatter)?;
+            let output_path =
+                resolve_file_path(&args.path, formatter.get_timestamp_compact(), "xml")?;
+            fs::write(&output_path, formatter.to_xml()).with_context(|| {
+                format!("Failed to write JUnit XML to: {}", output_path.display())
+            })?;
+            Ok(res)
+        }
+        OutputFormat::Markdown => {
+            let mut formatter = MarkdownFormatter::new(version.to_string());
+            formatter.on_start();
+            let res = execute_validation(args, &mut formatter)?;
+            let output = formatter.to_markdown();
+            if args.path.is_none() {
+                print!("{}", output);
+                return Ok(res);
+            }
+            let output_path =
+                resolve_file_path(&args.path, formatter.get_timestamp_compact(), "md")?;
+            fs::write(&output_path, output).with_context(|| {
+                format!("Failed to write Markdown to: {}", output_path.display())
+            })?;
+            Ok(res)
+        }
+        OutputFormat::Html => {
+            let mut formatter = HtmlFormatter::new(version.to_string());
+            formatter.on_start();
+            let res = execute_validation(args, &mut formatter)?;
+            let output_path =
+                resolve_file_path(&args.path, formatter.get_timestamp_compact(), "html")?;
+            fs::write(&output_path, formatter.to_html())
+                .with_context(|| format!("Failed to write HTML to: {}", output_path.display()))?;
+            Ok(res)
+        }
+        OutputFormat::Sarif => {
+            // Results point to the table files, which are only known from the config
+            let config = load_config(args)?;
+            let mut paths = HashMap::new();
+            for t in &config.table {
+                paths.extend(expand_table(t)?);
+            }
+            let mut formatter = SarifFormatter::new(version.to_string(), paths);
+            formatter.on_start();
+            let res = execute_validation(args, &mut formatter)?;
+            let output = formatter
+                .to_sarif()
+                .with_context(|| "Failed to serialize validation results to SARIF")?;
+            let output_path =
+                resolve_file_path(&args.path, formatter.get_timestamp_compact(), "sarif")?;
+            fs::write(&output_path, output)
+                .with_context(|| format!("Failed to write SARIF to: {}", output_path.display()))?;
+            Ok(res)
+        }
     }
 }
 
 pub fn watch_run(args: Args) -> Result<bool> {
+    check_force_format(&args)?;
+    thread_pool(args.threads)?.install(|| watch_report(&args))
+}
+
+fn watch_report(args: &Args) -> Result<bool> {
     let version = env!("CARGO_PKG_VERSION");
 
     // Process validation based on output format
@@ -50,30 +171,154 @@ pub fn watch_run(args: Args) -> Result<bool> {
         OutputFormat::Stdout => {
             let mut reporter = StdOutFormatter::new(version.to_string(), args.brief);
             reporter.on_start();
-            run_watch_loop(&args, &mut reporter)?;
+            run_watch_loop(args, &mut reporter)?;
         }
-        OutputFormat::Json => {
-            anyhow::bail!("Watch mode (--watch) is not currently supported with JSON output format. Please use --output stdout for watch mode.");
+        OutputFormat::Json
+        | OutputFormat::Junit
+        | OutputFormat::Markdown
+        | OutputFormat::Html
+        | OutputFormat::Sarif
+        | OutputFormat::Summary => {
+            anyhow::bail!("Watch mode (--watch) is only supported with stdout output format. Please use --output stdout for watch mode.");
         }
     }
     Ok(true)
 }
 
 fn execute_validation<R: Reporter>(args: &Args, reporter: &mut R) -> Result<bool> {
-    let mut validator = Validator::new();
+    let mut validator = Validator::new().with_continue_on_error(args.continue_on_error);
     reporter.on_loading();
-    let config = parse_config(args.config.clone())?;
+    let config = load_config(args)?;
+    if args.dry_run {
+        validate_cross_table(&config)?;
+    }
+    validate_selected_columns(&config, &args.only, "--only")?;
+    validate_selected_columns(&config, &args.value_counts, "--value-counts")?;
+    // Read before validating, a missing baseline fails the run early
+    let baseline = match &args.baseline {
+        Some(path) => Some(load_baseline(Path::new(path))?),
+        None => None,
+    };
     let n_tables = config.table.len();
+    let options = ReadOptions {
+        row_limit: args.sample,
+        read_mode: args.mode.into(),
+        memory_budget: args.memory_budget.saturating_mul(1024 * 1024),
+        profile: args.profile,
+        value_counts: args.value_counts.clone(),
+        fail_fast: args.fail_fast,
+        max_errors: args.max_errors,
+    };
 
+    // Tables that could not be built, only kept with `--continue-on-error` or `--dry-run`
+    let mut skipped: Vec<ValidationResult> = Vec::new();
+    // Schema checks of `--dry-run`, run instead of the validation
+    let mut dry_runs: Vec<ValidationResult> = Vec::new();
+    let mut unselected: Vec<String> = Vec::new();
+    // Local copies of the `s3://` and `gs://` tables, removed once the run is over
+    let mut downloads = Vec::new();
     for (i, t) in config.table.iter().enumerate() {
         reporter.on_table_load(i + 1, n_tables, &t.name);
-        let csv_table = construct_csv_table(t)
-            .with_context(|| format!("Failed to parse table: '{}'", t.name))?;
-        validator.add_table(t.name.clone(), csv_table);
+        // With `--only`, tables configuring none of the selected columns are not validated
+        if !args.only.is_empty()
+            && !args
+                .only
+                .iter()
+                .any(|name| t.column.iter().any(|col| col.matches(name)))
+        {
+            unselected.push(t.name.clone());
+            continue;
+        }
+        if args.dry_run {
+            if let Err(e) = validate_table(t) {
+                skipped.push(ValidationResult::from_error(t.name.clone(), e.to_string()));
+                continue;
+            }
+        }
+        // A glob or directory path gives one table per matched file
+        let tables = if is_object_store_url(&t.path) {
+            download_table(t, &options).map(|(tables, file)| {
+                downloads.push(file);
+                tables
+            })
+        } else {
+            construct_tables(t, &options)
+        };
+        let tables = match tables.with_context(|| format!("Failed to parse table: '{}'", t.name)) {
+            Ok(tables) => tables,
+            Err(e) if args.continue_on_error || args.dry_run => {
+                skipped.push(ValidationResult::from_error(
+                    t.name.clone(),
+                    format!("{:#}", e),
+                ));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        for (name, mut table) in tables {
+            if !args.only.is_empty() {
+                table.select_columns(&args.only);
+            }
+            if args.dry_run {
+                let result = table
+                    .dry_run()
+                    .unwrap_or_else(|e| ValidationResult::from_error(name, e.to_string()));
+                dry_runs.push(result);
+                continue;
+            }
+            validator.add_table(name, table);
+        }
+    }
+    if args.dry_run {
+        dry_runs.extend(skipped);
+        return Ok(report_dry_run(dry_runs, reporter));
+    }
+    for relation in config.relation.iter().flatten() {
+        let is_skipped = |table: &String| {
+            skipped.iter().any(|r| &r.table_name == table) || unselected.contains(table)
+        };
+        let is_excluded = |column: &String| !args.only.is_empty() && !args.only.contains(column);
+        if is_skipped(&relation.child.table)
+            || is_skipped(&relation.parent.table)
+            || is_excluded(&relation.child.column)
+            || is_excluded(&relation.parent.column)
+        {
+            continue;
+        }
+        validator.add_foreign_key(ForeignKey::new(
+            relation.child.table.clone(),
+            relation.child.column.clone(),
+            relation.parent.table.clone(),
+            relation.parent.column.clone(),
+            relation.threshold.unwrap_or(0.0),
+        ));
     }
 
     reporter.on_validation_start();
-    let res = validator.validate_all()?;
+    let display = if args.no_progress {
+        None
+    } else {
+        let progress = Arc::new(Progress::new());
+        validator = validator.with_progress(progress.clone());
+        Some(ProgressDisplay::start(progress))
+    };
+    let res = validator.validate_all();
+    if let Some(display) = display {
+        display.finish();
+    }
+    let mut res = res?;
+    res.extend(skipped);
+    res.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+    if let Some(history) = &args.history {
+        let lines = history_lines(&res).with_context(|| "Failed to serialize the run history")?;
+        append_history(Path::new(history), &lines)?;
+    }
+    if let Some(path) = &args.write_baseline {
+        let json = Baseline::from_results(&res)
+            .to_json()
+            .with_context(|| "Failed to serialize the baseline")?;
+        fs::write(path, json).with_context(|| format!("Failed to write baseline to: {}", path))?;
+    }
 
     for r in &res {
         reporter.on_table_result(r);
@@ -88,20 +333,107 @@ fn execute_validation<R: Reporter>(args: &Args, reporter: &mut R) -> Result<bool
         .count();
     let failed = res.len() - passed;
     reporter.on_complete(passed, failed);
+    let errored = res.iter().filter(|r| r.error_message.is_some()).count();
+    if errored > 0 {
+        eprintln!("{} table(s) skipped due to errors", errored);
+    }
 
-    Ok(failed == 0)
+    if let Some(baseline) = baseline {
+        let regressions = baseline.regressions(&res);
+        for r in &regressions {
+            let target = match &r.column {
+                Some(column) => format!("{}.{}", r.table, column),
+                None => r.table.clone(),
+            };
+            eprintln!(
+                "Baseline exceeded: {} {}: {} errors, baseline {} (+{})",
+                target,
+                r.rule,
+                r.error_count,
+                r.baseline,
+                r.excess()
+            );
+        }
+        // Schema errors and tables that could not be validated are not covered by the baseline
+        return Ok(regressions.is_empty() && res.iter().all(is_complete));
+    }
+    match args.fail_under {
+        Some(budget) => {
+            let rate = aggregate_error_rate(&res);
+            eprintln!("Aggregate error rate: {:.2}% (budget {:.2}%)", rate, budget);
+            // Tables that could not be fully validated are not covered by the budget
+            Ok(res.iter().all(is_complete) && rate <= budget)
+        }
+        None => Ok(failed == 0),
+    }
+}
+
+/// Report the schema checks of `--dry-run`, passing when no table has a problem
+fn report_dry_run<R: Reporter>(mut res: Vec<ValidationResult>, reporter: &mut R) -> bool {
+    res.sort_by(|a, b| a.table_name.cmp(&b.table_name));
+    for r in &res {
+        reporter.on_table_result(r);
+    }
+    let failed = res
+        .iter()
+        .filter(|r| {
+            let (pass, total) = r.is_passed();
+            !is_complete(r) || pass < total
+        })
+        .count();
+    reporter.on_complete(res.len() - failed, failed);
+    eprintln!(
+        "Dry run: {} table(s) checked, {} with problems, no rows were read",
+        res.len(),
+        failed
+    );
+    failed == 0
+}
+
+/// Whether the table was validated in full against the configured schema
+fn is_complete(result: &ValidationResult) -> bool {
+    result.error_message.is_none()
+        && result.get_missing_columns().is_empty()
+        && result.get_unexpected_columns().is_empty()
+}
+
+/// Read the rule error counts saved by `--write-baseline`
+fn load_baseline(path: &Path) -> Result<Baseline> {
+    let json = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read baseline: {}", path.display()))?;
+    Baseline::from_json(&json)
+        .with_context(|| format!("Failed to parse baseline: {}", path.display()))
+}
+
+/// Errors of every rule over the rows of every table, as a percentage
+fn aggregate_error_rate(results: &[ValidationResult]) -> f64 {
+    let total_rows: usize = results.iter().map(|r| r.total_rows).sum();
+    if total_rows == 0 {
+        return 0.0;
+    }
+    let total_errors: usize = results.iter().map(|r| r.error_count()).sum();
+    total_errors as f64 / total_rows as f64 * 100.0
 }
 
 fn run_watch_loop<R: Reporter>(args: &Args, reporter: &mut R) -> Result<bool> {
     reporter.on_waiting();
 
-    let config = parse_config(args.config.clone())?;
+    let config = load_config(args)?;
     if config.table.len() > 1 {
         return Err(ConfigError::TooMuchTable {
             n_table: config.table.len(),
         })
         .context("Watch mode only supports single table validation")?;
     }
+    if is_multi_file(&config.table[0].path) {
+        anyhow::bail!("Watch mode does not support glob or directory table paths");
+    }
+    if config.table[0].path == STDIN_PATH {
+        anyhow::bail!("Watch mode does not support tables reading the standard input");
+    }
+    if is_object_store_url(&config.table[0].path) {
+        anyhow::bail!("Watch mode does not support s3:// or gs:// table paths");
+    }
     let path = PathBuf::from(config.table[0].path.as_str());
 
     let (tx, rx) = std::sync::mpsc::channel();
@@ -115,17 +447,15 @@ fn run_watch_loop<R: Reporter>(args: &Args, reporter: &mut R) -> Result<bool> {
     for res in rx {
         match res {
             Ok(event) => match event.kind {
-                EventKind::Access(AccessKind::Close(..)) => {
-                    if modified {
-                        execute_validation(args, reporter)?;
-                        reporter.on_waiting();
-                        modified = false;
-                    }
+                EventKind::Access(AccessKind::Close(..)) if modified => {
+                    execute_validation(args, reporter)?;
+                    reporter.on_waiting();
+                    modified = false;
                 }
-                EventKind::Modify(ModifyKind::Data(..)) => {
-                    if path.file_name().unwrap() == event.paths[0].file_name().unwrap() {
-                        modified = true;
-                    }
+                EventKind::Modify(ModifyKind::Data(..))
+                    if path.file_name().unwrap() == event.paths[0].file_name().unwrap() =>
+                {
+                    modified = true;
                 }
                 _ => {}
             },
@@ -135,3 +465,46 @@ fn run_watch_loop<R: Reporter>(args: &Args, reporter: &mut R) -> Result<bool> {
 
     Ok(true)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dataguard_core::RuleResult;
+
+    #[test]
+    fn test_aggregate_error_rate() {
+        let mut orders = ValidationResult::new("orders".to_string(), 300);
+        orders.add_column_result(
+            "id".to_string(),
+            vec![
+                RuleResult::new("TypeCheck".to_string(), 2, 0.67, 0.0, None, false),
+                RuleResult::new("IsUnique".to_string(), 1, 0.33, 0.0, None, false),
+            ],
+        );
+        orders.add_relation_result(
+            "subtotal | total".to_string(),
+            vec![RuleResult::new(
+                "SumEqualsSkipped".to_string(),
+                50,
+                16.67,
+                100.0,
+                None,
+                true,
+            )],
+        );
+        let customers = ValidationResult::new("customers".to_string(), 100);
+
+        let rate = aggregate_error_rate(&[orders, customers]);
+        assert!((rate - 0.75).abs() < 1e-9);
+        assert_eq!(aggregate_error_rate(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_thread_pool() {
+        let pool = thread_pool(2).unwrap();
+        assert_eq!(pool.install(rayon::current_num_threads), 2);
+        // 0 uses every available core
+        let pool = thread_pool(0).unwrap();
+        assert!(pool.current_num_threads() >= 1);
+    }
+}
@@ -1,14 +1,20 @@
 use std::{
-    fs,
+    fs::{self, OpenOptions},
+    io::Write,
     path::{Path, PathBuf},
+    sync::Mutex,
 };
 
 use anyhow::{Context, Result};
 
-pub fn resolve_file_path(path: &Option<String>, timestamp: &str) -> Result<PathBuf> {
+pub fn resolve_file_path(
+    path: &Option<String>,
+    timestamp: &str,
+    extension: &str,
+) -> Result<PathBuf> {
     let base_path = path.as_deref().unwrap_or(".");
     let path = Path::new(base_path);
-    let filename = format!("validation_{}.json", timestamp);
+    let filename = format!("validation_{}.{}", timestamp, extension);
 
     let output_path = if path.exists() {
         if path.is_dir() {
@@ -36,6 +42,28 @@ pub fn resolve_file_path(path: &Option<String>, timestamp: &str) -> Result<PathB
     Ok(output_path)
 }
 
+/// Serializes the appends to history logs, so lines written by concurrent runs do not interleave
+static HISTORY_LOCK: Mutex<()> = Mutex::new(());
+
+/// Append `lines` to the history log at `path`, creating the file and its directory if absent.
+/// The lines are written at once in append mode, other processes appending to the log do not
+/// split them either.
+pub fn append_history(path: &Path, lines: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+    }
+    let _guard = HISTORY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .with_context(|| format!("Failed to append history to: {}", path.display()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,7 +71,7 @@ mod tests {
     use tempfile::TempDir;
     #[test]
     fn test_resolve_file_path_no_argument_uses_current_dir() {
-        let result = resolve_file_path(&None, "20251214-153045").unwrap();
+        let result = resolve_file_path(&None, "20251214-153045", "json").unwrap();
         assert_eq!(
             result.file_name().unwrap(),
             "validation_20251214-153045.json"
@@ -55,7 +83,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
         let path_str = temp_dir.path().to_str().unwrap().to_string();
 
-        let result = resolve_file_path(&Some(path_str), "20251214-153045").unwrap();
+        let result = resolve_file_path(&Some(path_str), "20251214-153045", "json").unwrap();
 
         assert_eq!(
             result.file_name().unwrap(),
@@ -70,7 +98,7 @@ mod tests {
         fs::write(&file_path, "test").unwrap();
 
         let path_str = file_path.to_str().unwrap().to_string();
-        let result = resolve_file_path(&Some(path_str.clone()), "20251214-153045").unwrap();
+        let result = resolve_file_path(&Some(path_str.clone()), "20251214-153045", "json").unwrap();
 
         assert_eq!(result, file_path);
     }
@@ -80,7 +108,7 @@ mod tests {
         let new_dir = temp_dir.path().join("new_dir/");
         let path_str = new_dir.to_str().unwrap().to_string();
 
-        let result = resolve_file_path(&Some(path_str), "20251214-153045").unwrap();
+        let result = resolve_file_path(&Some(path_str), "20251214-153045", "json").unwrap();
 
         assert!(new_dir.exists());
         assert!(new_dir.is_dir());
@@ -95,18 +123,51 @@ mod tests {
         let file_path = temp_dir.path().join("subdir/report.json");
         let path_str = file_path.to_str().unwrap().to_string();
 
-        let result = resolve_file_path(&Some(path_str.clone()), "20251214-153045").unwrap();
+        let result = resolve_file_path(&Some(path_str.clone()), "20251214-153045", "json").unwrap();
 
         assert!(file_path.parent().unwrap().exists());
         assert_eq!(result, file_path);
     }
     #[test]
     fn test_resolve_file_path_dot_path() {
-        let result = resolve_file_path(&Some(".".to_string()), "20251214-153045").unwrap();
+        let result = resolve_file_path(&Some(".".to_string()), "20251214-153045", "json").unwrap();
 
         assert_eq!(
             result.file_name().unwrap(),
             "validation_20251214-153045.json"
         );
     }
+    #[test]
+    fn test_resolve_file_path_uses_extension() {
+        let result = resolve_file_path(&None, "20251214-153045", "xml").unwrap();
+        assert_eq!(
+            result.file_name().unwrap(),
+            "validation_20251214-153045.xml"
+        );
+    }
+    #[test]
+    fn test_append_history_creates_and_appends() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("logs/history.jsonl");
+
+        append_history(&path, "{\"table\":\"users\"}\n").unwrap();
+        // Concurrent runs each append their own lines whole
+        std::thread::scope(|s| {
+            for table in ["orders", "events"] {
+                let path = &path;
+                s.spawn(move || {
+                    append_history(path, &format!("{{\"table\":\"{}\"}}\n", table)).unwrap()
+                });
+            }
+        });
+
+        let content = fs::read_to_string(&path).unwrap();
+        let mut lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.remove(0), "{\"table\":\"users\"}");
+        lines.sort();
+        assert_eq!(
+            lines,
+            vec!["{\"table\":\"events\"}", "{\"table\":\"orders\"}"]
+        );
+    }
 }
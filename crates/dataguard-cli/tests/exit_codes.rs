@@ -0,0 +1,142 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use tempfile::tempdir;
+
+/// Run the CLI on `config` and return its exit code
+fn exit_code(config: &Path) -> i32 {
+    exit_code_with(config, &[])
+}
+
+/// Run the CLI on `config` with extra `args` and return its exit code
+fn exit_code_with(config: &Path, args: &[&Path]) -> i32 {
+    Command::new(env!("CARGO_BIN_EXE_dataguard-cli"))
+        .arg("--config")
+        .arg(config)
+        .arg("--no-progress")
+        .args(args)
+        .output()
+        .unwrap()
+        .status
+        .code()
+        .unwrap()
+}
+
+/// Write a config validating `path` with `rule` on its `id` column
+fn write_config(dir: &Path, path: &Path, rule: &str) -> std::path::PathBuf {
+    let config = dir.join("config.toml");
+    fs::write(
+        &config,
+        format!(
+            "[[table]]\nname = \"ids\"\npath = {:?}\n\n\
+             [[table.column]]\nname = \"id\"\ndatatype = \"integer\"\n\
+             rule = [{{ name = \"{}\" }}]\n",
+            path, rule
+        ),
+    )
+    .unwrap();
+    config
+}
+
+#[test]
+fn test_exit_code_validation() {
+    let dir = tempdir().unwrap();
+    let data = dir.path().join("ids.csv");
+    fs::write(&data, "id\n1\n2\n2\n").unwrap();
+
+    let config = write_config(dir.path(), &data, "is_positive");
+    assert_eq!(exit_code(&config), 0);
+    let config = write_config(dir.path(), &data, "is_unique");
+    assert_eq!(exit_code(&config), 1);
+}
+
+#[test]
+fn test_exit_code_config_error() {
+    let dir = tempdir().unwrap();
+    let config = dir.path().join("config.toml");
+    fs::write(&config, "[[table]\nname = ").unwrap();
+    assert_eq!(exit_code(&config), 3);
+
+    let data = dir.path().join("ids.csv");
+    fs::write(&data, "id\n1\n").unwrap();
+    let config = write_config(dir.path(), &data, "not_a_rule");
+    assert_eq!(exit_code(&config), 3);
+}
+
+#[test]
+fn test_exit_code_file_not_found() {
+    let dir = tempdir().unwrap();
+    assert_eq!(exit_code(&dir.path().join("missing.toml")), 4);
+
+    let config = write_config(dir.path(), &dir.path().join("missing.csv"), "is_unique");
+    assert_eq!(exit_code(&config), 4);
+}
+
+#[test]
+fn test_exit_code_unknown_format() {
+    let dir = tempdir().unwrap();
+    let data = dir.path().join("ids.xyz");
+    fs::write(&data, [0u8, 159, 146, 150]).unwrap();
+
+    let config = write_config(dir.path(), &data, "is_unique");
+    assert_eq!(exit_code(&config), 5);
+}
+
+#[test]
+fn test_exit_code_baseline() {
+    let dir = tempdir().unwrap();
+    let data = dir.path().join("ids.csv");
+    fs::write(&data, "id\n1\n2\n2\n").unwrap();
+    let config = write_config(dir.path(), &data, "is_unique");
+    let baseline = dir.path().join("baseline.json");
+
+    // The baseline is written whatever the outcome of the run
+    let write = [Path::new("--write-baseline"), &baseline];
+    assert_eq!(exit_code_with(&config, &write), 1);
+    let accept = [Path::new("--baseline"), &baseline];
+    assert_eq!(exit_code_with(&config, &accept), 0);
+
+    // A new duplicate exceeds the baseline
+    fs::write(&data, "id\n1\n2\n2\n1\n").unwrap();
+    assert_eq!(exit_code_with(&config, &accept), 1);
+
+    let missing = dir.path().join("missing.json");
+    assert_eq!(
+        exit_code_with(&config, &[Path::new("--baseline"), &missing]),
+        4
+    );
+}
+
+#[test]
+fn test_exit_code_dry_run() {
+    let dir = tempdir().unwrap();
+    let data = dir.path().join("ids.csv");
+    fs::write(&data, "id\n1\n2\n2\n").unwrap();
+    let dry_run = [Path::new("--dry-run")];
+
+    // The duplicate rows are not read
+    let config = write_config(dir.path(), &data, "is_unique");
+    assert_eq!(exit_code_with(&config, &dry_run), 0);
+
+    fs::write(&data, "identifier\n1\n").unwrap();
+    assert_eq!(exit_code_with(&config, &dry_run), 1);
+
+    // Invalid tables are reported with the others instead of stopping the run
+    let config = write_config(dir.path(), &dir.path().join("missing.csv"), "is_unique");
+    assert_eq!(exit_code_with(&config, &dry_run), 1);
+}
+
+#[test]
+fn test_exit_code_force_format() {
+    let dir = tempdir().unwrap();
+    let data = dir.path().join("ids.dat");
+    fs::write(&data, "id\n1\n2\n").unwrap();
+    let config = write_config(dir.path(), &data, "is_unique");
+
+    assert_ne!(exit_code(&config), 0);
+    let force = [Path::new("--force-format"), Path::new("csv")];
+    assert_eq!(exit_code_with(&config, &force), 0);
+    let unknown = [Path::new("--force-format"), Path::new("xml")];
+    assert_eq!(exit_code_with(&config, &unknown), 5);
+}
@@ -0,0 +1,81 @@
+use std::fs::File;
+use std::hint::black_box;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Int64Array};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use dataguard_core::columns::ColumnBuilder;
+use dataguard_core::{NumericColumnBuilder, ParallelAxis, ParquetTable, Table};
+use parquet::arrow::ArrowWriter;
+use tempfile::TempDir;
+
+const NUM_ROWS: usize = 100_000;
+
+/// Write a parquet file with `num_columns` integer columns in a single row group,
+/// so the whole table is read as few batches.
+fn write_wide_parquet(dir: &TempDir, num_columns: usize) -> String {
+    let fields: Vec<Field> = (0..num_columns)
+        .map(|i| Field::new(format!("col_{}", i), DataType::Int64, true))
+        .collect();
+    let schema = Arc::new(Schema::new(fields));
+    let arrays: Vec<ArrayRef> = (0..num_columns)
+        .map(|i| {
+            let values: Int64Array = (0..NUM_ROWS as i64)
+                .map(|v| (v * 7 + i as i64) % 1_000)
+                .collect();
+            Arc::new(values) as ArrayRef
+        })
+        .collect();
+    let batch = RecordBatch::try_new(schema.clone(), arrays).unwrap();
+
+    let path = dir.path().join(format!("wide_{}.parquet", num_columns));
+    let file = File::create(&path).unwrap();
+    let mut writer = ArrowWriter::try_new(file, schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    path.into_os_string().into_string().unwrap()
+}
+
+fn columns(num_columns: usize) -> Vec<Box<dyn ColumnBuilder>> {
+    (0..num_columns)
+        .map(|i| {
+            let mut builder = NumericColumnBuilder::<i64>::new(format!("col_{}", i));
+            builder.between(0, 900, 0.0).is_non_negative(0.0);
+            Box::new(builder) as Box<dyn ColumnBuilder>
+        })
+        .collect()
+}
+
+/// Compare validating a wide table batch by batch and column by column.
+fn bench_parallel_axis(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_axis_wide_table");
+    group.sample_size(10);
+    let dir = TempDir::new().unwrap();
+
+    for num_columns in [10usize, 100, 300] {
+        let path = write_wide_parquet(&dir, num_columns);
+        group.throughput(criterion::Throughput::Elements(
+            (num_columns * NUM_ROWS) as u64,
+        ));
+        for axis in [ParallelAxis::Batches, ParallelAxis::Columns] {
+            let mut table = ParquetTable::new(path.clone(), "wide".to_string())
+                .unwrap()
+                .with_parallel_axis(axis);
+            table.prepare(columns(num_columns), vec![]).unwrap();
+            group.bench_with_input(
+                BenchmarkId::new(format!("{:?}", axis), num_columns),
+                &num_columns,
+                |b, _| {
+                    b.iter(|| black_box(table.validate().unwrap()));
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parallel_axis);
+criterion_main!(benches);
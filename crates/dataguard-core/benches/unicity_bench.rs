@@ -94,7 +94,7 @@ fn bench_unicity_100pct_unique(c: &mut Criterion) {
 fn bench_unicity_50pct_unique(c: &mut Criterion) {
     let mut group = c.benchmark_group("unicity_50pct_unique");
 
-    let rule = UnicityCheck::new();
+    let rule = UnicityCheck::new(0.0);
 
     for (size, arr) in ARRAYS_50PCT_UNIQUE.iter() {
         group.throughput(criterion::Throughput::Elements(*size as u64));
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use crate::{
+    columns::ColumnBuilder,
+    rules::boolean::{DEFAULT_FALSE_TOKENS, DEFAULT_TRUE_TOKENS},
+    ColumnRule, ColumnType, RuleError,
+};
+
+#[derive(Debug, Clone)]
+pub struct BooleanColumnBuilder {
+    name: String,
+    type_threshold: Option<f64>,
+    optional: bool,
+    // File column read instead of `name`
+    source_name: Option<String>,
+    true_tokens: Vec<String>,
+    false_tokens: Vec<String>,
+    rules: Vec<ColumnRule>,
+    // Labels of the rules, by index in `rules`
+    labels: HashMap<usize, String>,
+}
+
+impl ColumnBuilder for BooleanColumnBuilder {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn type_threshold(&self) -> f64 {
+        self.type_threshold.unwrap_or(0.)
+    }
+
+    fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    fn source_name(&self) -> Option<&str> {
+        self.source_name.as_deref()
+    }
+
+    fn column_type(&self) -> ColumnType {
+        ColumnType::Boolean
+    }
+
+    fn rules(&self) -> &[ColumnRule] {
+        self.rules.as_slice()
+    }
+
+    fn rule_labels(&self) -> &HashMap<usize, String> {
+        &self.labels
+    }
+
+    fn format(&self) -> Option<&str> {
+        None
+    }
+
+    fn boolean_tokens(&self) -> Option<(&[String], &[String])> {
+        Some((&self.true_tokens, &self.false_tokens))
+    }
+}
+
+impl BooleanColumnBuilder {
+    /// Create a boolean column parsing `true`/`false`, `1`/`0` and `yes`/`no`
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            type_threshold: None,
+            optional: false,
+            source_name: None,
+            true_tokens: DEFAULT_TRUE_TOKENS.iter().map(|t| t.to_string()).collect(),
+            false_tokens: DEFAULT_FALSE_TOKENS.iter().map(|t| t.to_string()).collect(),
+            rules: Vec::new(),
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Set the type checking threshold
+    pub fn with_type_threshold(mut self, threshold: f64) -> Self {
+        self.type_threshold = Some(threshold);
+        self
+    }
+
+    /// Skip the column when it is missing from the file, instead of failing the table
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Read the file column `source_name` when set, results are still reported under the
+    /// column name
+    pub fn with_source_name(mut self, source_name: Option<String>) -> Self {
+        self.source_name = source_name;
+        self
+    }
+
+    /// Label the last added rule, its results carry this human readable name, e.g.
+    /// "Customer name too short"
+    pub fn with_label(&mut self, label: String) -> Result<&mut Self, RuleError> {
+        let Some(index) = self.rules.len().checked_sub(1) else {
+            return Err(RuleError::ValidationError(
+                "A label must follow the rule it names".to_string(),
+            ));
+        };
+        self.labels.insert(index, label);
+        Ok(self)
+    }
+
+    /// Replace the tokens parsed as `true` and `false`, compared case-insensitively
+    pub fn with_tokens(
+        mut self,
+        true_tokens: Vec<String>,
+        false_tokens: Vec<String>,
+    ) -> Result<Self, RuleError> {
+        if true_tokens.is_empty() || false_tokens.is_empty() {
+            return Err(RuleError::ValidationError(
+                "Boolean tokens cannot be empty".to_string(),
+            ));
+        }
+        if let Some(token) = true_tokens
+            .iter()
+            .find(|t| false_tokens.iter().any(|f| f.eq_ignore_ascii_case(t)))
+        {
+            return Err(RuleError::ValidationError(format!(
+                "Token '{}' cannot be both true and false",
+                token
+            )));
+        }
+        self.true_tokens = true_tokens;
+        self.false_tokens = false_tokens;
+        Ok(self)
+    }
+
+    /// Add not null constraint
+    pub fn is_not_null(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::NullCheck { threshold });
+        self
+    }
+
+    /// Fail when every value of the column is null
+    pub fn not_all_null(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllNull);
+        self
+    }
+
+    /// Fail when the column holds less than two distinct values
+    pub fn not_all_same(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllSame);
+        self
+    }
+
+    /// Fail when the share of null values of the column is outside `[min_ratio, max_ratio]`,
+    /// catching both too many nulls and suspiciously none. The ratio is reported
+    pub fn null_ratio_between(
+        &mut self,
+        min_ratio: f64,
+        max_ratio: f64,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::null_ratio_between(min_ratio, max_ratio)?);
+        Ok(self)
+    }
+
+    /// Fail when the estimated number of distinct values of the column is outside
+    /// `[min, max]`, catching a sudden jump in cardinality. The estimate is reported
+    pub fn distinct_count_between(
+        &mut self,
+        min: usize,
+        max: usize,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::distinct_count_between(min, max)?);
+        Ok(self)
+    }
+
+    /// Check that all values are true
+    pub fn is_true(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::BooleanValue {
+            name: "IsTrue".to_string(),
+            threshold,
+            expected: true,
+        });
+        self
+    }
+
+    /// Check that all values are false
+    pub fn is_false(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::BooleanValue {
+            name: "IsFalse".to_string(),
+            threshold,
+            expected: false,
+        });
+        self
+    }
+
+    /// Check that the share of true values, among non-null ones, lies within `[min, max]`
+    pub fn true_ratio_between(
+        &mut self,
+        min: f64,
+        max: f64,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if !(0.0..=1.0).contains(&min) || !(0.0..=1.0).contains(&max) || min > max {
+            return Err(RuleError::ValidationError(format!(
+                "Invalid true ratio bounds [{}, {}], expected 0 <= min <= max <= 1",
+                min, max
+            )));
+        }
+        self.rules.push(ColumnRule::TrueRatio {
+            name: "TrueRatioBetween".to_string(),
+            threshold,
+            min,
+            max,
+        });
+        Ok(self)
+    }
+}
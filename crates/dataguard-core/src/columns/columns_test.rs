@@ -1,23 +1,45 @@
 #[cfg(test)]
 use crate::columns::{
-    date_builder::DateColumnBuilder, numeric_builder::NumericColumnBuilder,
-    string_builder::StringColumnBuilder, ColumnBuilder, ColumnRule, ColumnType,
+    boolean_builder::BooleanColumnBuilder, date_builder::DateColumnBuilder,
+    numeric_builder::NumericColumnBuilder, string_builder::StringColumnBuilder,
+    timestamp_builder::TimestampColumnBuilder, ColumnBuilder, ColumnRule, ColumnType,
 };
+use crate::rules::{DayOfMonthMode, IpVersion, JsonKind, LengthMode, NormalizeMode};
 
 #[test]
 fn test_string_column_builder() {
     let mut builder = StringColumnBuilder::new("name".to_string());
-    builder.with_min_length(3, 0.0).with_max_length(50, 0.0);
+    builder
+        .with_min_length(3, false, 0.0)
+        .with_max_length(50, false, 0.0);
 
     assert_eq!(builder.name(), "name");
     assert_eq!(builder.column_type(), ColumnType::String);
     assert_eq!(builder.rules().len(), 2);
 }
 
+#[test]
+fn test_string_column_byte_length() {
+    let mut builder = StringColumnBuilder::new("name".to_string());
+    builder
+        .with_max_length(50, true, 0.0)
+        .with_min_length(3, false, 0.0);
+
+    let modes: Vec<LengthMode> = builder
+        .rules()
+        .iter()
+        .map(|rule| match rule {
+            ColumnRule::StringLength { mode, .. } => *mode,
+            _ => panic!("Expected StringLength rule"),
+        })
+        .collect();
+    assert_eq!(modes, vec![LengthMode::Bytes, LengthMode::Chars]);
+}
+
 #[test]
 fn test_string_column_with_regex() {
     let mut builder = StringColumnBuilder::new("email".to_string());
-    builder.is_email(0.0).unwrap();
+    builder.is_email(false, 0.0).unwrap();
 
     assert_eq!(builder.column_type(), ColumnType::String);
     assert_eq!(builder.rules().len(), 1);
@@ -37,6 +59,270 @@ fn test_string_column_invalid_regex() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_string_column_invalid_regex_flag() {
+    let mut builder = StringColumnBuilder::new("test".to_string());
+    assert!(builder
+        .with_regex("^a$".to_string(), Some("x".to_string()), 0.0)
+        .is_err());
+    assert!(builder
+        .with_regex("^a$".to_string(), Some("ms".to_string()), 0.0)
+        .is_ok());
+    assert_eq!(builder.rules().len(), 1);
+}
+
+#[test]
+fn test_string_column_matches_any_of() {
+    let mut builder = StringColumnBuilder::new("code".to_string());
+    let err = builder
+        .matches_any_of(
+            vec![r"^AB-\d{4}$".to_string(), "[invalid(".to_string()],
+            None,
+            0.0,
+        )
+        .err()
+        .unwrap();
+    // The offending pattern is named
+    assert!(err.to_string().contains("[invalid("));
+    assert!(builder.matches_any_of(vec![], None, 0.0).is_err());
+
+    builder
+        .matches_any_of(
+            vec![r"^AB-\d{4}$".to_string(), r"^PRD_\d{3}$".to_string()],
+            Some("i".to_string()),
+            0.0,
+        )
+        .unwrap();
+    assert_eq!(builder.rules().len(), 1);
+    match &builder.rules()[0] {
+        ColumnRule::StringRegexAny { name, patterns, .. } => {
+            assert_eq!(name, "MatchesAnyOf");
+            assert_eq!(patterns.len(), 2);
+        }
+        _ => panic!("Expected StringRegexAny rule"),
+    }
+}
+
+#[test]
+fn test_string_column_membership() {
+    let mut builder = StringColumnBuilder::new("category".to_string());
+    builder
+        .is_in(vec!["books".to_string(), "legacy".to_string()], 0.0)
+        .is_not_in(vec!["legacy".to_string()], 0.0);
+
+    match &builder.rules()[1] {
+        ColumnRule::StringNotMembers { name, members, .. } => {
+            assert_eq!(name, "NotIn");
+            assert_eq!(members, &vec!["legacy".to_string()]);
+        }
+        _ => panic!("Expected StringNotMembers rule"),
+    }
+}
+
+#[test]
+fn test_string_column_affixes() {
+    let mut builder = StringColumnBuilder::new("sku".to_string());
+    builder
+        .with_prefix("FR".to_string(), 0.0)
+        .unwrap()
+        .with_suffix("-X".to_string(), 0.0)
+        .unwrap();
+
+    assert_eq!(builder.rules().len(), 2);
+    match &builder.rules()[0] {
+        ColumnRule::StartsWith { name, prefix, .. } => {
+            assert_eq!(name, "WithPrefix");
+            assert_eq!(prefix, "FR");
+        }
+        _ => panic!("Expected StartsWith rule"),
+    }
+    match &builder.rules()[1] {
+        ColumnRule::EndsWith { suffix, .. } => assert_eq!(suffix, "-X"),
+        _ => panic!("Expected EndsWith rule"),
+    }
+}
+
+#[test]
+fn test_string_column_empty_affix() {
+    let mut builder = StringColumnBuilder::new("sku".to_string());
+    assert!(builder.with_prefix(String::new(), 0.0).is_err());
+    assert!(builder.with_suffix(String::new(), 0.0).is_err());
+    assert_eq!(builder.rules().len(), 0);
+}
+
+#[test]
+fn test_string_column_contains() {
+    let mut builder = StringColumnBuilder::new("description".to_string());
+    builder
+        .contains("#ref".to_string(), None, 0.0)
+        .unwrap()
+        .not_contains("secret".to_string(), Some("i".to_string()), 0.0)
+        .unwrap();
+
+    match &builder.rules()[1] {
+        ColumnRule::Contains {
+            name,
+            negate,
+            case_insensitive,
+            ..
+        } => {
+            assert_eq!(name, "NotContains");
+            assert!(negate);
+            assert!(case_insensitive);
+        }
+        _ => panic!("Expected Contains rule"),
+    }
+    assert!(builder.contains(String::new(), None, 0.0).is_err());
+    assert!(builder
+        .contains("x".to_string(), Some("m".to_string()), 0.0)
+        .is_err());
+    assert_eq!(builder.rules().len(), 2);
+}
+
+#[test]
+fn test_string_column_contains_only() {
+    let mut builder = StringColumnBuilder::new("sequence".to_string());
+    builder.contains_only("ACGT".to_string(), 0.0).unwrap();
+    assert!(builder.contains_only(String::new(), 0.0).is_err());
+
+    assert_eq!(builder.rules().len(), 1);
+    match &builder.rules()[0] {
+        ColumnRule::ContainsOnly {
+            name,
+            allowed_chars,
+            ..
+        } => {
+            assert_eq!(name, "ContainsOnly");
+            assert_eq!(allowed_chars.len(), 4);
+            assert!(allowed_chars.contains(&'G'));
+        }
+        _ => panic!("Expected ContainsOnly rule"),
+    }
+}
+
+#[test]
+fn test_string_column_ip() {
+    let mut builder = StringColumnBuilder::new("address".to_string());
+    builder.is_ipv4(0.0).is_ipv6(0.0).is_ip(0.0);
+
+    let versions: Vec<IpVersion> = builder
+        .rules()
+        .iter()
+        .map(|rule| match rule {
+            ColumnRule::IpAddress { version, .. } => *version,
+            _ => panic!("Expected IpAddress rule"),
+        })
+        .collect();
+    assert_eq!(versions, vec![IpVersion::V4, IpVersion::V6, IpVersion::Any]);
+}
+
+#[test]
+fn test_string_column_ip_in_cidr() {
+    let mut builder = StringColumnBuilder::new("address".to_string());
+    builder
+        .ip_in_cidr(vec!["10.0.0.0/8".to_string(), "fd00::/8".to_string()], 0.0)
+        .unwrap();
+    assert!(builder
+        .ip_in_cidr(vec!["10.0.0.0/8".to_string(), "internal".to_string()], 0.0)
+        .is_err());
+
+    assert_eq!(builder.rules().len(), 1);
+    match &builder.rules()[0] {
+        ColumnRule::IpInCidr { name, allowed, .. } => {
+            assert_eq!(name, "IpInCidr");
+            assert_eq!(allowed.len(), 2);
+        }
+        _ => panic!("Expected IpInCidr rule"),
+    }
+}
+
+#[test]
+fn test_string_column_phone() {
+    let mut builder = StringColumnBuilder::new("phone".to_string());
+    builder.is_phone(Some("fr"), 0.0).unwrap();
+    assert!(builder.is_phone(Some("France"), 0.0).is_err());
+
+    match &builder.rules()[0] {
+        ColumnRule::IsPhone { region, .. } => assert_eq!(region.as_deref(), Some("FR")),
+        _ => panic!("Expected IsPhone rule"),
+    }
+}
+
+#[test]
+fn test_string_column_checksums() {
+    let mut builder = StringColumnBuilder::new("payment".to_string());
+    builder.is_credit_card(0.0).is_iban(0.1);
+
+    let rules = builder.rules();
+    assert_eq!(rules.len(), 2);
+    match &rules[0] {
+        ColumnRule::CreditCard { name, .. } => assert_eq!(name, "IsCreditCard"),
+        _ => panic!("Expected CreditCard rule"),
+    }
+    match &rules[1] {
+        ColumnRule::Iban { name, threshold } => {
+            assert_eq!(name, "IsIban");
+            assert_eq!(*threshold, 0.1);
+        }
+        _ => panic!("Expected Iban rule"),
+    }
+}
+
+#[test]
+fn test_string_column_encodings() {
+    let mut builder = StringColumnBuilder::new("signature".to_string());
+    builder.is_base64(false, 0.0).is_hex(true, 0.5);
+
+    let rules = builder.rules();
+    assert_eq!(rules.len(), 2);
+    match &rules[0] {
+        ColumnRule::Base64 {
+            name,
+            strict_padding,
+            ..
+        } => {
+            assert_eq!(name, "IsBase64");
+            assert!(!strict_padding);
+        }
+        _ => panic!("Expected Base64 rule"),
+    }
+    match &rules[1] {
+        ColumnRule::Hex {
+            name,
+            threshold,
+            allow_prefix,
+        } => {
+            assert_eq!(name, "IsHex");
+            assert_eq!(*threshold, 0.5);
+            assert!(allow_prefix);
+        }
+        _ => panic!("Expected Hex rule"),
+    }
+}
+
+#[test]
+fn test_string_column_json() {
+    let mut builder = StringColumnBuilder::new("payload".to_string());
+    builder.is_json(0.0).is_json_object(0.0).is_json_array(0.0);
+
+    let kinds: Vec<(String, JsonKind)> = builder
+        .rules()
+        .iter()
+        .map(|rule| match rule {
+            ColumnRule::Json { name, kind, .. } => (name.clone(), *kind),
+            _ => panic!("Expected Json rule"),
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ("IsJson".to_string(), JsonKind::Any),
+            ("IsJsonObject".to_string(), JsonKind::Object),
+            ("IsJsonArray".to_string(), JsonKind::Array),
+        ]
+    );
+}
+
 #[test]
 fn test_integer_column_builder() {
     let mut builder = NumericColumnBuilder::<i64>::new("age".to_string());
@@ -47,6 +333,26 @@ fn test_integer_column_builder() {
     assert_eq!(builder.rules().len(), 1);
 }
 
+#[test]
+fn test_float_column_between_exclusive() {
+    let mut builder = NumericColumnBuilder::<f64>::new("probability".to_string());
+    builder.between_exclusive(0.0, 1.0, 0.0);
+
+    match &builder.rules()[0] {
+        ColumnRule::NumericRange {
+            name,
+            min_inclusive,
+            max_inclusive,
+            ..
+        } => {
+            assert_eq!(name, "BetweenExclusive");
+            assert!(!min_inclusive);
+            assert!(!max_inclusive);
+        }
+        _ => panic!("Expected NumericRange rule"),
+    }
+}
+
 #[test]
 fn test_integer_column_is_positive() {
     let mut builder = NumericColumnBuilder::<i64>::new("count".to_string());
@@ -84,15 +390,43 @@ fn test_float_column_monotonicity() {
     }
 }
 
+#[test]
+fn test_integer_column_membership() {
+    let mut builder = NumericColumnBuilder::<i64>::new("status".to_string());
+    builder.is_in(vec![200, 404], 0.0).not_in(vec![500], 0.0);
+
+    match &builder.rules()[1] {
+        ColumnRule::NumericMembers {
+            name,
+            members,
+            negate,
+            ..
+        } => {
+            assert_eq!(name, "NotIn");
+            assert_eq!(members, &vec![500.0]);
+            assert!(negate);
+        }
+        _ => panic!("Expected NumericMembers rule"),
+    }
+}
+
+#[test]
+fn test_integer_column_multiple_of_zero() {
+    let mut builder = NumericColumnBuilder::<i64>::new("quantity".to_string());
+    assert!(builder.is_multiple_of(0, 0.0).is_err());
+    assert!(builder.is_multiple_of(12, 0.0).is_ok());
+    assert_eq!(builder.rules().len(), 1);
+}
+
 #[test]
 fn test_column_chaining() {
     let mut builder = StringColumnBuilder::new("username".to_string());
     builder
-        .with_min_length(3, 0.0)
-        .with_max_length(20, 0.0)
+        .with_min_length(3, false, 0.0)
+        .with_max_length(20, false, 0.0)
         .is_alphanumeric(0.0)
         .unwrap()
-        .is_unique(0.0);
+        .is_unique(NormalizeMode::None, 0.0);
 
     assert_eq!(builder.rules().len(), 4);
 }
@@ -270,6 +604,53 @@ fn test_date_column_is_unique() {
     }
 }
 
+#[test]
+fn test_string_column_is_unique_within() {
+    let mut builder = StringColumnBuilder::new("email".to_string());
+    builder.is_unique_within("tenant_id", 0.01);
+
+    match &builder.rules()[0] {
+        ColumnRule::UniqueWithinGroup {
+            threshold,
+            group_by,
+        } => {
+            assert_eq!(*threshold, 0.01);
+            assert_eq!(group_by, "tenant_id");
+        }
+        _ => panic!("Expected UniqueWithinGroup rule"),
+    }
+}
+
+#[test]
+fn test_date_column_day_of_month() {
+    let mut builder = DateColumnBuilder::new("snapshot".to_string(), "%Y-%m-%d".to_string());
+    builder
+        .is_month_end(0.0)
+        .is_month_start(0.0)
+        .is_day_of_month(15, 0.0);
+
+    assert_eq!(builder.rules().len(), 3);
+    match &builder.rules()[0] {
+        ColumnRule::DayOfMonth { name, mode, .. } => {
+            assert_eq!(name, "IsMonthEnd");
+            assert_eq!(mode, &DayOfMonthMode::LastOfMonth);
+        }
+        _ => panic!("Expected DayOfMonth rule"),
+    }
+    match &builder.rules()[1] {
+        ColumnRule::DayOfMonth { mode, .. } => {
+            assert_eq!(mode, &DayOfMonthMode::FirstOfMonth);
+        }
+        _ => panic!("Expected DayOfMonth rule"),
+    }
+    match &builder.rules()[2] {
+        ColumnRule::DayOfMonth { mode, .. } => {
+            assert_eq!(mode, &DayOfMonthMode::Day(15));
+        }
+        _ => panic!("Expected DayOfMonth rule"),
+    }
+}
+
 #[test]
 fn test_date_column_chaining() {
     let mut builder = DateColumnBuilder::new("order_date".to_string(), "%Y-%m-%d".to_string());
@@ -332,3 +713,123 @@ fn test_date_column_empty_rules() {
     let builder = DateColumnBuilder::new("date".to_string(), "%Y-%m-%d".to_string());
     assert_eq!(builder.rules().len(), 0);
 }
+
+// ============================================================================
+// Timestamp Column Builder Tests
+// ============================================================================
+
+#[test]
+fn test_timestamp_column_builder() {
+    let mut builder =
+        TimestampColumnBuilder::new("event_ts".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+    builder
+        .is_after("1970-01-01 00:00:01", 0.0)
+        .unwrap()
+        .time_between(9, 17, 0.0)
+        .unwrap();
+
+    assert_eq!(builder.column_type(), ColumnType::Timestamp);
+    assert_eq!(builder.format(), Some("%Y-%m-%d %H:%M:%S"));
+    assert_eq!(
+        builder.rules()[0],
+        ColumnRule::TimestampBoundary {
+            name: "IsAfter".to_string(),
+            threshold: 0.0,
+            after: true,
+            millis: 1_000,
+        }
+    );
+}
+
+#[test]
+fn test_timestamp_column_invalid_rules() {
+    let mut builder =
+        TimestampColumnBuilder::new("event_ts".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+    assert!(builder.is_before("2024-02-30 10:00:00", 0.0).is_err());
+    assert!(builder.time_between(9, 9, 0.0).is_err());
+    assert!(builder.time_between(0, 25, 0.0).is_err());
+    assert!(builder.rules().is_empty());
+}
+
+// ============================================================================
+// BOOLEAN COLUMN TESTS
+// ============================================================================
+
+#[test]
+fn test_boolean_column_builder() {
+    let mut builder = BooleanColumnBuilder::new("is_active".to_string());
+    builder.is_not_null(0.0).is_true(0.0);
+    builder.true_ratio_between(0.2, 0.8, 0.0).unwrap();
+
+    assert_eq!(builder.name(), "is_active");
+    assert_eq!(builder.column_type(), ColumnType::Boolean);
+    assert_eq!(builder.rules().len(), 3);
+    match &builder.rules()[2] {
+        ColumnRule::TrueRatio { name, min, max, .. } => {
+            assert_eq!(name, "TrueRatioBetween");
+            assert_eq!(*min, 0.2);
+            assert_eq!(*max, 0.8);
+        }
+        _ => panic!("Expected TrueRatio rule"),
+    }
+}
+
+#[test]
+fn test_boolean_column_default_tokens() {
+    let builder = BooleanColumnBuilder::new("flag".to_string());
+    let (true_tokens, false_tokens) = builder.boolean_tokens().unwrap();
+    assert_eq!(true_tokens, ["true", "1", "yes"]);
+    assert_eq!(false_tokens, ["false", "0", "no"]);
+}
+
+#[test]
+fn test_boolean_column_invalid_tokens() {
+    let builder = BooleanColumnBuilder::new("flag".to_string());
+    assert!(builder
+        .clone()
+        .with_tokens(vec![], vec!["n".to_string()])
+        .is_err());
+    assert!(builder
+        .with_tokens(vec!["Y".to_string()], vec!["y".to_string()])
+        .is_err());
+}
+
+#[test]
+fn test_boolean_column_invalid_ratio() {
+    let mut builder = BooleanColumnBuilder::new("flag".to_string());
+    assert!(builder.true_ratio_between(0.8, 0.2, 0.0).is_err());
+    assert!(builder.true_ratio_between(0.0, 1.5, 0.0).is_err());
+    assert_eq!(builder.rules().len(), 0);
+}
+
+#[test]
+fn test_null_ratio_between() {
+    let mut builder = StringColumnBuilder::new("email".to_string());
+    builder.null_ratio_between(0.0, 0.05).unwrap();
+    assert_eq!(
+        builder.rules()[0],
+        ColumnRule::NullRatioBetween {
+            min_ratio: 0.0,
+            max_ratio: 0.05
+        }
+    );
+
+    let mut builder = DateColumnBuilder::new("day".to_string(), "%Y-%m-%d".to_string());
+    assert!(builder.null_ratio_between(0.5, 0.1).is_err());
+    assert!(builder.null_ratio_between(-0.1, 0.1).is_err());
+    assert_eq!(builder.rules().len(), 0);
+}
+
+#[test]
+fn test_distinct_count_between() {
+    let mut builder = StringColumnBuilder::new("country_code".to_string());
+    builder.distinct_count_between(1, 250).unwrap();
+    assert_eq!(
+        builder.rules()[0],
+        ColumnRule::DistinctCountBetween { min: 1, max: 250 }
+    );
+
+    let mut builder = NumericColumnBuilder::<i64>::new("id".to_string());
+    assert!(builder.distinct_count_between(250, 1).is_err());
+    assert_eq!(builder.rules().len(), 0);
+}
@@ -1,13 +1,24 @@
-use chrono::Datelike;
+use std::collections::HashMap;
 
-use crate::{columns::ColumnBuilder, ColumnRule, ColumnType};
+use chrono::{Datelike, NaiveDate};
+
+use crate::{
+    columns::ColumnBuilder,
+    rules::{date::date_range_days, DayOfMonthMode, NormalizeMode},
+    ColumnRule, ColumnType, RuleError,
+};
 
 #[derive(Debug, Clone)]
 pub struct DateColumnBuilder {
     name: String,
     type_threshold: Option<f64>,
+    optional: bool,
+    // File column read instead of `name`
+    source_name: Option<String>,
     format: String,
     rules: Vec<ColumnRule>,
+    // Labels of the rules, by index in `rules`
+    labels: HashMap<usize, String>,
 }
 
 impl ColumnBuilder for DateColumnBuilder {
@@ -19,6 +30,14 @@ impl ColumnBuilder for DateColumnBuilder {
         self.type_threshold.unwrap_or(0.)
     }
 
+    fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    fn source_name(&self) -> Option<&str> {
+        self.source_name.as_deref()
+    }
+
     fn column_type(&self) -> ColumnType {
         ColumnType::DateType
     }
@@ -27,6 +46,10 @@ impl ColumnBuilder for DateColumnBuilder {
         self.rules.as_slice()
     }
 
+    fn rule_labels(&self) -> &HashMap<usize, String> {
+        &self.labels
+    }
+
     fn format(&self) -> Option<&str> {
         Some(&self.format)
     }
@@ -38,7 +61,10 @@ impl DateColumnBuilder {
             name,
             format,
             type_threshold: None,
+            optional: false,
+            source_name: None,
             rules: Vec::new(),
+            labels: HashMap::new(),
         }
     }
 
@@ -48,6 +74,31 @@ impl DateColumnBuilder {
         self
     }
 
+    /// Skip the column when it is missing from the file, instead of failing the table
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Read the file column `source_name` when set, results are still reported under the
+    /// column name
+    pub fn with_source_name(mut self, source_name: Option<String>) -> Self {
+        self.source_name = source_name;
+        self
+    }
+
+    /// Label the last added rule, its results carry this human readable name, e.g.
+    /// "Customer name too short"
+    pub fn with_label(&mut self, label: String) -> Result<&mut Self, RuleError> {
+        let Some(index) = self.rules.len().checked_sub(1) else {
+            return Err(RuleError::ValidationError(
+                "A label must follow the rule it names".to_string(),
+            ));
+        };
+        self.labels.insert(index, label);
+        Ok(self)
+    }
+
     pub fn get_format(&self) -> String {
         self.format.clone()
     }
@@ -58,9 +109,58 @@ impl DateColumnBuilder {
         self
     }
 
+    /// Fail when every value of the column is null
+    pub fn not_all_null(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllNull);
+        self
+    }
+
+    /// Fail when the column holds less than two distinct values
+    pub fn not_all_same(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllSame);
+        self
+    }
+
+    /// Fail when the share of null values of the column is outside `[min_ratio, max_ratio]`,
+    /// catching both too many nulls and suspiciously none. The ratio is reported
+    pub fn null_ratio_between(
+        &mut self,
+        min_ratio: f64,
+        max_ratio: f64,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::null_ratio_between(min_ratio, max_ratio)?);
+        Ok(self)
+    }
+
+    /// Fail when the estimated number of distinct values of the column is outside
+    /// `[min, max]`, catching a sudden jump in cardinality. The estimate is reported
+    pub fn distinct_count_between(
+        &mut self,
+        min: usize,
+        max: usize,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::distinct_count_between(min, max)?);
+        Ok(self)
+    }
+
     /// Add uniqueness constraint
     pub fn is_unique(&mut self, threshold: f64) -> &mut Self {
-        self.rules.push(ColumnRule::Unicity { threshold });
+        self.rules.push(ColumnRule::Unicity {
+            threshold,
+            normalize: NormalizeMode::None,
+        });
+        self
+    }
+
+    /// Add uniqueness constraint within each group of rows sharing the same `group_column`
+    /// value, e.g. an email unique per tenant but repeated across tenants
+    pub fn is_unique_within(&mut self, group_column: &str, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::UniqueWithinGroup {
+            threshold,
+            group_by: group_column.to_string(),
+        });
         self
     }
 
@@ -102,12 +202,29 @@ impl DateColumnBuilder {
         self
     }
 
-    /// Infer the date from today, and check that all dates are before today
-    pub fn is_not_futur(&mut self, threshold: f64) -> &mut Self {
-        let now = chrono::offset::Local::now();
-        let year = now.year() as usize;
-        let month = Some(now.month() as usize);
-        let day = Some(now.day() as usize);
+    /// Check that all dates are within `[start, end]`, bounds given as `(year, month, day)`
+    pub fn between(
+        &mut self,
+        start: (usize, u32, u32),
+        end: (usize, u32, u32),
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        // Fail early on impossible or inverted bounds
+        date_range_days(start, end)?;
+        self.rules.push(ColumnRule::DateRange {
+            name: "IsBetween".to_string(),
+            threshold,
+            start,
+            end,
+        });
+        Ok(self)
+    }
+
+    /// Check that all dates are before `reference_date`, today when `None`
+    ///
+    /// Set the reference to the logical date of the dataset for reproducible batch jobs.
+    pub fn is_not_futur(&mut self, reference_date: Option<NaiveDate>, threshold: f64) -> &mut Self {
+        let (year, month, day) = reference_ymd(reference_date);
         self.rules.push(ColumnRule::DateBoundary {
             name: "IsNotFutur".to_string(),
             threshold,
@@ -119,12 +236,9 @@ impl DateColumnBuilder {
         self
     }
 
-    /// Infer the date from today, and check that all dates are after today
-    pub fn is_not_past(&mut self, threshold: f64) -> &mut Self {
-        let now = chrono::offset::Local::now();
-        let year = now.year() as usize;
-        let month = Some(now.month() as usize);
-        let day = Some(now.day() as usize);
+    /// Check that all dates are after `reference_date`, today when `None`
+    pub fn is_not_past(&mut self, reference_date: Option<NaiveDate>, threshold: f64) -> &mut Self {
+        let (year, month, day) = reference_ymd(reference_date);
         self.rules.push(ColumnRule::DateBoundary {
             name: "IsNotPast".to_string(),
             threshold,
@@ -153,4 +267,70 @@ impl DateColumnBuilder {
         });
         self
     }
+
+    /// Check that all dates fall on the first day of their month
+    pub fn is_month_start(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::DayOfMonth {
+            name: "IsMonthStart".to_string(),
+            threshold,
+            mode: DayOfMonthMode::FirstOfMonth,
+        });
+        self
+    }
+
+    /// Check that all dates fall on the last day of their month
+    pub fn is_month_end(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::DayOfMonth {
+            name: "IsMonthEnd".to_string(),
+            threshold,
+            mode: DayOfMonthMode::LastOfMonth,
+        });
+        self
+    }
+
+    /// Check that all dates fall on the given day of their month
+    pub fn is_day_of_month(&mut self, day: u32, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::DayOfMonth {
+            name: "IsDayOfMonth".to_string(),
+            threshold,
+            mode: DayOfMonthMode::Day(day),
+        });
+        self
+    }
+
+    /// Check that the full years elapsed between each date and `reference_date` are within
+    /// `[min_years, max_years]`, e.g. an age computed from a birth date. The reference
+    /// defaults to today.
+    pub fn age_between(
+        &mut self,
+        min_years: u32,
+        max_years: u32,
+        reference_date: Option<NaiveDate>,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if min_years > max_years {
+            return Err(RuleError::ValidationError(format!(
+                "Minimum age {} is greater than maximum age {}",
+                min_years, max_years
+            )));
+        }
+        self.rules.push(ColumnRule::Age {
+            name: "AgeBetween".to_string(),
+            threshold,
+            min_years,
+            max_years,
+            reference_date,
+        });
+        Ok(self)
+    }
+}
+
+/// Year, month and day of the reference date, or of the local date when `None`
+fn reference_ymd(reference_date: Option<NaiveDate>) -> (usize, Option<usize>, Option<usize>) {
+    let date = reference_date.unwrap_or_else(|| chrono::Local::now().date_naive());
+    (
+        date.year() as usize,
+        Some(date.month() as usize),
+        Some(date.day() as usize),
+    )
 }
@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use crate::{columns::GroupConstraint, RuleError};
+
+/// Builder for assertions computed on groups of rows sharing the same key.
+pub struct GroupBuilder {
+    pub group_by: String,
+    /// Column summed by `sum_equals`, row counts do not need one
+    pub value: Option<String>,
+    pub rules: Vec<GroupConstraint>,
+}
+
+impl GroupBuilder {
+    pub fn new(group_by: String, value: String) -> Self {
+        Self {
+            group_by,
+            value: Some(value),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Create a builder only counting the rows of each group of `group_by`.
+    pub fn count_by(group_by: String) -> Self {
+        Self {
+            group_by,
+            value: None,
+            rules: Vec::new(),
+        }
+    }
+
+    pub fn rules(&self) -> &[GroupConstraint] {
+        &self.rules
+    }
+
+    /// Check that the sum of `value` per group matches the expected totals.
+    /// A group is flagged when its sum deviates by more than `tolerance`.
+    pub fn sum_equals(
+        &mut self,
+        expected: HashMap<String, f64>,
+        tolerance: f64,
+        threshold: f64,
+    ) -> &mut Self {
+        self.rules.push(GroupConstraint::SumEquals {
+            expected,
+            tolerance,
+            threshold,
+        });
+        self
+    }
+
+    /// Check that each group holds between `min` and `max` rows, each bound is optional.
+    /// Use the same value for both bounds to expect an exact count.
+    /// Rows with a null key do not belong to any group.
+    pub fn count_between(
+        &mut self,
+        min: Option<usize>,
+        max: Option<usize>,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        match (min, max) {
+            (None, None) => {
+                return Err(RuleError::ValidationError(
+                    "Group count needs a min or a max bound".to_string(),
+                ))
+            }
+            (Some(min), Some(max)) if min > max => {
+                return Err(RuleError::ValidationError(format!(
+                    "Group count min ({}) is greater than max ({})",
+                    min, max
+                )))
+            }
+            _ => {}
+        }
+        self.rules.push(GroupConstraint::CountBetween {
+            min,
+            max,
+            threshold,
+        });
+        Ok(self)
+    }
+}
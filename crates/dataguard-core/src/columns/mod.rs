@@ -1,24 +1,51 @@
+pub mod boolean_builder;
 pub mod date_builder;
+pub mod group_builder;
 pub mod numeric_builder;
 pub mod relation_builder;
 pub mod string_builder;
+pub mod timestamp_builder;
 
 #[cfg(test)]
 mod columns_test;
 
 use core::f64;
+use std::collections::{HashMap, HashSet};
 
-use crate::utils::operator::CompOperator;
+use chrono::NaiveDate;
+use ipnet::IpNet;
+
+use crate::{
+    rules::{
+        date::DayOfMonthMode,
+        generic::NormalizeMode,
+        string::{IpVersion, JsonKind, LengthMode},
+    },
+    utils::operator::CompOperator,
+    RuleError,
+};
 
 pub trait ColumnBuilder {
     fn name(&self) -> &str;
     fn column_type(&self) -> ColumnType;
     fn rules(&self) -> &[ColumnRule];
+    /// Labels given to the rules, keyed by their index in `rules`
+    fn rule_labels(&self) -> &HashMap<usize, String>;
     fn type_threshold(&self) -> f64;
+    /// Optional columns missing from the file are skipped, required ones fail the table
+    fn is_optional(&self) -> bool;
+    /// File column read for this column when its name differs, such as a renamed upstream
+    /// column
+    fn source_name(&self) -> Option<&str>;
 
     // For now only used for date, could be usefull for thousand separator in numeric or decimal
     // precision etc..
     fn format(&self) -> Option<&str>;
+
+    /// Tokens parsed as `true` and `false`, only used by boolean columns
+    fn boolean_tokens(&self) -> Option<(&[String], &[String])> {
+        None
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -27,6 +54,8 @@ pub enum ColumnType {
     Integer,
     Float,
     DateType,
+    Timestamp,
+    Boolean,
 }
 
 pub trait NumericType: Copy + Send + Sync {
@@ -75,6 +104,7 @@ pub enum ColumnRule {
         threshold: f64,
         min: Option<usize>,
         max: Option<usize>,
+        mode: LengthMode,
     },
     StringRegex {
         name: String,
@@ -82,11 +112,109 @@ pub enum ColumnRule {
         pattern: String,
         flags: Option<String>,
     },
+    StringRegexAny {
+        name: String,
+        threshold: f64,
+        patterns: Vec<String>,
+        flags: Option<String>,
+    },
     StringMembers {
         name: String,
         threshold: f64,
         members: Vec<String>,
     },
+    StringNotMembers {
+        name: String,
+        threshold: f64,
+        members: Vec<String>,
+    },
+    StringEquals {
+        name: String,
+        threshold: f64,
+        expected: String,
+    },
+    StartsWith {
+        name: String,
+        threshold: f64,
+        prefix: String,
+    },
+    EndsWith {
+        name: String,
+        threshold: f64,
+        suffix: String,
+    },
+    Contains {
+        name: String,
+        threshold: f64,
+        substring: String,
+        negate: bool,
+        case_insensitive: bool,
+    },
+    IpAddress {
+        name: String,
+        threshold: f64,
+        version: IpVersion,
+    },
+    IpInCidr {
+        name: String,
+        threshold: f64,
+        allowed: Vec<IpNet>,
+    },
+    Json {
+        name: String,
+        threshold: f64,
+        kind: JsonKind,
+    },
+    CreditCard {
+        name: String,
+        threshold: f64,
+    },
+    Iban {
+        name: String,
+        threshold: f64,
+    },
+    Base64 {
+        name: String,
+        threshold: f64,
+        /// Require the `=` padding to a multiple of 4 characters
+        strict_padding: bool,
+    },
+    Hex {
+        name: String,
+        threshold: f64,
+        /// Accept a leading `0x`
+        allow_prefix: bool,
+    },
+    IsPhone {
+        name: String,
+        /// ISO 3166 country code, international format only when `None`
+        region: Option<String>,
+        threshold: f64,
+    },
+    StringCase {
+        name: String,
+        threshold: f64,
+        upper: bool,
+        unicode: bool,
+    },
+    NoSurroundingWhitespace {
+        name: String,
+        threshold: f64,
+    },
+    NoWhitespace {
+        name: String,
+        threshold: f64,
+    },
+    ContainsOnly {
+        name: String,
+        threshold: f64,
+        allowed_chars: HashSet<char>,
+    },
+    StringConsistentLength {
+        name: String,
+        threshold: f64,
+        tolerance: usize,
+    },
 
     // Numeric rules (works for both Integer and Float)
     NumericRange {
@@ -94,6 +222,10 @@ pub enum ColumnRule {
         threshold: f64,
         min: Option<f64>,
         max: Option<f64>,
+        /// Values equal to `min` are valid
+        min_inclusive: bool,
+        /// Values equal to `max` are valid
+        max_inclusive: bool,
     },
 
     Monotonicity {
@@ -102,6 +234,36 @@ pub enum ColumnRule {
         ascending: bool,
     },
 
+    NumericMembers {
+        name: String,
+        members: Vec<f64>,
+        negate: bool,
+        threshold: f64,
+    },
+    NumericEquals {
+        name: String,
+        expected: f64,
+        threshold: f64,
+    },
+
+    // Integer only rules
+    MultipleOf {
+        name: String,
+        divisor: i64,
+        threshold: f64,
+    },
+
+    // Float only rules
+    IsFinite {
+        name: String,
+        threshold: f64,
+    },
+    MaxDecimalPlaces {
+        name: String,
+        places: u32,
+        threshold: f64,
+    },
+
     // Statistical rules (require stats computation)
     StdDevCheck {
         name: String,
@@ -113,6 +275,17 @@ pub enum ColumnRule {
         threshold: f64,
         max_variance_percent: f64,
     },
+    ZScoreOutlier {
+        name: String,
+        threshold: f64,
+        num_std: f64,
+    },
+    Percentile {
+        name: String,
+        threshold: f64,
+        quantile: f64,
+        bound: f64,
+    },
 
     // Date rules (works only for Date32 for now)
     DateBoundary {
@@ -124,23 +297,201 @@ pub enum ColumnRule {
         day: Option<usize>,
     },
 
+    DateRange {
+        name: String,
+        threshold: f64,
+        start: (usize, u32, u32),
+        end: (usize, u32, u32),
+    },
+
     WeekDay {
         name: String,
         threshold: f64,
         is_week: bool,
     },
 
+    DayOfMonth {
+        name: String,
+        threshold: f64,
+        mode: DayOfMonthMode,
+    },
+
+    Age {
+        name: String,
+        threshold: f64,
+        min_years: u32,
+        max_years: u32,
+        /// Ages are computed at this date, today when `None`
+        reference_date: Option<NaiveDate>,
+    },
+
+    // Timestamp rules, bounds are milliseconds since the Unix epoch
+    TimestampBoundary {
+        name: String,
+        threshold: f64,
+        after: bool,
+        millis: i64,
+    },
+    TimeOfDay {
+        name: String,
+        threshold: f64,
+        start_hour: u32,
+        end_hour: u32,
+    },
+
+    // Boolean rules
+    BooleanValue {
+        name: String,
+        threshold: f64,
+        expected: bool,
+    },
+    TrueRatio {
+        name: String,
+        threshold: f64,
+        min: f64,
+        max: f64,
+    },
+
     // Generic rules
     Unicity {
         threshold: f64,
+        /// Applied to strings before they are hashed, raw for the other types
+        normalize: NormalizeMode,
+    },
+    /// Values are unique among the rows sharing the same `group_by` key
+    UniqueWithinGroup {
+        threshold: f64,
+        group_by: String,
     },
     NullCheck {
         threshold: f64,
     },
+    /// At least one value of the column is not null
+    NotAllNull,
+    /// The column holds at least two distinct values
+    NotAllSame,
+    /// The share of null values of the column lies within `[min_ratio, max_ratio]`
+    NullRatioBetween {
+        min_ratio: f64,
+        max_ratio: f64,
+    },
+    /// The estimated number of distinct values of the column lies within `[min, max]`
+    DistinctCountBetween {
+        min: usize,
+        max: usize,
+    },
+}
+
+impl ColumnRule {
+    /// Null ratio rule shared by every builder, bounds must satisfy `0 <= min <= max <= 1`
+    pub(crate) fn null_ratio_between(min_ratio: f64, max_ratio: f64) -> Result<Self, RuleError> {
+        if !(0.0..=1.0).contains(&min_ratio)
+            || !(0.0..=1.0).contains(&max_ratio)
+            || min_ratio > max_ratio
+        {
+            return Err(RuleError::ValidationError(format!(
+                "Invalid null ratio bounds [{}, {}], expected 0 <= min <= max <= 1",
+                min_ratio, max_ratio
+            )));
+        }
+        Ok(ColumnRule::NullRatioBetween {
+            min_ratio,
+            max_ratio,
+        })
+    }
+
+    /// Distinct count rule shared by every builder, bounds must satisfy `min <= max`
+    pub(crate) fn distinct_count_between(min: usize, max: usize) -> Result<Self, RuleError> {
+        if min > max {
+            return Err(RuleError::ValidationError(format!(
+                "Invalid distinct count bounds [{}, {}], expected min <= max",
+                min, max
+            )));
+        }
+        Ok(ColumnRule::DistinctCountBetween { min, max })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TableConstraint {
-    DateComparaison { op: CompOperator, threshold: f64 },
-    NumericComparaison { op: CompOperator, threshold: f64 },
+    DateComparaison {
+        op: CompOperator,
+        threshold: f64,
+        /// Largest number of days allowed between both dates
+        max_gap_days: Option<u32>,
+    },
+    NumericComparaison {
+        op: CompOperator,
+        threshold: f64,
+    },
+    StringComparaison {
+        op: CompOperator,
+        threshold: f64,
+    },
+    SumEquals {
+        left: Vec<String>,
+        right: String,
+        tolerance: f64,
+        threshold: f64,
+    },
+    /// `then_rule` must hold on `then_column` for rows where `when_column` equals `when_equals`
+    Conditional {
+        when_column: String,
+        when_equals: String,
+        then_column: String,
+        then_rule: ConditionalRule,
+        threshold: f64,
+    },
+    /// The Pearson correlation of the two columns over all rows must be at least `min_coefficient`
+    Correlation {
+        min_coefficient: f64,
+        threshold: f64,
+    },
+    /// Seconds elapsed from the first timestamp to the second must be within `[min_secs, max_secs]`
+    Duration {
+        min_secs: i64,
+        max_secs: i64,
+        threshold: f64,
+    },
+    /// Every value of the first column must be found among the values of the second one
+    Subset {
+        threshold: f64,
+    },
+}
+
+/// Checks available as the consequent of a conditional relation
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionalRule {
+    NotNull,
+    IsNull,
+    /// Inclusive numeric range, null values are not checked
+    Between {
+        min: f64,
+        max: f64,
+    },
+}
+
+impl std::fmt::Display for ConditionalRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConditionalRule::NotNull => write!(f, "NotNull"),
+            ConditionalRule::IsNull => write!(f, "IsNull"),
+            ConditionalRule::Between { .. } => write!(f, "Between"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupConstraint {
+    SumEquals {
+        expected: HashMap<String, f64>,
+        tolerance: f64,
+        threshold: f64,
+    },
+    /// Each group must hold between `min` and `max` rows, each bound is optional
+    CountBetween {
+        min: Option<usize>,
+        max: Option<usize>,
+        threshold: f64,
+    },
 }
@@ -1,15 +1,25 @@
+use std::collections::HashMap;
 use std::marker::PhantomData;
 
 use crate::{
     columns::{ColumnBuilder, ColumnType, NumericType},
-    ColumnRule,
+    rules::NormalizeMode,
+    ColumnRule, RuleError,
 };
 
+/// A double holds about 15 significant decimal digits
+const MAX_DECIMAL_PLACES: u32 = 15;
+
 #[derive(Debug, Clone)]
 pub struct NumericColumnBuilder<T: NumericType> {
     name: String,
     type_threshold: Option<f64>,
+    optional: bool,
+    // File column read instead of `name`
+    source_name: Option<String>,
     rules: Vec<ColumnRule>,
+    // Labels of the rules, by index in `rules`
+    labels: HashMap<usize, String>,
     _phantom: PhantomData<T>,
 }
 
@@ -22,6 +32,14 @@ impl<T: NumericType> ColumnBuilder for NumericColumnBuilder<T> {
         self.type_threshold.unwrap_or(0.)
     }
 
+    fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    fn source_name(&self) -> Option<&str> {
+        self.source_name.as_deref()
+    }
+
     fn column_type(&self) -> ColumnType {
         T::column_type()
     }
@@ -30,6 +48,10 @@ impl<T: NumericType> ColumnBuilder for NumericColumnBuilder<T> {
         self.rules.as_slice()
     }
 
+    fn rule_labels(&self) -> &HashMap<usize, String> {
+        &self.labels
+    }
+
     fn format(&self) -> Option<&str> {
         None
     }
@@ -40,7 +62,10 @@ impl<T: NumericType> NumericColumnBuilder<T> {
         Self {
             name,
             rules: Vec::new(),
+            labels: HashMap::new(),
             type_threshold: None,
+            optional: false,
+            source_name: None,
             _phantom: PhantomData,
         }
     }
@@ -51,15 +76,89 @@ impl<T: NumericType> NumericColumnBuilder<T> {
         self
     }
 
+    /// Skip the column when it is missing from the file, instead of failing the table
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Read the file column `source_name` when set, results are still reported under the
+    /// column name
+    pub fn with_source_name(mut self, source_name: Option<String>) -> Self {
+        self.source_name = source_name;
+        self
+    }
+
+    /// Label the last added rule, its results carry this human readable name, e.g.
+    /// "Customer name too short"
+    pub fn with_label(&mut self, label: String) -> Result<&mut Self, RuleError> {
+        let Some(index) = self.rules.len().checked_sub(1) else {
+            return Err(RuleError::ValidationError(
+                "A label must follow the rule it names".to_string(),
+            ));
+        };
+        self.labels.insert(index, label);
+        Ok(self)
+    }
+
     /// Add not null constraint
     pub fn is_not_null(&mut self, threshold: f64) -> &mut Self {
         self.rules.push(ColumnRule::NullCheck { threshold });
         self
     }
 
+    /// Fail when every value of the column is null
+    pub fn not_all_null(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllNull);
+        self
+    }
+
+    /// Fail when the column holds less than two distinct values
+    pub fn not_all_same(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllSame);
+        self
+    }
+
+    /// Fail when the share of null values of the column is outside `[min_ratio, max_ratio]`,
+    /// catching both too many nulls and suspiciously none. The ratio is reported
+    pub fn null_ratio_between(
+        &mut self,
+        min_ratio: f64,
+        max_ratio: f64,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::null_ratio_between(min_ratio, max_ratio)?);
+        Ok(self)
+    }
+
+    /// Fail when the estimated number of distinct values of the column is outside
+    /// `[min, max]`, catching a sudden jump in cardinality. The estimate is reported
+    pub fn distinct_count_between(
+        &mut self,
+        min: usize,
+        max: usize,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::distinct_count_between(min, max)?);
+        Ok(self)
+    }
+
     /// Add uniqueness constraint
     pub fn is_unique(&mut self, threshold: f64) -> &mut Self {
-        self.rules.push(ColumnRule::Unicity { threshold });
+        self.rules.push(ColumnRule::Unicity {
+            threshold,
+            normalize: NormalizeMode::None,
+        });
+        self
+    }
+
+    /// Add uniqueness constraint within each group of rows sharing the same `group_column`
+    /// value, e.g. an email unique per tenant but repeated across tenants
+    pub fn is_unique_within(&mut self, group_column: &str, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::UniqueWithinGroup {
+            threshold,
+            group_by: group_column.to_string(),
+        });
         self
     }
 
@@ -70,6 +169,21 @@ impl<T: NumericType> NumericColumnBuilder<T> {
             threshold,
             min: Some(min.to_f64()),
             max: Some(max.to_f64()),
+            min_inclusive: true,
+            max_inclusive: true,
+        });
+        self
+    }
+
+    /// Set numeric range excluding both bounds, e.g. a probability in `(0, 1)`
+    pub fn between_exclusive(&mut self, min: T, max: T, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::NumericRange {
+            name: "BetweenExclusive".to_string(),
+            threshold,
+            min: Some(min.to_f64()),
+            max: Some(max.to_f64()),
+            min_inclusive: false,
+            max_inclusive: false,
         });
         self
     }
@@ -81,6 +195,8 @@ impl<T: NumericType> NumericColumnBuilder<T> {
             threshold,
             min: Some(min.to_f64()),
             max: None,
+            min_inclusive: true,
+            max_inclusive: true,
         });
         self
     }
@@ -92,6 +208,49 @@ impl<T: NumericType> NumericColumnBuilder<T> {
             threshold,
             min: None,
             max: Some(max.to_f64()),
+            min_inclusive: true,
+            max_inclusive: true,
+        });
+        self
+    }
+
+    /// Check if value is in a set of allowed values
+    ///
+    /// Values are compared with exact equality, see [`crate::rules::MembersCheck`]
+    /// for the float caveats.
+    pub fn is_in(&mut self, members: Vec<T>, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::NumericMembers {
+            name: "IsIn".to_string(),
+            members: members.into_iter().map(|m| m.to_f64()).collect(),
+            negate: false,
+            threshold,
+        });
+        self
+    }
+
+    /// Check that every value equals `value`, such as a constant flag
+    ///
+    /// Values are compared with exact equality, see [`crate::rules::MembersCheck`]
+    /// for the float caveats.
+    pub fn equals(&mut self, value: T, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::NumericEquals {
+            name: "Equals".to_string(),
+            expected: value.to_f64(),
+            threshold,
+        });
+        self
+    }
+
+    /// Check if value is outside a set of forbidden values
+    ///
+    /// Values are compared with exact equality, see [`crate::rules::MembersCheck`]
+    /// for the float caveats.
+    pub fn not_in(&mut self, members: Vec<T>, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::NumericMembers {
+            name: "NotIn".to_string(),
+            members: members.into_iter().map(|m| m.to_f64()).collect(),
+            negate: true,
+            threshold,
         });
         self
     }
@@ -103,6 +262,8 @@ impl<T: NumericType> NumericColumnBuilder<T> {
             threshold,
             min: Some(T::positive_threshold()),
             max: None,
+            min_inclusive: true,
+            max_inclusive: true,
         });
         self
     }
@@ -114,6 +275,8 @@ impl<T: NumericType> NumericColumnBuilder<T> {
             threshold,
             min: None,
             max: Some(T::negative_threshold()),
+            min_inclusive: true,
+            max_inclusive: true,
         });
         self
     }
@@ -125,6 +288,8 @@ impl<T: NumericType> NumericColumnBuilder<T> {
             threshold,
             min: Some(0.0),
             max: None,
+            min_inclusive: true,
+            max_inclusive: true,
         });
         self
     }
@@ -136,11 +301,17 @@ impl<T: NumericType> NumericColumnBuilder<T> {
             threshold,
             min: None,
             max: Some(0.0),
+            min_inclusive: true,
+            max_inclusive: true,
         });
         self
     }
 
     /// Check if values are monotonically increasing
+    ///
+    /// Each value is compared to the previous row, rows next to a null are not compared. The
+    /// first value of a batch is compared to the last value of the previous batch. Rows
+    /// breaking the order are sampled with their predecessor.
     pub fn is_monotonically_increasing(&mut self, threshold: f64) -> &mut Self {
         self.rules.push(ColumnRule::Monotonicity {
             name: "IsIncreasing".to_string(),
@@ -150,7 +321,7 @@ impl<T: NumericType> NumericColumnBuilder<T> {
         self
     }
 
-    /// Check if values are monotonically decreasing
+    /// Check if values are monotonically decreasing, see [`Self::is_monotonically_increasing`]
     pub fn is_monotonically_decreasing(&mut self, threshold: f64) -> &mut Self {
         self.rules.push(ColumnRule::Monotonicity {
             name: "IsDecreasing".to_string(),
@@ -179,4 +350,106 @@ impl<T: NumericType> NumericColumnBuilder<T> {
         });
         self
     }
+
+    /// Check that no value lies more than `num_std` standard deviations from the mean
+    ///
+    /// Mean and standard deviation are computed over the whole column and reported
+    /// with the result. A constant column has no outlier.
+    pub fn no_outliers_beyond(
+        &mut self,
+        num_std: f64,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if num_std <= 0.0 {
+            return Err(RuleError::ValidationError(format!(
+                "Number of standard deviations must be positive, got {}",
+                num_std
+            )));
+        }
+        self.rules.push(ColumnRule::ZScoreOutlier {
+            name: "NoOutliersBeyond".to_string(),
+            threshold,
+            num_std,
+        });
+        Ok(self)
+    }
+
+    /// Check that the `quantile` (between 0 and 1) of the column is at most `bound`
+    ///
+    /// The quantile is approximated in a single pass. When it exceeds `bound`, every
+    /// value above `bound` is reported as an error.
+    pub fn percentile_below(
+        &mut self,
+        quantile: f64,
+        bound: f64,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(RuleError::ValidationError(format!(
+                "Quantile must be between 0 and 1, got {}",
+                quantile
+            )));
+        }
+        self.rules.push(ColumnRule::Percentile {
+            name: "PercentileBelow".to_string(),
+            threshold,
+            quantile,
+            bound,
+        });
+        Ok(self)
+    }
+}
+
+impl NumericColumnBuilder<i64> {
+    /// Check that values are a multiple of `divisor`
+    pub fn is_multiple_of(&mut self, divisor: i64, threshold: f64) -> Result<&mut Self, RuleError> {
+        if divisor == 0 {
+            return Err(RuleError::ValidationError(
+                "Divisor cannot be zero".to_string(),
+            ));
+        }
+        self.rules.push(ColumnRule::MultipleOf {
+            name: "IsMultipleOf".to_string(),
+            divisor,
+            threshold,
+        });
+        Ok(self)
+    }
+}
+
+impl NumericColumnBuilder<f64> {
+    /// Check that values are neither `NaN` nor infinite
+    ///
+    /// Range rules compare against bounds, and any comparison with `NaN` is false,
+    /// so a `NaN` passes `between`, `min` and `max`. Combine both rules to reject it.
+    pub fn is_finite(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::IsFinite {
+            name: "IsFinite".to_string(),
+            threshold,
+        });
+        self
+    }
+
+    /// Check that values have at most `places` decimals, e.g. 2 for monetary amounts
+    ///
+    /// Floats are binary, a value is only flagged when rounding it to `places` decimals
+    /// moves it by more than a few ulps, see `DECIMAL_PLACES_EPSILON`.
+    pub fn max_decimal_places(
+        &mut self,
+        places: u32,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if places > MAX_DECIMAL_PLACES {
+            return Err(RuleError::ValidationError(format!(
+                "Decimal places must be at most {}, got {}",
+                MAX_DECIMAL_PLACES, places
+            )));
+        }
+        self.rules.push(ColumnRule::MaxDecimalPlaces {
+            name: "MaxDecimalPlaces".to_string(),
+            places,
+            threshold,
+        });
+        Ok(self)
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{columns::TableConstraint, utils::operator::CompOperator};
+use crate::{
+    columns::{ConditionalRule, TableConstraint},
+    utils::operator::CompOperator,
+    RuleError,
+};
 
 pub struct RelationBuilder {
     pub names: [String; 2],
@@ -21,9 +25,19 @@ impl RelationBuilder {
         [self.names[0].clone(), self.names[1].clone()]
     }
 
-    pub fn date_comparaison(&mut self, op: CompOperator, threshold: f64) -> &mut Self {
-        self.rules
-            .push(TableConstraint::DateComparaison { op, threshold });
+    /// Compare two date columns row by row, with `max_gap_days` rows whose dates are more
+    /// days apart also count as violations
+    pub fn date_comparaison(
+        &mut self,
+        op: CompOperator,
+        max_gap_days: Option<u32>,
+        threshold: f64,
+    ) -> &mut Self {
+        self.rules.push(TableConstraint::DateComparaison {
+            op,
+            threshold,
+            max_gap_days,
+        });
         self
     }
 
@@ -32,4 +46,121 @@ impl RelationBuilder {
             .push(TableConstraint::NumericComparaison { op, threshold });
         self
     }
+
+    /// Compare two string columns row by row, only `Eq` and `Neq` are supported
+    pub fn string_comparaison(&mut self, op: CompOperator, threshold: f64) -> &mut Self {
+        self.rules
+            .push(TableConstraint::StringComparaison { op, threshold });
+        self
+    }
+
+    /// Check that the first column plus `addends` equals the second column, within `tolerance`
+    ///
+    /// Rows where any of the columns is null are skipped and counted apart.
+    pub fn sum_equals(
+        &mut self,
+        addends: Vec<String>,
+        tolerance: f64,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if tolerance < 0.0 {
+            return Err(RuleError::ValidationError(format!(
+                "Tolerance must be positive, got {}",
+                tolerance
+            )));
+        }
+        let mut left = vec![self.names[0].clone()];
+        left.extend(addends);
+        self.rules.push(TableConstraint::SumEquals {
+            left,
+            right: self.names[1].clone(),
+            tolerance,
+            threshold,
+        });
+        Ok(self)
+    }
+
+    /// Check `then_rule` on the second column for rows where the first column equals `when_equals`
+    ///
+    /// Values of the first column are compared as text. Rows where it is null do not match the
+    /// condition and are not checked.
+    pub fn conditional(
+        &mut self,
+        when_equals: String,
+        then_rule: ConditionalRule,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if let ConditionalRule::Between { min, max } = then_rule {
+            if min > max {
+                return Err(RuleError::ValidationError(format!(
+                    "Min ({}) must be less than or equal to max ({})",
+                    min, max
+                )));
+            }
+        }
+        self.rules.push(TableConstraint::Conditional {
+            when_column: self.names[0].clone(),
+            when_equals,
+            then_column: self.names[1].clone(),
+            then_rule,
+            threshold,
+        });
+        Ok(self)
+    }
+
+    /// Check that the Pearson correlation of the two numeric columns is at least `min_coefficient`
+    ///
+    /// The coefficient is computed over all rows where both values are present and reported
+    /// in the result. When it is below the floor, every compared row counts as an error.
+    pub fn correlation(
+        &mut self,
+        min_coefficient: f64,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if !(-1.0..=1.0).contains(&min_coefficient) {
+            return Err(RuleError::ValidationError(format!(
+                "Minimum coefficient must be between -1 and 1, got {}",
+                min_coefficient
+            )));
+        }
+        self.rules.push(TableConstraint::Correlation {
+            min_coefficient,
+            threshold,
+        });
+        Ok(self)
+    }
+
+    /// Check that the seconds elapsed from the first timestamp column to the second are within
+    /// `[min_secs, max_secs]`
+    ///
+    /// Rows where either timestamp is null or could not be parsed are skipped and counted apart.
+    pub fn duration_between(
+        &mut self,
+        min_secs: i64,
+        max_secs: i64,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if min_secs > max_secs {
+            return Err(RuleError::ValidationError(format!(
+                "Min ({}) must be less than or equal to max ({})",
+                min_secs, max_secs
+            )));
+        }
+        self.rules.push(TableConstraint::Duration {
+            min_secs,
+            max_secs,
+            threshold,
+        });
+        Ok(self)
+    }
+
+    /// Check that every value of the first column is found among the values of the second
+    /// one, such as a child category always being one of the parent categories
+    ///
+    /// Both columns are accumulated over the whole table before being compared. Rows holding
+    /// a missing value are counted, null values are not checked.
+    pub fn is_subset_of(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(TableConstraint::Subset { threshold });
+        self
+    }
 }
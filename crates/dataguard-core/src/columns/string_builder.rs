@@ -1,14 +1,27 @@
+use std::collections::HashMap;
+
 use crate::{
     columns::{ColumnBuilder, ColumnRule, ColumnType},
     errors::RuleError,
+    rules::{
+        generic::NormalizeMode,
+        string::{
+            build_regex, parse_cidrs, parse_phone_region, IpVersion, JsonKind, LengthMode,
+            MatchesAny,
+        },
+    },
 };
-use regex::Regex;
 
 #[derive(Debug, Clone)]
 pub struct StringColumnBuilder {
     name: String,
     type_threshold: Option<f64>,
+    optional: bool,
+    // File column read instead of `name`
+    source_name: Option<String>,
     rules: Vec<ColumnRule>,
+    // Labels of the rules, by index in `rules`
+    labels: HashMap<usize, String>,
 }
 
 impl ColumnBuilder for StringColumnBuilder {
@@ -20,6 +33,14 @@ impl ColumnBuilder for StringColumnBuilder {
         self.type_threshold.unwrap_or(0.)
     }
 
+    fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    fn source_name(&self) -> Option<&str> {
+        self.source_name.as_deref()
+    }
+
     fn column_type(&self) -> ColumnType {
         ColumnType::String
     }
@@ -28,17 +49,32 @@ impl ColumnBuilder for StringColumnBuilder {
         self.rules.as_slice()
     }
 
+    fn rule_labels(&self) -> &HashMap<usize, String> {
+        &self.labels
+    }
+
     fn format(&self) -> Option<&str> {
         None
     }
 }
 
+fn length_mode(count_bytes: bool) -> LengthMode {
+    if count_bytes {
+        LengthMode::Bytes
+    } else {
+        LengthMode::Chars
+    }
+}
+
 impl StringColumnBuilder {
     pub fn new(name: String) -> Self {
         Self {
             name,
             type_threshold: None,
+            optional: false,
+            source_name: None,
             rules: Vec::new(),
+            labels: HashMap::new(),
         }
     }
 
@@ -48,15 +84,90 @@ impl StringColumnBuilder {
         self
     }
 
+    /// Skip the column when it is missing from the file, instead of failing the table
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Read the file column `source_name` when set, results are still reported under the
+    /// column name
+    pub fn with_source_name(mut self, source_name: Option<String>) -> Self {
+        self.source_name = source_name;
+        self
+    }
+
+    /// Label the last added rule, its results carry this human readable name, e.g.
+    /// "Customer name too short"
+    pub fn with_label(&mut self, label: String) -> Result<&mut Self, RuleError> {
+        let Some(index) = self.rules.len().checked_sub(1) else {
+            return Err(RuleError::ValidationError(
+                "A label must follow the rule it names".to_string(),
+            ));
+        };
+        self.labels.insert(index, label);
+        Ok(self)
+    }
+
     /// Add not null constraint
     pub fn is_not_null(&mut self, threshold: f64) -> &mut Self {
         self.rules.push(ColumnRule::NullCheck { threshold });
         self
     }
 
-    /// Add uniqueness constraint
-    pub fn is_unique(&mut self, threshold: f64) -> &mut Self {
-        self.rules.push(ColumnRule::Unicity { threshold });
+    /// Fail when every value of the column is null
+    pub fn not_all_null(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllNull);
+        self
+    }
+
+    /// Fail when the column holds less than two distinct values
+    pub fn not_all_same(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllSame);
+        self
+    }
+
+    /// Fail when the share of null values of the column is outside `[min_ratio, max_ratio]`,
+    /// catching both too many nulls and suspiciously none. The ratio is reported
+    pub fn null_ratio_between(
+        &mut self,
+        min_ratio: f64,
+        max_ratio: f64,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::null_ratio_between(min_ratio, max_ratio)?);
+        Ok(self)
+    }
+
+    /// Fail when the estimated number of distinct values of the column is outside
+    /// `[min, max]`, catching a sudden jump in cardinality. The estimate is reported
+    pub fn distinct_count_between(
+        &mut self,
+        min: usize,
+        max: usize,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::distinct_count_between(min, max)?);
+        Ok(self)
+    }
+
+    /// Add uniqueness constraint, values are compared once normalized: with
+    /// [`NormalizeMode::TrimLowercase`], `Foo@x.com` and ` foo@x.com` are duplicates
+    pub fn is_unique(&mut self, normalize: NormalizeMode, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::Unicity {
+            threshold,
+            normalize,
+        });
+        self
+    }
+
+    /// Add uniqueness constraint within each group of rows sharing the same `group_column`
+    /// value, e.g. an email unique per tenant but repeated across tenants
+    pub fn is_unique_within(&mut self, group_column: &str, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::UniqueWithinGroup {
+            threshold,
+            group_by: group_column.to_string(),
+        });
         self
     }
 
@@ -67,28 +178,48 @@ impl StringColumnBuilder {
             threshold,
             min: Some(min),
             max: Some(max),
+            mode: LengthMode::Chars,
+        });
+        self
+    }
+
+    /// Set length constraints (both min and max) counted in UTF-16 code units
+    pub fn with_utf16_length_between(
+        &mut self,
+        min: usize,
+        max: usize,
+        threshold: f64,
+    ) -> &mut Self {
+        self.rules.push(ColumnRule::StringLength {
+            name: "WithUtf16LengthBetween".to_string(),
+            threshold,
+            min: Some(min),
+            max: Some(max),
+            mode: LengthMode::Utf16,
         });
         self
     }
 
-    /// Set minimum length
-    pub fn with_min_length(&mut self, min: usize, threshold: f64) -> &mut Self {
+    /// Set minimum length, counted in UTF-8 bytes instead of characters if `count_bytes`
+    pub fn with_min_length(&mut self, min: usize, count_bytes: bool, threshold: f64) -> &mut Self {
         self.rules.push(ColumnRule::StringLength {
             name: "WithMinLength".to_string(),
             threshold,
             min: Some(min),
             max: None,
+            mode: length_mode(count_bytes),
         });
         self
     }
 
-    /// Set maximum length
-    pub fn with_max_length(&mut self, max: usize, threshold: f64) -> &mut Self {
+    /// Set maximum length, counted in UTF-8 bytes instead of characters if `count_bytes`
+    pub fn with_max_length(&mut self, max: usize, count_bytes: bool, threshold: f64) -> &mut Self {
         self.rules.push(ColumnRule::StringLength {
             name: "WithMaxLength".to_string(),
             threshold,
             min: None,
             max: Some(max),
+            mode: length_mode(count_bytes),
         });
         self
     }
@@ -100,6 +231,7 @@ impl StringColumnBuilder {
             threshold,
             min: Some(len),
             max: Some(len),
+            mode: LengthMode::Chars,
         });
         self
     }
@@ -114,17 +246,125 @@ impl StringColumnBuilder {
         self
     }
 
+    /// Check that no value is in a set of forbidden values, nulls pass
+    pub fn is_not_in(&mut self, members: Vec<String>, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::StringNotMembers {
+            name: "NotIn".to_string(),
+            threshold,
+            members,
+        });
+        self
+    }
+
+    /// Check that every value is exactly `value`, such as a constant schema version
+    pub fn equals(&mut self, value: String, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::StringEquals {
+            name: "Equals".to_string(),
+            threshold,
+            expected: value,
+        });
+        self
+    }
+
+    /// Check that values begin with `prefix`
+    pub fn with_prefix(&mut self, prefix: String, threshold: f64) -> Result<&mut Self, RuleError> {
+        if prefix.is_empty() {
+            return Err(RuleError::ValidationError(
+                "Prefix cannot be empty".to_string(),
+            ));
+        }
+        self.rules.push(ColumnRule::StartsWith {
+            name: "WithPrefix".to_string(),
+            threshold,
+            prefix,
+        });
+        Ok(self)
+    }
+
+    /// Check that values end with `suffix`
+    pub fn with_suffix(&mut self, suffix: String, threshold: f64) -> Result<&mut Self, RuleError> {
+        if suffix.is_empty() {
+            return Err(RuleError::ValidationError(
+                "Suffix cannot be empty".to_string(),
+            ));
+        }
+        self.rules.push(ColumnRule::EndsWith {
+            name: "WithSuffix".to_string(),
+            threshold,
+            suffix,
+        });
+        Ok(self)
+    }
+
+    /// Check that values contain `substring`
+    ///
+    /// The only supported flag is `i`, for a case-insensitive match.
+    pub fn contains(
+        &mut self,
+        substring: String,
+        flag: Option<String>,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        self.with_substring("Contains".to_string(), substring, false, flag, threshold)
+    }
+
+    /// Check that values do not contain `substring`
+    ///
+    /// The only supported flag is `i`, for a case-insensitive match.
+    pub fn not_contains(
+        &mut self,
+        substring: String,
+        flag: Option<String>,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        self.with_substring("NotContains".to_string(), substring, true, flag, threshold)
+    }
+
+    fn with_substring(
+        &mut self,
+        name: String,
+        substring: String,
+        negate: bool,
+        flag: Option<String>,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if substring.is_empty() {
+            return Err(RuleError::ValidationError(
+                "Substring cannot be empty".to_string(),
+            ));
+        }
+        let case_insensitive = match flag.as_deref() {
+            None | Some("") => false,
+            Some("i") => true,
+            Some(other) => {
+                return Err(RuleError::ValidationError(format!(
+                    "Unsupported flag '{}' for {}, expected 'i'",
+                    other, name
+                )))
+            }
+        };
+        self.rules.push(ColumnRule::Contains {
+            name,
+            threshold,
+            substring,
+            negate,
+            case_insensitive,
+        });
+        Ok(self)
+    }
+
     /// Match against a regex pattern
+    ///
+    /// `flags` can combine `i` (case-insensitive), `m` (multi-line), `s` (`.` matches a
+    /// newline) and `u` (Unicode classes, the default), e.g. `"im"`.
     pub fn with_regex(
         &mut self,
         pattern: String,
         flags: Option<String>,
         threshold: f64,
     ) -> Result<&mut Self, RuleError> {
-        // Validate regex at build time
-        Regex::new(&pattern).map_err(|e| {
-            RuleError::ValidationError(format!("Invalid regex pattern '{}': {}", pattern, e))
-        })?;
+        // Validate regex and flags at build time
+        build_regex(&pattern, flags.as_deref())?;
         self.rules.push(ColumnRule::StringRegex {
             name: "WithRegex".to_string(),
             threshold,
@@ -134,6 +374,26 @@ impl StringColumnBuilder {
         Ok(self)
     }
 
+    /// Match against any of several regex patterns, a value fails if it matches none
+    ///
+    /// `flags` apply to every pattern, see [`with_regex`](Self::with_regex).
+    pub fn matches_any_of(
+        &mut self,
+        patterns: Vec<String>,
+        flags: Option<String>,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        // Validate every pattern and the flags at build time
+        MatchesAny::new(String::new(), threshold, patterns.clone(), flags.clone())?;
+        self.rules.push(ColumnRule::StringRegexAny {
+            name: "MatchesAnyOf".to_string(),
+            threshold,
+            patterns,
+            flags,
+        });
+        Ok(self)
+    }
+
     fn with_defined_regex(
         &mut self,
         name: String,
@@ -141,10 +401,8 @@ impl StringColumnBuilder {
         flags: Option<String>,
         threshold: f64,
     ) -> Result<&mut Self, RuleError> {
-        // Validate regex at build time
-        Regex::new(&pattern).map_err(|e| {
-            RuleError::ValidationError(format!("Invalid regex pattern '{}': {}", pattern, e))
-        })?;
+        // Validate regex and flags at build time
+        build_regex(&pattern, flags.as_deref())?;
         self.rules.push(ColumnRule::StringRegex {
             name,
             threshold,
@@ -185,23 +443,80 @@ impl StringColumnBuilder {
     }
 
     /// Check if string is lowercase
-    pub fn is_lowercase(&mut self, threshold: f64) -> Result<&mut Self, RuleError> {
-        self.with_defined_regex(
-            "IsLowerCase".to_string(),
-            r"^[a-z0-9\s-]+$".to_string(),
-            None,
+    ///
+    /// Only ASCII letters are accepted unless `unicode` is set.
+    pub fn is_lowercase(&mut self, unicode: bool, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::StringCase {
+            name: "IsLowerCase".to_string(),
             threshold,
-        )
+            upper: false,
+            unicode,
+        });
+        self
     }
 
     /// Check if string is uppercase
-    pub fn is_uppercase(&mut self, threshold: f64) -> Result<&mut Self, RuleError> {
-        self.with_defined_regex(
-            "IsUpperCase".to_string(),
-            r"^[A-Z0-9\s-]+$".to_string(),
-            None,
+    ///
+    /// Only ASCII letters are accepted unless `unicode` is set.
+    pub fn is_uppercase(&mut self, unicode: bool, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::StringCase {
+            name: "IsUpperCase".to_string(),
             threshold,
-        )
+            upper: true,
+            unicode,
+        });
+        self
+    }
+
+    /// Check that values have no leading or trailing whitespace
+    pub fn has_no_surrounding_whitespace(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::NoSurroundingWhitespace {
+            name: "NoSurroundingWhitespace".to_string(),
+            threshold,
+        });
+        self
+    }
+
+    /// Check that values contain no whitespace at all
+    pub fn has_no_whitespace(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::NoWhitespace {
+            name: "NoWhitespace".to_string(),
+            threshold,
+        });
+        self
+    }
+
+    /// Check that values only use characters of `allowed`, e.g. `ACGT` for DNA sequences
+    ///
+    /// Empty strings and nulls pass. An empty `allowed` set is rejected.
+    pub fn contains_only(
+        &mut self,
+        allowed: String,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        if allowed.is_empty() {
+            return Err(RuleError::ValidationError(
+                "contains_only needs at least one allowed character".to_string(),
+            ));
+        }
+        self.rules.push(ColumnRule::ContainsOnly {
+            name: "ContainsOnly".to_string(),
+            threshold,
+            allowed_chars: allowed.chars().collect(),
+        });
+        Ok(self)
+    }
+
+    /// Check that values share the column's dominant length, give or take `tolerance` characters.
+    ///
+    /// Lengths are counted over the whole column, the dominant length is reported.
+    pub fn consistent_length(&mut self, tolerance: usize, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::StringConsistentLength {
+            name: "ConsistentLength".to_string(),
+            threshold,
+            tolerance,
+        });
+        self
     }
 
     /// Check if string is a valid URL
@@ -215,13 +530,17 @@ impl StringColumnBuilder {
     }
 
     /// Check if string is a valid email
-    pub fn is_email(&mut self, threshold: f64) -> Result<&mut Self, RuleError> {
-        self.with_defined_regex(
-            "IsEmail".to_string(),
-            r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$".to_string(),
-            None,
-            threshold,
-        )
+    ///
+    /// With `strict`, the domain must also be made of hostname labels, neither empty nor
+    /// starting or ending with a hyphen, and the local part can not start, end or repeat a dot.
+    /// Both levels are purely syntactic, the domain is never resolved.
+    pub fn is_email(&mut self, strict: bool, threshold: f64) -> Result<&mut Self, RuleError> {
+        let pattern = if strict {
+            r"^[a-zA-Z0-9_%+-]+(\.[a-zA-Z0-9_%+-]+)*@([a-zA-Z0-9]([a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?\.)+[a-zA-Z]{2,63}$"
+        } else {
+            r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$"
+        };
+        self.with_defined_regex("IsEmail".to_string(), pattern.to_string(), None, threshold)
     }
 
     /// Check if string is a valid UUID
@@ -234,4 +553,132 @@ impl StringColumnBuilder {
             threshold,
         )
     }
+
+    /// Check if string is a valid IPv4 address
+    pub fn is_ipv4(&mut self, threshold: f64) -> &mut Self {
+        self.with_ip_version("IsIpv4".to_string(), IpVersion::V4, threshold)
+    }
+
+    /// Check if string is a valid IPv6 address
+    pub fn is_ipv6(&mut self, threshold: f64) -> &mut Self {
+        self.with_ip_version("IsIpv6".to_string(), IpVersion::V6, threshold)
+    }
+
+    /// Check if string is a valid IPv4 or IPv6 address
+    pub fn is_ip(&mut self, threshold: f64) -> &mut Self {
+        self.with_ip_version("IsIp".to_string(), IpVersion::Any, threshold)
+    }
+
+    /// Check that values are IP addresses within one of the `cidrs` blocks, e.g. `10.0.0.0/8`
+    ///
+    /// Values that are not IP addresses fail, nulls pass. Malformed blocks are rejected here.
+    pub fn ip_in_cidr(
+        &mut self,
+        cidrs: Vec<String>,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        let allowed = parse_cidrs(&cidrs)?;
+        self.rules.push(ColumnRule::IpInCidr {
+            name: "IpInCidr".to_string(),
+            threshold,
+            allowed,
+        });
+        Ok(self)
+    }
+
+    fn with_ip_version(&mut self, name: String, version: IpVersion, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::IpAddress {
+            name,
+            threshold,
+            version,
+        });
+        self
+    }
+
+    /// Check if string is a parseable JSON document
+    pub fn is_json(&mut self, threshold: f64) -> &mut Self {
+        self.with_json_kind("IsJson".to_string(), JsonKind::Any, threshold)
+    }
+
+    /// Check if string is a JSON document holding an object at the top level
+    pub fn is_json_object(&mut self, threshold: f64) -> &mut Self {
+        self.with_json_kind("IsJsonObject".to_string(), JsonKind::Object, threshold)
+    }
+
+    /// Check if string is a JSON document holding an array at the top level
+    pub fn is_json_array(&mut self, threshold: f64) -> &mut Self {
+        self.with_json_kind("IsJsonArray".to_string(), JsonKind::Array, threshold)
+    }
+
+    fn with_json_kind(&mut self, name: String, kind: JsonKind, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::Json {
+            name,
+            threshold,
+            kind,
+        });
+        self
+    }
+
+    /// Check if string is a structurally valid card number, passing the Luhn checksum
+    pub fn is_credit_card(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::CreditCard {
+            name: "IsCreditCard".to_string(),
+            threshold,
+        });
+        self
+    }
+
+    /// Check if string is a structurally valid IBAN, passing the mod-97 check
+    pub fn is_iban(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::Iban {
+            name: "IsIban".to_string(),
+            threshold,
+        });
+        self
+    }
+
+    /// Check if string is standard base64
+    ///
+    /// With `strict_padding`, values must be padded with `=` to a multiple of 4 characters.
+    pub fn is_base64(&mut self, strict_padding: bool, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::Base64 {
+            name: "IsBase64".to_string(),
+            threshold,
+            strict_padding,
+        });
+        self
+    }
+
+    /// Check if string is an even number of hexadecimal digits
+    ///
+    /// A leading `0x` is only accepted when `allow_prefix` is set.
+    pub fn is_hex(&mut self, allow_prefix: bool, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::Hex {
+            name: "IsHex".to_string(),
+            threshold,
+            allow_prefix,
+        });
+        self
+    }
+
+    /// Check if string is a valid phone number, of `region` (ISO 3166 code such as `FR`)
+    /// when given, in international format otherwise
+    ///
+    /// Numbers are parsed against the full numbering plan metadata, expect this rule to be
+    /// much slower than a regex on large columns.
+    pub fn is_phone(
+        &mut self,
+        region: Option<&str>,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        let region = region
+            .map(|r| parse_phone_region(r).map(|id| id.as_ref().to_string()))
+            .transpose()?;
+        self.rules.push(ColumnRule::IsPhone {
+            name: "IsPhone".to_string(),
+            region,
+            threshold,
+        });
+        Ok(self)
+    }
 }
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::{
+    columns::ColumnBuilder,
+    rules::{
+        timestamp::{check_time_window, parse_timestamp_literal},
+        NormalizeMode,
+    },
+    ColumnRule, ColumnType, RuleError,
+};
+
+/// Builder for columns holding a date and a time of day, kept at millisecond precision.
+#[derive(Debug, Clone)]
+pub struct TimestampColumnBuilder {
+    name: String,
+    type_threshold: Option<f64>,
+    optional: bool,
+    // File column read instead of `name`
+    source_name: Option<String>,
+    format: String,
+    rules: Vec<ColumnRule>,
+    // Labels of the rules, by index in `rules`
+    labels: HashMap<usize, String>,
+}
+
+impl ColumnBuilder for TimestampColumnBuilder {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn type_threshold(&self) -> f64 {
+        self.type_threshold.unwrap_or(0.)
+    }
+
+    fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    fn source_name(&self) -> Option<&str> {
+        self.source_name.as_deref()
+    }
+
+    fn column_type(&self) -> ColumnType {
+        ColumnType::Timestamp
+    }
+
+    fn rules(&self) -> &[ColumnRule] {
+        self.rules.as_slice()
+    }
+
+    fn rule_labels(&self) -> &HashMap<usize, String> {
+        &self.labels
+    }
+
+    fn format(&self) -> Option<&str> {
+        Some(&self.format)
+    }
+}
+
+impl TimestampColumnBuilder {
+    /// Create a timestamp column parsed with a chrono `format`, e.g. `%Y-%m-%d %H:%M:%S`
+    pub fn new(name: String, format: String) -> Self {
+        Self {
+            name,
+            format,
+            type_threshold: None,
+            optional: false,
+            source_name: None,
+            rules: Vec::new(),
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Set the type checking threshold
+    pub fn with_type_threshold(mut self, threshold: f64) -> Self {
+        self.type_threshold = Some(threshold);
+        self
+    }
+
+    /// Skip the column when it is missing from the file, instead of failing the table
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    /// Read the file column `source_name` when set, results are still reported under the
+    /// column name
+    pub fn with_source_name(mut self, source_name: Option<String>) -> Self {
+        self.source_name = source_name;
+        self
+    }
+
+    /// Label the last added rule, its results carry this human readable name, e.g.
+    /// "Customer name too short"
+    pub fn with_label(&mut self, label: String) -> Result<&mut Self, RuleError> {
+        let Some(index) = self.rules.len().checked_sub(1) else {
+            return Err(RuleError::ValidationError(
+                "A label must follow the rule it names".to_string(),
+            ));
+        };
+        self.labels.insert(index, label);
+        Ok(self)
+    }
+
+    pub fn get_format(&self) -> String {
+        self.format.clone()
+    }
+
+    /// Add not null constraint
+    pub fn is_not_null(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::NullCheck { threshold });
+        self
+    }
+
+    /// Fail when every value of the column is null
+    pub fn not_all_null(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllNull);
+        self
+    }
+
+    /// Fail when the column holds less than two distinct values
+    pub fn not_all_same(&mut self) -> &mut Self {
+        self.rules.push(ColumnRule::NotAllSame);
+        self
+    }
+
+    /// Fail when the share of null values of the column is outside `[min_ratio, max_ratio]`,
+    /// catching both too many nulls and suspiciously none. The ratio is reported
+    pub fn null_ratio_between(
+        &mut self,
+        min_ratio: f64,
+        max_ratio: f64,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::null_ratio_between(min_ratio, max_ratio)?);
+        Ok(self)
+    }
+
+    /// Fail when the estimated number of distinct values of the column is outside
+    /// `[min, max]`, catching a sudden jump in cardinality. The estimate is reported
+    pub fn distinct_count_between(
+        &mut self,
+        min: usize,
+        max: usize,
+    ) -> Result<&mut Self, RuleError> {
+        self.rules
+            .push(ColumnRule::distinct_count_between(min, max)?);
+        Ok(self)
+    }
+
+    /// Add uniqueness constraint
+    pub fn is_unique(&mut self, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::Unicity {
+            threshold,
+            normalize: NormalizeMode::None,
+        });
+        self
+    }
+
+    /// Add uniqueness constraint within each group of rows sharing the same `group_column`
+    /// value, e.g. an email unique per tenant but repeated across tenants
+    pub fn is_unique_within(&mut self, group_column: &str, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::UniqueWithinGroup {
+            threshold,
+            group_by: group_column.to_string(),
+        });
+        self
+    }
+
+    /// Set a limit, the timestamp should be before `timestamp` (`YYYY-MM-DD HH:MM:SS`)
+    pub fn is_before(&mut self, timestamp: &str, threshold: f64) -> Result<&mut Self, RuleError> {
+        let millis = parse_timestamp_literal(timestamp)?;
+        self.rules.push(ColumnRule::TimestampBoundary {
+            name: "IsBefore".to_string(),
+            threshold,
+            after: false,
+            millis,
+        });
+        Ok(self)
+    }
+
+    /// Set a limit, the timestamp should be after `timestamp` (`YYYY-MM-DD HH:MM:SS`)
+    pub fn is_after(&mut self, timestamp: &str, threshold: f64) -> Result<&mut Self, RuleError> {
+        let millis = parse_timestamp_literal(timestamp)?;
+        self.rules.push(ColumnRule::TimestampBoundary {
+            name: "IsAfter".to_string(),
+            threshold,
+            after: true,
+            millis,
+        });
+        Ok(self)
+    }
+
+    /// Check that all timestamps are before `reference_date` at midnight, the current
+    /// local time when `None`
+    pub fn is_not_futur(&mut self, reference_date: Option<NaiveDate>, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::TimestampBoundary {
+            name: "IsNotFutur".to_string(),
+            threshold,
+            after: false,
+            millis: reference_millis(reference_date),
+        });
+        self
+    }
+
+    /// Check that all timestamps are after `reference_date` at midnight, the current
+    /// local time when `None`
+    pub fn is_not_past(&mut self, reference_date: Option<NaiveDate>, threshold: f64) -> &mut Self {
+        self.rules.push(ColumnRule::TimestampBoundary {
+            name: "IsNotPast".to_string(),
+            threshold,
+            after: true,
+            millis: reference_millis(reference_date),
+        });
+        self
+    }
+
+    /// Check that the time of day is within `[start_hour, end_hour)`, the window
+    /// wraps around midnight when `start_hour > end_hour`
+    pub fn time_between(
+        &mut self,
+        start_hour: u32,
+        end_hour: u32,
+        threshold: f64,
+    ) -> Result<&mut Self, RuleError> {
+        check_time_window(start_hour, end_hour)?;
+        self.rules.push(ColumnRule::TimeOfDay {
+            name: "TimeBetween".to_string(),
+            threshold,
+            start_hour,
+            end_hour,
+        });
+        Ok(self)
+    }
+}
+
+/// Timestamps are parsed without timezone, compare them with the local wall clock
+fn reference_millis(reference_date: Option<NaiveDate>) -> i64 {
+    match reference_date {
+        Some(date) => date.and_time(NaiveTime::MIN),
+        None => chrono::Local::now().naive_local(),
+    }
+    .and_utc()
+    .timestamp_millis()
+}
@@ -8,7 +8,7 @@
 
 use std::{collections::HashMap, fmt::Debug};
 
-use arrow::datatypes::{DataType, Date32Type, Float64Type, Int64Type};
+use arrow::datatypes::{DataType, Date32Type, Float64Type, Int64Type, TimestampMillisecondType};
 use arrow_array::ArrowNumericType;
 use num_traits::{Num, NumCast};
 
@@ -16,21 +16,43 @@ use num_traits::{Num, NumCast};
 mod tests;
 
 use crate::{
-    columns::{relation_builder::RelationBuilder, ColumnBuilder, NumericType, TableConstraint},
+    columns::{
+        group_builder::GroupBuilder, relation_builder::RelationBuilder, ColumnBuilder,
+        ConditionalRule, GroupConstraint, NumericType, TableConstraint,
+    },
     rules::{
-        date::{DateBoundaryCheck, DateRule, DateTypeCheck},
-        numeric::{MeanVarianceCheck, StdDevCheck},
-        relations::{CompareCheck, RelationRule},
-        IsInCheck, Monotonicity, NullCheck, NumericRule, Range, RegexMatch, StringLengthCheck,
-        StringRule, TypeCheck, UnicityCheck, WeekDayCheck,
+        boolean::{BooleanRule, BooleanTypeCheck, ExpectedValueCheck, TrueRatioCheck},
+        date::{
+            AgeCheck, DateBoundaryCheck, DateRangeCheck, DateRule, DateTypeCheck, DayOfMonthCheck,
+        },
+        numeric::{
+            FiniteCheck, MaxDecimalPlacesCheck, MeanVarianceCheck, MultipleOfCheck,
+            PercentileCheck, StdDevCheck, ZScoreOutlierCheck,
+        },
+        relations::{
+            CompareCheck, ConditionalCheck, CorrelationCheck, DurationCheck, RelationRule,
+            StringCompareCheck, SubsetCheck, SumEqualsCheck,
+        },
+        timestamp::{TimeOfDayCheck, TimestampBoundaryCheck, TimestampRule, TimestampTypeCheck},
+        Base64Check, CaseCheck, ConsistentLengthCheck, ContainsCheck, ContainsOnly,
+        CreditCardCheck, EndsWithCheck, EqualsConstant, GroupCountCheck, GroupSumCheck,
+        GroupedUnicityCheck, HexCheck, IbanCheck, IpCheck, IpInCidr, IsInCheck, IsNotInCheck,
+        JsonCheck, MatchesAny, MembersCheck, Monotonicity, NoSurroundingWhitespace, NoWhitespace,
+        NullCheck, NumericRule, PhoneCheck, Range, RegexMatch, SanityCheck, StartsWithCheck,
+        StringLengthCheck, StringRule, TypeCheck, UnicityCheck, WeekDayCheck,
     },
-    validator::{ExecutableColumn, ExecutableRelation},
+    utils::operator::CompOperator,
+    validator::{ExecutableColumn, ExecutableGroup, ExecutableRelation},
     ColumnRule, ColumnType, RuleError,
 };
 
+/// Date gaps are given in days, timestamps are compared in milliseconds
+const MILLISECONDS_PER_DAY: u64 = 86_400_000;
+
 /// Compile string column rules into executable validators.
 ///
-/// Separates domain rules (length, regex, membership) from meta-rules (unicity, null check).
+/// Separates domain rules (length, regex, membership, affixes) from column-level length
+/// distribution rules and meta-rules (unicity, null check).
 /// Returns an error if a non-string rule is found.
 #[allow(clippy::type_complexity)]
 fn compile_string_rules(
@@ -39,12 +61,14 @@ fn compile_string_rules(
 ) -> Result<
     (
         Vec<Box<dyn StringRule>>,
+        Vec<ConsistentLengthCheck>,
         Option<UnicityCheck>,
         Option<NullCheck>,
     ),
     RuleError,
 > {
     let mut executable_rules: Vec<Box<dyn StringRule>> = Vec::new();
+    let mut length_rules = Vec::new();
     let mut unicity_check = None;
     let mut null_check = None;
 
@@ -55,13 +79,11 @@ fn compile_string_rules(
                 threshold,
                 min,
                 max,
+                mode,
             } => {
-                executable_rules.push(Box::new(StringLengthCheck::new(
-                    name.clone(),
-                    *threshold,
-                    *min,
-                    *max,
-                )));
+                executable_rules.push(Box::new(
+                    StringLengthCheck::new(name.clone(), *threshold, *min, *max).with_mode(*mode),
+                ));
             }
             ColumnRule::StringRegex {
                 name,
@@ -74,6 +96,30 @@ fn compile_string_rules(
                     *threshold,
                     pattern.clone(),
                     flags.clone(),
+                )?));
+            }
+            ColumnRule::StringRegexAny {
+                name,
+                threshold,
+                patterns,
+                flags,
+            } => {
+                executable_rules.push(Box::new(MatchesAny::new(
+                    name.clone(),
+                    *threshold,
+                    patterns.clone(),
+                    flags.clone(),
+                )?));
+            }
+            ColumnRule::StringEquals {
+                name,
+                threshold,
+                expected,
+            } => {
+                executable_rules.push(Box::new(EqualsConstant::new(
+                    name.clone(),
+                    *threshold,
+                    expected.clone(),
                 )));
             }
             ColumnRule::StringMembers {
@@ -87,9 +133,175 @@ fn compile_string_rules(
                     members.to_vec(),
                 )));
             }
-            ColumnRule::Unicity { threshold } => {
-                unicity_check = Some(UnicityCheck::new(*threshold));
+            ColumnRule::StringNotMembers {
+                name,
+                threshold,
+                members,
+            } => {
+                executable_rules.push(Box::new(IsNotInCheck::new(
+                    name.clone(),
+                    *threshold,
+                    members.to_vec(),
+                )));
+            }
+            ColumnRule::StartsWith {
+                name,
+                threshold,
+                prefix,
+            } => {
+                executable_rules.push(Box::new(StartsWithCheck::new(
+                    name.clone(),
+                    *threshold,
+                    prefix.clone(),
+                )));
+            }
+            ColumnRule::EndsWith {
+                name,
+                threshold,
+                suffix,
+            } => {
+                executable_rules.push(Box::new(EndsWithCheck::new(
+                    name.clone(),
+                    *threshold,
+                    suffix.clone(),
+                )));
+            }
+            ColumnRule::Contains {
+                name,
+                threshold,
+                substring,
+                negate,
+                case_insensitive,
+            } => {
+                executable_rules.push(Box::new(ContainsCheck::new(
+                    name.clone(),
+                    *threshold,
+                    substring.clone(),
+                    *negate,
+                    *case_insensitive,
+                )));
+            }
+            ColumnRule::IpAddress {
+                name,
+                threshold,
+                version,
+            } => {
+                executable_rules.push(Box::new(IpCheck::new(name.clone(), *threshold, *version)));
+            }
+            ColumnRule::IpInCidr {
+                name,
+                threshold,
+                allowed,
+            } => {
+                executable_rules.push(Box::new(IpInCidr::new(
+                    name.clone(),
+                    *threshold,
+                    allowed.clone(),
+                )));
+            }
+            ColumnRule::Json {
+                name,
+                threshold,
+                kind,
+            } => {
+                executable_rules.push(Box::new(JsonCheck::new(name.clone(), *threshold, *kind)));
+            }
+            ColumnRule::CreditCard { name, threshold } => {
+                executable_rules.push(Box::new(CreditCardCheck::new(name.clone(), *threshold)));
+            }
+            ColumnRule::Iban { name, threshold } => {
+                executable_rules.push(Box::new(IbanCheck::new(name.clone(), *threshold)));
+            }
+            ColumnRule::Base64 {
+                name,
+                threshold,
+                strict_padding,
+            } => {
+                executable_rules.push(Box::new(Base64Check::new(
+                    name.clone(),
+                    *threshold,
+                    *strict_padding,
+                )));
+            }
+            ColumnRule::Hex {
+                name,
+                threshold,
+                allow_prefix,
+            } => {
+                executable_rules.push(Box::new(HexCheck::new(
+                    name.clone(),
+                    *threshold,
+                    *allow_prefix,
+                )));
+            }
+            ColumnRule::IsPhone {
+                name,
+                region,
+                threshold,
+            } => {
+                executable_rules.push(Box::new(PhoneCheck::new(
+                    name.clone(),
+                    *threshold,
+                    region.as_deref(),
+                )?));
+            }
+            ColumnRule::StringCase {
+                name,
+                threshold,
+                upper,
+                unicode,
+            } => {
+                executable_rules.push(Box::new(CaseCheck::new(
+                    name.clone(),
+                    *threshold,
+                    *upper,
+                    *unicode,
+                )));
+            }
+            ColumnRule::NoSurroundingWhitespace { name, threshold } => {
+                executable_rules.push(Box::new(NoSurroundingWhitespace::new(
+                    name.clone(),
+                    *threshold,
+                )));
+            }
+            ColumnRule::NoWhitespace { name, threshold } => {
+                executable_rules.push(Box::new(NoWhitespace::new(name.clone(), *threshold)));
+            }
+            ColumnRule::ContainsOnly {
+                name,
+                threshold,
+                allowed_chars,
+            } => {
+                executable_rules.push(Box::new(ContainsOnly::new(
+                    name.clone(),
+                    *threshold,
+                    allowed_chars.clone(),
+                )));
+            }
+            ColumnRule::StringConsistentLength {
+                name,
+                threshold,
+                tolerance,
+            } => {
+                length_rules.push(ConsistentLengthCheck::new(
+                    name.clone(),
+                    *threshold,
+                    *tolerance,
+                ));
             }
+            ColumnRule::Unicity {
+                threshold,
+                normalize,
+            } => {
+                unicity_check = Some(UnicityCheck::new(*threshold).with_normalize(*normalize));
+            }
+            // Compiled by `compile_sanity_rules`
+            ColumnRule::NotAllNull
+            | ColumnRule::NotAllSame
+            | ColumnRule::NullRatioBetween { .. }
+            | ColumnRule::DistinctCountBetween { .. } => {}
+            // Compiled by `compile_grouped_unicity`
+            ColumnRule::UniqueWithinGroup { .. } => {}
             ColumnRule::NullCheck { threshold } => {
                 null_check = Some(NullCheck::new(*threshold));
             }
@@ -101,7 +313,7 @@ fn compile_string_rules(
             }
         }
     }
-    Ok((executable_rules, unicity_check, null_check))
+    Ok((executable_rules, length_rules, unicity_check, null_check))
 }
 
 #[allow(clippy::type_complexity)]
@@ -122,9 +334,16 @@ fn compile_date_rules(
 
     for rule in rules {
         match rule {
-            ColumnRule::Unicity { threshold } => {
+            ColumnRule::Unicity { threshold, .. } => {
                 unicity_check = Some(UnicityCheck::new(*threshold));
             }
+            // Compiled by `compile_sanity_rules`
+            ColumnRule::NotAllNull
+            | ColumnRule::NotAllSame
+            | ColumnRule::NullRatioBetween { .. }
+            | ColumnRule::DistinctCountBetween { .. } => {}
+            // Compiled by `compile_grouped_unicity`
+            ColumnRule::UniqueWithinGroup { .. } => {}
             ColumnRule::NullCheck { threshold } => {
                 null_check = Some(NullCheck::new(*threshold));
             }
@@ -140,6 +359,15 @@ fn compile_date_rules(
                     DateBoundaryCheck::new(name.clone(), *threshold, *after, *year, *month, *day)?;
                 executable_rules.push(Box::new(rule));
             }
+            ColumnRule::DateRange {
+                name,
+                threshold,
+                start,
+                end,
+            } => {
+                let rule = DateRangeCheck::new(name.clone(), *threshold, *start, *end)?;
+                executable_rules.push(Box::new(rule));
+            }
             ColumnRule::WeekDay {
                 name,
                 threshold,
@@ -148,6 +376,30 @@ fn compile_date_rules(
                 let rule = WeekDayCheck::new(name.clone(), *threshold, *is_week);
                 executable_rules.push(Box::new(rule));
             }
+            ColumnRule::DayOfMonth {
+                name,
+                threshold,
+                mode,
+            } => {
+                let rule = DayOfMonthCheck::new(name.clone(), *threshold, *mode)?;
+                executable_rules.push(Box::new(rule));
+            }
+            ColumnRule::Age {
+                name,
+                threshold,
+                min_years,
+                max_years,
+                reference_date,
+            } => {
+                let rule = AgeCheck::new(
+                    name.clone(),
+                    *threshold,
+                    *min_years,
+                    *max_years,
+                    *reference_date,
+                );
+                executable_rules.push(Box::new(rule));
+            }
             _ => {
                 return Err(RuleError::ValidationError(format!(
                     "Invalid rule {:?} for Date column '{}'",
@@ -159,6 +411,69 @@ fn compile_date_rules(
     Ok((executable_rules, unicity_check, null_check))
 }
 
+/// Compile timestamp column rules into executable validators.
+///
+/// Returns an error if a non-timestamp rule is found.
+#[allow(clippy::type_complexity)]
+fn compile_timestamp_rules(
+    rules: &[ColumnRule],
+    column_name: &str,
+) -> Result<
+    (
+        Vec<Box<dyn TimestampRule>>,
+        Option<UnicityCheck>,
+        Option<NullCheck>,
+    ),
+    RuleError,
+> {
+    let mut executable_rules: Vec<Box<dyn TimestampRule>> = Vec::new();
+    let mut unicity_check = None;
+    let mut null_check = None;
+
+    for rule in rules {
+        match rule {
+            ColumnRule::Unicity { threshold, .. } => {
+                unicity_check = Some(UnicityCheck::new(*threshold));
+            }
+            // Compiled by `compile_sanity_rules`
+            ColumnRule::NotAllNull
+            | ColumnRule::NotAllSame
+            | ColumnRule::NullRatioBetween { .. }
+            | ColumnRule::DistinctCountBetween { .. } => {}
+            // Compiled by `compile_grouped_unicity`
+            ColumnRule::UniqueWithinGroup { .. } => {}
+            ColumnRule::NullCheck { threshold } => {
+                null_check = Some(NullCheck::new(*threshold));
+            }
+            ColumnRule::TimestampBoundary {
+                name,
+                threshold,
+                after,
+                millis,
+            } => {
+                let rule = TimestampBoundaryCheck::new(name.clone(), *threshold, *after, *millis);
+                executable_rules.push(Box::new(rule));
+            }
+            ColumnRule::TimeOfDay {
+                name,
+                threshold,
+                start_hour,
+                end_hour,
+            } => {
+                let rule = TimeOfDayCheck::new(name.clone(), *threshold, *start_hour, *end_hour)?;
+                executable_rules.push(Box::new(rule));
+            }
+            _ => {
+                return Err(RuleError::ValidationError(format!(
+                    "Invalid rule {:?} for Timestamp column '{}'",
+                    rule, column_name,
+                )))
+            }
+        }
+    }
+    Ok((executable_rules, unicity_check, null_check))
+}
+
 /// Compile numeric column rules into executable validators.
 ///
 /// Generic over both the native type (N) and Arrow type (A) to support
@@ -193,15 +508,15 @@ where
                 threshold,
                 min,
                 max,
+                min_inclusive,
+                max_inclusive,
             } => {
                 let min_conv = min.and_then(|v| N::from(v));
                 let max_conv = max.and_then(|v| N::from(v));
-                domain_rules.push(Box::new(Range::<N>::new(
-                    name.clone(),
-                    *threshold,
-                    min_conv,
-                    max_conv,
-                )));
+                domain_rules.push(Box::new(
+                    Range::<N>::new(name.clone(), *threshold, min_conv, max_conv)
+                        .with_inclusive(*min_inclusive, *max_inclusive),
+                ));
             }
             ColumnRule::Monotonicity {
                 name,
@@ -214,6 +529,37 @@ where
                     *ascending,
                 )));
             }
+            ColumnRule::NumericEquals {
+                name,
+                expected,
+                threshold,
+            } => {
+                let expected = N::from(*expected).ok_or_else(|| {
+                    RuleError::ValidationError(format!(
+                        "Expected value {} does not fit numeric column '{}'",
+                        expected, column_name
+                    ))
+                })?;
+                domain_rules.push(Box::new(EqualsConstant::new(
+                    name.clone(),
+                    *threshold,
+                    expected,
+                )));
+            }
+            ColumnRule::NumericMembers {
+                name,
+                members,
+                negate,
+                threshold,
+            } => {
+                let members = members.iter().filter_map(|m| N::from(*m)).collect();
+                domain_rules.push(Box::new(MembersCheck::<N>::new(
+                    name.clone(),
+                    *threshold,
+                    members,
+                    *negate,
+                )));
+            }
             ColumnRule::StdDevCheck {
                 name,
                 threshold,
@@ -236,8 +582,44 @@ where
                     *max_variance_percent,
                 )));
             }
+            ColumnRule::ZScoreOutlier {
+                name,
+                threshold,
+                num_std,
+            } => {
+                stats_rules.push(Box::new(ZScoreOutlierCheck::<N>::new(
+                    name.clone(),
+                    *threshold,
+                    *num_std,
+                )));
+            }
+            ColumnRule::Percentile {
+                name,
+                threshold,
+                quantile,
+                bound,
+            } => {
+                stats_rules.push(Box::new(PercentileCheck::<N>::new(
+                    name.clone(),
+                    *threshold,
+                    *quantile,
+                    *bound,
+                )));
+            }
+            // Compiled by `compile_integer_rules`
+            ColumnRule::MultipleOf { .. } if N::column_type() == ColumnType::Integer => {}
+            // Compiled by `compile_float_rules`
+            ColumnRule::IsFinite { .. } | ColumnRule::MaxDecimalPlaces { .. }
+                if N::column_type() == ColumnType::Float => {}
+            // Compiled by `compile_sanity_rules`
+            ColumnRule::NotAllNull
+            | ColumnRule::NotAllSame
+            | ColumnRule::NullRatioBetween { .. }
+            | ColumnRule::DistinctCountBetween { .. } => {}
+            // Compiled by `compile_grouped_unicity`
+            ColumnRule::UniqueWithinGroup { .. } => {}
             ColumnRule::NullCheck { threshold } => null_rule = Some(NullCheck::new(*threshold)),
-            ColumnRule::Unicity { threshold } => {
+            ColumnRule::Unicity { threshold, .. } => {
                 unicity = Some(UnicityCheck::new(*threshold));
             }
             _ => {
@@ -251,6 +633,156 @@ where
     Ok((domain_rules, stats_rules, unicity, null_rule))
 }
 
+/// Compile the rules only available on integer columns.
+///
+/// Shared numeric rules are handled by `compile_numeric_rules`, which skips these.
+fn compile_integer_rules(rules: &[ColumnRule]) -> Vec<Box<dyn NumericRule<Int64Type>>> {
+    let mut domain_rules: Vec<Box<dyn NumericRule<Int64Type>>> = Vec::new();
+    for rule in rules {
+        if let ColumnRule::MultipleOf {
+            name,
+            divisor,
+            threshold,
+        } = rule
+        {
+            domain_rules.push(Box::new(MultipleOfCheck::new(
+                name.clone(),
+                *threshold,
+                *divisor,
+            )));
+        }
+    }
+    domain_rules
+}
+
+/// Compile the rules only available on float columns.
+///
+/// Shared numeric rules are handled by `compile_numeric_rules`, which skips these.
+fn compile_float_rules(rules: &[ColumnRule]) -> Vec<Box<dyn NumericRule<Float64Type>>> {
+    let mut domain_rules: Vec<Box<dyn NumericRule<Float64Type>>> = Vec::new();
+    for rule in rules {
+        match rule {
+            ColumnRule::IsFinite { name, threshold } => {
+                domain_rules.push(Box::new(FiniteCheck::new(name.clone(), *threshold)));
+            }
+            ColumnRule::MaxDecimalPlaces {
+                name,
+                places,
+                threshold,
+            } => {
+                domain_rules.push(Box::new(MaxDecimalPlacesCheck::new(
+                    name.clone(),
+                    *threshold,
+                    *places,
+                )));
+            }
+            _ => {}
+        }
+    }
+    domain_rules
+}
+
+/// Compile boolean column rules into executable validators.
+///
+/// Separates per-batch rules (expected value) from column-level ratio rules.
+/// Returns an error if a non-boolean rule is found.
+#[allow(clippy::type_complexity)]
+fn compile_boolean_rules(
+    rules: &[ColumnRule],
+    column_name: &str,
+) -> Result<
+    (
+        Vec<Box<dyn BooleanRule>>,
+        Vec<TrueRatioCheck>,
+        Option<NullCheck>,
+    ),
+    RuleError,
+> {
+    let mut executable_rules: Vec<Box<dyn BooleanRule>> = Vec::new();
+    let mut ratio_rules = Vec::new();
+    let mut null_check = None;
+
+    for rule in rules {
+        match rule {
+            // Compiled by `compile_sanity_rules`
+            ColumnRule::NotAllNull
+            | ColumnRule::NotAllSame
+            | ColumnRule::NullRatioBetween { .. }
+            | ColumnRule::DistinctCountBetween { .. } => {}
+            ColumnRule::NullCheck { threshold } => {
+                null_check = Some(NullCheck::new(*threshold));
+            }
+            ColumnRule::BooleanValue {
+                name,
+                threshold,
+                expected,
+            } => {
+                executable_rules.push(Box::new(ExpectedValueCheck::new(
+                    name.clone(),
+                    *threshold,
+                    *expected,
+                )));
+            }
+            ColumnRule::TrueRatio {
+                name,
+                threshold,
+                min,
+                max,
+            } => {
+                ratio_rules.push(TrueRatioCheck::new(name.clone(), *threshold, *min, *max));
+            }
+            _ => {
+                return Err(RuleError::ValidationError(format!(
+                    "Invalid rule {:?} for Boolean column '{}'",
+                    rule, column_name,
+                )))
+            }
+        }
+    }
+    Ok((executable_rules, ratio_rules, null_check))
+}
+
+/// Compile the column-level sanity rules, shared by every column type.
+///
+/// Type-specific compilers skip these.
+fn compile_sanity_rules(rules: &[ColumnRule]) -> Vec<SanityCheck> {
+    rules
+        .iter()
+        .filter_map(|rule| match rule {
+            ColumnRule::NotAllNull => Some(SanityCheck::NotAllNull),
+            ColumnRule::NotAllSame => Some(SanityCheck::NotAllSame),
+            ColumnRule::NullRatioBetween {
+                min_ratio,
+                max_ratio,
+            } => Some(SanityCheck::NullRatioBetween {
+                min_ratio: *min_ratio,
+                max_ratio: *max_ratio,
+            }),
+            ColumnRule::DistinctCountBetween { min, max } => {
+                Some(SanityCheck::DistinctCountBetween {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Compile the uniqueness within groups of a column, shared by every column type but
+/// boolean. The last rule wins, as for `is_unique`.
+///
+/// Type-specific compilers skip it.
+fn compile_grouped_unicity(rules: &[ColumnRule]) -> Option<GroupedUnicityCheck> {
+    rules.iter().rev().find_map(|rule| match rule {
+        ColumnRule::UniqueWithinGroup {
+            threshold,
+            group_by,
+        } => Some(GroupedUnicityCheck::new(group_by.clone(), *threshold)),
+        _ => None,
+    })
+}
+
 /// Compile a column builder into an executable column.
 ///
 /// This is the main entry point for rule compilation. Takes a `ColumnBuilder` and produces
@@ -267,11 +799,20 @@ where
 pub fn compile_column(
     builder: Box<dyn ColumnBuilder>,
     need_type_check: bool,
+) -> Result<ExecutableColumn, RuleError> {
+    compile_column_rules(builder.as_ref(), builder.rules(), need_type_check)
+}
+
+/// Compile `rules` as the rules of the column of `builder`, see [`compile_column`].
+fn compile_column_rules(
+    builder: &dyn ColumnBuilder,
+    rules: &[ColumnRule],
+    need_type_check: bool,
 ) -> Result<ExecutableColumn, RuleError> {
     match builder.column_type() {
         ColumnType::String => {
-            let (executable_rules, unicity_check, null_check) =
-                compile_string_rules(builder.rules(), builder.name())?;
+            let (executable_rules, length_rules, unicity_check, null_check) =
+                compile_string_rules(rules, builder.name())?;
             let mut type_check = None;
             if need_type_check {
                 let t = builder.type_threshold();
@@ -284,14 +825,18 @@ pub fn compile_column(
             Ok(ExecutableColumn::String {
                 name: builder.name().to_string(),
                 rules: executable_rules,
+                length_rules,
                 type_check,
                 unicity_check,
+                grouped_unicity_check: compile_grouped_unicity(rules),
                 null_check,
+                sanity_rules: compile_sanity_rules(rules),
             })
         }
         ColumnType::Integer => {
-            let (domain_rules, statistical_rules, unicity_check, null_check) =
-                compile_numeric_rules(builder.rules(), builder.name())?;
+            let (mut domain_rules, statistical_rules, unicity_check, null_check) =
+                compile_numeric_rules(rules, builder.name())?;
+            domain_rules.extend(compile_integer_rules(rules));
             let mut type_check = None;
             if need_type_check {
                 let t = builder.type_threshold();
@@ -307,12 +852,15 @@ pub fn compile_column(
                 statistical_rules,
                 type_check,
                 unicity_check,
+                grouped_unicity_check: compile_grouped_unicity(rules),
                 null_check,
+                sanity_rules: compile_sanity_rules(rules),
             })
         }
         ColumnType::Float => {
-            let (executable_rules, statistical_rules, unicity_check, null_check) =
-                compile_numeric_rules(builder.rules(), builder.name())?;
+            let (mut executable_rules, statistical_rules, unicity_check, null_check) =
+                compile_numeric_rules(rules, builder.name())?;
+            executable_rules.extend(compile_float_rules(rules));
             let mut type_check = None;
             if need_type_check {
                 let t = builder.type_threshold();
@@ -328,12 +876,14 @@ pub fn compile_column(
                 statistical_rules,
                 type_check,
                 unicity_check,
+                grouped_unicity_check: compile_grouped_unicity(rules),
                 null_check,
+                sanity_rules: compile_sanity_rules(rules),
             })
         }
         ColumnType::DateType => {
             let (executable_rules, unicity_check, null_check) =
-                compile_date_rules(builder.rules(), builder.name())?;
+                compile_date_rules(rules, builder.name())?;
             let mut type_check = None;
             if need_type_check {
                 // Safety: DateColumnBuilder can only return Some()
@@ -351,12 +901,77 @@ pub fn compile_column(
                 rules: executable_rules,
                 type_check,
                 unicity_check,
+                grouped_unicity_check: compile_grouped_unicity(rules),
                 null_check,
+                sanity_rules: compile_sanity_rules(rules),
+            })
+        }
+        ColumnType::Timestamp => {
+            let (executable_rules, unicity_check, null_check) =
+                compile_timestamp_rules(rules, builder.name())?;
+            let mut type_check = None;
+            if need_type_check {
+                // Safety: TimestampColumnBuilder can only return Some()
+                let format = builder.format().unwrap();
+                let t = builder.type_threshold();
+                type_check = Some(TimestampTypeCheck::new(
+                    builder.name().to_string(),
+                    format.to_string(),
+                    t,
+                ));
+            }
+            Ok(ExecutableColumn::Timestamp {
+                name: builder.name().to_string(),
+                rules: executable_rules,
+                type_check,
+                unicity_check,
+                grouped_unicity_check: compile_grouped_unicity(rules),
+                null_check,
+                sanity_rules: compile_sanity_rules(rules),
+            })
+        }
+        ColumnType::Boolean => {
+            let (executable_rules, ratio_rules, null_check) =
+                compile_boolean_rules(rules, builder.name())?;
+            let mut type_check = None;
+            if need_type_check {
+                // Safety: BooleanColumnBuilder can only return Some()
+                let (true_tokens, false_tokens) = builder.boolean_tokens().unwrap();
+                let t = builder.type_threshold();
+                type_check = Some(BooleanTypeCheck::new(
+                    builder.name().to_string(),
+                    true_tokens.to_vec(),
+                    false_tokens.to_vec(),
+                    t,
+                ));
+            }
+            Ok(ExecutableColumn::Boolean {
+                name: builder.name().to_string(),
+                rules: executable_rules,
+                ratio_rules,
+                type_check,
+                null_check,
+                sanity_rules: compile_sanity_rules(rules),
             })
         }
     }
 }
 
+/// Labels of the rules of a column, keyed by the name their results are reported under.
+///
+/// Each labelled rule is compiled alone to find that name, which may differ from the rule
+/// method, e.g. `is_unique` is reported as `Unicity`.
+pub fn compile_labels(builder: &dyn ColumnBuilder) -> Result<HashMap<String, String>, RuleError> {
+    let mut labels = HashMap::new();
+    for (index, label) in builder.rule_labels() {
+        let rule = std::slice::from_ref(&builder.rules()[*index]);
+        for name in compile_column_rules(builder, rule, false)?.rule_names() {
+            labels.insert(name, label.clone());
+        }
+    }
+    Ok(labels)
+}
+
 /// Build a map of column names to their Arrow DataTypes.
 ///
 /// This helper function extracts type information from column builders,
@@ -371,6 +986,8 @@ pub fn build_column_type_map(columns: &[Box<dyn ColumnBuilder>]) -> HashMap<Stri
                 ColumnType::Float => DataType::Float64,
                 ColumnType::String => DataType::Utf8,
                 ColumnType::DateType => DataType::Date32,
+                ColumnType::Timestamp => TimestampTypeCheck::data_type(),
+                ColumnType::Boolean => DataType::Boolean,
             };
             (col.name().to_string(), data_type)
         })
@@ -383,6 +1000,8 @@ pub fn compile_relations(
 ) -> Result<ExecutableRelation, RuleError> {
     let RelationBuilder { names, rules } = builder;
     let mut executable_relations: Vec<Box<dyn RelationRule>> = Vec::new();
+    let mut correlations: Vec<CorrelationCheck> = Vec::new();
+    let mut subsets: Vec<SubsetCheck> = Vec::new();
 
     // For the cli we ensure from the config that the Column is present, we do not have the same
     // check in api, so we need to do this here
@@ -393,24 +1012,49 @@ pub fn compile_relations(
         RuleError::ValidationError(format!("Column '{}' not found in relation", names[1]))
     })?;
 
-    // We only validate columns of same type
-    if left_type != right_type {
-        return Err(RuleError::ValidationError(format!(
-            "Cannot compare columns of different types: '{}' ({:?}) vs '{}' ({:?})",
-            names[0], left_type, names[1], right_type
-        )));
-    }
+    let same_type = || {
+        // We only compare columns of same type
+        if left_type != right_type {
+            return Err(RuleError::ValidationError(format!(
+                "Cannot compare columns of different types: '{}' ({:?}) vs '{}' ({:?})",
+                names[0], left_type, names[1], right_type
+            )));
+        }
+        Ok(())
+    };
 
     for rule in rules {
+        if !matches!(
+            rule,
+            TableConstraint::SumEquals { .. }
+                | TableConstraint::Conditional { .. }
+                | TableConstraint::Correlation { .. }
+        ) {
+            same_type()?;
+        }
         match rule {
-            TableConstraint::DateComparaison { op, threshold } => match left_type {
+            TableConstraint::DateComparaison {
+                op,
+                threshold,
+                max_gap_days,
+            } => match left_type {
                 DataType::Date32 => {
-                    executable_relations
-                        .push(Box::new(CompareCheck::<Date32Type>::new(op, threshold)));
+                    let mut check = CompareCheck::<Date32Type>::new(op, threshold);
+                    if let Some(days) = max_gap_days {
+                        check = check.with_max_gap(days as u64);
+                    }
+                    executable_relations.push(Box::new(check));
+                }
+                DataType::Timestamp(..) => {
+                    let mut check = CompareCheck::<TimestampMillisecondType>::new(op, threshold);
+                    if let Some(days) = max_gap_days {
+                        check = check.with_max_gap(days as u64 * MILLISECONDS_PER_DAY);
+                    }
+                    executable_relations.push(Box::new(check));
                 }
                 other_type => {
                     return Err(RuleError::ValidationError(format!(
-                        "Date comparison not supported for type {:?}. Only Date32 is supported.",
+                        "Date comparison not supported for type {:?}. Only Date32 and Timestamp are supported.",
                         other_type
                     )));
                 }
@@ -436,7 +1080,173 @@ pub fn compile_relations(
                     }
                 }
             }
+            TableConstraint::StringComparaison { op, threshold } => {
+                if left_type != &DataType::Utf8 {
+                    return Err(RuleError::ValidationError(format!(
+                        "String comparison not supported for type {:?}. Only Utf8 is supported.",
+                        left_type
+                    )));
+                }
+                if !matches!(op, CompOperator::Eq | CompOperator::Neq) {
+                    return Err(RuleError::ValidationError(format!(
+                        "String comparison only supports equal and not equal, got {}",
+                        op
+                    )));
+                }
+                executable_relations.push(Box::new(StringCompareCheck::new(op, threshold)));
+            }
+            TableConstraint::SumEquals {
+                left,
+                right,
+                tolerance,
+                threshold,
+            } => {
+                // Summed columns can mix integers and floats, they are all read as Float64
+                for column in left.iter().chain(std::iter::once(&right)) {
+                    match column_types.get(column) {
+                        Some(DataType::Int64 | DataType::Float64) => {}
+                        Some(other_type) => {
+                            return Err(RuleError::ValidationError(format!(
+                                "Cannot sum column '{}' of type {:?} in relation",
+                                column, other_type
+                            )))
+                        }
+                        None => {
+                            return Err(RuleError::ValidationError(format!(
+                                "Column '{}' not found in relation",
+                                column
+                            )))
+                        }
+                    }
+                }
+                let addends = left.into_iter().skip(1).collect();
+                executable_relations
+                    .push(Box::new(SumEqualsCheck::new(addends, tolerance, threshold)));
+            }
+            TableConstraint::Conditional {
+                when_column,
+                when_equals,
+                then_column,
+                then_rule,
+                threshold,
+            } => {
+                // The condition is matched on the text value, floats would depend on formatting
+                if left_type == &DataType::Float64 {
+                    return Err(RuleError::ValidationError(format!(
+                        "Cannot use float column '{}' as a condition",
+                        when_column
+                    )));
+                }
+                if matches!(then_rule, ConditionalRule::Between { .. })
+                    && !matches!(right_type, DataType::Int64 | DataType::Float64)
+                {
+                    return Err(RuleError::ValidationError(format!(
+                        "Conditional range not supported for column '{}' of type {:?}",
+                        then_column, right_type
+                    )));
+                }
+                executable_relations.push(Box::new(ConditionalCheck::new(
+                    when_equals,
+                    then_rule,
+                    threshold,
+                )));
+            }
+            TableConstraint::Correlation {
+                min_coefficient,
+                threshold,
+            } => {
+                // Integers and floats can be correlated, both are read as Float64
+                for (column, data_type) in names.iter().zip([left_type, right_type]) {
+                    if !matches!(data_type, DataType::Int64 | DataType::Float64) {
+                        return Err(RuleError::ValidationError(format!(
+                            "Correlation not supported for column '{}' of type {:?}",
+                            column, data_type
+                        )));
+                    }
+                }
+                correlations.push(CorrelationCheck::new(min_coefficient, threshold));
+            }
+            TableConstraint::Duration {
+                min_secs,
+                max_secs,
+                threshold,
+            } => {
+                if !matches!(left_type, DataType::Timestamp(..)) {
+                    return Err(RuleError::ValidationError(format!(
+                        "Duration not supported for type {:?}. Only Timestamp is supported.",
+                        left_type
+                    )));
+                }
+                executable_relations
+                    .push(Box::new(DurationCheck::new(min_secs, max_secs, threshold)));
+            }
+            TableConstraint::Subset { threshold } => {
+                subsets.push(SubsetCheck::new(threshold));
+            }
         }
     }
-    Ok(ExecutableRelation::new(names, executable_relations))
+    Ok(ExecutableRelation::new(
+        names,
+        executable_relations,
+        correlations,
+        subsets,
+    ))
+}
+
+pub fn compile_groups(
+    builder: GroupBuilder,
+    column_types: &HashMap<String, DataType>,
+) -> Result<ExecutableGroup, RuleError> {
+    let GroupBuilder {
+        group_by,
+        value,
+        rules,
+    } = builder;
+
+    let mut sum_rules = Vec::new();
+    let mut count_rules = Vec::new();
+    for rule in rules {
+        match rule {
+            GroupConstraint::SumEquals {
+                expected,
+                tolerance,
+                threshold,
+            } => sum_rules.push(GroupSumCheck::new(expected, tolerance, threshold)),
+            GroupConstraint::CountBetween {
+                min,
+                max,
+                threshold,
+            } => count_rules.push(GroupCountCheck::new(min, max, threshold)),
+        }
+    }
+
+    let value = match value {
+        // Counting the rows does not read the value column
+        Some(_) if sum_rules.is_empty() => None,
+        Some(value) => {
+            // The value column does not need to be declared, but if it is it must be numeric
+            if let Some(value_type) = column_types.get(&value) {
+                if !matches!(value_type, DataType::Int64 | DataType::Float64) {
+                    return Err(RuleError::ValidationError(format!(
+                        "Cannot sum column '{}' of type {:?} in group assertion",
+                        value, value_type
+                    )));
+                }
+            }
+            Some(value)
+        }
+        None if !sum_rules.is_empty() => {
+            return Err(RuleError::ValidationError(format!(
+                "Group assertion on '{}' needs a value column to sum",
+                group_by
+            )));
+        }
+        None => None,
+    };
+    Ok(ExecutableGroup::new(
+        group_by,
+        value,
+        sum_rules,
+        count_rules,
+    ))
 }
@@ -1,16 +1,19 @@
 use crate::{
     columns::{
-        date_builder::DateColumnBuilder, numeric_builder::NumericColumnBuilder,
-        string_builder::StringColumnBuilder,
+        boolean_builder::BooleanColumnBuilder, date_builder::DateColumnBuilder,
+        numeric_builder::NumericColumnBuilder, string_builder::StringColumnBuilder,
     },
     compiler::compile_column,
+    rules::NormalizeMode,
     validator::ExecutableColumn,
 };
 
 #[test]
 fn test_compile_string_column_basic() {
     let mut builder = StringColumnBuilder::new("username".to_string());
-    builder.with_min_length(3, 0.0).with_max_length(20, 0.0);
+    builder
+        .with_min_length(3, false, 0.0)
+        .with_max_length(20, false, 0.0);
 
     let result = compile_column(Box::new(builder), true);
     assert!(result.is_ok());
@@ -28,7 +31,7 @@ fn test_compile_string_column_basic() {
 #[test]
 fn test_compile_string_column_with_null_and_unicity() {
     let mut builder = StringColumnBuilder::new("email".to_string());
-    builder.is_not_null(0.0).is_unique(0.0);
+    builder.is_not_null(0.0).is_unique(NormalizeMode::None, 0.0);
 
     let result = compile_column(Box::new(builder), true);
     assert!(result.is_ok());
@@ -137,6 +140,52 @@ fn test_compile_integer_column_positive() {
     }
 }
 
+#[test]
+fn test_compile_integer_column_multiple_of() {
+    let mut builder = NumericColumnBuilder::<i64>::new("quantity".to_string());
+    builder.is_positive(0.0).is_multiple_of(12, 0.0).unwrap();
+
+    let executable = compile_column(Box::new(builder), true).unwrap();
+    match executable {
+        ExecutableColumn::Integer { domain_rules, .. } => {
+            assert_eq!(domain_rules.len(), 2);
+            assert_eq!(domain_rules[1].name(), "IsMultipleOf");
+        }
+        _ => panic!("Expected Integer column"),
+    }
+}
+
+#[test]
+fn test_compile_float_column_is_finite() {
+    let mut builder = NumericColumnBuilder::<f64>::new("ratio".to_string());
+    builder.between(0.0, 1.0, 0.0).is_finite(0.0);
+
+    let executable = compile_column(Box::new(builder), true).unwrap();
+    match executable {
+        ExecutableColumn::Float { domain_rules, .. } => {
+            assert_eq!(domain_rules.len(), 2);
+            assert_eq!(domain_rules[1].name(), "IsFinite");
+        }
+        _ => panic!("Expected Float column"),
+    }
+}
+
+#[test]
+fn test_compile_float_column_max_decimal_places() {
+    let mut builder = NumericColumnBuilder::<f64>::new("amount".to_string());
+    builder.max_decimal_places(2, 0.0).unwrap();
+    assert!(builder.max_decimal_places(16, 0.0).is_err());
+
+    let executable = compile_column(Box::new(builder), true).unwrap();
+    match executable {
+        ExecutableColumn::Float { domain_rules, .. } => {
+            assert_eq!(domain_rules.len(), 1);
+            assert_eq!(domain_rules[0].name(), "MaxDecimalPlaces");
+        }
+        _ => panic!("Expected Float column"),
+    }
+}
+
 #[test]
 fn test_compile_float_column_basic() {
     let mut builder = NumericColumnBuilder::<f64>::new("price".to_string());
@@ -183,11 +232,11 @@ fn test_compile_float_column_non_negative() {
 fn test_compile_column_with_multiple_rules() {
     let mut builder = StringColumnBuilder::new("username".to_string());
     builder
-        .with_min_length(3, 0.0)
-        .with_max_length(20, 0.0)
+        .with_min_length(3, false, 0.0)
+        .with_max_length(20, false, 0.0)
         .is_alphanumeric(0.0)
         .unwrap()
-        .is_unique(0.0)
+        .is_unique(NormalizeMode::None, 0.0)
         .is_not_null(0.0);
 
     let result = compile_column(Box::new(builder), true);
@@ -255,7 +304,7 @@ fn test_compile_numeric_column_multiple_ranges() {
 #[test]
 fn test_compile_date_column_with_multiple_rules() {
     let mut builder = DateColumnBuilder::new("value".to_string(), "%Y-%m-%d".to_string());
-    builder.is_not_futur(0.0).is_weekday(0.0);
+    builder.is_not_futur(None, 0.0).is_weekday(0.0);
 
     let result = compile_column(Box::new(builder), true);
     assert!(result.is_ok());
@@ -337,3 +386,27 @@ fn test_compile_date_column_type_type_check_always_present() {
         _ => panic!("Expected Float column"),
     }
 }
+
+#[test]
+fn test_compile_boolean_column() {
+    let mut builder = BooleanColumnBuilder::new("is_active".to_string());
+    builder.is_not_null(0.0).is_true(0.0);
+    builder.true_ratio_between(0.5, 1.0, 0.0).unwrap();
+
+    let executable = compile_column(Box::new(builder), true).unwrap();
+    match executable {
+        ExecutableColumn::Boolean {
+            rules,
+            ratio_rules,
+            type_check,
+            null_check,
+            ..
+        } => {
+            assert_eq!(rules.len(), 1);
+            assert_eq!(ratio_rules.len(), 1);
+            assert!(type_check.is_some());
+            assert!(null_check.is_some());
+        }
+        _ => panic!("Expected Boolean column"),
+    }
+}
@@ -12,18 +12,66 @@ use crate::{
     RuleResult,
 };
 
+/// Default number of failing values kept per (column, rule)
+pub const DEFAULT_MAX_SAMPLES: usize = 5;
+
+/// Hard cap on failing values kept per (column, rule), whatever the configured count.
+///
+/// Keeps memory bounded when a column fails on every row and the count is set too high.
+pub const MAX_SAMPLES_CAP: usize = 1_000;
+
+/// Failing values kept for one (column, rule), the lowest row indices win.
+struct Samples {
+    kept: Mutex<Vec<(usize, String)>>,
+    // Highest kept row once the buffer is full, rows above it can not make it in.
+    // Checked before locking so fully failing columns skip the lock once full.
+    bound: AtomicUsize,
+}
+
+impl Samples {
+    fn new() -> Self {
+        Self {
+            kept: Mutex::new(Vec::new()),
+            bound: AtomicUsize::new(usize::MAX),
+        }
+    }
+
+    fn record(&self, samples: Vec<(usize, String)>, limit: usize) {
+        let bound = self.bound.load(Ordering::Relaxed);
+        let mut samples = samples
+            .into_iter()
+            .filter(|(row, _)| *row < bound)
+            .peekable();
+        if samples.peek().is_none() {
+            return;
+        }
+        let mut kept = self.kept.lock().unwrap();
+        kept.extend(samples);
+        kept.sort_by_key(|(row, _)| *row);
+        kept.truncate(limit);
+        if kept.len() == limit {
+            if let Some((row, _)) = kept.last() {
+                self.bound.store(*row, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
 /// Thread-safe accumulator for validation errors.
 ///
 /// Collects error counts per (column, rule) pair during parallel validation.
 /// After all batches are processed, converts to structured results with percentages.
 pub struct ResultAccumulator {
-    // (column_name, rule_name) -> error_count
-    column_results: DashMap<(String, String), (AtomicUsize, Mutex<f64>)>,
-    // (column_name, rule_name) -> error_count
-    relation_results: DashMap<(String, String), (AtomicUsize, Mutex<f64>)>,
+    // (column_name, rule_name) -> (error_count, threshold, failing samples)
+    column_results: DashMap<(String, String), (AtomicUsize, Mutex<f64>, Samples)>,
+    // (relation_name, rule_name) -> (error_count, threshold, failing samples)
+    relation_results: DashMap<(String, String), (AtomicUsize, Mutex<f64>, Samples)>,
     // column_name -> total_valid_values
     valid_values: DashMap<String, AtomicUsize>,
+    // (column_or_relation_name, rule_name) -> values computed by a statistical rule
+    observed: DashMap<(String, String), Vec<(String, f64)>>,
     total_rows: AtomicUsize,
+    max_samples: usize,
 }
 
 impl Default for ResultAccumulator {
@@ -39,10 +87,24 @@ impl ResultAccumulator {
             column_results: DashMap::new(),
             relation_results: DashMap::new(),
             valid_values: DashMap::new(),
+            observed: DashMap::new(),
             total_rows: AtomicUsize::new(0),
+            max_samples: DEFAULT_MAX_SAMPLES,
         }
     }
 
+    /// Set the number of failing values kept per (column, rule), 0 disables sampling.
+    ///
+    /// The count is capped at `MAX_SAMPLES_CAP`.
+    pub fn with_max_samples(mut self, max_samples: usize) -> Self {
+        self.max_samples = max_samples.min(MAX_SAMPLES_CAP);
+        self
+    }
+
+    pub fn max_samples(&self) -> usize {
+        self.max_samples
+    }
+
     /// Set the total number of rows being validated.
     ///
     /// Must be called before `to_results()` to get correct percentages.
@@ -65,6 +127,9 @@ impl ResultAccumulator {
 
     /// Record errors for a specific column and rule.
     ///
+    /// `samples` are `(row_index, value)` pairs of failing values. Batches being validated
+    /// in any order, only the `max_samples` lowest row indices are kept.
+    ///
     /// Thread-safe - can be called from multiple threads concurrently.
     pub fn record_column_result(
         &self,
@@ -72,17 +137,41 @@ impl ResultAccumulator {
         rule_name: String,
         threshold: f64,
         error_count: usize,
+        samples: Vec<(usize, String)>,
     ) {
-        self.column_results
+        let entry = self
+            .column_results
             .entry((column_name.to_string(), rule_name))
-            .and_modify(|(counter, _)| {
+            .and_modify(|(counter, _, _)| {
                 counter.fetch_add(error_count, Ordering::Relaxed);
             })
-            .or_insert_with(|| (AtomicUsize::new(error_count), Mutex::new(threshold)));
+            .or_insert_with(|| {
+                (
+                    AtomicUsize::new(error_count),
+                    Mutex::new(threshold),
+                    Samples::new(),
+                )
+            });
+        if !samples.is_empty() && self.max_samples > 0 {
+            entry.2.record(samples, self.max_samples);
+        }
+    }
+
+    /// Record the values computed by a statistical rule, on a column or a relation.
+    pub fn record_observed(
+        &self,
+        column_name: &str,
+        rule_name: String,
+        values: Vec<(String, f64)>,
+    ) {
+        self.observed
+            .insert((column_name.to_string(), rule_name), values);
     }
 
     /// Record errors for a specific relation and rule.
     ///
+    /// `samples` are kept as for [`ResultAccumulator::record_column_result`].
+    ///
     /// Thread-safe - can be called from multiple threads concurrently.
     pub fn record_relation_result(
         &self,
@@ -90,13 +179,24 @@ impl ResultAccumulator {
         rule_name: String,
         threshold: f64,
         error_count: usize,
+        samples: Vec<(usize, String)>,
     ) {
-        self.relation_results
+        let entry = self
+            .relation_results
             .entry((relation_name.to_string(), rule_name))
-            .and_modify(|(counter, _)| {
+            .and_modify(|(counter, _, _)| {
                 counter.fetch_add(error_count, Ordering::Relaxed);
             })
-            .or_insert_with(|| (AtomicUsize::new(0), Mutex::new(threshold)));
+            .or_insert_with(|| {
+                (
+                    AtomicUsize::new(error_count),
+                    Mutex::new(threshold),
+                    Samples::new(),
+                )
+            });
+        if !samples.is_empty() && self.max_samples > 0 {
+            entry.2.record(samples, self.max_samples);
+        }
     }
 
     /// Consolidates atomic counters into a final report of validation results.
@@ -136,6 +236,7 @@ impl ResultAccumulator {
                 .load(Ordering::Relaxed);
             let error_count = entry.value().0.load(Ordering::Relaxed);
             let threshold = entry.value().1.lock().unwrap().to_owned();
+            let samples = entry.value().2.kept.lock().unwrap().to_owned();
             let error_percentage = if total_rows > 0 {
                 (error_count as f64 / total_rows as f64) * 100.
             } else {
@@ -147,17 +248,23 @@ impl ResultAccumulator {
                 ));
             }
 
-            column_results
-                .entry(column_name.clone())
-                .or_default()
-                .push(RuleResult::new(
+            column_results.entry(column_name.clone()).or_default().push(
+                RuleResult::new(
                     rule_name.clone(),
                     error_count,
                     error_percentage,
                     threshold,
                     error_message,
                     error_percentage <= threshold,
-                ));
+                )
+                .with_samples(samples)
+                .with_observed(
+                    self.observed
+                        .get(entry.key())
+                        .map(|v| v.clone())
+                        .unwrap_or_default(),
+                ),
+            );
         }
 
         let mut sorted: Vec<_> = self.relation_results.iter().collect();
@@ -167,6 +274,7 @@ impl ResultAccumulator {
             let (relation_name, rule_name) = entry.key();
             let error_count = entry.value().0.load(Ordering::Relaxed);
             let threshold = entry.value().1.lock().unwrap().to_owned();
+            let samples = entry.value().2.kept.lock().unwrap().to_owned();
             let error_percentage = if total_rows > 0 {
                 (error_count as f64 / total_rows as f64) * 100.
             } else {
@@ -176,16 +284,79 @@ impl ResultAccumulator {
             relation_results
                 .entry(relation_name.clone())
                 .or_default()
-                .push(RuleResult::new(
+                .push(
+                    RuleResult::new(
+                        rule_name.clone(),
+                        error_count,
+                        error_percentage,
+                        threshold,
+                        None,
+                        error_percentage <= threshold,
+                    )
+                    .with_samples(samples)
+                    .with_observed(
+                        self.observed
+                            .get(entry.key())
+                            .map(|v| v.clone())
+                            .unwrap_or_default(),
+                    ),
+                );
+        }
+
+        (column_values, column_results, relation_results)
+    }
+
+    /// First rule whose errors recorded so far exceed its threshold over `total_rows`, used to
+    /// stop a fail fast validation. Columns are checked before relations, both by name.
+    ///
+    /// Returns the column or relation name, the partial result and whether it is a column.
+    pub fn first_failure(&self, total_rows: usize) -> Option<(String, RuleResult, bool)> {
+        if total_rows == 0 {
+            return None;
+        }
+        let percentage = |error_count: usize| (error_count as f64 / total_rows as f64) * 100.;
+
+        let mut columns: Vec<_> = self.column_results.iter().collect();
+        columns.sort_by(|a, b| a.key().cmp(b.key()));
+        for entry in columns {
+            let (column_name, rule_name) = entry.key();
+            let error_count = entry.value().0.load(Ordering::Relaxed);
+            let threshold = entry.value().1.lock().unwrap().to_owned();
+            let error_percentage = percentage(error_count);
+            if error_percentage > threshold {
+                let samples = entry.value().2.kept.lock().unwrap().to_owned();
+                let result = RuleResult::new(
                     rule_name.clone(),
                     error_count,
                     error_percentage,
                     threshold,
                     None,
-                    error_percentage <= threshold,
-                ));
+                    false,
+                )
+                .with_samples(samples);
+                return Some((column_name.clone(), result, true));
+            }
         }
 
-        (column_values, column_results, relation_results)
+        let mut relations: Vec<_> = self.relation_results.iter().collect();
+        relations.sort_by(|a, b| a.key().cmp(b.key()));
+        for entry in relations {
+            let (relation_name, rule_name) = entry.key();
+            let error_count = entry.value().0.load(Ordering::Relaxed);
+            let threshold = entry.value().1.lock().unwrap().to_owned();
+            let error_percentage = percentage(error_count);
+            if error_percentage > threshold {
+                let result = RuleResult::new(
+                    rule_name.clone(),
+                    error_count,
+                    error_percentage,
+                    threshold,
+                    None,
+                    false,
+                );
+                return Some((relation_name.clone(), result, false));
+            }
+        }
+        None
     }
 }
@@ -0,0 +1,65 @@
+use std::sync::Mutex;
+
+use arrow::{
+    compute::cast,
+    datatypes::{DataType, Float64Type},
+};
+use arrow_array::{Array, PrimitiveArray};
+
+use crate::{engine::CorrelationSums, validator::ExecutableRelation, RuleError};
+
+/// Manages the correlation sums of each relation across batches.
+///
+/// Each batch is summed locally, then merged into the sums of its relation.
+/// Coefficients are computed once all batches are done.
+pub(crate) struct CorrelationAccumulator {
+    // One entry per relation, `None` when it has no correlation check
+    sums: Vec<Option<Mutex<CorrelationSums>>>,
+}
+
+impl CorrelationAccumulator {
+    pub fn new(relations: &[ExecutableRelation]) -> Self {
+        Self {
+            sums: relations
+                .iter()
+                .map(|r| (!r.correlations.is_empty()).then(Mutex::default))
+                .collect(),
+        }
+    }
+
+    /// Sum one batch for the relation at `index`, rows with a null value are skipped.
+    pub fn record_batch(
+        &self,
+        index: usize,
+        lhs: &dyn Array,
+        rhs: &dyn Array,
+    ) -> Result<(), RuleError> {
+        let Some(sums) = &self.sums[index] else {
+            return Ok(());
+        };
+        let lhs = cast(lhs, &DataType::Float64)?;
+        let rhs = cast(rhs, &DataType::Float64)?;
+        // Safety: both arrays have just been cast to Float64
+        let lhs = lhs
+            .as_any()
+            .downcast_ref::<PrimitiveArray<Float64Type>>()
+            .unwrap();
+        let rhs = rhs
+            .as_any()
+            .downcast_ref::<PrimitiveArray<Float64Type>>()
+            .unwrap();
+
+        let mut local = CorrelationSums::default();
+        local.update(lhs, rhs);
+        sums.lock().unwrap().merge(&local);
+        Ok(())
+    }
+
+    /// Consume the accumulator, returning the final sums of each relation.
+    pub fn finalize(self) -> Vec<Option<CorrelationSums>> {
+        self.sums
+            .into_iter()
+            .map(|sums| sums.map(|s| s.into_inner().unwrap()))
+            .collect()
+    }
+}
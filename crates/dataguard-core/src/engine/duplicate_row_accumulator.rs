@@ -0,0 +1,105 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use arrow::{
+    error::ArrowError,
+    row::{RowConverter, SortField},
+};
+use arrow_array::RecordBatch;
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::utils::hasher::Xxh3Builder;
+
+/// Detects rows duplicated across every column read.
+///
+/// Each row is encoded with the Arrow row format, so values of every type compare the way
+/// they are stored, then hashed into a global set like the unicity check. Rows whose hash
+/// was already met count as duplicates.
+pub(crate) struct DuplicateRowAccumulator {
+    // `None` when the detection is disabled
+    hashes: Option<Mutex<HashSet<u64, Xxh3Builder>>>,
+    rows: AtomicUsize,
+}
+
+impl DuplicateRowAccumulator {
+    pub fn new(enabled: bool, capacity: usize) -> Self {
+        Self {
+            hashes: enabled
+                .then(|| Mutex::new(HashSet::with_capacity_and_hasher(capacity, Xxh3Builder))),
+            rows: AtomicUsize::new(0),
+        }
+    }
+
+    /// Hash every row of `batch` into the global set.
+    pub fn record_batch(&self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        let Some(hashes) = &self.hashes else {
+            return Ok(());
+        };
+        let converter = RowConverter::new(
+            batch
+                .schema()
+                .fields()
+                .iter()
+                .map(|field| SortField::new(field.data_type().clone()))
+                .collect(),
+        )?;
+        let rows = converter.convert_columns(batch.columns())?;
+        let local: HashSet<u64, Xxh3Builder> =
+            rows.iter().map(|row| xxh3_64(row.as_ref())).collect();
+        self.rows.fetch_add(batch.num_rows(), Ordering::Relaxed);
+        hashes.lock().unwrap().extend(local);
+        Ok(())
+    }
+
+    /// Number of duplicated rows, `None` when the detection is disabled.
+    pub fn finalize(self) -> Option<usize> {
+        let hashes = self.hashes?.into_inner().unwrap();
+        Some(self.rows.load(Ordering::Relaxed) - hashes.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use arrow_array::{Int64Array, StringArray};
+
+    fn batch(ids: Vec<i64>, names: Vec<Option<&str>>) -> RecordBatch {
+        RecordBatch::try_from_iter(vec![
+            ("id", Arc::new(Int64Array::from(ids)) as _),
+            ("name", Arc::new(StringArray::from(names)) as _),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_duplicate_rows_across_batches() {
+        let accumulator = DuplicateRowAccumulator::new(true, 0);
+        accumulator
+            .record_batch(&batch(
+                vec![1, 1, 2, 3],
+                vec![Some("a"), Some("a"), Some("a"), None],
+            ))
+            .unwrap();
+        // Rows differing in one column only are not duplicates, nulls compare equal
+        accumulator
+            .record_batch(&batch(vec![1, 3, 3], vec![Some("b"), None, Some("")]))
+            .unwrap();
+        assert_eq!(accumulator.finalize(), Some(2));
+    }
+
+    #[test]
+    fn test_duplicate_rows_disabled() {
+        let accumulator = DuplicateRowAccumulator::new(false, 0);
+        accumulator
+            .record_batch(&batch(vec![1, 1], vec![None, None]))
+            .unwrap();
+        assert_eq!(accumulator.finalize(), None);
+    }
+}
@@ -0,0 +1,77 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use arrow::{
+    compute::cast,
+    datatypes::{DataType, Float64Type},
+};
+use arrow_array::{Array, PrimitiveArray, StringArray};
+
+use crate::{validator::ExecutableGroup, RuleError};
+
+/// Manages per-group running sums across batches.
+///
+/// Each batch is aggregated locally, then merged into the global map of its
+/// group assertion. Sums are compared against expectations once all batches are done.
+pub(crate) struct GroupAccumulator {
+    // One map per group assertion: group key → running sum
+    sums: Vec<Mutex<HashMap<String, f64>>>,
+    // Summed column of each group assertion, to report values that are not numbers
+    values: Vec<Option<String>>,
+}
+
+impl GroupAccumulator {
+    pub fn new(groups: &[ExecutableGroup]) -> Self {
+        Self {
+            sums: groups.iter().map(|_| Mutex::new(HashMap::new())).collect(),
+            values: groups.iter().map(|g| g.value.clone()).collect(),
+        }
+    }
+
+    /// Aggregate one batch for the group assertion at `index`.
+    ///
+    /// Rows where the key or the value is null are skipped. A value that cannot be
+    /// read as a number fails the aggregation, as it would silently skew the sum.
+    pub fn record_batch(
+        &self,
+        index: usize,
+        keys: &dyn Array,
+        values: &dyn Array,
+    ) -> Result<(), RuleError> {
+        let keys = cast(keys, &DataType::Utf8)?;
+        let casted = cast(values, &DataType::Float64)?;
+        if casted.null_count() > values.null_count() {
+            return Err(RuleError::TypeCastError(
+                self.values[index].clone().unwrap_or_default(),
+                "Float64".to_string(),
+            ));
+        }
+        let values = casted;
+        // Safety: both arrays have just been cast to those types
+        let keys = keys.as_any().downcast_ref::<StringArray>().unwrap();
+        let values = values
+            .as_any()
+            .downcast_ref::<PrimitiveArray<Float64Type>>()
+            .unwrap();
+
+        let mut local: HashMap<&str, f64> = HashMap::new();
+        for (key, value) in keys.iter().zip(values.iter()) {
+            if let (Some(key), Some(value)) = (key, value) {
+                *local.entry(key).or_insert(0.) += value;
+            }
+        }
+
+        let mut sums = self.sums[index].lock().unwrap();
+        for (key, value) in local {
+            *sums.entry(key.to_string()).or_insert(0.) += value;
+        }
+        Ok(())
+    }
+
+    /// Consume the accumulator, returning the final sums for each group assertion.
+    pub fn finalize(self) -> Vec<HashMap<String, f64>> {
+        self.sums
+            .into_iter()
+            .map(|sums| sums.into_inner().unwrap())
+            .collect()
+    }
+}
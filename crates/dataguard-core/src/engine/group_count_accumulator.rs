@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use arrow::{compute::cast, datatypes::DataType};
+use arrow_array::{Array, StringArray};
+use dashmap::DashMap;
+
+use crate::{validator::ExecutableGroup, RuleError};
+
+/// Manages the number of rows of each group across batches.
+///
+/// Each batch is counted locally, then merged into the concurrent map of its group
+/// assertion. Counts are compared against their bounds once all batches are done.
+pub(crate) struct GroupCountAccumulator {
+    // One map per group assertion: group key → row count, `None` without count rule
+    counts: Vec<Option<DashMap<String, usize>>>,
+}
+
+impl GroupCountAccumulator {
+    pub fn new(groups: &[ExecutableGroup]) -> Self {
+        Self {
+            counts: groups
+                .iter()
+                .map(|g| (!g.count_rules.is_empty()).then(DashMap::new))
+                .collect(),
+        }
+    }
+
+    /// Count the rows of one batch for the group assertion at `index`.
+    ///
+    /// Rows with a null key do not belong to any group and are skipped.
+    pub fn record_batch(&self, index: usize, keys: &dyn Array) -> Result<(), RuleError> {
+        let Some(counts) = &self.counts[index] else {
+            return Ok(());
+        };
+        let keys = cast(keys, &DataType::Utf8)?;
+        // Safety: the array has just been cast to Utf8
+        let keys = keys.as_any().downcast_ref::<StringArray>().unwrap();
+
+        let mut local: HashMap<&str, usize> = HashMap::new();
+        for key in keys.iter().flatten() {
+            *local.entry(key).or_insert(0) += 1;
+        }
+        for (key, count) in local {
+            match counts.get_mut(key) {
+                Some(mut total) => *total += count,
+                None => *counts.entry(key.to_string()).or_insert(0) += count,
+            }
+        }
+        Ok(())
+    }
+
+    /// Consume the accumulator, returning the final counts for each group assertion.
+    pub fn finalize(self) -> Vec<HashMap<String, usize>> {
+        self.counts
+            .into_iter()
+            .map(|counts| counts.map(|c| c.into_iter().collect()).unwrap_or_default())
+            .collect()
+    }
+}
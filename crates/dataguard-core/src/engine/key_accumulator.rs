@@ -0,0 +1,50 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use arrow_array::RecordBatch;
+
+use crate::{errors::RuleError, rules::ColumnKeys};
+
+/// Collects the key columns of foreign keys across batches.
+///
+/// Keys are read from the batches as they come, before any type check, the way the
+/// column would be read on its own. Memory grows with the number of distinct keys, each
+/// keeping the text of its value for the orphan samples.
+pub(crate) struct KeyAccumulator {
+    // `None` until the column is found in a batch
+    columns: HashMap<String, Mutex<Option<ColumnKeys>>>,
+}
+
+impl KeyAccumulator {
+    pub fn new(columns: &[String]) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .map(|name| (name.clone(), Mutex::default()))
+                .collect(),
+        }
+    }
+
+    /// Collect the key columns of one batch starting at row `offset`.
+    pub fn record_batch(&self, batch: &RecordBatch, offset: usize) -> Result<(), RuleError> {
+        for (name, keys) in &self.columns {
+            let Some(array) = batch.column_by_name(name) else {
+                continue;
+            };
+            let mut local = ColumnKeys::default();
+            local.record(array, offset)?;
+            keys.lock()
+                .unwrap()
+                .get_or_insert_with(ColumnKeys::default)
+                .merge(local);
+        }
+        Ok(())
+    }
+
+    /// Keys of the columns found in the batches
+    pub fn finalize(self) -> HashMap<String, ColumnKeys> {
+        self.columns
+            .into_iter()
+            .filter_map(|(name, keys)| Some((name, keys.into_inner().unwrap()?)))
+            .collect()
+    }
+}
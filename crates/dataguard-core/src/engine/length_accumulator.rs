@@ -0,0 +1,56 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use arrow_array::{Array, StringArray};
+
+use crate::validator::ExecutableColumn;
+
+/// Manages the histogram of value lengths of string columns across batches.
+///
+/// Only columns with a length distribution rule are tracked. Each batch is counted
+/// locally, then merged into the histogram of its column.
+pub(crate) struct LengthAccumulator {
+    // Count of non-null values per length in characters, keyed by column name
+    lengths: HashMap<String, Mutex<HashMap<usize, usize>>>,
+}
+
+impl LengthAccumulator {
+    pub fn new(columns: &[ExecutableColumn]) -> Self {
+        Self {
+            lengths: columns
+                .iter()
+                .filter_map(|col| match col {
+                    ExecutableColumn::String {
+                        name, length_rules, ..
+                    } if !length_rules.is_empty() => Some((name.clone(), Mutex::default())),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Count the lengths of one batch of `column`, null values are skipped.
+    pub fn record_batch(&self, column: &str, array: &dyn Array) {
+        let Some(lengths) = self.lengths.get(column) else {
+            return;
+        };
+        let Some(array) = array.as_any().downcast_ref::<StringArray>() else {
+            return;
+        };
+        let mut local: HashMap<usize, usize> = HashMap::new();
+        for value in array.iter().flatten() {
+            *local.entry(value.chars().count()).or_default() += 1;
+        }
+        let mut lengths = lengths.lock().unwrap();
+        for (length, count) in local {
+            *lengths.entry(length).or_default() += count;
+        }
+    }
+
+    /// Consume the accumulator, returning the histogram of each tracked column.
+    pub fn finalize(self) -> HashMap<String, HashMap<usize, usize>> {
+        self.lengths
+            .into_iter()
+            .map(|(name, lengths)| (name, lengths.into_inner().unwrap()))
+            .collect()
+    }
+}
@@ -1,10 +1,22 @@
 mod accumulator;
-mod stats_accumulator;
+mod correlation_accumulator;
+mod duplicate_row_accumulator;
+mod group_accumulator;
+mod group_count_accumulator;
+mod key_accumulator;
+mod length_accumulator;
+mod monotonicity_accumulator;
+mod profile_accumulator;
+mod sanity_accumulator;
+pub(crate) mod stats_accumulator;
+mod subset_accumulator;
 mod unicity_accumulator;
 mod validation_engine;
+mod value_count_accumulator;
 
 #[cfg(test)]
 mod tests;
 
-pub use stats_accumulator::Stats;
-pub use validation_engine::ValidationEngine;
+pub use accumulator::DEFAULT_MAX_SAMPLES;
+pub use stats_accumulator::{CorrelationSums, Stats};
+pub use validation_engine::{ParallelAxis, ValidationEngine};
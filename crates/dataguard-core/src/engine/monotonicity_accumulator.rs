@@ -0,0 +1,213 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Arc, Mutex},
+};
+
+use arrow::util::display::array_value_to_string;
+use arrow_array::{Array, Float64Array, Int64Array};
+
+use crate::validator::ExecutableColumn;
+
+/// Value of a numeric column, compared in its native type.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Value {
+    Int(i64),
+    Float(f64),
+}
+
+/// A non-null value of a batch, with its file row.
+struct Boundary {
+    row: usize,
+    value: Value,
+    text: String,
+}
+
+impl Boundary {
+    fn new(array: &dyn Array, row: usize, offset: usize) -> Option<Self> {
+        let value = if let Some(array) = array.as_any().downcast_ref::<Int64Array>() {
+            Value::Int(array.value(row))
+        } else {
+            Value::Float(array.as_any().downcast_ref::<Float64Array>()?.value(row))
+        };
+        Some(Self {
+            row: offset + row,
+            value,
+            text: array_value_to_string(array, row).unwrap_or_default(),
+        })
+    }
+}
+
+/// Order rules of a column and the first and last non-null values of each batch.
+struct ColumnOrder {
+    // (rule name, threshold, ascending)
+    rules: Vec<(String, f64, bool)>,
+    // File row of the first row of each batch → (first, last) non-null values
+    batches: Mutex<BTreeMap<usize, (Boundary, Boundary)>>,
+}
+
+/// Order breaks found between batches for one monotonicity rule.
+pub(crate) struct OrderBreaks {
+    pub column: String,
+    pub rule_name: String,
+    pub threshold: f64,
+    pub count: usize,
+    /// File row and value of the first rows of a batch breaking the order, with the value
+    /// ending the previous batch
+    pub samples: Vec<(usize, String)>,
+}
+
+/// Manages the order of monotonic columns across batches.
+///
+/// Batches are validated apart, each one only comparing its own rows. The first value of a
+/// batch is compared here to the last value of the previous batch holding one, batches
+/// being ordered by their first file row whatever the order they were validated in.
+pub(crate) struct MonotonicityAccumulator {
+    columns: HashMap<String, ColumnOrder>,
+}
+
+impl MonotonicityAccumulator {
+    pub fn new(columns: &[ExecutableColumn]) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .filter_map(|col| {
+                    let rules: Vec<(String, f64, bool)> = match col {
+                        ExecutableColumn::Integer { domain_rules, .. } => domain_rules
+                            .iter()
+                            .filter_map(|r| {
+                                r.ascending_order()
+                                    .map(|asc| (r.name(), r.get_threshold(), asc))
+                            })
+                            .collect(),
+                        ExecutableColumn::Float { domain_rules, .. } => domain_rules
+                            .iter()
+                            .filter_map(|r| {
+                                r.ascending_order()
+                                    .map(|asc| (r.name(), r.get_threshold(), asc))
+                            })
+                            .collect(),
+                        _ => Vec::new(),
+                    };
+                    (!rules.is_empty()).then(|| {
+                        let order = ColumnOrder {
+                            rules,
+                            batches: Mutex::default(),
+                        };
+                        (col.get_name(), order)
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Keep the first and last non-null values of one batch, `offset` is the file row of
+    /// its first row and `casted` holds the arrays that passed the type check.
+    pub fn record_batch(&self, offset: usize, casted: &HashMap<String, Arc<dyn Array>>) {
+        for (name, order) in &self.columns {
+            let Some(array) = casted.get(name) else {
+                continue;
+            };
+            let array = array.as_ref();
+            let first = (0..array.len()).find(|row| array.is_valid(*row));
+            let last = (0..array.len()).rev().find(|row| array.is_valid(*row));
+            let (Some(first), Some(last)) = (first, last) else {
+                continue;
+            };
+            if let (Some(first), Some(last)) = (
+                Boundary::new(array, first, offset),
+                Boundary::new(array, last, offset),
+            ) {
+                order.batches.lock().unwrap().insert(offset, (first, last));
+            }
+        }
+    }
+
+    /// Consume the accumulator, returning the breaks of each rule broken between batches.
+    /// At most `max_samples` breaks are sampled per rule.
+    pub fn finalize(self, max_samples: usize) -> Vec<OrderBreaks> {
+        let mut breaks = Vec::new();
+        for (column, order) in self.columns {
+            let batches = order.batches.into_inner().unwrap();
+            let bounds: Vec<&(Boundary, Boundary)> = batches.values().collect();
+            for (rule_name, threshold, ascending) in order.rules {
+                // (last value of a batch, first value of the next one) breaking the order
+                let broken: Vec<(&Boundary, &Boundary)> = bounds
+                    .windows(2)
+                    .map(|pair| (&pair[0].1, &pair[1].0))
+                    .filter(|(previous, next)| match ascending {
+                        true => next.value < previous.value,
+                        false => next.value > previous.value,
+                    })
+                    .collect();
+                if broken.is_empty() {
+                    continue;
+                }
+                let samples = broken
+                    .iter()
+                    .take(max_samples)
+                    .map(|(previous, next)| {
+                        (
+                            next.row,
+                            format!("{} (previous {})", next.text, previous.text),
+                        )
+                    })
+                    .collect();
+                breaks.push(OrderBreaks {
+                    column: column.clone(),
+                    rule_name,
+                    threshold,
+                    count: broken.len(),
+                    samples,
+                });
+            }
+        }
+        breaks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accumulator(ascending: bool) -> MonotonicityAccumulator {
+        let order = ColumnOrder {
+            rules: vec![("IsIncreasing".to_string(), 0.0, ascending)],
+            batches: Mutex::default(),
+        };
+        MonotonicityAccumulator {
+            columns: HashMap::from([("id".to_string(), order)]),
+        }
+    }
+
+    fn casted(array: Arc<dyn Array>) -> HashMap<String, Arc<dyn Array>> {
+        HashMap::from([("id".to_string(), array)])
+    }
+
+    #[test]
+    fn test_order_breaks_between_batches() {
+        let increasing = accumulator(true);
+        // Recorded out of order, like batches validated in parallel
+        increasing.record_batch(6, &casted(Arc::new(Int64Array::from(vec![7, 9]))));
+        increasing.record_batch(
+            0,
+            &casted(Arc::new(Int64Array::from(vec![Some(1), Some(5), None]))),
+        );
+        // A batch of nulls only is skipped, the next one is compared to the first batch
+        increasing.record_batch(3, &casted(Arc::new(Int64Array::from(vec![None, None]))));
+        increasing.record_batch(5, &casted(Arc::new(Int64Array::from(vec![4]))));
+
+        let breaks = increasing.finalize(10);
+        assert_eq!(breaks.len(), 1);
+        assert_eq!(breaks[0].column, "id");
+        assert_eq!(breaks[0].count, 1);
+        assert_eq!(breaks[0].samples, vec![(5, "4 (previous 5)".to_string())]);
+    }
+
+    #[test]
+    fn test_float_order_kept_between_batches() {
+        let decreasing = accumulator(false);
+        decreasing.record_batch(0, &casted(Arc::new(Float64Array::from(vec![3.5, 2.0]))));
+        decreasing.record_batch(2, &casted(Arc::new(Float64Array::from(vec![2.0, 1.5]))));
+        assert!(decreasing.finalize(10).is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use arrow::datatypes::{DataType, Float64Type, Int64Type, Schema};
+use arrow_array::{cast::AsArray, Array};
+
+use crate::{
+    rules::generic::for_each_hash, utils::distinct_sketch::DistinctSketch,
+    validator::ExecutableColumn, ColumnProfile,
+};
+
+#[derive(Default)]
+struct ColumnSummary {
+    // The column was found in at least one batch
+    seen: bool,
+    null_count: usize,
+    distinct: DistinctSketch,
+    min: Option<f64>,
+    max: Option<f64>,
+    sum: f64,
+    count: usize,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+}
+
+impl ColumnSummary {
+    fn add_value(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn add_length(&mut self, length: usize) {
+        self.min_length = Some(self.min_length.map_or(length, |min| min.min(length)));
+        self.max_length = Some(self.max_length.map_or(length, |max| max.max(length)));
+    }
+
+    fn merge(&mut self, other: ColumnSummary) {
+        self.seen |= other.seen;
+        self.null_count += other.null_count;
+        self.distinct.merge(other.distinct);
+        for value in [other.min, other.max].into_iter().flatten() {
+            self.min = Some(self.min.map_or(value, |min| min.min(value)));
+            self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        }
+        self.sum += other.sum;
+        self.count += other.count;
+        for length in [other.min_length, other.max_length].into_iter().flatten() {
+            self.add_length(length);
+        }
+    }
+
+    fn into_profile(self, name: String) -> ColumnProfile {
+        ColumnProfile {
+            name,
+            null_count: self.null_count,
+            distinct: self.distinct.estimate(),
+            min: self.min,
+            max: self.max,
+            mean: (self.count > 0).then(|| self.sum / self.count as f64),
+            min_length: self.min_length,
+            max_length: self.max_length,
+        }
+    }
+}
+
+/// Summarizes the values of every column when profiling a table.
+///
+/// Works on the arrays casted by the type checks, so no rule needs to be configured.
+/// Distinct values reuse the unicity hashing.
+pub(crate) struct ProfileAccumulator {
+    columns: HashMap<String, Mutex<ColumnSummary>>,
+}
+
+impl ProfileAccumulator {
+    /// Track every column when `enabled`, none otherwise.
+    pub fn new(columns: &[ExecutableColumn], enabled: bool) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .filter(|_| enabled)
+                .map(|col| (col.get_name(), Mutex::default()))
+                .collect(),
+        }
+    }
+
+    /// Summarize one batch of `num_rows` rows, `casted` holds the arrays that passed
+    /// the type check. A column whose cast failed only holds null values.
+    pub fn record_batch(
+        &self,
+        schema: &Schema,
+        num_rows: usize,
+        casted: &HashMap<String, Arc<dyn Array>>,
+    ) {
+        for (name, summary) in &self.columns {
+            if schema.index_of(name).is_err() {
+                continue;
+            }
+            let mut local = ColumnSummary {
+                seen: true,
+                ..Default::default()
+            };
+            match casted.get(name) {
+                Some(array) => {
+                    local.null_count = array.null_count();
+                    for_each_hash(array.as_ref(), |hash| {
+                        local.distinct.insert(hash);
+                        true
+                    });
+                    match array.data_type() {
+                        DataType::Int64 => array
+                            .as_primitive::<Int64Type>()
+                            .iter()
+                            .flatten()
+                            .for_each(|v| local.add_value(v as f64)),
+                        DataType::Float64 => array
+                            .as_primitive::<Float64Type>()
+                            .iter()
+                            .flatten()
+                            .for_each(|v| local.add_value(v)),
+                        DataType::Utf8 => array
+                            .as_string::<i32>()
+                            .iter()
+                            .flatten()
+                            .for_each(|v| local.add_length(v.chars().count())),
+                        _ => {}
+                    }
+                }
+                None => local.null_count = num_rows,
+            }
+            summary.lock().unwrap().merge(local);
+        }
+    }
+
+    /// Consume the accumulator, returning the profile of each column found in the data.
+    pub fn finalize(self) -> Vec<ColumnProfile> {
+        self.columns
+            .into_iter()
+            .map(|(name, summary)| (name, summary.into_inner().unwrap()))
+            .filter(|(_, summary)| summary.seen)
+            .map(|(name, summary)| summary.into_profile(name))
+            .collect()
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use arrow::datatypes::Schema;
+use arrow_array::Array;
+
+use crate::{
+    rules::generic::{distinct_hashes, for_each_hash, SANITY_DISTINCT_LIMIT},
+    utils::{distinct_sketch::DistinctSketch, hasher::Xxh3Builder},
+    validator::ExecutableColumn,
+};
+
+/// Rows, non-null values and first distinct value hashes of a column.
+#[derive(Default)]
+struct ColumnSanity {
+    // The column was found in at least one batch
+    seen: AtomicBool,
+    rows: AtomicUsize,
+    values: AtomicUsize,
+    hashes: Mutex<HashSet<u64, Xxh3Builder>>,
+    // Set when a rule needs the distinct count estimate, replaces `hashes`
+    sketch: Option<Mutex<DistinctSketch>>,
+}
+
+/// Tracks how many values columns hold, and whether they hold distinct ones.
+///
+/// Only columns with a sanity rule are tracked. Distinctness reuses the unicity
+/// hashing, keeping at most [`SANITY_DISTINCT_LIMIT`] hashes per column, or a
+/// [`DistinctSketch`] when a rule counts the distinct values.
+pub(crate) struct SanityAccumulator {
+    columns: HashMap<String, ColumnSanity>,
+}
+
+impl SanityAccumulator {
+    pub fn new(columns: &[ExecutableColumn]) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .filter(|col| !col.sanity_rules().is_empty())
+                .map(|col| {
+                    let counts_distinct = col.sanity_rules().iter().any(|r| r.counts_distinct());
+                    let sanity = ColumnSanity {
+                        sketch: counts_distinct.then(Mutex::default),
+                        ..Default::default()
+                    };
+                    (col.get_name(), sanity)
+                })
+                .collect(),
+        }
+    }
+
+    /// Count the values of one batch of `num_rows` rows, `casted` holds the arrays that
+    /// passed the type check. A column whose cast failed only holds null values.
+    pub fn record_batch(
+        &self,
+        schema: &Schema,
+        num_rows: usize,
+        casted: &HashMap<String, Arc<dyn Array>>,
+    ) {
+        for (name, sanity) in &self.columns {
+            if schema.index_of(name).is_err() {
+                continue;
+            }
+            sanity.seen.store(true, Ordering::Relaxed);
+            sanity.rows.fetch_add(num_rows, Ordering::Relaxed);
+            let Some(array) = casted.get(name) else {
+                continue;
+            };
+            sanity
+                .values
+                .fetch_add(array.len() - array.null_count(), Ordering::Relaxed);
+            if let Some(sketch) = &sanity.sketch {
+                let mut local = DistinctSketch::default();
+                for_each_hash(array.as_ref(), |hash| {
+                    local.insert(hash);
+                    true
+                });
+                sketch.lock().unwrap().merge(local);
+                continue;
+            }
+            if sanity.hashes.lock().unwrap().len() >= SANITY_DISTINCT_LIMIT {
+                continue;
+            }
+            let local = distinct_hashes(array.as_ref(), SANITY_DISTINCT_LIMIT);
+            let mut hashes = sanity.hashes.lock().unwrap();
+            hashes.extend(local);
+        }
+    }
+
+    /// Consume the accumulator, returning the (rows, non-null values, distinct values) of
+    /// each tracked column found in the data. Distinct values are capped, or estimated
+    /// when a rule counts them.
+    pub fn finalize(self) -> HashMap<String, (usize, usize, usize)> {
+        self.columns
+            .into_iter()
+            .filter(|(_, sanity)| sanity.seen.load(Ordering::Relaxed))
+            .map(|(name, sanity)| {
+                let distinct = match sanity.sketch {
+                    Some(sketch) => sketch.into_inner().unwrap().estimate(),
+                    None => sanity.hashes.into_inner().unwrap().len(),
+                };
+                let rows = sanity.rows.into_inner();
+                (name, (rows, sanity.values.into_inner(), distinct))
+            })
+            .collect()
+    }
+}
@@ -7,6 +7,125 @@ use arrow_array::{
 
 use crate::columns::NumericType;
 
+// Higher values keep more centroids, trading memory for quantile accuracy
+const SKETCH_COMPRESSION: f64 = 200.0;
+// Number of raw values buffered before being folded into the centroids
+const SKETCH_BUFFER: usize = 2048;
+
+/// Approximate quantiles over a stream of values, a merging t-digest.
+///
+/// Values are summarized by weighted centroids, smaller near the tails so extreme
+/// quantiles stay accurate. Sketches built on separate batches can be merged.
+#[derive(Debug, Clone, Default)]
+pub struct QuantileSketch {
+    // (mean, weight), sorted by mean
+    centroids: Vec<(f64, f64)>,
+    buffer: Vec<f64>,
+    min: f64,
+    max: f64,
+}
+
+impl QuantileSketch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        if self.is_empty() {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.buffer.push(value);
+        if self.buffer.len() >= SKETCH_BUFFER {
+            self.compress();
+        }
+    }
+
+    pub fn merge(&mut self, other: &QuantileSketch) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            self.min = other.min;
+            self.max = other.max;
+        } else {
+            self.min = self.min.min(other.min);
+            self.max = self.max.max(other.max);
+        }
+        self.centroids.extend_from_slice(&other.centroids);
+        self.buffer.extend_from_slice(&other.buffer);
+        self.compress();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.buffer.is_empty()
+    }
+
+    /// Fold the buffered values into the centroids
+    fn compress(&mut self) {
+        let mut points = std::mem::take(&mut self.centroids);
+        points.extend(self.buffer.drain(..).map(|v| (v, 1.0)));
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let total: f64 = points.iter().map(|(_, w)| w).sum();
+
+        let mut merged: Vec<(f64, f64)> = Vec::new();
+        let mut cumulative = 0.0;
+        for (mean, weight) in points {
+            if let Some(last) = merged.last_mut() {
+                let q = (cumulative + last.1 / 2.0) / total;
+                let limit = (4.0 * total * q * (1.0 - q) / SKETCH_COMPRESSION).max(1.0);
+                if last.1 + weight <= limit {
+                    let new_weight = last.1 + weight;
+                    last.0 += (mean - last.0) * weight / new_weight;
+                    last.1 = new_weight;
+                    continue;
+                }
+                cumulative += last.1;
+            }
+            merged.push((mean, weight));
+        }
+        self.centroids = merged;
+    }
+
+    /// Estimate the `q` quantile, `q` between 0 and 1. Returns `None` without values.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut sketch = self.clone();
+        sketch.compress();
+        let centroids = &sketch.centroids;
+        let total: f64 = centroids.iter().map(|(_, w)| w).sum();
+        let target = q.clamp(0.0, 1.0) * total;
+
+        // Each centroid is centered on the middle of the weight it covers
+        let mut previous = (0.0, sketch.min);
+        let mut cumulative = 0.0;
+        for &(mean, weight) in centroids {
+            let center = cumulative + weight / 2.0;
+            if target <= center {
+                return Some(interpolate(previous, (center, mean), target));
+            }
+            previous = (center, mean);
+            cumulative += weight;
+        }
+        Some(interpolate(previous, (total, sketch.max), target))
+    }
+}
+
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 <= x0 {
+        return y1;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
 /// Statistics for a column, stored with the appropriate native type
 #[derive(Debug, Clone)]
 pub enum Stats {
@@ -16,6 +135,7 @@ pub enum Stats {
         m2: f64,
         min: i64,
         max: i64,
+        sketch: QuantileSketch,
     },
     Float {
         count: usize,
@@ -23,6 +143,7 @@ pub enum Stats {
         m2: f64,
         min: f64,
         max: f64,
+        sketch: QuantileSketch,
     },
 }
 
@@ -60,6 +181,14 @@ impl Stats {
         }
     }
 
+    /// Approximate `q` quantile, see [`QuantileSketch`]
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        match self {
+            Stats::Integer { sketch, .. } => sketch.quantile(q),
+            Stats::Float { sketch, .. } => sketch.quantile(q),
+        }
+    }
+
     /// Population variance (divides by N)
     pub fn population_variance(&self) -> f64 {
         let (count, m2) = match self {
@@ -75,6 +204,57 @@ impl Stats {
     }
 }
 
+/// Running sums of a pair of columns, enough to compute their Pearson correlation.
+///
+/// Only rows where both values are present are summed. Sums built on separate
+/// batches are merged by adding them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CorrelationSums {
+    pub count: usize,
+    pub sum_x: f64,
+    pub sum_y: f64,
+    pub sum_xy: f64,
+    pub sum_x2: f64,
+    pub sum_y2: f64,
+}
+
+impl CorrelationSums {
+    pub fn update(&mut self, x: &PrimitiveArray<Float64Type>, y: &PrimitiveArray<Float64Type>) {
+        for (x, y) in x.iter().zip(y.iter()) {
+            if let (Some(x), Some(y)) = (x, y) {
+                self.count += 1;
+                self.sum_x += x;
+                self.sum_y += y;
+                self.sum_xy += x * y;
+                self.sum_x2 += x * x;
+                self.sum_y2 += y * y;
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: &CorrelationSums) {
+        self.count += other.count;
+        self.sum_x += other.sum_x;
+        self.sum_y += other.sum_y;
+        self.sum_xy += other.sum_xy;
+        self.sum_x2 += other.sum_x2;
+        self.sum_y2 += other.sum_y2;
+    }
+
+    /// Pearson correlation coefficient, `None` with fewer than two rows or a constant column.
+    pub fn coefficient(&self) -> Option<f64> {
+        let n = self.count as f64;
+        let covariance = n * self.sum_xy - self.sum_x * self.sum_y;
+        let variance_x = n * self.sum_x2 - self.sum_x * self.sum_x;
+        let variance_y = n * self.sum_y2 - self.sum_y * self.sum_y;
+        if self.count < 2 || variance_x <= 0.0 || variance_y <= 0.0 {
+            return None;
+        }
+        // Rounding can push a perfect correlation slightly past 1
+        Some((covariance / (variance_x * variance_y).sqrt()).clamp(-1.0, 1.0))
+    }
+}
+
 /// Accumulator for computing statistics across multiple columns
 pub struct StatsAccumulator {
     pub columns: HashMap<String, Stats>,
@@ -98,6 +278,7 @@ impl StatsAccumulator {
                 m2: 0.0,
                 min: i64::MAX,
                 max: i64::MIN,
+                sketch: QuantileSketch::new(),
             });
 
         match stats {
@@ -107,6 +288,7 @@ impl StatsAccumulator {
                 m2,
                 min,
                 max,
+                sketch,
             } => {
                 for &value in array.values() {
                     // Welford's algorithm for mean and m2
@@ -115,6 +297,10 @@ impl StatsAccumulator {
                     // Update min/max with native type
                     Self::update_min_max(count, min, max, value);
                 }
+                // Null slots hold arbitrary values, keep them out of the quantiles
+                for value in array.iter().flatten() {
+                    sketch.add(value.to_f64());
+                }
             }
             Stats::Float { .. } => {
                 panic!(
@@ -136,6 +322,7 @@ impl StatsAccumulator {
                 m2: 0.0,
                 min: f64::MAX,
                 max: f64::MIN,
+                sketch: QuantileSketch::new(),
             });
 
         match stats {
@@ -145,6 +332,7 @@ impl StatsAccumulator {
                 m2,
                 min,
                 max,
+                sketch,
             } => {
                 for &value in array.values() {
                     // Welford's algorithm for mean and m2
@@ -153,6 +341,10 @@ impl StatsAccumulator {
                     // Update min/max with native type
                     Self::update_min_max(count, min, max, value);
                 }
+                // Null slots hold arbitrary values, keep them out of the quantiles
+                for value in array.iter().flatten() {
+                    sketch.add(value.to_f64());
+                }
             }
             Stats::Integer { .. } => {
                 panic!(
@@ -233,6 +425,7 @@ pub fn merge_stats(a: Stats, b: Stats) -> Stats {
                 m2: m2a,
                 min: mina,
                 max: maxa,
+                sketch: mut sketch_a,
             },
             Stats::Integer {
                 count: cb,
@@ -240,6 +433,7 @@ pub fn merge_stats(a: Stats, b: Stats) -> Stats {
                 m2: m2b,
                 min: minb,
                 max: maxb,
+                sketch: sketch_b,
             },
         ) => {
             // Chan's algorithm for merging
@@ -251,6 +445,7 @@ pub fn merge_stats(a: Stats, b: Stats) -> Stats {
                     m2: 0.0,
                     min: i64::MAX,
                     max: i64::MIN,
+                    sketch: QuantileSketch::new(),
                 };
             }
 
@@ -258,12 +453,14 @@ pub fn merge_stats(a: Stats, b: Stats) -> Stats {
             let delta = mb - ma;
             let m2_c = m2a + m2b + delta * delta * (ca * cb) as f64 / count_c as f64;
 
+            sketch_a.merge(&sketch_b);
             Stats::Integer {
                 count: count_c,
                 mean: mean_c,
                 m2: m2_c,
                 min: mina.min(minb),
                 max: maxa.max(maxb),
+                sketch: sketch_a,
             }
         }
         (
@@ -273,6 +470,7 @@ pub fn merge_stats(a: Stats, b: Stats) -> Stats {
                 m2: m2a,
                 min: mina,
                 max: maxa,
+                sketch: mut sketch_a,
             },
             Stats::Float {
                 count: cb,
@@ -280,6 +478,7 @@ pub fn merge_stats(a: Stats, b: Stats) -> Stats {
                 m2: m2b,
                 min: minb,
                 max: maxb,
+                sketch: sketch_b,
             },
         ) => {
             let count_c = ca + cb;
@@ -290,6 +489,7 @@ pub fn merge_stats(a: Stats, b: Stats) -> Stats {
                     m2: 0.0,
                     min: f64::MAX,
                     max: f64::MIN,
+                    sketch: QuantileSketch::new(),
                 };
             }
 
@@ -297,12 +497,14 @@ pub fn merge_stats(a: Stats, b: Stats) -> Stats {
             let delta = mb - ma;
             let m2_c = m2a + m2b + delta * delta * (ca * cb) as f64 / count_c as f64;
 
+            sketch_a.merge(&sketch_b);
             Stats::Float {
                 count: count_c,
                 mean: mean_c,
                 m2: m2_c,
                 min: mina.min(minb),
                 max: maxa.max(maxb),
+                sketch: sketch_a,
             }
         }
         _ => panic!("Cannot merge Integer and Float stats"),
@@ -443,6 +645,41 @@ mod tests {
         assert_eq!(stats.population_variance(), 4.0);
     }
 
+    #[test]
+    fn test_quantile_sketch_small() {
+        let mut acc = StatsAccumulator::new();
+        let arr = Int64Array::from(vec![Some(1), Some(2), None, Some(3), Some(4), Some(5)]);
+        acc.update_integer("values", &arr);
+
+        let stats = acc.columns.get("values").unwrap();
+        assert_eq!(stats.quantile(0.0), Some(1.0));
+        assert_eq!(stats.quantile(0.5), Some(3.0));
+        assert_eq!(stats.quantile(1.0), Some(5.0));
+    }
+
+    #[test]
+    fn test_quantile_sketch_merge() {
+        // Two halves of 1..=100_000 sketched separately then merged
+        let mut a = QuantileSketch::new();
+        let mut b = QuantileSketch::new();
+        for v in 1..=50_000 {
+            a.add(v as f64);
+            b.add((v + 50_000) as f64);
+        }
+        a.merge(&b);
+
+        for (q, expected) in [(0.5, 50_000.0), (0.9, 90_000.0), (0.99, 99_000.0)] {
+            let estimate = a.quantile(q).unwrap();
+            assert!(
+                (estimate - expected).abs() / expected < 0.01,
+                "q{} estimated at {}",
+                q,
+                estimate
+            );
+        }
+        assert_eq!(QuantileSketch::new().quantile(0.5), None);
+    }
+
     #[test]
     #[should_panic(expected = "Type mismatch")]
     fn test_type_mismatch_panic() {
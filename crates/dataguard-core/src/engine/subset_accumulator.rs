@@ -0,0 +1,116 @@
+use std::{collections::HashSet, sync::Mutex};
+
+use arrow::util::display::array_value_to_string;
+use arrow_array::Array;
+
+use crate::{
+    rules::{generic::for_each_row_hash, relations::SubsetValues},
+    utils::hasher::Xxh3Builder,
+    validator::ExecutableRelation,
+};
+
+/// Values of both columns of a subset relation.
+#[derive(Default)]
+pub(crate) struct SubsetColumns {
+    /// Distinct values of the first column, with their rows
+    pub values: SubsetValues,
+    /// Hashes of the distinct values of the second column
+    pub reference: HashSet<u64, Xxh3Builder>,
+}
+
+/// Collects the values of the relations with a subset check across batches.
+///
+/// Values are hashed the way the unicity check does. Memory grows with the number of
+/// distinct values of both columns, the first column also keeping the text of its values
+/// for the samples. Membership is only checked once all batches are done.
+pub(crate) struct SubsetAccumulator {
+    // One entry per relation, `None` when it has no subset check
+    columns: Vec<Option<Mutex<SubsetColumns>>>,
+}
+
+impl SubsetAccumulator {
+    pub fn new(relations: &[ExecutableRelation]) -> Self {
+        Self {
+            columns: relations
+                .iter()
+                .map(|r| (!r.subsets.is_empty()).then(Mutex::default))
+                .collect(),
+        }
+    }
+
+    /// Collect one batch starting at row `offset` for the relation at `index`.
+    pub fn record_batch(&self, index: usize, offset: usize, lhs: &dyn Array, rhs: &dyn Array) {
+        let Some(columns) = &self.columns[index] else {
+            return;
+        };
+        let mut local = SubsetColumns::default();
+        for_each_row_hash(lhs, |row, hash| {
+            local
+                .values
+                .entry(hash)
+                .and_modify(|(rows, _, _)| *rows += 1)
+                .or_insert_with(|| {
+                    let value = array_value_to_string(lhs, row).unwrap_or_default();
+                    (1, offset + row, value)
+                });
+            true
+        });
+        for_each_row_hash(rhs, |_, hash| {
+            local.reference.insert(hash);
+            true
+        });
+
+        let mut columns = columns.lock().unwrap();
+        for (hash, (rows, first_row, value)) in local.values {
+            columns
+                .values
+                .entry(hash)
+                .and_modify(|entry| {
+                    entry.0 += rows;
+                    if first_row < entry.1 {
+                        entry.1 = first_row;
+                    }
+                })
+                .or_insert((rows, first_row, value));
+        }
+        columns.reference.extend(local.reference);
+    }
+
+    /// Consume the accumulator, returning the collected values of each relation.
+    pub fn finalize(self) -> Vec<Option<SubsetColumns>> {
+        self.columns
+            .into_iter()
+            .map(|columns| columns.map(|c| c.into_inner().unwrap()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::StringArray;
+
+    #[test]
+    fn test_subset_accumulator_merges_batches() {
+        let relation = ExecutableRelation::new(
+            ["child".to_string(), "parent".to_string()],
+            Vec::new(),
+            Vec::new(),
+            vec![crate::rules::relations::SubsetCheck::new(0.0)],
+        );
+        let accumulator = SubsetAccumulator::new(std::slice::from_ref(&relation));
+        let child = StringArray::from(vec![Some("a"), Some("z"), None]);
+        let parent = StringArray::from(vec![Some("a"), Some("b"), Some("c")]);
+        // Batches are recorded in any order
+        accumulator.record_batch(0, 3, &child, &parent);
+        accumulator.record_batch(0, 0, &child, &parent);
+
+        let columns = accumulator.finalize().pop().flatten().unwrap();
+        assert_eq!(columns.reference.len(), 3);
+        assert_eq!(columns.values.len(), 2);
+        let (missing, samples) =
+            relation.subsets[0].validate(&columns.values, &columns.reference, 5);
+        assert_eq!(missing, 2);
+        assert_eq!(samples, vec![(1, "z".to_string())]);
+    }
+}
@@ -2,7 +2,9 @@
 
 use std::sync::Arc;
 
-use arrow::array::{Float64Array, Int64Array, StringArray};
+use arrow::array::{
+    ArrayRef, Float64Array, Int64Array, Int64Builder, MapBuilder, StringArray, StringBuilder,
+};
 use arrow::datatypes::{DataType, Field, Schema};
 use arrow::record_batch::RecordBatch;
 
@@ -11,12 +13,13 @@ use crate::columns::{
     relation_builder::RelationBuilder, string_builder::StringColumnBuilder,
 };
 use crate::compiler;
+use crate::rules::{relations::CorrelationCheck, NormalizeMode};
 use crate::utils::operator::CompOperator;
-use crate::validator::ExecutableColumn;
+use crate::validator::{ExecutableColumn, ExecutableRelation};
 
-use super::accumulator::ResultAccumulator;
+use super::accumulator::{ResultAccumulator, MAX_SAMPLES_CAP};
 use super::unicity_accumulator::UnicityAccumulator;
-use super::ValidationEngine;
+use super::{ParallelAxis, ValidationEngine};
 
 // ============================================================================
 // Test Utilities
@@ -49,14 +52,16 @@ fn create_float_batch(column_name: &str, values: Vec<Option<f64>>) -> Arc<Record
 /// Create an ExecutableColumn for a string column with unicity check.
 fn create_string_column_with_unicity(name: &str) -> ExecutableColumn {
     let mut builder = StringColumnBuilder::new(name.to_string());
-    builder.is_unique(0.0);
+    builder.is_unique(NormalizeMode::None, 0.0);
     compiler::compile_column(Box::new(builder), true).unwrap()
 }
 
 /// Create an ExecutableColumn for a string column with length constraints.
 fn create_string_column_with_length(name: &str, min: usize, max: usize) -> ExecutableColumn {
     let mut builder = StringColumnBuilder::new(name.to_string());
-    builder.with_min_length(min, 0.0).with_max_length(max, 0.0);
+    builder
+        .with_min_length(min, false, 0.0)
+        .with_max_length(max, false, 0.0);
     compiler::compile_column(Box::new(builder), true).unwrap()
 }
 
@@ -68,14 +73,14 @@ fn create_int_column_with_range(name: &str, min: i64, max: i64) -> ExecutableCol
 }
 
 /// Create an ExecutableColumn for an integer column with stats.
-fn create_int_column_with_stats(name: &str, min: i64, max: i64) -> ExecutableColumn {
+fn create_int_column_with_stats(name: &str, _min: i64, _max: i64) -> ExecutableColumn {
     let mut builder = NumericColumnBuilder::<i64>::new(name.to_string());
     builder.std_dev_check(0., 0.);
     compiler::compile_column(Box::new(builder), true).unwrap()
 }
 
 /// Create an ExecutableColumn for an integer column with stats.
-fn create_float_column_with_stats(name: &str, min: i64, max: i64) -> ExecutableColumn {
+fn create_float_column_with_stats(name: &str, _min: i64, _max: i64) -> ExecutableColumn {
     let mut builder = NumericColumnBuilder::<f64>::new(name.to_string());
     builder.std_dev_check(0., 0.);
     compiler::compile_column(Box::new(builder), true).unwrap()
@@ -142,7 +147,7 @@ mod result_accumulator_tests {
     fn test_record_single_result() {
         let accumulator = ResultAccumulator::new();
         accumulator.set_total_rows(100);
-        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5);
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5, Vec::new());
         accumulator.record_valid_values("column1", 5);
 
         let (valid_values, column_results, relation_results) = accumulator.to_results();
@@ -164,8 +169,8 @@ mod result_accumulator_tests {
         let accumulator = ResultAccumulator::new();
         accumulator.set_total_rows(100);
         accumulator.record_valid_values("column1", 100);
-        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5);
-        accumulator.record_column_result("column1", "rule2".to_string(), 0.0, 10);
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5, Vec::new());
+        accumulator.record_column_result("column1", "rule2".to_string(), 0.0, 10, Vec::new());
 
         let (_, column_results, _relation_results) = accumulator.to_results();
         assert_eq!(column_results.len(), 1);
@@ -178,9 +183,9 @@ mod result_accumulator_tests {
     fn test_record_multiple_columns() {
         let accumulator = ResultAccumulator::new();
         accumulator.set_total_rows(100);
-        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5);
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5, Vec::new());
         accumulator.record_valid_values("column1", 100);
-        accumulator.record_column_result("column2", "rule1".to_string(), 0.0, 10);
+        accumulator.record_column_result("column2", "rule1".to_string(), 0.0, 10, Vec::new());
         accumulator.record_valid_values("column2", 100);
 
         let (_, column_results, _relation_results) = accumulator.to_results();
@@ -194,7 +199,7 @@ mod result_accumulator_tests {
         let accumulator = ResultAccumulator::new();
         accumulator.set_total_rows(200);
         accumulator.record_valid_values("column1", 200);
-        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 50);
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 50, Vec::new());
 
         let (_, column_results, _relation_results) = accumulator.to_results();
         let column_res = &column_results["column1"];
@@ -206,7 +211,7 @@ mod result_accumulator_tests {
         let accumulator = ResultAccumulator::new();
         accumulator.set_total_rows(0);
         accumulator.record_valid_values("column1", 0);
-        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5);
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5, Vec::new());
 
         let (_, column_results, _relation_results) = accumulator.to_results();
         let column_res = &column_results["column1"];
@@ -218,9 +223,9 @@ mod result_accumulator_tests {
         let accumulator = ResultAccumulator::new();
         accumulator.set_total_rows(100);
         accumulator.record_valid_values("column1", 100);
-        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5);
-        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 3);
-        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 2);
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 5, Vec::new());
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 3, Vec::new());
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 2, Vec::new());
 
         let (_, column_results, _relation_results) = accumulator.to_results();
         let column_res = &column_results["column1"];
@@ -234,9 +239,9 @@ mod result_accumulator_tests {
         accumulator.record_valid_values("zebra", 100);
         accumulator.record_valid_values("apple", 100);
         accumulator.record_valid_values("banana", 100);
-        accumulator.record_column_result("zebra", "rule1".to_string(), 0.0, 1);
-        accumulator.record_column_result("apple", "rule1".to_string(), 0.0, 1);
-        accumulator.record_column_result("banana", "rule1".to_string(), 0.0, 1);
+        accumulator.record_column_result("zebra", "rule1".to_string(), 0.0, 1, Vec::new());
+        accumulator.record_column_result("apple", "rule1".to_string(), 0.0, 1, Vec::new());
+        accumulator.record_column_result("banana", "rule1".to_string(), 0.0, 1, Vec::new());
 
         let (_, column_results, _relation_results) = accumulator.to_results();
         let keys: Vec<_> = column_results.keys().cloned().collect();
@@ -256,8 +261,8 @@ mod result_accumulator_tests {
         // Record from multiple threads
         (0..10).into_par_iter().for_each(|i| {
             let colname = &format!("column{}", i);
-            accumulator.record_valid_values(&colname, 100);
-            accumulator.record_column_result(&colname, "rule1".to_string(), 0.0, 1);
+            accumulator.record_valid_values(colname, 100);
+            accumulator.record_column_result(colname, "rule1".to_string(), 0.0, 1, Vec::new());
         });
 
         let (_, column_results, _relation_results) = accumulator.to_results();
@@ -272,13 +277,84 @@ mod result_accumulator_tests {
 
         // Multiple threads recording to same (column, rule)
         (0..100).into_par_iter().for_each(|_| {
-            accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 1);
+            accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 1, Vec::new());
         });
 
         let (_, column_results, _relation_results) = accumulator.to_results();
         let column_res = &column_results["column1"];
         assert_eq!(column_res[0].error_count, 100);
     }
+
+    #[test]
+    fn test_samples_keep_lowest_rows() {
+        let accumulator = ResultAccumulator::new().with_max_samples(3);
+        accumulator.set_total_rows(100);
+        accumulator.record_valid_values("column1", 100);
+        // Batches may complete out of order
+        let batch_two = vec![(50, "e".to_string()), (52, "f".to_string())];
+        let batch_one = vec![(1, "a".to_string()), (3, "b".to_string())];
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 2, batch_two);
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 2, batch_one);
+
+        let (_, column_results, _relation_results) = accumulator.to_results();
+        let samples = &column_results["column1"][0].samples;
+        assert_eq!(
+            samples,
+            &vec![
+                (1, "a".to_string()),
+                (3, "b".to_string()),
+                (50, "e".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_samples_capped() {
+        // A misconfigured count is capped
+        let accumulator = ResultAccumulator::new().with_max_samples(usize::MAX);
+        assert_eq!(accumulator.max_samples(), MAX_SAMPLES_CAP);
+
+        let accumulator = ResultAccumulator::new().with_max_samples(2);
+        accumulator.set_total_rows(100);
+        accumulator.record_valid_values("column1", 100);
+        let batch = |rows: &[usize]| rows.iter().map(|r| (*r, r.to_string())).collect();
+        accumulator.record_column_result(
+            "column1",
+            "rule1".to_string(),
+            0.0,
+            3,
+            batch(&[10, 11, 12]),
+        );
+        // Rows above the highest kept one are dropped, lower ones still replace it
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 1, batch(&[20]));
+        accumulator.record_column_result("column1", "rule1".to_string(), 0.0, 1, batch(&[5]));
+
+        let (_, column_results, _relation_results) = accumulator.to_results();
+        let rows: Vec<usize> = column_results["column1"][0]
+            .samples
+            .iter()
+            .map(|(row, _)| *row)
+            .collect();
+        assert_eq!(rows, vec![5, 10]);
+        assert_eq!(column_results["column1"][0].error_count, 5);
+    }
+
+    #[test]
+    fn test_samples_disabled() {
+        let accumulator = ResultAccumulator::new().with_max_samples(0);
+        accumulator.set_total_rows(10);
+        accumulator.record_valid_values("column1", 10);
+        accumulator.record_column_result(
+            "column1",
+            "rule1".to_string(),
+            0.0,
+            1,
+            vec![(1, "a".to_string())],
+        );
+
+        let (_, column_results, _relation_results) = accumulator.to_results();
+        assert!(column_results["column1"][0].samples.is_empty());
+    }
 }
 
 // ============================================================================
@@ -290,7 +366,7 @@ mod unicity_accumulator_tests {
     use super::*;
     use crate::utils::hasher::Xxh3Builder;
     use rayon::prelude::*;
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
     use xxhash_rust::xxh3::xxh3_64;
 
     #[test]
@@ -478,6 +554,43 @@ mod unicity_accumulator_tests {
         let results = accumulator.finalize(100);
         assert_eq!(results.len(), 0);
     }
+
+    #[test]
+    fn test_record_groups_across_batches() {
+        let mut builder = StringColumnBuilder::new("email".to_string());
+        builder.is_unique_within("tenant_id", 0.0);
+        let columns = vec![compiler::compile_column(Box::new(builder), true).unwrap()];
+        let accumulator = UnicityAccumulator::new(&columns, 1);
+        assert_eq!(accumulator.finalize(4).len(), 0);
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("tenant_id", DataType::Int64, true),
+            Field::new("email", DataType::Utf8, true),
+        ]));
+        let batch = |tenants: Vec<Option<i64>>, emails: Vec<Option<&str>>| {
+            let emails: Arc<dyn arrow::array::Array> = Arc::new(StringArray::from(emails));
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(Int64Array::from(tenants)), emails.clone()],
+            )
+            .unwrap();
+            (batch, HashMap::from([("email".to_string(), emails)]))
+        };
+        let (first, first_arrays) = batch(
+            vec![Some(1), Some(2), None],
+            vec![Some("a@x.com"), Some("a@x.com"), Some("a@x.com")],
+        );
+        let (second, second_arrays) = batch(
+            vec![Some(1), Some(2), Some(2)],
+            vec![Some("a@x.com"), Some("b@x.com"), None],
+        );
+        accumulator.record_groups(&first, &first_arrays);
+        accumulator.record_groups(&second, &second_arrays);
+
+        // Only the second `a@x.com` of tenant 1 is a duplicate, null keys and values are skipped
+        let results = accumulator.finalize_groups();
+        assert_eq!(results["email"], (1, 0.0));
+    }
 }
 
 // ============================================================================
@@ -503,6 +616,7 @@ fn test_cols_with_stats() {
 #[cfg(test)]
 mod validation_engine_tests {
     use super::*;
+    use crate::{RuleError, ValidationResult};
 
     #[test]
     fn test_engine_creation() {
@@ -513,6 +627,442 @@ mod validation_engine_tests {
         // Just ensure it doesn't panic
     }
 
+    #[test]
+    fn test_parallel_axis_resolve() {
+        let threads = rayon::current_num_threads();
+        // A single thread has nothing to gain from splitting columns
+        let wide = if threads > 1 {
+            ParallelAxis::Columns
+        } else {
+            ParallelAxis::Batches
+        };
+        assert_eq!(ParallelAxis::Auto.resolve(1, 200), wide);
+        assert_eq!(ParallelAxis::Auto.resolve(1, 1), ParallelAxis::Batches);
+        assert_eq!(
+            ParallelAxis::Auto.resolve(threads, 200),
+            ParallelAxis::Batches
+        );
+        assert_eq!(ParallelAxis::Batches.resolve(1, 200), ParallelAxis::Batches);
+        assert_eq!(
+            ParallelAxis::Columns.resolve(threads * 2, 1),
+            ParallelAxis::Columns
+        );
+    }
+
+    #[test]
+    fn test_parallel_axes_same_results() {
+        let columns = vec![
+            create_string_column_with_unicity("email"),
+            create_int_column_with_range("age", 0, 100),
+        ]
+        .into_boxed_slice();
+        let relations = None;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("email", DataType::Utf8, true),
+            Field::new("age", DataType::Int64, true),
+        ]));
+        let batches: Vec<Arc<RecordBatch>> = [
+            (vec!["a", "b", "a"], vec![10, 200, 30]),
+            (vec!["c", "b", "d"], vec![-1, 40, 50]),
+        ]
+        .into_iter()
+        .map(|(emails, ages)| {
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(StringArray::from(emails)),
+                    Arc::new(Int64Array::from(ages)),
+                ],
+            )
+            .unwrap();
+            Arc::new(batch)
+        })
+        .collect();
+
+        for axis in [ParallelAxis::Batches, ParallelAxis::Columns] {
+            let engine = ValidationEngine::new(&columns, &relations).with_parallel_axis(axis);
+            let result = engine
+                .validate_batches("test_table".to_string(), &batches)
+                .unwrap();
+            let column_results = result.get_column_results();
+            let errors = |column: &str, rule: &str| {
+                column_results[column]
+                    .iter()
+                    .find(|r| r.rule_name == rule)
+                    .map(|r| r.error_count)
+                    .unwrap()
+            };
+            assert_eq!(errors("email", "Unicity"), 2, "{:?}", axis);
+            assert_eq!(errors("age", "Between"), 2, "{:?}", axis);
+        }
+    }
+
+    #[test]
+    fn test_streaming_same_results() {
+        let mut age = NumericColumnBuilder::<i64>::new("age".to_string());
+        age.between(0, 100, 0.0)
+            .no_outliers_beyond(1.5, 0.0)
+            .unwrap();
+        let columns = vec![
+            create_string_column_with_unicity("email"),
+            compiler::compile_column(Box::new(age), true).unwrap(),
+        ]
+        .into_boxed_slice();
+        let relations = None;
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("email", DataType::Utf8, true),
+            Field::new("age", DataType::Int64, true),
+        ]));
+        let batches: Vec<Arc<RecordBatch>> = (0..rayon::current_num_threads() * 2 + 1)
+            .map(|i| {
+                let i = i as i64;
+                let batch = RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(StringArray::from(vec![
+                            "same".to_string(),
+                            format!("{}", i),
+                        ])),
+                        Arc::new(Int64Array::from(vec![
+                            30 + i,
+                            if i == 1 { 200 } else { 40 },
+                        ])),
+                    ],
+                )
+                .unwrap();
+                Arc::new(batch)
+            })
+            .collect();
+
+        let engine = ValidationEngine::new(&columns, &relations);
+        let in_memory = engine
+            .validate_batches("test_table".to_string(), &batches)
+            .unwrap();
+        let opened = std::cell::Cell::new(0);
+        let streamed = engine
+            .validate_batches_streaming("test_table".to_string(), || {
+                opened.set(opened.get() + 1);
+                Ok(batches.clone().into_iter().map(Ok::<_, RuleError>))
+            })
+            .unwrap();
+        // Outliers need the stats of the whole column, the stream is read twice
+        assert_eq!(opened.get(), 2);
+        assert_eq!(streamed.total_rows, in_memory.total_rows);
+
+        let summary = |result: &ValidationResult| {
+            let mut rules: Vec<_> = result
+                .get_column_results()
+                .into_iter()
+                .flat_map(|(column, rules)| {
+                    rules.into_iter().map(move |r| {
+                        (
+                            column.clone(),
+                            r.rule_name.clone(),
+                            r.error_count,
+                            r.samples.clone(),
+                            r.observed.clone(),
+                        )
+                    })
+                })
+                .collect();
+            rules.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+            rules
+        };
+        assert_eq!(summary(&streamed), summary(&in_memory));
+        let errors = |column: &str, rule: &str| {
+            summary(&streamed)
+                .into_iter()
+                .find(|r| r.0 == column && r.1 == rule)
+                .unwrap()
+                .2
+        };
+        assert_eq!(errors("email", "Unicity"), batches.len() - 1);
+        assert_eq!(errors("age", "Between"), 1);
+    }
+
+    #[test]
+    fn test_fully_failing_column_samples_bounded() {
+        let columns = vec![create_int_column_with_range("age", 0, 100)].into_boxed_slice();
+        let relations = None;
+        let batches: Vec<Arc<RecordBatch>> = (0..20)
+            .map(|_| create_int_batch("age", vec![Some(500); 10_000]))
+            .collect();
+
+        let engine = ValidationEngine::new(&columns, &relations).with_max_samples(usize::MAX);
+        let result = engine
+            .validate_batches("test_table".to_string(), &batches)
+            .unwrap();
+        let between = result.get_column_results()["age"]
+            .iter()
+            .find(|r| r.rule_name == "Between")
+            .cloned()
+            .unwrap();
+        assert_eq!(between.error_count, 200_000);
+        assert_eq!(between.samples.len(), MAX_SAMPLES_CAP);
+        assert_eq!(between.samples[0].0, 0);
+        assert_eq!(between.samples[MAX_SAMPLES_CAP - 1].0, MAX_SAMPLES_CAP - 1);
+    }
+
+    #[test]
+    fn test_monotonicity_samples_across_batches() {
+        let mut builder = NumericColumnBuilder::<i64>::new("id".to_string());
+        builder.is_monotonically_increasing(0.0);
+        let columns =
+            vec![compiler::compile_column(Box::new(builder), true).unwrap()].into_boxed_slice();
+        let relations = None;
+        let batches = vec![
+            create_int_batch("id", vec![Some(1), Some(3), Some(2)]),
+            create_int_batch("id", vec![Some(0), Some(6), Some(5)]),
+        ];
+
+        let engine = ValidationEngine::new(&columns, &relations);
+        let result = engine
+            .validate_batches("test_table".to_string(), &batches)
+            .unwrap();
+        let increasing = result.get_column_results()["id"]
+            .iter()
+            .find(|r| r.rule_name == "IsIncreasing")
+            .cloned()
+            .unwrap();
+        // The break between the two batches is counted with the ones inside them
+        assert_eq!(increasing.error_count, 3);
+        // Samples point to the file rows, with the value preceding the break
+        assert_eq!(
+            increasing.samples,
+            vec![
+                (2, "2 (previous 3)".to_string()),
+                (3, "0 (previous 2)".to_string()),
+                (5, "5 (previous 6)".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_monotonicity_across_streamed_chunks() {
+        let mut builder = NumericColumnBuilder::<i64>::new("id".to_string());
+        builder.is_monotonically_decreasing(0.0);
+        let columns =
+            vec![compiler::compile_column(Box::new(builder), true).unwrap()].into_boxed_slice();
+        let relations = None;
+        let engine = ValidationEngine::new(&columns, &relations);
+        // Each batch is monotonic, their concatenation is not
+        let result = engine
+            .validate_batches_streaming("test_table".to_string(), || {
+                Ok::<_, RuleError>(vec![
+                    Ok::<_, RuleError>(create_int_batch("id", vec![Some(9), Some(7)])),
+                    Ok(create_int_batch("id", vec![None, Some(8), Some(4)])),
+                    Ok(create_int_batch("id", vec![Some(4), Some(1)])),
+                ])
+            })
+            .unwrap();
+        let decreasing = result.get_column_results()["id"]
+            .iter()
+            .find(|r| r.rule_name == "IsDecreasing")
+            .cloned()
+            .unwrap();
+        assert_eq!(decreasing.error_count, 1);
+        assert_eq!(decreasing.samples, vec![(3, "8 (previous 7)".to_string())]);
+    }
+
+    #[test]
+    fn test_fail_fast_reports_the_tripping_rule() {
+        let columns = vec![create_int_column_with_range("age", 0, 100)].into_boxed_slice();
+        let relations = None;
+        let batches: Vec<Arc<RecordBatch>> = (0..4)
+            .map(|i| create_int_batch("age", vec![Some(10), Some(150 + i), Some(20)]))
+            .collect();
+
+        // The full scan counts every failing row
+        let engine = ValidationEngine::new(&columns, &relations);
+        let result = engine
+            .validate_batches("test_table".to_string(), &batches)
+            .unwrap();
+        let between = result.get_column_results()["age"]
+            .iter()
+            .find(|r| r.rule_name == "Between")
+            .cloned()
+            .unwrap();
+        assert_eq!(between.error_count, 4);
+
+        let engine = ValidationEngine::new(&columns, &relations).with_fail_fast(true);
+        let result = engine
+            .validate_batches("test_table".to_string(), &batches)
+            .unwrap();
+        let column_results = result.get_column_results();
+        assert_eq!(column_results.len(), 1);
+        let tripped = &column_results["age"];
+        assert_eq!(tripped.len(), 1);
+        assert_eq!(tripped[0].rule_name, "Between");
+        assert!(!tripped[0].pass);
+        assert!(tripped[0].error_count >= 1);
+        assert!(tripped[0].error_message.is_some());
+        assert_ne!(result.is_passed().0, result.is_passed().1);
+    }
+
+    #[test]
+    fn test_fail_fast_passing_table_is_fully_validated() {
+        let mut builder = NumericColumnBuilder::<i64>::new("id".to_string());
+        builder.between(0, 100, 30.0).is_unique(0.0);
+        let columns =
+            vec![compiler::compile_column(Box::new(builder), true).unwrap()].into_boxed_slice();
+        let relations = None;
+        // One value out of range on 4 rows stays under the 30% threshold
+        let batches = vec![
+            create_int_batch("id", vec![Some(1), Some(2)]),
+            create_int_batch("id", vec![Some(500), Some(2)]),
+        ];
+
+        let engine = ValidationEngine::new(&columns, &relations).with_fail_fast(true);
+        let result = engine
+            .validate_batches("test_table".to_string(), &batches)
+            .unwrap();
+        let error_count = |rule: &str| {
+            result.get_column_results()["id"]
+                .iter()
+                .find(|r| r.rule_name == rule)
+                .unwrap()
+                .error_count
+        };
+        assert_eq!(error_count("Between"), 1);
+        // Rules needing the whole column still run when nothing tripped
+        assert_eq!(error_count("Unicity"), 1);
+    }
+
+    #[test]
+    fn test_fail_fast_stops_reading_the_stream() {
+        let columns = vec![create_int_column_with_range("age", 0, 100)].into_boxed_slice();
+        let relations = None;
+        let engine = ValidationEngine::new(&columns, &relations).with_fail_fast(true);
+        let pulled = std::sync::atomic::AtomicUsize::new(0);
+        // One thread validates windows of a single batch
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let result = pool
+            .install(|| {
+                engine.validate_batches_streaming("test_table".to_string(), || {
+                    Ok((0..10).map(|i| {
+                        pulled.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        let age = if i == 1 { 200 } else { 30 };
+                        Ok::<_, RuleError>(create_int_batch("age", vec![Some(age)]))
+                    }))
+                })
+            })
+            .unwrap();
+        assert_eq!(pulled.load(std::sync::atomic::Ordering::Relaxed), 2);
+        assert_eq!(result.total_rows, 2);
+        let tripped = &result.get_column_results()["age"][0];
+        assert_eq!(tripped.rule_name, "Between");
+        assert_eq!(tripped.error_count, 1);
+        assert_eq!(tripped.samples, vec![(1, "200".to_string())]);
+    }
+
+    #[test]
+    fn test_max_errors_aborts_validation() {
+        let columns = vec![create_int_column_with_range("age", 0, 100)].into_boxed_slice();
+        let relations = None;
+        let batches: Vec<Arc<RecordBatch>> = (0..10)
+            .map(|_| create_int_batch("age", vec![Some(150), Some(10), Some(200)]))
+            .collect();
+        // One thread validates the batches in order
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+
+        // Under the limit, every batch is validated
+        let engine = ValidationEngine::new(&columns, &relations).with_max_errors(Some(100));
+        let result = pool
+            .install(|| engine.validate_batches("test_table".to_string(), &batches))
+            .unwrap();
+        assert!(!result.aborted);
+        assert_eq!(result.total_rows, 30);
+
+        let engine = ValidationEngine::new(&columns, &relations).with_max_errors(Some(3));
+        let result = pool
+            .install(|| engine.validate_batches("test_table".to_string(), &batches))
+            .unwrap();
+        assert!(result.aborted);
+        // The limit is checked once a batch is validated
+        assert_eq!(result.total_rows, 6);
+        let between = result.get_column_results()["age"]
+            .iter()
+            .find(|r| r.rule_name == "Between")
+            .cloned()
+            .unwrap();
+        assert_eq!(between.error_count, 4);
+        // The type check passes, the range rule and the abort fail
+        assert_eq!(result.is_passed(), (1, 3));
+    }
+
+    #[test]
+    fn test_max_errors_stops_reading_the_stream() {
+        let columns = vec![create_int_column_with_range("age", 0, 100)].into_boxed_slice();
+        let relations = None;
+        let engine = ValidationEngine::new(&columns, &relations).with_max_errors(Some(2));
+        let pulled = std::sync::atomic::AtomicUsize::new(0);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let result = pool
+            .install(|| {
+                engine.validate_batches_streaming("test_table".to_string(), || {
+                    Ok((0..10).map(|_| {
+                        pulled.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                        Ok::<_, RuleError>(create_int_batch("age", vec![Some(200), Some(30)]))
+                    }))
+                })
+            })
+            .unwrap();
+        assert_eq!(pulled.load(std::sync::atomic::Ordering::Relaxed), 2);
+        assert!(result.aborted);
+        assert_eq!(result.total_rows, 4);
+        let between = result.get_column_results()["age"]
+            .iter()
+            .find(|r| r.rule_name == "Between")
+            .cloned()
+            .unwrap();
+        assert_eq!(between.error_count, 2);
+    }
+
+    #[test]
+    fn test_streaming_error_ends_validation() {
+        let columns = vec![create_int_column_with_range("age", 0, 100)].into_boxed_slice();
+        let relations = None;
+        let engine = ValidationEngine::new(&columns, &relations);
+        let result = engine.validate_batches_streaming("test_table".to_string(), || {
+            Ok(vec![
+                Ok(create_int_batch("age", vec![Some(1)])),
+                Err(std::io::Error::other("truncated file")),
+            ])
+        });
+        assert!(matches!(result, Err(RuleError::IoError(_))));
+    }
+
+    #[test]
+    fn test_duplicate_rows_unsupported_type_fails_validation() {
+        let columns = vec![].into_boxed_slice();
+        let relations = None;
+        let engine = ValidationEngine::new(&columns, &relations).with_duplicate_rows(true);
+
+        // Map columns can not be encoded in the Arrow row format
+        let mut builder = MapBuilder::new(None, StringBuilder::new(), Int64Builder::new());
+        builder.keys().append_value("a");
+        builder.values().append_value(1);
+        builder.append(true).unwrap();
+        builder.append(true).unwrap();
+        let batch = RecordBatch::try_from_iter(vec![(
+            "attributes",
+            Arc::new(builder.finish()) as ArrayRef,
+        )])
+        .unwrap();
+
+        let result = engine.validate_batches("test_table".to_string(), &[Arc::new(batch)]);
+        assert!(matches!(result, Err(RuleError::ArrowError(_))));
+    }
+
     #[test]
     fn test_engine_empty_columns() {
         let columns = vec![].into_boxed_slice();
@@ -887,7 +1437,7 @@ mod validation_engine_tests {
 
         // Create relation: start_date <= end_date
         let mut relation = RelationBuilder::new(["start_date".to_string(), "end_date".to_string()]);
-        relation.date_comparaison(CompOperator::Lte, 0.0);
+        relation.date_comparaison(CompOperator::Lte, None, 0.0);
         let executable_relation = compiler::compile_relations(relation, &column_types).unwrap();
         let relations = Some(vec![executable_relation].into_boxed_slice());
 
@@ -921,6 +1471,36 @@ mod validation_engine_tests {
         );
     }
 
+    #[test]
+    fn test_correlation_record_error_fails_validation() {
+        let start_col = create_date_column("start_date", "%Y-%m-%d");
+        let end_col = create_date_column("end_date", "%Y-%m-%d");
+        let columns = vec![start_col, end_col].into_boxed_slice();
+
+        // The compiler only accepts numeric correlations, date arrays can not be cast to f64
+        let relation = ExecutableRelation::new(
+            ["start_date".to_string(), "end_date".to_string()],
+            vec![],
+            vec![CorrelationCheck::new(0.5, 0.0)],
+            vec![],
+        );
+        let relations = Some(vec![relation].into_boxed_slice());
+        let engine = ValidationEngine::new(&columns, &relations);
+
+        let batch = create_two_date_batch(
+            "start_date",
+            "end_date",
+            vec![
+                (Some("2024-01-01"), Some("2024-12-31")),
+                (Some("2024-06-01"), Some("2024-07-01")),
+            ],
+        );
+
+        // The correlation must not be computed from the batches that could be recorded
+        let result = engine.validate_batches("test_table".to_string(), &[batch]);
+        assert!(matches!(result, Err(RuleError::ArrowError(_))));
+    }
+
     #[test]
     fn test_validate_i64_numeric_relation() {
         // Create two integer columns
@@ -1069,4 +1649,23 @@ mod validation_engine_tests {
             assert!(error_msg.contains("not found") || error_msg.contains("missing_col"));
         }
     }
+
+    #[test]
+    fn test_string_relation_operator_check() {
+        let billing = StringColumnBuilder::new("billing".to_string());
+        let shipping = StringColumnBuilder::new("shipping".to_string());
+        let column_builders: Vec<Box<dyn crate::columns::ColumnBuilder>> =
+            vec![Box::new(billing), Box::new(shipping)];
+        let column_types = compiler::build_column_type_map(&column_builders);
+
+        let names = ["billing".to_string(), "shipping".to_string()];
+        let mut relation = RelationBuilder::new(names.clone());
+        relation.string_comparaison(CompOperator::Neq, 0.0);
+        assert!(compiler::compile_relations(relation, &column_types).is_ok());
+
+        // Ordering strings is not supported
+        let mut relation = RelationBuilder::new(names);
+        relation.string_comparaison(CompOperator::Lt, 0.0);
+        assert!(compiler::compile_relations(relation, &column_types).is_err());
+    }
 }
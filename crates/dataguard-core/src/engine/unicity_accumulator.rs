@@ -6,7 +6,20 @@ use std::{
     },
 };
 
-use crate::{types::UnicityRecord, utils::hasher::Xxh3Builder, validator::ExecutableColumn};
+use arrow_array::{Array, RecordBatch};
+
+use crate::{
+    rules::GroupedUnicityCheck, types::UnicityRecord, utils::hasher::Xxh3Builder,
+    validator::ExecutableColumn,
+};
+
+/// Pairs of group key and value hashed for a uniqueness check within groups.
+struct GroupedRecord {
+    check: GroupedUnicityCheck,
+    /// Rows with both a key and a value
+    checked: AtomicUsize,
+    hashes: Mutex<HashSet<u64, Xxh3Builder>>,
+}
 
 /// Manages uniqueness checking across batches.
 ///
@@ -15,12 +28,15 @@ use crate::{types::UnicityRecord, utils::hasher::Xxh3Builder, validator::Executa
 pub(crate) struct UnicityAccumulator {
     // Column name → global hash set (thread-safe)
     accumulators: HashMap<String, UnicityRecord>,
+    // Column name → hashes of the (group key, value) pairs
+    grouped: HashMap<String, GroupedRecord>,
 }
 
 impl UnicityAccumulator {
     /// Create accumulator for columns that have unicity checks.
     pub fn new(columns: &[ExecutableColumn], capacity: usize) -> Self {
         let mut accumulators: HashMap<String, UnicityRecord> = HashMap::new();
+        let mut grouped: HashMap<String, GroupedRecord> = HashMap::new();
 
         for column in columns {
             if column.has_unicity() {
@@ -32,9 +48,20 @@ impl UnicityAccumulator {
                 let null_counter = AtomicUsize::new(0);
                 accumulators.insert(column.get_name(), (null_counter, map, threshold));
             }
+            if let Some(check) = column.grouped_unicity_check() {
+                let record = GroupedRecord {
+                    check: check.clone(),
+                    checked: AtomicUsize::new(0),
+                    hashes: Mutex::new(HashSet::with_capacity_and_hasher(capacity, Xxh3Builder)),
+                };
+                grouped.insert(column.get_name(), record);
+            }
         }
 
-        Self { accumulators }
+        Self {
+            accumulators,
+            grouped,
+        }
     }
 
     /// Record hashes from a batch for a specific column.
@@ -55,6 +82,25 @@ impl UnicityAccumulator {
         map.lock().unwrap().extend(hashes)
     }
 
+    /// Record the (group key, value) pairs of a batch for the columns unique within groups.
+    ///
+    /// `arrays` holds the casted arrays of the columns, the keys are read from `batch`. A
+    /// column whose array or key is missing from the batch is skipped.
+    pub fn record_groups(&self, batch: &RecordBatch, arrays: &HashMap<String, Arc<dyn Array>>) {
+        for (name, record) in &self.grouped {
+            let (Some(values), Some(keys)) = (
+                arrays.get(name),
+                batch.column_by_name(record.check.group_by()),
+            ) else {
+                continue;
+            };
+            if let Ok((checked, hashes)) = record.check.validate(keys, values.as_ref()) {
+                record.checked.fetch_add(checked, Ordering::Relaxed);
+                record.hashes.lock().unwrap().extend(hashes);
+            }
+        }
+    }
+
     /// Calculate error counts for all columns.
     /// Returns: HashMap<column_name, error_count>
     pub fn finalize(&self, total_rows: usize) -> HashMap<String, (usize, f64)> {
@@ -71,4 +117,21 @@ impl UnicityAccumulator {
             })
             .collect::<HashMap<String, (usize, f64)>>()
     }
+
+    /// Calculate the error counts of the uniqueness checks within groups: the rows whose
+    /// pair of key and value was already met.
+    /// Returns: HashMap<column_name, error_count>
+    pub fn finalize_groups(&self) -> HashMap<String, (usize, f64)> {
+        self.grouped
+            .iter()
+            .map(|(name, record)| {
+                let unique = record.hashes.lock().unwrap().len();
+                let checked = record.checked.load(Ordering::Relaxed);
+                (
+                    name.to_owned(),
+                    (checked - unique, record.check.get_threshold()),
+                )
+            })
+            .collect()
+    }
 }
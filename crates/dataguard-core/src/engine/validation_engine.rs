@@ -1,30 +1,79 @@
-use super::accumulator::ResultAccumulator;
-use arrow::datatypes::{Date32Type, Float64Type, Int64Type};
+use super::accumulator::{ResultAccumulator, DEFAULT_MAX_SAMPLES};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Date32Type, Float64Type, Int64Type};
 use std::{
     collections::HashMap,
+    ops::ControlFlow,
     sync::{
-        atomic::{AtomicUsize, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, OnceLock,
     },
+    time::{Duration, Instant},
 };
 
-use arrow_array::{Array, ArrowNumericType, PrimitiveArray, RecordBatch, StringArray};
+use arrow::util::display::array_value_to_string;
+use arrow_array::{
+    Array, ArrowNumericType, BooleanArray, PrimitiveArray, RecordBatch, StringArray,
+    TimestampMillisecondArray,
+};
 use rayon::prelude::*;
 
 use crate::{
     engine::{
+        correlation_accumulator::CorrelationAccumulator,
+        duplicate_row_accumulator::DuplicateRowAccumulator,
+        group_accumulator::GroupAccumulator,
+        group_count_accumulator::GroupCountAccumulator,
+        key_accumulator::KeyAccumulator,
+        length_accumulator::LengthAccumulator,
+        monotonicity_accumulator::MonotonicityAccumulator,
+        profile_accumulator::ProfileAccumulator,
+        sanity_accumulator::SanityAccumulator,
         stats_accumulator::{merge_stats, StatsAccumulator},
+        subset_accumulator::SubsetAccumulator,
         unicity_accumulator::UnicityAccumulator,
+        value_count_accumulator::ValueCountAccumulator,
         Stats,
     },
     rules::{
+        boolean::{BooleanRule, BooleanTypeCheck},
         date::{DateRule, DateTypeCheck},
+        timestamp::{TimestampRule, TimestampTypeCheck},
         NullCheck, NumericRule, StringRule, TypeCheck, UnicityCheck,
     },
-    validator::{ExecutableColumn, ExecutableRelation},
-    RuleError, ValidationResult,
+    validator::{ExecutableColumn, ExecutableGroup, ExecutableRelation},
+    GroupResult, Progress, RuleError, RuleResult, ValidationResult,
 };
 
+/// Which axis of the data is validated in parallel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParallelAxis {
+    /// Pick the axis offering the most parallelism for the data at hand.
+    #[default]
+    Auto,
+    /// One task per batch, columns of a batch are validated sequentially.
+    Batches,
+    /// Batches are validated one after another, their columns in parallel.
+    Columns,
+}
+
+impl ParallelAxis {
+    /// Resolve `Auto`: wide tables read as fewer batches than there are threads
+    /// are validated column by column, everything else batch by batch.
+    pub fn resolve(self, num_batches: usize, num_columns: usize) -> ParallelAxis {
+        match self {
+            ParallelAxis::Auto => {
+                if num_batches < rayon::current_num_threads() && num_columns > num_batches {
+                    ParallelAxis::Columns
+                } else {
+                    ParallelAxis::Batches
+                }
+            }
+            axis => axis,
+        }
+    }
+}
+
 /// ValidationEngine - executes validation rules on Arrow RecordBatches.
 ///
 /// Independent of data source - works with any system that produces Arrow batches.
@@ -33,6 +82,16 @@ use crate::{
 pub struct ValidationEngine<'a> {
     columns: &'a [ExecutableColumn],
     relations: &'a Option<Box<[ExecutableRelation]>>,
+    groups: &'a [ExecutableGroup],
+    max_samples: usize,
+    parallel_axis: ParallelAxis,
+    progress: Option<&'a Progress>,
+    profile: bool,
+    value_counts: &'a [String],
+    duplicate_rows: bool,
+    keys: &'a [String],
+    fail_fast: bool,
+    max_errors: Option<usize>,
 }
 
 impl<'a> ValidationEngine<'a> {
@@ -40,7 +99,101 @@ impl<'a> ValidationEngine<'a> {
         columns: &'a [ExecutableColumn],
         relations: &'a Option<Box<[ExecutableRelation]>>,
     ) -> Self {
-        Self { columns, relations }
+        Self {
+            columns,
+            relations,
+            groups: &[],
+            max_samples: DEFAULT_MAX_SAMPLES,
+            parallel_axis: ParallelAxis::Auto,
+            progress: None,
+            profile: false,
+            value_counts: &[],
+            duplicate_rows: false,
+            keys: &[],
+            fail_fast: false,
+            max_errors: None,
+        }
+    }
+
+    /// Set the number of failing values reported per rule, 0 disables sampling.
+    ///
+    /// The count is capped at `MAX_SAMPLES_CAP`.
+    pub fn with_max_samples(mut self, max_samples: usize) -> Self {
+        self.max_samples = max_samples;
+        self
+    }
+
+    /// Set the axis validated in parallel, defaults to `ParallelAxis::Auto`.
+    pub fn with_parallel_axis(mut self, parallel_axis: ParallelAxis) -> Self {
+        self.parallel_axis = parallel_axis;
+        self
+    }
+
+    /// Count the validated rows in `progress`, if any.
+    pub fn with_progress(mut self, progress: Option<&'a Progress>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Summarize the values of every column in the result, see [`ColumnProfile`].
+    ///
+    /// [`ColumnProfile`]: crate::ColumnProfile
+    pub fn with_profile(mut self, profile: bool) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Count the most frequent values of `columns` in the result, see [`ValueCounts`].
+    ///
+    /// [`ValueCounts`]: crate::ValueCounts
+    pub fn with_value_counts(mut self, columns: &'a [String]) -> Self {
+        self.value_counts = columns;
+        self
+    }
+
+    /// Count the rows duplicated across every column read, reported as a table result.
+    pub fn with_duplicate_rows(mut self, duplicate_rows: bool) -> Self {
+        self.duplicate_rows = duplicate_rows;
+        self
+    }
+
+    /// Collect the values of `columns` in the result, for the foreign keys using them.
+    ///
+    /// Keys are only attached to a result covering the whole table, not once a rule tripped
+    /// with fail fast or the error limit was reached.
+    pub fn with_keys(mut self, columns: &'a [String]) -> Self {
+        self.keys = columns;
+        self
+    }
+
+    /// Stop validating further batches once a rule fails beyond its threshold.
+    ///
+    /// The result then only holds the rule that tripped, with the errors counted so far. The
+    /// error rate is taken over every row of the table, or over the rows validated so far when
+    /// batches are streamed. Rules needing the whole column, such as unicity, only run when
+    /// no rule tripped. Defaults to false, every rule being counted over the whole table.
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Stop scheduling further batches once `max_errors` errors were counted over every rule.
+    ///
+    /// Batches already being validated are completed, the result is then marked as
+    /// [`aborted`] and its counts and error rates only cover the rows validated until then.
+    /// Rules needing the whole column, such as unicity, are evaluated on those rows. Defaults
+    /// to `None`, every batch being validated.
+    ///
+    /// [`aborted`]: ValidationResult::aborted
+    pub fn with_max_errors(mut self, max_errors: Option<usize>) -> Self {
+        self.max_errors = max_errors;
+        self
+    }
+
+    /// Add group assertions, evaluated once all batches have been aggregated.
+    pub fn with_groups(mut self, groups: &'a [ExecutableGroup]) -> Self {
+        self.groups = groups;
+        self
     }
 
     pub(super) fn get_cols_with_stats(&self) -> Option<Vec<&ExecutableColumn>> {
@@ -64,6 +217,40 @@ impl<'a> ValidationEngine<'a> {
         }
     }
 
+    /// Report the values computed by statistical rules, such as quantiles
+    fn record_observed(
+        &self,
+        columns: &[&ExecutableColumn],
+        columns_stats: &HashMap<String, Stats>,
+        report: &ResultAccumulator,
+    ) {
+        for col in columns {
+            let Some(stats) = columns_stats.get(&col.get_name()) else {
+                continue;
+            };
+            let observed: Vec<(String, Vec<(String, f64)>)> = match col {
+                ExecutableColumn::Integer {
+                    statistical_rules, ..
+                } => statistical_rules
+                    .iter()
+                    .map(|r| (r.name(), r.observed(stats)))
+                    .collect(),
+                ExecutableColumn::Float {
+                    statistical_rules, ..
+                } => statistical_rules
+                    .iter()
+                    .map(|r| (r.name(), r.observed(stats)))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            for (rule_name, values) in observed {
+                if !values.is_empty() {
+                    report.record_observed(&col.get_name(), rule_name, values);
+                }
+            }
+        }
+    }
+
     fn compute_stats(
         &self,
         batches: &[Arc<RecordBatch>],
@@ -125,16 +312,7 @@ impl<'a> ValidationEngine<'a> {
                 }
                 local_accumulator.columns
             })
-            .reduce(HashMap::new, |mut acc1, acc2| {
-                for (colname, stats2) in acc2 {
-                    acc1.entry(colname)
-                        .and_modify(|stats1| {
-                            *stats1 = merge_stats(stats1.clone(), stats2.clone());
-                        })
-                        .or_insert(stats2);
-                }
-                acc1
-            })
+            .reduce(HashMap::new, merge_columns_stats)
     }
 
     /// Validate batches and produce a validation result.
@@ -144,141 +322,434 @@ impl<'a> ValidationEngine<'a> {
         table_name: String,
         batches: &[Arc<RecordBatch>],
     ) -> Result<ValidationResult, RuleError> {
-        let error_counter = AtomicUsize::new(0);
-        let report = ResultAccumulator::new();
-
         let total_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
-        report.set_total_rows(total_rows);
+        let mut columns_stats: HashMap<String, Stats> = HashMap::new();
+        let stats_columns = self.get_cols_with_stats();
+        if let Some(columns) = &stats_columns {
+            columns_stats = self.compute_stats(batches, columns);
+        }
+        if let Some(progress) = self.progress {
+            progress.set_total_rows(total_rows);
+        }
+        let run = ValidationRun::new(self, total_rows, columns_stats);
+        if let Some(columns) = &stats_columns {
+            self.record_observed(columns, &run.columns_stats, &run.report);
+        }
+        self.process_batches(&run, batches, 0);
+        self.finalize(run, table_name, total_rows)
+    }
+
+    /// Validate batches as they are read, without holding the whole file in memory.
+    ///
+    /// Batches are pulled from the stream returned by `open` and validated in windows of
+    /// one batch per thread. Statistical rules need the stats of the whole column before
+    /// any batch is validated: when some are configured, `open` is called a second time
+    /// and the file is read twice. Time spent waiting on the stream is reported as read time.
+    pub fn validate_batches_streaming<F, I, E>(
+        &self,
+        table_name: String,
+        open: F,
+    ) -> Result<ValidationResult, RuleError>
+    where
+        F: Fn() -> Result<I, RuleError>,
+        I: IntoIterator<Item = Result<Arc<RecordBatch>, E>>,
+        RuleError: From<E>,
+    {
+        let window_size = rayon::current_num_threads().max(1);
+        let mut read_duration = Duration::ZERO;
+        let start = Instant::now();
 
-        let unicity_accumulators = UnicityAccumulator::new(self.columns, total_rows);
+        let stats_columns = self.get_cols_with_stats();
         let mut columns_stats: HashMap<String, Stats> = HashMap::new();
-        if let Some(columns) = self.get_cols_with_stats() {
-            columns_stats = self.compute_stats(batches, &columns);
+        if let Some(columns) = &stats_columns {
+            for_each_window(open()?, window_size, &mut read_duration, |window| {
+                let window_stats = self.compute_stats(window, columns);
+                columns_stats =
+                    merge_columns_stats(std::mem::take(&mut columns_stats), window_stats);
+                ControlFlow::Continue(())
+            })?;
         }
-        batches.par_iter().for_each(|batch| {
-            // We keep in memory a reference to the casted array
-            let mut array_ref: HashMap<String, Arc<dyn Array>> = HashMap::new();
-            for executable_col in self.columns {
-                match executable_col {
-                    ExecutableColumn::String {
-                        name,
-                        rules,
-                        type_check,
-                        unicity_check,
-                        null_check,
-                    } => {
-                        if let Ok(col_index) = batch.schema().index_of(name) {
+
+        // Hash sets of unicity checks grow as batches come, the row count is not known yet
+        let run = ValidationRun::new(self, 0, columns_stats);
+        if let Some(columns) = &stats_columns {
+            self.record_observed(columns, &run.columns_stats, &run.report);
+        }
+        let mut total_rows = 0;
+        for_each_window(open()?, window_size, &mut read_duration, |window| {
+            self.process_batches(&run, window, total_rows);
+            total_rows += window.iter().map(|batch| batch.num_rows()).sum::<usize>();
+            // With fail fast or an error limit, the rest of the file is not read once reached
+            match run.tripped.get() {
+                Some(_) => ControlFlow::Break(()),
+                None if run.aborted.load(Ordering::Relaxed) => ControlFlow::Break(()),
+                None => ControlFlow::Continue(()),
+            }
+        })?;
+        let mut results = self.finalize(run, table_name, total_rows)?;
+        results.set_durations(read_duration, start.elapsed().saturating_sub(read_duration));
+        Ok(results)
+    }
+
+    /// Validate the rules of every column and relation over `batches`, recording
+    /// errors in `run`. `first_offset` is the file row of the first batch.
+    fn process_batches(
+        &self,
+        run: &ValidationRun,
+        batches: &[Arc<RecordBatch>],
+        first_offset: usize,
+    ) {
+        let ValidationRun {
+            error_counter,
+            report,
+            unicity_accumulators,
+            group_accumulator,
+            group_count_accumulator,
+            correlation_accumulator,
+            subset_accumulator,
+            length_accumulator,
+            monotonicity_accumulator,
+            sanity_accumulator,
+            profile_accumulator,
+            value_count_accumulator,
+            duplicate_row_accumulator,
+            key_accumulator,
+            true_counts,
+            columns_stats,
+            expected_rows,
+            rows_validated,
+            tripped,
+            aborted,
+            failure,
+        } = run;
+        // Row index of the first row of each batch, to report samples by file row
+        let offsets: Vec<usize> = batches
+            .iter()
+            .scan(first_offset, |start, batch| {
+                let offset = *start;
+                *start += batch.num_rows();
+                Some(offset)
+            })
+            .collect();
+        let parallel_columns = self
+            .parallel_axis
+            .resolve(batches.len(), self.columns.len())
+            == ParallelAxis::Columns;
+        let process_batch = |(batch, offset): (&Arc<RecordBatch>, usize)| {
+            if tripped.get().is_some() || aborted.load(Ordering::Relaxed) || failure.get().is_some()
+            {
+                return;
+            }
+            // Validate a single column of the batch, returns the casted array used by relations
+            let validate_column =
+                |executable_col: &ExecutableColumn| -> Option<(String, Arc<dyn Array>)> {
+                    match executable_col {
+                        ExecutableColumn::String {
+                            name,
+                            rules,
+                            type_check,
+                            unicity_check,
+                            null_check,
+                            ..
+                        } => {
+                            if let Ok(col_index) = batch.schema().index_of(name) {
+                                let array = batch.column(col_index);
+                                if let Ok(string_array) = validate_string_column(
+                                    name,
+                                    rules,
+                                    type_check,
+                                    unicity_check,
+                                    null_check,
+                                    array,
+                                    offset,
+                                    error_counter,
+                                    report,
+                                    unicity_accumulators,
+                                ) {
+                                    length_accumulator.record_batch(name, string_array.as_ref());
+                                    return Some((name.clone(), string_array));
+                                }
+                            }
+                        }
+                        ExecutableColumn::Integer {
+                            name,
+                            domain_rules,
+                            statistical_rules,
+                            type_check,
+                            unicity_check,
+                            null_check,
+                            ..
+                        } => {
+                            if let Ok(col_index) = batch.schema().index_of(name) {
+                                let array = batch.column(col_index);
+                                let stats = columns_stats.get(name);
+                                if let Ok(casted_array) = validate_numeric_column::<Int64Type>(
+                                    name,
+                                    domain_rules,
+                                    statistical_rules,
+                                    stats,
+                                    type_check,
+                                    unicity_check,
+                                    null_check,
+                                    array,
+                                    offset,
+                                    error_counter,
+                                    report,
+                                    unicity_accumulators,
+                                ) {
+                                    return Some((name.clone(), casted_array));
+                                }
+                            }
+                        }
+                        ExecutableColumn::Float {
+                            name,
+                            domain_rules,
+                            statistical_rules,
+                            type_check,
+                            unicity_check,
+                            null_check,
+                            ..
+                        } => {
+                            if let Ok(col_index) = batch.schema().index_of(name) {
+                                let array = batch.column(col_index);
+                                let stats = columns_stats.get(name);
+                                if let Ok(casted_array) = validate_numeric_column::<Float64Type>(
+                                    name,
+                                    domain_rules,
+                                    statistical_rules,
+                                    stats,
+                                    type_check,
+                                    unicity_check,
+                                    null_check,
+                                    array,
+                                    offset,
+                                    error_counter,
+                                    report,
+                                    unicity_accumulators,
+                                ) {
+                                    return Some((name.clone(), casted_array));
+                                }
+                            }
+                        }
+                        ExecutableColumn::Date {
+                            name,
+                            rules,
+                            type_check,
+                            unicity_check,
+                            null_check,
+                            ..
+                        } => {
+                            let Ok(col_index) = batch.schema().index_of(name) else {
+                                return None;
+                            };
                             let array = batch.column(col_index);
-                            let _ = validate_string_column(
+                            if let Ok(casted_array) = validate_date_column(
                                 name,
                                 rules,
                                 type_check,
                                 unicity_check,
                                 null_check,
                                 array,
-                                &error_counter,
-                                &report,
-                                &unicity_accumulators,
-                            );
+                                offset,
+                                error_counter,
+                                report,
+                                unicity_accumulators,
+                            ) {
+                                return Some((name.clone(), casted_array));
+                            }
                         }
-                    }
-                    ExecutableColumn::Integer {
-                        name,
-                        domain_rules,
-                        statistical_rules,
-                        type_check,
-                        unicity_check,
-                        null_check,
-                    } => {
-                        if let Ok(col_index) = batch.schema().index_of(name) {
+                        ExecutableColumn::Timestamp {
+                            name,
+                            rules,
+                            type_check,
+                            unicity_check,
+                            null_check,
+                            ..
+                        } => {
+                            let Ok(col_index) = batch.schema().index_of(name) else {
+                                return None;
+                            };
                             let array = batch.column(col_index);
-                            let stats = columns_stats.get(name);
-                            if let Ok(casted_array) = validate_numeric_column::<Int64Type>(
+                            if let Ok(casted_array) = validate_timestamp_column(
                                 name,
-                                domain_rules,
-                                statistical_rules,
-                                stats,
+                                rules,
                                 type_check,
                                 unicity_check,
                                 null_check,
                                 array,
-                                &error_counter,
-                                &report,
-                                &unicity_accumulators,
+                                offset,
+                                error_counter,
+                                report,
+                                unicity_accumulators,
                             ) {
-                                println!("ok: {}", name.clone());
-                                array_ref.insert(name.clone(), casted_array);
+                                return Some((name.clone(), casted_array));
                             }
                         }
-                    }
-                    ExecutableColumn::Float {
-                        name,
-                        domain_rules,
-                        statistical_rules,
-                        type_check,
-                        unicity_check,
-                        null_check,
-                    } => {
-                        if let Ok(col_index) = batch.schema().index_of(name) {
+                        ExecutableColumn::Boolean {
+                            name,
+                            rules,
+                            type_check,
+                            null_check,
+                            ..
+                        } => {
+                            let Ok(col_index) = batch.schema().index_of(name) else {
+                                return None;
+                            };
                             let array = batch.column(col_index);
-                            let stats = columns_stats.get(name);
-                            if let Ok(casted_array) = validate_numeric_column::<Float64Type>(
+                            if let Ok(bool_array) = validate_boolean_column(
                                 name,
-                                domain_rules,
-                                statistical_rules,
-                                stats,
+                                rules,
                                 type_check,
-                                unicity_check,
                                 null_check,
                                 array,
-                                &error_counter,
-                                &report,
-                                &unicity_accumulators,
+                                offset,
+                                error_counter,
+                                report,
                             ) {
-                                println!("ok: {}", name.clone());
-                                array_ref.insert(name.clone(), casted_array);
+                                if let Some((true_count, values)) = true_counts.get(name) {
+                                    true_count
+                                        .fetch_add(bool_array.true_count(), Ordering::Relaxed);
+                                    values.fetch_add(
+                                        bool_array.len() - bool_array.null_count(),
+                                        Ordering::Relaxed,
+                                    );
+                                }
+                                return Some((name.clone(), Arc::new(bool_array)));
                             }
                         }
                     }
-                    ExecutableColumn::Date {
-                        name,
-                        rules,
-                        type_check,
-                        unicity_check,
-                        null_check,
-                    } => {
-                        let Ok(col_index) = batch.schema().index_of(name) else {
-                            continue;
-                        };
-                        let array = batch.column(col_index);
-                        if let Ok(casted_array) = validate_date_column(
-                            name,
-                            rules,
-                            type_check,
-                            unicity_check,
-                            null_check,
-                            array,
-                            &error_counter,
-                            &report,
-                            &unicity_accumulators,
-                        ) {
-                            array_ref.insert(name.clone(), casted_array);
-                        }
-                    }
-                }
+                    None
+                };
+            // We keep in memory a reference to the casted array
+            let array_ref: HashMap<String, Arc<dyn Array>> = if parallel_columns {
+                self.columns
+                    .par_iter()
+                    .filter_map(validate_column)
+                    .collect()
+            } else {
+                self.columns.iter().filter_map(validate_column).collect()
+            };
+            unicity_accumulators.record_groups(batch, &array_ref);
+            sanity_accumulator.record_batch(&batch.schema(), batch.num_rows(), &array_ref);
+            monotonicity_accumulator.record_batch(offset, &array_ref);
+            profile_accumulator.record_batch(&batch.schema(), batch.num_rows(), &array_ref);
+            value_count_accumulator.record_batch(&batch.schema(), batch.num_rows(), &array_ref);
+            if let Err(e) = duplicate_row_accumulator.record_batch(batch) {
+                let _ = failure.set(e.into());
+                return;
+            }
+            if let Err(e) = key_accumulator.record_batch(batch, offset) {
+                let _ = failure.set(e);
+                return;
             }
             if let Some(relations) = self.relations {
-                for executable_relation in relations {
+                for (index, executable_relation) in relations.iter().enumerate() {
                     // Since the array could not be added in case of type cast failure
                     // We ensure that both key exist before running the validation
-                    if array_ref.contains_key(&executable_relation.names[0])
-                        && array_ref.contains_key(&executable_relation.names[1])
-                    {
-                        validate_relation(executable_relation, &array_ref, &error_counter, &report);
+                    if let (Some(lhs), Some(rhs)) = (
+                        array_ref.get(&executable_relation.names[0]),
+                        array_ref.get(&executable_relation.names[1]),
+                    ) {
+                        validate_relation(executable_relation, &array_ref, error_counter, report);
+                        if let Err(e) = correlation_accumulator.record_batch(index, lhs, rhs) {
+                            let _ = failure.set(e);
+                            return;
+                        }
+                        subset_accumulator.record_batch(index, offset, lhs.as_ref(), rhs.as_ref());
                     }
                 }
             }
-        });
+            let record_groups = || -> Result<(), RuleError> {
+                for (index, group) in self.groups.iter().enumerate() {
+                    let column = |name: &str| {
+                        batch
+                            .column_by_name(name)
+                            .ok_or_else(|| RuleError::ColumnNotFound(name.to_string()))
+                    };
+                    let keys = column(&group.group_by)?;
+                    if let Some(value) = &group.value {
+                        group_accumulator.record_batch(index, keys, column(value)?)?;
+                    }
+                    group_count_accumulator.record_batch(index, keys)?;
+                }
+                Ok(())
+            };
+            if let Err(e) = record_groups() {
+                let _ = failure.set(e);
+                return;
+            }
+            if let Some(progress) = self.progress {
+                progress.add_rows(batch.num_rows());
+            }
+            let rows =
+                rows_validated.fetch_add(batch.num_rows(), Ordering::Relaxed) + batch.num_rows();
+            if self.fail_fast {
+                if let Some(failure) = report.first_failure(rows.max(*expected_rows)) {
+                    let _ = tripped.set(failure);
+                }
+            }
+            if self
+                .max_errors
+                .is_some_and(|max| error_counter.load(Ordering::Relaxed) >= max)
+            {
+                aborted.store(true, Ordering::Relaxed);
+            }
+        };
+        if parallel_columns {
+            batches.iter().zip(offsets).for_each(process_batch);
+        } else {
+            batches.par_iter().zip(offsets).for_each(process_batch);
+        }
+    }
+
+    /// Evaluate the rules needing the whole column, and build the validation result.
+    fn finalize(
+        &self,
+        mut run: ValidationRun,
+        table_name: String,
+        total_rows: usize,
+    ) -> Result<ValidationResult, RuleError> {
+        if let Some(e) = run.failure.take() {
+            return Err(e);
+        }
+        if let Some((name, mut result, is_col)) = run.tripped.take() {
+            result.set_error_message(
+                "Validation stopped at this rule (fail fast), errors are counted on the rows \
+                 validated until then"
+                    .to_string(),
+            );
+            let mut results = ValidationResult::new(table_name, total_rows);
+            match is_col {
+                true => results.add_column_result(name, vec![result]),
+                false => results.add_relation_result(name, vec![result]),
+            }
+            return Ok(results);
+        }
+        // Counts of an aborted run only cover the rows validated before the error limit
+        let aborted = run.aborted.load(Ordering::Relaxed);
+        let total_rows = match aborted {
+            true => run.rows_validated.load(Ordering::Relaxed),
+            false => total_rows,
+        };
+        let ValidationRun {
+            error_counter,
+            report,
+            unicity_accumulators,
+            group_accumulator,
+            group_count_accumulator,
+            correlation_accumulator,
+            subset_accumulator,
+            length_accumulator,
+            monotonicity_accumulator,
+            sanity_accumulator,
+            profile_accumulator,
+            value_count_accumulator,
+            duplicate_row_accumulator,
+            key_accumulator,
+            true_counts,
+            ..
+        } = run;
+        report.set_total_rows(total_rows);
 
         // We need to calculate the unicity errors now
         // We unwrap all lock should have been clearer from the earlier loop
@@ -290,34 +761,415 @@ impl<'a> ValidationEngine<'a> {
                 "Unicity".to_string(),
                 threshold,
                 unicity_error,
+                Vec::new(),
+            );
+        }
+        for (column_name, (unicity_error, threshold)) in unicity_accumulators.finalize_groups() {
+            error_counter.fetch_add(unicity_error, Ordering::Relaxed);
+            report.record_column_result(
+                &column_name,
+                "UniqueWithinGroup".to_string(),
+                threshold,
+                unicity_error,
+                Vec::new(),
+            );
+        }
+
+        // Ratio rules are evaluated on the counts of the whole column
+        for column in self.columns {
+            if let ExecutableColumn::Boolean {
+                name, ratio_rules, ..
+            } = column
+            {
+                let Some((true_count, values)) = true_counts.get(name) else {
+                    continue;
+                };
+                let true_count = true_count.load(Ordering::Relaxed);
+                let values = values.load(Ordering::Relaxed);
+                for rule in ratio_rules {
+                    record_validation_result(
+                        name,
+                        rule.name(),
+                        rule.validate(true_count, values),
+                        &error_counter,
+                        rule.get_threshold(),
+                        &report,
+                        true,
+                        Vec::new(),
+                    );
+                }
+            }
+        }
+
+        // Length distributions are evaluated on the histogram of the whole column
+        let mut column_lengths = length_accumulator.finalize();
+        for column in self.columns {
+            if let ExecutableColumn::String {
+                name, length_rules, ..
+            } = column
+            {
+                let Some(lengths) = column_lengths.remove(name) else {
+                    continue;
+                };
+                for rule in length_rules {
+                    record_validation_result(
+                        name,
+                        rule.name(),
+                        rule.validate(&lengths),
+                        &error_counter,
+                        rule.get_threshold(),
+                        &report,
+                        true,
+                        Vec::new(),
+                    );
+                    let observed = rule.observed(&lengths);
+                    if !observed.is_empty() {
+                        report.record_observed(name, rule.name(), observed);
+                    }
+                }
+            }
+        }
+
+        // Monotonicity between batches is checked once every batch has been seen
+        for breaks in monotonicity_accumulator.finalize(report.max_samples()) {
+            record_validation_result(
+                &breaks.column,
+                breaks.rule_name,
+                breaks.count,
+                &error_counter,
+                breaks.threshold,
+                &report,
+                true,
+                breaks.samples,
             );
         }
 
+        // Sanity rules are evaluated once every batch has been seen
+        let column_sanity = sanity_accumulator.finalize();
+        for column in self.columns {
+            let name = column.get_name();
+            let Some(&(rows, values, distinct)) = column_sanity.get(&name) else {
+                continue;
+            };
+            for rule in column.sanity_rules() {
+                record_validation_result(
+                    &name,
+                    rule.name(),
+                    rule.validate(rows, values, distinct),
+                    &error_counter,
+                    rule.get_threshold(),
+                    &report,
+                    true,
+                    Vec::new(),
+                );
+                let observed = rule.observed(rows, values, distinct);
+                if !observed.is_empty() {
+                    report.record_observed(&name, rule.name(), observed);
+                }
+            }
+        }
+
+        // Correlations are only known once every batch has been summed
+        if let Some(relations) = self.relations {
+            let correlation_sums = correlation_accumulator.finalize();
+            for (relation, sums) in relations.iter().zip(correlation_sums) {
+                let Some(sums) = sums else {
+                    continue;
+                };
+                let relation_name = format!("{} | {}", relation.names[0], relation.names[1]);
+                for rule in &relation.correlations {
+                    record_validation_result(
+                        &relation_name,
+                        rule.name(),
+                        rule.validate(&sums),
+                        &error_counter,
+                        rule.get_threshold(),
+                        &report,
+                        false,
+                        Vec::new(),
+                    );
+                    let observed = rule.observed(&sums);
+                    if !observed.is_empty() {
+                        report.record_observed(&relation_name, rule.name(), observed);
+                    }
+                }
+            }
+
+            // Membership is only known once the second column was fully read
+            let subset_columns = subset_accumulator.finalize();
+            for (relation, columns) in relations.iter().zip(subset_columns) {
+                let Some(columns) = columns else {
+                    continue;
+                };
+                let relation_name = format!("{} | {}", relation.names[0], relation.names[1]);
+                for rule in &relation.subsets {
+                    let (missing, samples) =
+                        rule.validate(&columns.values, &columns.reference, self.max_samples);
+                    record_validation_result(
+                        &relation_name,
+                        rule.name(),
+                        missing,
+                        &error_counter,
+                        rule.get_threshold(),
+                        &report,
+                        false,
+                        samples,
+                    );
+                    report.record_observed(
+                        &relation_name,
+                        rule.name(),
+                        rule.observed(&columns.values, &columns.reference),
+                    );
+                }
+            }
+        }
+
         // We create the validation result for report formatting
         let (column_values, column_results, relation_result) = report.to_results();
         let mut results = ValidationResult::new(table_name.clone(), total_rows);
+        results.aborted = aborted;
         results.add_columns_values(column_values);
         results.add_column_results(column_results);
         results.add_relation_results(relation_result);
+        results.add_profiles(profile_accumulator.finalize());
+        results.add_value_counts(value_count_accumulator.finalize());
+        // Keys of an aborted run miss the rows after the error limit
+        if !aborted {
+            results.set_keys(key_accumulator.finalize());
+        }
+        if let Some(duplicates) = duplicate_row_accumulator.finalize() {
+            let error_percentage = if total_rows > 0 {
+                (duplicates as f64 / total_rows as f64) * 100.
+            } else {
+                0.0
+            };
+            results.add_table_result(RuleResult::new(
+                "DuplicateRows".to_string(),
+                duplicates,
+                error_percentage,
+                0.0,
+                None,
+                duplicates == 0,
+            ));
+        }
+
+        // Group sums and counts are only complete once every batch has been aggregated
+        let group_result =
+            |rule_name: String, threshold: f64, group_count: usize, errors: usize| {
+                error_counter.fetch_add(errors, Ordering::Relaxed);
+                let error_percentage = if group_count > 0 {
+                    (errors as f64 / group_count as f64) * 100.
+                } else {
+                    0.0
+                };
+                RuleResult::new(
+                    rule_name,
+                    errors,
+                    error_percentage,
+                    threshold,
+                    None,
+                    error_percentage <= threshold,
+                )
+            };
+        let group_sums = group_accumulator.finalize();
+        let group_counts = group_count_accumulator.finalize();
+        for ((group, sums), counts) in self.groups.iter().zip(&group_sums).zip(&group_counts) {
+            for rule in &group.rules {
+                let (group_count, mut mismatches) = rule.validate(sums);
+                let rule_result = group_result(
+                    rule.name(),
+                    rule.get_threshold(),
+                    group_count,
+                    mismatches.len(),
+                );
+                mismatches.truncate(self.max_samples);
+                results.add_group_result(GroupResult::new(
+                    format!(
+                        "{} | {}",
+                        group.group_by,
+                        group.value.as_deref().unwrap_or_default()
+                    ),
+                    rule_result,
+                    mismatches,
+                ));
+            }
+            for rule in &group.count_rules {
+                let (group_count, mut mismatches) = rule.validate(counts);
+                let rule_result = group_result(
+                    rule.name(),
+                    rule.get_threshold(),
+                    group_count,
+                    mismatches.len(),
+                );
+                // Offending groups are sampled like failing values, the error count covers all
+                mismatches.truncate(self.max_samples);
+                results.add_group_result(GroupResult::new(
+                    group.group_by.clone(),
+                    rule_result,
+                    mismatches,
+                ));
+            }
+        }
 
         Ok(results)
     }
 }
 
+/// Accumulators shared by the batches of a single validation.
+///
+/// Rules needing the whole column are only evaluated once every batch was processed.
+struct ValidationRun {
+    error_counter: AtomicUsize,
+    report: ResultAccumulator,
+    unicity_accumulators: UnicityAccumulator,
+    group_accumulator: GroupAccumulator,
+    group_count_accumulator: GroupCountAccumulator,
+    correlation_accumulator: CorrelationAccumulator,
+    subset_accumulator: SubsetAccumulator,
+    length_accumulator: LengthAccumulator,
+    monotonicity_accumulator: MonotonicityAccumulator,
+    sanity_accumulator: SanityAccumulator,
+    profile_accumulator: ProfileAccumulator,
+    value_count_accumulator: ValueCountAccumulator,
+    duplicate_row_accumulator: DuplicateRowAccumulator,
+    key_accumulator: KeyAccumulator,
+    // Boolean ratio rules need the (true, non-null) counts of the whole column
+    true_counts: HashMap<String, (AtomicUsize, AtomicUsize)>,
+    columns_stats: HashMap<String, Stats>,
+    // Rows of the whole table, 0 when batches are streamed
+    expected_rows: usize,
+    rows_validated: AtomicUsize,
+    // With fail fast, the first rule failing beyond its threshold: (name, result, is_col)
+    tripped: OnceLock<(String, RuleResult, bool)>,
+    // Set once the error limit is reached, no further batch is validated
+    aborted: AtomicBool,
+    // First error preventing an accumulator from recording a batch, returned by finalize
+    failure: OnceLock<RuleError>,
+}
+
+impl ValidationRun {
+    fn new(
+        engine: &ValidationEngine,
+        capacity: usize,
+        columns_stats: HashMap<String, Stats>,
+    ) -> Self {
+        let true_counts = engine
+            .columns
+            .iter()
+            .filter_map(|col| match col {
+                ExecutableColumn::Boolean {
+                    name, ratio_rules, ..
+                } if !ratio_rules.is_empty() => {
+                    Some((name.clone(), (AtomicUsize::new(0), AtomicUsize::new(0))))
+                }
+                _ => None,
+            })
+            .collect();
+        Self {
+            error_counter: AtomicUsize::new(0),
+            report: ResultAccumulator::new().with_max_samples(engine.max_samples),
+            unicity_accumulators: UnicityAccumulator::new(engine.columns, capacity),
+            group_accumulator: GroupAccumulator::new(engine.groups),
+            group_count_accumulator: GroupCountAccumulator::new(engine.groups),
+            correlation_accumulator: CorrelationAccumulator::new(
+                engine.relations.as_deref().unwrap_or_default(),
+            ),
+            subset_accumulator: SubsetAccumulator::new(
+                engine.relations.as_deref().unwrap_or_default(),
+            ),
+            length_accumulator: LengthAccumulator::new(engine.columns),
+            monotonicity_accumulator: MonotonicityAccumulator::new(engine.columns),
+            sanity_accumulator: SanityAccumulator::new(engine.columns),
+            profile_accumulator: ProfileAccumulator::new(engine.columns, engine.profile),
+            value_count_accumulator: ValueCountAccumulator::new(
+                engine.columns,
+                engine.value_counts,
+            ),
+            duplicate_row_accumulator: DuplicateRowAccumulator::new(
+                engine.duplicate_rows,
+                capacity,
+            ),
+            key_accumulator: KeyAccumulator::new(engine.keys),
+            true_counts,
+            columns_stats,
+            expected_rows: capacity,
+            rows_validated: AtomicUsize::new(0),
+            tripped: OnceLock::new(),
+            aborted: AtomicBool::new(false),
+            failure: OnceLock::new(),
+        }
+    }
+}
+
+/// Merge the stats computed on two sets of batches, column by column
+fn merge_columns_stats(
+    mut acc1: HashMap<String, Stats>,
+    acc2: HashMap<String, Stats>,
+) -> HashMap<String, Stats> {
+    for (colname, stats2) in acc2 {
+        acc1.entry(colname)
+            .and_modify(|stats1| {
+                *stats1 = merge_stats(stats1.clone(), stats2.clone());
+            })
+            .or_insert(stats2);
+    }
+    acc1
+}
+
+/// Pull batches from `stream` and hand them to `f` by windows of `window_size`, until `f`
+/// breaks.
+///
+/// Time spent waiting for a batch is added to `read_duration`.
+fn for_each_window<I, E>(
+    stream: I,
+    window_size: usize,
+    read_duration: &mut Duration,
+    mut f: impl FnMut(&[Arc<RecordBatch>]) -> ControlFlow<()>,
+) -> Result<(), RuleError>
+where
+    I: IntoIterator<Item = Result<Arc<RecordBatch>, E>>,
+    RuleError: From<E>,
+{
+    let mut stream = stream.into_iter();
+    let mut window = Vec::with_capacity(window_size);
+    loop {
+        let start = Instant::now();
+        let next = stream.next();
+        *read_duration += start.elapsed();
+        match next {
+            Some(batch) => window.push(batch?),
+            None => break,
+        }
+        if window.len() == window_size {
+            if f(&window).is_break() {
+                return Ok(());
+            }
+            window.clear();
+        }
+    }
+    if !window.is_empty() {
+        let _ = f(&window);
+    }
+    Ok(())
+}
+
 /// Validate null check and record results
 fn validate_null_check(
     null_check: &Option<NullCheck>,
     array: &dyn Array,
     column_name: &str,
+    error_counter: &AtomicUsize,
     report: &ResultAccumulator,
 ) {
     if let Some(null_rule) = null_check {
         let null_count = null_rule.validate(array);
+        error_counter.fetch_add(null_count, Ordering::Relaxed);
         report.record_column_result(
             column_name,
             null_rule.name(),
             null_rule.get_threshold(),
             null_count,
+            Vec::new(),
         );
     }
 }
@@ -331,13 +1183,72 @@ fn record_validation_result(
     threshold: f64,
     report: &ResultAccumulator,
     is_col: bool,
+    samples: Vec<(usize, String)>,
 ) {
     error_counter.fetch_add(error_count_value, Ordering::Relaxed);
     if is_col {
-        report.record_column_result(column_name, rule_name, threshold, error_count_value);
+        report.record_column_result(
+            column_name,
+            rule_name,
+            threshold,
+            error_count_value,
+            samples,
+        );
     } else {
-        report.record_relation_result(column_name, rule_name, threshold, error_count_value);
+        report.record_relation_result(
+            column_name,
+            rule_name,
+            threshold,
+            error_count_value,
+            samples,
+        );
+    }
+}
+
+/// Stringify the first failing values of a rule, `failing_rows` is only called on errors.
+fn collect_samples(
+    error_count: usize,
+    array: &dyn Array,
+    offset: usize,
+    report: &ResultAccumulator,
+    failing_rows: impl FnOnce(usize) -> Vec<usize>,
+) -> Vec<(usize, String)> {
+    collect_samples_with(error_count, offset, report, failing_rows, |row| {
+        array_value_to_string(array, row).unwrap_or_default()
+    })
+}
+
+/// Like [`collect_samples`], with the text of each failing row given by `sample_value`.
+fn collect_samples_with(
+    error_count: usize,
+    offset: usize,
+    report: &ResultAccumulator,
+    failing_rows: impl FnOnce(usize) -> Vec<usize>,
+    sample_value: impl Fn(usize) -> String,
+) -> Vec<(usize, String)> {
+    if error_count == 0 || report.max_samples() == 0 {
+        return Vec::new();
     }
+    failing_rows(report.max_samples())
+        .into_iter()
+        .map(|row| (offset + row, sample_value(row)))
+        .collect()
+}
+
+/// Sample the raw values that could not be casted: non-null before, null after the type check.
+fn collect_type_samples(
+    error_count: usize,
+    raw: &dyn Array,
+    casted: &dyn Array,
+    offset: usize,
+    report: &ResultAccumulator,
+) -> Vec<(usize, String)> {
+    collect_samples(error_count, raw, offset, report, |limit| {
+        (0..raw.len())
+            .filter(|row| raw.is_valid(*row) && casted.is_null(*row))
+            .take(limit)
+            .collect()
+    })
 }
 
 /// Record type check error when casting fails completely
@@ -350,7 +1261,13 @@ fn record_type_check_error(
     report: &ResultAccumulator,
 ) {
     error_counter.fetch_add(array_len, Ordering::Relaxed);
-    report.record_column_result(column_name, type_check_name, threshold, array_len);
+    report.record_column_result(
+        column_name,
+        type_check_name,
+        threshold,
+        array_len,
+        Vec::new(),
+    );
 }
 
 /// Record downcast failure
@@ -360,7 +1277,7 @@ fn record_downcast_failure(
     type_check_name: String,
     report: &ResultAccumulator,
 ) {
-    report.record_column_result(column_name, type_check_name, 0., array_len);
+    report.record_column_result(column_name, type_check_name, 0., array_len, Vec::new());
 }
 
 fn validate_string_column(
@@ -370,14 +1287,15 @@ fn validate_string_column(
     unicity_check: &Option<UnicityCheck>,
     null_check: &Option<NullCheck>,
     array: &dyn Array,
+    offset: usize,
     error_counter: &AtomicUsize,
     report: &ResultAccumulator,
     unicity_accumulators: &UnicityAccumulator,
-) -> Result<(), RuleError> {
+) -> Result<Arc<dyn Array>, RuleError> {
     let array_values = array.len() - array.null_count();
     report.record_valid_values(name, array_values);
     // Run null check if present
-    validate_null_check(null_check, array, name, report);
+    validate_null_check(null_check, array, name, error_counter, report);
 
     // we only run a type check if the table is a CsvTable
     if let Some(type_rule) = type_check {
@@ -391,6 +1309,7 @@ fn validate_string_column(
                     type_rule.get_threshold(),
                     report,
                     true,
+                    collect_type_samples(errors, array, &casted_array, offset, report),
                 );
                 if errors == array_values {
                     // We return early in case of a full invalid initial data type
@@ -410,6 +1329,9 @@ fn validate_string_column(
                             rule.get_threshold(),
                             report,
                             true,
+                            collect_samples(count, string_array, offset, report, |limit| {
+                                rule.failing_rows(string_array, limit)
+                            }),
                         );
                     }
                 }
@@ -418,7 +1340,7 @@ fn validate_string_column(
                     let (null_count, local_hash) = unicity_rule.validate_str(string_array);
                     unicity_accumulators.record_hashes(name, null_count, local_hash);
                 }
-                Ok(())
+                Ok(casted_array)
             }
             Err(e) => {
                 record_type_check_error(
@@ -449,6 +1371,9 @@ fn validate_string_column(
                             rule.get_threshold(),
                             report,
                             true,
+                            collect_samples(count, string_array, offset, report, |limit| {
+                                rule.failing_rows(string_array, limit)
+                            }),
                         );
                     }
                 }
@@ -457,12 +1382,13 @@ fn validate_string_column(
                     let (null_count, local_hash) = unicity_rule.validate_str(string_array);
                     unicity_accumulators.record_hashes(name, null_count, local_hash);
                 }
+                Ok(Arc::new(string_array.clone()))
             }
             None => {
                 record_downcast_failure(array.len(), name, "DowncastFailure".to_string(), report);
+                Err(RuleError::TypeCastFailed)
             }
         }
-        Ok(())
     }
 }
 
@@ -476,6 +1402,7 @@ fn validate_numeric_column<T: ArrowNumericType>(
     unicity_check: &Option<UnicityCheck>,
     null_check: &Option<NullCheck>,
     array: &dyn Array,
+    offset: usize,
     error_counter: &AtomicUsize,
     report: &ResultAccumulator,
     unicity_accumulators: &UnicityAccumulator,
@@ -483,7 +1410,7 @@ fn validate_numeric_column<T: ArrowNumericType>(
     let array_values = array.len() - array.null_count();
     report.record_valid_values(name, array_values);
     // Run null check if present
-    validate_null_check(null_check, array, name, report);
+    validate_null_check(null_check, array, name, error_counter, report);
 
     // we only run a type check if the table is a CsvTable
     if let Some(type_rule) = type_check {
@@ -497,6 +1424,7 @@ fn validate_numeric_column<T: ArrowNumericType>(
                     type_rule.get_threshold(),
                     report,
                     true,
+                    collect_type_samples(errors, array, &casted_array, offset, report),
                 );
                 if errors == array_values {
                     // We return early in case of a full invalid initial data type
@@ -519,6 +1447,13 @@ fn validate_numeric_column<T: ArrowNumericType>(
                             rule.get_threshold(),
                             report,
                             true,
+                            collect_samples_with(
+                                count,
+                                offset,
+                                report,
+                                |limit| rule.failing_rows(numeric_array, limit),
+                                |row| rule.sample_value(numeric_array, row),
+                            ),
                         );
                     }
                 }
@@ -534,6 +1469,7 @@ fn validate_numeric_column<T: ArrowNumericType>(
                         rule.get_threshold(),
                         report,
                         true,
+                        Vec::new(),
                     );
                 }
                 // If we have a unicity rule in place, update the global hashset
@@ -572,6 +1508,30 @@ fn validate_numeric_column<T: ArrowNumericType>(
                             rule.get_threshold(),
                             report,
                             true,
+                            collect_samples_with(
+                                count,
+                                offset,
+                                report,
+                                |limit| rule.failing_rows(numeric_array, limit),
+                                |row| rule.sample_value(numeric_array, row),
+                            ),
+                        );
+                    }
+                }
+                // Typed arrays have their stats computed as is, they are only missing when the
+                // column has no statistical rule
+                if let Some(stats) = stats {
+                    for rule in statistical_rules {
+                        let count = rule.validate_with_stats(numeric_array, stats);
+                        record_validation_result(
+                            name,
+                            rule.name(),
+                            count,
+                            error_counter,
+                            rule.get_threshold(),
+                            report,
+                            true,
+                            Vec::new(),
                         );
                     }
                 }
@@ -597,6 +1557,7 @@ pub fn validate_date_column(
     unicity_check: &Option<UnicityCheck>,
     null_check: &Option<NullCheck>,
     array: &dyn Array,
+    offset: usize,
     error_counter: &AtomicUsize,
     report: &ResultAccumulator,
     unicity_accumulators: &UnicityAccumulator,
@@ -604,7 +1565,7 @@ pub fn validate_date_column(
     let array_values = array.len() - array.null_count();
     report.record_valid_values(name, array_values);
     // Run null check if present
-    validate_null_check(null_check, array, name, report);
+    validate_null_check(null_check, array, name, error_counter, report);
 
     // we only run a type check if the table is a CsvTable
     if let Some(type_rule) = type_check {
@@ -618,6 +1579,7 @@ pub fn validate_date_column(
                     type_rule.get_threshold(),
                     report,
                     true,
+                    collect_type_samples(errors, array, &date_array, offset, report),
                 );
                 if errors == array_values {
                     // We return early in case of a full invalid initial data type
@@ -635,6 +1597,9 @@ pub fn validate_date_column(
                             rule.get_threshold(),
                             report,
                             true,
+                            collect_samples(count, &date_array, offset, report, |limit| {
+                                rule.failing_rows(&date_array, limit)
+                            }),
                         );
                     }
                 }
@@ -677,6 +1642,9 @@ pub fn validate_date_column(
                             rule.get_threshold(),
                             report,
                             true,
+                            collect_samples(count, date_array, offset, report, |limit| {
+                                rule.failing_rows(date_array, limit)
+                            }),
                         );
                     }
                 }
@@ -695,6 +1663,192 @@ pub fn validate_date_column(
     }
 }
 
+/// Run the timestamp rules on a millisecond array, shared by the parsed and native paths
+fn run_timestamp_rules(
+    name: &str,
+    rules: &[Box<dyn TimestampRule>],
+    unicity_check: &Option<UnicityCheck>,
+    ts_array: &TimestampMillisecondArray,
+    offset: usize,
+    error_counter: &AtomicUsize,
+    report: &ResultAccumulator,
+    unicity_accumulators: &UnicityAccumulator,
+) {
+    for rule in rules {
+        if let Ok(count) = rule.validate(ts_array, name.to_string()) {
+            record_validation_result(
+                name,
+                rule.name(),
+                count,
+                error_counter,
+                rule.get_threshold(),
+                report,
+                true,
+                collect_samples(count, ts_array, offset, report, |limit| {
+                    rule.failing_rows(ts_array, limit)
+                }),
+            );
+        }
+    }
+    // If we have a unicity rule in place, update the global hashset
+    if let Some(unicity_rule) = unicity_check {
+        let (null_count, local_hash) = unicity_rule.validate_numeric(ts_array);
+        unicity_accumulators.record_hashes(name, null_count, local_hash);
+    }
+}
+
+fn validate_timestamp_column(
+    name: &str,
+    rules: &[Box<dyn TimestampRule>],
+    type_check: &Option<TimestampTypeCheck>,
+    unicity_check: &Option<UnicityCheck>,
+    null_check: &Option<NullCheck>,
+    array: &dyn Array,
+    offset: usize,
+    error_counter: &AtomicUsize,
+    report: &ResultAccumulator,
+    unicity_accumulators: &UnicityAccumulator,
+) -> Result<Arc<dyn Array>, RuleError> {
+    let array_values = array.len() - array.null_count();
+    report.record_valid_values(name, array_values);
+    validate_null_check(null_check, array, name, error_counter, report);
+
+    if let Some(type_rule) = type_check {
+        let (errors, ts_array) = type_rule.validate(array)?;
+        record_validation_result(
+            name,
+            type_rule.name(),
+            errors,
+            error_counter,
+            type_rule.get_threshold(),
+            report,
+            true,
+            collect_type_samples(errors, array, &ts_array, offset, report),
+        );
+        if errors == array_values {
+            // We return early in case of a full invalid initial data type
+            return Err(RuleError::TypeCastFailed);
+        }
+        run_timestamp_rules(
+            name,
+            rules,
+            unicity_check,
+            &ts_array,
+            offset,
+            error_counter,
+            report,
+            unicity_accumulators,
+        );
+        Ok(Arc::new(ts_array))
+    } else {
+        // Native timestamps can use any unit, bring them to milliseconds like parsed ones
+        let casted = match array.data_type() {
+            DataType::Timestamp(..) => cast(array, &TimestampTypeCheck::data_type()).ok(),
+            _ => None,
+        };
+        match casted
+            .as_ref()
+            .and_then(|a| a.as_any().downcast_ref::<TimestampMillisecondArray>())
+        {
+            Some(ts_array) => {
+                run_timestamp_rules(
+                    name,
+                    rules,
+                    unicity_check,
+                    ts_array,
+                    offset,
+                    error_counter,
+                    report,
+                    unicity_accumulators,
+                );
+                Ok(Arc::new(ts_array.to_owned()))
+            }
+            None => {
+                record_downcast_failure(array.len(), name, "DowncastFailure".to_string(), report);
+                Err(RuleError::TypeCastFailed)
+            }
+        }
+    }
+}
+
+fn validate_boolean_column(
+    name: &str,
+    rules: &[Box<dyn BooleanRule>],
+    type_check: &Option<BooleanTypeCheck>,
+    null_check: &Option<NullCheck>,
+    array: &dyn Array,
+    offset: usize,
+    error_counter: &AtomicUsize,
+    report: &ResultAccumulator,
+) -> Result<BooleanArray, RuleError> {
+    let array_values = array.len() - array.null_count();
+    report.record_valid_values(name, array_values);
+    // Run null check if present
+    validate_null_check(null_check, array, name, error_counter, report);
+
+    let bool_array = if let Some(type_rule) = type_check {
+        match type_rule.validate(array) {
+            Ok((errors, bool_array)) => {
+                record_validation_result(
+                    name,
+                    type_rule.name(),
+                    errors,
+                    error_counter,
+                    type_rule.get_threshold(),
+                    report,
+                    true,
+                    collect_type_samples(errors, array, &bool_array, offset, report),
+                );
+                if errors == array_values {
+                    // We return early in case of a full invalid initial data type
+                    return Err(RuleError::TypeCastFailed);
+                }
+                bool_array
+            }
+            Err(e) => {
+                record_type_check_error(
+                    array.len(),
+                    name,
+                    type_rule.name(),
+                    type_rule.get_threshold(),
+                    error_counter,
+                    report,
+                );
+                return Err(e);
+            }
+        }
+    } else {
+        // Contrary to using TypeCheck here we cant ensure that the given array can be safely
+        // downcast. We need to match on the result
+        match array.as_any().downcast_ref::<BooleanArray>() {
+            Some(bool_array) => bool_array.clone(),
+            None => {
+                record_downcast_failure(array.len(), name, "DowncastFailure".to_string(), report);
+                return Err(RuleError::TypeCastFailed);
+            }
+        }
+    };
+
+    // We run all domain level rules
+    for rule in rules {
+        if let Ok(count) = rule.validate(&bool_array, name.to_string()) {
+            record_validation_result(
+                name,
+                rule.name(),
+                count,
+                error_counter,
+                rule.get_threshold(),
+                report,
+                true,
+                collect_samples(count, &bool_array, offset, report, |limit| {
+                    rule.failing_rows(&bool_array, limit)
+                }),
+            );
+        }
+    }
+    Ok(bool_array)
+}
+
 fn validate_relation(
     executable_relation: &ExecutableRelation,
     array_ref: &HashMap<String, Arc<dyn Array>>,
@@ -703,19 +1857,40 @@ fn validate_relation(
 ) {
     let lhs_name = executable_relation.names[0].as_str();
     let rhs_name = executable_relation.names[1].as_str();
+    let relation_name = format!("{} | {}", lhs_name, rhs_name);
     // We can safely unwrap as both keys are check before calling the function
     let lsh = array_ref.get(lhs_name).unwrap();
     let rhs = array_ref.get(rhs_name).unwrap();
     for rule in &executable_relation.rules {
-        if let Ok(count) = rule.validate(lsh, rhs, [lhs_name, rhs_name]) {
+        // Extra columns may have failed their type cast, skip the rule in that case
+        let Some(extra) = rule
+            .extra_columns()
+            .iter()
+            .map(|c| array_ref.get(c))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        if let Ok(count) = rule.validate(lsh, rhs, &extra, [lhs_name, rhs_name]) {
             record_validation_result(
-                format!("{} | {}", lhs_name, rhs_name).as_str(),
+                relation_name.as_str(),
                 rule.name(),
                 count,
                 error_counter,
                 rule.get_threshold(),
                 report,
                 false,
+                Vec::new(),
+            );
+        }
+        // Skipped rows are informative only, they never fail the relation
+        if let Some(skipped) = rule.skipped_rows(lsh, rhs, &extra) {
+            report.record_relation_result(
+                relation_name.as_str(),
+                format!("{}Skipped", rule.name()),
+                100.0,
+                skipped,
+                Vec::new(),
             );
         }
     }
@@ -0,0 +1,157 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use arrow::{datatypes::Schema, util::display::array_value_to_string};
+use arrow_array::Array;
+
+use crate::{
+    rules::generic::for_each_hash, utils::hasher::Xxh3Builder, validator::ExecutableColumn,
+    ValueCounts,
+};
+
+/// Distinct values counted per column, the rows of any further value fall into "other"
+const VALUE_COUNTS_MAX_TRACKED: usize = 10_000;
+/// Most frequent values reported per column
+const VALUE_COUNTS_TOP: usize = 10;
+
+#[derive(Default)]
+struct ColumnCounts {
+    // The column was found in at least one batch
+    seen: bool,
+    // Value hash → (first value met, count)
+    counts: HashMap<u64, (String, usize), Xxh3Builder>,
+    other: usize,
+    null_count: usize,
+}
+
+/// Counts the occurrences of each value of the requested columns.
+///
+/// Works on the arrays casted by the type checks, values are hashed the way the unicity
+/// check does. Memory is bounded by `VALUE_COUNTS_MAX_TRACKED` distinct values per column.
+pub(crate) struct ValueCountAccumulator {
+    columns: HashMap<String, Mutex<ColumnCounts>>,
+}
+
+impl ValueCountAccumulator {
+    /// Track the configured columns found in `requested`.
+    pub fn new(columns: &[ExecutableColumn], requested: &[String]) -> Self {
+        Self {
+            columns: columns
+                .iter()
+                .map(|col| col.get_name())
+                .filter(|name| requested.contains(name))
+                .map(|name| (name, Mutex::default()))
+                .collect(),
+        }
+    }
+
+    /// Count the values of one batch of `num_rows` rows, `casted` holds the arrays that
+    /// passed the type check. A column whose cast failed only holds null values.
+    pub fn record_batch(
+        &self,
+        schema: &Schema,
+        num_rows: usize,
+        casted: &HashMap<String, Arc<dyn Array>>,
+    ) {
+        for (name, counts) in &self.columns {
+            if schema.index_of(name).is_err() {
+                continue;
+            }
+            let Some(array) = casted.get(name) else {
+                let mut counts = counts.lock().unwrap();
+                counts.seen = true;
+                counts.null_count += num_rows;
+                continue;
+            };
+            // Hashes are yielded for the non-null rows, in order
+            let mut rows = (0..array.len()).filter(|row| array.is_valid(*row));
+            let mut local: HashMap<u64, (usize, usize), Xxh3Builder> =
+                HashMap::with_hasher(Xxh3Builder);
+            for_each_hash(array.as_ref(), |hash| {
+                let row = rows.next().unwrap_or_default();
+                local.entry(hash).or_insert((row, 0)).1 += 1;
+                true
+            });
+
+            let mut counts = counts.lock().unwrap();
+            counts.seen = true;
+            counts.null_count += array.null_count();
+            for (hash, (row, count)) in local {
+                if let Some((_, total)) = counts.counts.get_mut(&hash) {
+                    *total += count;
+                } else if counts.counts.len() < VALUE_COUNTS_MAX_TRACKED {
+                    let value = array_value_to_string(array.as_ref(), row).unwrap_or_default();
+                    counts.counts.insert(hash, (value, count));
+                } else {
+                    counts.other += count;
+                }
+            }
+        }
+    }
+
+    /// Consume the accumulator, returning the `VALUE_COUNTS_TOP` most frequent values of
+    /// each column found in the data. Ties are ordered by value.
+    pub fn finalize(self) -> Vec<ValueCounts> {
+        self.columns
+            .into_iter()
+            .map(|(name, counts)| (name, counts.into_inner().unwrap()))
+            .filter(|(_, counts)| counts.seen)
+            .map(|(name, counts)| {
+                let mut values: Vec<(String, usize)> = counts.counts.into_values().collect();
+                values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+                let other = counts.other
+                    + values
+                        .iter()
+                        .skip(VALUE_COUNTS_TOP)
+                        .map(|(_, count)| count)
+                        .sum::<usize>();
+                values.truncate(VALUE_COUNTS_TOP);
+                ValueCounts {
+                    name,
+                    values,
+                    other,
+                    null_count: counts.null_count,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::{DataType, Field};
+    use arrow_array::StringArray;
+
+    #[test]
+    fn test_value_counts_top_and_other() {
+        let schema = Schema::new(vec![Field::new("status", DataType::Utf8, true)]);
+        let mut values = vec![Some("active"); 5];
+        values.extend([Some("closed"); 3]);
+        values.extend([None, Some("pending")]);
+        // Values past the top are reported as "other"
+        let tail: Vec<String> = (0..VALUE_COUNTS_TOP).map(|i| format!("v{i}")).collect();
+        values.extend(tail.iter().map(|v| Some(v.as_str())));
+
+        let accumulator = ValueCountAccumulator {
+            columns: HashMap::from([("status".to_string(), Mutex::default())]),
+        };
+        let array: Arc<dyn Array> = Arc::new(StringArray::from(values));
+        let casted = HashMap::from([("status".to_string(), array.clone())]);
+        accumulator.record_batch(&schema, array.len(), &casted);
+        accumulator.record_batch(&schema, array.len(), &casted);
+
+        let counts = accumulator.finalize().remove(0);
+        assert_eq!(counts.name, "status");
+        assert_eq!(counts.values.len(), VALUE_COUNTS_TOP);
+        assert_eq!(counts.values[0], ("active".to_string(), 10));
+        assert_eq!(counts.values[1], ("closed".to_string(), 6));
+        assert_eq!(counts.values[2], ("pending".to_string(), 2));
+        // Ties are ordered by value
+        assert_eq!(counts.values[3], ("v0".to_string(), 2));
+        assert_eq!(counts.other, 6);
+        assert_eq!(counts.null_count, 2);
+    }
+}
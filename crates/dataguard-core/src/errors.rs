@@ -41,4 +41,8 @@ pub enum RuleError {
     /// Incorrect operator for comparaison
     #[error("Unknown operator: '{0}'")]
     IncorrentOperatorError(String),
+
+    /// The file content does not match its declared format
+    #[error("File '{0}' is declared as {1} but looks like {2}")]
+    FormatMismatch(String, String, String),
 }
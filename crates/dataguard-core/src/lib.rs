@@ -2,6 +2,7 @@ pub mod columns;
 mod compiler;
 mod engine;
 pub mod errors;
+pub mod progress;
 pub mod readers;
 pub mod results;
 pub mod rules;
@@ -10,10 +11,22 @@ pub mod types;
 pub mod utils;
 pub mod validator;
 
+pub use columns::boolean_builder::BooleanColumnBuilder;
 pub use columns::numeric_builder::NumericColumnBuilder;
 pub use columns::string_builder::StringColumnBuilder;
+pub use columns::timestamp_builder::TimestampColumnBuilder;
 pub use columns::{ColumnRule, ColumnType};
+pub use engine::ParallelAxis;
 pub use errors::RuleError;
-pub use results::{RuleResult, ValidationResult};
-pub use tables::{csv_table::CsvTable, parquet_table::ParquetTable, Table};
-pub use validator::Validator;
+pub use progress::Progress;
+pub use readers::ReadMode;
+pub use results::{
+    ColumnProfile, ForeignKeyResult, GroupMismatch, GroupResult, RuleResult, ValidationResult,
+    ValueCounts,
+};
+pub use tables::{
+    arrow_table::ArrowTable, avro_table::AvroTable, csv_table::CsvTable,
+    fixed_width_table::FixedWidthTable, ipc_table::IpcTable, json_table::JsonTable,
+    orc_table::OrcTable, parquet_table::ParquetTable, Table,
+};
+pub use validator::{ForeignKey, Validator};
@@ -0,0 +1,62 @@
+//! Progress of a running validation, shared with whoever displays it.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// Rows validated so far for the table being validated.
+///
+/// A [`Validator`] given a `Progress` updates it as batches are validated, another
+/// thread can poll it to display a progress bar. The total row count is only known
+/// when the whole table is read in memory, it stays unknown while streaming.
+///
+/// [`Validator`]: crate::Validator
+#[derive(Debug, Default)]
+pub struct Progress {
+    table: Mutex<Option<String>>,
+    rows: AtomicUsize,
+    // 0 while unknown
+    total_rows: AtomicUsize,
+}
+
+impl Progress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start counting the rows of `table`, resetting the previous counts.
+    pub fn start_table(&self, table: &str) {
+        self.rows.store(0, Ordering::Relaxed);
+        self.total_rows.store(0, Ordering::Relaxed);
+        *self.table.lock().unwrap() = Some(table.to_string());
+    }
+
+    /// No table is being validated anymore, the counts of the last one are kept.
+    pub fn finish(&self) {
+        *self.table.lock().unwrap() = None;
+    }
+
+    pub fn set_total_rows(&self, total_rows: usize) {
+        self.total_rows.store(total_rows, Ordering::Relaxed);
+    }
+
+    pub fn add_rows(&self, rows: usize) {
+        self.rows.fetch_add(rows, Ordering::Relaxed);
+    }
+
+    /// Name of the table being validated, if any
+    pub fn table(&self) -> Option<String> {
+        self.table.lock().unwrap().clone()
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows.load(Ordering::Relaxed)
+    }
+
+    /// Row count of the table, `None` while unknown
+    pub fn total_rows(&self) -> Option<usize> {
+        match self.total_rows.load(Ordering::Relaxed) {
+            0 => None,
+            total => Some(total),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use arrow_avro::reader::{Reader, ReaderBuilder};
+
+use crate::readers::source_reader::{spawn_stream, StreamFrom};
+use crate::readers::BATCH_SIZE;
+
+/// Open an Avro object container file, the writer schema is read from its header.
+fn open_avro(path: &str) -> Result<Reader<BufReader<File>>, io::Error> {
+    let file = BufReader::new(File::open(path)?);
+    ReaderBuilder::new()
+        .with_batch_size(BATCH_SIZE)
+        .build(file)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Positions of the requested columns found in the file schema, in request order.
+///
+/// Empty strings and columns absent from the file are dismissed, like for Parquet.
+fn projection(schema: &Schema, cols: &[String]) -> Vec<usize> {
+    cols.iter()
+        .filter_map(|c| schema.index_of(c).ok())
+        .collect()
+}
+
+/// Reads an Avro object container file sequentially in a single thread.
+///
+/// # Arguments
+///
+/// * `path` - Path to the Avro file
+/// * `cols` - List of column names to read
+///
+/// # Returns
+///
+/// A vector of Arrow RecordBatches containing the requested columns, with the types
+/// of the Avro schema (`long` as Int64, `string` as Utf8, ...).
+///
+/// # Note
+///
+/// Blocks of a container file can not be located without decoding the previous ones,
+/// files are always read sequentially. A requested column that is not present in the
+/// file is silently dismissed.
+pub fn read_avro_sequential(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let reader = open_avro(path)?;
+    let indices = projection(&reader.schema(), &cols);
+
+    let mut batches = Vec::new();
+    for batch_result in reader {
+        let batch = batch_result
+            .and_then(|b| b.project(&indices))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        batches.push(Arc::new(batch));
+    }
+    Ok(batches)
+}
+
+/// Reads an Avro object container file batch by batch on a background thread.
+///
+/// Only a few batches are buffered at a time, keeping memory bounded for large files.
+/// Decoding errors are sent through the channel and end the stream.
+pub fn read_avro_streaming(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Receiver<Result<Arc<RecordBatch>, io::Error>>, io::Error> {
+    let reader = open_avro(path)?;
+    let indices = projection(&reader.schema(), &cols);
+
+    let batches = reader.map(move |batch| batch.and_then(|b| b.project(&indices)));
+    Ok(spawn_stream(batches))
+}
+
+/// Reads an Avro object container from a non-seekable source, see [`read_streaming_from`].
+///
+/// [`read_streaming_from`]: crate::readers::read_streaming_from
+pub(crate) fn avro_stream_from<R: BufRead + Send + 'static>(
+    source: R,
+    cols: Vec<String>,
+) -> Result<StreamFrom, io::Error> {
+    let reader = ReaderBuilder::new()
+        .with_batch_size(BATCH_SIZE)
+        .build(source)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let schema = reader.schema();
+    let columns = schema.fields().iter().map(|f| f.name().clone()).collect();
+    let indices = projection(&schema, &cols);
+    let batches = reader.map(move |batch| batch.and_then(|b| b.project(&indices)));
+    Ok((columns, spawn_stream(batches)))
+}
+
+/// Returns the Arrow schema decoded from the writer schema of the Avro header.
+pub fn avro_schema(path: &str) -> Result<SchemaRef, io::Error> {
+    Ok(open_avro(path)?.schema())
+}
+
+/// Returns the top-level field names of the schema stored in the Avro file header.
+pub fn avro_column_names(path: &str) -> Result<Vec<String>, io::Error> {
+    Ok(avro_schema(path)?
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use arrow_avro::writer::AvroWriter;
+    use tempfile::NamedTempFile;
+
+    fn write_events() -> NamedTempFile {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("kind", DataType::Utf8, true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("click"), None, Some("view")])),
+            ],
+        )
+        .unwrap();
+        let file = NamedTempFile::new().unwrap();
+        let mut writer = AvroWriter::new(file.reopen().unwrap(), schema).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        file
+    }
+
+    #[test]
+    fn test_avro_sequential_projection() {
+        let file = write_events();
+        let path = file.path().to_str().unwrap();
+        let batches = read_avro_sequential(
+            path,
+            vec!["kind".to_string(), "missing".to_string(), "".to_string()],
+        )
+        .unwrap();
+
+        let total_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(total_rows, 3);
+        assert_eq!(batches[0].num_columns(), 1, "Should only read 'kind'");
+        let kind = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(kind.value(0), "click");
+        assert!(kind.is_null(1));
+    }
+
+    #[test]
+    fn test_avro_streaming_matches_sequential() {
+        let file = write_events();
+        let path = file.path().to_str().unwrap();
+        let cols = vec!["id".to_string(), "kind".to_string()];
+        let sequential = read_avro_sequential(path, cols.clone()).unwrap();
+        let streamed: Vec<_> = read_avro_streaming(path, cols)
+            .unwrap()
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(sequential, streamed);
+        assert_eq!(avro_column_names(path).unwrap(), vec!["id", "kind"]);
+    }
+
+    #[test]
+    fn test_avro_from_source() {
+        let file = write_events();
+        let (columns, batches) = crate::readers::read_sequential_from(
+            file.reopen().unwrap(),
+            crate::readers::FileFormat::Avro,
+            vec!["kind".to_string()],
+            &crate::readers::ReaderConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(columns, vec!["id", "kind"]);
+        assert_eq!(batches[0].num_columns(), 1);
+        assert_eq!(batches[0].num_rows(), 3);
+    }
+}
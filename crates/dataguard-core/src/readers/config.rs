@@ -1,4 +1,6 @@
 #![allow(dead_code)]
+use regex::Regex;
+
 pub struct ReaderConfig {
     min_chunk_size: u64,
     max_chunk_size: u64,
@@ -6,6 +8,14 @@ pub struct ReaderConfig {
     pub batch_size: u32,
     streaming: bool,
     streaming_threshold: u64,
+    /// CSV field delimiter
+    pub delimiter: u8,
+    /// CSV quote character
+    pub quote: u8,
+    /// Whether the first CSV line holds the column names
+    pub has_header: bool,
+    /// CSV tokens read as null, on top of the empty field
+    pub null_values: Vec<String>,
 }
 
 impl Default for ReaderConfig {
@@ -17,6 +27,10 @@ impl Default for ReaderConfig {
             batch_size: 128 * 1024,
             streaming: false,
             streaming_threshold: 500 * 1024 * 1024,
+            delimiter: b',',
+            quote: b'"',
+            has_header: true,
+            null_values: Vec::new(),
         }
     }
 }
@@ -25,6 +39,17 @@ impl ReaderConfig {
     pub fn should_stream(&self, file_size: u64) -> bool {
         self.streaming || file_size >= self.streaming_threshold
     }
+
+    /// Regex matching the empty field and the configured null tokens, `None` keeps
+    /// the reader default where only the empty field is null.
+    pub(crate) fn null_regex(&self) -> Option<Regex> {
+        if self.null_values.is_empty() {
+            return None;
+        }
+        let tokens: Vec<String> = self.null_values.iter().map(|t| regex::escape(t)).collect();
+        // Escaped literals always compile
+        Some(Regex::new(&format!("^(?:|{})$", tokens.join("|"))).unwrap())
+    }
 }
 
 pub fn calculate_chunk_size(
@@ -49,6 +74,16 @@ pub struct ReaderConfigBuilder {
     batch_size: u32,
     streaming: bool,
     streaming_threshold: u64,
+    delimiter: u8,
+    quote: u8,
+    has_header: bool,
+    null_values: Vec<String>,
+}
+
+impl Default for ReaderConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ReaderConfigBuilder {
@@ -62,6 +97,10 @@ impl ReaderConfigBuilder {
             batch_size: reader.batch_size,
             streaming: reader.streaming,
             streaming_threshold: reader.streaming_threshold,
+            delimiter: reader.delimiter,
+            quote: reader.quote,
+            has_header: reader.has_header,
+            null_values: reader.null_values,
         }
     }
 
@@ -74,6 +113,10 @@ impl ReaderConfigBuilder {
             batch_size: self.batch_size,
             streaming: self.streaming,
             streaming_threshold: self.streaming_threshold,
+            delimiter: self.delimiter,
+            quote: self.quote,
+            has_header: self.has_header,
+            null_values: self.null_values,
         }
     }
 
@@ -112,6 +155,26 @@ impl ReaderConfigBuilder {
             ..self
         }
     }
+
+    pub fn with_delimiter(self, delimiter: u8) -> Self {
+        Self { delimiter, ..self }
+    }
+
+    pub fn with_quote(self, quote: u8) -> Self {
+        Self { quote, ..self }
+    }
+
+    pub fn with_header(self, has_header: bool) -> Self {
+        Self { has_header, ..self }
+    }
+
+    /// Read these CSV tokens as null, e.g. `NA` or `\N`. Matching is exact and case sensitive.
+    pub fn with_null_values(self, null_values: Vec<String>) -> Self {
+        Self {
+            null_values,
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
@@ -125,14 +188,17 @@ mod tests {
         assert_eq!(reader.max_chunk_size, 104857600);
         assert_eq!(reader.chunks_per_thread, 5);
         assert_eq!(reader.batch_size, 128 * 1024);
-        assert_eq!(reader.streaming, false);
+        assert!(!reader.streaming);
         assert_eq!(reader.streaming_threshold, 500 * 1024 * 1024);
+        assert_eq!(reader.delimiter, b',');
+        assert_eq!(reader.quote, b'"');
+        assert!(reader.has_header);
     }
 
     #[test]
     fn test_reader_streaming() {
         let reader = ReaderConfig::default();
-        let st = reader.should_stream(1 * 1024 * 1024 * 1024); // 1G should stream
+        let st = reader.should_stream(1024 * 1024 * 1024); // 1G should stream
         assert!(st)
     }
 
@@ -142,13 +208,31 @@ mod tests {
         let reader_builder = reader_builder
             .with_batch_size(128_000)
             .with_streaming_threshold(100 * 1024 * 1024)
-            .with_min_chunk_size(2 * 1024 * 1024);
+            .with_min_chunk_size(2 * 1024 * 1024)
+            .with_delimiter(b';')
+            .with_header(false);
         let reader = reader_builder.build();
+        assert_eq!(reader.delimiter, b';');
+        assert!(!reader.has_header);
         assert_eq!(reader.batch_size, 128_000);
         assert_eq!(reader.streaming_threshold, 100 * 1024 * 1024);
         assert_eq!(reader.min_chunk_size, 2 * 1024 * 1024);
     }
 
+    #[test]
+    fn test_reader_null_regex() {
+        assert!(ReaderConfig::default().null_regex().is_none());
+        let reader = ReaderConfigBuilder::new()
+            .with_null_values(vec!["NA".to_string(), "\\N".to_string()])
+            .build();
+        let regex = reader.null_regex().unwrap();
+        assert!(regex.is_match(""));
+        assert!(regex.is_match("NA"));
+        assert!(regex.is_match("\\N"));
+        assert!(!regex.is_match("NAN"));
+        assert!(!regex.is_match("na"));
+    }
+
     #[test]
     fn test_chunk_small_file() {
         let reader = ReaderConfig::default();
@@ -1,13 +1,17 @@
+use arrow::csv::reader::Format;
 use arrow::csv::ReaderBuilder;
 use arrow::datatypes::{DataType, Field, Schema};
 use arrow::record_batch::RecordBatch;
+use flate2::read::MultiGzDecoder;
 use rayon::prelude::*;
 use std::fs::File;
 use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::sync::mpsc::Receiver;
 use std::sync::Arc;
 
-use crate::readers::config::{calculate_chunk_size, ReaderConfig};
-use crate::readers::BATCH_SIZE;
+use crate::readers::config::{calculate_chunk_size, ReaderConfig, ReaderConfigBuilder};
+use crate::readers::source_reader::{spawn_stream, StreamFrom};
+use crate::readers::{is_gzip, BATCH_SIZE};
 
 const MIN_CHUNK_SIZE: u64 = 50 * 1024 * 1024; // 50MB minimum per chunk
 
@@ -27,21 +31,25 @@ const MIN_CHUNK_SIZE: u64 = 50 * 1024 * 1024; // 50MB minimum per chunk
 /// If a requested column is not present in the CSV file, it will be silently
 /// dismissed without raising an error. Only columns that exist in the file
 /// will be included in the resulting batches.
+///
+/// Gzip compressed files can not be split in chunks and are read sequentially.
 pub fn read_csv_parallel(
     path: &str,
     cols: Vec<String>,
 ) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let config = ReaderConfigBuilder::new().with_batch_size(256_000).build();
+    if is_gzip(path) {
+        return read_gzip_fallback(path, cols, &config);
+    }
     let cols: Vec<&str> = cols.iter().map(|v| v.as_str()).collect();
     let file = File::open(path)?;
     let file_size = file.metadata()?.len();
 
-    let schema = Arc::new(generate_utf_schema(path)?);
+    let schema = Arc::new(generate_utf_schema(path, &config)?);
     let cols = cols.as_slice();
     let projection = calculate_projection(&schema, cols);
 
-    let mut header_reader = BufReader::new(File::open(path)?);
-    let mut header = String::new();
-    header_reader.read_line(&mut header)?;
+    let header = read_header(path, &config)?;
     let header_len = header.len() as u64;
 
     let num_threads = rayon::current_num_threads();
@@ -52,7 +60,7 @@ pub fn read_csv_parallel(
 
     let batches: Result<Vec<_>, _> = chunks
         .into_par_iter()
-        .map(|(start, end)| parse_chunk(path, &schema, &projection, 256_000, &header, start, end))
+        .map(|(start, end)| parse_chunk(path, &schema, &projection, &config, &header, start, end))
         .collect();
 
     Ok(batches?.into_iter().flatten().collect())
@@ -75,22 +83,25 @@ pub fn read_csv_parallel(
 /// If a requested column is not present in the CSV file, it will be silently
 /// dismissed without raising an error. Only columns that exist in the file
 /// will be included in the resulting batches.
+///
+/// Gzip compressed files can not be split in chunks and are read sequentially.
 pub fn read_csv_parallel_with_config(
     path: &str,
     cols: Vec<String>,
     config: &ReaderConfig,
 ) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    if is_gzip(path) {
+        return read_gzip_fallback(path, cols, config);
+    }
     let cols: Vec<&str> = cols.iter().map(|v| v.as_str()).collect();
     let file = File::open(path)?;
     let file_size = file.metadata()?.len();
 
-    let schema = Arc::new(generate_utf_schema(path)?);
+    let schema = Arc::new(generate_utf_schema(path, config)?);
     let cols = cols.as_slice();
     let projection = calculate_projection(&schema, cols);
 
-    let mut header_reader = BufReader::new(File::open(path)?);
-    let mut header = String::new();
-    header_reader.read_line(&mut header)?;
+    let header = read_header(path, config)?;
     let header_len = header.len() as u64;
 
     let num_threads = rayon::current_num_threads();
@@ -99,22 +110,23 @@ pub fn read_csv_parallel_with_config(
 
     let batches: Result<Vec<_>, _> = chunks
         .into_par_iter()
-        .map(|(start, end)| {
-            parse_chunk(
-                path,
-                &schema,
-                &projection,
-                config.batch_size as usize,
-                &header,
-                start,
-                end,
-            )
-        })
+        .map(|(start, end)| parse_chunk(path, &schema, &projection, config, &header, start, end))
         .collect();
 
     Ok(batches?.into_iter().flatten().collect())
 }
 
+/// Gzip streams can not be seeked, chunk offsets would point inside compressed data.
+///
+/// The fallback is silent, tables report it through `ValidationResult::read_note`.
+fn read_gzip_fallback(
+    path: &str,
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    read_csv_sequential_with_config(path, cols, config)
+}
+
 /// Calculates column indices for projection based on requested column names.
 ///
 /// # Arguments
@@ -133,7 +145,7 @@ fn calculate_projection(schema: &Schema, requested_cols: &[&str]) -> Vec<usize>
         .collect()
 }
 
-fn create_chunks(
+pub(crate) fn create_chunks(
     path: &str,
     header_len: u64,
     file_size: u64,
@@ -175,7 +187,7 @@ fn parse_chunk(
     path: &str,
     schema: &Arc<Schema>,
     projection: &[usize],
-    batch_size: usize,
+    config: &ReaderConfig,
     header: &str,
     start: u64,
     end: u64,
@@ -192,10 +204,7 @@ fn parse_chunk(
     limited.read_to_end(&mut buffer)?;
 
     let cursor = io::Cursor::new(buffer);
-    let reader = ReaderBuilder::new(schema.clone())
-        .with_header(true)
-        .with_projection(projection.to_vec())
-        .with_batch_size(batch_size)
+    let reader = csv_reader_builder(schema.clone(), projection, config)
         .build(cursor)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
@@ -227,18 +236,28 @@ fn parse_chunk(
 pub fn read_csv_sequential(
     path: &str,
     cols: Vec<String>,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let config = ReaderConfigBuilder::new()
+        .with_batch_size(BATCH_SIZE as u32)
+        .build();
+    read_csv_sequential_with_config(path, cols, &config)
+}
+
+/// Reads a CSV file sequentially in a single thread, see [`read_csv_sequential`].
+///
+/// The delimiter, quote and header options are taken from `config`.
+pub fn read_csv_sequential_with_config(
+    path: &str,
+    cols: Vec<String>,
+    config: &ReaderConfig,
 ) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
     let cols: Vec<&str> = cols.iter().map(|v| v.as_str()).collect();
-    let file = File::open(path)?;
-    let schema = Arc::new(generate_utf_schema(path)?);
-    let cols = cols.as_slice();
-    let projection = calculate_projection(&schema, cols);
+    let file = open_csv(path)?;
+    let schema = Arc::new(generate_utf_schema(path, config)?);
+    let projection = calculate_projection(&schema, cols.as_slice());
     let mut batches = Vec::new();
 
-    let reader = ReaderBuilder::new(schema)
-        .with_header(true)
-        .with_projection(projection.to_vec())
-        .with_batch_size(BATCH_SIZE)
+    let reader = csv_reader_builder(schema, &projection, config)
         .build(file)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
@@ -250,29 +269,135 @@ pub fn read_csv_sequential(
     Ok(batches)
 }
 
-fn generate_utf_schema(path: &str) -> Result<Schema, io::Error> {
+/// Reads a CSV file batch by batch on a background thread.
+///
+/// Only a few batches are buffered at a time, keeping memory bounded for large files.
+/// Gzip compressed files are decompressed incrementally as batches are decoded.
+/// Decoding errors are sent through the channel and end the stream.
+pub fn read_csv_streaming(
+    path: &str,
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<Receiver<Result<Arc<RecordBatch>, io::Error>>, io::Error> {
+    let cols: Vec<&str> = cols.iter().map(|v| v.as_str()).collect();
+    let file = open_csv(path)?;
+    let schema = Arc::new(generate_utf_schema(path, config)?);
+    let projection = calculate_projection(&schema, cols.as_slice());
+    let reader = csv_reader_builder(schema, &projection, config)
+        .build(file)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(spawn_stream(reader))
+}
+
+fn csv_reader_builder(
+    schema: Arc<Schema>,
+    projection: &[usize],
+    config: &ReaderConfig,
+) -> ReaderBuilder {
+    let builder = ReaderBuilder::new(schema)
+        .with_header(config.has_header)
+        .with_delimiter(config.delimiter)
+        .with_quote(config.quote)
+        .with_projection(projection.to_vec())
+        .with_batch_size(config.batch_size as usize);
+    match config.null_regex() {
+        Some(null_regex) => builder.with_null_regex(null_regex),
+        None => builder,
+    }
+}
+
+/// Open a CSV file, decompressing it on the fly when it is gzip compressed.
+fn open_csv(path: &str) -> Result<Box<dyn Read + Send>, io::Error> {
     let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
-    if let Some(first) = lines.next() {
-        let header = first?;
-        let all_columns: Vec<&str> = header.split(',').collect();
-        let fields: Vec<Field> = all_columns
-            .iter()
-            .map(|c| Field::new(c.trim(), DataType::Utf8, true))
-            .collect();
-        Ok(Schema::new(fields))
+    if is_gzip(path) {
+        Ok(Box::new(MultiGzDecoder::new(BufReader::new(file))))
     } else {
-        Err(io::Error::new(
+        Ok(Box::new(file))
+    }
+}
+
+/// Returns the raw header line, prepended to every chunk, or an empty string
+/// when the file has no header.
+fn read_header(path: &str, config: &ReaderConfig) -> Result<String, io::Error> {
+    let mut header = String::new();
+    if config.has_header {
+        BufReader::new(File::open(path)?).read_line(&mut header)?;
+    }
+    Ok(header)
+}
+
+/// Returns the column names of a CSV file, as the readers see them.
+pub fn csv_column_names(path: &str, config: &ReaderConfig) -> Result<Vec<String>, io::Error> {
+    let schema = generate_utf_schema(path, config)?;
+    Ok(schema.fields().iter().map(|f| f.name().clone()).collect())
+}
+
+/// Reads CSV batches from a non-seekable source, see [`read_streaming_from`].
+///
+/// The first line is consumed to build the schema, then replayed in front of the rest
+/// of the source.
+///
+/// [`read_streaming_from`]: crate::readers::read_streaming_from
+pub(crate) fn csv_stream_from<R: BufRead + Send + 'static>(
+    mut source: R,
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<StreamFrom, io::Error> {
+    let mut head = Vec::new();
+    source.read_until(b'\n', &mut head)?;
+    let schema = Arc::new(utf_schema(head.as_slice(), config)?);
+    let columns = schema.fields().iter().map(|f| f.name().clone()).collect();
+    let cols: Vec<&str> = cols.iter().map(|v| v.as_str()).collect();
+    let projection = calculate_projection(&schema, cols.as_slice());
+    let reader = csv_reader_builder(schema, &projection, config)
+        .build(io::Cursor::new(head).chain(source))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok((columns, spawn_stream(reader)))
+}
+
+/// Build a schema with every column typed as Utf8.
+///
+/// Column names come from the header line, split on the configured delimiter.
+/// Files without header get synthetic names: `col_0`, `col_1`, ...
+fn generate_utf_schema(path: &str, config: &ReaderConfig) -> Result<Schema, io::Error> {
+    utf_schema(BufReader::new(open_csv(path)?), config)
+}
+
+/// Build the Utf8 schema from the first line of `head`, see [`generate_utf_schema`].
+fn utf_schema(head: impl Read, config: &ReaderConfig) -> Result<Schema, io::Error> {
+    let (inferred, _) = Format::default()
+        .with_header(config.has_header)
+        .with_delimiter(config.delimiter)
+        .with_quote(config.quote)
+        .infer_schema(head, Some(1))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    if inferred.fields().is_empty() {
+        return Err(io::Error::new(
             io::ErrorKind::InvalidData,
             "CSV file is empty",
-        ))
+        ));
     }
+    let fields: Vec<Field> = inferred
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let name = if config.has_header {
+                f.name().trim().to_string()
+            } else {
+                format!("col_{}", i)
+            };
+            Field::new(name, DataType::Utf8, true)
+        })
+        .collect();
+    Ok(Schema::new(fields))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow::array::{Array, AsArray};
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -282,7 +407,8 @@ mod tests {
         writeln!(file, "name,age,city").unwrap();
         writeln!(file, "Alice,30,New York").unwrap();
 
-        let schema = generate_utf_schema(file.path().to_str().unwrap()).unwrap();
+        let schema =
+            generate_utf_schema(file.path().to_str().unwrap(), &ReaderConfig::default()).unwrap();
         assert_eq!(schema.fields().len(), 3);
         assert_eq!(schema.field(0).name(), "name");
         assert_eq!(schema.field(1).name(), "age");
@@ -295,7 +421,8 @@ mod tests {
         writeln!(file, "name,age,city").unwrap();
         writeln!(file, "Alice,30,New York").unwrap();
 
-        let schema = generate_utf_schema(file.path().to_str().unwrap()).unwrap();
+        let schema =
+            generate_utf_schema(file.path().to_str().unwrap(), &ReaderConfig::default()).unwrap();
         assert_eq!(schema.fields().len(), 3);
         assert_eq!(schema.field(0).name(), "name");
         assert_eq!(schema.field(1).name(), "age");
@@ -305,7 +432,7 @@ mod tests {
     #[test]
     fn test_generate_utf_schema_empty_file() {
         let file = NamedTempFile::new().unwrap();
-        let result = generate_utf_schema(file.path().to_str().unwrap());
+        let result = generate_utf_schema(file.path().to_str().unwrap(), &ReaderConfig::default());
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
     }
@@ -348,4 +475,117 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err().kind(), io::ErrorKind::NotFound);
     }
+
+    #[test]
+    fn test_read_csv_custom_delimiter_and_quote() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "name;'city; country'").unwrap();
+        writeln!(file, "Alice;'Paris; France'").unwrap();
+        writeln!(file, "Bob;Berlin").unwrap();
+
+        let config = ReaderConfigBuilder::new()
+            .with_delimiter(b';')
+            .with_quote(b'\'')
+            .build();
+        let path = file.path().to_str().unwrap();
+        let schema = generate_utf_schema(path, &config).unwrap();
+        assert_eq!(schema.field(1).name(), "city; country");
+
+        let batches =
+            read_csv_sequential_with_config(path, vec!["city; country".to_string()], &config)
+                .unwrap();
+        let cities = batches[0].column(0).as_string::<i32>();
+        assert_eq!(cities.value(0), "Paris; France");
+        assert_eq!(cities.value(1), "Berlin");
+    }
+
+    #[test]
+    fn test_read_csv_without_header() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "Alice\t30").unwrap();
+        writeln!(file, "Bob\t25").unwrap();
+
+        let config = ReaderConfigBuilder::new()
+            .with_delimiter(b'\t')
+            .with_header(false)
+            .build();
+        let path = file.path().to_str().unwrap();
+        let batches =
+            read_csv_parallel_with_config(path, vec!["col_1".to_string()], &config).unwrap();
+        let total: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(total, 2);
+        assert_eq!(batches[0].schema().field(0).name(), "col_1");
+        assert_eq!(batches[0].column(0).as_string::<i32>().value(0), "30");
+    }
+
+    #[test]
+    fn test_read_csv_null_values() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "name,score").unwrap();
+        writeln!(file, "Alice,NA").unwrap();
+        writeln!(file, "NULL,\"\"").unwrap();
+        writeln!(file, "Bob,NAN").unwrap();
+
+        let config = ReaderConfigBuilder::new()
+            .with_null_values(vec!["NA".to_string(), "NULL".to_string()])
+            .build();
+        let path = file.path().to_str().unwrap();
+        let batches = read_csv_sequential_with_config(
+            path,
+            vec!["name".to_string(), "score".to_string()],
+            &config,
+        )
+        .unwrap();
+        let names = batches[0].column(0).as_string::<i32>();
+        let scores = batches[0].column(1).as_string::<i32>();
+        assert!(names.is_null(1));
+        assert!(scores.is_null(0));
+        assert!(scores.is_null(1));
+        // Tokens must match the whole field
+        assert_eq!(scores.value(2), "NAN");
+    }
+
+    #[test]
+    fn test_read_csv_streaming() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "name,age").unwrap();
+        for i in 0..10 {
+            writeln!(file, "user{},{}", i, i).unwrap();
+        }
+        let config = ReaderConfigBuilder::new().with_batch_size(3).build();
+        let rx = read_csv_streaming(
+            file.path().to_str().unwrap(),
+            vec!["age".to_string()],
+            &config,
+        )
+        .unwrap();
+        let batches: Vec<_> = rx.iter().map(|b| b.unwrap()).collect();
+        assert_eq!(batches.len(), 4);
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 10);
+    }
+
+    #[test]
+    fn test_read_csv_gzip() {
+        let mut encoder =
+            flate2::write::GzEncoder::new(NamedTempFile::new().unwrap(), Default::default());
+        writeln!(encoder, "name,age").unwrap();
+        for i in 0..10 {
+            writeln!(encoder, "user{},{}", i, i).unwrap();
+        }
+        let file = encoder.finish().unwrap();
+        let path = file.path().to_str().unwrap();
+        let config = ReaderConfigBuilder::new().with_batch_size(3).build();
+
+        assert_eq!(csv_column_names(path, &config).unwrap(), ["name", "age"]);
+
+        let batches =
+            read_csv_parallel_with_config(path, vec!["age".to_string()], &config).unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 10);
+        assert_eq!(batches[0].column(0).as_string::<i32>().value(1), "1");
+
+        let rx = read_csv_streaming(path, vec!["name".to_string()], &config).unwrap();
+        let batches: Vec<_> = rx.iter().map(|b| b.unwrap()).collect();
+        assert_eq!(batches.len(), 4);
+        assert_eq!(batches[3].column(0).as_string::<i32>().value(0), "user9");
+    }
 }
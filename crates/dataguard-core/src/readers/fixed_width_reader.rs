@@ -0,0 +1,372 @@
+use arrow::array::{ArrayRef, StringBuilder};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::error::ArrowError;
+use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+
+use crate::readers::config::{calculate_chunk_size, ReaderConfig};
+use crate::readers::csv_reader::create_chunks;
+use crate::readers::source_reader::{spawn_stream, StreamFrom};
+use crate::readers::BATCH_SIZE;
+
+/// Position of a column in the lines of a fixed-width file.
+///
+/// Offsets and widths count bytes, a field cutting a multi bytes character fails the read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedWidthField {
+    pub name: String,
+    /// Byte offset of the field from the start of the line
+    pub offset: usize,
+    /// Number of bytes of the field
+    pub width: usize,
+}
+
+impl FixedWidthField {
+    pub fn new(name: String, offset: usize, width: usize) -> Self {
+        Self {
+            name,
+            offset,
+            width,
+        }
+    }
+}
+
+/// Reads a fixed-width file sequentially in a single thread.
+///
+/// # Arguments
+///
+/// * `path`   - Path to the fixed-width file
+/// * `fields` - Position of every column of the file
+/// * `cols`   - List of column names to read
+///
+/// # Returns
+///
+/// A vector of Arrow RecordBatches containing the requested columns, all typed as Utf8.
+///
+/// # Note
+///
+/// The file has no header. Values are trimmed of the spaces padding them and blank
+/// values are null. Lines shorter than expected are padded: a field starting past the
+/// end of the line is null, a field cut by the end of the line keeps the bytes present.
+/// Empty lines are skipped. A requested column without position is silently dismissed,
+/// like for CSV.
+pub fn fixed_width_read_sequential(
+    path: &str,
+    fields: &[FixedWidthField],
+    cols: Vec<String>,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let file = BufReader::new(File::open(path)?);
+    FixedWidthDecoder::new(file, fields, &cols, BATCH_SIZE, 0)
+        .map(|batch| batch.map(Arc::new).map_err(invalid_data))
+        .collect()
+}
+
+/// Reads a fixed-width file in parallel using multiple threads.
+///
+/// The file is split on newline boundaries, each chunk being decoded independently.
+///
+/// # Arguments
+///
+/// * `path`   - Path to the fixed-width file
+/// * `fields` - Position of every column of the file
+/// * `cols`   - List of column names to read
+/// * `config` - A [`ReaderConfig`]
+///
+/// # Returns
+///
+/// A vector of Arrow RecordBatches containing the requested columns, all typed as Utf8.
+pub fn fixed_width_read_parallel(
+    path: &str,
+    fields: &[FixedWidthField],
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let file_size = File::open(path)?.metadata()?.len();
+
+    let num_threads = rayon::current_num_threads();
+    let chunk_size = calculate_chunk_size(file_size, 0, num_threads, config);
+    let chunks = create_chunks(path, 0, file_size, chunk_size)?;
+
+    let batches: Result<Vec<_>, _> = chunks
+        .into_par_iter()
+        .map(|(start, end)| {
+            let mut file = File::open(path)?;
+            file.seek(SeekFrom::Start(start))?;
+            let chunk = BufReader::new(file.take(end - start));
+            FixedWidthDecoder::new(chunk, fields, &cols, config.batch_size as usize, start)
+                .map(|batch| batch.map(Arc::new).map_err(invalid_data))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect();
+
+    Ok(batches?.into_iter().flatten().collect())
+}
+
+/// Reads a fixed-width file batch by batch on a background thread.
+///
+/// Only a few batches are buffered at a time, keeping memory bounded for large files.
+/// Decoding errors are sent through the channel and end the stream.
+pub fn fixed_width_read_streaming(
+    path: &str,
+    fields: &[FixedWidthField],
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<Receiver<Result<Arc<RecordBatch>, io::Error>>, io::Error> {
+    let file = BufReader::new(File::open(path)?);
+    let decoder = FixedWidthDecoder::new(file, fields, &cols, config.batch_size as usize, 0);
+    Ok(spawn_stream(decoder))
+}
+
+/// Reads fixed-width batches from a non-seekable source, see [`read_streaming_from`].
+///
+/// The source has no header, the column names are the names of `fields`.
+///
+/// [`read_streaming_from`]: crate::readers::read_streaming_from
+pub(crate) fn fixed_width_stream_from<R: BufRead + Send + 'static>(
+    source: R,
+    fields: &[FixedWidthField],
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> StreamFrom {
+    let columns = fields.iter().map(|f| f.name.clone()).collect();
+    let decoder = FixedWidthDecoder::new(source, fields, &cols, config.batch_size as usize, 0);
+    (columns, spawn_stream(decoder))
+}
+
+/// Slices the lines of a source into batches of Utf8 columns.
+struct FixedWidthDecoder<R> {
+    source: R,
+    schema: Arc<Schema>,
+    // (offset, width) of each schema field
+    positions: Vec<(usize, usize)>,
+    batch_size: usize,
+    // Byte position of the next line in the file, used in error messages
+    position: u64,
+    line: Vec<u8>,
+    done: bool,
+}
+
+impl<R: BufRead> FixedWidthDecoder<R> {
+    /// Decode the requested columns found in `fields`, in request order. `position` is the
+    /// byte position of the source in the file.
+    fn new(
+        source: R,
+        fields: &[FixedWidthField],
+        cols: &[String],
+        batch_size: usize,
+        position: u64,
+    ) -> Self {
+        let selected: Vec<&FixedWidthField> = cols
+            .iter()
+            .filter_map(|c| fields.iter().find(|f| &f.name == c))
+            .collect();
+        let schema = Schema::new(
+            selected
+                .iter()
+                .map(|f| Field::new(f.name.as_str(), DataType::Utf8, true))
+                .collect::<Vec<_>>(),
+        );
+        Self {
+            source,
+            schema: Arc::new(schema),
+            positions: selected.iter().map(|f| (f.offset, f.width)).collect(),
+            batch_size: batch_size.max(1),
+            position,
+            line: Vec::new(),
+            done: false,
+        }
+    }
+
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let mut builders: Vec<StringBuilder> = self
+            .positions
+            .iter()
+            .map(|_| StringBuilder::new())
+            .collect();
+        let mut rows = 0;
+        while rows < self.batch_size {
+            self.line.clear();
+            let read = self.source.read_until(b'\n', &mut self.line)?;
+            if read == 0 {
+                self.done = true;
+                break;
+            }
+            let line_position = self.position;
+            self.position += read as u64;
+            let line = trim_line_end(&self.line);
+            if line.is_empty() {
+                continue;
+            }
+            for (i, (&(offset, width), builder)) in
+                self.positions.iter().zip(&mut builders).enumerate()
+            {
+                let value = field_value(line, offset, width).map_err(|_| {
+                    ArrowError::ParseError(format!(
+                        "field '{}' of the line at byte {} is not valid UTF-8",
+                        self.schema.field(i).name(),
+                        line_position
+                    ))
+                })?;
+                builder.append_option(value);
+            }
+            rows += 1;
+        }
+        if rows == 0 {
+            return Ok(None);
+        }
+        let columns: Vec<ArrayRef> = builders
+            .iter_mut()
+            .map(|builder| Arc::new(builder.finish()) as ArrayRef)
+            .collect();
+        // The row count is kept when no requested column has a position
+        let options = RecordBatchOptions::new().with_row_count(Some(rows));
+        RecordBatch::try_new_with_options(self.schema.clone(), columns, &options).map(Some)
+    }
+}
+
+impl<R: BufRead> Iterator for FixedWidthDecoder<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let batch = self.next_batch();
+        if batch.is_err() {
+            self.done = true;
+        }
+        batch.transpose()
+    }
+}
+
+/// Strip the `\n` or `\r\n` ending a line
+fn trim_line_end(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+/// Value of the field at `offset`, trimmed of its padding. Blank fields and fields past
+/// the end of the line are null.
+fn field_value(
+    line: &[u8],
+    offset: usize,
+    width: usize,
+) -> Result<Option<&str>, std::str::Utf8Error> {
+    let start = offset.min(line.len());
+    let end = offset.saturating_add(width).min(line.len());
+    let value = std::str::from_utf8(&line[start..end])?.trim_matches(' ');
+    Ok((!value.is_empty()).then_some(value))
+}
+
+fn invalid_data(e: ArrowError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, AsArray};
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn fields() -> Vec<FixedWidthField> {
+        vec![
+            FixedWidthField::new("id".to_string(), 0, 4),
+            FixedWidthField::new("name".to_string(), 4, 8),
+            FixedWidthField::new("amount".to_string(), 12, 6),
+        ]
+    }
+
+    fn cols() -> Vec<String> {
+        vec![
+            "amount".to_string(),
+            "id".to_string(),
+            "missing".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_fixed_width_read_sequential() {
+        let mut file = NamedTempFile::new().unwrap();
+        write!(file, "0001Alice    12.50\r\n").unwrap();
+        writeln!(file, "0002Zoé         7").unwrap();
+        writeln!(file).unwrap();
+        // Short lines are padded
+        writeln!(file, "0003Bob").unwrap();
+        writeln!(file, "0004Eve       3").unwrap();
+        let batches = fixed_width_read_sequential(
+            file.path().to_str().unwrap(),
+            &fields(),
+            vec!["amount".to_string(), "id".to_string(), "name".to_string()],
+        )
+        .unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 4);
+        assert_eq!(batch.schema().field(0).name(), "amount");
+
+        let amounts = batch.column_by_name("amount").unwrap().as_string::<i32>();
+        assert_eq!(amounts.value(0), "12.50");
+        // "Zoé" takes 4 bytes, the amount field still starts at byte 12
+        assert_eq!(amounts.value(1), "7");
+        assert!(amounts.is_null(2));
+        assert_eq!(amounts.value(3), "3");
+        let names = batch.column_by_name("name").unwrap().as_string::<i32>();
+        assert_eq!(names.value(1), "Zoé");
+        assert_eq!(names.value(2), "Bob");
+    }
+
+    #[test]
+    fn test_fixed_width_read_parallel_matches_sequential() {
+        let mut file = NamedTempFile::new().unwrap();
+        for i in 0..5000 {
+            writeln!(file, "{:04}{:<8}{:>6}", i, format!("row{}", i), i).unwrap();
+        }
+        let path = file.path().to_str().unwrap();
+        let config = crate::readers::config::ReaderConfigBuilder::new()
+            .with_min_chunk_size(1024)
+            .with_max_chunk_size(4096)
+            .build();
+        let batches = fixed_width_read_parallel(path, &fields(), cols(), &config).unwrap();
+        assert!(batches.len() > 1);
+        let total: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(total, 5000);
+        assert_eq!(batches[0].num_columns(), 2);
+    }
+
+    #[test]
+    fn test_fixed_width_read_streaming() {
+        let mut file = NamedTempFile::new().unwrap();
+        for i in 0..10 {
+            writeln!(file, "{:04}user{:>10}", i, i).unwrap();
+        }
+        let config = crate::readers::config::ReaderConfigBuilder::new()
+            .with_batch_size(3)
+            .build();
+        let rx =
+            fixed_width_read_streaming(file.path().to_str().unwrap(), &fields(), cols(), &config)
+                .unwrap();
+        let batches: Vec<_> = rx.iter().map(|b| b.unwrap()).collect();
+        assert_eq!(batches.len(), 4);
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 10);
+    }
+
+    #[test]
+    fn test_fixed_width_cut_character() {
+        let mut file = NamedTempFile::new().unwrap();
+        // The name field ends in the middle of "é"
+        writeln!(file, "0001Zoé Zoé 1").unwrap();
+        let result = fixed_width_read_sequential(
+            file.path().to_str().unwrap(),
+            &fields(),
+            vec!["name".to_string()],
+        );
+        let error = result.unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("'name'"));
+    }
+}
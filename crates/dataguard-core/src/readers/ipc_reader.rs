@@ -0,0 +1,192 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::ipc::reader::{FileReader, StreamReader};
+use arrow::record_batch::RecordBatch;
+
+use crate::readers::source_reader::{spawn_stream, StreamFrom};
+
+/// Open an Arrow IPC file, only decoding the requested columns.
+fn open_ipc(path: &str, cols: &[String]) -> Result<FileReader<BufReader<File>>, io::Error> {
+    // The schema is stored in the footer, it is needed to locate the requested columns
+    let schema = FileReader::try_new(BufReader::new(File::open(path)?), None)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        .schema();
+    let indices = projection(&schema, cols);
+    FileReader::try_new(BufReader::new(File::open(path)?), Some(indices))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Positions of the requested columns found in the file schema, in request order.
+///
+/// Empty strings and columns absent from the file are dismissed, like for Parquet.
+fn projection(schema: &Schema, cols: &[String]) -> Vec<usize> {
+    cols.iter()
+        .filter_map(|c| schema.index_of(c).ok())
+        .collect()
+}
+
+/// Reads an Arrow IPC file (Feather v2) sequentially in a single thread.
+///
+/// # Arguments
+///
+/// * `path` - Path to the IPC file
+/// * `cols` - List of column names to read
+///
+/// # Returns
+///
+/// A vector of Arrow RecordBatches containing the requested columns, with the types
+/// stored in the file.
+///
+/// # Note
+///
+/// Only the requested columns are decoded. A requested column that is not present in
+/// the file is silently dismissed.
+pub fn read_ipc_sequential(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    open_ipc(path, &cols)?
+        .map(|batch| {
+            batch
+                .map(Arc::new)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect()
+}
+
+/// Reads an Arrow IPC file batch by batch on a background thread.
+///
+/// Only a few batches are buffered at a time, keeping memory bounded for large files.
+/// Decoding errors are sent through the channel and end the stream.
+pub fn read_ipc_streaming(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Receiver<Result<Arc<RecordBatch>, io::Error>>, io::Error> {
+    Ok(spawn_stream(open_ipc(path, &cols)?))
+}
+
+/// Reads the Arrow IPC stream format from a non-seekable source, see [`read_streaming_from`].
+///
+/// [`read_streaming_from`]: crate::readers::read_streaming_from
+pub(crate) fn ipc_stream_from<R: BufRead + Send + 'static>(
+    source: R,
+    cols: Vec<String>,
+) -> Result<StreamFrom, io::Error> {
+    let reader = StreamReader::try_new(source, None)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let schema = reader.schema();
+    let columns = schema.fields().iter().map(|f| f.name().clone()).collect();
+    let indices = projection(&schema, &cols);
+    let batches = reader.map(move |batch| batch.and_then(|b| b.project(&indices)));
+    Ok((columns, spawn_stream(batches)))
+}
+
+/// Returns the schema stored in the IPC file footer.
+pub fn ipc_schema(path: &str) -> Result<SchemaRef, io::Error> {
+    let reader = FileReader::try_new(BufReader::new(File::open(path)?), None)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(reader.schema())
+}
+
+/// Returns the top-level field names of the schema stored in the IPC file footer.
+pub fn ipc_column_names(path: &str) -> Result<Vec<String>, io::Error> {
+    Ok(ipc_schema(path)?
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field};
+    use arrow::ipc::writer::{FileWriter, StreamWriter};
+    use tempfile::NamedTempFile;
+
+    fn events() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("kind", DataType::Utf8, true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("click"), None, Some("view")])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn write_events() -> NamedTempFile {
+        let batch = events();
+        let file = NamedTempFile::new().unwrap();
+        let mut writer = FileWriter::try_new(file.reopen().unwrap(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        file
+    }
+
+    #[test]
+    fn test_ipc_sequential_projection() {
+        let file = write_events();
+        let path = file.path().to_str().unwrap();
+        let batches = read_ipc_sequential(
+            path,
+            vec!["kind".to_string(), "missing".to_string(), "".to_string()],
+        )
+        .unwrap();
+
+        let total_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(total_rows, 3);
+        assert_eq!(batches[0].num_columns(), 1, "Should only read 'kind'");
+        let kind = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(kind.value(0), "click");
+        assert!(kind.is_null(1));
+    }
+
+    #[test]
+    fn test_ipc_streaming_matches_sequential() {
+        let file = write_events();
+        let path = file.path().to_str().unwrap();
+        let cols = vec!["id".to_string(), "kind".to_string()];
+        let sequential = read_ipc_sequential(path, cols.clone()).unwrap();
+        let streamed: Vec<_> = read_ipc_streaming(path, cols)
+            .unwrap()
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(sequential, streamed);
+        assert_eq!(ipc_column_names(path).unwrap(), vec!["id", "kind"]);
+    }
+
+    #[test]
+    fn test_ipc_from_source() {
+        let batch = events();
+        let mut buffer = Vec::new();
+        let mut writer = StreamWriter::try_new(&mut buffer, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+
+        let (columns, batches) = crate::readers::read_sequential_from(
+            io::Cursor::new(buffer),
+            crate::readers::FileFormat::Arrow,
+            vec!["kind".to_string()],
+            &crate::readers::ReaderConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(columns, vec!["id", "kind"]);
+        assert_eq!(batches[0].num_columns(), 1);
+        assert_eq!(batches[0].num_rows(), 3);
+    }
+}
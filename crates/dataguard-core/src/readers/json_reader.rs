@@ -0,0 +1,265 @@
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::json::reader::infer_json_schema;
+use arrow::json::ReaderBuilder;
+use arrow::record_batch::RecordBatch;
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+
+use crate::readers::config::{calculate_chunk_size, ReaderConfig};
+use crate::readers::csv_reader::create_chunks;
+use crate::readers::source_reader::{spawn_stream, StreamFrom};
+use crate::readers::BATCH_SIZE;
+
+// Number of records used to discover the keys of the file
+const SCHEMA_INFERENCE_RECORDS: usize = 1000;
+
+/// Reads a newline-delimited JSON file sequentially in a single thread.
+///
+/// # Arguments
+///
+/// * `path` - Path to the NDJSON file
+/// * `cols` - List of column names to read
+///
+/// # Returns
+///
+/// A vector of Arrow RecordBatches containing the requested columns, all typed as Utf8.
+///
+/// # Note
+///
+/// Keys are discovered from the first records of the file. A requested column that is
+/// not found there is silently dismissed, like for CSV.
+pub fn json_read_sequential(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let schema = Arc::new(json_generate_schema(path, &cols)?);
+    let file = BufReader::new(File::open(path)?);
+    let reader = ReaderBuilder::new(schema)
+        .with_batch_size(BATCH_SIZE)
+        .with_coerce_primitive(true)
+        .build(file)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let mut batches = Vec::new();
+    for batch_result in reader {
+        let batch = batch_result.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        batches.push(Arc::new(batch));
+    }
+    Ok(batches)
+}
+
+/// Reads a newline-delimited JSON file in parallel using multiple threads.
+///
+/// The file is split on newline boundaries, each chunk being decoded independently.
+///
+/// # Arguments
+///
+/// * `path`   - Path to the NDJSON file
+/// * `cols`   - List of column names to read
+/// * `config` - A [`ReaderConfig`]
+///
+/// # Returns
+///
+/// A vector of Arrow RecordBatches containing the requested columns, all typed as Utf8.
+pub fn json_read_parallel(
+    path: &str,
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let schema = Arc::new(json_generate_schema(path, &cols)?);
+    let file_size = File::open(path)?.metadata()?.len();
+
+    let num_threads = rayon::current_num_threads();
+    let chunk_size = calculate_chunk_size(file_size, 0, num_threads, config);
+    let chunks = create_chunks(path, 0, file_size, chunk_size)?;
+
+    let batches: Result<Vec<_>, _> = chunks
+        .into_par_iter()
+        .map(|(start, end)| parse_json_chunk(path, &schema, config.batch_size as usize, start, end))
+        .collect();
+
+    Ok(batches?.into_iter().flatten().collect())
+}
+
+/// Reads a newline-delimited JSON file batch by batch on a background thread.
+///
+/// Only a few batches are buffered at a time, keeping memory bounded for large files.
+/// Decoding errors are sent through the channel and end the stream.
+pub fn json_read_streaming(
+    path: &str,
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<Receiver<Result<Arc<RecordBatch>, io::Error>>, io::Error> {
+    let schema = Arc::new(json_generate_schema(path, &cols)?);
+    let file = BufReader::new(File::open(path)?);
+    let reader = ReaderBuilder::new(schema)
+        .with_batch_size(config.batch_size as usize)
+        .with_coerce_primitive(true)
+        .build(file)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(spawn_stream(reader))
+}
+
+fn parse_json_chunk(
+    path: &str,
+    schema: &Arc<Schema>,
+    batch_size: usize,
+    start: u64,
+    end: u64,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(start))?;
+
+    let mut buffer = Vec::with_capacity((end - start) as usize);
+    file.take(end - start).read_to_end(&mut buffer)?;
+
+    let reader = ReaderBuilder::new(schema.clone())
+        .with_batch_size(batch_size)
+        .with_coerce_primitive(true)
+        .build(io::Cursor::new(buffer))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let mut batches = Vec::new();
+    for batch_result in reader {
+        let batch = batch_result.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        batches.push(Arc::new(batch));
+    }
+    Ok(batches)
+}
+
+/// Returns the keys found in the first records of a newline-delimited JSON file.
+pub fn json_column_names(path: &str) -> Result<Vec<String>, io::Error> {
+    let file = BufReader::new(File::open(path)?);
+    let (inferred, _) = infer_json_schema(file, Some(SCHEMA_INFERENCE_RECORDS))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(inferred.fields().iter().map(|f| f.name().clone()).collect())
+}
+
+/// Reads NDJSON batches from a non-seekable source, see [`read_streaming_from`].
+///
+/// The records used to discover the keys are consumed from the source, then replayed
+/// in front of the rest of it.
+///
+/// [`read_streaming_from`]: crate::readers::read_streaming_from
+pub(crate) fn json_stream_from<R: BufRead + Send + 'static>(
+    mut source: R,
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<StreamFrom, io::Error> {
+    let mut head = Vec::new();
+    for _ in 0..SCHEMA_INFERENCE_RECORDS {
+        if source.read_until(b'\n', &mut head)? == 0 {
+            break;
+        }
+    }
+    let inferred = infer_schema(head.as_slice())?;
+    let columns = inferred.fields().iter().map(|f| f.name().clone()).collect();
+    let reader = ReaderBuilder::new(Arc::new(utf_schema(&inferred, &cols)))
+        .with_batch_size(config.batch_size as usize)
+        .with_coerce_primitive(true)
+        .build(io::Cursor::new(head).chain(source))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok((columns, spawn_stream(reader)))
+}
+
+/// Build a Utf8 schema holding the requested columns found in the file, in request order.
+fn json_generate_schema(path: &str, cols: &[String]) -> Result<Schema, io::Error> {
+    let inferred = infer_schema(BufReader::new(File::open(path)?))?;
+    Ok(utf_schema(&inferred, cols))
+}
+
+/// Discover the keys of the first records, failing when there is none.
+fn infer_schema(head: impl BufRead) -> Result<Schema, io::Error> {
+    let (inferred, records) = infer_json_schema(head, Some(SCHEMA_INFERENCE_RECORDS))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    if records == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "JSON file is empty",
+        ));
+    }
+    Ok(inferred)
+}
+
+fn utf_schema(inferred: &Schema, cols: &[String]) -> Schema {
+    let fields: Vec<Field> = cols
+        .iter()
+        .filter(|c| inferred.field_with_name(c).is_ok())
+        .map(|c| Field::new(c.as_str(), DataType::Utf8, true))
+        .collect();
+    Schema::new(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::AsArray;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn ndjson_file() -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"name": "Alice", "age": 30, "city": "Paris"}}"#).unwrap();
+        writeln!(file, r#"{{"name": "Bob", "age": 25.5}}"#).unwrap();
+        writeln!(file).unwrap();
+        writeln!(file, r#"{{"name": null, "age": 41, "active": true}}"#).unwrap();
+        file
+    }
+
+    fn cols() -> Vec<String> {
+        vec!["name".to_string(), "age".to_string(), "missing".to_string()]
+    }
+
+    #[test]
+    fn test_json_read_sequential() {
+        let file = ndjson_file();
+        let batches = json_read_sequential(file.path().to_str().unwrap(), cols()).unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.num_columns(), 2);
+
+        let ages = batch.column_by_name("age").unwrap().as_string::<i32>();
+        assert_eq!(ages.value(0), "30");
+        assert_eq!(ages.value(1), "25.5");
+        assert_eq!(batch.column_by_name("name").unwrap().null_count(), 1);
+    }
+
+    #[test]
+    fn test_json_read_parallel_matches_sequential() {
+        let mut file = NamedTempFile::new().unwrap();
+        for i in 0..5000 {
+            writeln!(file, r#"{{"id": {}, "label": "row-{}"}}"#, i, i).unwrap();
+        }
+        let path = file.path().to_str().unwrap();
+        let config = crate::readers::config::ReaderConfigBuilder::new()
+            .with_min_chunk_size(1024)
+            .with_max_chunk_size(4096)
+            .build();
+        let batches =
+            json_read_parallel(path, vec!["id".to_string(), "label".to_string()], &config).unwrap();
+        assert!(batches.len() > 1);
+        let total: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(total, 5000);
+    }
+
+    #[test]
+    fn test_json_read_streaming() {
+        let file = ndjson_file();
+        let config = ReaderConfig::default();
+        let rx = json_read_streaming(file.path().to_str().unwrap(), cols(), &config).unwrap();
+        let total: usize = rx.iter().map(|b| b.unwrap().num_rows()).sum();
+        assert_eq!(total, 3);
+    }
+
+    #[test]
+    fn test_json_read_empty_file() {
+        let file = NamedTempFile::new().unwrap();
+        let result = json_read_sequential(file.path().to_str().unwrap(), cols());
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+}
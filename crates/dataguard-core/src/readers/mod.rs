@@ -1,9 +1,42 @@
+pub mod avro_reader;
 mod config;
 pub mod csv_reader;
+pub mod fixed_width_reader;
+pub mod ipc_reader;
+pub mod json_reader;
+#[cfg(feature = "object-store")]
+pub mod object_store_reader;
+pub mod orc_reader;
 pub mod parquet_reader;
+pub mod reader;
+pub mod source_reader;
 
-pub use config::ReaderConfig;
-pub use parquet_reader::read_parquet_parallel;
+pub use avro_reader::{avro_column_names, avro_schema, read_avro_sequential, read_avro_streaming};
+pub use config::{ReaderConfig, ReaderConfigBuilder};
+pub use csv_reader::csv_column_names;
+pub use fixed_width_reader::{
+    fixed_width_read_parallel, fixed_width_read_sequential, fixed_width_read_streaming,
+    FixedWidthField,
+};
+pub use ipc_reader::{ipc_column_names, ipc_schema, read_ipc_sequential, read_ipc_streaming};
+pub use json_reader::{
+    json_column_names, json_read_parallel, json_read_sequential, json_read_streaming,
+};
+#[cfg(feature = "object-store")]
+pub use object_store_reader::{download_object, is_object_store_url};
+pub use orc_reader::{
+    orc_column_names, orc_schema, read_orc_parallel, read_orc_sequential, read_orc_streaming,
+};
 pub use parquet_reader::read_parquet_sequential;
+pub use parquet_reader::{
+    parquet_column_names, parquet_schema, read_parquet_parallel, read_parquet_streaming,
+};
+pub use reader::{
+    is_gzip, read_first_rows, sniff_format, verify_format, FileFormat, ReadMode,
+    DEFAULT_MEMORY_BUDGET,
+};
+pub use source_reader::{read_sequential_from, read_streaming_from, STDIN_PATH};
 
 const BATCH_SIZE: usize = 256_000;
+// Number of batches buffered ahead of the consumer in streaming mode
+const STREAMING_BUFFER: usize = 4;
@@ -0,0 +1,139 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use object_store::aws::AmazonS3Builder;
+use object_store::gcp::GoogleCloudStorageBuilder;
+use object_store::path::Path as ObjectPath;
+use object_store::ObjectStore;
+
+/// URL schemes read through an object store
+pub const OBJECT_STORE_SCHEMES: [&str; 2] = ["s3://", "gs://"];
+
+/// Whether a table path points to an object store rather than a local file.
+pub fn is_object_store_url(path: &str) -> bool {
+    OBJECT_STORE_SCHEMES
+        .iter()
+        .any(|scheme| path.starts_with(scheme))
+}
+
+/// Downloads an object to a local file.
+///
+/// # Arguments
+///
+/// * `url`  - Location of the object, `s3://bucket/key` or `gs://bucket/key`
+/// * `dest` - Local file, created or truncated
+///
+/// # Note
+///
+/// Credentials, region and endpoint are read from the environment: the `AWS_*`
+/// variables for S3 and the `GOOGLE_*` ones for Google Cloud Storage. The object is
+/// streamed to `dest` chunk by chunk and never held whole in memory.
+pub fn download_object(url: &str, dest: &Path) -> Result<(), io::Error> {
+    let (store, location) = open_store(url)?;
+    let mut file = BufWriter::new(File::create(dest)?);
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?;
+    runtime.block_on(copy_object(store.as_ref(), &location, &mut file))?;
+    file.flush()
+}
+
+/// Store holding the object of `url`, along with the location of the object in it.
+fn open_store(url: &str) -> Result<(Arc<dyn ObjectStore>, ObjectPath), io::Error> {
+    let key = OBJECT_STORE_SCHEMES
+        .iter()
+        .find_map(|scheme| url.strip_prefix(scheme))
+        .and_then(|rest| rest.split_once('/'))
+        .map(|(_, key)| key)
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "expected 's3://bucket/key' or 'gs://bucket/key', got '{}'",
+                    url
+                ),
+            )
+        })?;
+    let location = ObjectPath::parse(key).map_err(io::Error::other)?;
+    let store: Arc<dyn ObjectStore> = if url.starts_with("s3://") {
+        Arc::new(
+            AmazonS3Builder::from_env()
+                .with_url(url)
+                .build()
+                .map_err(io::Error::other)?,
+        )
+    } else {
+        Arc::new(
+            GoogleCloudStorageBuilder::from_env()
+                .with_url(url)
+                .build()
+                .map_err(io::Error::other)?,
+        )
+    };
+    Ok((store, location))
+}
+
+async fn copy_object<W: Write>(
+    store: &dyn ObjectStore,
+    location: &ObjectPath,
+    dest: &mut W,
+) -> Result<(), io::Error> {
+    let mut chunks = store
+        .get(location)
+        .await
+        .map_err(io::Error::other)?
+        .into_stream();
+    while let Some(chunk) = chunks.next().await {
+        dest.write_all(&chunk.map_err(io::Error::other)?)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object_store::memory::InMemory;
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn test_is_object_store_url() {
+        assert!(is_object_store_url("s3://bucket/data.parquet"));
+        assert!(is_object_store_url("gs://bucket/dir/data.csv"));
+        assert!(!is_object_store_url("data/s3://file.csv"));
+        assert!(!is_object_store_url("https://example.com/data.csv"));
+        assert!(!is_object_store_url("data.csv"));
+    }
+
+    #[test]
+    fn test_open_store_requires_a_key() {
+        for url in ["s3://bucket", "s3://bucket/", "gs://", "data.csv"] {
+            let err = open_store(url).err().unwrap();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_copy_object() {
+        let store = InMemory::new();
+        let location = ObjectPath::parse("dir/data.csv").unwrap();
+        let content = "id,name\n1,a\n2,b\n";
+        block_on(store.put(&location, content.into())).unwrap();
+
+        let mut dest = Vec::new();
+        block_on(copy_object(&store, &location, &mut dest)).unwrap();
+        assert_eq!(dest, content.as_bytes());
+
+        let missing = ObjectPath::parse("dir/missing.csv").unwrap();
+        assert!(block_on(copy_object(&store, &missing, &mut Vec::new())).is_err());
+    }
+}
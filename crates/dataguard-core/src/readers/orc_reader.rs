@@ -0,0 +1,244 @@
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use orc_rust::projection::ProjectionMask;
+use orc_rust::ArrowReaderBuilder;
+use rayon::prelude::*;
+
+use crate::readers::source_reader::spawn_stream;
+use crate::readers::BATCH_SIZE;
+
+/// Open an ORC file, only decoding the requested columns.
+///
+/// Columns absent from the file and empty strings are dismissed, like for Parquet.
+fn open_orc(path: &str, cols: &[String]) -> Result<ArrowReaderBuilder<File>, io::Error> {
+    let builder = ArrowReaderBuilder::try_new(File::open(path)?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let projection = ProjectionMask::named_roots(builder.file_metadata().root_data_type(), cols);
+    Ok(builder
+        .with_projection(projection)
+        .with_batch_size(BATCH_SIZE))
+}
+
+/// Decode the batches of an ORC reader, optionally restricted to the stripes starting
+/// within `stripes`.
+fn read_orc(
+    path: &str,
+    cols: &[String],
+    stripes: Option<Range<usize>>,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let mut builder = open_orc(path, cols)?;
+    if let Some(stripes) = stripes {
+        builder = builder.with_file_byte_range(stripes);
+    }
+    builder
+        .build()
+        .map(|batch| {
+            batch
+                .map(Arc::new)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect()
+}
+
+/// Reads an ORC file sequentially in a single thread.
+///
+/// # Arguments
+///
+/// * `path` - Path to the ORC file
+/// * `cols` - List of column names to read
+///
+/// # Returns
+///
+/// A vector of Arrow RecordBatches containing the requested columns, with the types
+/// stored in the file.
+///
+/// # Note
+///
+/// Only the requested columns are decoded. A requested column that is not present in
+/// the file is silently dismissed.
+pub fn read_orc_sequential(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    read_orc(path, &cols, None)
+}
+
+/// Reads an ORC file in parallel by decoding its stripes concurrently.
+///
+/// # Arguments
+///
+/// * `path` - Path to the ORC file
+/// * `cols` - List of column names to read
+///
+/// # Returns
+///
+/// A vector of Arrow RecordBatches containing the requested columns, in file order.
+///
+/// # Note
+///
+/// A requested column that is not present in the file is silently dismissed. Each
+/// stripe is read by its own file handle, a file holding a single stripe is read by
+/// one thread.
+pub fn read_orc_parallel(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Vec<Arc<RecordBatch>>, io::Error> {
+    let stripe_offsets: Vec<usize> = open_orc(path, &cols)?
+        .file_metadata()
+        .stripe_metadatas()
+        .iter()
+        .map(|stripe| stripe.offset() as usize)
+        .collect();
+
+    // A range holding only the first byte of a stripe selects that stripe
+    let batches: Result<Vec<_>, _> = stripe_offsets
+        .into_par_iter()
+        .map(|offset| read_orc(path, &cols, Some(offset..offset + 1)))
+        .collect();
+
+    Ok(batches?.into_iter().flatten().collect())
+}
+
+/// Reads an ORC file batch by batch on a background thread.
+///
+/// Only a few batches are buffered at a time, keeping memory bounded for large files.
+/// Decoding errors are sent through the channel and end the stream.
+pub fn read_orc_streaming(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Receiver<Result<Arc<RecordBatch>, io::Error>>, io::Error> {
+    Ok(spawn_stream(open_orc(path, &cols)?.build()))
+}
+
+/// Returns the Arrow schema derived from the ORC file footer.
+pub fn orc_schema(path: &str) -> Result<SchemaRef, io::Error> {
+    let builder = ArrowReaderBuilder::try_new(File::open(path)?)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(builder.schema())
+}
+
+/// Returns the top-level column names stored in the ORC file footer.
+pub fn orc_column_names(path: &str) -> Result<Vec<String>, io::Error> {
+    Ok(orc_schema(path)?
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use orc_rust::ArrowWriterBuilder;
+    use tempfile::NamedTempFile;
+
+    /// Write `stripes` stripes of 3 events each, ids follow each other across stripes
+    fn write_events(stripes: i32) -> NamedTempFile {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("kind", DataType::Utf8, true),
+        ]));
+        let file = NamedTempFile::new().unwrap();
+        let mut writer = ArrowWriterBuilder::new(file.reopen().unwrap(), schema.clone())
+            .try_build()
+            .unwrap();
+        for stripe in 0..stripes {
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int32Array::from_iter_values(stripe * 3..stripe * 3 + 3)),
+                    Arc::new(StringArray::from(vec![Some("click"), None, Some("view")])),
+                ],
+            )
+            .unwrap();
+            writer.write(&batch).unwrap();
+            writer.flush_stripe().unwrap();
+        }
+        writer.close().unwrap();
+        file
+    }
+
+    fn ids(batches: &[Arc<RecordBatch>]) -> Vec<i32> {
+        batches
+            .iter()
+            .flat_map(|b| {
+                let ids = b.column_by_name("id").unwrap();
+                let ids = ids.as_any().downcast_ref::<Int32Array>().unwrap();
+                ids.values().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_orc_sequential_projection() {
+        let file = write_events(1);
+        let path = file.path().to_str().unwrap();
+        let batches = read_orc_sequential(
+            path,
+            vec!["kind".to_string(), "missing".to_string(), "".to_string()],
+        )
+        .unwrap();
+
+        let total_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(total_rows, 3);
+        assert_eq!(batches[0].num_columns(), 1, "Should only read 'kind'");
+        let kind = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(kind.value(0), "click");
+        assert!(kind.is_null(1));
+    }
+
+    #[test]
+    fn test_orc_parallel_reads_every_stripe_in_order() {
+        let file = write_events(4);
+        let path = file.path().to_str().unwrap();
+        let cols = vec!["id".to_string(), "kind".to_string()];
+
+        let sequential = read_orc_sequential(path, cols.clone()).unwrap();
+        let parallel = read_orc_parallel(path, cols).unwrap();
+        assert_eq!(ids(&parallel), (0..12).collect::<Vec<_>>());
+        assert_eq!(ids(&parallel), ids(&sequential));
+        assert_eq!(parallel[0].num_columns(), 2);
+    }
+
+    #[test]
+    fn test_orc_streaming() {
+        let file = write_events(2);
+        let path = file.path().to_str().unwrap();
+        let batches: Vec<_> = read_orc_streaming(path, vec!["id".to_string()])
+            .unwrap()
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(ids(&batches), (0..6).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_orc_schema() {
+        let file = write_events(1);
+        let path = file.path().to_str().unwrap();
+        let schema = orc_schema(path).unwrap();
+        assert_eq!(schema.field(0).data_type(), &DataType::Int32);
+        assert_eq!(schema.field(1).data_type(), &DataType::Utf8);
+        assert_eq!(orc_column_names(path).unwrap(), vec!["id", "kind"]);
+    }
+
+    #[test]
+    fn test_orc_not_an_orc_file() {
+        let file = NamedTempFile::new().unwrap();
+        std::fs::write(file.path(), "id,kind\n1,click\n").unwrap();
+        let err = read_orc_sequential(file.path().to_str().unwrap(), vec![]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -1,12 +1,14 @@
-use std::{fs::File, sync::Arc};
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::{fs::File, io, sync::Arc, thread};
 
+use arrow::datatypes::SchemaRef;
 use arrow_array::RecordBatch;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::arrow::ProjectionMask;
 use parquet::schema::types::SchemaDescriptor;
 use rayon::prelude::*;
 
-use crate::readers::BATCH_SIZE;
+use crate::readers::{BATCH_SIZE, STREAMING_BUFFER};
 
 /// Create a projection mask from column names.
 ///
@@ -158,6 +160,58 @@ pub fn read_parquet_parallel(
     Ok(batches?.into_iter().flatten().collect())
 }
 
+/// Reads a Parquet file batch by batch on a background thread.
+///
+/// Only a few batches are buffered at a time, keeping memory bounded for large files.
+/// Decoding errors are sent through the channel and end the stream.
+pub fn read_parquet_streaming(
+    path: &str,
+    cols: Vec<String>,
+) -> Result<Receiver<Result<Arc<RecordBatch>, io::Error>>, io::Error> {
+    let cols: Vec<&str> = cols.iter().map(|v| v.as_str()).collect();
+    let file = File::open(path)?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let projection = create_projection_mask(builder.parquet_schema(), cols.as_slice());
+    let reader = builder
+        .with_projection(projection)
+        .with_batch_size(BATCH_SIZE)
+        .build()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let (tx, rx) = sync_channel(STREAMING_BUFFER);
+    thread::spawn(move || {
+        for batch_result in reader {
+            let batch = batch_result
+                .map(Arc::new)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+            let failed = batch.is_err();
+            // The receiver was dropped, nobody is listening anymore
+            if tx.send(batch).is_err() || failed {
+                break;
+            }
+        }
+    });
+    Ok(rx)
+}
+
+/// Returns the Arrow schema derived from the Parquet file metadata.
+pub fn parquet_schema(path: &str) -> Result<SchemaRef, std::io::Error> {
+    let file = File::open(path)?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    Ok(builder.schema().clone())
+}
+
+/// Returns the top-level column names stored in the Parquet file metadata.
+pub fn parquet_column_names(path: &str) -> Result<Vec<String>, std::io::Error> {
+    Ok(parquet_schema(path)?
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
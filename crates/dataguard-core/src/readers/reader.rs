@@ -0,0 +1,521 @@
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{self, Read},
+    path::Path,
+    sync::{mpsc::Receiver, Arc},
+};
+
+use arrow_array::RecordBatch;
+use flate2::read::MultiGzDecoder;
+
+use crate::RuleError;
+
+const PARQUET_MAGIC: &[u8; 4] = b"PAR1";
+const AVRO_MAGIC: &[u8; 4] = b"Obj\x01";
+const ARROW_MAGIC: &[u8; 6] = b"ARROW1";
+const ORC_MAGIC: &[u8; 3] = b"ORC";
+const GZIP_MAGIC: &[u8; 2] = b"\x1f\x8b";
+// Number of bytes read from the head of a file to guess its content
+const SNIFF_SIZE: usize = 1024;
+
+/// File formats supported by the readers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    Csv,
+    Parquet,
+    /// Newline-delimited JSON
+    Json,
+    /// Avro object container file
+    Avro,
+    /// Arrow IPC file, also known as Feather v2. The standard input is read as an IPC stream
+    Arrow,
+    /// Fixed-width text file without delimiter nor header, columns are sliced from each
+    /// line at their configured position
+    FixedWidth,
+    /// Optimized Row Columnar file, its stripes are read in parallel like Parquet row groups
+    Orc,
+}
+
+impl FileFormat {
+    /// Every supported format, in the order they are listed to users
+    pub const ALL: [FileFormat; 7] = [
+        Self::Csv,
+        Self::Parquet,
+        Self::Json,
+        Self::Avro,
+        Self::Arrow,
+        Self::FixedWidth,
+        Self::Orc,
+    ];
+
+    /// Detect the format from the file extension.
+    ///
+    /// Gzip compressed files are only supported for CSV, `.csv.gz` and bare `.gz`
+    /// files are both detected as CSV.
+    pub fn from_path(path: &str) -> Option<Self> {
+        let path = Path::new(path);
+        let extension = path.extension()?.to_str()?;
+        if extension.eq_ignore_ascii_case("gz") {
+            let inner = path.file_stem().map(Path::new).and_then(Path::extension);
+            return match inner.and_then(|e| e.to_str()) {
+                Some(e) if !e.eq_ignore_ascii_case("csv") => None,
+                _ => Some(Self::Csv),
+            };
+        }
+        match extension.to_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "parquet" => Some(Self::Parquet),
+            "ndjson" | "jsonl" => Some(Self::Json),
+            "avro" => Some(Self::Avro),
+            "arrow" | "feather" | "ipc" => Some(Self::Arrow),
+            "fwf" => Some(Self::FixedWidth),
+            "orc" => Some(Self::Orc),
+            _ => None,
+        }
+    }
+
+    /// Parse a format name as written in a configuration file.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "parquet" => Some(Self::Parquet),
+            "json" | "ndjson" | "jsonl" => Some(Self::Json),
+            "avro" => Some(Self::Avro),
+            "arrow" | "feather" | "ipc" => Some(Self::Arrow),
+            "fixed_width" | "fixed-width" | "fwf" => Some(Self::FixedWidth),
+            "orc" => Some(Self::Orc),
+            _ => None,
+        }
+    }
+
+    /// Name of the format in a configuration file, parsed back by `from_name`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+            Self::Json => "json",
+            Self::Avro => "avro",
+            Self::Arrow => "arrow",
+            Self::FixedWidth => "fixed_width",
+            Self::Orc => "orc",
+        }
+    }
+}
+
+impl fmt::Display for FileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileFormat::Csv => write!(f, "CSV"),
+            FileFormat::Parquet => write!(f, "Parquet"),
+            FileFormat::Json => write!(f, "JSON"),
+            FileFormat::Avro => write!(f, "Avro"),
+            FileFormat::Arrow => write!(f, "Arrow IPC"),
+            FileFormat::FixedWidth => write!(f, "fixed-width"),
+            FileFormat::Orc => write!(f, "ORC"),
+        }
+    }
+}
+
+/// Files larger than this are streamed by `ReadMode::Auto`
+pub const DEFAULT_MEMORY_BUDGET: u64 = 500 * 1024 * 1024;
+
+/// How a table reads its file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReadMode {
+    /// Stream files larger than the memory budget, read the others in parallel.
+    #[default]
+    Auto,
+    /// Load the whole file in memory, split between threads when the format allows it.
+    Parallel,
+    /// Validate batches as they are read, memory stays bounded whatever the file size.
+    Streaming,
+    /// Load the whole file in memory from a single thread.
+    Sequential,
+}
+
+impl ReadMode {
+    /// Resolve `Auto` from the size of the file on disk, compressed files are not inflated.
+    ///
+    /// Files that can not be inspected are read in parallel, the reader will surface the error.
+    pub fn resolve(self, path: &str, memory_budget: u64) -> ReadMode {
+        match self {
+            ReadMode::Auto => match fs::metadata(path) {
+                Ok(metadata) if metadata.len() > memory_budget => ReadMode::Streaming,
+                _ => ReadMode::Parallel,
+            },
+            mode => mode,
+        }
+    }
+}
+
+impl fmt::Display for ReadMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadMode::Auto => write!(f, "auto"),
+            ReadMode::Parallel => write!(f, "parallel"),
+            ReadMode::Streaming => write!(f, "streaming"),
+            ReadMode::Sequential => write!(f, "sequential"),
+        }
+    }
+}
+
+/// Detect the format from the first bytes of the file.
+///
+/// Parquet files start with the `PAR1` magic, Avro container files with `Obj\x01`, Arrow
+/// IPC files with `ARROW1` and ORC files with `ORC` followed by binary content. Plain text
+/// starting with a `{` is considered NDJSON and any other plain text CSV.
+///
+/// Gzip compressed files are sniffed on their decompressed content. Returns `None` when the
+/// file can not be read or the content is unknown.
+pub fn sniff_format(path: &str) -> Option<FileFormat> {
+    let file = File::open(path).ok()?;
+    let mut buffer = [0u8; SNIFF_SIZE];
+    let read = if is_gzip(path) {
+        fill_buffer(MultiGzDecoder::new(file), &mut buffer)?
+    } else {
+        fill_buffer(file, &mut buffer)?
+    };
+    let head = &buffer[..read];
+
+    if head.starts_with(PARQUET_MAGIC) {
+        return Some(FileFormat::Parquet);
+    }
+    if head.starts_with(AVRO_MAGIC) {
+        return Some(FileFormat::Avro);
+    }
+    if head.starts_with(ARROW_MAGIC) {
+        return Some(FileFormat::Arrow);
+    }
+    // A CSV header may start with the same letters
+    if head.starts_with(ORC_MAGIC) && !is_text(head) {
+        return Some(FileFormat::Orc);
+    }
+    if !head.is_empty() && is_text(head) {
+        if head.trim_ascii_start().starts_with(b"{") {
+            return Some(FileFormat::Json);
+        }
+        return Some(FileFormat::Csv);
+    }
+    None
+}
+
+/// Ensure that the content of the file matches the declared format.
+///
+/// Files whose content can not be recognized are let through, the reader will
+/// surface its own error if they are invalid. Fixed-width files are plain text, they are
+/// sniffed as CSV.
+pub fn verify_format(path: &str, declared: FileFormat) -> Result<(), RuleError> {
+    match sniff_format(path) {
+        Some(FileFormat::Csv) if declared == FileFormat::FixedWidth => Ok(()),
+        Some(detected) if detected != declared => Err(RuleError::FormatMismatch(
+            path.to_string(),
+            declared.to_string(),
+            detected.to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Returns true when the file starts with the gzip magic bytes.
+pub fn is_gzip(path: &str) -> bool {
+    let mut magic = [0u8; 2];
+    File::open(path)
+        .and_then(|mut file| file.read_exact(&mut magic))
+        .is_ok()
+        && &magic == GZIP_MAGIC
+}
+
+/// Pull batches from a streaming reader until `limit` rows are read.
+///
+/// The last batch is cut to the limit. The receiver is dropped on return, which
+/// stops the reading thread instead of decoding the rest of the file.
+///
+/// Returns the batches read along with whether rows were left unread. When the limit
+/// falls on a batch boundary, the next batch is pulled to know whether the stream ends
+/// there, a stream failing past the limit is considered to have rows left.
+pub fn read_first_rows(
+    batches: Receiver<Result<Arc<RecordBatch>, io::Error>>,
+    limit: usize,
+) -> Result<(Vec<Arc<RecordBatch>>, bool), io::Error> {
+    let mut rows = 0;
+    let mut head = Vec::new();
+    let mut rows_left = false;
+    while rows < limit {
+        // The reading thread closes the channel at the end of the file
+        let Ok(batch) = batches.recv() else {
+            break;
+        };
+        let batch = batch?;
+        let take = batch.num_rows().min(limit - rows);
+        rows += take;
+        if take < batch.num_rows() {
+            head.push(Arc::new(batch.slice(0, take)));
+            rows_left = true;
+        } else {
+            head.push(batch);
+        }
+    }
+    if rows == limit && !rows_left {
+        rows_left = batches
+            .iter()
+            .any(|batch| !matches!(batch, Ok(b) if b.num_rows() == 0));
+    }
+    Ok((head, rows_left))
+}
+
+/// Read up to `buffer.len()` bytes, returns the number of bytes read.
+fn fill_buffer(mut reader: impl Read, buffer: &mut [u8]) -> Option<usize> {
+    let mut read = 0;
+    // A single read is not guaranteed to fill the buffer
+    while read < buffer.len() {
+        match reader.read(&mut buffer[read..]) {
+            Ok(0) => break,
+            Ok(n) => read += n,
+            Err(_) => return None,
+        }
+    }
+    Some(read)
+}
+
+fn is_text(head: &[u8]) -> bool {
+    if head.contains(&0) {
+        return false;
+    }
+    match std::str::from_utf8(head) {
+        Ok(_) => true,
+        // The buffer may cut a multi bytes character at its end
+        Err(e) => e.error_len().is_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(FileFormat::from_path("data.csv"), Some(FileFormat::Csv));
+        assert_eq!(
+            FileFormat::from_path("./dir/DATA.CSV"),
+            Some(FileFormat::Csv)
+        );
+        assert_eq!(
+            FileFormat::from_path("../data.v2.parquet"),
+            Some(FileFormat::Parquet)
+        );
+        assert_eq!(
+            FileFormat::from_path("events.jsonl"),
+            Some(FileFormat::Json)
+        );
+        assert_eq!(
+            FileFormat::from_path("events.NDJSON"),
+            Some(FileFormat::Json)
+        );
+        assert_eq!(
+            FileFormat::from_path("archive/data.csv.gz"),
+            Some(FileFormat::Csv)
+        );
+        assert_eq!(FileFormat::from_path("export.GZ"), Some(FileFormat::Csv));
+        assert_eq!(
+            FileFormat::from_path("events/2024-01-31.avro"),
+            Some(FileFormat::Avro)
+        );
+        assert_eq!(
+            FileFormat::from_path("staging/events.feather"),
+            Some(FileFormat::Arrow)
+        );
+        assert_eq!(
+            FileFormat::from_path("staging/events.arrow"),
+            Some(FileFormat::Arrow)
+        );
+        assert_eq!(
+            FileFormat::from_path("warehouse/part-0.orc"),
+            Some(FileFormat::Orc)
+        );
+        assert_eq!(
+            FileFormat::from_path("legacy/accounts.fwf"),
+            Some(FileFormat::FixedWidth)
+        );
+        assert_eq!(FileFormat::from_path("data.parquet.gz"), None);
+        assert_eq!(FileFormat::from_path("data.txt"), None);
+        assert_eq!(FileFormat::from_path("data"), None);
+    }
+
+    #[test]
+    fn test_name_round_trip() {
+        for format in FileFormat::ALL {
+            assert_eq!(FileFormat::from_name(format.name()), Some(format));
+        }
+    }
+
+    #[test]
+    fn test_read_mode_resolve() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "id\n1\n2").unwrap();
+        let path = file.path().to_str().unwrap();
+        assert_eq!(ReadMode::Auto.resolve(path, 1024), ReadMode::Parallel);
+        assert_eq!(ReadMode::Auto.resolve(path, 4), ReadMode::Streaming);
+        assert_eq!(ReadMode::Sequential.resolve(path, 4), ReadMode::Sequential);
+        // The reader reports missing files
+        assert_eq!(ReadMode::Auto.resolve("missing.csv", 0), ReadMode::Parallel);
+    }
+
+    #[test]
+    fn test_sniff_csv() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "name,city").unwrap();
+        writeln!(file, "Zoé,Köln").unwrap();
+        let path = file.path().to_str().unwrap();
+        assert_eq!(sniff_format(path), Some(FileFormat::Csv));
+        assert!(verify_format(path, FileFormat::FixedWidth).is_ok());
+        assert!(verify_format(path, FileFormat::Json).is_err());
+    }
+
+    #[test]
+    fn test_sniff_json() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "  {{\"name\": \"Zoé\"}}").unwrap();
+        assert_eq!(
+            sniff_format(file.path().to_str().unwrap()),
+            Some(FileFormat::Json)
+        );
+    }
+
+    #[test]
+    fn test_sniff_gzip_csv() {
+        let mut encoder =
+            flate2::write::GzEncoder::new(NamedTempFile::new().unwrap(), Default::default());
+        writeln!(encoder, "name,city").unwrap();
+        writeln!(encoder, "Zoé,Köln").unwrap();
+        let file = encoder.finish().unwrap();
+        let path = file.path().to_str().unwrap();
+        assert!(is_gzip(path));
+        assert_eq!(sniff_format(path), Some(FileFormat::Csv));
+        assert!(verify_format(path, FileFormat::Csv).is_ok());
+    }
+
+    #[test]
+    fn test_sniff_parquet() {
+        assert_eq!(
+            sniff_format("tests/fixtures/test_ecommerce_data.parquet"),
+            Some(FileFormat::Parquet)
+        );
+    }
+
+    #[test]
+    fn test_sniff_avro() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"Obj\x01\x04\x14avro.codec").unwrap();
+        let path = file.path().to_str().unwrap();
+        assert_eq!(sniff_format(path), Some(FileFormat::Avro));
+        assert!(verify_format(path, FileFormat::Parquet).is_err());
+    }
+
+    #[test]
+    fn test_sniff_orc() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"ORC\x0a\x0b\x1a\x00\xff\x80").unwrap();
+        let path = file.path().to_str().unwrap();
+        assert_eq!(sniff_format(path), Some(FileFormat::Orc));
+        assert!(verify_format(path, FileFormat::Parquet).is_err());
+
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "ORCID,name").unwrap();
+        let path = file.path().to_str().unwrap();
+        assert_eq!(sniff_format(path), Some(FileFormat::Csv));
+    }
+
+    #[test]
+    fn test_sniff_arrow() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"ARROW1\x00\x00\xff\xff\xff\xff").unwrap();
+        let path = file.path().to_str().unwrap();
+        assert_eq!(sniff_format(path), Some(FileFormat::Arrow));
+        assert!(verify_format(path, FileFormat::Avro).is_err());
+    }
+
+    #[test]
+    fn test_sniff_binary_and_empty() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(&[0x00, 0xFF, 0x12, 0x00]).unwrap();
+        assert_eq!(sniff_format(file.path().to_str().unwrap()), None);
+
+        let empty = NamedTempFile::new().unwrap();
+        assert_eq!(sniff_format(empty.path().to_str().unwrap()), None);
+        assert_eq!(sniff_format("does/not/exist.csv"), None);
+    }
+
+    #[test]
+    fn test_verify_format_mismatch() {
+        let result = verify_format(
+            "tests/fixtures/test_ecommerce_data.parquet",
+            FileFormat::Csv,
+        );
+        match result {
+            Err(RuleError::FormatMismatch(_, declared, detected)) => {
+                assert_eq!(declared, "CSV");
+                assert_eq!(detected, "Parquet");
+            }
+            _ => panic!("Expected FormatMismatch error"),
+        }
+        assert!(verify_format(
+            "tests/fixtures/test_ecommerce_data.parquet",
+            FileFormat::Parquet
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_read_first_rows() {
+        use arrow_array::Int64Array;
+        use std::sync::mpsc::sync_channel;
+
+        let (tx, rx) = sync_channel(4);
+        for start in [0, 3, 6] {
+            let array = Int64Array::from_iter_values(start..start + 3);
+            let batch = RecordBatch::try_from_iter([("id", Arc::new(array) as _)]).unwrap();
+            tx.send(Ok(Arc::new(batch))).unwrap();
+        }
+        let (head, rows_left) = read_first_rows(rx, 5).unwrap();
+        assert_eq!(head.len(), 2);
+        assert_eq!(head[1].num_rows(), 2);
+        assert!(rows_left);
+        // The receiver is dropped, the producer sees the stream is closed
+        assert!(tx.send(Err(io::Error::other("stop"))).is_err());
+    }
+
+    #[test]
+    fn test_read_first_rows_on_batch_boundary() {
+        use arrow_array::Int64Array;
+        use std::sync::mpsc::sync_channel;
+
+        let batch = |rows: i64| {
+            let array = Int64Array::from_iter_values(0..rows);
+            Ok(Arc::new(
+                RecordBatch::try_from_iter([("id", Arc::new(array) as _)]).unwrap(),
+            ))
+        };
+        // The stream ends on the limit, empty batches hold no row
+        let (tx, rx) = sync_channel(4);
+        for rows in [3, 3, 0] {
+            tx.send(batch(rows)).unwrap();
+        }
+        drop(tx);
+        let (head, rows_left) = read_first_rows(rx, 6).unwrap();
+        assert_eq!(head.len(), 2);
+        assert!(!rows_left);
+
+        // A batch follows the limit
+        let (tx, rx) = sync_channel(4);
+        for rows in [3, 3, 0, 1] {
+            tx.send(batch(rows)).unwrap();
+        }
+        drop(tx);
+        let (head, rows_left) = read_first_rows(rx, 6).unwrap();
+        assert_eq!(head.len(), 2);
+        assert!(rows_left);
+    }
+}
@@ -0,0 +1,190 @@
+use std::io::{self, BufReader, Read};
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::sync::Arc;
+use std::thread;
+
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+
+use crate::readers::avro_reader::avro_stream_from;
+use crate::readers::csv_reader::csv_stream_from;
+use crate::readers::ipc_reader::ipc_stream_from;
+use crate::readers::json_reader::json_stream_from;
+use crate::readers::{FileFormat, ReaderConfig, STREAMING_BUFFER};
+
+/// Table path standing for the standard input
+pub const STDIN_PATH: &str = "-";
+
+/// Column names found in the head of a source, with the stream of its batches
+pub(crate) type StreamFrom = (Vec<String>, Receiver<Result<Arc<RecordBatch>, io::Error>>);
+
+/// Reads a non-seekable source, such as the standard input, batch by batch on a
+/// background thread.
+///
+/// # Arguments
+///
+/// * `source` - Any reader, consumed once from start to end
+/// * `format` - Format of the content, it can not be detected from a path
+/// * `cols`   - List of column names to read
+/// * `config` - A [`ReaderConfig`], used for CSV and NDJSON
+///
+/// # Returns
+///
+/// The column names found in the head of the source, along with the stream of batches
+/// holding the requested columns.
+///
+/// # Note
+///
+/// The source can not be split between threads nor read twice: parallel reading degrades
+/// to sequential. Parquet and ORC keep their metadata in a footer and can not be streamed,
+/// Arrow is read in the IPC stream format instead of the file format. Fixed-width sources
+/// need the position of their columns, see `fixed_width_stream_from`.
+pub fn read_streaming_from<R: Read + Send + 'static>(
+    source: R,
+    format: FileFormat,
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<StreamFrom, io::Error> {
+    let source = BufReader::new(source);
+    match format {
+        FileFormat::Csv => csv_stream_from(source, cols, config),
+        FileFormat::Json => json_stream_from(source, cols, config),
+        FileFormat::Avro => avro_stream_from(source, cols),
+        FileFormat::Arrow => ipc_stream_from(source, cols),
+        FileFormat::Parquet | FileFormat::Orc => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("{} can not be read from a non-seekable source", format),
+        )),
+        FileFormat::FixedWidth => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "fixed-width sources need the position of their columns",
+        )),
+    }
+}
+
+/// Reads a whole non-seekable source in memory, see [`read_streaming_from`].
+pub fn read_sequential_from<R: Read + Send + 'static>(
+    source: R,
+    format: FileFormat,
+    cols: Vec<String>,
+    config: &ReaderConfig,
+) -> Result<(Vec<String>, Vec<Arc<RecordBatch>>), io::Error> {
+    let (columns, batches) = read_streaming_from(source, format, cols, config)?;
+    Ok((columns, batches.into_iter().collect::<Result<_, _>>()?))
+}
+
+/// Decode `batches` on a background thread, buffering only a few of them.
+///
+/// Decoding errors are sent through the channel and end the stream.
+pub(crate) fn spawn_stream<I>(batches: I) -> Receiver<Result<Arc<RecordBatch>, io::Error>>
+where
+    I: Iterator<Item = Result<RecordBatch, ArrowError>> + Send + 'static,
+{
+    let (tx, rx) = sync_channel(STREAMING_BUFFER);
+    thread::spawn(move || {
+        for batch_result in batches {
+            let batch = batch_result
+                .map(Arc::new)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e));
+            let failed = batch.is_err();
+            // The receiver was dropped, nobody is listening anymore
+            if tx.send(batch).is_err() || failed {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::AsArray;
+    use std::io::Cursor;
+
+    use crate::readers::ReaderConfigBuilder;
+
+    fn num_rows(batches: &[Arc<RecordBatch>]) -> usize {
+        batches.iter().map(|b| b.num_rows()).sum()
+    }
+
+    #[test]
+    fn test_read_csv_from_source() {
+        let content: String = std::iter::once("name,age\n".to_string())
+            .chain((0..10).map(|i| format!("user{},{}\n", i, i)))
+            .collect();
+        let config = ReaderConfigBuilder::new().with_batch_size(3).build();
+        let (columns, batches) = read_sequential_from(
+            Cursor::new(content),
+            FileFormat::Csv,
+            vec!["age".to_string(), "missing".to_string()],
+            &config,
+        )
+        .unwrap();
+
+        assert_eq!(columns, ["name", "age"]);
+        assert_eq!(batches.len(), 4);
+        assert_eq!(num_rows(&batches), 10);
+        assert_eq!(batches[0].num_columns(), 1);
+        assert_eq!(batches[0].column(0).as_string::<i32>().value(0), "0");
+    }
+
+    #[test]
+    fn test_read_csv_from_source_without_header() {
+        let config = ReaderConfigBuilder::new().with_header(false).build();
+        let (columns, batches) = read_sequential_from(
+            Cursor::new("Alice,30\nBob,25\n"),
+            FileFormat::Csv,
+            vec!["col_0".to_string()],
+            &config,
+        )
+        .unwrap();
+
+        assert_eq!(columns, ["col_0", "col_1"]);
+        let names = batches[0].column(0).as_string::<i32>();
+        assert_eq!(names.value(0), "Alice");
+        assert_eq!(names.value(1), "Bob");
+    }
+
+    #[test]
+    fn test_read_json_from_source() {
+        let content =
+            "{\"name\": \"Alice\", \"age\": 30}\n{\"name\": \"Bob\", \"city\": \"Paris\"}\n";
+        let (columns, batches) = read_sequential_from(
+            Cursor::new(content),
+            FileFormat::Json,
+            vec!["age".to_string(), "city".to_string()],
+            &ReaderConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(columns.len(), 3);
+        assert_eq!(num_rows(&batches), 2);
+        assert_eq!(batches[0].column(0).as_string::<i32>().value(0), "30");
+        assert_eq!(batches[0].column(1).as_string::<i32>().value(1), "Paris");
+    }
+
+    #[test]
+    fn test_read_from_empty_source() {
+        for format in [FileFormat::Csv, FileFormat::Json] {
+            let result = read_streaming_from(
+                Cursor::new(""),
+                format,
+                vec!["name".to_string()],
+                &ReaderConfig::default(),
+            );
+            assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn test_read_parquet_from_source_unsupported() {
+        let result = read_streaming_from(
+            Cursor::new(b"PAR1".to_vec()),
+            FileFormat::Parquet,
+            vec![],
+            &ReaderConfig::default(),
+        );
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::Unsupported);
+    }
+}
@@ -1,13 +1,43 @@
 use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::readers::ReadMode;
+use crate::rules::ColumnKeys;
 
 #[derive(Debug, Clone)]
 pub struct ValidationResult {
     pub table_name: String,
     pub total_rows: usize,
     pub error_message: Option<String>,
+    /// Time spent reading the file into batches
+    pub read_duration: Duration,
+    /// Time spent running the rules over the batches
+    pub validation_duration: Duration,
+    /// Validation stopped at the row limit, the file may hold more rows
+    pub sampled: bool,
+    /// Validation stopped once the error limit was reached, the counts only cover the rows
+    /// validated until then. An aborted table fails
+    pub aborted: bool,
+    /// How the file was read, `None` for in-memory tables
+    pub read_mode: Option<ReadMode>,
+    /// Why the file was not read with the requested mode, such as a gzip compressed file
+    /// read sequentially
+    pub read_note: Option<String>,
     column_values: HashMap<String, usize>,
+    /// Checks on the table as a whole, such as its row count
+    table_results: Vec<RuleResult>,
     column_results: HashMap<String, Vec<RuleResult>>,
     relation_results: HashMap<String, Vec<RuleResult>>,
+    group_results: Vec<GroupResult>,
+    foreign_key_results: Vec<ForeignKeyResult>,
+    missing_columns: Vec<String>,
+    unexpected_columns: Vec<String>,
+    /// Column summaries, only computed when profiling, sorted by column name
+    profiles: Vec<ColumnProfile>,
+    /// Most frequent values of the requested columns, sorted by column name
+    value_counts: Vec<ValueCounts>,
+    /// Values of the foreign key columns, collected while validating the whole table
+    keys: HashMap<String, ColumnKeys>,
 }
 
 impl ValidationResult {
@@ -16,10 +46,47 @@ impl ValidationResult {
             table_name,
             total_rows,
             error_message: None,
+            read_duration: Duration::ZERO,
+            validation_duration: Duration::ZERO,
+            sampled: false,
+            aborted: false,
+            read_mode: None,
+            read_note: None,
             column_values: HashMap::new(),
+            table_results: Vec::new(),
             column_results: HashMap::new(),
             relation_results: HashMap::new(),
+            group_results: Vec::new(),
+            foreign_key_results: Vec::new(),
+            missing_columns: Vec::new(),
+            unexpected_columns: Vec::new(),
+            profiles: Vec::new(),
+            value_counts: Vec::new(),
+            keys: HashMap::new(),
+        }
+    }
+
+    /// Result of a table that could not be validated, it fails with `error_message`.
+    pub fn from_error(table_name: String, error_message: String) -> Self {
+        let mut result = Self::new(table_name, 0);
+        result.error_message = Some(error_message);
+        result
+    }
+
+    pub fn set_durations(&mut self, read: Duration, validation: Duration) {
+        self.read_duration = read;
+        self.validation_duration = validation;
+    }
+
+    /// Rows validated per second, over both reading and validation.
+    ///
+    /// Returns 0 when no time was recorded.
+    pub fn rows_per_second(&self) -> f64 {
+        let elapsed = (self.read_duration + self.validation_duration).as_secs_f64();
+        if elapsed == 0.0 {
+            return 0.0;
         }
+        self.total_rows as f64 / elapsed
     }
 
     pub fn add_column_values(&mut self, column_name: String, values: usize) {
@@ -30,6 +97,10 @@ impl ValidationResult {
         self.column_values = column_values
     }
 
+    pub fn add_table_result(&mut self, result: RuleResult) {
+        self.table_results.push(result);
+    }
+
     pub fn add_column_result(&mut self, column_name: String, results: Vec<RuleResult>) {
         self.column_results.insert(column_name, results);
     }
@@ -38,6 +109,18 @@ impl ValidationResult {
         self.column_results = column_results
     }
 
+    /// Attach the labels of the column rules, keyed by column then by rule name
+    pub fn add_rule_labels(&mut self, labels: &HashMap<String, HashMap<String, String>>) {
+        for (column_name, results) in self.column_results.iter_mut() {
+            let Some(labels) = labels.get(column_name) else {
+                continue;
+            };
+            for result in results {
+                result.label = labels.get(&result.rule_name).cloned();
+            }
+        }
+    }
+
     pub fn add_relation_result(&mut self, relation_name: String, results: Vec<RuleResult>) {
         self.relation_results.insert(relation_name, results);
     }
@@ -46,6 +129,44 @@ impl ValidationResult {
         self.relation_results = relation_results
     }
 
+    pub fn add_group_result(&mut self, group_result: GroupResult) {
+        self.group_results.push(group_result);
+    }
+
+    pub fn add_foreign_key_result(&mut self, foreign_key_result: ForeignKeyResult) {
+        self.foreign_key_results.push(foreign_key_result);
+    }
+
+    /// Record configured columns absent from the file and, in strict mode,
+    /// file columns absent from the configuration
+    pub fn add_schema_errors(&mut self, missing: Vec<String>, unexpected: Vec<String>) {
+        self.missing_columns = missing;
+        self.unexpected_columns = unexpected;
+    }
+
+    pub fn add_profiles(&mut self, mut profiles: Vec<ColumnProfile>) {
+        profiles.sort_by(|a, b| a.name.cmp(&b.name));
+        self.profiles = profiles;
+    }
+
+    pub fn add_value_counts(&mut self, mut value_counts: Vec<ValueCounts>) {
+        value_counts.sort_by(|a, b| a.name.cmp(&b.name));
+        self.value_counts = value_counts;
+    }
+
+    pub(crate) fn set_keys(&mut self, keys: HashMap<String, ColumnKeys>) {
+        self.keys = keys;
+    }
+
+    /// Take the collected foreign key values, they are only needed once
+    pub(crate) fn take_keys(&mut self) -> HashMap<String, ColumnKeys> {
+        std::mem::take(&mut self.keys)
+    }
+
+    pub fn get_table_results(&self) -> &[RuleResult] {
+        &self.table_results
+    }
+
     pub fn get_column_results(&self) -> HashMap<String, Vec<&RuleResult>> {
         self.column_results
             .iter()
@@ -60,11 +181,57 @@ impl ValidationResult {
             .collect()
     }
 
+    pub fn get_group_results(&self) -> &[GroupResult] {
+        &self.group_results
+    }
+
+    pub fn get_foreign_key_results(&self) -> &[ForeignKeyResult] {
+        &self.foreign_key_results
+    }
+
+    pub fn get_profiles(&self) -> &[ColumnProfile] {
+        &self.profiles
+    }
+
+    pub fn get_value_counts(&self) -> &[ValueCounts] {
+        &self.value_counts
+    }
+
+    pub fn get_missing_columns(&self) -> &[String] {
+        &self.missing_columns
+    }
+
+    pub fn get_unexpected_columns(&self) -> &[String] {
+        &self.unexpected_columns
+    }
+
+    /// Errors of the column, relation, group and foreign key rules over the rows.
+    ///
+    /// Rows skipped by a rule are informative only, they are not counted. Table-level
+    /// results, such as the row count, do not count rows in error and are left out.
+    pub fn error_count(&self) -> usize {
+        let rules = self
+            .column_results
+            .values()
+            .chain(self.relation_results.values())
+            .flatten()
+            .filter(|rule| !rule.rule_name.ends_with("Skipped"));
+        rules
+            .chain(self.group_results.iter().map(|g| &g.result))
+            .chain(self.foreign_key_results.iter().map(|fk| &fk.result))
+            .map(|rule| rule.error_count)
+            .sum()
+    }
+
     /// Returns the number of passed rules and the total number of rules executed.
     ///
     /// The return value is a tuple `(passed_count, total_count)`:
     /// - The first element is the sum of all rules where `status` was `true`.
-    /// - The second element is the total count of all rules across all columns.
+    /// - The second element is the total count of all rules across the table, its columns,
+    ///   groups and foreign keys.
+    ///
+    /// Each missing or unexpected column counts as one failed check, as does an
+    /// `error_message` that stopped the validation of the table.
     ///
     /// # Panics
     ///
@@ -73,9 +240,32 @@ impl ValidationResult {
     /// This allow for 255 total number of rules per Table
     pub fn is_passed(&self) -> (u8, u8) {
         let passed = self
-            .column_results
-            .values()
-            .flat_map(|rules| rules.iter().map(|rule| rule.pass as u8))
+            .table_results
+            .iter()
+            .map(|rule| rule.pass as u8)
+            .chain(
+                self.column_results
+                    .values()
+                    .flat_map(|rules| rules.iter().map(|rule| rule.pass as u8)),
+            )
+            .chain(
+                self.group_results
+                    .iter()
+                    .map(|group| group.result.pass as u8),
+            )
+            .chain(
+                self.foreign_key_results
+                    .iter()
+                    .map(|fk| fk.result.pass as u8),
+            )
+            .chain(
+                self.missing_columns
+                    .iter()
+                    .chain(self.unexpected_columns.iter())
+                    .chain(self.error_message.iter())
+                    .map(|_| 0),
+            )
+            .chain(self.aborted.then_some(0))
             .collect::<Vec<u8>>();
 
         (passed.iter().sum(), passed.len() as u8)
@@ -90,6 +280,12 @@ pub struct RuleResult {
     pub threshold: f64,
     pub error_message: Option<String>,
     pub pass: bool,
+    /// First failing `(row_index, value)` pairs, ordered by row index
+    pub samples: Vec<(usize, String)>,
+    /// Column level values computed by the rule, such as a quantile, as `(name, value)`
+    pub observed: Vec<(String, f64)>,
+    /// Human readable name given to the rule, e.g. "Customer name too short"
+    pub label: Option<String>,
 }
 
 impl RuleResult {
@@ -108,14 +304,133 @@ impl RuleResult {
             threshold,
             error_message,
             pass,
+            samples: Vec::new(),
+            observed: Vec::new(),
+            label: None,
         }
     }
 
+    /// Name to show to users: the label of the rule when it has one, its name otherwise
+    pub fn display_name(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.rule_name)
+    }
+
+    /// Attach failing row samples to the result
+    pub fn with_samples(mut self, samples: Vec<(usize, String)>) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Attach the values computed by the rule
+    pub fn with_observed(mut self, observed: Vec<(String, f64)>) -> Self {
+        self.observed = observed;
+        self
+    }
+
     pub fn set_error_message(&mut self, error_message: String) {
         self.error_message = Some(error_message)
     }
 }
 
+/// Outcome of a group assertion, with the detail of every group out of tolerance.
+#[derive(Debug, Clone)]
+pub struct GroupResult {
+    pub name: String,
+    pub result: RuleResult,
+    pub mismatches: Vec<GroupMismatch>,
+}
+
+impl GroupResult {
+    pub fn new(name: String, result: RuleResult, mismatches: Vec<GroupMismatch>) -> Self {
+        Self {
+            name,
+            result,
+            mismatches,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupMismatch {
+    pub group: String,
+    pub expected: f64,
+    pub actual: f64,
+}
+
+impl GroupMismatch {
+    pub fn new(group: String, expected: f64, actual: f64) -> Self {
+        Self {
+            group,
+            expected,
+            actual,
+        }
+    }
+
+    pub fn delta(&self) -> f64 {
+        self.actual - self.expected
+    }
+}
+
+/// Outcome of a cross-table foreign key check, attached to the child table.
+#[derive(Debug, Clone)]
+pub struct ForeignKeyResult {
+    pub name: String,
+    pub result: RuleResult,
+    pub orphans: Vec<String>,
+}
+
+impl ForeignKeyResult {
+    pub fn new(name: String, result: RuleResult, orphans: Vec<String>) -> Self {
+        Self {
+            name,
+            result,
+            orphans,
+        }
+    }
+}
+
+/// Summary of the values of a column, computed when profiling a table.
+///
+/// Values failing the type check are counted as nulls.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnProfile {
+    pub name: String,
+    pub null_count: usize,
+    /// Estimated count of distinct non-null values, exact for small columns
+    pub distinct: usize,
+    /// Numeric columns only
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub mean: Option<f64>,
+    /// String columns only, in characters
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+}
+
+/// Frequency of the most common values of a column.
+///
+/// Values failing the type check are counted as nulls.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValueCounts {
+    pub name: String,
+    /// Most frequent values as `(value, count)`, by decreasing count
+    pub values: Vec<(String, usize)>,
+    /// Non-null rows holding any other value
+    pub other: usize,
+    pub null_count: usize,
+}
+
+impl ValueCounts {
+    /// Share of the `total_rows` holding `count` values, in percent
+    pub fn percentage(count: usize, total_rows: usize) -> f64 {
+        if total_rows > 0 {
+            count as f64 / total_rows as f64 * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -129,4 +444,13 @@ mod test {
         assert_eq!(validation.table_name, table_name);
         assert_eq!(validation.total_rows, total_rows);
     }
+
+    #[test]
+    fn test_rows_per_second() {
+        let mut validation = ValidationResult::new("products".to_string(), 3_000);
+        assert_eq!(validation.rows_per_second(), 0.0);
+
+        validation.set_durations(Duration::from_millis(500), Duration::from_millis(1_000));
+        assert_eq!(validation.rows_per_second(), 2_000.0);
+    }
 }
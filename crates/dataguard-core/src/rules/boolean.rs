@@ -0,0 +1,239 @@
+use arrow::array::{Array, BooleanArray, StringArray};
+use arrow::datatypes::DataType;
+
+use crate::{errors::RuleError, rules::first_failing_rows};
+
+/// Default tokens parsed as `true`, compared case-insensitively
+pub const DEFAULT_TRUE_TOKENS: [&str; 3] = ["true", "1", "yes"];
+/// Default tokens parsed as `false`, compared case-insensitively
+pub const DEFAULT_FALSE_TOKENS: [&str; 3] = ["false", "0", "no"];
+
+pub struct BooleanTypeCheck {
+    column: String,
+    threshold: f64,
+    true_tokens: Vec<String>,
+    false_tokens: Vec<String>,
+}
+
+impl BooleanTypeCheck {
+    /// Tokens are trimmed and lowercased, values are matched the same way.
+    pub fn new(
+        column: String,
+        true_tokens: Vec<String>,
+        false_tokens: Vec<String>,
+        threshold: f64,
+    ) -> Self {
+        let normalize =
+            |tokens: Vec<String>| tokens.iter().map(|t| t.trim().to_lowercase()).collect();
+        Self {
+            column,
+            threshold,
+            true_tokens: normalize(true_tokens),
+            false_tokens: normalize(false_tokens),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        "TypeCheck".to_string()
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn parse(&self, value: &str) -> Option<bool> {
+        let value = value.trim().to_lowercase();
+        if self.true_tokens.contains(&value) {
+            Some(true)
+        } else if self.false_tokens.contains(&value) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    /// Parse a string array into a `BooleanArray`, unknown tokens become null.
+    ///
+    /// Returns the number of values that could not be parsed along with the casted array.
+    pub fn validate(&self, array: &dyn Array) -> Result<(usize, BooleanArray), RuleError> {
+        let base_nulls = array.null_count();
+        let array = array
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .ok_or_else(|| {
+                RuleError::TypeCastError(self.column.clone(), DataType::Boolean.to_string())
+            })?;
+        let casted_array: BooleanArray = array
+            .iter()
+            .map(|v| v.and_then(|v| self.parse(v)))
+            .collect();
+        let errors = casted_array.null_count() - base_nulls;
+        Ok((errors, casted_array))
+    }
+}
+
+/// A trait for defining validation rules on boolean arrays.
+pub trait BooleanRule: Send + Sync {
+    /// Returns the name of the rule.
+    fn name(&self) -> String;
+    /// Returns the rule threshold
+    fn get_threshold(&self) -> f64;
+    /// Validates an Arrow `BooleanArray`.
+    fn validate(&self, array: &BooleanArray, column: String) -> Result<usize, RuleError>;
+    /// Returns the positions of the first `limit` failing values, used for reporting samples.
+    fn failing_rows(&self, _array: &BooleanArray, _limit: usize) -> Vec<usize> {
+        Vec::new()
+    }
+}
+
+/// Every non-null value must equal `expected`.
+pub struct ExpectedValueCheck {
+    name: String,
+    threshold: f64,
+    expected: bool,
+}
+
+impl ExpectedValueCheck {
+    pub fn new(name: String, threshold: f64, expected: bool) -> Self {
+        Self {
+            name,
+            threshold,
+            expected,
+        }
+    }
+}
+
+impl BooleanRule for ExpectedValueCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &BooleanArray, _column: String) -> Result<usize, RuleError> {
+        let true_count = array.true_count();
+        let values = array.len() - array.null_count();
+        if self.expected {
+            Ok(values - true_count)
+        } else {
+            Ok(true_count)
+        }
+    }
+
+    fn failing_rows(&self, array: &BooleanArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| v != self.expected)
+    }
+}
+
+/// The share of `true` among non-null values must stay within `[min, max]`.
+///
+/// The ratio only makes sense on the whole column, so it is evaluated once all
+/// batches have been counted. The error count is the number of values that would
+/// need to flip for the ratio to fall back within bounds.
+pub struct TrueRatioCheck {
+    name: String,
+    threshold: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TrueRatioCheck {
+    pub fn new(name: String, threshold: f64, min: f64, max: f64) -> Self {
+        Self {
+            name,
+            threshold,
+            min,
+            max,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    pub fn validate(&self, true_count: usize, values: usize) -> usize {
+        if values == 0 {
+            return 0;
+        }
+        let min_true = (self.min * values as f64).ceil() as usize;
+        let max_true = (self.max * values as f64).floor() as usize;
+        if true_count < min_true {
+            min_true - true_count
+        } else {
+            true_count.saturating_sub(max_true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_type_check() -> BooleanTypeCheck {
+        BooleanTypeCheck::new(
+            "col".to_string(),
+            DEFAULT_TRUE_TOKENS.iter().map(|t| t.to_string()).collect(),
+            DEFAULT_FALSE_TOKENS.iter().map(|t| t.to_string()).collect(),
+            0.0,
+        )
+    }
+
+    #[test]
+    fn test_boolean_type_check_default_tokens() {
+        let rule = default_type_check();
+        let array = StringArray::from(vec![
+            Some("true"),
+            Some("FALSE"),
+            Some("1"),
+            Some("0"),
+            Some(" Yes "),
+            Some("no"),
+            Some("maybe"),
+            None,
+        ]);
+        let (errors, casted) = rule.validate(&array).unwrap();
+        assert_eq!(errors, 1);
+        assert_eq!(casted.null_count(), 2);
+        assert_eq!(casted.true_count(), 3);
+        assert_eq!(casted.false_count(), 3);
+    }
+
+    #[test]
+    fn test_boolean_type_check_custom_tokens() {
+        let rule = BooleanTypeCheck::new(
+            "col".to_string(),
+            vec!["Y".to_string()],
+            vec!["N".to_string()],
+            0.0,
+        );
+        let array = StringArray::from(vec!["y", "n", "true"]);
+        let (errors, casted) = rule.validate(&array).unwrap();
+        assert_eq!(errors, 1);
+        assert!(casted.value(0));
+        assert!(!casted.value(1));
+    }
+
+    #[test]
+    fn test_expected_value_check() {
+        let array = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        let is_true = ExpectedValueCheck::new("IsTrue".to_string(), 0.0, true);
+        let is_false = ExpectedValueCheck::new("IsFalse".to_string(), 0.0, false);
+        assert_eq!(is_true.validate(&array, "col".to_string()).unwrap(), 1);
+        assert_eq!(is_false.validate(&array, "col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_true_ratio_check() {
+        let rule = TrueRatioCheck::new("TrueRatioBetween".to_string(), 0.0, 0.4, 0.6);
+        assert_eq!(rule.validate(5, 10), 0);
+        assert_eq!(rule.validate(2, 10), 2);
+        assert_eq!(rule.validate(9, 10), 3);
+        assert_eq!(rule.validate(0, 0), 0);
+    }
+}
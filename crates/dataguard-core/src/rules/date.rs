@@ -2,7 +2,7 @@ use arrow::datatypes::DataType;
 use arrow_array::{Array, Date32Array, StringArray};
 use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
-use crate::{utils::date_parser::parse_date_column, RuleError};
+use crate::{rules::first_failing_rows, utils::date_parser::parse_date_column, RuleError};
 
 pub struct DateTypeCheck {
     // Those two field are not needed now as we dont need the expected
@@ -50,6 +50,35 @@ pub trait DateRule: Send + Sync {
     fn get_threshold(&self) -> f64;
     /// Validates an Arrow `Array`.
     fn validate(&self, array: &Date32Array, column: String) -> Result<usize, RuleError>;
+    /// Returns the positions of the first `limit` failing values, used for reporting samples.
+    fn failing_rows(&self, _array: &Date32Array, _limit: usize) -> Vec<usize> {
+        Vec::new()
+    }
+}
+
+/// Convert a date to a number of days since the Unix epoch, the Date32 representation.
+pub(crate) fn days_since_epoch(year: usize, month: u32, day: u32) -> Result<i32, RuleError> {
+    let date = NaiveDate::from_ymd_opt(year as i32, month, day)
+        .ok_or(RuleError::IncorrectDateError(year, month, day))?;
+    // Here we can unwrap date is correct
+    let unix = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    Ok(date.signed_duration_since(unix).num_days() as i32)
+}
+
+/// Convert the `[start, end]` bounds of a date range to days, `start` must not be after `end`.
+pub(crate) fn date_range_days(
+    start: (usize, u32, u32),
+    end: (usize, u32, u32),
+) -> Result<(i32, i32), RuleError> {
+    let start_days = days_since_epoch(start.0, start.1, start.2)?;
+    let end_days = days_since_epoch(end.0, end.1, end.2)?;
+    if start_days > end_days {
+        return Err(RuleError::ValidationError(format!(
+            "Start date {}-{}-{} is after end date {}-{}-{}",
+            start.0, start.1, start.2, end.0, end.1, end.2
+        )));
+    }
+    Ok((start_days, end_days))
 }
 
 pub struct DateBoundaryCheck {
@@ -70,21 +99,13 @@ impl DateBoundaryCheck {
     ) -> Result<Self, RuleError> {
         let m = month.unwrap_or(1) as u32;
         let d = day.unwrap_or(1) as u32;
-        let res = NaiveDate::from_ymd_opt(year as i32, m, d);
-        match res {
-            Some(date) => {
-                // Here we can unwrap date is correct
-                let unix = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
-                let days = date.signed_duration_since(unix).num_days() as i32;
-                Ok(Self {
-                    name,
-                    threshold,
-                    after,
-                    days,
-                })
-            }
-            None => Err(RuleError::IncorrectDateError(year, m, d)),
-        }
+        let days = days_since_epoch(year, m, d)?;
+        Ok(Self {
+            name,
+            threshold,
+            after,
+            days,
+        })
     }
 }
 
@@ -110,6 +131,66 @@ impl DateRule for DateBoundaryCheck {
         }
         Ok(counter)
     }
+
+    fn failing_rows(&self, array: &Date32Array, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |day| {
+            if self.after {
+                day <= self.days
+            } else {
+                day >= self.days
+            }
+        })
+    }
+}
+
+/// Dates must fall within `[start, end]`, both bounds included.
+pub struct DateRangeCheck {
+    name: String,
+    threshold: f64,
+    start: i32,
+    end: i32,
+}
+
+impl DateRangeCheck {
+    /// Bounds are given as `(year, month, day)`.
+    pub fn new(
+        name: String,
+        threshold: f64,
+        start: (usize, u32, u32),
+        end: (usize, u32, u32),
+    ) -> Result<Self, RuleError> {
+        let (start, end) = date_range_days(start, end)?;
+        Ok(Self {
+            name,
+            threshold,
+            start,
+            end,
+        })
+    }
+}
+
+impl DateRule for DateRangeCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &Date32Array, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|day| !(self.start..=self.end).contains(day))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &Date32Array, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |day| {
+            !(self.start..=self.end).contains(&day)
+        })
+    }
 }
 
 pub struct WeekDayCheck {
@@ -164,6 +245,147 @@ impl DateRule for WeekDayCheck {
         }
         Ok(violations)
     }
+
+    fn failing_rows(&self, array: &Date32Array, limit: usize) -> Vec<usize> {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        first_failing_rows(array.iter(), limit, |day| {
+            let date = epoch + Duration::days(day as i64);
+            let is_weekend = matches!(date.weekday(), Weekday::Sun | Weekday::Sat);
+            is_weekend == self.is_week
+        })
+    }
+}
+
+/// Day of the month a date is expected to fall on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DayOfMonthMode {
+    FirstOfMonth,
+    LastOfMonth,
+    Day(u32),
+}
+
+pub struct DayOfMonthCheck {
+    name: String,
+    threshold: f64,
+    mode: DayOfMonthMode,
+}
+
+impl DayOfMonthCheck {
+    pub fn new(name: String, threshold: f64, mode: DayOfMonthMode) -> Result<Self, RuleError> {
+        if let DayOfMonthMode::Day(day) = mode {
+            if !(1..=31).contains(&day) {
+                return Err(RuleError::ValidationError(format!(
+                    "Day of month must be between 1 and 31, got {}",
+                    day
+                )));
+            }
+        }
+        Ok(Self {
+            name,
+            threshold,
+            mode,
+        })
+    }
+
+    fn is_aligned(&self, date: NaiveDate) -> bool {
+        match self.mode {
+            DayOfMonthMode::FirstOfMonth => date.day() == 1,
+            // The next day falls on the 1st only when we are on the last day of the month,
+            // which takes care of the month length and leap years for us.
+            DayOfMonthMode::LastOfMonth => date.succ_opt().is_none_or(|next| next.day() == 1),
+            DayOfMonthMode::Day(day) => date.day() == day,
+        }
+    }
+}
+
+impl DateRule for DayOfMonthCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &Date32Array, _column: String) -> Result<usize, RuleError> {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let violations = array
+            .iter()
+            .flatten()
+            .filter(|day| !self.is_aligned(epoch + Duration::days(*day as i64)))
+            .count();
+        Ok(violations)
+    }
+
+    fn failing_rows(&self, array: &Date32Array, limit: usize) -> Vec<usize> {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        first_failing_rows(array.iter(), limit, |day| {
+            !self.is_aligned(epoch + Duration::days(day as i64))
+        })
+    }
+}
+
+/// The full years elapsed between a date and `today` must be within `[min_years, max_years]`.
+///
+/// Dates in the future have a negative age.
+pub struct AgeCheck {
+    name: String,
+    threshold: f64,
+    min_years: i32,
+    max_years: i32,
+    today: NaiveDate,
+}
+
+impl AgeCheck {
+    /// `today` defaults to the local date, set it for reproducible results.
+    pub fn new(
+        name: String,
+        threshold: f64,
+        min_years: u32,
+        max_years: u32,
+        today: Option<NaiveDate>,
+    ) -> Self {
+        Self {
+            name,
+            threshold,
+            min_years: min_years as i32,
+            max_years: max_years as i32,
+            today: today.unwrap_or_else(|| chrono::Local::now().date_naive()),
+        }
+    }
+
+    fn is_valid(&self, day: i32) -> bool {
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let date = epoch + Duration::days(day as i64);
+        let mut age = self.today.year() - date.year();
+        // The birthday has not come yet this year
+        if (self.today.month(), self.today.day()) < (date.month(), date.day()) {
+            age -= 1;
+        }
+        (self.min_years..=self.max_years).contains(&age)
+    }
+}
+
+impl DateRule for AgeCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &Date32Array, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|day| !self.is_valid(*day))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &Date32Array, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |day| !self.is_valid(day))
+    }
 }
 
 #[cfg(test)]
@@ -196,7 +418,7 @@ mod tests {
         assert!(result.is_ok());
         let rule = result.unwrap();
         assert_eq!(rule.days, date_to_days(2020, 6, 15));
-        assert_eq!(rule.after, true);
+        assert!(rule.after);
     }
 
     #[test]
@@ -213,7 +435,7 @@ mod tests {
         let rule = result.unwrap();
         // Should default to day 1
         assert_eq!(rule.days, date_to_days(2020, 6, 1));
-        assert_eq!(rule.after, false);
+        assert!(!rule.after);
     }
 
     #[test]
@@ -971,4 +1193,168 @@ mod tests {
         // 2 violations: Monday and Wednesday
         assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
     }
+
+    // ============================================================================
+    // DayOfMonthCheck Tests
+    // ============================================================================
+
+    #[test]
+    fn test_day_of_month_invalid_day() {
+        assert!(
+            DayOfMonthCheck::new("IsDayOfMonth".to_string(), 0.0, DayOfMonthMode::Day(0)).is_err()
+        );
+        assert!(
+            DayOfMonthCheck::new("IsDayOfMonth".to_string(), 0.0, DayOfMonthMode::Day(32)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_month_end_across_month_boundaries() {
+        let rule = DayOfMonthCheck::new("IsMonthEnd".to_string(), 0.0, DayOfMonthMode::LastOfMonth)
+            .unwrap();
+
+        let array = Date32Array::from(vec![
+            Some(date_to_days(2025, 1, 31)),  // ok
+            Some(date_to_days(2025, 4, 30)),  // ok
+            Some(date_to_days(2025, 12, 31)), // ok
+            Some(date_to_days(2025, 4, 29)),  // violation
+            Some(date_to_days(2025, 5, 1)),   // violation
+            None,                             // ignored
+        ]);
+
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_month_end_february_leap_year() {
+        let rule = DayOfMonthCheck::new("IsMonthEnd".to_string(), 0.0, DayOfMonthMode::LastOfMonth)
+            .unwrap();
+
+        let array = Date32Array::from(vec![
+            Some(date_to_days(2024, 2, 29)), // ok, 2024 is a leap year
+            Some(date_to_days(2024, 2, 28)), // violation
+            Some(date_to_days(2000, 2, 29)), // ok, divisible by 400
+        ]);
+
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_month_end_february_non_leap_year() {
+        let rule = DayOfMonthCheck::new("IsMonthEnd".to_string(), 0.0, DayOfMonthMode::LastOfMonth)
+            .unwrap();
+
+        let array = Date32Array::from(vec![
+            Some(date_to_days(2025, 2, 28)), // ok
+            Some(date_to_days(1900, 2, 28)), // ok, 1900 is not a leap year
+            Some(date_to_days(2025, 2, 27)), // violation
+        ]);
+
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_month_start() {
+        let rule = DayOfMonthCheck::new(
+            "IsMonthStart".to_string(),
+            0.0,
+            DayOfMonthMode::FirstOfMonth,
+        )
+        .unwrap();
+
+        let array = Date32Array::from(vec![
+            Some(date_to_days(2024, 3, 1)),   // ok
+            Some(date_to_days(2024, 2, 29)),  // violation
+            Some(date_to_days(2025, 1, 1)),   // ok
+            Some(date_to_days(2024, 12, 31)), // violation
+        ]);
+
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_fixed_day_of_month() {
+        let rule =
+            DayOfMonthCheck::new("IsDayOfMonth".to_string(), 0.0, DayOfMonthMode::Day(15)).unwrap();
+
+        let array = Date32Array::from(vec![
+            Some(date_to_days(2024, 2, 15)), // ok
+            Some(date_to_days(2025, 2, 15)), // ok
+            Some(date_to_days(2025, 3, 16)), // violation
+            Some(date_to_days(2025, 3, 14)), // violation
+        ]);
+
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+    }
+
+    // ============================================================================
+    // DateRangeCheck Tests
+    // ============================================================================
+
+    #[test]
+    fn test_date_range_inclusive() {
+        let rule = DateRangeCheck::new("IsBetween".to_string(), 0.0, (2020, 1, 1), (2024, 12, 31))
+            .unwrap();
+
+        let array = Date32Array::from(vec![
+            Some(date_to_days(2020, 1, 1)),   // ok, start bound
+            Some(date_to_days(2024, 12, 31)), // ok, end bound
+            Some(date_to_days(2022, 6, 15)),  // ok
+            Some(date_to_days(2019, 12, 31)), // violation
+            Some(date_to_days(2025, 1, 1)),   // violation
+            None,
+        ]);
+
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 10), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_date_range_invalid_bounds() {
+        let result = DateRangeCheck::new("IsBetween".to_string(), 0.0, (2024, 2, 30), (2025, 1, 1));
+        assert!(matches!(
+            result,
+            Err(RuleError::IncorrectDateError(2024, 2, 30))
+        ));
+
+        let result = DateRangeCheck::new("IsBetween".to_string(), 0.0, (2025, 1, 1), (2024, 1, 1));
+        assert!(matches!(result, Err(RuleError::ValidationError(_))));
+    }
+
+    // ============================================================================
+    // AgeCheck Tests
+    // ============================================================================
+
+    #[test]
+    fn test_age_between() {
+        let today = NaiveDate::from_ymd_opt(2024, 6, 15).unwrap();
+        let rule = AgeCheck::new("AgeBetween".to_string(), 0.0, 18, 65, Some(today));
+
+        let array = Date32Array::from(vec![
+            Some(date_to_days(2006, 6, 15)), // ok, 18 today
+            Some(date_to_days(2006, 6, 16)), // violation, 17
+            Some(date_to_days(1958, 6, 16)), // ok, 65
+            Some(date_to_days(1959, 6, 15)), // ok, 65 today
+            Some(date_to_days(1958, 6, 15)), // violation, 66 today
+            Some(date_to_days(2030, 1, 1)),  // violation, future date
+            None,
+        ]);
+
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 3);
+        assert_eq!(rule.failing_rows(&array, 10), vec![1, 4, 5]);
+    }
+
+    #[test]
+    fn test_age_between_leap_day() {
+        // Born on February 29th, the birthday is reached on March 1st on common years
+        let rule = AgeCheck::new(
+            "AgeBetween".to_string(),
+            0.0,
+            18,
+            65,
+            NaiveDate::from_ymd_opt(2022, 2, 28),
+        );
+        let array = Date32Array::from(vec![date_to_days(2004, 2, 29)]);
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+    }
 }
@@ -1,13 +1,21 @@
 use arrow::{
-    array::Array,
+    array::{Array, AsArray},
     compute::{self},
-    datatypes::{DataType, ToByteSlice},
+    datatypes::{
+        DataType, Date32Type, Float64Type, Int64Type, TimeUnit, TimestampMillisecondType,
+        ToByteSlice,
+    },
+};
+use arrow_array::{ArrowNumericType, ArrowPrimitiveType, Date32Array, PrimitiveArray, StringArray};
+use std::{borrow::Cow, collections::HashSet, sync::Arc};
+use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
+
+use crate::{
+    engine::Stats,
+    errors::RuleError,
+    rules::{first_failing_rows, NumericRule, StringRule},
+    utils::hasher::Xxh3Builder,
 };
-use arrow_array::{ArrowPrimitiveType, Date32Array, PrimitiveArray, StringArray};
-use std::{collections::HashSet, sync::Arc};
-use xxhash_rust::xxh3::xxh3_64;
-
-use crate::{errors::RuleError, utils::hasher::Xxh3Builder};
 
 pub struct NullCheck {
     threshold: f64,
@@ -71,9 +79,50 @@ impl TypeCheck {
     }
 }
 
+/// Normalization applied to strings before they are hashed by [`UnicityCheck`], so that
+/// `Foo@x.com` and ` foo@x.com` can count as duplicates.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum NormalizeMode {
+    /// Raw bytes are hashed
+    #[default]
+    None,
+    /// Values are lowercased
+    Lowercase,
+    /// Leading and trailing whitespace is removed
+    Trim,
+    /// Values are trimmed then lowercased
+    TrimLowercase,
+}
+
+impl NormalizeMode {
+    fn apply<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        match self {
+            NormalizeMode::None => Cow::Borrowed(value),
+            NormalizeMode::Lowercase => Cow::Owned(value.to_lowercase()),
+            NormalizeMode::Trim => Cow::Borrowed(value.trim()),
+            NormalizeMode::TrimLowercase => Cow::Owned(value.trim().to_lowercase()),
+        }
+    }
+}
+
+/// Parse a normalization name: `none`, `lowercase`, `trim` or `trim_lowercase`.
+pub fn parse_normalize_mode(name: &str) -> Result<NormalizeMode, RuleError> {
+    match name {
+        "none" => Ok(NormalizeMode::None),
+        "lowercase" => Ok(NormalizeMode::Lowercase),
+        "trim" => Ok(NormalizeMode::Trim),
+        "trim_lowercase" => Ok(NormalizeMode::TrimLowercase),
+        other => Err(RuleError::ValidationError(format!(
+            "Unknown normalization '{}', expected none, lowercase, trim or trim_lowercase",
+            other
+        ))),
+    }
+}
+
 #[derive(Clone)]
 pub struct UnicityCheck {
     threshold: f64,
+    normalize: NormalizeMode,
 }
 
 impl Default for UnicityCheck {
@@ -84,7 +133,16 @@ impl Default for UnicityCheck {
 
 impl UnicityCheck {
     pub fn new(threshold: f64) -> Self {
-        Self { threshold }
+        Self {
+            threshold,
+            normalize: NormalizeMode::default(),
+        }
+    }
+
+    /// Set the normalization applied to strings before they are hashed
+    pub fn with_normalize(mut self, normalize: NormalizeMode) -> Self {
+        self.normalize = normalize;
+        self
     }
 
     pub fn get_threshold(&self) -> f64 {
@@ -99,7 +157,7 @@ impl UnicityCheck {
         let mut local_hash = HashSet::with_capacity_and_hasher(array.len(), Xxh3Builder);
         array.iter().for_each(|v_option| {
             if let Some(v) = v_option {
-                let hash = xxh3_64(v.as_bytes());
+                let hash = xxh3_64(self.normalize.apply(v).as_bytes());
                 let _ = local_hash.insert(hash);
             }
         });
@@ -132,14 +190,292 @@ impl UnicityCheck {
     }
 }
 
+/// Uniqueness of the values of a column within each group of rows sharing the same
+/// `group_by` key, e.g. an email unique per tenant.
+#[derive(Debug, Clone)]
+pub struct GroupedUnicityCheck {
+    threshold: f64,
+    group_by: String,
+}
+
+impl GroupedUnicityCheck {
+    pub fn new(group_by: String, threshold: f64) -> Self {
+        Self {
+            threshold,
+            group_by,
+        }
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    pub fn name(&self) -> String {
+        "UniqueWithinGroup".to_string()
+    }
+
+    /// Column holding the group key
+    pub fn group_by(&self) -> &str {
+        &self.group_by
+    }
+
+    /// Hash the `(key, value)` pair of each row, returns the number of rows hashed and the
+    /// distinct pair hashes.
+    ///
+    /// `values` is the casted array of the column. Keys are compared as text, rows with a
+    /// null key or a null value are skipped.
+    pub fn validate(
+        &self,
+        keys: &dyn Array,
+        values: &dyn Array,
+    ) -> Result<(usize, HashSet<u64, Xxh3Builder>), RuleError> {
+        let keys = compute::cast(keys, &DataType::Utf8)?;
+        let keys = keys.as_string::<i32>();
+        let mut checked = 0;
+        let mut local_hash = HashSet::with_capacity_and_hasher(values.len(), Xxh3Builder);
+        for_each_row_hash(values, |row, hash| {
+            if keys.is_valid(row) {
+                checked += 1;
+                local_hash.insert(xxh3_64_with_seed(keys.value(row).as_bytes(), hash));
+            }
+            true
+        });
+        Ok((checked, local_hash))
+    }
+}
+
+/// Column-level sanity rules, evaluated once every batch was seen.
+///
+/// They report a single pass/fail result: one error when the column fails.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SanityCheck {
+    /// At least one value of the column is not null
+    NotAllNull,
+    /// The column holds at least two distinct non-null values
+    NotAllSame,
+    /// The share of null values of the column lies within `[min_ratio, max_ratio]`
+    NullRatioBetween { min_ratio: f64, max_ratio: f64 },
+    /// The estimated number of distinct non-null values lies within `[min, max]`
+    DistinctCountBetween { min: usize, max: usize },
+}
+
+/// Share of null values among `rows`, `None` for a column without rows.
+fn null_ratio(rows: usize, values: usize) -> Option<f64> {
+    (rows > 0).then(|| (rows - values) as f64 / rows as f64)
+}
+
+impl SanityCheck {
+    pub fn name(&self) -> String {
+        match self {
+            SanityCheck::NotAllNull => "NotAllNull".to_string(),
+            SanityCheck::NotAllSame => "NotAllSame".to_string(),
+            SanityCheck::NullRatioBetween { .. } => "NullRatioBetween".to_string(),
+            SanityCheck::DistinctCountBetween { .. } => "DistinctCountBetween".to_string(),
+        }
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns 1 when the column fails, given its number of `rows`, its non-null `values`
+    /// count and the number of `distinct` values met. `distinct` is capped at
+    /// [`SANITY_DISTINCT_LIMIT`] unless the column counts its distinct values, see
+    /// [`SanityCheck::counts_distinct`].
+    pub fn validate(&self, rows: usize, values: usize, distinct: usize) -> usize {
+        let pass = match self {
+            SanityCheck::NotAllNull => values > 0,
+            SanityCheck::NotAllSame => distinct >= SANITY_DISTINCT_LIMIT,
+            SanityCheck::NullRatioBetween {
+                min_ratio,
+                max_ratio,
+            } => null_ratio(rows, values)
+                .is_none_or(|ratio| (*min_ratio..=*max_ratio).contains(&ratio)),
+            SanityCheck::DistinctCountBetween { min, max } => (*min..=*max).contains(&distinct),
+        };
+        usize::from(!pass)
+    }
+
+    /// Whether the rule needs the distinct count estimate of the column rather than the
+    /// first [`SANITY_DISTINCT_LIMIT`] distinct values.
+    pub fn counts_distinct(&self) -> bool {
+        matches!(self, SanityCheck::DistinctCountBetween { .. })
+    }
+
+    /// Values computed by the rule, reported with its result.
+    pub fn observed(&self, rows: usize, values: usize, distinct: usize) -> Vec<(String, f64)> {
+        match self {
+            SanityCheck::NullRatioBetween { .. } => null_ratio(rows, values)
+                .map(|ratio| vec![("null_ratio".to_string(), ratio)])
+                .unwrap_or_default(),
+            SanityCheck::DistinctCountBetween { .. } => {
+                vec![("distinct_count".to_string(), distinct as f64)]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A rule to check that every value equals an expected constant, such as a schema
+/// version.
+///
+/// Strings are compared exactly, numbers with the equality of their native type.
+/// Null values are not counted, use a null check to forbid them.
+pub struct EqualsConstant<V> {
+    name: String,
+    threshold: f64,
+    expected: V,
+}
+
+impl<V> EqualsConstant<V> {
+    pub fn new(name: String, threshold: f64, expected: V) -> Self {
+        Self {
+            name,
+            threshold,
+            expected,
+        }
+    }
+}
+
+impl StringRule for EqualsConstant<String> {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| *v != self.expected)
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| v != self.expected)
+    }
+}
+
+impl<T, N> NumericRule<T> for EqualsConstant<N>
+where
+    T: ArrowNumericType<Native = N>,
+    N: PartialEq + Copy + Send + Sync,
+{
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &PrimitiveArray<T>, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| *v != self.expected)
+            .count())
+    }
+
+    fn failing_rows(&self, array: &PrimitiveArray<T>, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| v != self.expected)
+    }
+
+    fn validate_with_stats(&self, _array: &PrimitiveArray<T>, _stats: &Stats) -> usize {
+        // Only statistical rules are validated with the column stats
+        unreachable!()
+    }
+}
+
+/// Two distinct values are enough to tell a column is not constant
+pub const SANITY_DISTINCT_LIMIT: usize = 2;
+
+/// Call `f` with the hash of every non-null value of a casted array, the way the
+/// unicity check hashes them, until it returns `false`. Arrays of an unexpected type
+/// yield no hash.
+pub(crate) fn for_each_hash(array: &dyn Array, mut f: impl FnMut(u64) -> bool) {
+    for_each_row_hash(array, |_, hash| f(hash))
+}
+
+/// Like [`for_each_hash`], `f` also receives the row of each value within the array.
+pub(crate) fn for_each_row_hash(array: &dyn Array, mut f: impl FnMut(usize, u64) -> bool) {
+    fn feed(hashes: impl Iterator<Item = Option<u64>>, f: &mut impl FnMut(usize, u64) -> bool) {
+        for (row, hash) in hashes.enumerate() {
+            if let Some(hash) = hash {
+                if !f(row, hash) {
+                    break;
+                }
+            }
+        }
+    }
+    fn primitive<T: ArrowPrimitiveType>(array: &dyn Array, f: &mut impl FnMut(usize, u64) -> bool) {
+        let array = array.as_primitive::<T>();
+        feed(
+            array.iter().map(|v| v.map(|v| xxh3_64(v.to_byte_slice()))),
+            f,
+        )
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => feed(
+            array
+                .as_string::<i32>()
+                .iter()
+                .map(|v| v.map(|v| xxh3_64(v.as_bytes()))),
+            &mut f,
+        ),
+        DataType::Boolean => feed(array.as_boolean().iter().map(|v| v.map(u64::from)), &mut f),
+        DataType::Int64 => primitive::<Int64Type>(array, &mut f),
+        DataType::Float64 => primitive::<Float64Type>(array, &mut f),
+        DataType::Date32 => primitive::<Date32Type>(array, &mut f),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            primitive::<TimestampMillisecondType>(array, &mut f)
+        }
+        _ => {}
+    }
+}
+
+/// Hash up to `limit` distinct non-null values of a casted array, see [`for_each_hash`].
+pub(crate) fn distinct_hashes(array: &dyn Array, limit: usize) -> HashSet<u64, Xxh3Builder> {
+    let mut distinct = HashSet::with_hasher(Xxh3Builder);
+    for_each_hash(array, |hash| {
+        distinct.insert(hash);
+        distinct.len() < limit
+    });
+    distinct
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use arrow_array::Int64Array;
     use rayon::prelude::*;
 
     // DashSet and Arc are no longer directly used in tests for UnicityCheck
     // as the validate method now returns a HashSet.
 
+    #[test]
+    fn test_grouped_unicity() {
+        let rule = GroupedUnicityCheck::new("tenant_id".to_string(), 0.0);
+        let keys = StringArray::from(vec![Some("t1"), Some("t2"), Some("t1"), None, Some("t1")]);
+        let values = StringArray::from(vec![Some("a"), Some("a"), Some("a"), Some("a"), None]);
+
+        let (checked, local_set) = rule.validate(&keys, &values).unwrap();
+        // The null key and the null value are skipped
+        assert_eq!(checked, 3);
+        // `a` is duplicated within `t1` only
+        assert_eq!(local_set.len(), 2);
+
+        // Keys of any type are compared as text
+        let keys = Int64Array::from(vec![1, 2, 1]);
+        let values = Int64Array::from(vec![7, 7, 8]);
+        let (checked, local_set) = rule.validate(&keys, &values).unwrap();
+        assert_eq!((checked, local_set.len()), (3, 3));
+    }
+
     #[test]
     fn test_unicity_sequential_happy() {
         let rule = UnicityCheck::new(0.0);
@@ -237,4 +573,92 @@ mod tests {
         assert!(local_set.contains(&xxh3_64("a".as_bytes())));
         assert!(local_set.contains(&xxh3_64("b".as_bytes())));
     }
+
+    #[test]
+    fn test_unicity_normalize() {
+        let array = StringArray::from(vec![Some("Foo@x.com"), Some(" foo@x.com "), None]);
+        let distinct = |mode| {
+            UnicityCheck::new(0.0)
+                .with_normalize(mode)
+                .validate_str(&array)
+        };
+        assert_eq!(distinct(NormalizeMode::None).1.len(), 2);
+        assert_eq!(distinct(NormalizeMode::Lowercase).1.len(), 2);
+        assert_eq!(distinct(NormalizeMode::Trim).1.len(), 2);
+        let (null_count, local_set) = distinct(NormalizeMode::TrimLowercase);
+        assert_eq!(local_set.len(), 1);
+        assert_eq!(null_count, 1);
+        assert!(local_set.contains(&xxh3_64("foo@x.com".as_bytes())));
+
+        assert_eq!(parse_normalize_mode("trim").unwrap(), NormalizeMode::Trim);
+        assert!(parse_normalize_mode("upper").is_err());
+    }
+
+    #[test]
+    fn test_sanity_checks() {
+        let array = StringArray::from(vec![None, Some("a"), Some("a"), Some("b"), Some("c")]);
+        assert_eq!(distinct_hashes(&array, SANITY_DISTINCT_LIMIT).len(), 2);
+
+        let constant = Int64Array::from(vec![Some(3), None, Some(3)]);
+        let distinct = distinct_hashes(&constant, SANITY_DISTINCT_LIMIT).len();
+        assert_eq!(distinct, 1);
+        assert_eq!(SanityCheck::NotAllSame.validate(3, 2, distinct), 1);
+        assert_eq!(SanityCheck::NotAllNull.validate(3, 2, distinct), 0);
+        assert_eq!(SanityCheck::NotAllNull.validate(3, 0, 0), 1);
+        assert_eq!(SanityCheck::NotAllSame.validate(5, 5, 2), 0);
+    }
+
+    #[test]
+    fn test_null_ratio_between() {
+        let rule = SanityCheck::NullRatioBetween {
+            min_ratio: 0.01,
+            max_ratio: 0.05,
+        };
+        assert_eq!(rule.validate(100, 97, 0), 0);
+        assert_eq!(
+            rule.observed(100, 97, 0),
+            vec![("null_ratio".to_string(), 0.03)]
+        );
+        // Too many nulls, or suspiciously none
+        assert_eq!(rule.validate(100, 90, 0), 1);
+        assert_eq!(rule.validate(100, 100, 0), 1);
+        // A column without rows has no ratio
+        assert_eq!(rule.validate(0, 0, 0), 0);
+        assert!(rule.observed(0, 0, 0).is_empty());
+        assert!(SanityCheck::NotAllNull.observed(100, 97, 0).is_empty());
+    }
+
+    #[test]
+    fn test_distinct_count_between() {
+        let rule = SanityCheck::DistinctCountBetween { min: 1, max: 250 };
+        assert!(rule.counts_distinct());
+        assert!(!SanityCheck::NotAllSame.counts_distinct());
+        assert_eq!(rule.validate(1000, 1000, 249), 0);
+        assert_eq!(rule.validate(1000, 1000, 250), 0);
+        assert_eq!(
+            rule.observed(1000, 1000, 249),
+            vec![("distinct_count".to_string(), 249.0)]
+        );
+        // A sudden jump in cardinality, or an all null column
+        assert_eq!(rule.validate(100_000, 100_000, 50_000), 1);
+        assert_eq!(rule.validate(1000, 0, 0), 1);
+    }
+
+    #[test]
+    fn test_equals_constant() {
+        let versions = StringArray::from(vec![Some("v3"), Some("v2"), None, Some("V3")]);
+        let rule = EqualsConstant::new("Equals".to_string(), 0.0, "v3".to_string());
+        assert_eq!(rule.validate(&versions, "version".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&versions, 10), vec![1, 3]);
+
+        let rule = EqualsConstant::new("Equals".to_string(), 0.0, 1i64);
+        let flags = Int64Array::from(vec![1, 1, 2, 1]);
+        assert_eq!(rule.validate(&flags, "flag".to_string()).unwrap(), 1);
+        let flags = Int64Array::from(vec![Some(1), None, Some(0)]);
+        assert_eq!(rule.validate(&flags, "flag".to_string()).unwrap(), 1);
+        assert_eq!(
+            NumericRule::<Int64Type>::failing_rows(&rule, &flags, 10),
+            vec![2]
+        );
+    }
 }
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use crate::results::GroupMismatch;
+
+/// Compare per-group sums against expected totals.
+pub struct GroupSumCheck {
+    expected: HashMap<String, f64>,
+    tolerance: f64,
+    threshold: f64,
+}
+
+impl GroupSumCheck {
+    pub fn new(expected: HashMap<String, f64>, tolerance: f64, threshold: f64) -> Self {
+        Self {
+            expected,
+            tolerance,
+            threshold,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        "GroupSum".to_string()
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Returns the number of groups compared and the groups outside of tolerance.
+    ///
+    /// Groups missing from the data are compared with a sum of 0, groups missing
+    /// from the lookup are compared with an expected total of 0.
+    pub fn validate(&self, sums: &HashMap<String, f64>) -> (usize, Vec<GroupMismatch>) {
+        let mut groups: Vec<&String> = self.expected.keys().chain(sums.keys()).collect();
+        groups.sort();
+        groups.dedup();
+
+        let mismatches = groups
+            .iter()
+            .filter_map(|group| {
+                let expected = self.expected.get(*group).copied().unwrap_or(0.);
+                let actual = sums.get(*group).copied().unwrap_or(0.);
+                if (actual - expected).abs() > self.tolerance {
+                    Some(GroupMismatch::new(group.to_string(), expected, actual))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        (groups.len(), mismatches)
+    }
+}
+
+/// Compare the number of rows of each group against optional bounds.
+pub struct GroupCountCheck {
+    min: Option<usize>,
+    max: Option<usize>,
+    threshold: f64,
+}
+
+impl GroupCountCheck {
+    pub fn new(min: Option<usize>, max: Option<usize>, threshold: f64) -> Self {
+        Self {
+            min,
+            max,
+            threshold,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        "GroupCount".to_string()
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Returns the number of groups found and the groups outside of the bounds.
+    ///
+    /// Mismatches are sorted by group key, their expected value is the bound crossed.
+    pub fn validate(&self, counts: &HashMap<String, usize>) -> (usize, Vec<GroupMismatch>) {
+        let mut mismatches: Vec<GroupMismatch> = counts
+            .iter()
+            .filter_map(|(group, count)| {
+                let bound = match (self.min, self.max) {
+                    (Some(min), _) if *count < min => min,
+                    (_, Some(max)) if *count > max => max,
+                    _ => return None,
+                };
+                Some(GroupMismatch::new(
+                    group.clone(),
+                    bound as f64,
+                    *count as f64,
+                ))
+            })
+            .collect();
+        mismatches.sort_by(|a, b| a.group.cmp(&b.group));
+        (counts.len(), mismatches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected() -> HashMap<String, f64> {
+        HashMap::from([("north".to_string(), 100.), ("south".to_string(), 50.)])
+    }
+
+    #[test]
+    fn test_group_sum_all_matching() {
+        let rule = GroupSumCheck::new(expected(), 0.01, 0.);
+        let sums = HashMap::from([("north".to_string(), 100.), ("south".to_string(), 50.001)]);
+        let (groups, mismatches) = rule.validate(&sums);
+        assert_eq!(groups, 2);
+        assert!(mismatches.is_empty());
+    }
+
+    #[test]
+    fn test_group_sum_mismatch() {
+        let rule = GroupSumCheck::new(expected(), 0.01, 0.);
+        let sums = HashMap::from([("north".to_string(), 90.), ("south".to_string(), 50.)]);
+        let (groups, mismatches) = rule.validate(&sums);
+        assert_eq!(groups, 2);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].group, "north");
+        assert_eq!(mismatches[0].delta(), -10.);
+    }
+
+    #[test]
+    fn test_group_sum_missing_and_unexpected_groups() {
+        let rule = GroupSumCheck::new(expected(), 0.01, 0.);
+        let sums = HashMap::from([("north".to_string(), 100.), ("east".to_string(), 5.)]);
+        let (groups, mismatches) = rule.validate(&sums);
+        assert_eq!(groups, 3);
+        assert_eq!(mismatches.len(), 2);
+        // Sorted by group key
+        assert_eq!(mismatches[0].group, "east");
+        assert_eq!(mismatches[0].expected, 0.);
+        assert_eq!(mismatches[1].group, "south");
+        assert_eq!(mismatches[1].actual, 0.);
+    }
+
+    #[test]
+    fn test_group_count_bounds() {
+        let counts = HashMap::from([
+            ("north".to_string(), 12),
+            ("south".to_string(), 11),
+            ("east".to_string(), 13),
+        ]);
+        let rule = GroupCountCheck::new(Some(12), Some(12), 0.);
+        let (groups, mismatches) = rule.validate(&counts);
+        assert_eq!(groups, 3);
+        assert_eq!(mismatches.len(), 2);
+        assert_eq!(mismatches[0].group, "east");
+        assert_eq!(mismatches[0].delta(), 1.);
+        assert_eq!(mismatches[1].group, "south");
+        assert_eq!(mismatches[1].expected, 12.);
+        assert_eq!(mismatches[1].actual, 11.);
+
+        let rule = GroupCountCheck::new(None, Some(12), 0.);
+        let (_, mismatches) = rule.validate(&counts);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].group, "east");
+    }
+}
@@ -1,10 +1,47 @@
+pub mod boolean;
 pub mod date;
 pub mod generic;
+pub mod group;
 pub mod numeric;
+pub mod reference;
 pub mod relations;
 pub mod string;
+pub mod timestamp;
 
-pub use date::{DateBoundaryCheck, DateRule, WeekDayCheck};
-pub use generic::{NullCheck, TypeCheck, UnicityCheck};
-pub use numeric::{Monotonicity, NumericRule, Range};
-pub use string::{IsInCheck, RegexMatch, StringLengthCheck, StringRule};
+pub use boolean::{BooleanRule, BooleanTypeCheck, ExpectedValueCheck, TrueRatioCheck};
+pub use date::{
+    AgeCheck, DateBoundaryCheck, DateRangeCheck, DateRule, DayOfMonthCheck, DayOfMonthMode,
+    WeekDayCheck,
+};
+pub use generic::{
+    parse_normalize_mode, EqualsConstant, GroupedUnicityCheck, NormalizeMode, NullCheck,
+    SanityCheck, TypeCheck, UnicityCheck,
+};
+pub use group::{GroupCountCheck, GroupSumCheck};
+pub use numeric::{
+    FiniteCheck, MaxDecimalPlacesCheck, MembersCheck, Monotonicity, MultipleOfCheck, NumericRule,
+    Range,
+};
+pub use reference::{ColumnKeys, ForeignKeyCheck, ForeignKeyOutcome};
+pub use string::{
+    Base64Check, CaseCheck, ConsistentLengthCheck, ContainsCheck, ContainsOnly, CreditCardCheck,
+    EndsWithCheck, HexCheck, IbanCheck, IpCheck, IpInCidr, IpVersion, IsInCheck, IsNotInCheck,
+    JsonCheck, JsonKind, LengthMode, MatchesAny, NoSurroundingWhitespace, NoWhitespace, PhoneCheck,
+    RegexMatch, StartsWithCheck, StringLengthCheck, StringRule,
+};
+pub use timestamp::{TimeOfDayCheck, TimestampBoundaryCheck, TimestampRule, TimestampTypeCheck};
+
+/// Positions of the first `limit` non-null values matching `is_violation`.
+///
+/// Shared by the rules' `failing_rows` implementations to report samples.
+pub(crate) fn first_failing_rows<V: Copy>(
+    values: impl Iterator<Item = Option<V>>,
+    limit: usize,
+    is_violation: impl Fn(V) -> bool,
+) -> Vec<usize> {
+    values
+        .enumerate()
+        .filter_map(|(i, v)| v.filter(|v| is_violation(*v)).map(|_| i))
+        .take(limit)
+        .collect()
+}
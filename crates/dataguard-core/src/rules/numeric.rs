@@ -1,10 +1,14 @@
 use arrow::array::Array;
-use arrow_array::{ArrowNumericType, PrimitiveArray};
+use arrow::util::display::array_value_to_string;
+use arrow_array::{
+    types::{Float64Type, Int64Type},
+    ArrowNumericType, PrimitiveArray,
+};
 use arrow_ord::cmp::{gt, lt};
 use num_traits::Num;
 use std::{fmt::Debug, marker::PhantomData};
 
-use crate::{columns::NumericType, engine::Stats, errors::RuleError};
+use crate::{columns::NumericType, engine::Stats, errors::RuleError, rules::first_failing_rows};
 
 pub trait NumericRule<T: ArrowNumericType>: Send + Sync {
     /// Returns the name of the rule.
@@ -15,6 +19,23 @@ pub trait NumericRule<T: ArrowNumericType>: Send + Sync {
     fn validate(&self, array: &PrimitiveArray<T>, column: String) -> Result<usize, RuleError>;
     /// Validates an Arrow `Array`, by statistics
     fn validate_with_stats(&self, array: &PrimitiveArray<T>, stats: &Stats) -> usize;
+    /// Returns the positions of the first `limit` failing values, used for reporting samples.
+    fn failing_rows(&self, _array: &PrimitiveArray<T>, _limit: usize) -> Vec<usize> {
+        Vec::new()
+    }
+    /// `Some(ascending)` for an order rule, whose batch boundaries are also checked once
+    /// every batch was validated.
+    fn ascending_order(&self) -> Option<bool> {
+        None
+    }
+    /// Text reported for a failing row, its value unless the rule needs more context.
+    fn sample_value(&self, array: &PrimitiveArray<T>, row: usize) -> String {
+        array_value_to_string(array, row).unwrap_or_default()
+    }
+    /// Returns the column level values computed by a statistical rule, reported with its result.
+    fn observed(&self, _stats: &Stats) -> Vec<(String, f64)> {
+        Vec::new()
+    }
 }
 
 pub struct Range<N: Num + PartialOrd + Copy + Debug> {
@@ -22,20 +43,52 @@ pub struct Range<N: Num + PartialOrd + Copy + Debug> {
     threshold: f64,
     min: Option<N>,
     max: Option<N>,
+    min_inclusive: bool,
+    max_inclusive: bool,
 }
 
 impl<N> Range<N>
 where
     N: Num + PartialOrd + Copy + Debug,
 {
+    /// Both bounds are inclusive, see [`Range::with_inclusive`]
     pub fn new(name: String, threshold: f64, min: Option<N>, max: Option<N>) -> Self {
         Self {
             name,
             threshold,
             min,
             max,
+            min_inclusive: true,
+            max_inclusive: true,
         }
     }
+
+    /// Whether values equal to `min` (`max`) are valid
+    pub fn with_inclusive(mut self, min_inclusive: bool, max_inclusive: bool) -> Self {
+        self.min_inclusive = min_inclusive;
+        self.max_inclusive = max_inclusive;
+        self
+    }
+
+    fn below_min(&self, value: N) -> bool {
+        self.min.is_some_and(|min| {
+            if self.min_inclusive {
+                value < min
+            } else {
+                value <= min
+            }
+        })
+    }
+
+    fn above_max(&self, value: N) -> bool {
+        self.max.is_some_and(|max| {
+            if self.max_inclusive {
+                value > max
+            } else {
+                value >= max
+            }
+        })
+    }
 }
 
 impl<T, N> NumericRule<T> for Range<N>
@@ -56,15 +109,11 @@ where
         for value in array.iter() {
             match value {
                 Some(i) => {
-                    if let Some(min) = self.min {
-                        if i < min {
-                            counter += 1
-                        }
+                    if self.below_min(i) {
+                        counter += 1
                     }
-                    if let Some(max) = self.max {
-                        if i > max {
-                            counter += 1
-                        }
+                    if self.above_max(i) {
+                        counter += 1
                     }
                 }
                 None => counter += 0, // Null doesnt count as error
@@ -73,6 +122,12 @@ where
         Ok(counter)
     }
 
+    fn failing_rows(&self, array: &PrimitiveArray<T>, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |i| {
+            self.below_min(i) || self.above_max(i)
+        })
+    }
+
     fn validate_with_stats(&self, _array: &PrimitiveArray<T>, _stats: &Stats) -> usize {
         // We should never call this method on this rule
         // It it's happen we panic and fix this case
@@ -146,6 +201,41 @@ where
         Ok(violation)
     }
 
+    /// A failing row is one breaking the order relative to its predecessor.
+    fn failing_rows(&self, array: &PrimitiveArray<T>, limit: usize) -> Vec<usize> {
+        let pairs = array
+            .iter()
+            .zip(array.iter().skip(1))
+            .map(|(prev, next)| match (prev, next) {
+                (Some(prev), Some(next)) => Some((prev, next)),
+                _ => None,
+            });
+        first_failing_rows(pairs, limit, |(prev, next)| match self.asc {
+            true => next < prev,
+            false => next > prev,
+        })
+        .into_iter()
+        .map(|i| i + 1)
+        .collect()
+    }
+
+    fn ascending_order(&self) -> Option<bool> {
+        Some(self.asc)
+    }
+
+    /// The value breaking the order is reported with its predecessor.
+    fn sample_value(&self, array: &PrimitiveArray<T>, row: usize) -> String {
+        let value = array_value_to_string(array, row).unwrap_or_default();
+        match row {
+            0 => value,
+            _ => format!(
+                "{} (previous {})",
+                value,
+                array_value_to_string(array, row - 1).unwrap_or_default()
+            ),
+        }
+    }
+
     fn validate_with_stats(&self, _array: &PrimitiveArray<T>, _stats: &Stats) -> usize {
         // We should never call this method on this rule
         // It it's happen we panic and fix this case
@@ -153,6 +243,218 @@ where
     }
 }
 
+/// Checks that values belong (or, when negated, do not belong) to a set of members.
+///
+/// Membership uses exact equality on the native type. For float columns this means
+/// `0.1 + 0.2` is not a member of `{0.3}` and `NaN` never matches, round values
+/// before validating or prefer a range rule when values come from computations.
+pub struct MembersCheck<N> {
+    name: String,
+    threshold: f64,
+    members: Vec<N>,
+    negate: bool,
+}
+
+impl<N: PartialEq> MembersCheck<N> {
+    pub fn new(name: String, threshold: f64, members: Vec<N>, negate: bool) -> Self {
+        Self {
+            name,
+            threshold,
+            members,
+            negate,
+        }
+    }
+
+    fn is_violation(&self, value: &N) -> bool {
+        self.members.contains(value) == self.negate
+    }
+}
+
+impl<T, N> NumericRule<T> for MembersCheck<N>
+where
+    T: ArrowNumericType<Native = N>,
+    N: PartialEq + Copy + Send + Sync,
+{
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &PrimitiveArray<T>, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| self.is_violation(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &PrimitiveArray<T>, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| self.is_violation(&v))
+    }
+
+    fn validate_with_stats(&self, _array: &PrimitiveArray<T>, _stats: &Stats) -> usize {
+        // We should never call this method on this rule
+        // It it's happen we panic and fix this case
+        unreachable!()
+    }
+}
+
+/// Checks that integer values are divisible by `divisor`, nulls are skipped.
+pub struct MultipleOfCheck {
+    name: String,
+    threshold: f64,
+    divisor: i64,
+}
+
+impl MultipleOfCheck {
+    /// `divisor` must not be zero, the builder rejects it beforehand.
+    pub fn new(name: String, threshold: f64, divisor: i64) -> Self {
+        Self {
+            name,
+            threshold,
+            divisor,
+        }
+    }
+}
+
+impl NumericRule<Int64Type> for MultipleOfCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(
+        &self,
+        array: &PrimitiveArray<Int64Type>,
+        _column: String,
+    ) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| v % self.divisor != 0)
+            .count())
+    }
+
+    fn failing_rows(&self, array: &PrimitiveArray<Int64Type>, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| v % self.divisor != 0)
+    }
+
+    fn validate_with_stats(&self, _array: &PrimitiveArray<Int64Type>, _stats: &Stats) -> usize {
+        // We should never call this method on this rule
+        // It it's happen we panic and fix this case
+        unreachable!()
+    }
+}
+
+/// Flags `NaN` and infinite values, nulls are skipped.
+pub struct FiniteCheck {
+    name: String,
+    threshold: f64,
+}
+
+impl FiniteCheck {
+    pub fn new(name: String, threshold: f64) -> Self {
+        Self { name, threshold }
+    }
+}
+
+impl NumericRule<Float64Type> for FiniteCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(
+        &self,
+        array: &PrimitiveArray<Float64Type>,
+        _column: String,
+    ) -> Result<usize, RuleError> {
+        Ok(array.iter().flatten().filter(|v| !v.is_finite()).count())
+    }
+
+    fn failing_rows(&self, array: &PrimitiveArray<Float64Type>, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !v.is_finite())
+    }
+
+    fn validate_with_stats(&self, _array: &PrimitiveArray<Float64Type>, _stats: &Stats) -> usize {
+        // We should never call this method on this rule
+        // It it's happen we panic and fix this case
+        unreachable!()
+    }
+}
+
+/// Relative tolerance of `MaxDecimalPlacesCheck`, a few ulps of the scaled value.
+///
+/// Most decimals have no exact binary representation, `0.1 * 100.0` is `10.000000000000002`,
+/// so a value is only flagged when it is further than this from its rounded scaled value.
+/// Beyond roughly 15 significant digits a double can not tell the decimals apart anymore,
+/// large values with many places pass the check whatever their digits.
+pub const DECIMAL_PLACES_EPSILON: f64 = 4.0 * f64::EPSILON;
+
+/// Flags values with more than `places` decimals, nulls, `NaN` and infinite values are skipped.
+pub struct MaxDecimalPlacesCheck {
+    name: String,
+    threshold: f64,
+    factor: f64,
+}
+
+impl MaxDecimalPlacesCheck {
+    pub fn new(name: String, threshold: f64, places: u32) -> Self {
+        Self {
+            name,
+            threshold,
+            factor: 10f64.powi(places as i32),
+        }
+    }
+
+    fn has_more_places(&self, value: f64) -> bool {
+        let scaled = value * self.factor;
+        // `NaN` and infinite values compare false, they are left to `IsFinite`
+        (scaled - scaled.round()).abs() > DECIMAL_PLACES_EPSILON * scaled.abs().max(1.0)
+    }
+}
+
+impl NumericRule<Float64Type> for MaxDecimalPlacesCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(
+        &self,
+        array: &PrimitiveArray<Float64Type>,
+        _column: String,
+    ) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| self.has_more_places(*v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &PrimitiveArray<Float64Type>, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| self.has_more_places(v))
+    }
+
+    fn validate_with_stats(&self, _array: &PrimitiveArray<Float64Type>, _stats: &Stats) -> usize {
+        // We should never call this method on this rule
+        // It it's happen we panic and fix this case
+        unreachable!()
+    }
+}
+
 pub struct StdDevCheck<N: NumericType> {
     name: String,
     threshold: f64,
@@ -254,9 +556,130 @@ where
     }
 }
 
+/// Values must lie within `num_std` standard deviations of the column mean.
+///
+/// Mean and standard deviation come from the stats pass over all batches, values
+/// are then compared batch by batch. A zero standard deviation reports no outlier.
+pub struct ZScoreOutlierCheck<N: NumericType> {
+    name: String,
+    threshold: f64,
+    num_std: f64,
+    _phantom: PhantomData<N>,
+}
+
+impl<N: NumericType> ZScoreOutlierCheck<N> {
+    pub fn new(name: String, threshold: f64, num_std: f64) -> Self {
+        Self {
+            name,
+            threshold,
+            num_std,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, N> NumericRule<T> for ZScoreOutlierCheck<N>
+where
+    T: ArrowNumericType<Native = N>,
+    N: NumericType,
+{
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, _array: &PrimitiveArray<T>, _column: String) -> Result<usize, RuleError> {
+        unreachable!()
+    }
+
+    fn validate_with_stats(&self, array: &PrimitiveArray<T>, stats: &Stats) -> usize {
+        let mean = stats.mean();
+        let std_dev = stats.std_dev();
+        if std_dev == 0. {
+            return 0;
+        }
+        array
+            .iter()
+            .flatten()
+            .filter(|v| (v.to_f64() - mean).abs() / std_dev > self.num_std)
+            .count()
+    }
+
+    fn observed(&self, stats: &Stats) -> Vec<(String, f64)> {
+        vec![
+            ("mean".to_string(), stats.mean()),
+            ("std_dev".to_string(), stats.std_dev()),
+        ]
+    }
+}
+
+/// The `quantile` of the column must be at most `bound`.
+///
+/// The quantile comes from the approximate sketch of the column stats. When it
+/// exceeds `bound`, the values above `bound` are counted as errors.
+pub struct PercentileCheck<N: NumericType> {
+    name: String,
+    threshold: f64,
+    quantile: f64,
+    bound: f64,
+    _phantom: PhantomData<N>,
+}
+
+impl<N: NumericType> PercentileCheck<N> {
+    pub fn new(name: String, threshold: f64, quantile: f64, bound: f64) -> Self {
+        Self {
+            name,
+            threshold,
+            quantile,
+            bound,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, N> NumericRule<T> for PercentileCheck<N>
+where
+    T: ArrowNumericType<Native = N>,
+    N: NumericType,
+{
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, _array: &PrimitiveArray<T>, _column: String) -> Result<usize, RuleError> {
+        unreachable!()
+    }
+
+    fn validate_with_stats(&self, array: &PrimitiveArray<T>, stats: &Stats) -> usize {
+        match stats.quantile(self.quantile) {
+            Some(value) if value > self.bound => array
+                .iter()
+                .flatten()
+                .filter(|v| v.to_f64() > self.bound)
+                .count(),
+            _ => 0,
+        }
+    }
+
+    fn observed(&self, stats: &Stats) -> Vec<(String, f64)> {
+        stats
+            .quantile(self.quantile)
+            .map(|v| vec![("quantile".to_string(), v)])
+            .unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::stats_accumulator::QuantileSketch;
     use arrow::array::Int64Array;
     use arrow_array::Float64Array;
 
@@ -292,6 +715,20 @@ mod tests {
         assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
     }
 
+    #[test]
+    fn test_range_exclusive_bounds() {
+        let array = Int64Array::from(vec![Some(0), Some(1), Some(5), None, Some(10)]);
+        let rule = Range::new("range_test".to_string(), 0.0, Some(0i64), Some(10i64))
+            .with_inclusive(false, false);
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 10), vec![0, 4]);
+
+        let rule = Range::new("range_test".to_string(), 0.0, Some(0i64), Some(10i64))
+            .with_inclusive(true, false);
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+        assert_eq!(rule.failing_rows(&array, 10), vec![4]);
+    }
+
     #[test]
     fn test_range_between_integer_with_null() {
         let rule = Range::new("range_test".to_string(), 0.0, Some(2i64), Some(4i64));
@@ -322,6 +759,20 @@ mod tests {
         assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
     }
 
+    #[test]
+    fn test_monotonicity_samples() {
+        let rule = Monotonicity::<i64>::default();
+        let array = Int64Array::from(vec![Some(1), Some(5), Some(4), None, Some(3), Some(10)]);
+        // Rows next to a null are not compared
+        assert_eq!(NumericRule::failing_rows(&rule, &array, 10), vec![2]);
+        assert_eq!(rule.sample_value(&array, 2), "4 (previous 5)");
+
+        let rule = Monotonicity::<i64>::new("IsDecreasing".to_string(), 0.0, false);
+        let array = Int64Array::from(vec![10, 3, 4, 5, 1]);
+        assert_eq!(NumericRule::failing_rows(&rule, &array, 1), vec![2]);
+        assert_eq!(rule.sample_value(&array, 3), "5 (previous 4)");
+    }
+
     #[test]
     fn test_monotonicity_desc_valid() {
         let rule = Monotonicity::<i64>::new("monotonicity_test".to_string(), 0.0, false);
@@ -370,6 +821,64 @@ mod tests {
         assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 1);
     }
 
+    #[test]
+    fn test_members_integer() {
+        let rule = MembersCheck::new("IsIn".to_string(), 0.0, vec![200i64, 301, 404, 500], false);
+        let array = Int64Array::from(vec![Some(200), Some(302), None, Some(500), Some(418)]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 5), vec![1, 4]);
+    }
+
+    #[test]
+    fn test_members_float_negated() {
+        let rule = MembersCheck::new("NotIn".to_string(), 0.0, vec![0.0f64, -1.0], true);
+        let array = Float64Array::from(vec![Some(0.0), Some(0.5), Some(-1.0), Some(f64::NAN)]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_multiple_of() {
+        let rule = MultipleOfCheck::new("IsMultipleOf".to_string(), 0.0, 12);
+        let array = Int64Array::from(vec![Some(12), Some(24), Some(30), None, Some(-36), Some(0)]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 1);
+        assert_eq!(rule.failing_rows(&array, 5), vec![2]);
+    }
+
+    #[test]
+    fn test_finite_check() {
+        let rule = FiniteCheck::new("IsFinite".to_string(), 0.0);
+        let array = Float64Array::from(vec![
+            Some(1.5),
+            Some(f64::NAN),
+            None,
+            Some(f64::INFINITY),
+            Some(f64::NEG_INFINITY),
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 3);
+        assert_eq!(rule.failing_rows(&array, 2), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_max_decimal_places_check() {
+        let rule = MaxDecimalPlacesCheck::new("MaxDecimalPlaces".to_string(), 0.0, 2);
+        let array = Float64Array::from(vec![
+            Some(19.99),
+            Some(0.1),
+            Some(0.125),
+            None,
+            Some(-3.0),
+            Some(1234567.89),
+            Some(0.001),
+            Some(f64::NAN),
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 5), vec![2, 6]);
+
+        let rule = MaxDecimalPlacesCheck::new("MaxDecimalPlaces".to_string(), 0.0, 0);
+        let array = Float64Array::from(vec![Some(42.0), Some(0.5), Some(-7.0)]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 1);
+    }
+
     #[test]
     fn test_int_std_dev_check() {
         let rule = StdDevCheck::new("std_dev_check".to_string(), 0.0, 2.0);
@@ -380,6 +889,7 @@ mod tests {
             m2: 1000.,
             min: 2,
             max: 2,
+            sketch: QuantileSketch::new(),
         };
         // std_dev +/- 10 | fail, pass, fail, pass, _, fail
         let violations = rule.validate_with_stats(&array, &stats);
@@ -404,6 +914,7 @@ mod tests {
             m2: 1000.,
             min: 2.,
             max: 2.,
+            sketch: QuantileSketch::new(),
         };
         // std_dev +/- 10 | fail, pass, fail, pass, _, fail
         let violations = rule.validate_with_stats(&array, &stats);
@@ -429,6 +940,7 @@ mod tests {
             m2: 1000.,
             min: 2,
             max: 2,
+            sketch: QuantileSketch::new(),
         };
         // bound = 9. | fail, pass, fail, fail, _, fail, pass
         let violations = rule.validate_with_stats(&array, &stats);
@@ -454,10 +966,75 @@ mod tests {
             m2: 1000.,
             min: 2.,
             max: 2.,
+            sketch: QuantileSketch::new(),
         };
         // bound = 9. | fail, pass, fail, fail, _, fail, pass
         let violations = rule.validate_with_stats(&array, &stats);
 
         assert_eq!(violations, 4)
     }
+
+    #[test]
+    fn test_zscore_outlier_check() {
+        let rule = ZScoreOutlierCheck::<i64>::new("NoOutliersBeyond".to_string(), 0.0, 2.0);
+        let array = Int64Array::from(vec![Some(90), Some(25), Some(65), Some(35), None, Some(-2)]);
+        let stats = Stats::Integer {
+            count: 10,
+            mean: 45.,
+            m2: 900.,
+            min: -2,
+            max: 90,
+            sketch: QuantileSketch::new(),
+        };
+        // std_dev = 10, |z| > 2 outside (25, 65): 90 and -2, the bounds pass
+        assert_eq!(rule.validate_with_stats(&array, &stats), 2);
+        let observed = NumericRule::<arrow::datatypes::Int64Type>::observed(&rule, &stats);
+        assert_eq!(
+            observed,
+            vec![("mean".to_string(), 45.0), ("std_dev".to_string(), 10.0)]
+        );
+
+        let constant = Stats::Integer {
+            count: 3,
+            mean: 5.,
+            m2: 0.,
+            min: 5,
+            max: 5,
+            sketch: QuantileSketch::new(),
+        };
+        assert_eq!(rule.validate_with_stats(&array, &constant), 0);
+    }
+
+    #[test]
+    fn test_percentile_check() {
+        let rule = PercentileCheck::<i64>::new("PercentileBelow".to_string(), 0.0, 0.9, 80.0);
+        let values: Vec<i64> = (1..=100).collect();
+        let mut sketch = QuantileSketch::new();
+        values.iter().for_each(|v| sketch.add(*v as f64));
+        let stats = Stats::Integer {
+            count: 100,
+            mean: 50.5,
+            m2: 0.,
+            min: 1,
+            max: 100,
+            sketch,
+        };
+        let array = Int64Array::from(values);
+
+        // p90 is ~90, above the bound: 81..=100 are errors
+        assert_eq!(rule.validate_with_stats(&array, &stats), 20);
+        let observed = NumericRule::<arrow::datatypes::Int64Type>::observed(&rule, &stats);
+        assert!((observed[0].1 - 90.0).abs() < 1.0);
+
+        let rule = PercentileCheck::<i64>::new("PercentileBelow".to_string(), 0.0, 0.5, 80.0);
+        assert_eq!(rule.validate_with_stats(&array, &stats), 0);
+    }
+
+    #[test]
+    fn test_monotonicity_failing_rows() {
+        let rule = Monotonicity::<i64>::new("IsIncreasing".to_string(), 0.0, true);
+        let array = Int64Array::from(vec![Some(1), Some(3), Some(2), None, Some(5), Some(4)]);
+        let rows = NumericRule::<arrow::datatypes::Int64Type>::failing_rows(&rule, &array, 5);
+        assert_eq!(rows, vec![2, 5]);
+    }
 }
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use arrow::{
+    array::{ArrayRef, AsArray},
+    compute::cast,
+    datatypes::DataType,
+};
+use arrow_array::Array;
+use xxhash_rust::xxh3::xxh3_64;
+
+use crate::{errors::RuleError, utils::hasher::Xxh3Builder};
+
+/// Number of orphan values kept for reporting
+const MAX_ORPHAN_SAMPLES: usize = 5;
+
+/// Outcome of a foreign key check on the child column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignKeyOutcome {
+    /// Number of non-null values in the child column
+    pub checked: usize,
+    /// Number of child values absent from the parent column
+    pub orphans: usize,
+    /// First distinct orphan values, in reading order
+    pub samples: Vec<String>,
+}
+
+/// Distinct values of a key column, compared on their string representation.
+///
+/// Collected from the batches of the main validation pass, so that foreign keys do not
+/// read their columns a second time.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnKeys {
+    /// Number of non-null values
+    checked: usize,
+    // Value hash → (row of its first occurrence, value, occurrences)
+    values: HashMap<u64, (usize, String, usize), Xxh3Builder>,
+}
+
+impl ColumnKeys {
+    /// Collect the values of a whole column, read in order
+    pub fn from_arrays(arrays: &[ArrayRef]) -> Result<Self, RuleError> {
+        let mut keys = Self::default();
+        let mut offset = 0;
+        for array in arrays {
+            keys.record(array, offset)?;
+            offset += array.len();
+        }
+        Ok(keys)
+    }
+
+    /// Record the values of `array`, whose first value is row `offset` of the column
+    pub fn record(&mut self, array: &ArrayRef, offset: usize) -> Result<(), RuleError> {
+        let array = cast(array, &DataType::Utf8)?;
+        let array = array.as_string::<i32>();
+        self.checked += array.len() - array.null_count();
+        for (row, value) in array.iter().enumerate() {
+            let Some(value) = value else {
+                continue;
+            };
+            self.values
+                .entry(xxh3_64(value.as_bytes()))
+                .or_insert_with(|| (offset + row, value.to_string(), 0))
+                .2 += 1;
+        }
+        Ok(())
+    }
+
+    /// Add the values collected from other rows of the same column
+    pub fn merge(&mut self, other: ColumnKeys) {
+        self.checked += other.checked;
+        for (hash, (row, value, count)) in other.values {
+            let entry = self.values.entry(hash).or_insert((row, value.clone(), 0));
+            if row < entry.0 {
+                entry.0 = row;
+                entry.1 = value;
+            }
+            entry.2 += count;
+        }
+    }
+}
+
+/// Check that every value of a child column exists in a parent column.
+///
+/// Both sides are compared on their string representation, so a CSV key can
+/// reference a typed Parquet key.
+pub struct ForeignKeyCheck {
+    threshold: f64,
+}
+
+impl ForeignKeyCheck {
+    pub fn new(threshold: f64) -> Self {
+        Self { threshold }
+    }
+
+    pub fn name(&self) -> String {
+        "ForeignKey".to_string()
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Collect the keys of `parent` and `child`, then look up every value of `child`.
+    ///
+    /// Null values on the child side are not counted as orphans.
+    pub fn validate(
+        &self,
+        parent: &[ArrayRef],
+        child: &[ArrayRef],
+    ) -> Result<ForeignKeyOutcome, RuleError> {
+        Ok(self.validate_keys(
+            &ColumnKeys::from_arrays(parent)?,
+            &ColumnKeys::from_arrays(child)?,
+        ))
+    }
+
+    /// Look up every key of `child` in the keys of `parent`.
+    pub fn validate_keys(&self, parent: &ColumnKeys, child: &ColumnKeys) -> ForeignKeyOutcome {
+        let mut orphans: Vec<&(usize, String, usize)> = child
+            .values
+            .iter()
+            .filter(|(hash, _)| !parent.values.contains_key(hash))
+            .map(|(_, orphan)| orphan)
+            .collect();
+        orphans.sort_unstable_by_key(|(row, _, _)| *row);
+        ForeignKeyOutcome {
+            checked: child.checked,
+            orphans: orphans.iter().map(|(_, _, count)| count).sum(),
+            samples: orphans
+                .iter()
+                .take(MAX_ORPHAN_SAMPLES)
+                .map(|(_, value, _)| value.clone())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Int64Array, StringArray};
+
+    use super::*;
+
+    #[test]
+    fn test_foreign_key_no_orphans() {
+        let rule = ForeignKeyCheck::new(0.0);
+        let parent: Vec<ArrayRef> = vec![Arc::new(StringArray::from(vec!["1", "2", "3"]))];
+        let child: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec![Some("1"), Some("3")])),
+            Arc::new(StringArray::from(vec![Some("2"), None])),
+        ];
+        let outcome = rule.validate(&parent, &child).unwrap();
+        assert_eq!(outcome.checked, 3);
+        assert_eq!(outcome.orphans, 0);
+        assert!(outcome.samples.is_empty());
+    }
+
+    #[test]
+    fn test_foreign_key_orphans_are_sampled_once() {
+        let rule = ForeignKeyCheck::new(0.0);
+        let parent: Vec<ArrayRef> = vec![Arc::new(StringArray::from(vec!["1", "2"]))];
+        let child: Vec<ArrayRef> = vec![Arc::new(StringArray::from(vec!["1", "9", "9", "7"]))];
+        let outcome = rule.validate(&parent, &child).unwrap();
+        assert_eq!(outcome.checked, 4);
+        assert_eq!(outcome.orphans, 3);
+        assert_eq!(outcome.samples, vec!["9".to_string(), "7".to_string()]);
+    }
+
+    #[test]
+    fn test_column_keys_merged_out_of_order() {
+        let rule = ForeignKeyCheck::new(0.0);
+        let parent =
+            ColumnKeys::from_arrays(&[Arc::new(StringArray::from(vec!["1"])) as _]).unwrap();
+        // Batches validated in parallel are recorded in any order
+        let mut child = ColumnKeys::default();
+        let mut late = ColumnKeys::default();
+        late.record(&(Arc::new(StringArray::from(vec!["8", "9"])) as _), 2)
+            .unwrap();
+        let mut early = ColumnKeys::default();
+        early
+            .record(
+                &(Arc::new(StringArray::from(vec![Some("9"), None])) as _),
+                0,
+            )
+            .unwrap();
+        child.merge(late);
+        child.merge(early);
+        let outcome = rule.validate_keys(&parent, &child);
+        assert_eq!(outcome.checked, 3);
+        assert_eq!(outcome.orphans, 3);
+        assert_eq!(outcome.samples, vec!["9".to_string(), "8".to_string()]);
+    }
+
+    #[test]
+    fn test_foreign_key_mixed_types() {
+        let rule = ForeignKeyCheck::new(0.0);
+        let parent: Vec<ArrayRef> = vec![Arc::new(Int64Array::from(vec![10, 20]))];
+        let child: Vec<ArrayRef> = vec![Arc::new(StringArray::from(vec!["10", "30"]))];
+        let outcome = rule.validate(&parent, &child).unwrap();
+        assert_eq!(outcome.orphans, 1);
+        assert_eq!(outcome.samples, vec!["30".to_string()]);
+    }
+}
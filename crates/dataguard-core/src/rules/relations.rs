@@ -1,23 +1,52 @@
-use std::{marker::PhantomData, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+    sync::Arc,
+};
 
-use arrow_array::{Array, ArrowPrimitiveType, PrimitiveArray};
+use arrow::compute::cast;
+use arrow::datatypes::{ArrowNativeType, DataType};
+use arrow_array::{
+    Array, ArrowPrimitiveType, Float64Array, PrimitiveArray, StringArray, TimestampMillisecondArray,
+};
 
-use crate::{utils::operator::CompOperator, RuleError};
+use crate::{
+    columns::ConditionalRule,
+    engine::CorrelationSums,
+    utils::{hasher::Xxh3Builder, operator::CompOperator},
+    RuleError,
+};
 
 pub trait RelationRule: Send + Sync {
     fn name(&self) -> String;
     fn get_threshold(&self) -> f64;
+    /// Columns read by the rule besides the relation pair, passed in order as `extra`.
+    fn extra_columns(&self) -> &[String] {
+        &[]
+    }
     fn validate(
         &self,
         lhs: &Arc<dyn Array>,
         rhs: &Arc<dyn Array>,
+        extra: &[&Arc<dyn Array>],
         columns: [&str; 2],
     ) -> Result<usize, RuleError>;
+    /// Rows the rule could not evaluate, reported apart from the violations.
+    fn skipped_rows(
+        &self,
+        _lhs: &Arc<dyn Array>,
+        _rhs: &Arc<dyn Array>,
+        _extra: &[&Arc<dyn Array>],
+    ) -> Option<usize> {
+        None
+    }
 }
 
 pub struct CompareCheck<T: ArrowPrimitiveType> {
     op: CompOperator,
     threshold: f64,
+    // Largest absolute difference allowed between both sides, in units of `T::Native`
+    max_gap: Option<u64>,
     _phantom: PhantomData<T>,
 }
 
@@ -26,14 +55,32 @@ impl<T: ArrowPrimitiveType> CompareCheck<T> {
         Self {
             op,
             threshold,
+            max_gap: None,
             _phantom: PhantomData,
         }
     }
+
+    /// Also count a violation when both sides are more than `max_gap` units apart, like days
+    /// for `Date32` or milliseconds for millisecond timestamps.
+    pub fn with_max_gap(mut self, max_gap: u64) -> Self {
+        self.max_gap = Some(max_gap);
+        self
+    }
+
+    fn exceeds_gap(&self, a: T::Native, b: T::Native) -> bool {
+        match (self.max_gap, a.to_i64(), b.to_i64()) {
+            (Some(max_gap), Some(a), Some(b)) => a.abs_diff(b) > max_gap,
+            _ => false,
+        }
+    }
 }
 
 impl<T: ArrowPrimitiveType + Send + Sync> RelationRule for CompareCheck<T> {
     fn name(&self) -> String {
-        format!("DateCompare{}", self.op)
+        match self.max_gap {
+            Some(_) => format!("DateCompare{}WithinGap", self.op),
+            None => format!("DateCompare{}", self.op),
+        }
     }
 
     fn get_threshold(&self) -> f64 {
@@ -44,6 +91,7 @@ impl<T: ArrowPrimitiveType + Send + Sync> RelationRule for CompareCheck<T> {
         &self,
         lhs: &Arc<dyn Array>,
         rhs: &Arc<dyn Array>,
+        _extra: &[&Arc<dyn Array>],
         column: [&str; 2],
     ) -> Result<usize, RuleError> {
         // There might be a compute kernel but null on either side return a null when we want to
@@ -57,7 +105,7 @@ impl<T: ArrowPrimitiveType + Send + Sync> RelationRule for CompareCheck<T> {
                 for (i, j) in lhs.iter().zip(rhs) {
                     match (i, j) {
                         (Some(a), Some(b)) => {
-                            if !compare(a, b) {
+                            if !compare(a, b) || self.exceeds_gap(a, b) {
                                 violations += 1
                             }
                         }
@@ -83,3 +131,601 @@ impl<T: ArrowPrimitiveType + Send + Sync> RelationRule for CompareCheck<T> {
         }
     }
 }
+
+/// Compares two string columns row by row, for equality or inequality.
+///
+/// Rows where either side is null are skipped.
+pub struct StringCompareCheck {
+    op: CompOperator,
+    threshold: f64,
+}
+
+impl StringCompareCheck {
+    /// `op` must be `Eq` or `Neq`, other operators are rejected at compile time.
+    pub fn new(op: CompOperator, threshold: f64) -> Self {
+        Self { op, threshold }
+    }
+}
+
+impl RelationRule for StringCompareCheck {
+    fn name(&self) -> String {
+        format!("StringCompare{}", self.op)
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(
+        &self,
+        lhs: &Arc<dyn Array>,
+        rhs: &Arc<dyn Array>,
+        _extra: &[&Arc<dyn Array>],
+        columns: [&str; 2],
+    ) -> Result<usize, RuleError> {
+        let downcast = |array: &Arc<dyn Array>, column: &str| {
+            array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .cloned()
+                .ok_or_else(|| {
+                    RuleError::TypeCastError(column.to_string(), "StringArray".to_string())
+                })
+        };
+        let lhs = downcast(lhs, columns[0])?;
+        let rhs = downcast(rhs, columns[1])?;
+        let compare = self.op.get_comparator::<&str>();
+        Ok(lhs
+            .iter()
+            .zip(rhs.iter())
+            .filter(|(a, b)| matches!((a, b), (Some(a), Some(b)) if !compare(a, b)))
+            .count())
+    }
+}
+
+/// Checks, per row, that `lhs + sum(extra)` equals `rhs` within `tolerance`.
+///
+/// Rows where any participating column is null are skipped and reported
+/// through `skipped_rows` instead of counting as violations.
+pub struct SumEqualsCheck {
+    addends: Vec<String>,
+    tolerance: f64,
+    threshold: f64,
+}
+
+impl SumEqualsCheck {
+    pub fn new(addends: Vec<String>, tolerance: f64, threshold: f64) -> Self {
+        Self {
+            addends,
+            tolerance,
+            threshold,
+        }
+    }
+
+    /// Yields, per row, the sum and the expected total, or `None` when a value is null.
+    fn rows(
+        &self,
+        lhs: &Arc<dyn Array>,
+        rhs: &Arc<dyn Array>,
+        extra: &[&Arc<dyn Array>],
+        columns: [&str; 2],
+    ) -> Result<Vec<Option<(f64, f64)>>, RuleError> {
+        let to_f64 = |array: &Arc<dyn Array>, column: &str| {
+            cast(array, &DataType::Float64)
+                .ok()
+                .and_then(|a| a.as_any().downcast_ref::<Float64Array>().cloned())
+                .ok_or_else(|| {
+                    RuleError::TypeCastError(column.to_string(), "Float64Array".to_string())
+                })
+        };
+        let lhs = to_f64(lhs, columns[0])?;
+        let rhs = to_f64(rhs, columns[1])?;
+        let extra = extra
+            .iter()
+            .zip(&self.addends)
+            .map(|(a, column)| to_f64(a, column))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((0..lhs.len())
+            .map(|i| {
+                if lhs.is_null(i) || rhs.is_null(i) || extra.iter().any(|a| a.is_null(i)) {
+                    return None;
+                }
+                let sum = lhs.value(i) + extra.iter().map(|a| a.value(i)).sum::<f64>();
+                Some((sum, rhs.value(i)))
+            })
+            .collect())
+    }
+}
+
+impl RelationRule for SumEqualsCheck {
+    fn name(&self) -> String {
+        "SumEquals".to_string()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn extra_columns(&self) -> &[String] {
+        &self.addends
+    }
+
+    fn validate(
+        &self,
+        lhs: &Arc<dyn Array>,
+        rhs: &Arc<dyn Array>,
+        extra: &[&Arc<dyn Array>],
+        columns: [&str; 2],
+    ) -> Result<usize, RuleError> {
+        Ok(self
+            .rows(lhs, rhs, extra, columns)?
+            .into_iter()
+            .flatten()
+            .filter(|(sum, total)| (sum - total).abs() > self.tolerance)
+            .count())
+    }
+
+    fn skipped_rows(
+        &self,
+        lhs: &Arc<dyn Array>,
+        rhs: &Arc<dyn Array>,
+        extra: &[&Arc<dyn Array>],
+    ) -> Option<usize> {
+        Some(
+            (0..lhs.len())
+                .filter(|&i| lhs.is_null(i) || rhs.is_null(i) || extra.iter().any(|a| a.is_null(i)))
+                .count(),
+        )
+    }
+}
+
+/// Checks, per row, that the time elapsed from the left timestamp to the right one is within
+/// `[min_secs, max_secs]`, e.g. a session lasting between 1 second and an hour.
+///
+/// Timestamps are compared in milliseconds. Rows where either side is null, including values
+/// that failed to parse, are skipped and reported through `skipped_rows`.
+pub struct DurationCheck {
+    min_secs: i64,
+    max_secs: i64,
+    threshold: f64,
+}
+
+impl DurationCheck {
+    pub fn new(min_secs: i64, max_secs: i64, threshold: f64) -> Self {
+        Self {
+            min_secs,
+            max_secs,
+            threshold,
+        }
+    }
+
+    fn is_violation(&self, start: i64, end: i64) -> bool {
+        let elapsed = end.saturating_sub(start);
+        elapsed < self.min_secs.saturating_mul(1000) || elapsed > self.max_secs.saturating_mul(1000)
+    }
+}
+
+impl RelationRule for DurationCheck {
+    fn name(&self) -> String {
+        "DurationBetween".to_string()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(
+        &self,
+        lhs: &Arc<dyn Array>,
+        rhs: &Arc<dyn Array>,
+        _extra: &[&Arc<dyn Array>],
+        columns: [&str; 2],
+    ) -> Result<usize, RuleError> {
+        let downcast = |array: &Arc<dyn Array>, column: &str| {
+            array
+                .as_any()
+                .downcast_ref::<TimestampMillisecondArray>()
+                .cloned()
+                .ok_or_else(|| {
+                    RuleError::TypeCastError(
+                        column.to_string(),
+                        "TimestampMillisecondArray".to_string(),
+                    )
+                })
+        };
+        let start = downcast(lhs, columns[0])?;
+        let end = downcast(rhs, columns[1])?;
+        Ok(start
+            .iter()
+            .zip(end.iter())
+            .filter(|(a, b)| matches!((a, b), (Some(a), Some(b)) if self.is_violation(*a, *b)))
+            .count())
+    }
+
+    fn skipped_rows(
+        &self,
+        lhs: &Arc<dyn Array>,
+        rhs: &Arc<dyn Array>,
+        _extra: &[&Arc<dyn Array>],
+    ) -> Option<usize> {
+        Some(
+            (0..lhs.len())
+                .filter(|&i| lhs.is_null(i) || rhs.is_null(i))
+                .count(),
+        )
+    }
+}
+
+/// Applies `then_rule` to the right column on rows where the left column equals `when_equals`.
+///
+/// The left column is compared as text, a null never matches the condition so the row is not
+/// checked. On matching rows a null right value only fails `NotNull`.
+pub struct ConditionalCheck {
+    when_equals: String,
+    then_rule: ConditionalRule,
+    threshold: f64,
+}
+
+impl ConditionalCheck {
+    pub fn new(when_equals: String, then_rule: ConditionalRule, threshold: f64) -> Self {
+        Self {
+            when_equals,
+            then_rule,
+            threshold,
+        }
+    }
+}
+
+impl RelationRule for ConditionalCheck {
+    fn name(&self) -> String {
+        format!("Conditional{}", self.then_rule)
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(
+        &self,
+        lhs: &Arc<dyn Array>,
+        rhs: &Arc<dyn Array>,
+        _extra: &[&Arc<dyn Array>],
+        columns: [&str; 2],
+    ) -> Result<usize, RuleError> {
+        let when = cast(lhs, &DataType::Utf8)
+            .ok()
+            .and_then(|a| a.as_any().downcast_ref::<StringArray>().cloned())
+            .ok_or_else(|| {
+                RuleError::TypeCastError(columns[0].to_string(), "StringArray".to_string())
+            })?;
+        let matching =
+            (0..when.len()).filter(|&i| !when.is_null(i) && when.value(i) == self.when_equals);
+
+        match self.then_rule {
+            ConditionalRule::NotNull => Ok(matching.filter(|&i| rhs.is_null(i)).count()),
+            ConditionalRule::IsNull => Ok(matching.filter(|&i| !rhs.is_null(i)).count()),
+            ConditionalRule::Between { min, max } => {
+                let then = cast(rhs, &DataType::Float64)
+                    .ok()
+                    .and_then(|a| a.as_any().downcast_ref::<Float64Array>().cloned())
+                    .ok_or_else(|| {
+                        RuleError::TypeCastError(columns[1].to_string(), "Float64Array".to_string())
+                    })?;
+                Ok(matching
+                    .filter(|&i| !then.is_null(i) && !(min..=max).contains(&then.value(i)))
+                    .count())
+            }
+        }
+    }
+}
+
+/// The Pearson correlation of the two columns must be at least `min_coefficient`.
+///
+/// Evaluated once on sums accumulated over all batches. When it fails every compared
+/// row counts as an error, as does an undefined coefficient (a constant column).
+pub struct CorrelationCheck {
+    min_coefficient: f64,
+    threshold: f64,
+}
+
+impl CorrelationCheck {
+    pub fn new(min_coefficient: f64, threshold: f64) -> Self {
+        Self {
+            min_coefficient,
+            threshold,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        "Correlation".to_string()
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    pub fn validate(&self, sums: &CorrelationSums) -> usize {
+        match sums.coefficient() {
+            Some(coefficient) if coefficient >= self.min_coefficient => 0,
+            _ => sums.count,
+        }
+    }
+
+    pub fn observed(&self, sums: &CorrelationSums) -> Vec<(String, f64)> {
+        sums.coefficient()
+            .map(|c| vec![("coefficient".to_string(), c)])
+            .unwrap_or_default()
+    }
+}
+
+/// Distinct values of the first column of a subset relation, by hash: the number of rows
+/// holding the value, its first row and its text.
+pub type SubsetValues = HashMap<u64, (usize, usize, String), Xxh3Builder>;
+
+/// Every value of the first column must be found among the values of the second one.
+///
+/// Evaluated once the values of both columns are accumulated over all batches. Each row
+/// holding a value missing from the second column counts as an error, null values are
+/// not checked.
+pub struct SubsetCheck {
+    threshold: f64,
+}
+
+impl SubsetCheck {
+    pub fn new(threshold: f64) -> Self {
+        Self { threshold }
+    }
+
+    pub fn name(&self) -> String {
+        "IsSubsetOf".to_string()
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// Returns the rows of `values` missing from `reference`, along with the first row of
+    /// up to `max_samples` missing values.
+    pub fn validate(
+        &self,
+        values: &SubsetValues,
+        reference: &HashSet<u64, Xxh3Builder>,
+        max_samples: usize,
+    ) -> (usize, Vec<(usize, String)>) {
+        let mut missing = 0;
+        let mut samples = Vec::new();
+        for (hash, (rows, first_row, value)) in values {
+            if reference.contains(hash) {
+                continue;
+            }
+            missing += rows;
+            samples.push((*first_row, value.clone()));
+        }
+        samples.sort_by_key(|(row, _)| *row);
+        samples.truncate(max_samples);
+        (missing, samples)
+    }
+
+    /// Number of distinct values of `values` missing from `reference`.
+    pub fn observed(
+        &self,
+        values: &SubsetValues,
+        reference: &HashSet<u64, Xxh3Builder>,
+    ) -> Vec<(String, f64)> {
+        let missing = values.keys().filter(|h| !reference.contains(*h)).count();
+        vec![("missing_values".to_string(), missing as f64)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::{Date32Type, TimestampMillisecondType};
+    use arrow_array::{Date32Array, Int64Array};
+
+    #[test]
+    fn test_date_compare_max_gap() {
+        let order: Arc<dyn Array> = Arc::new(Date32Array::from(vec![
+            Some(19_000),
+            Some(19_000),
+            Some(19_000),
+            Some(19_000),
+        ]));
+        let ship: Arc<dyn Array> = Arc::new(Date32Array::from(vec![
+            Some(19_030),
+            Some(19_031),
+            Some(18_999),
+            None,
+        ]));
+        let columns = ["order_date", "ship_date"];
+
+        let ordering = CompareCheck::<Date32Type>::new(CompOperator::Lte, 0.0);
+        assert_eq!(ordering.name(), "DateCompareLesserThanEqual");
+        // Only the wrong ordering and the null are violations
+        assert_eq!(ordering.validate(&order, &ship, &[], columns).unwrap(), 2);
+
+        let gap = CompareCheck::<Date32Type>::new(CompOperator::Lte, 0.0).with_max_gap(30);
+        assert_eq!(gap.name(), "DateCompareLesserThanEqualWithinGap");
+        // 31 days exceeds the gap, the wrong ordering and the null are still violations
+        assert_eq!(gap.validate(&order, &ship, &[], columns).unwrap(), 3);
+
+        let day = 86_400_000;
+        let created: Arc<dyn Array> = Arc::new(TimestampMillisecondArray::from(vec![0, 0]));
+        let closed: Arc<dyn Array> =
+            Arc::new(TimestampMillisecondArray::from(vec![2 * day, 2 * day + 1]));
+        let gap = CompareCheck::<TimestampMillisecondType>::new(CompOperator::Lt, 0.0)
+            .with_max_gap(2 * day as u64);
+        assert_eq!(gap.validate(&created, &closed, &[], columns).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_string_compare_check() {
+        let billing: Arc<dyn Array> = Arc::new(StringArray::from(vec![
+            Some("FR"),
+            Some("DE"),
+            None,
+            Some("IT"),
+        ]));
+        let shipping: Arc<dyn Array> = Arc::new(StringArray::from(vec![
+            Some("FR"),
+            Some("FR"),
+            Some("ES"),
+            Some("it"),
+        ]));
+        let columns = ["billing", "shipping"];
+
+        let eq = StringCompareCheck::new(CompOperator::Eq, 0.0);
+        assert_eq!(eq.name(), "StringCompareEqual");
+        assert_eq!(eq.validate(&billing, &shipping, &[], columns).unwrap(), 2);
+        let neq = StringCompareCheck::new(CompOperator::Neq, 0.0);
+        assert_eq!(neq.validate(&billing, &shipping, &[], columns).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_sum_equals_check() {
+        let rule = SumEqualsCheck::new(vec!["tax".to_string()], 0.01, 0.0);
+        let subtotal: Arc<dyn Array> =
+            Arc::new(Int64Array::from(vec![Some(100), Some(50), None, Some(10)]));
+        let tax: Arc<dyn Array> = Arc::new(Float64Array::from(vec![
+            Some(20.0),
+            Some(10.0),
+            Some(1.0),
+            None,
+        ]));
+        let total: Arc<dyn Array> = Arc::new(Float64Array::from(vec![
+            Some(120.0),
+            Some(61.0),
+            Some(3.0),
+            Some(12.0),
+        ]));
+
+        let columns = ["subtotal", "total"];
+        let errors = rule.validate(&subtotal, &total, &[&tax], columns).unwrap();
+        assert_eq!(errors, 1);
+        assert_eq!(rule.skipped_rows(&subtotal, &total, &[&tax]), Some(2));
+    }
+
+    #[test]
+    fn test_duration_check() {
+        let rule = DurationCheck::new(1, 3600, 0.0);
+        let start: Arc<dyn Array> = Arc::new(TimestampMillisecondArray::from(vec![
+            Some(0),
+            Some(0),
+            Some(0),
+            Some(10_000),
+            None,
+        ]));
+        let end: Arc<dyn Array> = Arc::new(TimestampMillisecondArray::from(vec![
+            Some(1_000),
+            Some(3_600_001),
+            Some(500),
+            Some(0),
+            Some(0),
+        ]));
+        let columns = ["start_ts", "end_ts"];
+
+        assert_eq!(rule.name(), "DurationBetween");
+        // Over an hour, under a second and a negative duration
+        assert_eq!(rule.validate(&start, &end, &[], columns).unwrap(), 3);
+        assert_eq!(rule.skipped_rows(&start, &end, &[]), Some(1));
+
+        let dates: Arc<dyn Array> = Arc::new(Date32Array::from(vec![0]));
+        assert!(rule.validate(&dates, &end, &[], columns).is_err());
+    }
+
+    #[test]
+    fn test_conditional_check() {
+        let status: Arc<dyn Array> = Arc::new(StringArray::from(vec![
+            Some("shipped"),
+            Some("shipped"),
+            Some("pending"),
+            None,
+            Some("shipped"),
+        ]));
+        let shipped_date: Arc<dyn Array> = Arc::new(Int64Array::from(vec![
+            Some(5),
+            None, // violation
+            None,
+            None, // null status never matches
+            Some(50),
+        ]));
+        let columns = ["status", "shipped_date"];
+
+        let not_null = ConditionalCheck::new("shipped".to_string(), ConditionalRule::NotNull, 0.0);
+        assert_eq!(not_null.name(), "ConditionalNotNull");
+        let errors = not_null.validate(&status, &shipped_date, &[], columns);
+        assert_eq!(errors.unwrap(), 1);
+
+        let is_null = ConditionalCheck::new("pending".to_string(), ConditionalRule::IsNull, 0.0);
+        let errors = is_null.validate(&status, &shipped_date, &[], columns);
+        assert_eq!(errors.unwrap(), 0);
+
+        let between = ConditionalCheck::new(
+            "shipped".to_string(),
+            ConditionalRule::Between {
+                min: 0.0,
+                max: 10.0,
+            },
+            0.0,
+        );
+        let errors = between.validate(&status, &shipped_date, &[], columns);
+        assert_eq!(errors.unwrap(), 1);
+    }
+
+    #[test]
+    fn test_correlation_check() {
+        let x = Float64Array::from(vec![Some(1.0), Some(2.0), Some(3.0), None]);
+        let y = Float64Array::from(vec![Some(2.0), Some(4.1), Some(5.9), Some(8.0)]);
+        // Sums merged across batches give the same coefficient as a single pass
+        let mut sums = CorrelationSums::default();
+        sums.update(&x.slice(0, 2), &y.slice(0, 2));
+        sums.merge(&{
+            let mut other = CorrelationSums::default();
+            other.update(&x.slice(2, 2), &y.slice(2, 2));
+            other
+        });
+        assert_eq!(sums.count, 3);
+        let coefficient = sums.coefficient().unwrap();
+        assert!(coefficient > 0.99 && coefficient <= 1.0);
+
+        let check = CorrelationCheck::new(0.9, 0.0);
+        assert_eq!(check.validate(&sums), 0);
+        assert_eq!(check.observed(&sums)[0].0, "coefficient");
+        let negative = CorrelationCheck::new(1.0, 0.0);
+        assert_eq!(negative.validate(&sums), 3);
+
+        // A constant column has no coefficient and fails
+        let mut constant = CorrelationSums::default();
+        constant.update(&Float64Array::from(vec![1.0, 1.0]), &y.slice(0, 2));
+        assert_eq!(constant.coefficient(), None);
+        assert_eq!(check.validate(&constant), 2);
+        assert!(check.observed(&constant).is_empty());
+    }
+
+    #[test]
+    fn test_subset_check() {
+        let reference: HashSet<u64, Xxh3Builder> = [1, 2, 3].into_iter().collect();
+        let mut values = SubsetValues::default();
+        values.insert(1, (10, 0, "a".to_string()));
+        values.insert(7, (2, 5, "x".to_string()));
+        values.insert(8, (1, 3, "y".to_string()));
+
+        let check = SubsetCheck::new(0.0);
+        assert_eq!(
+            check.validate(&values, &reference, 5),
+            (3, vec![(3, "y".to_string()), (5, "x".to_string())])
+        );
+        assert_eq!(
+            check.validate(&values, &reference, 1),
+            (3, vec![(3, "y".to_string())])
+        );
+        assert_eq!(
+            check.observed(&values, &reference),
+            vec![("missing_values".to_string(), 2.0)]
+        );
+
+        values.retain(|hash, _| reference.contains(hash));
+        assert_eq!(check.validate(&values, &reference, 5), (0, Vec::new()));
+    }
+}
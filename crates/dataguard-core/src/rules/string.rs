@@ -1,14 +1,15 @@
-use std::collections::HashSet;
-
-use arrow::{
-    array::{Int32Array, StringArray},
-    compute::{self},
+use std::{
+    collections::{HashMap, HashSet},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
 };
-use arrow_array::Array;
-use arrow_string::length::length;
+
+use arrow::array::StringArray;
+use ipnet::IpNet;
+use phonenumber::country;
+use regex::{Regex, RegexBuilder};
 use xxhash_rust::xxh3::xxh3_64;
 
-use crate::{errors::RuleError, utils::hasher::Xxh3Builder};
+use crate::{errors::RuleError, rules::first_failing_rows, utils::hasher::Xxh3Builder};
 
 /// A trait for defining validation rules on Arrow arrays.
 pub trait StringRule: Send + Sync {
@@ -17,6 +18,32 @@ pub trait StringRule: Send + Sync {
     fn get_threshold(&self) -> f64;
     /// Validates an Arrow `Array`.
     fn validate(&self, array: &StringArray, column: String) -> Result<usize, RuleError>;
+    /// Returns the positions of the first `limit` failing values, used for reporting samples.
+    fn failing_rows(&self, _array: &StringArray, _limit: usize) -> Vec<usize> {
+        Vec::new()
+    }
+}
+
+/// Unit used to measure the length of a string.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LengthMode {
+    /// Unicode scalar values, `str::chars().count()`
+    #[default]
+    Chars,
+    /// UTF-16 code units, as seen by JavaScript or Java (characters outside the BMP count as 2)
+    Utf16,
+    /// UTF-8 bytes, as limited by most database column sizes
+    Bytes,
+}
+
+impl LengthMode {
+    fn measure(&self, value: &str) -> usize {
+        match self {
+            LengthMode::Chars => value.chars().count(),
+            LengthMode::Utf16 => value.encode_utf16().count(),
+            LengthMode::Bytes => value.len(),
+        }
+    }
 }
 
 /// A rule to check the length of strings in a `StringArray`.
@@ -25,6 +52,7 @@ pub struct StringLengthCheck {
     threshold: f64,
     min: Option<usize>,
     max: Option<usize>,
+    mode: LengthMode,
 }
 
 impl StringLengthCheck {
@@ -34,8 +62,20 @@ impl StringLengthCheck {
             threshold,
             min,
             max,
+            mode: LengthMode::default(),
         }
     }
+
+    /// Set the unit used to measure the length
+    pub fn with_mode(mut self, mode: LengthMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    fn is_violation(&self, value: &str) -> bool {
+        let len = self.mode.measure(value);
+        self.min.is_some_and(|min| len < min) || self.max.is_some_and(|max| len > max)
+    }
 }
 
 impl StringRule for StringLengthCheck {
@@ -47,60 +87,1049 @@ impl StringRule for StringLengthCheck {
         self.threshold
     }
 
-    fn validate(&self, array: &StringArray, column: String) -> Result<usize, RuleError> {
-        let res = length(array);
-        match res {
-            Ok(dyn_array) => {
-                let len_array =
-                    dyn_array
-                        .as_any()
-                        .downcast_ref::<Int32Array>()
-                        .ok_or_else(|| {
-                            RuleError::ValidationError(format!(
-                                "StringLengthCheck applied to a non-string column '{}'",
-                                column
-                            ))
-                        })?;
-                let mut counter: u32 = 0;
-                for i in len_array.iter().flatten() {
-                    if let Some(min) = self.min {
-                        if i < min as i32 {
-                            counter += 1
-                        }
-                    }
-                    if let Some(max) = self.max {
-                        if i > max as i32 {
-                            counter += 1
-                        }
-                    }
-                }
-                Ok(counter as usize)
-            }
-            Err(e) => Err(RuleError::ArrowError(e)),
-        }
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        let mut counter = 0;
+        for value in array.iter().flatten() {
+            let len = self.mode.measure(value);
+            if let Some(min) = self.min {
+                if len < min {
+                    counter += 1
+                }
+            }
+            if let Some(max) = self.max {
+                if len > max {
+                    counter += 1
+                }
+            }
+        }
+        Ok(counter)
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| self.is_violation(v))
+    }
+}
+
+/// A rule to check if strings in a `StringArray` match a regex pattern.
+pub struct RegexMatch {
+    name: String,
+    threshold: f64,
+    regex: Regex,
+}
+
+impl RegexMatch {
+    /// Compile `pattern` with the given flags, see [`build_regex`] for the supported ones.
+    pub fn new(
+        name: String,
+        threshold: f64,
+        pattern: String,
+        flags: Option<String>,
+    ) -> Result<Self, RuleError> {
+        let regex = build_regex(&pattern, flags.as_deref())?;
+        Ok(Self {
+            name,
+            threshold,
+            regex,
+        })
+    }
+}
+
+/// Compile a regex pattern, each character of `flags` toggles an option:
+///
+/// * `i` - case-insensitive match
+/// * `m` - multi-line, `^` and `$` match at the start and end of each line
+/// * `s` - `.` also matches a newline
+/// * `u` - Unicode-aware classes, like `\d` or `\w` (already the default)
+///
+/// Unknown and repeated flags are rejected.
+pub fn build_regex(pattern: &str, flags: Option<&str>) -> Result<Regex, RuleError> {
+    let mut builder = RegexBuilder::new(pattern);
+    let mut seen = HashSet::new();
+    for flag in flags.unwrap_or_default().chars() {
+        if !seen.insert(flag) {
+            return Err(RuleError::ValidationError(format!(
+                "Flag '{}' is repeated in '{}'",
+                flag,
+                flags.unwrap_or_default()
+            )));
+        }
+        match flag {
+            'i' => builder.case_insensitive(true),
+            'm' => builder.multi_line(true),
+            's' => builder.dot_matches_new_line(true),
+            'u' => builder.unicode(true),
+            other => {
+                return Err(RuleError::ValidationError(format!(
+                    "Unsupported regex flag '{}', expected any of 'i', 'm', 's' or 'u'",
+                    other
+                )))
+            }
+        };
+    }
+    builder.build().map_err(|e| {
+        RuleError::ValidationError(format!("Invalid regex pattern '{}': {}", pattern, e))
+    })
+}
+
+impl StringRule for RegexMatch {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| !self.regex.is_match(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.regex.is_match(v))
+    }
+}
+
+/// A rule to check if strings in a `StringArray` match at least one of several patterns.
+pub struct MatchesAny {
+    name: String,
+    threshold: f64,
+    patterns: Vec<Regex>,
+}
+
+impl MatchesAny {
+    /// Compile every pattern with the same flags, see [`build_regex`].
+    ///
+    /// Fails on the first invalid pattern, or when no pattern is given.
+    pub fn new(
+        name: String,
+        threshold: f64,
+        patterns: Vec<String>,
+        flags: Option<String>,
+    ) -> Result<Self, RuleError> {
+        if patterns.is_empty() {
+            return Err(RuleError::ValidationError(
+                "At least one regex pattern is required".to_string(),
+            ));
+        }
+        let patterns = patterns
+            .iter()
+            .map(|pattern| build_regex(pattern, flags.as_deref()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            name,
+            threshold,
+            patterns,
+        })
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        self.patterns.iter().any(|regex| regex.is_match(value))
+    }
+}
+
+impl StringRule for MatchesAny {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array.iter().flatten().filter(|v| !self.is_match(v)).count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.is_match(v))
+    }
+}
+
+/// A rule to check that strings in a `StringArray` begin with a given prefix.
+pub struct StartsWithCheck {
+    name: String,
+    threshold: f64,
+    prefix: String,
+}
+
+impl StartsWithCheck {
+    pub fn new(name: String, threshold: f64, prefix: String) -> Self {
+        Self {
+            name,
+            threshold,
+            prefix,
+        }
+    }
+}
+
+impl StringRule for StartsWithCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| !v.starts_with(self.prefix.as_str()))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| {
+            !v.starts_with(self.prefix.as_str())
+        })
+    }
+}
+
+/// A rule to check that strings in a `StringArray` end with a given suffix.
+pub struct EndsWithCheck {
+    name: String,
+    threshold: f64,
+    suffix: String,
+}
+
+impl EndsWithCheck {
+    pub fn new(name: String, threshold: f64, suffix: String) -> Self {
+        Self {
+            name,
+            threshold,
+            suffix,
+        }
+    }
+}
+
+impl StringRule for EndsWithCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| !v.ends_with(self.suffix.as_str()))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !v.ends_with(self.suffix.as_str()))
+    }
+}
+
+/// A rule to check that strings in a `StringArray` contain (or not) a substring.
+pub struct ContainsCheck {
+    name: String,
+    threshold: f64,
+    substring: String,
+    negate: bool,
+    case_insensitive: bool,
+}
+
+impl ContainsCheck {
+    pub fn new(
+        name: String,
+        threshold: f64,
+        substring: String,
+        negate: bool,
+        case_insensitive: bool,
+    ) -> Self {
+        let substring = if case_insensitive {
+            substring.to_lowercase()
+        } else {
+            substring
+        };
+        Self {
+            name,
+            threshold,
+            substring,
+            negate,
+            case_insensitive,
+        }
+    }
+
+    fn contains(&self, value: &str) -> bool {
+        if self.case_insensitive {
+            value.to_lowercase().contains(self.substring.as_str())
+        } else {
+            value.contains(self.substring.as_str())
+        }
+    }
+}
+
+impl StringRule for ContainsCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| self.contains(v) == self.negate)
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| self.contains(v) == self.negate)
+    }
+}
+
+/// IP protocol version accepted by [`IpCheck`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IpVersion {
+    V4,
+    V6,
+    /// Either an IPv4 or an IPv6 address
+    Any,
+}
+
+/// A rule to check that strings in a `StringArray` are valid IP addresses.
+///
+/// Parsing relies on `std::net`, so IPv4 octets with leading zeros or out of
+/// range are rejected and every IPv6 shorthand (`::1`, `::ffff:1.2.3.4`) is accepted.
+pub struct IpCheck {
+    name: String,
+    threshold: f64,
+    version: IpVersion,
+}
+
+impl IpCheck {
+    pub fn new(name: String, threshold: f64, version: IpVersion) -> Self {
+        Self {
+            name,
+            threshold,
+            version,
+        }
+    }
+
+    fn is_valid(&self, value: &str) -> bool {
+        match self.version {
+            IpVersion::V4 => value.parse::<Ipv4Addr>().is_ok(),
+            IpVersion::V6 => value.parse::<Ipv6Addr>().is_ok(),
+            IpVersion::Any => value.parse::<IpAddr>().is_ok(),
+        }
+    }
+}
+
+impl StringRule for IpCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array.iter().flatten().filter(|v| !self.is_valid(v)).count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.is_valid(v))
+    }
+}
+
+/// Parse CIDR blocks such as `10.0.0.0/8` or `fd00::/8`, erroring on the first malformed one.
+pub fn parse_cidrs(cidrs: &[String]) -> Result<Vec<IpNet>, RuleError> {
+    if cidrs.is_empty() {
+        return Err(RuleError::ValidationError(
+            "At least one CIDR block is required".to_string(),
+        ));
+    }
+    cidrs
+        .iter()
+        .map(|cidr| {
+            cidr.trim().parse::<IpNet>().map_err(|_| {
+                RuleError::ValidationError(format!(
+                    "Invalid CIDR block '{}', expected an address and a prefix such as '10.0.0.0/8'",
+                    cidr
+                ))
+            })
+        })
+        .collect()
+}
+
+/// A rule to check that strings in a `StringArray` are IP addresses within allowed CIDR blocks.
+///
+/// Values that are not IP addresses fail. An IPv4 address is only matched by IPv4 blocks.
+pub struct IpInCidr {
+    name: String,
+    threshold: f64,
+    allowed: Vec<IpNet>,
+}
+
+impl IpInCidr {
+    pub fn new(name: String, threshold: f64, allowed: Vec<IpNet>) -> Self {
+        Self {
+            name,
+            threshold,
+            allowed,
+        }
+    }
+
+    fn is_allowed(&self, value: &str) -> bool {
+        value
+            .parse::<IpAddr>()
+            .is_ok_and(|ip| self.allowed.iter().any(|net| net.contains(&ip)))
+    }
+}
+
+impl StringRule for IpInCidr {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| !self.is_allowed(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.is_allowed(v))
+    }
+}
+
+/// Top-level JSON value accepted by [`JsonCheck`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JsonKind {
+    /// Any JSON value, including bare scalars such as `42` or `"text"`
+    Any,
+    Object,
+    Array,
+}
+
+/// A rule to check that strings in a `StringArray` are parseable JSON documents.
+///
+/// Each value is fully parsed with `serde_json`, surrounding whitespace is allowed.
+pub struct JsonCheck {
+    name: String,
+    threshold: f64,
+    kind: JsonKind,
+}
+
+impl JsonCheck {
+    pub fn new(name: String, threshold: f64, kind: JsonKind) -> Self {
+        Self {
+            name,
+            threshold,
+            kind,
+        }
+    }
+
+    fn is_valid(&self, value: &str) -> bool {
+        match serde_json::from_str::<serde_json::Value>(value) {
+            Ok(json) => match self.kind {
+                JsonKind::Any => true,
+                JsonKind::Object => json.is_object(),
+                JsonKind::Array => json.is_array(),
+            },
+            Err(_) => false,
+        }
+    }
+}
+
+impl StringRule for JsonCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array.iter().flatten().filter(|v| !self.is_valid(v)).count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.is_valid(v))
+    }
+}
+
+/// Parse an ISO 3166 country code such as `FR` or `us` into a phone number region.
+pub fn parse_phone_region(region: &str) -> Result<country::Id, RuleError> {
+    region.to_uppercase().parse().map_err(|_| {
+        RuleError::ValidationError(format!(
+            "Unknown phone region '{}', expected an ISO 3166 country code such as 'FR'",
+            region
+        ))
+    })
+}
+
+/// A rule to check that strings in a `StringArray` are valid phone numbers.
+///
+/// Without a region, numbers must be in international format (`+33 1 23 45 67 89`).
+/// With a region, national formats are accepted too and the number must belong to it.
+/// Parsing is done with the `phonenumber` crate metadata and is far slower than a
+/// regex, empty values fail before being parsed.
+pub struct PhoneCheck {
+    name: String,
+    threshold: f64,
+    region: Option<country::Id>,
+}
+
+impl PhoneCheck {
+    pub fn new(name: String, threshold: f64, region: Option<&str>) -> Result<Self, RuleError> {
+        Ok(Self {
+            name,
+            threshold,
+            region: region.map(parse_phone_region).transpose()?,
+        })
+    }
+
+    fn is_valid(&self, value: &str) -> bool {
+        if value.trim().is_empty() {
+            return false;
+        }
+        match phonenumber::parse(self.region, value) {
+            Ok(number) => {
+                number.is_valid()
+                    && self
+                        .region
+                        .is_none_or(|region| number.country().id() == Some(region))
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl StringRule for PhoneCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array.iter().flatten().filter(|v| !self.is_valid(v)).count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.is_valid(v))
+    }
+}
+
+/// A rule to check that strings in a `StringArray` are structurally valid card numbers.
+///
+/// Spaces and dashes are ignored, the remaining 12 to 19 digits must pass the Luhn checksum.
+/// The issuer prefix is not checked.
+pub struct CreditCardCheck {
+    name: String,
+    threshold: f64,
+}
+
+impl CreditCardCheck {
+    pub fn new(name: String, threshold: f64) -> Self {
+        Self { name, threshold }
+    }
+
+    fn is_valid(value: &str) -> bool {
+        let mut digits = Vec::with_capacity(value.len());
+        for c in value.chars().filter(|c| *c != ' ' && *c != '-') {
+            match c.to_digit(10) {
+                Some(d) => digits.push(d),
+                None => return false,
+            }
+        }
+        if !(12..=19).contains(&digits.len()) {
+            return false;
+        }
+        // Double every second digit starting from the rightmost check digit
+        let sum: u32 = digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, d)| match (i % 2, d * 2) {
+                (0, _) => *d,
+                (_, doubled) if doubled > 9 => doubled - 9,
+                (_, doubled) => doubled,
+            })
+            .sum();
+        sum.is_multiple_of(10)
+    }
+}
+
+impl StringRule for CreditCardCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| !Self::is_valid(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !Self::is_valid(v))
+    }
+}
+
+/// A rule to check that strings in a `StringArray` are structurally valid IBANs.
+///
+/// Spaces are ignored and letters may be lowercase. The value must hold a country code,
+/// two check digits and up to 30 alphanumeric characters, passing the ISO 7064 mod-97 check.
+/// The length expected by each country is not checked.
+pub struct IbanCheck {
+    name: String,
+    threshold: f64,
+}
+
+impl IbanCheck {
+    pub fn new(name: String, threshold: f64) -> Self {
+        Self { name, threshold }
+    }
+
+    fn is_valid(value: &str) -> bool {
+        let iban: Vec<char> = value
+            .chars()
+            .filter(|c| *c != ' ')
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+        if !(15..=34).contains(&iban.len())
+            || !iban[..2].iter().all(char::is_ascii_uppercase)
+            || !iban[2..4].iter().all(char::is_ascii_digit)
+        {
+            return false;
+        }
+        // Move the country code and check digits to the end, letters count as 10 to 35
+        let mut remainder = 0;
+        for c in iban[4..].iter().chain(&iban[..4]) {
+            let Some(value) = c.to_digit(36) else {
+                return false;
+            };
+            remainder = if value < 10 {
+                (remainder * 10 + value) % 97
+            } else {
+                (remainder * 100 + value) % 97
+            };
+        }
+        remainder == 1
+    }
+}
+
+impl StringRule for IbanCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| !Self::is_valid(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !Self::is_valid(v))
+    }
+}
+
+/// A rule to check that strings in a `StringArray` are standard base64 (`A-Z`, `a-z`,
+/// `0-9`, `+` and `/`).
+///
+/// With `strict_padding`, the value must be padded with `=` to a multiple of 4 characters.
+/// Otherwise the padding may be left out, but a value that is padded must be padded
+/// correctly. Empty values fail.
+pub struct Base64Check {
+    name: String,
+    threshold: f64,
+    strict_padding: bool,
+}
+
+impl Base64Check {
+    pub fn new(name: String, threshold: f64, strict_padding: bool) -> Self {
+        Self {
+            name,
+            threshold,
+            strict_padding,
+        }
+    }
+
+    fn is_valid(&self, value: &str) -> bool {
+        let data = value.trim_end_matches('=');
+        let padding = value.len() - data.len();
+        if data.is_empty()
+            || padding > 2
+            || !data
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'+' || b == b'/')
+        {
+            return false;
+        }
+        // A last group of a single character does not encode a full byte
+        if data.len() % 4 == 1 {
+            return false;
+        }
+        if padding == 0 {
+            !self.strict_padding || data.len().is_multiple_of(4)
+        } else {
+            value.len().is_multiple_of(4)
+        }
+    }
+}
+
+impl StringRule for Base64Check {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array.iter().flatten().filter(|v| !self.is_valid(v)).count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.is_valid(v))
+    }
+}
+
+/// A rule to check that strings in a `StringArray` are hexadecimal, an even number of
+/// `0-9`, `a-f` or `A-F` digits.
+///
+/// With `allow_prefix`, a leading `0x` (or `0X`) is accepted and not counted. Empty values
+/// fail.
+pub struct HexCheck {
+    name: String,
+    threshold: f64,
+    allow_prefix: bool,
+}
+
+impl HexCheck {
+    pub fn new(name: String, threshold: f64, allow_prefix: bool) -> Self {
+        Self {
+            name,
+            threshold,
+            allow_prefix,
+        }
+    }
+
+    fn is_valid(&self, value: &str) -> bool {
+        let digits = match value.get(..2) {
+            Some("0x" | "0X") if self.allow_prefix => &value[2..],
+            _ => value,
+        };
+        !digits.is_empty()
+            && digits.len().is_multiple_of(2)
+            && digits.bytes().all(|b| b.is_ascii_hexdigit())
+    }
+}
+
+impl StringRule for HexCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array.iter().flatten().filter(|v| !self.is_valid(v)).count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.is_valid(v))
+    }
+}
+
+/// A rule to check that strings in a `StringArray` are uppercase (or lowercase).
+///
+/// Values may only hold letters of the expected case, digits, whitespace and hyphens.
+/// Letters are ASCII only unless `unicode` is set, then any letter left unchanged by
+/// the case mapping is accepted: `"É"` is uppercase and `"ß"` lowercase.
+pub struct CaseCheck {
+    name: String,
+    threshold: f64,
+    upper: bool,
+    unicode: bool,
+}
+
+impl CaseCheck {
+    pub fn new(name: String, threshold: f64, upper: bool, unicode: bool) -> Self {
+        Self {
+            name,
+            threshold,
+            upper,
+            unicode,
+        }
+    }
+
+    fn is_allowed(&self, c: char) -> bool {
+        if c.is_whitespace() || c == '-' || c.is_ascii_digit() {
+            return true;
+        }
+        match (self.unicode, self.upper) {
+            (false, true) => c.is_ascii_uppercase(),
+            (false, false) => c.is_ascii_lowercase(),
+            (true, true) => c.is_alphanumeric() && c.to_uppercase().eq([c]),
+            (true, false) => c.is_alphanumeric() && c.to_lowercase().eq([c]),
+        }
+    }
+
+    fn is_valid(&self, value: &str) -> bool {
+        !value.is_empty() && value.chars().all(|c| self.is_allowed(c))
+    }
+}
+
+impl StringRule for CaseCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array.iter().flatten().filter(|v| !self.is_valid(v)).count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| !self.is_valid(v))
+    }
+}
+
+/// A rule to check that strings in a `StringArray` have no leading or trailing whitespace.
+pub struct NoSurroundingWhitespace {
+    name: String,
+    threshold: f64,
+}
+
+impl NoSurroundingWhitespace {
+    pub fn new(name: String, threshold: f64) -> Self {
+        Self { name, threshold }
+    }
+
+    fn is_violation(value: &str) -> bool {
+        value != value.trim()
+    }
+}
+
+impl StringRule for NoSurroundingWhitespace {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| Self::is_violation(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, Self::is_violation)
+    }
+}
+
+/// A rule to check that strings in a `StringArray` contain no whitespace at all.
+pub struct NoWhitespace {
+    name: String,
+    threshold: f64,
+}
+
+impl NoWhitespace {
+    pub fn new(name: String, threshold: f64) -> Self {
+        Self { name, threshold }
+    }
+
+    fn is_violation(value: &str) -> bool {
+        value.chars().any(char::is_whitespace)
+    }
+}
+
+impl StringRule for NoWhitespace {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| Self::is_violation(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, Self::is_violation)
+    }
+}
+
+/// A rule to check that strings in a `StringArray` only use characters of an allowed set,
+/// such as `ACGT` for DNA sequences. Nulls and empty strings pass.
+pub struct ContainsOnly {
+    name: String,
+    threshold: f64,
+    allowed_chars: HashSet<char>,
+}
+
+impl ContainsOnly {
+    pub fn new(name: String, threshold: f64, allowed_chars: HashSet<char>) -> Self {
+        Self {
+            name,
+            threshold,
+            allowed_chars,
+        }
+    }
+
+    fn is_violation(&self, value: &str) -> bool {
+        value.chars().any(|c| !self.allowed_chars.contains(&c))
+    }
+}
+
+impl StringRule for ContainsOnly {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| self.is_violation(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| self.is_violation(v))
+    }
+}
+
+pub struct IsInCheck {
+    name: String,
+    threshold: f64,
+    members: HashSet<u64, Xxh3Builder>,
+}
+
+impl IsInCheck {
+    pub fn new(name: String, threshold: f64, members: Vec<String>) -> Self {
+        let mut hashset = HashSet::with_hasher(Xxh3Builder);
+        members.into_iter().for_each(|m| {
+            let hash = xxh3_64(m.as_bytes());
+            let _ = hashset.insert(hash);
+        });
+        Self {
+            name,
+            threshold,
+            members: hashset,
+        }
+    }
+}
+
+impl StringRule for IsInCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        let errors = array
+            .iter()
+            .map(|v| {
+                if let Some(s) = v {
+                    let s_hash = xxh3_64(s.as_bytes());
+                    if !self.members.contains(&s_hash) {
+                        1
+                    } else {
+                        0
+                    }
+                } else {
+                    0
+                }
+            })
+            .sum();
+        Ok(errors)
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| {
+            !self.members.contains(&xxh3_64(v.as_bytes()))
+        })
     }
 }
 
-/// A rule to check if strings in a `StringArray` match a regex pattern.
-pub struct RegexMatch {
+/// Values must not be in a blocklist, such as retired category codes. Nulls pass.
+pub struct IsNotInCheck {
     name: String,
     threshold: f64,
-    pattern: String,
-    flag: Option<String>,
+    members: HashSet<u64, Xxh3Builder>,
 }
 
-impl RegexMatch {
-    pub fn new(name: String, threshold: f64, pattern: String, flag: Option<String>) -> Self {
+impl IsNotInCheck {
+    pub fn new(name: String, threshold: f64, members: Vec<String>) -> Self {
+        let mut hashset = HashSet::with_hasher(Xxh3Builder);
+        hashset.extend(members.iter().map(|m| xxh3_64(m.as_bytes())));
         Self {
             name,
             threshold,
-            pattern,
-            flag,
+            members: hashset,
         }
     }
+
+    fn is_violation(&self, value: &str) -> bool {
+        self.members.contains(&xxh3_64(value.as_bytes()))
+    }
 }
 
-impl StringRule for RegexMatch {
+impl StringRule for IsNotInCheck {
     fn name(&self) -> String {
         self.name.clone()
     }
@@ -109,68 +1138,69 @@ impl StringRule for RegexMatch {
         self.threshold
     }
 
-    fn validate(&self, array: &StringArray, column: String) -> Result<usize, RuleError> {
-        let flag = self.flag.as_deref();
-        if let Ok(match_array) = compute::regexp_is_match_scalar(array, self.pattern.as_str(), flag)
-        {
-            let n = match_array.len();
-            let null_value = match_array.null_count();
-            let true_count = match_array.true_count();
-            let violations = n - true_count - null_value;
-            Ok(violations)
-        } else {
-            Err(RuleError::ValidationError(column))
-        }
+    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| self.is_violation(v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &StringArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| self.is_violation(v))
     }
 }
 
-pub struct IsInCheck {
+/// Most values must have the column's dominant length, give or take `tolerance` characters.
+///
+/// Evaluated on the length histogram of the whole column, the dominant length is the
+/// most frequent one (the shortest on ties).
+pub struct ConsistentLengthCheck {
     name: String,
     threshold: f64,
-    members: HashSet<u64, Xxh3Builder>,
+    tolerance: usize,
 }
 
-impl IsInCheck {
-    pub fn new(name: String, threshold: f64, members: Vec<String>) -> Self {
-        let mut hashset = HashSet::with_hasher(Xxh3Builder);
-        members.into_iter().for_each(|m| {
-            let hash = xxh3_64(m.as_bytes());
-            let _ = hashset.insert(hash);
-        });
+impl ConsistentLengthCheck {
+    pub fn new(name: String, threshold: f64, tolerance: usize) -> Self {
         Self {
             name,
             threshold,
-            members: hashset,
+            tolerance,
         }
     }
-}
 
-impl StringRule for IsInCheck {
-    fn name(&self) -> String {
+    pub fn name(&self) -> String {
         self.name.clone()
     }
 
-    fn get_threshold(&self) -> f64 {
+    pub fn get_threshold(&self) -> f64 {
         self.threshold
     }
 
-    fn validate(&self, array: &StringArray, _column: String) -> Result<usize, RuleError> {
-        let errors = array
+    fn dominant_length(lengths: &HashMap<usize, usize>) -> Option<usize> {
+        lengths
             .iter()
-            .map(|v| {
-                if let Some(s) = v {
-                    let s_hash = xxh3_64(s.as_bytes());
-                    if !self.members.contains(&s_hash) {
-                        1
-                    } else {
-                        0
-                    }
-                } else {
-                    0
-                }
-            })
-            .sum();
-        Ok(errors)
+            .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+            .map(|(length, _)| *length)
+    }
+
+    /// Number of values whose length is further than `tolerance` from the dominant length.
+    pub fn validate(&self, lengths: &HashMap<usize, usize>) -> usize {
+        let Some(dominant) = Self::dominant_length(lengths) else {
+            return 0;
+        };
+        lengths
+            .iter()
+            .filter(|(length, _)| length.abs_diff(dominant) > self.tolerance)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    pub fn observed(&self, lengths: &HashMap<usize, usize>) -> Vec<(String, f64)> {
+        Self::dominant_length(lengths)
+            .map(|dominant| vec![("dominant_length".to_string(), dominant as f64)])
+            .unwrap_or_default()
     }
 }
 
@@ -214,6 +1244,46 @@ mod tests {
         assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
     }
 
+    #[test]
+    fn test_string_length_check_counts_chars() {
+        let rule = StringLengthCheck::new("string_length_test".to_string(), 0.0, None, Some(4));
+        // 4 chars but 5 bytes, 1 emoji is a single char
+        let array = StringArray::from(vec!["café", "ab😀c"]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_string_length_check_utf16() {
+        let rule = StringLengthCheck::new("string_length_test".to_string(), 0.0, Some(2), Some(4))
+            .with_mode(LengthMode::Utf16);
+        let array = StringArray::from(vec![
+            Some("café"),   // BMP only, 4 units
+            Some("日本語"), // BMP CJK, 3 units
+            Some("ab😀"),   // supplementary plane, 2 + 2 = 4 units
+            Some("abc😀"),  // 3 + 2 = 5 units, error
+            Some("😀😀😀"), // 3 chars but 6 units, error
+            Some("𝄞"),      // 1 char but 2 units
+            Some("a"),      // 1 unit, error
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_string_length_check_bytes() {
+        let rule = StringLengthCheck::new("string_length_test".to_string(), 0.0, None, Some(4))
+            .with_mode(LengthMode::Bytes);
+        let array = StringArray::from(vec![
+            Some("abcd"), // 4 bytes
+            Some("café"), // 4 chars but 5 bytes, error
+            Some("日本"), // 2 chars but 6 bytes, error
+            Some("😀"),   // 1 char, 4 bytes
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 10), vec![1, 2]);
+    }
+
     #[test]
     fn test_regex_match() {
         let rule = RegexMatch::new(
@@ -221,7 +1291,8 @@ mod tests {
             0.0,
             r"^\d{3}$".to_string(),
             None,
-        ); // Expects exactly 3 digits
+        )
+        .unwrap(); // Expects exactly 3 digits
         let array = StringArray::from(vec![
             Some("123"),  // ok
             Some("abc"),  // error
@@ -243,7 +1314,8 @@ mod tests {
             0.0,
             "abc".to_string(),
             Some("i".to_string()),
-        );
+        )
+        .unwrap();
         let array = StringArray::from(vec![
             Some("ABC"), // ok
             Some("def"), // error
@@ -252,6 +1324,429 @@ mod tests {
         assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 1);
     }
 
+    #[test]
+    fn test_regex_match_multi_line_flag() {
+        let array = StringArray::from(vec![Some("first\nsecond"), Some("only")]);
+        // Without `m`, `^second$` can only match the whole value
+        let rule = RegexMatch::new("regex".to_string(), 0.0, "^second$".to_string(), None).unwrap();
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+        let rule = RegexMatch::new(
+            "regex".to_string(),
+            0.0,
+            "^second$".to_string(),
+            Some("m".to_string()),
+        )
+        .unwrap();
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_regex_match_dot_new_line_flag() {
+        let array = StringArray::from(vec![Some("a\nb"), Some("ab")]);
+        let rule = RegexMatch::new("regex".to_string(), 0.0, "^a.*b$".to_string(), None).unwrap();
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+        let rule = RegexMatch::new(
+            "regex".to_string(),
+            0.0,
+            "^a.*b$".to_string(),
+            Some("s".to_string()),
+        )
+        .unwrap();
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_regex_match_combined_flags() {
+        let array = StringArray::from(vec![Some("header\nID-١٢٣"), Some("id-12x")]);
+        // `u` keeps `\d` matching any Unicode digit, here Arabic-Indic ones
+        let rule = RegexMatch::new(
+            "regex".to_string(),
+            0.0,
+            r"^id-\d+$".to_string(),
+            Some("imu".to_string()),
+        )
+        .unwrap();
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+        assert_eq!(rule.failing_rows(&array, 10), vec![1]);
+    }
+
+    #[test]
+    fn test_matches_any() {
+        let array = StringArray::from(vec![
+            Some("AB-1234"),
+            Some("x9999"),
+            None,
+            Some("ab-12"),
+            Some("PRD_001"),
+        ]);
+        let rule = MatchesAny::new(
+            "MatchesAnyOf".to_string(),
+            0.0,
+            vec![r"^AB-\d{4}$".to_string(), r"^PRD_\d{3}$".to_string()],
+            None,
+        )
+        .unwrap();
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 10), vec![1, 3]);
+
+        // Flags apply to every pattern
+        let rule = MatchesAny::new(
+            "MatchesAnyOf".to_string(),
+            0.0,
+            vec![r"^ab-\d+$".to_string(), r"^x\d+$".to_string()],
+            Some("i".to_string()),
+        )
+        .unwrap();
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_matches_any_invalid_pattern() {
+        let err = MatchesAny::new(
+            "MatchesAnyOf".to_string(),
+            0.0,
+            vec!["^ok$".to_string(), "[unclosed".to_string()],
+            None,
+        )
+        .err()
+        .unwrap();
+        assert!(err.to_string().contains("'[unclosed'"));
+        assert!(MatchesAny::new("MatchesAnyOf".to_string(), 0.0, vec![], None).is_err());
+    }
+
+    #[test]
+    fn test_build_regex_invalid_flags() {
+        let err = build_regex("abc", Some("ix")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Unsupported regex flag 'x', expected any of 'i', 'm', 's' or 'u'"
+        );
+        let err = build_regex("abc", Some("imi")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Flag 'i' is repeated in 'imi'"
+        );
+        assert!(build_regex("abc", Some("")).is_ok());
+        assert!(build_regex("(abc", Some("i")).is_err());
+    }
+
+    #[test]
+    fn test_starts_with_check() {
+        let rule = StartsWithCheck::new("starts_with_test".to_string(), 0.0, "FR".to_string());
+        let array = StringArray::from(vec![
+            Some("FR-001"), // ok
+            Some("DE-002"), // error
+            Some("fr-003"), // error, case sensitive
+            None,           // ok
+            Some(""),       // error
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_ends_with_check() {
+        let rule = EndsWithCheck::new("ends_with_test".to_string(), 0.0, "-X".to_string());
+        let array = StringArray::from(vec![
+            Some("FR-001-X"), // ok
+            Some("FR-002-Y"), // error
+            Some("-X"),       // ok
+            None,             // ok
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_contains_check() {
+        let rule = ContainsCheck::new(
+            "contains_test".to_string(),
+            0.0,
+            "#ok".to_string(),
+            false,
+            false,
+        );
+        let array = StringArray::from(vec![
+            Some("done #ok"), // ok
+            Some("#OK"),      // error, case sensitive
+            Some("nothing"),  // error
+            None,             // ok
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_not_contains_check_case_insensitive() {
+        let rule = ContainsCheck::new(
+            "not_contains_test".to_string(),
+            0.0,
+            "Secret".to_string(),
+            true,
+            true,
+        );
+        let array = StringArray::from(vec![
+            Some("public note"),    // ok
+            Some("top SECRET doc"), // error
+            Some("secretive"),      // error
+            None,                   // ok
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_ip_check_v4() {
+        let rule = IpCheck::new("ip_test".to_string(), 0.0, IpVersion::V4);
+        let array = StringArray::from(vec![
+            Some("192.168.0.1"), // ok
+            Some("255.255.255.255"),
+            Some("256.1.1.1"),    // error, out of range
+            Some("192.168.0.01"), // error, leading zero
+            Some("::1"),          // error, v6
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_ip_check_v6() {
+        let rule = IpCheck::new("ip_test".to_string(), 0.0, IpVersion::V6);
+        let array = StringArray::from(vec![
+            "::1",
+            "2001:db8::8a2e:370:7334",
+            "::ffff:192.0.2.1",
+            "2001:db8:::1", // error
+            "10.0.0.1",     // error, v4
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_ip_check_any() {
+        let rule = IpCheck::new("ip_test".to_string(), 0.0, IpVersion::Any);
+        let array = StringArray::from(vec!["10.0.0.1", "fe80::1", "localhost", ""]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_ip_in_cidr() {
+        let allowed = parse_cidrs(&["10.0.0.0/8".to_string(), "fd00::/8".to_string()]).unwrap();
+        let rule = IpInCidr::new("IpInCidr".to_string(), 0.0, allowed);
+        let array = StringArray::from(vec![
+            Some("10.1.2.3"),
+            Some("fd12::1"),
+            Some("192.168.0.1"),     // error, outside the blocks
+            Some("::ffff:10.0.0.1"), // error, mapped addresses are IPv6
+            Some("10.0.0"),          // error, not an address
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 3);
+        assert_eq!(rule.failing_rows(&array, 10), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_parse_cidrs() {
+        assert!(parse_cidrs(&[" 192.168.0.0/16 ".to_string()]).is_ok());
+        for invalid in [
+            vec!["10.0.0.0/33".to_string()],
+            vec!["10.0.0.1".to_string()],
+            vec![],
+        ] {
+            assert!(matches!(
+                parse_cidrs(&invalid),
+                Err(RuleError::ValidationError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_json_check() {
+        let array = StringArray::from(vec![
+            Some(r#"{"user": "alice", "tags": [1, 2]}"#),
+            Some(" [1, 2] "),
+            Some("42"),
+            Some(r#"{"user": "alice""#),
+            Some(""),
+            None,
+        ]);
+        let any = JsonCheck::new("IsJson".to_string(), 0.0, JsonKind::Any);
+        assert_eq!(any.validate(&array, "payload".to_string()).unwrap(), 2);
+        assert_eq!(any.failing_rows(&array, 10), vec![3, 4]);
+        let object = JsonCheck::new("IsJsonObject".to_string(), 0.0, JsonKind::Object);
+        assert_eq!(object.failing_rows(&array, 10), vec![1, 2, 3, 4]);
+        let array_kind = JsonCheck::new("IsJsonArray".to_string(), 0.0, JsonKind::Array);
+        assert_eq!(array_kind.failing_rows(&array, 10), vec![0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_phone_check_international() {
+        let rule = PhoneCheck::new("IsPhone".to_string(), 0.0, None).unwrap();
+        let array = StringArray::from(vec![
+            Some("+33 1 42 68 53 00"),
+            Some("+1 202-456-1111"),
+            Some("01 42 68 53 00"), // national format needs a region
+            Some("+33 12"),
+            Some(""),
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "phone".to_string()).unwrap(), 3);
+        assert_eq!(rule.failing_rows(&array, 10), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_phone_check_region() {
+        let rule = PhoneCheck::new("IsPhone".to_string(), 0.0, Some("fr")).unwrap();
+        let array = StringArray::from(vec![
+            "01 42 68 53 00",
+            "+33 1 42 68 53 00",
+            "+1 202-456-1111", // valid, but not a French number
+            "not a phone",
+        ]);
+        assert_eq!(rule.failing_rows(&array, 10), vec![2, 3]);
+        assert!(PhoneCheck::new("IsPhone".to_string(), 0.0, Some("XX")).is_err());
+    }
+
+    #[test]
+    fn test_credit_card_check() {
+        let rule = CreditCardCheck::new("IsCreditCard".to_string(), 0.0);
+        let array = StringArray::from(vec![
+            Some("4111 1111 1111 1111"),
+            Some("5500-0000-0000-0004"),
+            Some("378282246310005"),
+            Some("4111 1111 1111 1112"), // wrong check digit
+            Some("4111 1111 1111 111a"),
+            Some("0000 0000"), // too short
+            Some(""),
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "card".to_string()).unwrap(), 4);
+        assert_eq!(rule.failing_rows(&array, 10), vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_iban_check() {
+        let rule = IbanCheck::new("IsIban".to_string(), 0.0);
+        let array = StringArray::from(vec![
+            Some("GB82 WEST 1234 5698 7654 32"),
+            Some("DE89370400440532013000"),
+            Some("fr14 2004 1010 0505 0001 3m02 606"),
+            Some("GB82 WEST 1234 5698 7654 33"), // wrong check digits
+            Some("GB82-WEST-1234-5698-7654-32"),
+            Some("1282 WEST 1234 5698 7654 32"),
+            Some("GB82"),
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "iban".to_string()).unwrap(), 4);
+        assert_eq!(rule.failing_rows(&array, 10), vec![3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_base64_check() {
+        let strict = Base64Check::new("IsBase64".to_string(), 0.0, true);
+        let lenient = Base64Check::new("IsBase64".to_string(), 0.0, false);
+        let array = StringArray::from(vec![
+            Some("aGVsbG8="),
+            Some("aGk="),
+            Some("aGVsbG8h"),
+            Some("a+/9Zz=="),
+            Some("aGVsbG8"),   // padding left out
+            Some("aGk"),       // padding left out
+            Some("aGVsbG8h="), // padded past a multiple of 4
+            Some("aG=k"),      // padding before data
+            Some("aGVsb"),     // a single character in the last group
+            Some("aGVs bG8="),
+            Some("aGVsbG8-"), // URL-safe alphabet
+            Some("===="),
+            Some(""),
+            None,
+        ]);
+        assert_eq!(strict.validate(&array, "blob".to_string()).unwrap(), 9);
+        assert_eq!(
+            strict.failing_rows(&array, 20),
+            vec![4, 5, 6, 7, 8, 9, 10, 11, 12]
+        );
+        assert_eq!(
+            lenient.failing_rows(&array, 20),
+            vec![6, 7, 8, 9, 10, 11, 12]
+        );
+    }
+
+    #[test]
+    fn test_hex_check() {
+        let plain = HexCheck::new("IsHex".to_string(), 0.0, false);
+        let prefixed = HexCheck::new("IsHex".to_string(), 0.0, true);
+        let array = StringArray::from(vec![
+            Some("deadBEEF"),
+            Some("00ff"),
+            Some("0xdeadbeef"),
+            Some("0X00"),
+            Some("abc"), // odd length
+            Some("0xabc"),
+            Some("ghij"),
+            Some("0x"),
+            Some(""),
+            None,
+        ]);
+        // The prefix is only accepted when allowed, the digits must still be of even length
+        assert_eq!(plain.failing_rows(&array, 10), vec![2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(prefixed.failing_rows(&array, 10), vec![4, 5, 6, 7, 8]);
+        assert_eq!(prefixed.validate(&array, "hash".to_string()).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_case_check_ascii() {
+        let upper = CaseCheck::new("IsUpperCase".to_string(), 0.0, true, false);
+        let lower = CaseCheck::new("IsLowerCase".to_string(), 0.0, false, false);
+        let array = StringArray::from(vec![Some("ABC-1 D"), Some("abc"), Some("ÉTÉ"), None]);
+        assert_eq!(upper.validate(&array, "col".to_string()).unwrap(), 2);
+        assert_eq!(upper.failing_rows(&array, 10), vec![1, 2]);
+        // Accented letters are not ASCII, they fail both checks
+        assert_eq!(lower.validate(&array, "col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_case_check_unicode() {
+        let upper = CaseCheck::new("IsUpperCase".to_string(), 0.0, true, true);
+        let lower = CaseCheck::new("IsLowerCase".to_string(), 0.0, false, true);
+        let array = StringArray::from(vec!["ÉTÉ", "ÅSA-ØRN", "été", "straße", "Ölçü", "ΣΟΦΙΑ"]);
+        assert_eq!(upper.failing_rows(&array, 10), vec![2, 3, 4]);
+        assert_eq!(lower.failing_rows(&array, 10), vec![0, 1, 4, 5]);
+    }
+
+    #[test]
+    fn test_no_surrounding_whitespace() {
+        let rule = NoSurroundingWhitespace::new("NoSurroundingWhitespace".to_string(), 0.0);
+        let array = StringArray::from(vec![
+            Some("alice"),
+            Some(" bob"),
+            Some("carol\t"),
+            Some("dan smith"),
+            Some(""),
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 10), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_no_whitespace() {
+        let rule = NoWhitespace::new("NoWhitespace".to_string(), 0.0);
+        let array = StringArray::from(vec![Some("alice"), Some(" bob"), Some("dan smith"), None]);
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 10), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_contains_only() {
+        let rule = ContainsOnly::new("ContainsOnly".to_string(), 0.0, "ACGT".chars().collect());
+        let array = StringArray::from(vec![
+            Some("GATTACA"),
+            Some("gattaca"),
+            Some("ACGN"),
+            Some(""),
+            None,
+        ]);
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 10), vec![1, 2]);
+    }
+
     #[test]
     fn test_is_in_check_basic() {
         let members = vec!["apple".to_string(), "banana".to_string()];
@@ -291,4 +1786,58 @@ mod tests {
         // Expected errors: "apple", "banana" (2 errors)
         assert_eq!(rule.validate(&array, "test_col".to_string()).unwrap(), 2);
     }
+
+    #[test]
+    fn test_is_not_in_check() {
+        let members = vec!["legacy".to_string(), "retired".to_string()];
+        let rule = IsNotInCheck::new("NotIn".to_string(), 0.0, members);
+        let array = StringArray::from(vec![
+            Some("active"),
+            Some("legacy"),
+            None, // Nulls are not in the blocklist
+            Some("Retired"),
+            Some("retired"),
+        ]);
+        assert_eq!(rule.validate(&array, "category".to_string()).unwrap(), 2);
+        assert_eq!(rule.failing_rows(&array, 10), vec![1, 4]);
+
+        // A value absent from the blocklist and a null both pass
+        let array = StringArray::from(vec![Some("active"), None]);
+        assert_eq!(rule.validate(&array, "category".to_string()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_failing_rows_skip_nulls_and_respect_limit() {
+        let rule = StringLengthCheck::new("len".to_string(), 0.0, Some(3), None);
+        let array = StringArray::from(vec![Some("a"), None, Some("abc"), Some("b"), Some("c")]);
+        assert_eq!(rule.failing_rows(&array, 5), vec![0, 3, 4]);
+        assert_eq!(rule.failing_rows(&array, 2), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_regex_failing_rows() {
+        let rule = RegexMatch::new("regex".to_string(), 0.0, "^[a-z]+$".to_string(), None).unwrap();
+        let array = StringArray::from(vec![Some("abc"), Some("ab1"), None, Some("X")]);
+        assert_eq!(rule.failing_rows(&array, 5), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_consistent_length_check() {
+        let rule = ConsistentLengthCheck::new("ConsistentLength".to_string(), 0.0, 1);
+        // 5 values of length 10, 2 of length 11 (within tolerance), 3 outliers
+        let lengths = HashMap::from([(10, 5), (11, 2), (4, 1), (15, 2)]);
+        assert_eq!(rule.validate(&lengths), 3);
+        assert_eq!(
+            rule.observed(&lengths),
+            vec![("dominant_length".to_string(), 10.0)]
+        );
+
+        // Ties go to the shorter length
+        let tied = HashMap::from([(3, 2), (8, 2)]);
+        assert_eq!(rule.observed(&tied)[0].1, 3.0);
+        assert_eq!(rule.validate(&tied), 2);
+
+        assert_eq!(rule.validate(&HashMap::new()), 0);
+        assert!(rule.observed(&HashMap::new()).is_empty());
+    }
 }
@@ -0,0 +1,285 @@
+use arrow::datatypes::{DataType, TimeUnit};
+use arrow_array::{Array, StringArray, TimestampMillisecondArray};
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::{rules::first_failing_rows, utils::date_parser::parse_timestamp_column, RuleError};
+
+const MILLIS_PER_HOUR: i64 = 3_600_000;
+const MILLIS_PER_DAY: i64 = 24 * MILLIS_PER_HOUR;
+
+/// Formats accepted for timestamp bounds given to the rules
+const LITERAL_FORMATS: [&str; 2] = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"];
+
+/// Parse a timestamp bound such as `2024-01-31 18:00:00`, a bare date is read as midnight.
+///
+/// Returns the number of milliseconds since the Unix epoch.
+pub(crate) fn parse_timestamp_literal(value: &str) -> Result<i64, RuleError> {
+    LITERAL_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|datetime| datetime.and_utc().timestamp_millis())
+        .ok_or_else(|| {
+            RuleError::ValidationError(format!(
+                "Invalid timestamp '{}', expected YYYY-MM-DD HH:MM:SS",
+                value
+            ))
+        })
+}
+
+/// Hours of a time window must be distinct and within a day.
+pub(crate) fn check_time_window(start_hour: u32, end_hour: u32) -> Result<(), RuleError> {
+    if start_hour > 24 || end_hour > 24 || start_hour == end_hour {
+        return Err(RuleError::ValidationError(format!(
+            "Invalid time window {}h to {}h, hours must be distinct and between 0 and 24",
+            start_hour, end_hour
+        )));
+    }
+    Ok(())
+}
+
+pub struct TimestampTypeCheck {
+    _column: String,
+    threshold: f64,
+    format: String,
+}
+
+impl TimestampTypeCheck {
+    pub fn new(column: String, format: String, threshold: f64) -> Self {
+        Self {
+            _column: column,
+            threshold,
+            format,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        "TypeCheck".to_string()
+    }
+
+    pub fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    /// The Arrow type produced by the type check
+    pub fn data_type() -> DataType {
+        DataType::Timestamp(TimeUnit::Millisecond, None)
+    }
+
+    pub fn validate(
+        &self,
+        array: &dyn Array,
+    ) -> Result<(usize, TimestampMillisecondArray), RuleError> {
+        let base_nulls = array.null_count();
+        // Same as dates, incoming CSV columns are always read as StringArray
+        let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+        let casted_array = parse_timestamp_column(array, &self.format);
+        let errors = casted_array.null_count() - base_nulls;
+        Ok((errors, casted_array))
+    }
+}
+
+/// A trait for defining validation rules on timestamp arrays.
+pub trait TimestampRule: Send + Sync {
+    /// Returns the name of the rule.
+    fn name(&self) -> String;
+    /// Returns the rule threshold
+    fn get_threshold(&self) -> f64;
+    /// Validates an Arrow `TimestampMillisecondArray`.
+    fn validate(
+        &self,
+        array: &TimestampMillisecondArray,
+        column: String,
+    ) -> Result<usize, RuleError>;
+    /// Returns the positions of the first `limit` failing values, used for reporting samples.
+    fn failing_rows(&self, _array: &TimestampMillisecondArray, _limit: usize) -> Vec<usize> {
+        Vec::new()
+    }
+}
+
+/// Timestamps must be strictly before (or after) a bound, at millisecond resolution.
+pub struct TimestampBoundaryCheck {
+    name: String,
+    threshold: f64,
+    after: bool,
+    millis: i64,
+}
+
+impl TimestampBoundaryCheck {
+    pub fn new(name: String, threshold: f64, after: bool, millis: i64) -> Self {
+        Self {
+            name,
+            threshold,
+            after,
+            millis,
+        }
+    }
+
+    fn is_violation(&self, value: i64) -> bool {
+        if self.after {
+            value <= self.millis
+        } else {
+            value >= self.millis
+        }
+    }
+}
+
+impl TimestampRule for TimestampBoundaryCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(
+        &self,
+        array: &TimestampMillisecondArray,
+        _column: String,
+    ) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| self.is_violation(*v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &TimestampMillisecondArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| self.is_violation(v))
+    }
+}
+
+/// The time of day must fall within `[start_hour, end_hour)`.
+///
+/// A window with `start_hour > end_hour` wraps around midnight, 22 to 6 accepts
+/// night times.
+pub struct TimeOfDayCheck {
+    name: String,
+    threshold: f64,
+    start: i64,
+    end: i64,
+}
+
+impl TimeOfDayCheck {
+    pub fn new(
+        name: String,
+        threshold: f64,
+        start_hour: u32,
+        end_hour: u32,
+    ) -> Result<Self, RuleError> {
+        check_time_window(start_hour, end_hour)?;
+        Ok(Self {
+            name,
+            threshold,
+            start: start_hour as i64 * MILLIS_PER_HOUR,
+            end: end_hour as i64 * MILLIS_PER_HOUR,
+        })
+    }
+
+    fn is_violation(&self, value: i64) -> bool {
+        let time = value.rem_euclid(MILLIS_PER_DAY);
+        if self.start < self.end {
+            !(self.start..self.end).contains(&time)
+        } else {
+            (self.end..self.start).contains(&time)
+        }
+    }
+}
+
+impl TimestampRule for TimeOfDayCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn get_threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn validate(
+        &self,
+        array: &TimestampMillisecondArray,
+        _column: String,
+    ) -> Result<usize, RuleError> {
+        Ok(array
+            .iter()
+            .flatten()
+            .filter(|v| self.is_violation(*v))
+            .count())
+    }
+
+    fn failing_rows(&self, array: &TimestampMillisecondArray, limit: usize) -> Vec<usize> {
+        first_failing_rows(array.iter(), limit, |v| self.is_violation(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(value: &str) -> i64 {
+        parse_timestamp_literal(value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_timestamp_literal() {
+        assert_eq!(ts("1970-01-01T00:00:01"), 1_000);
+        assert_eq!(ts("1970-01-02 01:00:00"), MILLIS_PER_DAY + MILLIS_PER_HOUR);
+        assert_eq!(ts("1970-01-02"), MILLIS_PER_DAY);
+        assert!(parse_timestamp_literal("2024-13-01").is_err());
+    }
+
+    #[test]
+    fn test_timestamp_type_check() {
+        let rule = TimestampTypeCheck::new("col".to_string(), "%Y-%m-%d %H:%M:%S".to_string(), 0.0);
+        let array = StringArray::from(vec![Some("2024-01-31 18:30:00"), Some("2024-01-31"), None]);
+        let (errors, casted) = rule.validate(&array).unwrap();
+        assert_eq!(errors, 1);
+        assert_eq!(casted.value(0), ts("2024-01-31 18:30:00"));
+    }
+
+    #[test]
+    fn test_timestamp_boundary_sub_day() {
+        let bound = ts("2024-01-31 12:00:00");
+        let before = TimestampBoundaryCheck::new("IsBefore".to_string(), 0.0, false, bound);
+        let after = TimestampBoundaryCheck::new("IsAfter".to_string(), 0.0, true, bound);
+        let array = TimestampMillisecondArray::from(vec![
+            Some(ts("2024-01-31 11:59:59")),
+            Some(bound),
+            Some(ts("2024-01-31 12:00:01")),
+            None,
+        ]);
+        assert_eq!(before.validate(&array, "col".to_string()).unwrap(), 2);
+        assert_eq!(before.failing_rows(&array, 10), vec![1, 2]);
+        assert_eq!(after.validate(&array, "col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_time_between() {
+        let rule = TimeOfDayCheck::new("TimeBetween".to_string(), 0.0, 9, 17).unwrap();
+        let array = TimestampMillisecondArray::from(vec![
+            ts("2024-01-31 09:00:00"), // ok, start included
+            ts("2024-01-31 16:59:59"), // ok
+            ts("2024-01-31 17:00:00"), // violation, end excluded
+            ts("1969-12-31 08:00:00"), // violation, before the epoch
+        ]);
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_time_between_wraps_midnight() {
+        let rule = TimeOfDayCheck::new("TimeBetween".to_string(), 0.0, 22, 6).unwrap();
+        let array = TimestampMillisecondArray::from(vec![
+            ts("2024-01-31 23:00:00"), // ok
+            ts("2024-01-31 05:59:59"), // ok
+            ts("2024-01-31 12:00:00"), // violation
+        ]);
+        assert_eq!(rule.validate(&array, "col".to_string()).unwrap(), 1);
+        assert!(TimeOfDayCheck::new("TimeBetween".to_string(), 0.0, 9, 9).is_err());
+        assert!(TimeOfDayCheck::new("TimeBetween".to_string(), 0.0, 9, 25).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
+
+use crate::{
+    readers::{source_reader::spawn_stream, FileFormat, ReadMode},
+    tables::{BatchStream, FileTable, FormatReader},
+    RuleError,
+};
+
+/// A table validating Arrow record batches already in memory.
+///
+/// Columns keep their native Arrow types, as with Parquet files: strings are `Utf8`,
+/// integers `Int64`, floats `Float64`, dates `Date32` and booleans `Boolean`.
+/// Narrower types of the same family (`Int32`, `Float32`, `LargeUtf8`, `Utf8View`, ...)
+/// are widened before validation, other types fail the `TypeCheck` of their column.
+pub type ArrowTable = FileTable<ArrowReader>;
+
+/// Hands the in-memory batches of an [`ArrowTable`] over, nothing is read from disk
+pub struct ArrowReader {
+    schema: SchemaRef,
+    batches: Vec<Arc<RecordBatch>>,
+}
+
+impl ArrowTable {
+    /// Create a table over `batches`, which must all share `schema`
+    pub fn new(table_name: String, schema: SchemaRef, batches: Vec<RecordBatch>) -> Self {
+        let reader = ArrowReader {
+            schema,
+            batches: batches.into_iter().map(Arc::new).collect(),
+        };
+        Self::from_reader(reader, table_name)
+    }
+}
+
+impl ArrowReader {
+    /// Keep the requested columns of every batch, as the file readers do
+    fn project(&self, columns: &[String]) -> Result<Vec<RecordBatch>, RuleError> {
+        let indices: Vec<usize> = columns
+            .iter()
+            .filter_map(|c| self.schema.index_of(c).ok())
+            .collect();
+        self.batches
+            .iter()
+            .map(|batch| Ok(batch.project(&indices)?))
+            .collect()
+    }
+}
+
+impl FormatReader for ArrowReader {
+    const FORMAT: FileFormat = FileFormat::Arrow;
+    const TYPED: bool = true;
+
+    fn path(&self) -> Option<&str> {
+        None
+    }
+
+    fn schema(&self) -> Result<SchemaRef, RuleError> {
+        Ok(self.schema.clone())
+    }
+
+    fn read(
+        &self,
+        columns: Vec<String>,
+        _read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        Ok(self.project(&columns)?.into_iter().map(Arc::new).collect())
+    }
+
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError> {
+        Ok(spawn_stream(self.project(&columns)?.into_iter().map(Ok)))
+    }
+}
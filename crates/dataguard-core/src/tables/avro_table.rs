@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
+
+use crate::{
+    readers::{avro_schema, read_avro_sequential, read_avro_streaming, FileFormat, ReadMode},
+    tables::{BatchStream, FileTable, FormatReader},
+    RuleError,
+};
+
+/// A table reading an Avro object container file.
+///
+/// Columns keep the types of the Avro schema, `int` and `float` fields are widened
+/// before validation as for `ArrowTable`.
+pub type AvroTable = FileTable<AvroReader>;
+
+/// Reads the Avro file of an [`AvroTable`]
+pub struct AvroReader {
+    path: String,
+}
+
+impl AvroTable {
+    /// Create a new table reading an Avro file
+    pub fn new(path: String, table_name: String) -> Result<Self, RuleError> {
+        Ok(Self::from_reader(AvroReader { path }, table_name))
+    }
+}
+
+impl FormatReader for AvroReader {
+    const FORMAT: FileFormat = FileFormat::Avro;
+    const TYPED: bool = true;
+
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    /// Schema decoded from the Avro writer schema
+    fn schema(&self) -> Result<SchemaRef, RuleError> {
+        Ok(avro_schema(&self.path)?)
+    }
+
+    /// Container blocks can not be split between threads, the file is read sequentially
+    fn read(
+        &self,
+        columns: Vec<String>,
+        _read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        Ok(read_avro_sequential(&self.path, columns)?)
+    }
+
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError> {
+        Ok(read_avro_streaming(&self.path, columns)?)
+    }
+}
@@ -1,117 +1,87 @@
-use std::collections::HashMap;
+use std::io;
+use std::sync::Arc;
+
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
 
-use crate::columns::{relation_builder::RelationBuilder, ColumnBuilder};
 use crate::errors::RuleError;
-use crate::readers::csv_reader::read_csv_parallel_with_config;
-use crate::readers::ReaderConfig;
-use crate::tables::Table;
-use crate::validator::{ExecutableColumn, ExecutableRelation};
-use crate::{compiler, engine, ValidationResult};
+use crate::readers::csv_reader::{
+    read_csv_parallel_with_config, read_csv_sequential_with_config, read_csv_streaming,
+};
+use crate::readers::{
+    csv_column_names, is_gzip, read_streaming_from, FileFormat, ReadMode, ReaderConfig,
+};
+use crate::tables::{utf8_schema, BatchStream, FileTable, FormatReader};
+
+/// A table reading a CSV file, optionally gzip compressed.
+///
+/// Values are read as strings and parsed by the `TypeCheck` of their column.
+pub type CsvTable = FileTable<CsvReader>;
 
-pub struct CsvTable {
+/// Reads the CSV file of a [`CsvTable`]
+pub struct CsvReader {
     path: String,
-    table_name: String,
-    executable_columns: Box<[ExecutableColumn]>,
-    executable_relations: Option<Box<[ExecutableRelation]>>,
+    config: ReaderConfig,
 }
 
 impl CsvTable {
     /// Create a new Validator instance
     pub fn new(path: String, table_name: String) -> Result<Self, RuleError> {
-        Ok(Self {
+        let reader = CsvReader {
             path,
-            table_name,
-            executable_columns: Box::new([]),
-            executable_relations: None,
-        })
+            config: ReaderConfig::default(),
+        };
+        Ok(Self::from_reader(reader, table_name))
     }
-}
 
-impl Table for CsvTable {
-    /// Commit column configurations and compile them into executable rules
-    fn prepare(
-        &mut self,
-        columns: Vec<Box<dyn ColumnBuilder>>,
-        relations: Vec<RelationBuilder>,
-    ) -> Result<(), RuleError> {
-        // Build column type map before consuming the columns vector
-        let column_types = compiler::build_column_type_map(&columns);
+    /// Set the reader options (delimiter, quote, header, chunking)
+    pub fn with_reader_config(mut self, config: ReaderConfig) -> Self {
+        self.reader.config = config;
+        self
+    }
+}
 
-        self.executable_columns = columns
-            .into_iter()
-            .map(|col| compiler::compile_column(col, true))
-            .collect::<Result<Vec<_>, _>>()?
-            .into_boxed_slice();
+impl FormatReader for CsvReader {
+    const FORMAT: FileFormat = FileFormat::Csv;
+    const TYPED: bool = false;
 
-        self.executable_relations = Some(
-            relations
-                .into_iter()
-                .map(|rel| compiler::compile_relations(rel, &column_types))
-                .collect::<Result<Vec<_>, _>>()?
-                .into_boxed_slice(),
-        );
-        Ok(())
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
     }
 
-    /// Validate a CSV file against the committed rules
-    fn validate(&mut self) -> Result<ValidationResult, RuleError> {
-        let needed_cols: Vec<String> = self
-            .executable_columns
-            .iter()
-            .map(|v| v.get_name())
-            .collect();
-        let config = ReaderConfig::default();
-        let batches = read_csv_parallel_with_config(self.path.as_str(), needed_cols, &config)?;
-        //let batches = read_csv_parallel(self.path.as_str(), needed_cols)?;
-        // What should i do with this!
-        let _existing_cols = self
-            .executable_columns
-            .iter()
-            .filter(|exec| batches[0].schema().index_of(&exec.get_name()).is_ok())
-            .collect::<Vec<&ExecutableColumn>>()
-            .into_boxed_slice();
-        let engine =
-            engine::ValidationEngine::new(&self.executable_columns, &self.executable_relations);
-        engine.validate_batches(self.table_name.clone(), &batches)
+    /// Columns of the CSV header
+    fn schema(&self) -> Result<SchemaRef, RuleError> {
+        Ok(utf8_schema(csv_column_names(&self.path, &self.config)?))
     }
 
-    /// Get a summary of configured rules
-    fn get_rules(&self) -> HashMap<String, Vec<String>> {
-        let mut result = HashMap::new();
-        for column in &self.executable_columns {
-            match column {
-                ExecutableColumn::String { name, rules, .. } => {
-                    let mut rule_names = vec!["TypeCheck".to_string()];
-                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
-                    result.insert(name.clone(), rule_names);
-                }
-                ExecutableColumn::Integer {
-                    name,
-                    domain_rules: rules,
-                    statistical_rules,
-                    ..
-                } => {
-                    let mut rule_names = vec!["TypeCheck".to_string()];
-                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
-                    rule_names.extend(statistical_rules.iter().map(|r| r.name().to_string()));
-                    result.insert(name.clone(), rule_names);
-                }
-                ExecutableColumn::Float {
-                    name,
-                    domain_rules: rules,
-                    ..
-                } => {
-                    let mut rule_names = vec!["TypeCheck".to_string()];
-                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
-                    result.insert(name.clone(), rule_names);
-                }
-                ExecutableColumn::Date { name, rules, .. } => {
-                    let mut rule_names = vec!["TypeCheck".to_string()];
-                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
-                    result.insert(name.clone(), rule_names);
-                }
+    fn read(
+        &self,
+        columns: Vec<String>,
+        read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        let batches = match read_mode {
+            ReadMode::Sequential => {
+                read_csv_sequential_with_config(&self.path, columns, &self.config)?
             }
-        }
-        result
+            _ => read_csv_parallel_with_config(&self.path, columns, &self.config)?,
+        };
+        Ok(batches)
+    }
+
+    fn sequential_reason(&self) -> Option<String> {
+        is_gzip(&self.path)
+            .then(|| "gzip compressed files can not be split between threads".to_string())
+    }
+
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError> {
+        Ok(read_csv_streaming(&self.path, columns, &self.config)?)
+    }
+
+    fn read_stdin(&self, columns: Vec<String>) -> Result<(Vec<String>, BatchStream), RuleError> {
+        Ok(read_streaming_from(
+            io::stdin(),
+            FileFormat::Csv,
+            columns,
+            &self.config,
+        )?)
     }
 }
@@ -0,0 +1,108 @@
+use std::collections::HashSet;
+use std::io::{self, BufReader};
+use std::sync::Arc;
+
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
+
+use crate::errors::RuleError;
+use crate::readers::fixed_width_reader::fixed_width_stream_from;
+use crate::readers::{
+    fixed_width_read_parallel, fixed_width_read_sequential, fixed_width_read_streaming, FileFormat,
+    FixedWidthField, ReadMode, ReaderConfig,
+};
+use crate::tables::{utf8_schema, BatchStream, FileTable, FormatReader};
+
+/// A table reading a fixed-width file, columns are sliced from each line at their
+/// configured position.
+///
+/// Values are read as strings, type checking works the same way as for CSV.
+pub type FixedWidthTable = FileTable<FixedWidthReader>;
+
+/// Reads the fixed-width file of a [`FixedWidthTable`]
+pub struct FixedWidthReader {
+    path: String,
+    // Position of every column of the file
+    fields: Vec<FixedWidthField>,
+}
+
+impl FixedWidthTable {
+    /// Create a new table reading a fixed-width file whose columns are at `fields`
+    ///
+    /// Fails when a field is empty or when two fields share a name.
+    pub fn new(
+        path: String,
+        table_name: String,
+        fields: Vec<FixedWidthField>,
+    ) -> Result<Self, RuleError> {
+        let mut names = HashSet::new();
+        for field in &fields {
+            if field.width == 0 {
+                return Err(RuleError::ValidationError(format!(
+                    "Fixed-width field '{}' has a width of 0",
+                    field.name
+                )));
+            }
+            if !names.insert(field.name.as_str()) {
+                return Err(RuleError::ValidationError(format!(
+                    "Fixed-width field '{}' is positioned twice",
+                    field.name
+                )));
+            }
+        }
+        Ok(Self::from_reader(
+            FixedWidthReader { path, fields },
+            table_name,
+        ))
+    }
+}
+
+impl FormatReader for FixedWidthReader {
+    const FORMAT: FileFormat = FileFormat::FixedWidth;
+    const TYPED: bool = false;
+
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    /// The positioned fields, the file has no header
+    fn schema(&self) -> Result<SchemaRef, RuleError> {
+        Ok(utf8_schema(
+            self.fields.iter().map(|f| f.name.clone()).collect(),
+        ))
+    }
+
+    fn read(
+        &self,
+        columns: Vec<String>,
+        read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        let batches = match read_mode {
+            ReadMode::Sequential => fixed_width_read_sequential(&self.path, &self.fields, columns)?,
+            _ => fixed_width_read_parallel(
+                &self.path,
+                &self.fields,
+                columns,
+                &ReaderConfig::default(),
+            )?,
+        };
+        Ok(batches)
+    }
+
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError> {
+        Ok(fixed_width_read_streaming(
+            &self.path,
+            &self.fields,
+            columns,
+            &ReaderConfig::default(),
+        )?)
+    }
+
+    fn read_stdin(&self, columns: Vec<String>) -> Result<(Vec<String>, BatchStream), RuleError> {
+        Ok(fixed_width_stream_from(
+            BufReader::new(io::stdin()),
+            &self.fields,
+            columns,
+            &ReaderConfig::default(),
+        ))
+    }
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
+
+use crate::{
+    readers::{ipc_schema, read_ipc_sequential, read_ipc_streaming, FileFormat, ReadMode},
+    tables::{BatchStream, FileTable, FormatReader},
+    RuleError,
+};
+
+/// A table reading an Arrow IPC file, also known as Feather v2.
+///
+/// Columns keep the types stored in the file, narrower integers, floats and strings
+/// are widened before validation as for `ArrowTable`.
+pub type IpcTable = FileTable<IpcReader>;
+
+/// Reads the Arrow IPC file of an [`IpcTable`]
+pub struct IpcReader {
+    path: String,
+}
+
+impl IpcTable {
+    /// Create a new table reading an Arrow IPC file
+    pub fn new(path: String, table_name: String) -> Result<Self, RuleError> {
+        Ok(Self::from_reader(IpcReader { path }, table_name))
+    }
+}
+
+impl FormatReader for IpcReader {
+    const FORMAT: FileFormat = FileFormat::Arrow;
+    const TYPED: bool = true;
+
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    /// Schema of the IPC footer
+    fn schema(&self) -> Result<SchemaRef, RuleError> {
+        Ok(ipc_schema(&self.path)?)
+    }
+
+    fn read(
+        &self,
+        columns: Vec<String>,
+        _read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        Ok(read_ipc_sequential(&self.path, columns)?)
+    }
+
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError> {
+        Ok(read_ipc_streaming(&self.path, columns)?)
+    }
+}
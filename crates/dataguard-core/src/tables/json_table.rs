@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
+
+use crate::readers::{
+    json_column_names, json_read_parallel, json_read_sequential, json_read_streaming, FileFormat,
+    ReadMode, ReaderConfig,
+};
+use crate::tables::{utf8_schema, BatchStream, FileTable, FormatReader};
+use crate::RuleError;
+
+/// A table reading a newline-delimited JSON file.
+///
+/// Values are read as strings, type checking works the same way as for CSV.
+pub type JsonTable = FileTable<JsonReader>;
+
+/// Reads the newline-delimited JSON file of a [`JsonTable`]
+pub struct JsonReader {
+    path: String,
+}
+
+impl JsonTable {
+    /// Create a new table reading a newline-delimited JSON file
+    pub fn new(path: String, table_name: String) -> Result<Self, RuleError> {
+        Ok(Self::from_reader(JsonReader { path }, table_name))
+    }
+}
+
+impl FormatReader for JsonReader {
+    const FORMAT: FileFormat = FileFormat::Json;
+    const TYPED: bool = false;
+
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    /// Keys of the first records
+    fn schema(&self) -> Result<SchemaRef, RuleError> {
+        Ok(utf8_schema(json_column_names(&self.path)?))
+    }
+
+    fn read(
+        &self,
+        columns: Vec<String>,
+        read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        let batches = match read_mode {
+            ReadMode::Sequential => json_read_sequential(&self.path, columns)?,
+            _ => json_read_parallel(&self.path, columns, &ReaderConfig::default())?,
+        };
+        Ok(batches)
+    }
+
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError> {
+        Ok(json_read_streaming(
+            &self.path,
+            columns,
+            &ReaderConfig::default(),
+        )?)
+    }
+}
@@ -1,11 +1,33 @@
 use std::collections::HashMap;
+use std::io;
+use std::sync::{mpsc::Receiver, Arc};
+use std::time::Instant;
+
+use arrow::{
+    array::ArrayRef,
+    compute::cast,
+    datatypes::{DataType, Field, Schema, SchemaRef},
+    record_batch::{RecordBatch, RecordBatchOptions},
+};
 
 use crate::{
-    columns::{relation_builder::RelationBuilder, ColumnBuilder},
-    RuleError, ValidationResult,
+    columns::{group_builder::GroupBuilder, relation_builder::RelationBuilder, ColumnBuilder},
+    compiler, engine,
+    readers::{
+        read_first_rows, read_streaming_from, verify_format, FileFormat, ReadMode, ReaderConfig,
+        DEFAULT_MEMORY_BUDGET, STDIN_PATH,
+    },
+    validator::{ExecutableColumn, ExecutableGroup, ExecutableRelation},
+    Progress, RuleError, RuleResult, ValidationResult,
 };
 
+pub mod arrow_table;
+pub mod avro_table;
 pub mod csv_table;
+pub mod fixed_width_table;
+pub mod ipc_table;
+pub mod json_table;
+pub mod orc_table;
 pub mod parquet_table;
 
 pub trait Table {
@@ -15,5 +37,968 @@ pub trait Table {
         columns: Vec<Box<dyn ColumnBuilder>>,
         relations: Vec<RelationBuilder>,
     ) -> Result<(), RuleError>;
+    fn prepare_groups(&mut self, groups: Vec<GroupBuilder>) -> Result<(), RuleError>;
     fn get_rules(&self) -> HashMap<String, Vec<String>>;
+    /// Count the rows validated by the next calls to `validate` in `progress`
+    fn set_progress(&mut self, progress: Arc<Progress>);
+    /// Only validate `columns`, must be called after `prepare_groups`.
+    ///
+    /// The rules of the other columns are dropped along with the relations and group
+    /// assertions using them, so that only the selected columns are read. The schema is
+    /// no longer checked strictly, the other file columns are expected.
+    fn select_columns(&mut self, columns: &[String]);
+    /// Read a single column from the underlying file, used by cross-table checks
+    fn read_column(&self, column: &str) -> Result<Vec<ArrayRef>, RuleError>;
+    /// Collect the values of `column` while validating, for a foreign key using it.
+    ///
+    /// The column is read along with the rules. Its values are only attached to a result
+    /// covering the whole table, the validator reads the column again otherwise.
+    fn collect_keys(&mut self, column: &str);
+    /// Check the configured columns against the file schema without reading any row, only
+    /// the header or footer is read. Formats storing their types also have the declared
+    /// types checked. The standard input can not be read ahead, its schema is not checked.
+    fn dry_run(&self) -> Result<ValidationResult, RuleError>;
+}
+
+/// Batches decoded on a background thread, see [`FormatReader::read_streaming`]
+pub type BatchStream = Receiver<Result<Arc<RecordBatch>, io::Error>>;
+
+/// Reads the rows of a [`FileTable`] in one format.
+///
+/// Readers only read: the schema checks, the validation and the post-processing of the
+/// result are shared by every table.
+pub trait FormatReader {
+    /// Format of the file, checked against its content before it is read
+    const FORMAT: FileFormat;
+    /// Whether the format stores the type of its columns. Typed columns get no
+    /// `TypeCheck`, their declared type is compared to the rules and narrower types are
+    /// widened before validation.
+    const TYPED: bool;
+
+    /// Path of the file, `None` for batches already in memory
+    fn path(&self) -> Option<&str>;
+    /// Schema found in the header or footer of the file, without reading any row.
+    /// Untyped formats declare every column as `Utf8`.
+    fn schema(&self) -> Result<SchemaRef, RuleError>;
+    /// Read `columns` at once, `read_mode` is either `Parallel` or `Sequential`. Formats
+    /// that can not be split between threads are read sequentially.
+    fn read(
+        &self,
+        columns: Vec<String>,
+        read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError>;
+    /// Why the file can not be split between threads, a `Parallel` read then falls back
+    /// to `Sequential` and the reason is noted on the result
+    fn sequential_reason(&self) -> Option<String> {
+        None
+    }
+    /// Read `columns` batch by batch, only a few batches are held in memory
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError>;
+    /// Read `columns` from the standard input, along with the column names found in its head
+    fn read_stdin(&self, columns: Vec<String>) -> Result<(Vec<String>, BatchStream), RuleError> {
+        let config = ReaderConfig::default();
+        Ok(read_streaming_from(
+            io::stdin(),
+            Self::FORMAT,
+            columns,
+            &config,
+        )?)
+    }
+}
+
+/// Schema of an untyped format, whose values are all read as strings
+pub(crate) fn utf8_schema(columns: Vec<String>) -> SchemaRef {
+    let fields: Vec<Field> = columns
+        .into_iter()
+        .map(|name| Field::new(name, DataType::Utf8, true))
+        .collect();
+    Arc::new(Schema::new(fields))
+}
+
+/// Options shared by every table, set through the `with_*` methods of [`FileTable`]
+#[derive(Clone)]
+pub(crate) struct TableOptions {
+    max_samples: usize,
+    parallel_axis: engine::ParallelAxis,
+    progress: Option<Arc<Progress>>,
+    profile: bool,
+    // Columns whose most frequent values are counted
+    value_counts: Vec<String>,
+    duplicate_rows: bool,
+    // Columns whose values are collected for the foreign keys
+    key_columns: Vec<String>,
+    fail_fast: bool,
+    max_errors: Option<usize>,
+    strict_schema: bool,
+    row_limit: Option<usize>,
+    min_rows: Option<usize>,
+    max_rows: Option<usize>,
+    read_mode: ReadMode,
+    memory_budget: u64,
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self {
+            max_samples: engine::DEFAULT_MAX_SAMPLES,
+            parallel_axis: engine::ParallelAxis::Auto,
+            progress: None,
+            profile: false,
+            value_counts: Vec::new(),
+            duplicate_rows: false,
+            key_columns: Vec::new(),
+            fail_fast: false,
+            max_errors: None,
+            strict_schema: false,
+            row_limit: None,
+            min_rows: None,
+            max_rows: None,
+            read_mode: ReadMode::Auto,
+            memory_budget: DEFAULT_MEMORY_BUDGET,
+        }
+    }
+}
+
+/// A table validating the rows of a file, read by a [`FormatReader`].
+///
+/// Each format is exposed under its own name, such as [`csv_table::CsvTable`] or
+/// [`parquet_table::ParquetTable`].
+pub struct FileTable<R> {
+    reader: R,
+    table_name: String,
+    options: TableOptions,
+    executable_columns: Box<[ExecutableColumn]>,
+    executable_relations: Option<Box<[ExecutableRelation]>>,
+    executable_groups: Box<[ExecutableGroup]>,
+    column_types: HashMap<String, DataType>,
+    // Labels of the column rules, by column then by reported rule name
+    labels: HashMap<String, HashMap<String, String>>,
+    // Configured columns that may be missing from the file
+    optional_columns: Vec<String>,
+    // File columns read under another name
+    source_names: SourceNames,
+}
+
+impl<R: FormatReader> FileTable<R> {
+    pub(crate) fn from_reader(reader: R, table_name: String) -> Self {
+        Self {
+            reader,
+            table_name,
+            options: TableOptions::default(),
+            executable_columns: Box::new([]),
+            executable_relations: None,
+            executable_groups: Box::new([]),
+            column_types: HashMap::new(),
+            labels: HashMap::new(),
+            optional_columns: Vec::new(),
+            source_names: SourceNames::default(),
+        }
+    }
+
+    /// Set the number of failing values reported per rule, 0 disables sampling
+    pub fn with_max_samples(mut self, max_samples: usize) -> Self {
+        self.options.max_samples = max_samples;
+        self
+    }
+
+    /// Summarize the values of every configured column in the result, even those
+    /// without rules
+    pub fn with_profile(mut self, profile: bool) -> Self {
+        self.options.profile = profile;
+        self
+    }
+
+    /// Count the most frequent values of `columns` in the result
+    pub fn with_value_counts(mut self, columns: Vec<String>) -> Self {
+        self.options.value_counts = columns;
+        self
+    }
+
+    /// Count the rows duplicated across every configured column
+    pub fn with_duplicate_rows(mut self, duplicate_rows: bool) -> Self {
+        self.options.duplicate_rows = duplicate_rows;
+        self
+    }
+
+    /// Stop at the first rule failing beyond its threshold, the result only holds that rule
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.options.fail_fast = fail_fast;
+        self
+    }
+
+    /// Stop reading batches once `max_errors` errors were counted, the result is then
+    /// marked as aborted
+    pub fn with_max_errors(mut self, max_errors: Option<usize>) -> Self {
+        self.options.max_errors = max_errors;
+        self
+    }
+
+    /// Set whether batches or columns are validated in parallel
+    pub fn with_parallel_axis(mut self, parallel_axis: engine::ParallelAxis) -> Self {
+        self.options.parallel_axis = parallel_axis;
+        self
+    }
+
+    /// Also report file columns that are not configured
+    pub fn with_strict_schema(mut self, strict_schema: bool) -> Self {
+        self.options.strict_schema = strict_schema;
+        self
+    }
+
+    /// Only validate the first `limit` rows, the rest of the file is not read
+    pub fn with_row_limit(mut self, limit: usize) -> Self {
+        self.options.row_limit = Some(limit);
+        self
+    }
+
+    /// Expect the table to hold between `min_rows` and `max_rows` rows, each bound is optional
+    pub fn with_row_count(mut self, min_rows: Option<usize>, max_rows: Option<usize>) -> Self {
+        self.options.min_rows = min_rows;
+        self.options.max_rows = max_rows;
+        self
+    }
+
+    /// Set how the file is read, defaults to `ReadMode::Auto`
+    pub fn with_read_mode(mut self, read_mode: ReadMode) -> Self {
+        self.options.read_mode = read_mode;
+        self
+    }
+
+    /// Set the file size, in bytes, above which `ReadMode::Auto` streams the file
+    pub fn with_memory_budget(mut self, memory_budget: u64) -> Self {
+        self.options.memory_budget = memory_budget;
+        self
+    }
+
+    fn engine(&self) -> engine::ValidationEngine<'_> {
+        engine::ValidationEngine::new(&self.executable_columns, &self.executable_relations)
+            .with_groups(&self.executable_groups)
+            .with_max_samples(self.options.max_samples)
+            .with_parallel_axis(self.options.parallel_axis)
+            .with_progress(self.options.progress.as_deref())
+            .with_profile(self.options.profile)
+            .with_value_counts(&self.options.value_counts)
+            .with_duplicate_rows(self.options.duplicate_rows)
+            .with_fail_fast(self.options.fail_fast)
+            .with_max_errors(self.options.max_errors)
+    }
+
+    /// File schema with the columns read under another name renamed
+    fn schema(&self) -> Result<Schema, RuleError> {
+        Ok(self
+            .source_names
+            .rename_schema(self.reader.schema()?.as_ref()))
+    }
+
+    /// Rename the columns read under another name, then widen the typed columns
+    fn prepare_batches(
+        &self,
+        batches: Vec<Arc<RecordBatch>>,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        let batches = self.source_names.rename_all(batches)?;
+        if R::TYPED {
+            widen_batches(batches, &self.column_types)
+        } else {
+            Ok(batches)
+        }
+    }
+
+    /// Validate the standard input, read sequentially whatever the read mode
+    fn validate_stdin(&self, needed_cols: Vec<String>) -> Result<ValidationResult, RuleError> {
+        let start = Instant::now();
+        let mut read_cols = needed_cols.clone();
+        for column in &self.options.key_columns {
+            if !read_cols.contains(column) {
+                read_cols.push(column.clone());
+            }
+        }
+        let (file_columns, batches) = self
+            .reader
+            .read_stdin(self.source_names.sources(&read_cols))?;
+        let (batches, sampled) = match self.options.row_limit {
+            Some(limit) => read_first_rows(batches, limit)?,
+            None => (batches.into_iter().collect::<Result<_, _>>()?, false),
+        };
+        let batches = self.prepare_batches(batches)?;
+        let read_duration = start.elapsed();
+        let schema_errors = check_schema(
+            &needed_cols,
+            &self.optional_columns,
+            &self.source_names.names(file_columns),
+            self.options.strict_schema,
+        );
+        let start = Instant::now();
+        let mut result = self
+            .engine()
+            .with_keys(&self.options.key_columns)
+            .validate_batches(self.table_name.clone(), &batches)?;
+        result.set_durations(read_duration, start.elapsed());
+        result.sampled = sampled;
+        self.complete(
+            result,
+            Some(ReadMode::Sequential),
+            schema_errors,
+            Vec::new(),
+        )
+    }
+
+    /// Add the schema errors, declared type mismatches, row count bounds and rule labels
+    /// to the result of the engine, whose `sampled` flag is set by the caller
+    fn complete(
+        &self,
+        mut result: ValidationResult,
+        read_mode: Option<ReadMode>,
+        schema_errors: (Vec<String>, Vec<String>),
+        mismatches: Vec<(String, DataType)>,
+    ) -> Result<ValidationResult, RuleError> {
+        result.read_mode = read_mode;
+        result.add_schema_errors(schema_errors.0, schema_errors.1);
+        let origin = match self.reader.path() {
+            Some(_) => R::FORMAT.to_string(),
+            None => "Arrow".to_string(),
+        };
+        for (name, declared) in mismatches {
+            let expected = &self.column_types[&name];
+            let error_percentage = if result.total_rows > 0 { 100.0 } else { 0.0 };
+            let message = format!("{origin} column is {declared}, expected {expected}");
+            result.add_column_result(
+                name,
+                vec![RuleResult::new(
+                    "TypeCheck".to_string(),
+                    result.total_rows,
+                    error_percentage,
+                    0.0,
+                    Some(message),
+                    false,
+                )],
+            );
+        }
+        // Keys of a sample miss the rows past the limit
+        if result.sampled {
+            result.take_keys();
+        }
+        check_row_count(&mut result, self.options.min_rows, self.options.max_rows);
+        result.add_rule_labels(&self.labels);
+        Ok(result)
+    }
+}
+
+impl<R: FormatReader> Table for FileTable<R> {
+    /// Commit column configurations and compile them into executable rules
+    fn prepare(
+        &mut self,
+        columns: Vec<Box<dyn ColumnBuilder>>,
+        relations: Vec<RelationBuilder>,
+    ) -> Result<(), RuleError> {
+        // Build column type map before consuming the columns vector
+        let column_types = compiler::build_column_type_map(&columns);
+        self.optional_columns = optional_columns(&columns);
+        self.source_names = SourceNames::new(&columns)?;
+
+        self.labels = columns
+            .iter()
+            .map(|col| {
+                Ok((
+                    col.name().to_string(),
+                    compiler::compile_labels(col.as_ref())?,
+                ))
+            })
+            .collect::<Result<_, RuleError>>()?;
+        // Typed columns get no TypeCheck, their declared types are compared in `validate`
+        self.executable_columns = columns
+            .into_iter()
+            .map(|col| compiler::compile_column(col, !R::TYPED))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_boxed_slice();
+
+        self.executable_relations = Some(
+            relations
+                .into_iter()
+                .map(|rel| compiler::compile_relations(rel, &column_types))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_boxed_slice(),
+        );
+        self.column_types = column_types;
+        Ok(())
+    }
+
+    /// Compile group assertions, must be called after `prepare`
+    fn prepare_groups(&mut self, groups: Vec<GroupBuilder>) -> Result<(), RuleError> {
+        self.executable_groups = groups
+            .into_iter()
+            .map(|group| compiler::compile_groups(group, &self.column_types))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_boxed_slice();
+        Ok(())
+    }
+
+    /// Validate the file against the committed rules
+    fn validate(&mut self) -> Result<ValidationResult, RuleError> {
+        let mut needed_cols = needed_columns(&self.executable_columns, &self.executable_groups);
+        if let Some(path) = self.reader.path() {
+            if path == STDIN_PATH {
+                return self.validate_stdin(needed_cols);
+            }
+            verify_format(path, R::FORMAT)?;
+        }
+        let schema = self.schema()?;
+        let file_columns: Vec<String> = schema.fields().iter().map(|f| f.name().clone()).collect();
+        let schema_errors = check_schema(
+            &needed_cols,
+            &self.optional_columns,
+            &file_columns,
+            self.options.strict_schema,
+        );
+        // Columns declared with an incompatible type are not read, their rules can not run
+        let mismatches = match R::TYPED {
+            true => type_mismatches(&schema, &self.column_types),
+            false => Vec::new(),
+        };
+        needed_cols.retain(|c| !mismatches.iter().any(|(name, _)| name == c));
+        // Foreign key columns are read along with the rules, unless declared with another type
+        let key_cols: Vec<String> = self
+            .options
+            .key_columns
+            .iter()
+            .filter(|c| file_columns.contains(c) && !mismatches.iter().any(|(name, _)| name == *c))
+            .cloned()
+            .collect();
+        for column in &key_cols {
+            if !needed_cols.contains(column) {
+                needed_cols.push(column.clone());
+            }
+        }
+        let read_cols = self.source_names.sources(&needed_cols);
+        let engine = self.engine().with_keys(&key_cols);
+        let mut read_mode = match (self.options.row_limit, self.reader.path()) {
+            // A sample is pulled from a stream, then validated in memory
+            (Some(_), _) => ReadMode::Streaming,
+            (None, Some(path)) => self
+                .options
+                .read_mode
+                .resolve(path, self.options.memory_budget),
+            // Batches already in memory are not read
+            (None, None) => ReadMode::Sequential,
+        };
+        let read_note = match read_mode {
+            ReadMode::Parallel => self.reader.sequential_reason(),
+            _ => None,
+        };
+        if read_note.is_some() {
+            read_mode = ReadMode::Sequential;
+        }
+        let mut result = if self.options.row_limit.is_none() && read_mode == ReadMode::Streaming {
+            engine.validate_batches_streaming(self.table_name.clone(), || {
+                let stream = self.reader.read_streaming(read_cols.clone())?;
+                Ok(stream.into_iter().map(|batch| {
+                    self.prepare_batches(vec![batch?])
+                        .map(|mut batches| batches.remove(0))
+                }))
+            })?
+        } else {
+            let start = Instant::now();
+            let (batches, sampled) = match self.options.row_limit {
+                Some(limit) => read_first_rows(self.reader.read_streaming(read_cols)?, limit)?,
+                None => (self.reader.read(read_cols, read_mode)?, false),
+            };
+            let batches = self.prepare_batches(batches)?;
+            let read_duration = start.elapsed();
+            let start = Instant::now();
+            let mut result = engine.validate_batches(self.table_name.clone(), &batches)?;
+            result.set_durations(read_duration, start.elapsed());
+            result.sampled = sampled;
+            result
+        };
+        let read_mode = self.reader.path().map(|_| read_mode);
+        result.read_note = read_note;
+        self.complete(result, read_mode, schema_errors, mismatches)
+    }
+
+    fn set_progress(&mut self, progress: Arc<Progress>) {
+        self.options.progress = Some(progress);
+    }
+
+    fn collect_keys(&mut self, column: &str) {
+        if !self.options.key_columns.iter().any(|c| c == column) {
+            self.options.key_columns.push(column.to_string());
+        }
+    }
+
+    fn select_columns(&mut self, columns: &[String]) {
+        retain_selected(
+            columns,
+            &mut self.executable_columns,
+            &mut self.executable_relations,
+            &mut self.executable_groups,
+        );
+        self.options.strict_schema = false;
+    }
+
+    /// Check the configured columns, and the declared types of typed formats, against the
+    /// header or footer of the file
+    fn dry_run(&self) -> Result<ValidationResult, RuleError> {
+        let needed_cols = needed_columns(&self.executable_columns, &self.executable_groups);
+        if let Some(path) = self.reader.path() {
+            if path == STDIN_PATH {
+                return Ok(ValidationResult::new(self.table_name.clone(), 0));
+            }
+            verify_format(path, R::FORMAT)?;
+        }
+        let schema = self.schema()?;
+        let file_columns: Vec<String> = schema.fields().iter().map(|f| f.name().clone()).collect();
+        let schema_errors = check_schema(
+            &needed_cols,
+            &self.optional_columns,
+            &file_columns,
+            self.options.strict_schema,
+        );
+        let mismatches = match R::TYPED {
+            true => type_mismatches(&schema, &self.column_types),
+            false => Vec::new(),
+        };
+        Ok(dry_run_result(
+            self.table_name.clone(),
+            schema_errors,
+            mismatches,
+            &self.column_types,
+        ))
+    }
+
+    /// Read a single column for cross-table checks
+    fn read_column(&self, column: &str) -> Result<Vec<ArrayRef>, RuleError> {
+        if let Some(path) = self.reader.path() {
+            verify_format(path, R::FORMAT)?;
+        }
+        let source = self.source_names.source(column);
+        let batches = self
+            .reader
+            .read(vec![source.to_string()], ReadMode::Parallel)?;
+        extract_column(&batches, source)
+    }
+
+    /// Get a summary of configured rules
+    fn get_rules(&self) -> HashMap<String, Vec<String>> {
+        let mut result = HashMap::new();
+        for column in &self.executable_columns {
+            let mut rule_names = vec!["TypeCheck".to_string()];
+            let name = match column {
+                ExecutableColumn::String {
+                    name,
+                    rules,
+                    length_rules,
+                    ..
+                } => {
+                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
+                    rule_names.extend(length_rules.iter().map(|r| r.name()));
+                    name
+                }
+                ExecutableColumn::Integer {
+                    name,
+                    domain_rules,
+                    statistical_rules,
+                    ..
+                } => {
+                    rule_names.extend(domain_rules.iter().map(|r| r.name().to_string()));
+                    rule_names.extend(statistical_rules.iter().map(|r| r.name().to_string()));
+                    name
+                }
+                ExecutableColumn::Float {
+                    name,
+                    domain_rules,
+                    statistical_rules,
+                    ..
+                } => {
+                    rule_names.extend(domain_rules.iter().map(|r| r.name().to_string()));
+                    rule_names.extend(statistical_rules.iter().map(|r| r.name().to_string()));
+                    name
+                }
+                ExecutableColumn::Date { name, rules, .. } => {
+                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
+                    name
+                }
+                ExecutableColumn::Timestamp { name, rules, .. } => {
+                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
+                    name
+                }
+                ExecutableColumn::Boolean {
+                    name,
+                    rules,
+                    ratio_rules,
+                    ..
+                } => {
+                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
+                    rule_names.extend(ratio_rules.iter().map(|r| r.name()));
+                    name
+                }
+            };
+            result.insert(name.clone(), rule_names);
+        }
+        result
+    }
+}
+
+/// Extract `column` from every batch, failing if the file does not contain it.
+pub(crate) fn extract_column(
+    batches: &[Arc<RecordBatch>],
+    column: &str,
+) -> Result<Vec<ArrayRef>, RuleError> {
+    batches
+        .iter()
+        .map(|batch| {
+            batch
+                .column_by_name(column)
+                .cloned()
+                .ok_or_else(|| RuleError::ColumnNotFound(column.to_string()))
+        })
+        .collect()
+}
+
+/// Keep the compiled rules only using `selected` columns, see [`Table::select_columns`].
+pub(crate) fn retain_selected(
+    selected: &[String],
+    columns: &mut Box<[ExecutableColumn]>,
+    relations: &mut Option<Box<[ExecutableRelation]>>,
+    groups: &mut Box<[ExecutableGroup]>,
+) {
+    *columns = std::mem::take(columns)
+        .into_vec()
+        .into_iter()
+        .filter(|col| selected.contains(&col.get_name()))
+        .collect();
+    if let Some(rels) = relations.take() {
+        *relations = Some(
+            rels.into_vec()
+                .into_iter()
+                .filter(|rel| rel.names.iter().all(|name| selected.contains(name)))
+                .collect(),
+        );
+    }
+    *groups = std::mem::take(groups)
+        .into_vec()
+        .into_iter()
+        .filter(|group| group.columns().all(|name| selected.contains(name)))
+        .collect();
+}
+
+/// Check the number of rows read against the expected bounds.
+///
+/// Each bound is recorded as a table-level result, `MinRows` counting the missing rows
+/// and `MaxRows` the extra ones. Sampled and aborted results are skipped, the file holds
+/// more rows than were read.
+pub(crate) fn check_row_count(
+    result: &mut ValidationResult,
+    min_rows: Option<usize>,
+    max_rows: Option<usize>,
+) {
+    if result.sampled || result.aborted {
+        return;
+    }
+    let total_rows = result.total_rows;
+    let checks = [
+        (
+            "MinRows",
+            min_rows,
+            min_rows.map(|min| min.saturating_sub(total_rows)),
+        ),
+        (
+            "MaxRows",
+            max_rows,
+            max_rows.map(|max| total_rows.saturating_sub(max)),
+        ),
+    ];
+    for (rule_name, bound, errors) in checks {
+        let (Some(bound), Some(errors)) = (bound, errors) else {
+            continue;
+        };
+        let error_percentage = if bound > 0 {
+            (errors as f64 / bound as f64) * 100.
+        } else {
+            0.0
+        };
+        let rule_result = RuleResult::new(
+            rule_name.to_string(),
+            errors,
+            error_percentage,
+            0.0,
+            None,
+            errors == 0,
+        )
+        .with_observed(vec![("rows".to_string(), total_rows as f64)]);
+        result.add_table_result(rule_result);
+    }
+}
+
+/// Columns read by the rules and group assertions of a table
+pub(crate) fn needed_columns(
+    columns: &[ExecutableColumn],
+    groups: &[ExecutableGroup],
+) -> Vec<String> {
+    let mut needed_cols: Vec<String> = columns.iter().map(|v| v.get_name()).collect();
+    let group_keys = columns
+        .iter()
+        .filter_map(|c| c.grouped_unicity_check())
+        .map(|c| c.group_by());
+    for name in groups
+        .iter()
+        .flat_map(|g| g.columns().map(String::as_str))
+        .chain(group_keys)
+    {
+        if !needed_cols.iter().any(|c| c == name) {
+            needed_cols.push(name.to_string());
+        }
+    }
+    needed_cols
+}
+
+/// Result of a dry run, holding no row: the schema errors of the table and a failed
+/// `TypeCheck` for each of the `mismatches` found by [`type_mismatches`].
+pub(crate) fn dry_run_result(
+    table_name: String,
+    schema_errors: (Vec<String>, Vec<String>),
+    mismatches: Vec<(String, DataType)>,
+    column_types: &HashMap<String, DataType>,
+) -> ValidationResult {
+    let mut result = ValidationResult::new(table_name, 0);
+    result.add_schema_errors(schema_errors.0, schema_errors.1);
+    for (name, declared) in mismatches {
+        let message = format!(
+            "File column is {}, expected {}",
+            declared, column_types[&name]
+        );
+        result.add_column_result(
+            name,
+            vec![RuleResult::new(
+                "TypeCheck".to_string(),
+                0,
+                0.0,
+                0.0,
+                Some(message),
+                false,
+            )],
+        );
+    }
+    result
+}
+
+/// File columns read under another name, see [`ColumnBuilder::source_name`].
+///
+/// Batches are renamed as soon as they are read, the rules, relations and reports only
+/// know the configured names.
+#[derive(Debug, Default)]
+pub(crate) struct SourceNames {
+    // Configured name → file column
+    sources: HashMap<String, String>,
+}
+
+impl SourceNames {
+    /// Collect the source names of `columns`, failing when two columns read the same one.
+    ///
+    /// A column without a source name reads the file column of its own name, a source name
+    /// can not be another configured column.
+    pub fn new(columns: &[Box<dyn ColumnBuilder>]) -> Result<Self, RuleError> {
+        let mut sources = HashMap::new();
+        for column in columns {
+            let Some(source) = column.source_name() else {
+                continue;
+            };
+            if let Some(other) = columns.iter().find(|c| {
+                c.name() != column.name() && c.source_name().unwrap_or(c.name()) == source
+            }) {
+                return Err(RuleError::ValidationError(format!(
+                    "Columns '{}' and '{}' both read the file column '{}'",
+                    other.name(),
+                    column.name(),
+                    source
+                )));
+            }
+            sources.insert(column.name().to_string(), source.to_string());
+        }
+        Ok(Self { sources })
+    }
+
+    /// File column read for the configured column `name`
+    pub fn source<'a>(&'a self, name: &'a str) -> &'a str {
+        self.sources.get(name).map_or(name, String::as_str)
+    }
+
+    /// File columns to read for the configured `columns`
+    pub fn sources(&self, columns: &[String]) -> Vec<String> {
+        columns.iter().map(|c| self.source(c).to_string()).collect()
+    }
+
+    /// Configured names of the file columns, for schema checks
+    pub fn names(&self, file_columns: Vec<String>) -> Vec<String> {
+        file_columns
+            .into_iter()
+            .map(|c| self.name(&c).unwrap_or(c))
+            .collect()
+    }
+
+    fn name(&self, source: &str) -> Option<String> {
+        self.sources
+            .iter()
+            .find(|(_, s)| s.as_str() == source)
+            .map(|(name, _)| name.clone())
+    }
+
+    /// Rename the fields of `schema` read under another name
+    pub fn rename_schema(&self, schema: &Schema) -> Schema {
+        let fields: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|field| match self.name(field.name()) {
+                Some(name) => Arc::new(field.as_ref().clone().with_name(name)),
+                None => field.clone(),
+            })
+            .collect();
+        Schema::new_with_metadata(fields, schema.metadata().clone())
+    }
+
+    /// Rename the columns of `batch` read under another name
+    pub fn rename(&self, batch: Arc<RecordBatch>) -> Result<Arc<RecordBatch>, RuleError> {
+        if self.sources.is_empty() {
+            return Ok(batch);
+        }
+        let schema = Arc::new(self.rename_schema(&batch.schema()));
+        let options = RecordBatchOptions::new().with_row_count(Some(batch.num_rows()));
+        let renamed =
+            RecordBatch::try_new_with_options(schema, batch.columns().to_vec(), &options)?;
+        Ok(Arc::new(renamed))
+    }
+
+    /// Rename the columns of every batch, see [`SourceNames::rename`]
+    pub fn rename_all(
+        &self,
+        batches: Vec<Arc<RecordBatch>>,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        batches
+            .into_iter()
+            .map(|batch| self.rename(batch))
+            .collect()
+    }
+}
+
+/// Names of the columns marked as optional.
+pub(crate) fn optional_columns(columns: &[Box<dyn ColumnBuilder>]) -> Vec<String> {
+    columns
+        .iter()
+        .filter(|c| c.is_optional())
+        .map(|c| c.name().to_string())
+        .collect()
+}
+
+/// Compare the configured columns against the columns found in the file.
+///
+/// Returns the required columns absent from the file and, when `strict` is set,
+/// the file columns that are not configured. Missing optional columns are not reported,
+/// their rules are skipped.
+pub(crate) fn check_schema(
+    configured: &[String],
+    optional: &[String],
+    file_columns: &[String],
+    strict: bool,
+) -> (Vec<String>, Vec<String>) {
+    let missing = configured
+        .iter()
+        .filter(|c| !file_columns.contains(c) && !optional.contains(c))
+        .cloned()
+        .collect();
+    let unexpected = if strict {
+        file_columns
+            .iter()
+            .filter(|c| !configured.contains(c))
+            .cloned()
+            .collect()
+    } else {
+        Vec::new()
+    };
+    (missing, unexpected)
+}
+
+/// Widen typed columns to the type expected by their rules.
+///
+/// Used by the tables reading typed data, such as Arrow batches, Parquet, Avro or IPC
+/// files, whose schema may use narrower types than the rules.
+pub(crate) fn widen_batches(
+    batches: Vec<Arc<RecordBatch>>,
+    column_types: &HashMap<String, DataType>,
+) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+    let Some(first) = batches.first() else {
+        return Ok(batches);
+    };
+    let widened: Vec<(usize, DataType)> = first
+        .schema()
+        .fields()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, field)| {
+            let expected = column_types.get(field.name())?;
+            can_widen(field.data_type(), expected).then(|| (i, expected.clone()))
+        })
+        .collect();
+    if widened.is_empty() {
+        return Ok(batches);
+    }
+    batches
+        .iter()
+        .map(|batch| {
+            let mut columns = batch.columns().to_vec();
+            let mut fields: Vec<_> = batch.schema().fields().iter().cloned().collect();
+            for (i, data_type) in &widened {
+                columns[*i] = cast(&columns[*i], data_type)?;
+                fields[*i] = Arc::new(
+                    fields[*i]
+                        .as_ref()
+                        .clone()
+                        .with_data_type(data_type.clone()),
+                );
+            }
+            let schema = Arc::new(Schema::new(fields));
+            Ok(Arc::new(RecordBatch::try_new(schema, columns)?))
+        })
+        .collect()
+}
+
+/// Configured columns whose type, declared in the file schema, can not be validated by
+/// their rules, as `(column, declared type)`.
+///
+/// Declared types matching the rules or that can be widened to them are accepted, as
+/// are timestamps of any unit or time zone.
+pub(crate) fn type_mismatches(
+    schema: &Schema,
+    column_types: &HashMap<String, DataType>,
+) -> Vec<(String, DataType)> {
+    schema
+        .fields()
+        .iter()
+        .filter_map(|field| {
+            let expected = column_types.get(field.name())?;
+            let actual = field.data_type();
+            let compatible = actual == expected
+                || can_widen(actual, expected)
+                || matches!(
+                    (actual, expected),
+                    (DataType::Timestamp(..), DataType::Timestamp(..))
+                );
+            (!compatible).then(|| (field.name().clone(), actual.clone()))
+        })
+        .collect()
+}
+
+/// Lossless conversions towards the types used by the rules
+fn can_widen(actual: &DataType, expected: &DataType) -> bool {
+    match expected {
+        DataType::Utf8 => matches!(actual, DataType::LargeUtf8 | DataType::Utf8View),
+        DataType::Int64 => matches!(
+            actual,
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+        ),
+        DataType::Float64 => matches!(actual, DataType::Float16 | DataType::Float32),
+        _ => false,
+    }
 }
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
+
+use crate::{
+    readers::{
+        orc_schema, read_orc_parallel, read_orc_sequential, read_orc_streaming, FileFormat,
+        ReadMode,
+    },
+    tables::{BatchStream, FileTable, FormatReader},
+    RuleError,
+};
+
+/// A table reading an ORC file.
+///
+/// ORC columns are natively typed, no TypeCheck is added. The types declared in the file
+/// footer are compared to the configured ones, narrower types are widened as for Parquet.
+pub type OrcTable = FileTable<OrcReader>;
+
+/// Reads the ORC file of an [`OrcTable`]
+pub struct OrcReader {
+    path: String,
+}
+
+impl OrcTable {
+    /// Create a new table reading an ORC file
+    pub fn new(path: String, table_name: String) -> Result<Self, RuleError> {
+        Ok(Self::from_reader(OrcReader { path }, table_name))
+    }
+}
+
+impl FormatReader for OrcReader {
+    const FORMAT: FileFormat = FileFormat::Orc;
+    const TYPED: bool = true;
+
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
+
+    /// Schema of the ORC footer
+    fn schema(&self) -> Result<SchemaRef, RuleError> {
+        Ok(orc_schema(&self.path)?)
+    }
+
+    /// Stripes are read in parallel
+    fn read(
+        &self,
+        columns: Vec<String>,
+        read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        let batches = match read_mode {
+            ReadMode::Sequential => read_orc_sequential(&self.path, columns)?,
+            _ => read_orc_parallel(&self.path, columns)?,
+        };
+        Ok(batches)
+    }
+
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError> {
+        Ok(read_orc_streaming(&self.path, columns)?)
+    }
+}
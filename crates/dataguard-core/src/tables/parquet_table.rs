@@ -1,113 +1,61 @@
-use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::{datatypes::SchemaRef, record_batch::RecordBatch};
 
 use crate::{
-    columns::{relation_builder::RelationBuilder, ColumnBuilder},
-    compiler, engine,
-    readers::read_parquet_parallel,
-    validator::{ExecutableColumn, ExecutableRelation},
-    RuleError, Table, ValidationResult,
+    readers::{
+        parquet_schema, read_parquet_parallel, read_parquet_sequential, read_parquet_streaming,
+        FileFormat, ReadMode,
+    },
+    tables::{BatchStream, FileTable, FormatReader},
+    RuleError,
 };
 
-pub struct ParquetTable {
+/// A table reading a Parquet file.
+///
+/// Parquet columns are natively typed, no TypeCheck is added. The types declared in the
+/// file schema are compared to the configured ones instead.
+pub type ParquetTable = FileTable<ParquetReader>;
+
+/// Reads the Parquet file of a [`ParquetTable`]
+pub struct ParquetReader {
     path: String,
-    table_name: String,
-    executable_columns: Box<[ExecutableColumn]>,
-    executable_relations: Option<Box<[ExecutableRelation]>>,
 }
 
 impl ParquetTable {
     /// Create a new Validator instance
     pub fn new(path: String, table_name: String) -> Result<Self, RuleError> {
-        Ok(Self {
-            path,
-            table_name,
-            executable_columns: Box::new([]),
-            executable_relations: None,
-        })
+        Ok(Self::from_reader(ParquetReader { path }, table_name))
     }
 }
 
-impl Table for ParquetTable {
-    /// Commit column configurations and compile them into executable rules
-    fn prepare(
-        &mut self,
-        columns: Vec<Box<dyn ColumnBuilder>>,
-        relations: Vec<RelationBuilder>,
-    ) -> Result<(), RuleError> {
-        // Here with parquet, we do not add TypeCheck, we use the schema from the parquet file
-        // Since we only support a small range of available Arrow type for now this is incomplete
+impl FormatReader for ParquetReader {
+    const FORMAT: FileFormat = FileFormat::Parquet;
+    const TYPED: bool = true;
 
-        // Build column type map before consuming the columns vector
-        let column_types = compiler::build_column_type_map(&columns);
-
-        self.executable_columns = columns
-            .into_iter()
-            .map(|col| compiler::compile_column(col, false))
-            .collect::<Result<Vec<_>, _>>()?
-            .into_boxed_slice();
+    fn path(&self) -> Option<&str> {
+        Some(&self.path)
+    }
 
-        self.executable_relations = Some(
-            relations
-                .into_iter()
-                .map(|rel| compiler::compile_relations(rel, &column_types))
-                .collect::<Result<Vec<_>, _>>()?
-                .into_boxed_slice(),
-        );
-        Ok(())
+    /// Schema of the Parquet footer
+    fn schema(&self) -> Result<SchemaRef, RuleError> {
+        Ok(parquet_schema(&self.path)?)
     }
 
-    /// Validate a Parquet file against the committed rules
-    fn validate(&mut self) -> Result<ValidationResult, RuleError> {
-        let needed_cols: Vec<String> = self
-            .executable_columns
-            .iter()
-            .map(|v| v.get_name())
-            .collect();
-        let batches = read_parquet_parallel(self.path.as_str(), needed_cols)?;
-        let engine =
-            engine::ValidationEngine::new(&self.executable_columns, &self.executable_relations);
-        engine.validate_batches(self.table_name.clone(), &batches)
+    /// Row groups are read in parallel
+    fn read(
+        &self,
+        columns: Vec<String>,
+        read_mode: ReadMode,
+    ) -> Result<Vec<Arc<RecordBatch>>, RuleError> {
+        let batches = match read_mode {
+            ReadMode::Sequential => read_parquet_sequential(&self.path, columns)?,
+            _ => read_parquet_parallel(&self.path, columns)?,
+        };
+        Ok(batches)
     }
 
-    /// Get a summary of configured rules
-    fn get_rules(&self) -> HashMap<String, Vec<String>> {
-        let mut result = HashMap::new();
-        for column in &self.executable_columns {
-            match column {
-                ExecutableColumn::String { name, rules, .. } => {
-                    let mut rule_names = vec!["TypeCheck".to_string()];
-                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
-                    result.insert(name.clone(), rule_names);
-                }
-                ExecutableColumn::Integer {
-                    name,
-                    domain_rules,
-                    statistical_rules,
-                    ..
-                } => {
-                    let mut rule_names = vec!["TypeCheck".to_string()];
-                    rule_names.extend(domain_rules.iter().map(|r| r.name().to_string()));
-                    rule_names.extend(statistical_rules.iter().map(|r| r.name().to_string()));
-                    result.insert(name.clone(), rule_names);
-                }
-                ExecutableColumn::Float {
-                    name,
-                    domain_rules: rules,
-                    statistical_rules,
-                    ..
-                } => {
-                    let mut rule_names = vec!["TypeCheck".to_string()];
-                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
-                    rule_names.extend(statistical_rules.iter().map(|r| r.name().to_string()));
-                    result.insert(name.clone(), rule_names);
-                }
-                ExecutableColumn::Date { name, rules, .. } => {
-                    let mut rule_names = vec!["TypeCheck".to_string()];
-                    rule_names.extend(rules.iter().map(|r| r.name().to_string()));
-                    result.insert(name.clone(), rule_names);
-                }
-            }
-        }
-        result
+    fn read_streaming(&self, columns: Vec<String>) -> Result<BatchStream, RuleError> {
+        Ok(read_parquet_streaming(&self.path, columns)?)
     }
 }
@@ -1,5 +1,5 @@
-use arrow_array::{Date32Array, StringArray};
-use chrono::NaiveDate;
+use arrow_array::{Date32Array, StringArray, TimestampMillisecondArray};
+use chrono::{NaiveDate, NaiveDateTime};
 
 pub fn parse_date_column(array: &StringArray, format: &str) -> Date32Array {
     array
@@ -17,3 +17,19 @@ fn parse_date(str_date: &str, format: &str) -> Option<i32> {
                 .num_days() as i32
         })
 }
+
+/// Parse a string array into milliseconds since the Unix epoch, invalid values become null.
+///
+/// Timestamps carry no timezone, they are stored as given.
+pub fn parse_timestamp_column(array: &StringArray, format: &str) -> TimestampMillisecondArray {
+    array
+        .iter()
+        .map(|opt_str| {
+            opt_str.and_then(|str_ts| {
+                NaiveDateTime::parse_from_str(str_ts, format)
+                    .ok()
+                    .map(|datetime| datetime.and_utc().timestamp_millis())
+            })
+        })
+        .collect()
+}
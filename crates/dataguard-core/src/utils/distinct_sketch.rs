@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+// Smallest value hashes kept to estimate a distinct count
+const DISTINCT_SKETCH_SIZE: usize = 1024;
+
+/// Distinct count estimate keeping the smallest value hashes (k minimum values).
+///
+/// Exact while fewer than `DISTINCT_SKETCH_SIZE` distinct values were met, the relative
+/// error stays around 3% above. Memory is bounded by `DISTINCT_SKETCH_SIZE` hashes
+/// whatever the cardinality.
+#[derive(Default)]
+pub struct DistinctSketch {
+    hashes: BTreeSet<u64>,
+}
+
+impl DistinctSketch {
+    pub fn insert(&mut self, hash: u64) {
+        if self.hashes.len() < DISTINCT_SKETCH_SIZE {
+            self.hashes.insert(hash);
+        } else if self.hashes.last().is_some_and(|&largest| hash < largest)
+            && self.hashes.insert(hash)
+        {
+            self.hashes.pop_last();
+        }
+    }
+
+    pub fn merge(&mut self, other: DistinctSketch) {
+        for hash in other.hashes {
+            self.insert(hash);
+        }
+    }
+
+    pub fn estimate(&self) -> usize {
+        match self.hashes.last() {
+            Some(&largest) if self.hashes.len() == DISTINCT_SKETCH_SIZE => {
+                let ratio = u64::MAX as f64 / largest.max(1) as f64;
+                ((DISTINCT_SKETCH_SIZE - 1) as f64 * ratio).round() as usize
+            }
+            _ => self.hashes.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distinct_sketch_estimate() {
+        let mut sketch = DistinctSketch::default();
+        for i in 0..100u64 {
+            sketch.insert(xxhash_rust::xxh3::xxh3_64(&i.to_le_bytes()));
+        }
+        assert_eq!(sketch.estimate(), 100);
+
+        let mut other = DistinctSketch::default();
+        for i in 0..50_000u64 {
+            other.insert(xxhash_rust::xxh3::xxh3_64(&i.to_le_bytes()));
+        }
+        sketch.merge(other);
+        let estimate = sketch.estimate() as f64;
+        assert!((estimate - 50_000.0).abs() / 50_000.0 < 0.1, "{}", estimate);
+    }
+}
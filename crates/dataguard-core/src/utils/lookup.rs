@@ -0,0 +1,61 @@
+use std::{collections::HashMap, fs};
+
+use crate::RuleError;
+
+/// Load a `key,expected` lookup file into a map.
+///
+/// The file is expected to be a two columns CSV with a header line. Keys are
+/// kept as strings, expected values must parse as floats.
+pub fn load_lookup(path: &str) -> Result<HashMap<String, f64>, RuleError> {
+    let content = fs::read_to_string(path)?;
+    let mut lookup = HashMap::new();
+    for (line_number, line) in content.lines().enumerate().skip(1) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Some((key, value)) = line.split_once(',') else {
+            return Err(RuleError::ValidationError(format!(
+                "Lookup file '{}' line {}: expected 'key,value'",
+                path,
+                line_number + 1
+            )));
+        };
+        let value = value.trim().trim_matches('"').parse::<f64>().map_err(|_| {
+            RuleError::ValidationError(format!(
+                "Lookup file '{}' line {}: '{}' is not a number",
+                path,
+                line_number + 1,
+                value
+            ))
+        })?;
+        lookup.insert(key.trim().trim_matches('"').to_string(), value);
+    }
+    Ok(lookup)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_load_lookup() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "region,total").unwrap();
+        writeln!(file, "north,100.5").unwrap();
+        writeln!(file, "\"south\",20").unwrap();
+        let lookup = load_lookup(file.path().to_str().unwrap()).unwrap();
+        assert_eq!(lookup.len(), 2);
+        assert_eq!(lookup.get("north"), Some(&100.5));
+        assert_eq!(lookup.get("south"), Some(&20.0));
+    }
+
+    #[test]
+    fn test_load_lookup_invalid_value() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "region,total").unwrap();
+        writeln!(file, "north,abc").unwrap();
+        assert!(load_lookup(file.path().to_str().unwrap()).is_err());
+    }
+}
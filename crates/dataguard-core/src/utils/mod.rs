@@ -1,3 +1,5 @@
 pub mod date_parser;
+pub mod distinct_sketch;
 pub mod hasher;
+pub mod lookup;
 pub mod operator;
@@ -7,6 +7,7 @@ pub enum CompOperator {
     Gt,
     Gte,
     Eq,
+    Neq,
     Lte,
     Lt,
 }
@@ -17,6 +18,7 @@ impl Display for CompOperator {
             CompOperator::Gt => write!(f, "GreaterThan"),
             CompOperator::Gte => write!(f, "GreaterThanEqual"),
             CompOperator::Eq => write!(f, "Equal"),
+            CompOperator::Neq => write!(f, "NotEqual"),
             CompOperator::Lte => write!(f, "LesserThanEqual"),
             CompOperator::Lt => write!(f, "LesserThan"),
         }
@@ -30,7 +32,8 @@ impl TryFrom<&str> for CompOperator {
         match value {
             "greater than" | "gt" | ">" => Ok(CompOperator::Gt),
             "greater than or equal" | "gte" | ">=" => Ok(CompOperator::Gte),
-            "equal" | "=" => Ok(CompOperator::Eq),
+            "equal" | "eq" | "=" => Ok(CompOperator::Eq),
+            "not equal" | "neq" | "!=" => Ok(CompOperator::Neq),
             "lesser than" | "lt" | "<" => Ok(CompOperator::Lt),
             "lesser than or equal" | "lte" | "<=" => Ok(CompOperator::Lte),
             _ => Err(RuleError::IncorrentOperatorError(value.to_string())),
@@ -44,6 +47,7 @@ impl CompOperator {
             CompOperator::Gt => |a, b| a > b,
             CompOperator::Gte => |a, b| a >= b,
             CompOperator::Eq => |a, b| a == b,
+            CompOperator::Neq => |a, b| a != b,
             CompOperator::Lte => |a, b| a <= b,
             CompOperator::Lt => |a, b| a < b,
         }
@@ -1,3 +1,5 @@
 mod validation;
 
-pub use validation::{ExecutableColumn, ExecutableRelation, Validator};
+pub use validation::{
+    ExecutableColumn, ExecutableGroup, ExecutableRelation, ForeignKey, Validator,
+};
@@ -5,15 +5,22 @@
 //! validation execution.
 
 use crate::errors::RuleError;
+use crate::results::ForeignKeyResult;
+use crate::rules::boolean::{BooleanRule, BooleanTypeCheck, TrueRatioCheck};
 use crate::rules::date::{DateRule, DateTypeCheck};
-use crate::rules::generic::{TypeCheck, UnicityCheck};
+use crate::rules::generic::{GroupedUnicityCheck, TypeCheck, UnicityCheck};
+use crate::rules::group::{GroupCountCheck, GroupSumCheck};
 use crate::rules::numeric::NumericRule;
-use crate::rules::relations::RelationRule;
-use crate::rules::string::StringRule;
-use crate::rules::NullCheck;
-use crate::{Table, ValidationResult};
+use crate::rules::reference::{ColumnKeys, ForeignKeyCheck};
+use crate::rules::relations::{CorrelationCheck, RelationRule, SubsetCheck};
+use crate::rules::string::{ConsistentLengthCheck, StringRule};
+use crate::rules::timestamp::{TimestampRule, TimestampTypeCheck};
+use crate::rules::{NullCheck, SanityCheck};
+use crate::{Progress, RuleResult, Table, ValidationResult};
 use arrow::datatypes::{Float64Type, Int64Type};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Compiled, executable validation rules for a column.
 ///
@@ -31,12 +38,18 @@ pub enum ExecutableColumn {
         name: String,
         /// Domain-level string rules (length, regex, membership)
         rules: Vec<Box<dyn StringRule>>,
+        /// Column-level length distribution rules, evaluated once all batches are counted
+        length_rules: Vec<ConsistentLengthCheck>,
         /// Type checking (CSV: string → validated string)
         type_check: Option<TypeCheck>,
         /// Optional uniqueness constraint
         unicity_check: Option<UnicityCheck>,
+        /// Optional uniqueness constraint within groups of rows
+        grouped_unicity_check: Option<GroupedUnicityCheck>,
         /// Optional null constraint
         null_check: Option<NullCheck>,
+        /// Column-level sanity rules, evaluated once all batches are seen
+        sanity_rules: Vec<SanityCheck>,
     },
     /// Integer column with i64 validation rules.
     Integer {
@@ -50,8 +63,12 @@ pub enum ExecutableColumn {
         type_check: Option<TypeCheck>,
         /// Optional uniqueness constraint
         unicity_check: Option<UnicityCheck>,
+        /// Optional uniqueness constraint within groups of rows
+        grouped_unicity_check: Option<GroupedUnicityCheck>,
         /// Optional null constraint
         null_check: Option<NullCheck>,
+        /// Column-level sanity rules, evaluated once all batches are seen
+        sanity_rules: Vec<SanityCheck>,
     },
     /// Float column with f64 validation rules.
     Float {
@@ -65,8 +82,12 @@ pub enum ExecutableColumn {
         type_check: Option<TypeCheck>,
         /// Optional uniqueness constraint
         unicity_check: Option<UnicityCheck>,
+        /// Optional uniqueness constraint within groups of rows
+        grouped_unicity_check: Option<GroupedUnicityCheck>,
         /// Optional null constraint
         null_check: Option<NullCheck>,
+        /// Column-level sanity rules, evaluated once all batches are seen
+        sanity_rules: Vec<SanityCheck>,
     },
     /// Date column with DateType32 validation rules.
     Date {
@@ -78,8 +99,44 @@ pub enum ExecutableColumn {
         type_check: Option<DateTypeCheck>,
         /// Optional uniqueness constraint
         unicity_check: Option<UnicityCheck>,
+        /// Optional uniqueness constraint within groups of rows
+        grouped_unicity_check: Option<GroupedUnicityCheck>,
         /// Optional null constraint
         null_check: Option<NullCheck>,
+        /// Column-level sanity rules, evaluated once all batches are seen
+        sanity_rules: Vec<SanityCheck>,
+    },
+    /// Timestamp column with millisecond precision.
+    Timestamp {
+        /// Column name (must match schema)
+        name: String,
+        /// Domain-level timestamp rules (boundaries, time of day)
+        rules: Vec<Box<dyn TimestampRule>>,
+        /// Type checking (CSV: string → timestamp)
+        type_check: Option<TimestampTypeCheck>,
+        /// Optional uniqueness constraint
+        unicity_check: Option<UnicityCheck>,
+        /// Optional uniqueness constraint within groups of rows
+        grouped_unicity_check: Option<GroupedUnicityCheck>,
+        /// Optional null constraint
+        null_check: Option<NullCheck>,
+        /// Column-level sanity rules, evaluated once all batches are seen
+        sanity_rules: Vec<SanityCheck>,
+    },
+    /// Boolean column parsed from truthy/falsy tokens.
+    Boolean {
+        /// Column name (must match schema)
+        name: String,
+        /// Domain-level boolean rules (expected value)
+        rules: Vec<Box<dyn BooleanRule>>,
+        /// Column-level ratio rules, evaluated once all batches are counted
+        ratio_rules: Vec<TrueRatioCheck>,
+        /// Type checking (CSV: string → bool)
+        type_check: Option<BooleanTypeCheck>,
+        /// Optional null constraint
+        null_check: Option<NullCheck>,
+        /// Column-level sanity rules, evaluated once all batches are seen
+        sanity_rules: Vec<SanityCheck>,
     },
 }
 
@@ -94,9 +151,95 @@ impl ExecutableColumn {
             ExecutableColumn::Integer { name, .. } => name.clone(),
             ExecutableColumn::Float { name, .. } => name.clone(),
             ExecutableColumn::Date { name, .. } => name.clone(),
+            ExecutableColumn::Timestamp { name, .. } => name.clone(),
+            ExecutableColumn::Boolean { name, .. } => name.clone(),
+        }
+    }
+
+    /// Get the column-level sanity rules (not all null, not all same).
+    pub fn sanity_rules(&self) -> &[SanityCheck] {
+        match self {
+            ExecutableColumn::String { sanity_rules, .. } => sanity_rules,
+            ExecutableColumn::Integer { sanity_rules, .. } => sanity_rules,
+            ExecutableColumn::Float { sanity_rules, .. } => sanity_rules,
+            ExecutableColumn::Date { sanity_rules, .. } => sanity_rules,
+            ExecutableColumn::Timestamp { sanity_rules, .. } => sanity_rules,
+            ExecutableColumn::Boolean { sanity_rules, .. } => sanity_rules,
         }
     }
 
+    /// Names the results of the column rules are reported under, the type check aside.
+    pub fn rule_names(&self) -> Vec<String> {
+        let (mut names, null_check): (Vec<String>, _) = match self {
+            ExecutableColumn::String {
+                rules,
+                length_rules,
+                null_check,
+                ..
+            } => (
+                rules
+                    .iter()
+                    .map(|r| r.name())
+                    .chain(length_rules.iter().map(|r| r.name()))
+                    .collect(),
+                null_check,
+            ),
+            ExecutableColumn::Integer {
+                domain_rules,
+                statistical_rules,
+                null_check,
+                ..
+            } => (
+                domain_rules
+                    .iter()
+                    .chain(statistical_rules)
+                    .map(|r| r.name())
+                    .collect(),
+                null_check,
+            ),
+            ExecutableColumn::Float {
+                domain_rules,
+                statistical_rules,
+                null_check,
+                ..
+            } => (
+                domain_rules
+                    .iter()
+                    .chain(statistical_rules)
+                    .map(|r| r.name())
+                    .collect(),
+                null_check,
+            ),
+            ExecutableColumn::Date {
+                rules, null_check, ..
+            } => (rules.iter().map(|r| r.name()).collect(), null_check),
+            ExecutableColumn::Timestamp {
+                rules, null_check, ..
+            } => (rules.iter().map(|r| r.name()).collect(), null_check),
+            ExecutableColumn::Boolean {
+                rules,
+                ratio_rules,
+                null_check,
+                ..
+            } => (
+                rules
+                    .iter()
+                    .map(|r| r.name())
+                    .chain(ratio_rules.iter().map(|r| r.name()))
+                    .collect(),
+                null_check,
+            ),
+        };
+        names.extend(null_check.iter().map(|r| r.name()));
+        if self.has_unicity() {
+            // Reported by the engine once every batch is hashed
+            names.push("Unicity".to_string());
+        }
+        names.extend(self.grouped_unicity_check().map(|r| r.name()));
+        names.extend(self.sanity_rules().iter().map(|r| r.name()));
+        names
+    }
+
     /// Check if this column has a uniqueness constraint.
     ///
     /// Used by the validation engine to determine which columns need global
@@ -107,8 +250,37 @@ impl ExecutableColumn {
             ExecutableColumn::Integer { unicity_check, .. } => unicity_check.is_some(),
             ExecutableColumn::Float { unicity_check, .. } => unicity_check.is_some(),
             ExecutableColumn::Date { unicity_check, .. } => unicity_check.is_some(),
+            ExecutableColumn::Timestamp { unicity_check, .. } => unicity_check.is_some(),
+            ExecutableColumn::Boolean { .. } => false,
+        }
+    }
+    /// Get the uniqueness constraint within groups of rows, if any.
+    pub fn grouped_unicity_check(&self) -> Option<&GroupedUnicityCheck> {
+        match self {
+            ExecutableColumn::String {
+                grouped_unicity_check,
+                ..
+            }
+            | ExecutableColumn::Integer {
+                grouped_unicity_check,
+                ..
+            }
+            | ExecutableColumn::Float {
+                grouped_unicity_check,
+                ..
+            }
+            | ExecutableColumn::Date {
+                grouped_unicity_check,
+                ..
+            }
+            | ExecutableColumn::Timestamp {
+                grouped_unicity_check,
+                ..
+            } => grouped_unicity_check.as_ref(),
+            ExecutableColumn::Boolean { .. } => None,
         }
     }
+
     /// Get the unicity threshold if this column has a uniqueness constraint.
     ///
     /// Returns the configured threshold, or 0.0 as default if no unicity check exists.
@@ -130,6 +302,11 @@ impl ExecutableColumn {
                 .as_ref()
                 .map(|u| u.get_threshold())
                 .unwrap_or(0.0),
+            ExecutableColumn::Timestamp { unicity_check, .. } => unicity_check
+                .as_ref()
+                .map(|u| u.get_threshold())
+                .unwrap_or(0.0),
+            ExecutableColumn::Boolean { .. } => 0.0,
         }
     }
 }
@@ -137,11 +314,125 @@ impl ExecutableColumn {
 pub struct ExecutableRelation {
     pub names: [String; 2],
     pub rules: Vec<Box<dyn RelationRule>>,
+    /// Checked once all batches are accumulated, not row by row
+    pub correlations: Vec<CorrelationCheck>,
+    /// Checked once the values of both columns are accumulated
+    pub subsets: Vec<SubsetCheck>,
 }
 
 impl ExecutableRelation {
-    pub fn new(names: [String; 2], rules: Vec<Box<dyn RelationRule>>) -> Self {
-        Self { names, rules }
+    pub fn new(
+        names: [String; 2],
+        rules: Vec<Box<dyn RelationRule>>,
+        correlations: Vec<CorrelationCheck>,
+        subsets: Vec<SubsetCheck>,
+    ) -> Self {
+        Self {
+            names,
+            rules,
+            correlations,
+            subsets,
+        }
+    }
+}
+
+/// Compiled group assertions, evaluated once all batches are aggregated.
+pub struct ExecutableGroup {
+    pub group_by: String,
+    /// Summed column, only set when there are sum rules
+    pub value: Option<String>,
+    pub rules: Vec<GroupSumCheck>,
+    pub count_rules: Vec<GroupCountCheck>,
+}
+
+impl ExecutableGroup {
+    pub fn new(
+        group_by: String,
+        value: Option<String>,
+        rules: Vec<GroupSumCheck>,
+        count_rules: Vec<GroupCountCheck>,
+    ) -> Self {
+        Self {
+            group_by,
+            value,
+            rules,
+            count_rules,
+        }
+    }
+
+    /// Columns read by the group assertions
+    pub fn columns(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.group_by).chain(&self.value)
+    }
+}
+
+/// Cross-table referential constraint: every value of `child_table.child_column`
+/// must exist in `parent_table.parent_column`.
+pub struct ForeignKey {
+    pub child_table: String,
+    pub child_column: String,
+    pub parent_table: String,
+    pub parent_column: String,
+    rule: ForeignKeyCheck,
+}
+
+impl ForeignKey {
+    pub fn new(
+        child_table: String,
+        child_column: String,
+        parent_table: String,
+        parent_column: String,
+        threshold: f64,
+    ) -> Self {
+        Self {
+            child_table,
+            child_column,
+            parent_table,
+            parent_column,
+            rule: ForeignKeyCheck::new(threshold),
+        }
+    }
+
+    /// Display name, e.g. `orders.customer_id -> customers.id`
+    pub fn name(&self) -> String {
+        format!(
+            "{}.{} -> {}.{}",
+            self.child_table, self.child_column, self.parent_table, self.parent_column
+        )
+    }
+
+    /// Check the keys collected while validating both tables, a column whose keys were not
+    /// collected, such as the column of a sampled table, is read again.
+    fn validate(
+        &self,
+        parent: &dyn Table,
+        child: &dyn Table,
+        keys: &HashMap<String, HashMap<String, ColumnKeys>>,
+    ) -> Result<ForeignKeyResult, RuleError> {
+        let column_keys = |table: &dyn Table, table_name: &str, column: &str| match keys
+            .get(table_name)
+            .and_then(|keys| keys.get(column))
+        {
+            Some(keys) => Ok(Cow::Borrowed(keys)),
+            None => ColumnKeys::from_arrays(&table.read_column(column)?).map(Cow::Owned),
+        };
+        let parent_keys = column_keys(parent, &self.parent_table, &self.parent_column)?;
+        let child_keys = column_keys(child, &self.child_table, &self.child_column)?;
+        let outcome = self.rule.validate_keys(&parent_keys, &child_keys);
+        let error_percentage = if outcome.checked > 0 {
+            (outcome.orphans as f64 / outcome.checked as f64) * 100.
+        } else {
+            0.0
+        };
+        let result = RuleResult::new(
+            self.rule.name(),
+            outcome.orphans,
+            error_percentage,
+            self.rule.get_threshold(),
+            None,
+            error_percentage <= self.rule.get_threshold(),
+        );
+        Ok(ForeignKeyResult::new(self.name(), result, outcome.samples))
     }
 }
 
@@ -158,6 +449,9 @@ impl ExecutableRelation {
 /// - Thread-safe: tables can be validated in parallel
 pub struct Validator {
     tables: HashMap<String, Box<dyn Table>>,
+    foreign_keys: Vec<ForeignKey>,
+    continue_on_error: bool,
+    progress: Option<Arc<Progress>>,
 }
 
 impl Default for Validator {
@@ -173,9 +467,29 @@ impl Validator {
     pub fn new() -> Self {
         Self {
             tables: HashMap::new(),
+            foreign_keys: Vec::new(),
+            continue_on_error: false,
+            progress: None,
         }
     }
 
+    /// Keep validating the other tables when one fails to validate.
+    ///
+    /// The failing table is reported as a [`ValidationResult`] holding the error
+    /// message, its foreign keys are skipped.
+    pub fn with_continue_on_error(mut self, continue_on_error: bool) -> Self {
+        self.continue_on_error = continue_on_error;
+        self
+    }
+
+    /// Count the rows validated by `validate_all` in `progress`, table by table.
+    ///
+    /// `progress` can be polled from another thread to display the advancement.
+    pub fn with_progress(mut self, progress: Arc<Progress>) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
     /// Add a table to the validator.
     ///
     /// The table must be configured (via `commit()`) before being added.
@@ -189,6 +503,12 @@ impl Validator {
         let _ = self.tables.insert(name, table);
     }
 
+    /// Add a cross-table foreign key, checked by `validate_all()` once every table
+    /// has been validated. The result is attached to the child table.
+    pub fn add_foreign_key(&mut self, foreign_key: ForeignKey) {
+        self.foreign_keys.push(foreign_key);
+    }
+
     /// Validate a specific table by name.
     ///
     /// # Arguments
@@ -210,20 +530,84 @@ impl Validator {
 
     /// Validate all tables in the validator.
     ///
-    /// Validates each table sequentially and collects results.
+    /// Validates each table sequentially and collects results, then evaluates
+    /// the foreign keys across tables.
     /// If any table fails to validate, the error is propagated immediately
-    /// and remaining tables are not validated.
+    /// and remaining tables are not validated, unless `continue_on_error` is set.
     ///
     /// # Returns
     ///
     /// * `Ok(Vec<ValidationResult>)` - All tables validated
     /// * `Err(RuleError)` - First error encountered
     pub fn validate_all(&mut self) -> Result<Vec<ValidationResult>, RuleError> {
-        let mut results = Vec::new();
-        for (_name, table) in self.tables.iter_mut() {
-            let result = table.validate()?;
-            results.push(result);
+        let continue_on_error = self.continue_on_error;
+        // Key columns are collected during the validation of their table
+        for foreign_key in &self.foreign_keys {
+            for (table, column) in [
+                (&foreign_key.parent_table, &foreign_key.parent_column),
+                (&foreign_key.child_table, &foreign_key.child_column),
+            ] {
+                if let Some(table) = self.tables.get_mut(table) {
+                    table.collect_keys(column);
+                }
+            }
+        }
+        let mut results = HashMap::new();
+        for (name, table) in self.tables.iter_mut() {
+            if let Some(progress) = &self.progress {
+                progress.start_table(name);
+                table.set_progress(progress.clone());
+            }
+            let result = match table.validate() {
+                Ok(result) => result,
+                Err(e) if continue_on_error => {
+                    ValidationResult::from_error(name.clone(), e.to_string())
+                }
+                Err(e) => return Err(e),
+            };
+            results.insert(name.clone(), result);
+        }
+        if let Some(progress) = &self.progress {
+            progress.finish();
+        }
+        let keys: HashMap<String, HashMap<String, ColumnKeys>> = results
+            .iter_mut()
+            .map(|(name, result): (&String, &mut ValidationResult)| {
+                (name.clone(), result.take_keys())
+            })
+            .collect();
+
+        for foreign_key in &self.foreign_keys {
+            let parent = self
+                .tables
+                .get(&foreign_key.parent_table)
+                .ok_or_else(|| RuleError::TableNotFound(foreign_key.parent_table.clone()))?;
+            let child = self
+                .tables
+                .get(&foreign_key.child_table)
+                .ok_or_else(|| RuleError::TableNotFound(foreign_key.child_table.clone()))?;
+            let failed = |table: &String| {
+                results
+                    .get(table)
+                    .is_some_and(|r: &ValidationResult| r.error_message.is_some())
+            };
+            if failed(&foreign_key.parent_table) || failed(&foreign_key.child_table) {
+                continue;
+            }
+            let fk_result = match foreign_key.validate(parent.as_ref(), child.as_ref(), &keys) {
+                Ok(fk_result) => fk_result,
+                Err(e) if continue_on_error => {
+                    if let Some(result) = results.get_mut(&foreign_key.child_table) {
+                        result.error_message = Some(format!("{}: {}", foreign_key.name(), e));
+                    }
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            if let Some(result) = results.get_mut(&foreign_key.child_table) {
+                result.add_foreign_key_result(fk_result);
+            }
         }
-        Ok(results)
+        Ok(results.into_values().collect())
     }
 }
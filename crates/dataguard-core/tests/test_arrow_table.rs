@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use arrow::array::{Int32Array, LargeStringArray, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use dataguard_core::{ArrowTable, NumericColumnBuilder, StringColumnBuilder, Table};
+
+fn users_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("user", DataType::LargeUtf8, true),
+        Field::new("age", DataType::Int32, true),
+        Field::new("city", DataType::Utf8, true),
+    ]))
+}
+
+fn users_batch(users: Vec<Option<&str>>, ages: Vec<Option<i32>>) -> RecordBatch {
+    let cities = StringArray::from(vec![Some("Paris"); users.len()]);
+    RecordBatch::try_new(
+        users_schema(),
+        vec![
+            Arc::new(LargeStringArray::from(users)),
+            Arc::new(Int32Array::from(ages)),
+            Arc::new(cities),
+        ],
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_arrow_table_validation() {
+    let batches = vec![
+        users_batch(vec![Some("alice"), Some("al")], vec![Some(25), Some(150)]),
+        users_batch(vec![None, Some("carol")], vec![Some(40), None]),
+    ];
+
+    let mut user_col = StringColumnBuilder::new("user".to_string());
+    user_col.with_min_length(3, false, 0.0).is_not_null(0.0);
+    let mut age_col = NumericColumnBuilder::<i64>::new("age".to_string());
+    age_col.between(0, 120, 0.0);
+
+    let mut table =
+        ArrowTable::new("users".to_string(), users_schema(), batches).with_strict_schema(true);
+    table
+        .prepare(vec![Box::new(user_col), Box::new(age_col)], vec![])
+        .unwrap();
+
+    let res = table.validate().unwrap();
+    assert_eq!(res.total_rows, 4);
+    assert_eq!(res.get_unexpected_columns(), &["city".to_string()]);
+
+    // LargeUtf8 and Int32 columns are widened, the rules run on them
+    let columns = res.get_column_results();
+    let error_count = |column: &str, rule: &str| {
+        columns[column]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(error_count("user", "WithMinLength"), 1);
+    assert_eq!(error_count("user", "NullCheck"), 1);
+    assert_eq!(error_count("age", "Between"), 1);
+}
+
+#[test]
+fn test_arrow_table_incompatible_type() {
+    let batches = vec![users_batch(vec![Some("alice")], vec![Some(25)])];
+
+    // A string column cannot be validated as a float
+    let city_col = NumericColumnBuilder::<f64>::new("city".to_string());
+    let mut table = ArrowTable::new("users".to_string(), users_schema(), batches);
+    table.prepare(vec![Box::new(city_col)], vec![]).unwrap();
+
+    // The declared type is reported as for Parquet files, the column is not validated
+    let res = table.validate().unwrap();
+    let columns = res.get_column_results();
+    let type_check = columns["city"][0];
+    assert_eq!(type_check.rule_name, "TypeCheck");
+    assert_eq!(type_check.error_count, 1);
+    assert_eq!(
+        type_check.error_message.as_deref(),
+        Some("Arrow column is Utf8, expected Float64")
+    );
+}
+
+#[test]
+fn test_arrow_table_statistical_rules() {
+    let mut ages = vec![Some(30); 9];
+    ages.push(Some(300));
+    let batches = vec![users_batch(vec![Some("alice"); 10], ages)];
+
+    let mut age_col = NumericColumnBuilder::<i64>::new("age".to_string());
+    age_col.no_outliers_beyond(2.0, 0.0).unwrap();
+    let mut table = ArrowTable::new("users".to_string(), users_schema(), batches);
+    table.prepare(vec![Box::new(age_col)], vec![]).unwrap();
+
+    // Typed columns are not type checked, statistical rules still run on them
+    let res = table.validate().unwrap();
+    let columns = res.get_column_results();
+    let outliers = columns["age"]
+        .iter()
+        .find(|r| r.rule_name == "NoOutliersBeyond")
+        .unwrap();
+    assert_eq!(outliers.error_count, 1);
+}
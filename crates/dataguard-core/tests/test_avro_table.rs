@@ -0,0 +1,103 @@
+use std::fs::File;
+use std::sync::Arc;
+
+use arrow::array::{Float32Array, Int32Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use arrow_avro::writer::AvroWriter;
+use dataguard_core::rules::NormalizeMode;
+use dataguard_core::{AvroTable, NumericColumnBuilder, StringColumnBuilder, Table};
+use tempfile::tempdir;
+
+fn write_orders(path: &std::path::Path) {
+    let schema = Schema::new(vec![
+        Field::new("order_id", DataType::Utf8, true),
+        Field::new("quantity", DataType::Int32, false),
+        Field::new("price", DataType::Float32, false),
+    ]);
+    let batch = RecordBatch::try_new(
+        Arc::new(schema.clone()),
+        vec![
+            Arc::new(StringArray::from(vec![
+                Some("A-1"),
+                Some("A-2"),
+                None,
+                Some("A-2"),
+            ])),
+            Arc::new(Int32Array::from(vec![1, 0, 3, 12])),
+            Arc::new(Float32Array::from(vec![9.5, 3.0, -1.0, 4.25])),
+        ],
+    )
+    .unwrap();
+    let mut writer = AvroWriter::new(File::create(path).unwrap(), schema).unwrap();
+    writer.write(&batch).unwrap();
+    writer.finish().unwrap();
+}
+
+#[test]
+fn test_avro_table_validation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.avro");
+    write_orders(&file_path);
+
+    let mut order_col = StringColumnBuilder::new("order_id".to_string());
+    order_col
+        .is_not_null(0.0)
+        .is_unique(NormalizeMode::None, 0.0);
+    let mut quantity_col = NumericColumnBuilder::<i64>::new("quantity".to_string());
+    quantity_col.between(1, 10, 0.0);
+    let mut price_col = NumericColumnBuilder::<f64>::new("price".to_string());
+    price_col.is_positive(0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut table = AvroTable::new(file_path, "orders".to_string())
+        .unwrap()
+        .with_strict_schema(true);
+    table
+        .prepare(
+            vec![
+                Box::new(order_col),
+                Box::new(quantity_col),
+                Box::new(price_col),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+    let res = table.validate().unwrap();
+    assert_eq!(res.total_rows, 4);
+    assert!(res.get_missing_columns().is_empty());
+    assert!(res.get_unexpected_columns().is_empty());
+
+    // Avro `int` and `float` fields are widened, the rules run on them
+    let columns = res.get_column_results();
+    let error_count = |column: &str, rule: &str| {
+        columns[column]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(error_count("order_id", "NullCheck"), 1);
+    assert_eq!(error_count("order_id", "Unicity"), 1);
+    assert_eq!(error_count("quantity", "Between"), 2);
+    assert_eq!(error_count("price", "IsPositive"), 1);
+}
+
+#[test]
+fn test_avro_table_row_limit() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.avro");
+    write_orders(&file_path);
+
+    let quantity_col = NumericColumnBuilder::<i64>::new("quantity".to_string());
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut table = AvroTable::new(file_path, "orders".to_string())
+        .unwrap()
+        .with_row_limit(2);
+    table.prepare(vec![Box::new(quantity_col)], vec![]).unwrap();
+
+    let res = table.validate().unwrap();
+    assert_eq!(res.total_rows, 2);
+    assert!(res.sampled);
+}
@@ -1,7 +1,12 @@
+use chrono::NaiveDate;
 use dataguard_core::{
-    columns::date_builder::DateColumnBuilder, columns::relation_builder::RelationBuilder,
-    utils::operator::CompOperator, CsvTable, NumericColumnBuilder, StringColumnBuilder, Table,
+    columns::date_builder::DateColumnBuilder, columns::group_builder::GroupBuilder,
+    columns::relation_builder::RelationBuilder, columns::ConditionalRule,
+    readers::ReaderConfigBuilder, rules::NormalizeMode, utils::lookup::load_lookup,
+    utils::operator::CompOperator, BooleanColumnBuilder, CsvTable, NumericColumnBuilder, ReadMode,
+    RuleError, StringColumnBuilder, Table, TimestampColumnBuilder, ValidationResult,
 };
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
 use tempfile::tempdir;
@@ -24,7 +29,7 @@ fn test_table_string_column_validation() {
     desc_col
         .with_regex("^[a-z ]+$".to_string(), None, 0.0)
         .unwrap()
-        .with_min_length(6, 0.0);
+        .with_min_length(6, false, 0.0);
 
     let file_path = file_path.into_os_string().into_string().unwrap();
 
@@ -139,8 +144,8 @@ fn test_table_multiple_rules_per_column() {
 
     let mut username_col = StringColumnBuilder::new("username".to_string());
     username_col
-        .with_min_length(3, 0.0)
-        .with_max_length(20, 0.0)
+        .with_min_length(3, false, 0.0)
+        .with_max_length(20, false, 0.0)
         .is_alpha(0.0)
         .unwrap();
 
@@ -163,7 +168,7 @@ fn test_table_all_pass() {
     writeln!(file, "charlie,35").unwrap();
 
     let mut name_col = StringColumnBuilder::new("name".to_string());
-    name_col.with_min_length(3, 0.0);
+    name_col.with_min_length(3, false, 0.0);
 
     let mut age_col = NumericColumnBuilder::<i64>::new("age".to_string());
     age_col.is_positive(0.0);
@@ -176,7 +181,7 @@ fn test_table_all_pass() {
 
     let res = csv_table.validate();
 
-    assert_eq!(res.is_ok(), true);
+    assert!(res.is_ok());
 }
 
 #[test]
@@ -191,7 +196,7 @@ fn test_table_email_validation() {
     writeln!(file, "@invalid.com").unwrap(); // fail
 
     let mut email_col = StringColumnBuilder::new("email".to_string());
-    email_col.is_email(0.0).unwrap();
+    email_col.is_email(false, 0.0).unwrap();
 
     let file_path = file_path.into_os_string().into_string().unwrap();
     let mut csv_table = CsvTable::new(file_path, "stdout".to_string()).unwrap();
@@ -203,6 +208,88 @@ fn test_table_email_validation() {
     assert!(res.is_ok())
 }
 
+#[test]
+fn test_table_email_strict() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "lenient,strict").unwrap();
+    for email in [
+        "test@example.com",        // ok
+        "first.last@mail.co.uk",   // ok
+        "user+tag@my-domain.io",   // ok
+        "a@b",                     // fail, no TLD
+        "user@",                   // fail, local part only
+        "user@domain..com",        // strict fail, empty label
+        "user@-domain.com",        // strict fail, label starts with a hyphen
+        ".user@example.com",       // strict fail, leading dot
+        "first..last@example.com", // strict fail, repeated dot
+    ] {
+        writeln!(file, "{},{}", email, email).unwrap();
+    }
+
+    let mut lenient_col = StringColumnBuilder::new("lenient".to_string());
+    lenient_col.is_email(false, 0.0).unwrap();
+    let mut strict_col = StringColumnBuilder::new("strict".to_string());
+    strict_col.is_email(true, 0.0).unwrap();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "emails".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(lenient_col), Box::new(strict_col)], vec![])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let results = res.get_column_results();
+    let errors = |column: &str| {
+        results[column]
+            .iter()
+            .find(|r| r.rule_name == "IsEmail")
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(errors("lenient"), 2);
+    assert_eq!(errors("strict"), 6);
+}
+
+#[test]
+fn test_table_unique_within_group() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "tenant_id,email,user_id").unwrap();
+    writeln!(file, "1,alice@x.com,10").unwrap();
+    writeln!(file, "2,alice@x.com,10").unwrap(); // ok, other tenant
+    writeln!(file, "1,bob@x.com,11").unwrap();
+    writeln!(file, "1,alice@x.com,12").unwrap(); // fail, same tenant
+    writeln!(file, ",alice@x.com,13").unwrap(); // skipped, no tenant
+
+    // The key column needs no rule of its own
+    let mut email_col = StringColumnBuilder::new("email".to_string());
+    email_col.is_unique_within("tenant_id", 0.0);
+    let mut user_col = NumericColumnBuilder::<i64>::new("user_id".to_string());
+    user_col.is_unique_within("tenant_id", 0.0).is_unique(0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "users".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(email_col), Box::new(user_col)], vec![])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let results = res.get_column_results();
+    let errors = |column: &str, rule_name: &str| {
+        results[column]
+            .iter()
+            .find(|r| r.rule_name == rule_name)
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(errors("email", "UniqueWithinGroup"), 1);
+    assert_eq!(errors("user_id", "UniqueWithinGroup"), 0);
+    assert_eq!(errors("user_id", "Unicity"), 1);
+}
+
 #[test]
 fn test_table_mixed_column_types() {
     let dir = tempdir().unwrap();
@@ -215,7 +302,7 @@ fn test_table_mixed_column_types() {
     writeln!(file, "dave,35,90,5.0,2024-02-01,2024-02-28").unwrap(); // price fail (monotonicity)
 
     let mut name_col = StringColumnBuilder::new("name".to_string());
-    name_col.with_min_length(3, 0.0);
+    name_col.with_min_length(3, false, 0.0);
 
     let mut age_col = NumericColumnBuilder::<i64>::new("age".to_string());
     age_col.between(0, 120, 0.0);
@@ -234,7 +321,7 @@ fn test_table_mixed_column_types() {
     // Create relation: start_date <= end_date
     let mut date_relation =
         RelationBuilder::new(["start_date".to_string(), "end_date".to_string()]);
-    date_relation.date_comparaison(CompOperator::Lte, 0.0);
+    date_relation.date_comparaison(CompOperator::Lte, None, 0.0);
 
     let file_path = file_path.into_os_string().into_string().unwrap();
     let mut csv_table = CsvTable::new(file_path, "stdout".to_string()).unwrap();
@@ -256,3 +343,1411 @@ fn test_table_mixed_column_types() {
     let res = csv_table.validate();
     assert!(res.is_ok())
 }
+
+#[test]
+fn test_table_timestamp_column() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "event_ts,closed_ts").unwrap();
+    writeln!(file, "2024-01-31 09:15:00,2024-01-31 10:00:00").unwrap(); // ok
+    writeln!(file, "2024-01-31 20:45:10,2024-01-31 20:45:09").unwrap(); // outside hours, closed before opened
+    writeln!(file, "2024-01-31,2024-02-01 10:00:00").unwrap(); // not a timestamp
+    writeln!(file, "2023-12-31 23:59:59,2024-01-01 10:00:00").unwrap(); // before bound, outside hours
+
+    let mut event =
+        TimestampColumnBuilder::new("event_ts".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+    event
+        .time_between(9, 18, 0.0)
+        .unwrap()
+        .is_after("2024-01-01 00:00:00", 0.0)
+        .unwrap();
+    let closed =
+        TimestampColumnBuilder::new("closed_ts".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+    let mut relation = RelationBuilder::new(["event_ts".to_string(), "closed_ts".to_string()]);
+    relation.date_comparaison(CompOperator::Lte, None, 0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "events".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(event), Box::new(closed)], vec![relation])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let errors = |rule: &str| {
+        res.get_column_results()["event_ts"]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .map(|r| r.error_count)
+            .unwrap()
+    };
+    assert_eq!(errors("TypeCheck"), 1);
+    assert_eq!(errors("TimeBetween"), 2);
+    assert_eq!(errors("IsAfter"), 1);
+    let relation_errors: usize = res
+        .get_relation_results()
+        .values()
+        .flatten()
+        .map(|r| r.error_count)
+        .sum();
+    // The unparsable timestamp is a null on one side, which counts as a violation
+    assert_eq!(relation_errors, 2);
+}
+
+#[test]
+fn test_table_date_comparaison_max_gap() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "order_date,ship_date").unwrap();
+    writeln!(file, "2024-01-01,2024-01-31").unwrap(); // 30 days, ok
+    writeln!(file, "2024-01-01,2024-02-01").unwrap(); // 31 days, gap exceeded
+    writeln!(file, "2024-01-10,2024-01-09").unwrap(); // shipped before ordered
+    writeln!(file, "2024-03-01,2024-03-01").unwrap(); // ok
+
+    let order = DateColumnBuilder::new("order_date".to_string(), "%Y-%m-%d".to_string());
+    let ship = DateColumnBuilder::new("ship_date".to_string(), "%Y-%m-%d".to_string());
+    let mut relation = RelationBuilder::new(["order_date".to_string(), "ship_date".to_string()]);
+    relation.date_comparaison(CompOperator::Lte, Some(30), 0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "orders".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(order), Box::new(ship)], vec![relation])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let rule = &res.get_relation_results()["order_date | ship_date"][0];
+    assert_eq!(rule.rule_name, "DateCompareLesserThanEqualWithinGap");
+    assert_eq!(rule.error_count, 2);
+}
+
+#[test]
+fn test_table_duration_between() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "start_ts,end_ts").unwrap();
+    writeln!(file, "2024-01-01 10:00:00,2024-01-01 10:30:00").unwrap(); // ok
+    writeln!(file, "2024-01-01 10:00:00,2024-01-01 11:00:01").unwrap(); // over an hour
+    writeln!(file, "2024-01-01 10:00:00,2024-01-01 10:00:00").unwrap(); // under a second
+    writeln!(file, "2024-01-01 10:00:00,not a timestamp").unwrap(); // skipped
+    writeln!(file, ",2024-01-01 10:00:00").unwrap(); // skipped
+
+    let format = "%Y-%m-%d %H:%M:%S".to_string();
+    let start = TimestampColumnBuilder::new("start_ts".to_string(), format.clone());
+    let end = TimestampColumnBuilder::new("end_ts".to_string(), format);
+    let mut relation = RelationBuilder::new(["start_ts".to_string(), "end_ts".to_string()]);
+    relation.duration_between(1, 3600, 0.0).unwrap();
+    assert!(relation.duration_between(10, 1, 0.0).is_err());
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "sessions".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(start), Box::new(end)], vec![relation])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let rules = &res.get_relation_results()["start_ts | end_ts"];
+    let error_count = |name: &str| {
+        rules
+            .iter()
+            .find(|r| r.rule_name == name)
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(error_count("DurationBetween"), 2);
+    assert_eq!(error_count("DurationBetweenSkipped"), 2);
+}
+
+#[test]
+fn test_table_row_limit() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "id").unwrap();
+    for i in 0..100 {
+        writeln!(file, "{}", i).unwrap();
+    }
+
+    let mut id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+    id_col.between(0, 9, 0.0);
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let reader_config = ReaderConfigBuilder::new().with_batch_size(3).build();
+
+    let mut sample = CsvTable::new(file_path.clone(), "ids".to_string())
+        .unwrap()
+        .with_reader_config(reader_config)
+        .with_row_limit(10);
+    sample.prepare(vec![Box::new(id_col)], vec![]).unwrap();
+    let res = sample.validate().unwrap();
+    assert_eq!(res.total_rows, 10);
+    assert!(res.sampled);
+    assert!(res.get_column_results()["id"].iter().all(|r| r.pass));
+
+    // A limit above the row count validates the whole file
+    let mut whole = CsvTable::new(file_path, "ids".to_string())
+        .unwrap()
+        .with_row_limit(1_000);
+    let id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+    whole.prepare(vec![Box::new(id_col)], vec![]).unwrap();
+    let res = whole.validate().unwrap();
+    assert_eq!(res.total_rows, 100);
+    assert!(!res.sampled);
+}
+
+#[test]
+fn test_table_row_limit_exact() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "id").unwrap();
+    for i in 0..10 {
+        writeln!(file, "{}", i).unwrap();
+    }
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    // A file of exactly `limit` rows is validated whole, the limit falling within a
+    // batch or on a batch boundary
+    for batch_size in [3, 5, 10, 100] {
+        let reader_config = ReaderConfigBuilder::new()
+            .with_batch_size(batch_size)
+            .build();
+        let mut table = CsvTable::new(file_path.clone(), "ids".to_string())
+            .unwrap()
+            .with_reader_config(reader_config)
+            .with_row_limit(10);
+        let id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+        table.prepare(vec![Box::new(id_col)], vec![]).unwrap();
+        let res = table.validate().unwrap();
+        assert_eq!(res.total_rows, 10);
+        assert!(!res.sampled, "batch size {batch_size}");
+    }
+}
+
+#[test]
+fn test_table_row_count() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "id").unwrap();
+    for i in 0..3 {
+        writeln!(file, "{}", i).unwrap();
+    }
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let validate = |min_rows, max_rows| {
+        let id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+        let mut table = CsvTable::new(file_path.clone(), "export".to_string())
+            .unwrap()
+            .with_row_count(min_rows, max_rows);
+        table.prepare(vec![Box::new(id_col)], vec![]).unwrap();
+        table.validate().unwrap()
+    };
+
+    // A truncated export fails even though every value passes
+    let res = validate(Some(1_000), None);
+    let table_results = res.get_table_results();
+    assert_eq!(table_results.len(), 1);
+    assert_eq!(table_results[0].rule_name, "MinRows");
+    assert_eq!(table_results[0].error_count, 997);
+    assert!(!table_results[0].pass);
+    assert_eq!(table_results[0].observed, vec![("rows".to_string(), 3.0)]);
+    let (passed, total) = res.is_passed();
+    assert!(passed < total);
+
+    let res = validate(Some(1), Some(2));
+    let table_results = res.get_table_results();
+    assert!(table_results[0].pass);
+    assert_eq!(table_results[1].rule_name, "MaxRows");
+    assert_eq!(table_results[1].error_count, 1);
+
+    assert!(validate(None, None).get_table_results().is_empty());
+}
+
+#[test]
+fn test_table_read_modes() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "id,name").unwrap();
+    for i in 0..50 {
+        writeln!(file, "{},{}", i % 40, if i == 7 { "x" } else { "name" }).unwrap();
+    }
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let validate = |read_mode, memory_budget| {
+        let mut id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+        id_col.is_unique(0.0).between(0, 30, 0.0);
+        let mut name_col = StringColumnBuilder::new("name".to_string());
+        name_col.with_min_length(2, false, 0.0);
+        let mut table = CsvTable::new(file_path.clone(), "modes".to_string())
+            .unwrap()
+            .with_read_mode(read_mode)
+            .with_memory_budget(memory_budget);
+        table
+            .prepare(vec![Box::new(id_col), Box::new(name_col)], vec![])
+            .unwrap();
+        table.validate().unwrap()
+    };
+    let error_count = |res: &ValidationResult, column: &str, rule: &str| {
+        res.get_column_results()[column]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap()
+            .error_count
+    };
+
+    // The file is larger than the budget, auto mode streams it
+    let res = validate(ReadMode::Auto, 16);
+    assert_eq!(res.read_mode, Some(ReadMode::Streaming));
+    let res = validate(ReadMode::Auto, 1024 * 1024);
+    assert_eq!(res.read_mode, Some(ReadMode::Parallel));
+
+    for mode in [
+        ReadMode::Parallel,
+        ReadMode::Sequential,
+        ReadMode::Streaming,
+    ] {
+        let res = validate(mode, 0);
+        assert_eq!(res.read_mode, Some(mode));
+        assert_eq!(res.total_rows, 50, "{}", mode);
+        assert_eq!(error_count(&res, "id", "Unicity"), 10, "{}", mode);
+        assert_eq!(error_count(&res, "id", "Between"), 9, "{}", mode);
+        assert_eq!(error_count(&res, "name", "WithMinLength"), 1, "{}", mode);
+    }
+}
+
+#[test]
+fn test_table_gzip_read_sequentially() {
+    use flate2::{write::GzEncoder, Compression};
+
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv.gz");
+    let mut encoder = GzEncoder::new(File::create(&file_path).unwrap(), Compression::default());
+    writeln!(encoder, "id").unwrap();
+    for i in 0..20 {
+        writeln!(encoder, "{}", i).unwrap();
+    }
+    encoder.finish().unwrap();
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let validate = |read_mode| {
+        let mut id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+        id_col.is_unique(0.0);
+        let mut table = CsvTable::new(file_path.clone(), "gzip".to_string())
+            .unwrap()
+            .with_read_mode(read_mode);
+        table.prepare(vec![Box::new(id_col)], vec![]).unwrap();
+        table.validate().unwrap()
+    };
+
+    // Gzip streams can not be split, the parallel read falls back and says why
+    for read_mode in [ReadMode::Auto, ReadMode::Parallel] {
+        let res = validate(read_mode);
+        assert_eq!(res.total_rows, 20);
+        assert_eq!(res.read_mode, Some(ReadMode::Sequential));
+        assert_eq!(
+            res.read_note.as_deref(),
+            Some("gzip compressed files can not be split between threads")
+        );
+    }
+    for read_mode in [ReadMode::Sequential, ReadMode::Streaming] {
+        let res = validate(read_mode);
+        assert_eq!(res.read_mode, Some(read_mode));
+        assert!(res.read_note.is_none());
+    }
+}
+
+#[test]
+fn test_table_streaming_matches_batch_mode() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "id,amount,code").unwrap();
+    for i in 0..100 {
+        let amount = if i % 33 == 0 { 500 } else { i % 17 };
+        writeln!(file, "{},{},C{}", i % 90, amount, i % 45).unwrap();
+    }
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    // Mini batches spread duplicates and the column statistics over several batches
+    let validate = |read_mode| {
+        let mut id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+        id_col.is_unique(0.0).is_monotonically_increasing(0.0);
+        let mut amount_col = NumericColumnBuilder::<i64>::new("amount".to_string());
+        amount_col
+            .std_dev_check(0.0, 2.0)
+            .mean_variance(0.0, 100.0)
+            .no_outliers_beyond(3.0, 0.0)
+            .unwrap()
+            .percentile_below(0.9, 10.0, 0.0)
+            .unwrap();
+        let mut code_col = StringColumnBuilder::new("code".to_string());
+        code_col.is_unique(NormalizeMode::None, 0.0);
+        let mut table = CsvTable::new(file_path.clone(), "modes".to_string())
+            .unwrap()
+            .with_reader_config(ReaderConfigBuilder::new().with_batch_size(7).build())
+            .with_read_mode(read_mode);
+        table
+            .prepare(
+                vec![Box::new(id_col), Box::new(amount_col), Box::new(code_col)],
+                vec![],
+            )
+            .unwrap();
+        table.validate().unwrap()
+    };
+    let error_counts = |res: &ValidationResult| {
+        let mut counts: Vec<(String, String, usize)> = res
+            .get_column_results()
+            .iter()
+            .flat_map(|(column, rules)| {
+                rules
+                    .iter()
+                    .map(|r| (column.to_string(), r.rule_name.clone(), r.error_count))
+            })
+            .collect();
+        counts.sort();
+        counts
+    };
+
+    let batch = validate(ReadMode::Sequential);
+    let streamed = validate(ReadMode::Streaming);
+    assert_eq!(streamed.read_mode, Some(ReadMode::Streaming));
+    assert_eq!(streamed.total_rows, 100);
+    assert_eq!(error_counts(&streamed), error_counts(&batch));
+    assert_eq!(
+        error_counts(&validate(ReadMode::Parallel)),
+        error_counts(&batch)
+    );
+
+    let unicity = |column: &str| {
+        streamed.get_column_results()[column]
+            .iter()
+            .find(|r| r.rule_name == "Unicity")
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(unicity("id"), 10);
+    assert_eq!(unicity("code"), 55);
+}
+
+#[test]
+fn test_table_sum_equals_relation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "subtotal,tax,total").unwrap();
+    writeln!(file, "100,20.0,120.0").unwrap();
+    writeln!(file, "50,10.0,61.0").unwrap(); // off by one
+    writeln!(file, "10,,12.0").unwrap(); // tax null, skipped
+    writeln!(file, "5,1.0,6.001").unwrap(); // within tolerance
+
+    let subtotal = NumericColumnBuilder::<i64>::new("subtotal".to_string());
+    let tax = NumericColumnBuilder::<f64>::new("tax".to_string());
+    let total = NumericColumnBuilder::<f64>::new("total".to_string());
+    let mut relation = RelationBuilder::new(["subtotal".to_string(), "total".to_string()]);
+    relation
+        .sum_equals(vec!["tax".to_string()], 0.01, 0.0)
+        .unwrap();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "orders".to_string()).unwrap();
+    csv_table
+        .prepare(
+            vec![Box::new(subtotal), Box::new(tax), Box::new(total)],
+            vec![relation],
+        )
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let rules = &res.get_relation_results()["subtotal | total"];
+    let rule = |name: &str| *rules.iter().find(|r| r.rule_name == name).unwrap();
+    assert_eq!(rule("SumEquals").error_count, 1);
+    assert!(!rule("SumEquals").pass);
+    assert_eq!(rule("SumEqualsSkipped").error_count, 1);
+    assert!(rule("SumEqualsSkipped").pass);
+}
+
+#[test]
+fn test_table_consistent_length() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "sku").unwrap();
+    writeln!(file, "AB-1234").unwrap();
+    writeln!(file, "AB-5678").unwrap();
+    writeln!(file, "AB-9012").unwrap();
+    writeln!(file, "AB-34567").unwrap(); // within tolerance
+    writeln!(file, "AB12").unwrap(); // outlier
+
+    let mut sku_col = StringColumnBuilder::new("sku".to_string());
+    sku_col.consistent_length(1, 10.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "products".to_string()).unwrap();
+    csv_table.prepare(vec![Box::new(sku_col)], vec![]).unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let columns = res.get_column_results();
+    let rule = columns["sku"]
+        .iter()
+        .find(|r| r.rule_name == "ConsistentLength")
+        .unwrap();
+    assert_eq!(rule.error_count, 1);
+    assert!(!rule.pass);
+    assert_eq!(rule.observed, vec![("dominant_length".to_string(), 7.0)]);
+}
+
+#[test]
+fn test_table_correlation_relation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "inside,outside").unwrap();
+    writeln!(file, "10,20.5").unwrap();
+    writeln!(file, "12,23.0").unwrap();
+    writeln!(file, "15,").unwrap(); // null side, not compared
+    writeln!(file, "14,27.5").unwrap();
+    writeln!(file, "18,35.0").unwrap();
+
+    let inside = NumericColumnBuilder::<i64>::new("inside".to_string());
+    let outside = NumericColumnBuilder::<f64>::new("outside".to_string());
+    let mut relation = RelationBuilder::new(["inside".to_string(), "outside".to_string()]);
+    relation.correlation(0.9, 0.0).unwrap();
+    assert!(relation.correlation(1.5, 0.0).is_err());
+    let mut strict = RelationBuilder::new(["outside".to_string(), "inside".to_string()]);
+    strict.correlation(1.0, 0.0).unwrap();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "sensors".to_string()).unwrap();
+    csv_table
+        .prepare(
+            vec![Box::new(inside), Box::new(outside)],
+            vec![relation, strict],
+        )
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let relations = res.get_relation_results();
+    let correlation = &relations["inside | outside"][0];
+    assert_eq!(correlation.rule_name, "Correlation");
+    assert!(correlation.pass);
+    assert_eq!(correlation.observed[0].0, "coefficient");
+    assert!(correlation.observed[0].1 > 0.99);
+    // Below the floor, every compared row is an error
+    let strict = &relations["outside | inside"][0];
+    assert!(!strict.pass);
+    assert_eq!(strict.error_count, 4);
+}
+
+#[test]
+fn test_table_subset_relation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "child_category,parent_category").unwrap();
+    writeln!(file, "books,").unwrap(); // only found as a parent further down
+    writeln!(file, "toys,books").unwrap();
+    writeln!(file, "garden,toys").unwrap(); // never a parent
+    writeln!(file, ",garden_tools").unwrap(); // null child, not checked
+    writeln!(file, "garden,books").unwrap();
+
+    let child = StringColumnBuilder::new("child_category".to_string());
+    let parent = StringColumnBuilder::new("parent_category".to_string());
+    let mut relation =
+        RelationBuilder::new(["child_category".to_string(), "parent_category".to_string()]);
+    relation.is_subset_of(0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "categories".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(child), Box::new(parent)], vec![relation])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let relations = res.get_relation_results();
+    let subset = &relations["child_category | parent_category"][0];
+    assert_eq!(subset.rule_name, "IsSubsetOf");
+    assert!(!subset.pass);
+    assert_eq!(subset.error_count, 2);
+    assert_eq!(subset.samples, vec![(2, "garden".to_string())]);
+    assert_eq!(subset.observed, vec![("missing_values".to_string(), 1.0)]);
+}
+
+#[test]
+fn test_table_conditional_relation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "status,shipped_date,weight").unwrap();
+    writeln!(file, "shipped,2024-01-10,2.5").unwrap();
+    writeln!(file, "shipped,,1.0").unwrap(); // shipped without a date
+    writeln!(file, "pending,,120.0").unwrap(); // not shipped, not checked
+    writeln!(file, ",,").unwrap(); // null status never matches
+    writeln!(file, "shipped,2024-01-12,80.0").unwrap(); // too heavy to ship
+
+    let status = StringColumnBuilder::new("status".to_string());
+    let shipped_date = DateColumnBuilder::new("shipped_date".to_string(), "%Y-%m-%d".to_string());
+    let weight = NumericColumnBuilder::<f64>::new("weight".to_string());
+
+    let mut date_relation =
+        RelationBuilder::new(["status".to_string(), "shipped_date".to_string()]);
+    date_relation
+        .conditional("shipped".to_string(), ConditionalRule::NotNull, 0.0)
+        .unwrap();
+    let mut weight_relation = RelationBuilder::new(["status".to_string(), "weight".to_string()]);
+    weight_relation
+        .conditional(
+            "shipped".to_string(),
+            ConditionalRule::Between {
+                min: 0.0,
+                max: 50.0,
+            },
+            0.0,
+        )
+        .unwrap();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "orders".to_string()).unwrap();
+    csv_table
+        .prepare(
+            vec![Box::new(status), Box::new(shipped_date), Box::new(weight)],
+            vec![date_relation, weight_relation],
+        )
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let relations = res.get_relation_results();
+    let date_rule = relations["status | shipped_date"][0];
+    assert_eq!(date_rule.rule_name, "ConditionalNotNull");
+    assert_eq!(date_rule.error_count, 1);
+    let weight_rule = relations["status | weight"][0];
+    assert_eq!(weight_rule.rule_name, "ConditionalBetween");
+    assert_eq!(weight_rule.error_count, 1);
+}
+
+#[test]
+fn test_table_conditional_relation_float_condition() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "ratio,note").unwrap();
+
+    let mut relation = RelationBuilder::new(["ratio".to_string(), "note".to_string()]);
+    relation
+        .conditional("1.0".to_string(), ConditionalRule::NotNull, 0.0)
+        .unwrap();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "notes".to_string()).unwrap();
+    let res = csv_table.prepare(
+        vec![
+            Box::new(NumericColumnBuilder::<f64>::new("ratio".to_string())),
+            Box::new(StringColumnBuilder::new("note".to_string())),
+        ],
+        vec![relation],
+    );
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_table_schema_errors() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "id,name,extra").unwrap();
+    writeln!(file, "1,alice,x").unwrap();
+
+    let columns = || -> Vec<Box<dyn dataguard_core::columns::ColumnBuilder>> {
+        vec![
+            Box::new(NumericColumnBuilder::<i64>::new("id".to_string())),
+            Box::new(StringColumnBuilder::new("name".to_string())),
+            Box::new(StringColumnBuilder::new("email".to_string())),
+        ]
+    };
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let mut csv_table = CsvTable::new(file_path.clone(), "users".to_string()).unwrap();
+    csv_table.prepare(columns(), vec![]).unwrap();
+    let res = csv_table.validate().unwrap();
+    assert_eq!(res.get_missing_columns(), ["email".to_string()]);
+    assert!(res.get_unexpected_columns().is_empty());
+    let (passed, total) = res.is_passed();
+    assert!(passed < total);
+
+    let mut csv_table = CsvTable::new(file_path, "users".to_string())
+        .unwrap()
+        .with_strict_schema(true);
+    csv_table.prepare(columns(), vec![]).unwrap();
+    let res = csv_table.validate().unwrap();
+    assert_eq!(res.get_missing_columns(), ["email".to_string()]);
+    assert_eq!(res.get_unexpected_columns(), ["extra".to_string()]);
+}
+
+#[test]
+fn test_table_optional_column() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "id,name").unwrap();
+    writeln!(file, "1,alice").unwrap();
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let mut email_col = StringColumnBuilder::new("email".to_string()).with_optional(true);
+    email_col.is_not_null(0.0);
+    let mut phone_col = StringColumnBuilder::new("phone".to_string());
+    phone_col.is_not_null(0.0);
+    let mut name_col = StringColumnBuilder::new("name".to_string()).with_optional(true);
+    name_col.with_min_length(10, false, 0.0);
+
+    let mut csv_table = CsvTable::new(file_path, "users".to_string())
+        .unwrap()
+        .with_strict_schema(true);
+    csv_table
+        .prepare(
+            vec![Box::new(email_col), Box::new(phone_col), Box::new(name_col)],
+            vec![],
+        )
+        .unwrap();
+    let res = csv_table.validate().unwrap();
+    // Only the required column is reported, the rules of the missing optional one are skipped
+    assert_eq!(res.get_missing_columns(), ["phone".to_string()]);
+    let columns = res.get_column_results();
+    assert!(!columns.contains_key("email"));
+    // An optional column present in the file is validated as usual
+    let min_length = columns["name"]
+        .iter()
+        .find(|r| r.rule_name == "WithMinLength")
+        .unwrap();
+    assert!(!min_length.pass);
+}
+
+#[test]
+fn test_table_reference_date() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "day,event_ts,birth_date").unwrap();
+    writeln!(file, "2024-06-14,2024-06-14 23:59:59,2006-06-15").unwrap();
+    writeln!(file, "2024-06-15,2024-06-15 00:00:00,2006-06-16").unwrap(); // 17 on the reference date
+    writeln!(file, "2024-06-16,2024-06-15 12:00:00,1959-06-15").unwrap();
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let reference = NaiveDate::from_ymd_opt(2024, 6, 15);
+    let mut day = DateColumnBuilder::new("day".to_string(), "%Y-%m-%d".to_string());
+    day.is_not_futur(reference, 0.0);
+    let mut past_day = DateColumnBuilder::new("day".to_string(), "%Y-%m-%d".to_string());
+    past_day.is_not_past(reference, 0.0);
+    let mut event =
+        TimestampColumnBuilder::new("event_ts".to_string(), "%Y-%m-%d %H:%M:%S".to_string());
+    event.is_not_futur(reference, 0.0);
+    let mut birth = DateColumnBuilder::new("birth_date".to_string(), "%Y-%m-%d".to_string());
+    birth.age_between(18, 65, reference, 0.0).unwrap();
+
+    let validate = |column: DateColumnBuilder| {
+        let mut csv_table = CsvTable::new(file_path.clone(), "events".to_string()).unwrap();
+        csv_table
+            .prepare(
+                vec![
+                    Box::new(column),
+                    Box::new(event.clone()),
+                    Box::new(birth.clone()),
+                ],
+                vec![],
+            )
+            .unwrap();
+        csv_table.validate().unwrap()
+    };
+    let errors = |res: &ValidationResult, column: &str, rule: &str| {
+        res.get_column_results()[column]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .map(|r| r.error_count)
+            .unwrap()
+    };
+
+    // Dates on the reference date itself are flagged by both rules
+    let res = validate(day);
+    assert_eq!(errors(&res, "day", "IsNotFutur"), 2);
+    assert_eq!(errors(&res, "event_ts", "IsNotFutur"), 2);
+    assert_eq!(errors(&res, "birth_date", "AgeBetween"), 1);
+    let res = validate(past_day);
+    assert_eq!(errors(&res, "day", "IsNotPast"), 2);
+}
+
+#[test]
+fn test_table_percentile_below() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "latency").unwrap();
+    for i in 1..=100 {
+        writeln!(file, "{}", i).unwrap();
+    }
+    writeln!(file).unwrap(); // null, ignored by the quantile
+
+    let mut latency = NumericColumnBuilder::<i64>::new("latency".to_string());
+    latency.percentile_below(0.95, 90.0, 0.0).unwrap();
+    assert!(latency.percentile_below(95.0, 90.0, 0.0).is_err());
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "requests".to_string()).unwrap();
+    csv_table.prepare(vec![Box::new(latency)], vec![]).unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let rules = &res.get_column_results()["latency"];
+    let rule = rules
+        .iter()
+        .find(|r| r.rule_name == "PercentileBelow")
+        .unwrap();
+    // p95 is ~95, the 10 values above 90 are errors
+    assert!(!rule.pass);
+    assert_eq!(rule.error_count, 10);
+    assert_eq!(rule.observed[0].0, "quantile");
+    assert!((rule.observed[0].1 - 95.0).abs() < 1.0);
+}
+
+#[test]
+fn test_table_group_sum_assertion() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "region,amount").unwrap();
+    writeln!(file, "north,10.5").unwrap();
+    writeln!(file, "south,20").unwrap();
+    writeln!(file, "north,4.5").unwrap();
+    writeln!(file, "east,7").unwrap();
+    writeln!(file, "south,").unwrap(); // null value, skipped
+
+    let lookup_path = dir.path().join("lookup.csv");
+    let mut lookup = File::create(&lookup_path).unwrap();
+    writeln!(lookup, "region,expected").unwrap();
+    writeln!(lookup, "north,15").unwrap(); // match
+    writeln!(lookup, "south,20").unwrap(); // match
+    writeln!(lookup, "east,10").unwrap(); // mismatch, delta -3
+
+    let expected = load_lookup(lookup_path.to_str().unwrap()).unwrap();
+    let mut group = GroupBuilder::new("region".to_string(), "amount".to_string());
+    group.sum_equals(expected, 0.01, 0.0);
+
+    let mut amount_col = NumericColumnBuilder::<f64>::new("amount".to_string());
+    amount_col.is_positive(0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "groups".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(amount_col)], vec![])
+        .unwrap();
+    csv_table.prepare_groups(vec![group]).unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let groups = res.get_group_results();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].name, "region | amount");
+    assert_eq!(groups[0].result.error_count, 1);
+    assert!(!groups[0].result.pass);
+    assert_eq!(groups[0].mismatches.len(), 1);
+    assert_eq!(groups[0].mismatches[0].group, "east");
+    assert_eq!(groups[0].mismatches[0].delta(), -3.0);
+
+    let (passed, total) = res.is_passed();
+    // TypeCheck and IsPositive on amount, plus the group assertion
+    assert_eq!(total, 3);
+    assert_eq!(passed, 2);
+}
+
+#[test]
+fn test_table_group_sum_unparseable_value() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "region,amount").unwrap();
+    writeln!(file, "north,10.5").unwrap();
+    writeln!(file, "south,n/a").unwrap();
+
+    let mut group = GroupBuilder::new("region".to_string(), "amount".to_string());
+    group.sum_equals(HashMap::new(), 0.01, 0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "groups".to_string()).unwrap();
+    csv_table.prepare(vec![], vec![]).unwrap();
+    csv_table.prepare_groups(vec![group]).unwrap();
+
+    // The unparseable amount must not be silently left out of the south sum
+    assert!(matches!(
+        csv_table.validate(),
+        Err(RuleError::TypeCastError(column, _)) if column == "amount"
+    ));
+}
+
+#[test]
+fn test_table_group_sum_mismatches_sampled() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "region,amount").unwrap();
+    writeln!(file, "north,10").unwrap();
+    writeln!(file, "south,20").unwrap();
+    writeln!(file, "east,7").unwrap();
+
+    let expected = HashMap::from([
+        ("north".to_string(), 15.0),
+        ("south".to_string(), 25.0),
+        ("east".to_string(), 7.0),
+    ]);
+    let mut group = GroupBuilder::new("region".to_string(), "amount".to_string());
+    group.sum_equals(expected, 0.01, 0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "groups".to_string())
+        .unwrap()
+        .with_max_samples(1);
+    csv_table.prepare(vec![], vec![]).unwrap();
+    csv_table.prepare_groups(vec![group]).unwrap();
+
+    // Mismatching groups are sampled, the error count covers all of them
+    let res = csv_table.validate().unwrap();
+    let groups = res.get_group_results();
+    assert_eq!(groups[0].result.error_count, 2);
+    assert_eq!(groups[0].mismatches.len(), 1);
+    assert_eq!(groups[0].mismatches[0].group, "north");
+}
+
+#[test]
+fn test_table_group_count_assertion() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "region,month").unwrap();
+    for month in 1..=3 {
+        writeln!(file, "north,{}", month).unwrap();
+        writeln!(file, "south,{}", month).unwrap();
+    }
+    writeln!(file, "south,3").unwrap(); // duplicated month
+    writeln!(file, "east,1").unwrap(); // missing months
+    writeln!(file, ",2").unwrap(); // null key, skipped
+
+    let mut group = GroupBuilder::count_by("region".to_string());
+    group.count_between(Some(3), Some(3), 0.0).unwrap();
+    assert!(GroupBuilder::count_by("region".to_string())
+        .count_between(None, None, 0.0)
+        .is_err());
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "groups".to_string())
+        .unwrap()
+        .with_max_samples(1);
+    csv_table.prepare(vec![], vec![]).unwrap();
+    csv_table.prepare_groups(vec![group]).unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let groups = res.get_group_results();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].name, "region");
+    assert_eq!(groups[0].result.rule_name, "GroupCount");
+    assert_eq!(groups[0].result.error_count, 2);
+    assert!(!groups[0].result.pass);
+    // Offending groups are sampled, sorted by key
+    assert_eq!(groups[0].mismatches.len(), 1);
+    assert_eq!(groups[0].mismatches[0].group, "east");
+    assert_eq!(groups[0].mismatches[0].delta(), -2.0);
+}
+
+#[test]
+fn test_table_not_all_null_not_all_same() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "note,status,amount,code,active").unwrap();
+    writeln!(file, ",ok,10,x,true").unwrap();
+    writeln!(file, ",ok,12,y,true").unwrap();
+    writeln!(file, ",ok,,z,").unwrap();
+
+    let mut note_col = StringColumnBuilder::new("note".to_string());
+    note_col.not_all_null();
+    let mut status_col = StringColumnBuilder::new("status".to_string());
+    status_col.not_all_null().not_all_same();
+    let mut amount_col = NumericColumnBuilder::<i64>::new("amount".to_string());
+    amount_col.not_all_null().not_all_same();
+    // Values failing the type check are null once casted
+    let mut code_col = NumericColumnBuilder::<i64>::new("code".to_string());
+    code_col.not_all_null();
+    let mut active_col = BooleanColumnBuilder::new("active".to_string());
+    active_col.not_all_same();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "sanity".to_string()).unwrap();
+    csv_table
+        .prepare(
+            vec![
+                Box::new(note_col),
+                Box::new(status_col),
+                Box::new(amount_col),
+                Box::new(code_col),
+                Box::new(active_col),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let results = res.get_column_results();
+    let passed = |column: &str, rule: &str| {
+        let result = results[column]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap();
+        assert!(result.error_count <= 1);
+        result.pass
+    };
+    assert!(!passed("note", "NotAllNull"));
+    assert!(passed("status", "NotAllNull"));
+    assert!(!passed("status", "NotAllSame"));
+    assert!(passed("amount", "NotAllNull"));
+    assert!(passed("amount", "NotAllSame"));
+    assert!(!passed("code", "NotAllNull"));
+    assert!(!passed("active", "NotAllSame"));
+}
+
+#[test]
+fn test_table_null_ratio_between() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "email,amount").unwrap();
+    for i in 0..20 {
+        let email = if i % 10 == 0 { "" } else { "a@b.c" };
+        writeln!(file, "{},{}", email, i).unwrap();
+    }
+
+    let mut email_col = StringColumnBuilder::new("email".to_string());
+    email_col.null_ratio_between(0.05, 0.15).unwrap();
+    // A column without any null is suspicious too
+    let mut amount_col = NumericColumnBuilder::<i64>::new("amount".to_string());
+    amount_col.null_ratio_between(0.01, 0.05).unwrap();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "nulls".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(email_col), Box::new(amount_col)], vec![])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let results = res.get_column_results();
+    let null_ratio = |column: &str| {
+        results[column]
+            .iter()
+            .find(|r| r.rule_name == "NullRatioBetween")
+            .unwrap()
+    };
+    let email = null_ratio("email");
+    assert!(email.pass);
+    assert_eq!(email.observed, vec![("null_ratio".to_string(), 0.1)]);
+    let amount = null_ratio("amount");
+    assert!(!amount.pass);
+    assert_eq!(amount.error_count, 1);
+    assert_eq!(amount.observed, vec![("null_ratio".to_string(), 0.0)]);
+}
+
+#[test]
+fn test_table_distinct_count_between() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "country_code,id").unwrap();
+    for i in 0..3000 {
+        let country = ["FR", "DE", "IT"][i % 3];
+        writeln!(file, "{},{}", country, i).unwrap();
+    }
+
+    let mut country_col = StringColumnBuilder::new("country_code".to_string());
+    country_col.distinct_count_between(1, 250).unwrap();
+    country_col.not_all_same();
+    // Above the exact range of the estimate
+    let mut id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+    id_col.distinct_count_between(1, 250).unwrap();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "countries".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(country_col), Box::new(id_col)], vec![])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let results = res.get_column_results();
+    let rule = |column: &str, rule_name: &str| {
+        results[column]
+            .iter()
+            .find(|r| r.rule_name == rule_name)
+            .unwrap()
+    };
+    let country = rule("country_code", "DistinctCountBetween");
+    assert!(country.pass);
+    assert_eq!(country.observed, vec![("distinct_count".to_string(), 3.0)]);
+    assert!(rule("country_code", "NotAllSame").pass);
+    let id = rule("id", "DistinctCountBetween");
+    assert!(!id.pass);
+    assert_eq!(id.error_count, 1);
+    let estimate = id.observed[0].1;
+    assert!((estimate - 3000.0).abs() / 3000.0 < 0.1, "{}", estimate);
+}
+
+#[test]
+fn test_table_float_is_finite() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "ratio").unwrap();
+    writeln!(file, "0.5").unwrap();
+    writeln!(file, "NaN").unwrap();
+    writeln!(file, "inf").unwrap();
+    writeln!(file).unwrap();
+
+    let mut ratio_col = NumericColumnBuilder::<f64>::new("ratio".to_string());
+    ratio_col.between(0.0, 1.0, 0.0).is_finite(0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "finite".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(ratio_col)], vec![])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let rules = &res.get_column_results()["ratio"];
+    let error_count = |rule: &str| {
+        rules
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(error_count("TypeCheck"), 0);
+    // NaN slips through the range check, only inf is above the bound
+    assert_eq!(error_count("Between"), 1);
+    assert_eq!(error_count("IsFinite"), 2);
+}
+
+#[test]
+fn test_table_boolean_column_validation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "is_active,consent").unwrap();
+    writeln!(file, "true,Y").unwrap();
+    writeln!(file, "1,N").unwrap(); // consent not true
+    writeln!(file, "no,Y").unwrap();
+    writeln!(file, "maybe,Y").unwrap(); // is_active type fail
+    writeln!(file, "yes,").unwrap(); // consent null
+
+    let mut active_col = BooleanColumnBuilder::new("is_active".to_string());
+    active_col.true_ratio_between(0.9, 1.0, 0.0).unwrap();
+    let mut consent_col = BooleanColumnBuilder::new("consent".to_string())
+        .with_tokens(vec!["Y".to_string()], vec!["N".to_string()])
+        .unwrap();
+    consent_col.is_true(0.0).is_not_null(0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "boolean".to_string()).unwrap();
+    csv_table
+        .prepare(vec![Box::new(active_col), Box::new(consent_col)], vec![])
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let columns = res.get_column_results();
+    let error_count = |column: &str, rule: &str| {
+        columns[column]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(error_count("is_active", "TypeCheck"), 1);
+    // 3 true out of 4 parsed values, 1 false value must flip to reach 90%
+    assert_eq!(error_count("is_active", "TrueRatioBetween"), 1);
+    assert_eq!(error_count("consent", "TypeCheck"), 0);
+    assert_eq!(error_count("consent", "IsTrue"), 1);
+    assert_eq!(error_count("consent", "NullCheck"), 1);
+}
+
+#[test]
+fn test_table_failing_row_samples() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "age").unwrap();
+    writeln!(file, "25").unwrap();
+    writeln!(file, "150").unwrap(); // row 1, range fail
+    writeln!(file, "abc").unwrap(); // row 2, type fail
+    writeln!(file, "-5").unwrap(); // row 3, range fail
+    writeln!(file, "200").unwrap(); // row 4, range fail
+
+    let mut age_col = NumericColumnBuilder::<i64>::new("age".to_string());
+    age_col.between(0, 120, 0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "samples".to_string())
+        .unwrap()
+        .with_max_samples(2);
+    csv_table.prepare(vec![Box::new(age_col)], vec![]).unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let columns = res.get_column_results();
+    let samples = |rule: &str| {
+        columns["age"]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap()
+            .samples
+            .clone()
+    };
+    assert_eq!(samples("TypeCheck"), vec![(2, "abc".to_string())]);
+    assert_eq!(
+        samples("Between"),
+        vec![(1, "150".to_string()), (3, "-5".to_string())]
+    );
+}
+
+#[test]
+fn test_table_profile() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "name,age,score,code").unwrap();
+    writeln!(file, "alice,30,1.5,x").unwrap();
+    writeln!(file, "bob,25,,1").unwrap();
+    writeln!(file, "alice,,4.5,2").unwrap();
+    writeln!(file, "zoé,41,3.0,2").unwrap();
+
+    // No rule is needed to profile a column
+    let columns: Vec<Box<dyn dataguard_core::columns::ColumnBuilder>> = vec![
+        Box::new(StringColumnBuilder::new("name".to_string())),
+        Box::new(NumericColumnBuilder::<i64>::new("age".to_string())),
+        Box::new(NumericColumnBuilder::<f64>::new("score".to_string())),
+        Box::new(NumericColumnBuilder::<i64>::new("code".to_string())),
+    ];
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path.clone(), "profile".to_string())
+        .unwrap()
+        .with_profile(true);
+    csv_table.prepare(columns, vec![]).unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let profiles = res.get_profiles();
+    let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["age", "code", "name", "score"]);
+
+    let age = &profiles[0];
+    assert_eq!(age.null_count, 1);
+    assert_eq!(age.distinct, 3);
+    assert_eq!(
+        (age.min, age.max, age.mean),
+        (Some(25.0), Some(41.0), Some(32.0))
+    );
+    assert_eq!(age.min_length, None);
+    // Values failing the type check are counted as nulls
+    assert_eq!(profiles[1].null_count, 1);
+    assert_eq!(profiles[1].distinct, 2);
+    let name = &profiles[2];
+    assert_eq!(name.distinct, 3);
+    assert_eq!((name.min_length, name.max_length), (Some(3), Some(5)));
+    assert_eq!(name.mean, None);
+    assert_eq!(profiles[3].mean, Some(3.0));
+
+    // Profiles are only computed on demand
+    let mut csv_table = CsvTable::new(file_path, "profile".to_string()).unwrap();
+    csv_table
+        .prepare(
+            vec![Box::new(StringColumnBuilder::new("name".to_string()))],
+            vec![],
+        )
+        .unwrap();
+    assert!(csv_table.validate().unwrap().get_profiles().is_empty());
+}
+
+#[test]
+fn test_table_equals_constant() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "schema_version,source_id,rate").unwrap();
+    writeln!(file, "v3,7,0.5").unwrap();
+    writeln!(file, "v3,7,0.5").unwrap();
+    writeln!(file, "V3,8,0.5").unwrap();
+    writeln!(file, ",7,").unwrap();
+
+    let mut version_col = StringColumnBuilder::new("schema_version".to_string());
+    version_col.equals("v3".to_string(), 0.0);
+    let mut source_col = NumericColumnBuilder::<i64>::new("source_id".to_string());
+    source_col.equals(7, 30.0);
+    let mut rate_col = NumericColumnBuilder::<f64>::new("rate".to_string());
+    rate_col.equals(0.5, 0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "constants".to_string()).unwrap();
+    csv_table
+        .prepare(
+            vec![
+                Box::new(version_col),
+                Box::new(source_col),
+                Box::new(rate_col),
+            ],
+            vec![],
+        )
+        .unwrap();
+
+    let res = csv_table.validate().unwrap();
+    let results = res.get_column_results();
+    let equals = |column: &str| {
+        let rule = results[column]
+            .iter()
+            .find(|r| r.rule_name == "Equals")
+            .unwrap();
+        (rule.error_count, rule.pass)
+    };
+    // Nulls are left to the null check
+    assert_eq!(equals("schema_version"), (1, false));
+    assert_eq!(equals("source_id"), (1, true));
+    assert_eq!(equals("rate"), (0, true));
+}
+
+#[test]
+fn test_table_select_columns() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("test.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "id,name,start_date,end_date").unwrap();
+    writeln!(file, "1,alice,2024-01-01,2024-02-01").unwrap();
+    writeln!(file, "1,,2024-03-01,2024-02-01").unwrap();
+
+    let mut id_col = NumericColumnBuilder::<i64>::new("id".to_string());
+    id_col.is_unique(0.0);
+    let mut name_col = StringColumnBuilder::new("name".to_string());
+    name_col.is_not_null(0.0);
+    let start = DateColumnBuilder::new("start_date".to_string(), "%Y-%m-%d".to_string());
+    let end = DateColumnBuilder::new("end_date".to_string(), "%Y-%m-%d".to_string());
+    let mut relation = RelationBuilder::new(["start_date".to_string(), "end_date".to_string()]);
+    relation.date_comparaison(CompOperator::Lte, None, 0.0);
+    // Not in the file, only reported when selected
+    let region = StringColumnBuilder::new("region".to_string());
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut csv_table = CsvTable::new(file_path, "selected".to_string())
+        .unwrap()
+        .with_strict_schema(true);
+    csv_table
+        .prepare(
+            vec![
+                Box::new(id_col),
+                Box::new(name_col),
+                Box::new(start),
+                Box::new(end),
+                Box::new(region),
+            ],
+            vec![relation],
+        )
+        .unwrap();
+    csv_table.select_columns(&["name".to_string(), "start_date".to_string()]);
+
+    let res = csv_table.validate().unwrap();
+    let columns = res.get_column_results();
+    let mut names: Vec<&String> = columns.keys().collect();
+    names.sort();
+    assert_eq!(names, ["name", "start_date"]);
+    let null_check = columns["name"]
+        .iter()
+        .find(|r| r.rule_name == "NullCheck")
+        .unwrap();
+    assert_eq!(null_check.error_count, 1);
+    // The relation uses a column that is not selected
+    assert!(res.get_relation_results().is_empty());
+    assert!(res.get_missing_columns().is_empty());
+    assert!(res.get_unexpected_columns().is_empty());
+}
+
+#[test]
+fn test_table_unicity_normalize() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("emails.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "email").unwrap();
+    for email in ["foo@x.com", "Foo@x.com", "\" FOO@x.com\"", "bar@x.com"] {
+        writeln!(file, "{}", email).unwrap();
+    }
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let duplicates = |normalize, read_mode| {
+        let mut email_col = StringColumnBuilder::new("email".to_string());
+        email_col.is_unique(normalize, 0.0);
+        let mut table = CsvTable::new(file_path.clone(), "emails".to_string())
+            .unwrap()
+            .with_read_mode(read_mode);
+        table.prepare(vec![Box::new(email_col)], vec![]).unwrap();
+        let res = table.validate().unwrap();
+        res.get_column_results()["email"]
+            .iter()
+            .find(|r| r.rule_name == "Unicity")
+            .unwrap()
+            .error_count
+    };
+
+    for read_mode in [ReadMode::Sequential, ReadMode::Streaming] {
+        assert_eq!(duplicates(NormalizeMode::None, read_mode), 0);
+        assert_eq!(duplicates(NormalizeMode::Lowercase, read_mode), 1);
+        assert_eq!(duplicates(NormalizeMode::TrimLowercase, read_mode), 2);
+    }
+}
+
+#[test]
+fn test_table_rule_labels() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("customers.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, "name,age").unwrap();
+    for line in ["Al,30", "Bob,", "Al,40"] {
+        writeln!(file, "{}", line).unwrap();
+    }
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let mut name_col = StringColumnBuilder::new("name".to_string());
+    name_col
+        .with_min_length(3, false, 0.0)
+        .with_label("Customer name too short".to_string())
+        .unwrap();
+    name_col
+        .is_unique(NormalizeMode::None, 0.0)
+        .with_label("Duplicate customer".to_string())
+        .unwrap();
+    let mut age_col = NumericColumnBuilder::<i64>::new("age".to_string());
+    age_col.is_not_null(0.0);
+    assert!(NumericColumnBuilder::<i64>::new("id".to_string())
+        .with_label("No rule to name".to_string())
+        .is_err());
+
+    let mut table = CsvTable::new(file_path, "customers".to_string()).unwrap();
+    table
+        .prepare(vec![Box::new(name_col), Box::new(age_col)], vec![])
+        .unwrap();
+    let res = table.validate().unwrap();
+    let results = res.get_column_results();
+    let label = |column: &str, rule: &str| {
+        let result = results[column]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap();
+        (result.label.clone(), result.display_name().to_string())
+    };
+    assert_eq!(
+        label("name", "WithMinLength"),
+        (
+            Some("Customer name too short".to_string()),
+            "Customer name too short".to_string()
+        )
+    );
+    assert_eq!(
+        label("name", "Unicity").0.as_deref(),
+        Some("Duplicate customer")
+    );
+    assert_eq!(label("name", "TypeCheck"), (None, "TypeCheck".to_string()));
+    assert_eq!(label("age", "NullCheck"), (None, "NullCheck".to_string()));
+}
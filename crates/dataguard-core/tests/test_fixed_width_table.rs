@@ -0,0 +1,89 @@
+use std::fs;
+
+use dataguard_core::readers::FixedWidthField;
+use dataguard_core::rules::NormalizeMode;
+use dataguard_core::{
+    FixedWidthTable, NumericColumnBuilder, ReadMode, RuleError, StringColumnBuilder, Table,
+};
+use tempfile::tempdir;
+
+fn fields() -> Vec<FixedWidthField> {
+    vec![
+        FixedWidthField::new("account".to_string(), 0, 6),
+        FixedWidthField::new("branch".to_string(), 6, 4),
+        FixedWidthField::new("balance".to_string(), 10, 8),
+    ]
+}
+
+fn accounts_table(path: String, read_mode: ReadMode) -> FixedWidthTable {
+    let mut account_col = StringColumnBuilder::new("account".to_string());
+    account_col
+        .is_not_null(0.0)
+        .is_unique(NormalizeMode::None, 0.0);
+    let mut balance_col = NumericColumnBuilder::<f64>::new("balance".to_string());
+    balance_col.is_not_null(0.0).is_positive(0.0);
+
+    let mut table = FixedWidthTable::new(path, "accounts".to_string(), fields())
+        .unwrap()
+        .with_strict_schema(true)
+        .with_read_mode(read_mode);
+    table
+        .prepare(vec![Box::new(account_col), Box::new(balance_col)], vec![])
+        .unwrap();
+    table
+}
+
+#[test]
+fn test_fixed_width_table_validation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("accounts.fwf");
+    let lines = [
+        "A00001PAR1  1200.50",
+        "A00002LYO1    -3.00",
+        "A00002PAR2    45.10",
+        "A00004LYO2",
+        "      PAR1     9.99",
+    ];
+    fs::write(&file_path, lines.join("\n")).unwrap();
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    for read_mode in [
+        ReadMode::Sequential,
+        ReadMode::Parallel,
+        ReadMode::Streaming,
+    ] {
+        let res = accounts_table(file_path.clone(), read_mode)
+            .validate()
+            .unwrap();
+        assert_eq!(res.total_rows, 5);
+        assert!(res.get_missing_columns().is_empty());
+        // Every positioned field is a file column
+        assert_eq!(res.get_unexpected_columns(), ["branch"]);
+
+        let columns = res.get_column_results();
+        let error_count = |column: &str, rule: &str| {
+            columns[column]
+                .iter()
+                .find(|r| r.rule_name == rule)
+                .unwrap()
+                .error_count
+        };
+        assert_eq!(error_count("account", "NullCheck"), 1);
+        assert_eq!(error_count("account", "Unicity"), 1);
+        // The short line has no balance
+        assert_eq!(error_count("balance", "NullCheck"), 1);
+        assert_eq!(error_count("balance", "IsPositive"), 1);
+    }
+}
+
+#[test]
+fn test_fixed_width_table_invalid_fields() {
+    let mut fields = fields();
+    fields.push(FixedWidthField::new("account".to_string(), 18, 2));
+    let result = FixedWidthTable::new("accounts.fwf".to_string(), "accounts".to_string(), fields);
+    assert!(matches!(result, Err(RuleError::ValidationError(_))));
+
+    let fields = vec![FixedWidthField::new("account".to_string(), 0, 0)];
+    let result = FixedWidthTable::new("accounts.fwf".to_string(), "accounts".to_string(), fields);
+    assert!(matches!(result, Err(RuleError::ValidationError(_))));
+}
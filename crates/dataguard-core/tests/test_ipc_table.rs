@@ -0,0 +1,117 @@
+use std::fs::File;
+use std::sync::Arc;
+
+use arrow::array::{Float32Array, Int32Array, LargeStringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::ipc::writer::FileWriter;
+use arrow::record_batch::RecordBatch;
+use dataguard_core::rules::NormalizeMode;
+use dataguard_core::{IpcTable, NumericColumnBuilder, ReadMode, StringColumnBuilder, Table};
+use tempfile::tempdir;
+
+fn write_orders(path: &std::path::Path) {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("order_id", DataType::LargeUtf8, true),
+        Field::new("quantity", DataType::Int32, false),
+        Field::new("price", DataType::Float32, false),
+    ]));
+    let first = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(LargeStringArray::from(vec![Some("A-1"), Some("A-2")])),
+            Arc::new(Int32Array::from(vec![1, 0])),
+            Arc::new(Float32Array::from(vec![9.5, 3.0])),
+        ],
+    )
+    .unwrap();
+    let second = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(LargeStringArray::from(vec![None, Some("A-2")])),
+            Arc::new(Int32Array::from(vec![3, 12])),
+            Arc::new(Float32Array::from(vec![-1.0, 4.25])),
+        ],
+    )
+    .unwrap();
+    let mut writer = FileWriter::try_new(File::create(path).unwrap(), &schema).unwrap();
+    writer.write(&first).unwrap();
+    writer.write(&second).unwrap();
+    writer.finish().unwrap();
+}
+
+fn orders_table(path: String, read_mode: ReadMode) -> IpcTable {
+    let mut order_col = StringColumnBuilder::new("order_id".to_string());
+    order_col
+        .is_not_null(0.0)
+        .is_unique(NormalizeMode::None, 0.0);
+    let mut quantity_col = NumericColumnBuilder::<i64>::new("quantity".to_string());
+    quantity_col.between(1, 10, 0.0);
+    let mut price_col = NumericColumnBuilder::<f64>::new("price".to_string());
+    price_col.is_positive(0.0);
+
+    let mut table = IpcTable::new(path, "orders".to_string())
+        .unwrap()
+        .with_strict_schema(true)
+        .with_read_mode(read_mode);
+    table
+        .prepare(
+            vec![
+                Box::new(order_col),
+                Box::new(quantity_col),
+                Box::new(price_col),
+            ],
+            vec![],
+        )
+        .unwrap();
+    table
+}
+
+#[test]
+fn test_ipc_table_validation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.feather");
+    write_orders(&file_path);
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    for read_mode in [ReadMode::Sequential, ReadMode::Streaming] {
+        let res = orders_table(file_path.clone(), read_mode)
+            .validate()
+            .unwrap();
+        assert_eq!(res.total_rows, 4);
+        assert_eq!(res.read_mode, Some(read_mode));
+        assert!(res.get_missing_columns().is_empty());
+        assert!(res.get_unexpected_columns().is_empty());
+
+        // Large strings, `Int32` and `Float32` columns are widened, the rules run on them
+        let columns = res.get_column_results();
+        let error_count = |column: &str, rule: &str| {
+            columns[column]
+                .iter()
+                .find(|r| r.rule_name == rule)
+                .unwrap()
+                .error_count
+        };
+        assert_eq!(error_count("order_id", "NullCheck"), 1);
+        assert_eq!(error_count("order_id", "Unicity"), 1);
+        assert_eq!(error_count("quantity", "Between"), 2);
+        assert_eq!(error_count("price", "IsPositive"), 1);
+    }
+}
+
+#[test]
+fn test_ipc_table_row_limit() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.arrow");
+    write_orders(&file_path);
+
+    let quantity_col = NumericColumnBuilder::<i64>::new("quantity".to_string());
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut table = IpcTable::new(file_path, "orders".to_string())
+        .unwrap()
+        .with_row_limit(3);
+    table.prepare(vec![Box::new(quantity_col)], vec![]).unwrap();
+
+    let res = table.validate().unwrap();
+    assert_eq!(res.total_rows, 3);
+    assert!(res.sampled);
+}
@@ -0,0 +1,69 @@
+use dataguard_core::{
+    CsvTable, JsonTable, NumericColumnBuilder, RuleError, StringColumnBuilder, Table,
+};
+use std::fs::File;
+use std::io::Write;
+use tempfile::tempdir;
+
+#[test]
+fn test_json_table_validation() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("events.ndjson");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, r#"{{"user": "alice", "age": 25}}"#).unwrap(); // ok
+    writeln!(file, r#"{{"user": "bob", "age": 150}}"#).unwrap(); // age fail (>120)
+    writeln!(file, r#"{{"user": "al", "age": "unknown"}}"#).unwrap(); // user length fail, age type fail
+    writeln!(file, r#"{{"user": null, "age": 40, "extra": [1, 2]}}"#).unwrap(); // user null
+
+    let mut user_col = StringColumnBuilder::new("user".to_string());
+    user_col.with_min_length(3, false, 0.0).is_not_null(0.0);
+    let mut age_col = NumericColumnBuilder::<i64>::new("age".to_string());
+    age_col.between(0, 120, 0.0);
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut table = JsonTable::new(file_path, "events".to_string()).unwrap();
+    table
+        .prepare(vec![Box::new(user_col), Box::new(age_col)], vec![])
+        .unwrap();
+
+    let res = table.validate().unwrap();
+    assert_eq!(res.total_rows, 4);
+
+    let columns = res.get_column_results();
+    let error_count = |column: &str, rule: &str| {
+        columns[column]
+            .iter()
+            .find(|r| r.rule_name == rule)
+            .unwrap()
+            .error_count
+    };
+    assert_eq!(error_count("user", "WithMinLength"), 1);
+    assert_eq!(error_count("user", "NullCheck"), 1);
+    assert_eq!(error_count("age", "TypeCheck"), 1);
+    assert_eq!(error_count("age", "Between"), 1);
+}
+
+#[test]
+fn test_json_file_declared_as_csv_is_detected() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("events.csv");
+    let mut file = File::create(&file_path).unwrap();
+    writeln!(file, r#"{{"user": "alice"}}"#).unwrap();
+
+    let file_path = file_path.into_os_string().into_string().unwrap();
+    let mut table = CsvTable::new(file_path, "events".to_string()).unwrap();
+    table
+        .prepare(
+            vec![Box::new(StringColumnBuilder::new("user".to_string()))],
+            vec![],
+        )
+        .unwrap();
+
+    match table.validate() {
+        Err(RuleError::FormatMismatch(_, declared, detected)) => {
+            assert_eq!(declared, "CSV");
+            assert_eq!(detected, "JSON");
+        }
+        _ => panic!("Expected FormatMismatch error"),
+    }
+}
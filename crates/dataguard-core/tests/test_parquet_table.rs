@@ -1,5 +1,17 @@
-use dataguard_core::{NumericColumnBuilder, ParquetTable, StringColumnBuilder, Table};
+use arrow::array::{Float32Array, Int32Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+use dataguard_core::rules::NormalizeMode;
+use dataguard_core::{
+    columns::ColumnBuilder, CsvTable, NumericColumnBuilder, ParquetTable, ReadMode, RuleError,
+    StringColumnBuilder, Table,
+};
+use parquet::arrow::ArrowWriter;
+use parquet::file::properties::WriterProperties;
+use std::fs::File;
 use std::path::PathBuf;
+use std::sync::Arc;
+use tempfile::tempdir;
 
 fn get_test_file_path() -> PathBuf {
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -86,8 +98,8 @@ fn test_table_get_rules() {
 fn test_table_multiple_rules_per_column() {
     let file_path = get_test_file_path().to_str().unwrap().to_string();
     let mut name = StringColumnBuilder::new("name".to_string());
-    name.with_min_length(3, 0.0)
-        .with_max_length(20, 0.0)
+    name.with_min_length(3, false, 0.0)
+        .with_max_length(20, false, 0.0)
         .is_alpha(0.0)
         .unwrap();
 
@@ -114,3 +126,336 @@ fn test_table_mixed_column_types() {
     let res = parquet_table.validate();
     assert!(res.is_ok())
 }
+
+#[test]
+fn test_parquet_renamed_to_csv_is_detected() {
+    let dir = tempdir().unwrap();
+    let renamed = dir.path().join("orders.csv");
+    std::fs::copy(get_test_file_path(), &renamed).unwrap();
+
+    let mut name = StringColumnBuilder::new(String::from("name"));
+    name.is_not_null(0.);
+    let mut csv_table =
+        CsvTable::new(renamed.to_str().unwrap().to_string(), "stdout".to_string()).unwrap();
+    csv_table.prepare(vec![Box::new(name)], vec![]).unwrap();
+
+    match csv_table.validate() {
+        Err(RuleError::FormatMismatch(path, declared, detected)) => {
+            assert!(path.ends_with("orders.csv"));
+            assert_eq!(declared, "CSV");
+            assert_eq!(detected, "Parquet");
+        }
+        _ => panic!("Expected FormatMismatch error"),
+    }
+}
+
+#[test]
+fn test_parquet_declared_types() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.parquet");
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("quantity", DataType::Int32, false),
+        Field::new("price", DataType::Float32, false),
+        Field::new("order_id", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 0, 12])),
+            Arc::new(Float32Array::from(vec![9.5, -1.0, 4.25])),
+            Arc::new(StringArray::from(vec!["A-1", "A-2", "A-3"])),
+        ],
+    )
+    .unwrap();
+    let mut writer = ArrowWriter::try_new(File::create(&file_path).unwrap(), schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let mut quantity = NumericColumnBuilder::<i64>::new("quantity".to_string());
+    quantity.between(1, 10, 0.0);
+    let mut price = NumericColumnBuilder::<f64>::new("price".to_string());
+    price.is_positive(0.0);
+    // A string column configured as an integer
+    let mut order_id = NumericColumnBuilder::<i64>::new("order_id".to_string());
+    order_id.min(0, 0.0);
+    let mut parquet_table = ParquetTable::new(
+        file_path.into_os_string().into_string().unwrap(),
+        "orders".to_string(),
+    )
+    .unwrap();
+    parquet_table
+        .prepare(
+            vec![Box::new(quantity), Box::new(price), Box::new(order_id)],
+            vec![],
+        )
+        .unwrap();
+
+    let res = parquet_table.validate().unwrap();
+    let columns = res.get_column_results();
+    // Narrower native types are widened, the rules run on them
+    assert_eq!(columns["quantity"][0].rule_name, "Between");
+    assert_eq!(columns["quantity"][0].error_count, 2);
+    assert_eq!(columns["price"][0].rule_name, "IsPositive");
+    assert_eq!(columns["price"][0].error_count, 1);
+
+    // The declared type does not match, the column rules are not run
+    let type_check = columns["order_id"][0];
+    assert_eq!(columns["order_id"].len(), 1);
+    assert_eq!(type_check.rule_name, "TypeCheck");
+    assert_eq!(type_check.error_count, 3);
+    assert!(!type_check.pass);
+    assert_eq!(
+        type_check.error_message.as_deref(),
+        Some("Parquet column is Utf8, expected Int64")
+    );
+}
+
+#[test]
+fn test_parquet_source_name() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.parquet");
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("qty", DataType::Int32, false),
+        Field::new("order_id", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 0, 12])),
+            Arc::new(StringArray::from(vec!["A-1", "A-2", "A-3"])),
+        ],
+    )
+    .unwrap();
+    let mut writer = ArrowWriter::try_new(File::create(&file_path).unwrap(), schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    for read_mode in [
+        ReadMode::Sequential,
+        ReadMode::Parallel,
+        ReadMode::Streaming,
+    ] {
+        let mut quantity = NumericColumnBuilder::<i64>::new("quantity".to_string())
+            .with_source_name(Some("qty".to_string()));
+        quantity.between(1, 10, 0.0);
+        let mut table = ParquetTable::new(file_path.clone(), "orders".to_string())
+            .unwrap()
+            .with_strict_schema(true)
+            .with_read_mode(read_mode);
+        table.prepare(vec![Box::new(quantity)], vec![]).unwrap();
+
+        let res = table.validate().unwrap();
+        // The file column is reported under the configured name
+        assert_eq!(res.get_column_results()["quantity"][0].error_count, 2);
+        assert!(res.get_missing_columns().is_empty());
+        assert_eq!(res.get_unexpected_columns(), ["order_id"]);
+        assert_eq!(table.read_column("quantity").unwrap()[0].len(), 3);
+    }
+}
+
+#[test]
+fn test_parquet_source_name_conflicts() {
+    let file_path = get_test_file_path().to_str().unwrap().to_string();
+    let column = |name: &str, source: Option<&str>| -> Box<dyn ColumnBuilder> {
+        Box::new(
+            NumericColumnBuilder::<i64>::new(name.to_string())
+                .with_source_name(source.map(str::to_string)),
+        )
+    };
+    let prepare = |columns| {
+        ParquetTable::new(file_path.clone(), "orders".to_string())
+            .unwrap()
+            .prepare(columns, vec![])
+    };
+
+    // Two columns reading the same source name
+    let err = prepare(vec![
+        column("quantity", Some("qty")),
+        column("amount", Some("qty")),
+    ])
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Columns 'amount' and 'quantity' both read the file column 'qty'"));
+
+    // A source name shadowing another configured column
+    let err = prepare(vec![column("quantity", Some("qty")), column("qty", None)]).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Columns 'qty' and 'quantity' both read the file column 'qty'"));
+
+    // Swapped names each read a single file column
+    assert!(prepare(vec![
+        column("quantity", Some("qty")),
+        column("qty", Some("quantity")),
+    ])
+    .is_ok());
+}
+
+#[test]
+fn test_parquet_dry_run() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.parquet");
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("quantity", DataType::Int32, false),
+        Field::new("order_id", DataType::Utf8, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 0, 12])),
+            Arc::new(StringArray::from(vec!["A-1", "A-2", "A-3"])),
+        ],
+    )
+    .unwrap();
+    let mut writer = ArrowWriter::try_new(File::create(&file_path).unwrap(), schema, None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let mut quantity = NumericColumnBuilder::<i64>::new("quantity".to_string());
+    quantity.between(1, 10, 0.0);
+    let mut order_id = NumericColumnBuilder::<i64>::new("order_id".to_string());
+    order_id.min(0, 0.0);
+    let mut status = StringColumnBuilder::new("status".to_string());
+    status.is_not_null(0.0);
+    let mut table = ParquetTable::new(
+        file_path.into_os_string().into_string().unwrap(),
+        "orders".to_string(),
+    )
+    .unwrap();
+    table
+        .prepare(
+            vec![Box::new(quantity), Box::new(order_id), Box::new(status)],
+            vec![],
+        )
+        .unwrap();
+
+    let res = table.dry_run().unwrap();
+    // Only the footer is read, the rules are not run
+    assert_eq!(res.total_rows, 0);
+    assert!(!res.get_column_results().contains_key("quantity"));
+    assert_eq!(res.get_missing_columns(), ["status"]);
+    let type_check = res.get_column_results()["order_id"][0];
+    assert_eq!(type_check.rule_name, "TypeCheck");
+    assert!(!type_check.pass);
+    assert_eq!(
+        type_check.error_message.as_deref(),
+        Some("File column is Utf8, expected Int64")
+    );
+}
+
+#[test]
+fn test_parquet_streaming_matches_batch_mode() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.parquet");
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("quantity", DataType::Int32, false),
+        Field::new("order_id", DataType::Utf8, false),
+    ]));
+    // Small row groups, duplicates span several of them
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(4)
+        .build();
+    let mut writer = ArrowWriter::try_new(
+        File::create(&file_path).unwrap(),
+        schema.clone(),
+        Some(props),
+    )
+    .unwrap();
+    for offset in 0..5 {
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from_iter_values(
+                    (0..6).map(|i| (offset * 6 + i) % 20),
+                )),
+                Arc::new(StringArray::from_iter_values(
+                    (0..6).map(|i| format!("A-{}", (offset * 6 + i) % 25)),
+                )),
+            ],
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+    }
+    writer.close().unwrap();
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    let validate = |read_mode| {
+        let mut quantity = NumericColumnBuilder::<i64>::new("quantity".to_string());
+        quantity.is_unique(0.0).between(1, 15, 0.0);
+        let mut order_id = StringColumnBuilder::new("order_id".to_string());
+        order_id.is_unique(NormalizeMode::None, 0.0);
+        let mut table = ParquetTable::new(file_path.clone(), "orders".to_string())
+            .unwrap()
+            .with_read_mode(read_mode);
+        table
+            .prepare(vec![Box::new(quantity), Box::new(order_id)], vec![])
+            .unwrap();
+        table.validate().unwrap()
+    };
+
+    for read_mode in [
+        ReadMode::Sequential,
+        ReadMode::Parallel,
+        ReadMode::Streaming,
+    ] {
+        let res = validate(read_mode);
+        assert_eq!(res.read_mode, Some(read_mode));
+        assert_eq!(res.total_rows, 30);
+        let columns = res.get_column_results();
+        let error_count = |column: &str, rule: &str| {
+            columns[column]
+                .iter()
+                .find(|r| r.rule_name == rule)
+                .unwrap()
+                .error_count
+        };
+        assert_eq!(error_count("quantity", "Unicity"), 10, "{}", read_mode);
+        assert_eq!(error_count("quantity", "Between"), 6, "{}", read_mode);
+        assert_eq!(error_count("order_id", "Unicity"), 5, "{}", read_mode);
+    }
+}
+
+#[test]
+fn test_parquet_percentile_below() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("orders.parquet");
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "quantity",
+        DataType::Int32,
+        false,
+    )]));
+    let mut writer =
+        ArrowWriter::try_new(File::create(&file_path).unwrap(), schema.clone(), None).unwrap();
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(Int32Array::from_iter_values(1..=20))],
+    )
+    .unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    let file_path = file_path.into_os_string().into_string().unwrap();
+
+    for read_mode in [
+        ReadMode::Sequential,
+        ReadMode::Parallel,
+        ReadMode::Streaming,
+    ] {
+        let mut quantity = NumericColumnBuilder::<i64>::new("quantity".to_string());
+        quantity.percentile_below(0.5, 5.0, 0.0).unwrap();
+        let mut table = ParquetTable::new(file_path.clone(), "orders".to_string())
+            .unwrap()
+            .with_read_mode(read_mode);
+        table.prepare(vec![Box::new(quantity)], vec![]).unwrap();
+
+        // The median is above the bound, every value above 5 is reported
+        let res = table.validate().unwrap();
+        let columns = res.get_column_results();
+        let percentile = columns["quantity"]
+            .iter()
+            .find(|r| r.rule_name == "PercentileBelow")
+            .unwrap();
+        assert_eq!(percentile.error_count, 15, "{}", read_mode);
+    }
+}
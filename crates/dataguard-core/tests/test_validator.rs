@@ -1,6 +1,10 @@
-use dataguard_core::{CsvTable, NumericColumnBuilder, StringColumnBuilder, Table, Validator};
+use dataguard_core::{
+    readers::ReaderConfigBuilder, CsvTable, ForeignKey, NumericColumnBuilder, Progress, ReadMode,
+    StringColumnBuilder, Table, ValidationResult, Validator,
+};
 use std::fs::File;
 use std::io::Write;
+use std::sync::Arc;
 use tempfile::tempdir;
 
 #[test]
@@ -54,7 +58,7 @@ fn test_validator_validate_existing_table() {
     writeln!(file, "bob,30").unwrap();
 
     let mut name_col = StringColumnBuilder::new("name".to_string());
-    name_col.with_min_length(3, 0.0);
+    name_col.with_min_length(3, false, 0.0);
 
     let mut age_col = NumericColumnBuilder::<i64>::new("age".to_string());
     age_col.is_positive(0.0);
@@ -90,3 +94,248 @@ fn test_validator_replace_table() {
     // The second table should have replaced the first
     // This is implicitly tested by the HashMap behavior
 }
+
+fn validate_orders_and_customers(order_ids: &[&str]) -> Vec<ValidationResult> {
+    let dir = tempdir().unwrap();
+    let customers_path = dir.path().join("customers.csv");
+    let mut file = File::create(&customers_path).unwrap();
+    writeln!(file, "id,name").unwrap();
+    writeln!(file, "1,alice").unwrap();
+    writeln!(file, "2,bob").unwrap();
+    writeln!(file, "3,carol").unwrap();
+
+    let orders_path = dir.path().join("orders.csv");
+    let mut file = File::create(&orders_path).unwrap();
+    writeln!(file, "order_id,customer_id").unwrap();
+    for (i, customer_id) in order_ids.iter().enumerate() {
+        writeln!(file, "{},{}", i, customer_id).unwrap();
+    }
+
+    let mut validator = Validator::new();
+    for (name, path, column) in [
+        ("customers", customers_path, "id"),
+        ("orders", orders_path, "customer_id"),
+    ] {
+        let mut col = NumericColumnBuilder::<i64>::new(column.to_string());
+        col.is_positive(0.0);
+        let mut table = CsvTable::new(
+            path.into_os_string().into_string().unwrap(),
+            name.to_string(),
+        )
+        .unwrap();
+        table.prepare(vec![Box::new(col)], vec![]).unwrap();
+        validator.add_table(name.to_string(), Box::new(table));
+    }
+    validator.add_foreign_key(ForeignKey::new(
+        "orders".to_string(),
+        "customer_id".to_string(),
+        "customers".to_string(),
+        "id".to_string(),
+        0.0,
+    ));
+    validator.validate_all().unwrap()
+}
+
+#[test]
+fn test_validator_foreign_key_without_orphans() {
+    let results = validate_orders_and_customers(&["1", "2", "2", "3", ""]);
+
+    let orders = results.iter().find(|r| r.table_name == "orders").unwrap();
+    let fks = orders.get_foreign_key_results();
+    assert_eq!(fks.len(), 1);
+    assert_eq!(fks[0].name, "orders.customer_id -> customers.id");
+    assert_eq!(fks[0].result.error_count, 0);
+    assert!(fks[0].result.pass);
+    assert!(fks[0].orphans.is_empty());
+
+    let customers = results
+        .iter()
+        .find(|r| r.table_name == "customers")
+        .unwrap();
+    assert!(customers.get_foreign_key_results().is_empty());
+}
+
+#[test]
+fn test_validator_foreign_key_with_orphans() {
+    let results = validate_orders_and_customers(&["1", "4", "2", "4", "9"]);
+
+    let orders = results.iter().find(|r| r.table_name == "orders").unwrap();
+    let fk = &orders.get_foreign_key_results()[0];
+    assert_eq!(fk.result.error_count, 3);
+    assert_eq!(fk.result.error_percentage, 60.0);
+    assert!(!fk.result.pass);
+    assert_eq!(fk.orphans, vec!["4".to_string(), "9".to_string()]);
+
+    let (passed, total) = orders.is_passed();
+    assert!(passed < total);
+}
+
+#[test]
+fn test_validator_foreign_key_multi_batch() {
+    let dir = tempdir().unwrap();
+    let customers_path = dir.path().join("customers.csv");
+    let mut file = File::create(&customers_path).unwrap();
+    writeln!(file, "id,name").unwrap();
+    for i in 0..40 {
+        writeln!(file, "{},name", i).unwrap();
+    }
+    let orders_path = dir.path().join("orders.csv");
+    let mut file = File::create(&orders_path).unwrap();
+    writeln!(file, "order_id,customer_id").unwrap();
+    for i in 0..100 {
+        // Orphans 70 and 50 first appear at rows 3 and 20, then repeat
+        let customer_id = match i % 7 {
+            3 => 70,
+            _ if i % 10 == 0 && i > 0 => 50,
+            _ => i % 40,
+        };
+        writeln!(file, "{},{}", i, customer_id).unwrap();
+    }
+    let path = |path: &std::path::Path| path.to_str().unwrap().to_string();
+
+    let validate = |read_mode, row_limit: Option<usize>| {
+        let reader_config = || ReaderConfigBuilder::new().with_batch_size(6).build();
+        let mut validator = Validator::new();
+        // The parent key column has no rule, it is only read for the foreign key
+        let mut name_col = StringColumnBuilder::new("name".to_string());
+        name_col.with_min_length(1, false, 0.0);
+        let mut customers = CsvTable::new(path(&customers_path), "customers".to_string())
+            .unwrap()
+            .with_reader_config(reader_config())
+            .with_read_mode(read_mode);
+        customers.prepare(vec![Box::new(name_col)], vec![]).unwrap();
+        validator.add_table("customers".to_string(), Box::new(customers));
+
+        let mut id_col = NumericColumnBuilder::<i64>::new("customer_id".to_string());
+        id_col.is_positive(0.0);
+        let mut orders = CsvTable::new(path(&orders_path), "orders".to_string())
+            .unwrap()
+            .with_reader_config(reader_config())
+            .with_read_mode(read_mode);
+        if let Some(limit) = row_limit {
+            orders = orders.with_row_limit(limit);
+        }
+        orders.prepare(vec![Box::new(id_col)], vec![]).unwrap();
+        validator.add_table("orders".to_string(), Box::new(orders));
+
+        validator.add_foreign_key(ForeignKey::new(
+            "orders".to_string(),
+            "customer_id".to_string(),
+            "customers".to_string(),
+            "id".to_string(),
+            0.0,
+        ));
+        let results = validator.validate_all().unwrap();
+        let orders = results.iter().find(|r| r.table_name == "orders").unwrap();
+        orders.get_foreign_key_results()[0].clone()
+    };
+
+    // 14 rows hold 70, 7 rows hold 50, whatever the batch reading the rows
+    for read_mode in [
+        ReadMode::Parallel,
+        ReadMode::Sequential,
+        ReadMode::Streaming,
+    ] {
+        let fk = validate(read_mode, None);
+        assert_eq!(fk.result.error_count, 21, "{}", read_mode);
+        assert_eq!(fk.orphans, vec!["70".to_string(), "50".to_string()]);
+    }
+    // A sampled child is checked on its whole column, read again
+    let fk = validate(ReadMode::Auto, Some(5));
+    assert_eq!(fk.result.error_count, 21);
+}
+
+#[test]
+fn test_validator_foreign_key_unknown_table() {
+    let mut validator = Validator::new();
+    validator.add_foreign_key(ForeignKey::new(
+        "orders".to_string(),
+        "customer_id".to_string(),
+        "customers".to_string(),
+        "id".to_string(),
+        0.0,
+    ));
+    let result = validator.validate_all();
+    assert!(result.is_err());
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Table 'customers' not found in Validator"
+    );
+}
+
+#[test]
+fn test_validator_continue_on_error() {
+    let dir = tempdir().unwrap();
+    let users_path = dir.path().join("users.csv");
+    let mut file = File::create(&users_path).unwrap();
+    writeln!(file, "id").unwrap();
+    writeln!(file, "1").unwrap();
+
+    let tables = [
+        ("users", users_path.into_os_string().into_string().unwrap()),
+        ("orders", "missing/orders.csv".to_string()),
+    ];
+    let build = |continue_on_error: bool| {
+        let mut validator = Validator::new().with_continue_on_error(continue_on_error);
+        for (name, path) in &tables {
+            let mut col = NumericColumnBuilder::<i64>::new("id".to_string());
+            col.is_positive(0.0);
+            let mut table = CsvTable::new(path.clone(), name.to_string()).unwrap();
+            table.prepare(vec![Box::new(col)], vec![]).unwrap();
+            validator.add_table(name.to_string(), Box::new(table));
+        }
+        validator.add_foreign_key(ForeignKey::new(
+            "orders".to_string(),
+            "id".to_string(),
+            "users".to_string(),
+            "id".to_string(),
+            0.0,
+        ));
+        validator
+    };
+
+    // Fail fast by default
+    assert!(build(false).validate_all().is_err());
+
+    let results = build(true).validate_all().unwrap();
+    assert_eq!(results.len(), 2);
+    let users = results.iter().find(|r| r.table_name == "users").unwrap();
+    let (passed, total) = users.is_passed();
+    assert_eq!(passed, total);
+    let orders = results.iter().find(|r| r.table_name == "orders").unwrap();
+    assert!(orders.error_message.is_some());
+    assert!(orders.get_foreign_key_results().is_empty());
+    assert_eq!(orders.is_passed(), (0, 1));
+}
+
+#[test]
+fn test_validator_progress() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("users.csv");
+    let mut file = File::create(&path).unwrap();
+    writeln!(file, "id").unwrap();
+    for i in 1..=5 {
+        writeln!(file, "{}", i).unwrap();
+    }
+    let path = path.into_os_string().into_string().unwrap();
+
+    for (read_mode, total_rows) in [(ReadMode::Parallel, Some(5)), (ReadMode::Streaming, None)] {
+        let mut col = NumericColumnBuilder::<i64>::new("id".to_string());
+        col.is_positive(0.0);
+        let mut table = CsvTable::new(path.clone(), "users".to_string())
+            .unwrap()
+            .with_read_mode(read_mode);
+        table.prepare(vec![Box::new(col)], vec![]).unwrap();
+
+        let progress = Arc::new(Progress::new());
+        let mut validator = Validator::new().with_progress(progress.clone());
+        validator.add_table("users".to_string(), Box::new(table));
+        validator.validate_all().unwrap();
+
+        // The counts of the last table are kept once validation is over
+        assert_eq!(progress.table(), None);
+        assert_eq!(progress.rows(), 5);
+        // Streamed tables are validated before their row count is known
+        assert_eq!(progress.total_rows(), total_rows);
+    }
+}
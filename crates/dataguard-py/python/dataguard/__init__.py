@@ -18,19 +18,21 @@ Example usage:
     >>>
     >>> # Validate
     >>> result = table.validate()
-    >>> print(f"Validated {result['total_rows']} rows")
-    >>> print(f"Passed {result['passed'][0]}/{result['passed'][1]} rules")
+    >>> print(f"Validated {result.total_rows} rows")
+    >>> print(f"Passed {result.passed[0]}/{result.passed[1]} rules")
 """
 
 from .dataguard import (
     # Table types
     CsvTable,
     ParquetTable,
+    ArrowTable,
     # Column builder functions
     string_column,
     integer_column,
     float_column,
     date_column,
+    timestamp_column,
     # Relation builder function
     relation,
     # Builder classes (for type hints)
@@ -38,18 +40,24 @@ from .dataguard import (
     IntegerColumnBuilder,
     FloatColumnBuilder,
     DateColumnBuilder,
+    TimestampColumnBuilder,
     RelationBuilder,
+    # Result classes
+    ValidationResult,
+    RuleResult,
 )
 
 __all__ = [
     # Tables
     "CsvTable",
     "ParquetTable",
+    "ArrowTable",
     # Column builder functions
     "string_column",
     "integer_column",
     "float_column",
     "date_column",
+    "timestamp_column",
     # Relation builder
     "relation",
     # Builder classes
@@ -57,7 +65,11 @@ __all__ = [
     "IntegerColumnBuilder",
     "FloatColumnBuilder",
     "DateColumnBuilder",
+    "TimestampColumnBuilder",
     "RelationBuilder",
+    # Results
+    "ValidationResult",
+    "RuleResult",
 ]
 
 __version__ = "0.1.0"
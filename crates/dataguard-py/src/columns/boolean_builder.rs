@@ -0,0 +1,237 @@
+use dataguard_core::{errors::RuleError, BooleanColumnBuilder as CoreBooleanColumnBuilder};
+use pyo3::prelude::*;
+
+/// Python wrapper for BooleanColumnBuilder from dataguard-core.
+///
+/// A builder for defining validation rules on boolean columns.
+#[pyclass(name = "BooleanColumnBuilder")]
+#[derive(Clone)]
+pub struct BooleanColumnBuilder {
+    inner: CoreBooleanColumnBuilder,
+}
+
+impl BooleanColumnBuilder {
+    /// Convert this Python builder to a core ColumnBuilder trait object
+    pub fn to_core_column_builder(
+        &self,
+    ) -> Result<Box<dyn dataguard_core::columns::ColumnBuilder>, RuleError> {
+        Ok(Box::new(self.inner.clone()))
+    }
+}
+
+#[pymethods]
+impl BooleanColumnBuilder {
+    /// Set the type checking threshold.
+    ///
+    /// Args:
+    ///     threshold (float): The threshold for type checking (0.0 to 1.0).
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    pub fn with_type_threshold(&mut self, threshold: f64) -> Self {
+        self.inner = self.inner.clone().with_type_threshold(threshold);
+        self.clone()
+    }
+
+    /// Skip the column when it is missing from the file, instead of failing the table.
+    ///
+    /// Args:
+    ///     optional (bool): Whether the column may be missing (default: True).
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (optional=true))]
+    pub fn with_optional(&mut self, optional: bool) -> Self {
+        self.inner = self.inner.clone().with_optional(optional);
+        self.clone()
+    }
+
+    /// Read the column from a file column named differently, results keep the column name.
+    ///
+    /// Args:
+    ///     source_name (str): Name of the column in the file.
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    pub fn with_source_name(&mut self, source_name: String) -> Self {
+        self.inner = self.inner.clone().with_source_name(Some(source_name));
+        self.clone()
+    }
+
+    /// Label the last added rule, its results carry this human readable name.
+    ///
+    /// Args:
+    ///     label (str): Name reported for the rule, e.g. "Customer name too short".
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If no rule was added yet.
+    pub fn with_label(&mut self, label: String) -> PyResult<Self> {
+        self.inner
+            .with_label(label)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Replace the tokens parsed as true and false, compared case-insensitively.
+    ///
+    /// Args:
+    ///     true_tokens (list[str]): Tokens parsed as true.
+    ///     false_tokens (list[str]): Tokens parsed as false.
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If a list is empty or a token appears in both.
+    pub fn with_tokens(
+        &mut self,
+        true_tokens: Vec<String>,
+        false_tokens: Vec<String>,
+    ) -> PyResult<Self> {
+        self.inner = self
+            .inner
+            .clone()
+            .with_tokens(true_tokens, false_tokens)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Add a not-null constraint.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of null values allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_not_null(&mut self, threshold: f64) -> Self {
+        self.inner.is_not_null(threshold);
+        self.clone()
+    }
+
+    /// Fail when every value of the column is null.
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    pub fn not_all_null(&mut self) -> Self {
+        self.inner.not_all_null();
+        self.clone()
+    }
+
+    /// Fail when the column holds less than two distinct values.
+    ///
+    /// Distinct values are estimated with the hashes used by the uniqueness check.
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    pub fn not_all_same(&mut self) -> Self {
+        self.inner.not_all_same();
+        self.clone()
+    }
+
+    /// Fail when the share of null values is outside min_ratio and max_ratio.
+    ///
+    /// Catches both too many nulls and suspiciously none, the observed ratio is reported.
+    ///
+    /// Args:
+    ///     min_ratio (float): Minimum share of null values (0.0 to 1.0).
+    ///     max_ratio (float): Maximum share of null values (0.0 to 1.0).
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the bounds are outside [0, 1] or min_ratio > max_ratio.
+    pub fn null_ratio_between(&mut self, min_ratio: f64, max_ratio: f64) -> PyResult<Self> {
+        self.inner
+            .null_ratio_between(min_ratio, max_ratio)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Fail when the estimated number of distinct values is outside min and max.
+    ///
+    /// The count is exact up to 1024 distinct values and estimated within about 3% above,
+    /// the estimate is reported.
+    ///
+    /// Args:
+    ///     min (int): Minimum number of distinct values.
+    ///     max (int): Maximum number of distinct values.
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If min > max.
+    pub fn distinct_count_between(&mut self, min: usize, max: usize) -> PyResult<Self> {
+        self.inner
+            .distinct_count_between(min, max)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that all values are true.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_true(&mut self, threshold: f64) -> Self {
+        self.inner.is_true(threshold);
+        self.clone()
+    }
+
+    /// Check that all values are false.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_false(&mut self, threshold: f64) -> Self {
+        self.inner.is_false(threshold);
+        self.clone()
+    }
+
+    /// Check that the share of true values lies between min and max.
+    ///
+    /// Args:
+    ///     min (float): Minimum share of true values (0.0 to 1.0).
+    ///     max (float): Maximum share of true values (0.0 to 1.0).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     BooleanColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the bounds are outside [0, 1] or min > max.
+    #[pyo3(signature = (min, max, threshold=0.0))]
+    pub fn true_ratio_between(&mut self, min: f64, max: f64, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .true_ratio_between(min, max, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+}
+
+/// Creates a builder for defining rules on a boolean column.
+///
+/// By default `true`/`false`, `1`/`0` and `yes`/`no` are recognized.
+///
+/// Args:
+///     name (str): The name of the column.
+///
+/// Returns:
+///     BooleanColumnBuilder: A builder object for chaining rules.
+#[pyfunction]
+pub fn boolean_column(name: String) -> BooleanColumnBuilder {
+    BooleanColumnBuilder {
+        inner: CoreBooleanColumnBuilder::new(name),
+    }
+}
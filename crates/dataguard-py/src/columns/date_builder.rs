@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use dataguard_core::{
     columns::date_builder::DateColumnBuilder as CoreDateColumnBuilder, errors::RuleError,
 };
@@ -35,6 +36,48 @@ impl DateColumnBuilder {
         self.clone()
     }
 
+    /// Skip the column when it is missing from the file, instead of failing the table.
+    ///
+    /// Args:
+    ///     optional (bool): Whether the column may be missing (default: True).
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (optional=true))]
+    pub fn with_optional(&mut self, optional: bool) -> Self {
+        self.inner = self.inner.clone().with_optional(optional);
+        self.clone()
+    }
+
+    /// Read the column from a file column named differently, results keep the column name.
+    ///
+    /// Args:
+    ///     source_name (str): Name of the column in the file.
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    pub fn with_source_name(&mut self, source_name: String) -> Self {
+        self.inner = self.inner.clone().with_source_name(Some(source_name));
+        self.clone()
+    }
+
+    /// Label the last added rule, its results carry this human readable name.
+    ///
+    /// Args:
+    ///     label (str): Name reported for the rule, e.g. "Customer name too short".
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If no rule was added yet.
+    pub fn with_label(&mut self, label: String) -> PyResult<Self> {
+        self.inner
+            .with_label(label)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Get the date format string.
     ///
     /// Returns:
@@ -56,6 +99,67 @@ impl DateColumnBuilder {
         self.clone()
     }
 
+    /// Fail when every value of the column is null.
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    pub fn not_all_null(&mut self) -> Self {
+        self.inner.not_all_null();
+        self.clone()
+    }
+
+    /// Fail when the column holds less than two distinct values.
+    ///
+    /// Distinct values are estimated with the hashes used by the uniqueness check.
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    pub fn not_all_same(&mut self) -> Self {
+        self.inner.not_all_same();
+        self.clone()
+    }
+
+    /// Fail when the share of null values is outside min_ratio and max_ratio.
+    ///
+    /// Catches both too many nulls and suspiciously none, the observed ratio is reported.
+    ///
+    /// Args:
+    ///     min_ratio (float): Minimum share of null values (0.0 to 1.0).
+    ///     max_ratio (float): Maximum share of null values (0.0 to 1.0).
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the bounds are outside [0, 1] or min_ratio > max_ratio.
+    pub fn null_ratio_between(&mut self, min_ratio: f64, max_ratio: f64) -> PyResult<Self> {
+        self.inner
+            .null_ratio_between(min_ratio, max_ratio)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Fail when the estimated number of distinct values is outside min and max.
+    ///
+    /// The count is exact up to 1024 distinct values and estimated within about 3% above,
+    /// the estimate is reported.
+    ///
+    /// Args:
+    ///     min (int): Minimum number of distinct values.
+    ///     max (int): Maximum number of distinct values.
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If min > max.
+    pub fn distinct_count_between(&mut self, min: usize, max: usize) -> PyResult<Self> {
+        self.inner
+            .distinct_count_between(min, max)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Add uniqueness constraint.
     ///
     /// Args:
@@ -69,6 +173,21 @@ impl DateColumnBuilder {
         self.clone()
     }
 
+    /// Add uniqueness constraint within each group of rows sharing the same key.
+    ///
+    /// Args:
+    ///     group_column (str): Column holding the group key, compared as text. Rows with a
+    ///         null key are skipped.
+    ///     threshold (float): Maximum percentage of duplicate values allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (group_column, threshold=0.0))]
+    pub fn is_unique_within(&mut self, group_column: &str, threshold: f64) -> Self {
+        self.inner.is_unique_within(group_column, threshold);
+        self.clone()
+    }
+
     /// Set a limit - the date should be before the given date.
     ///
     /// Args:
@@ -113,29 +232,33 @@ impl DateColumnBuilder {
         self.clone()
     }
 
-    /// Check that all dates are not in the future (before today).
+    /// Check that all dates are not in the future (before the reference date).
+    ///
+    /// Set the reference to the logical date of the dataset for reproducible batch jobs.
     ///
     /// Args:
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///     reference_date (datetime.date | None): Date compared against, today when None.
     ///
     /// Returns:
     ///     DateColumnBuilder: Self for method chaining.
-    #[pyo3(signature = (threshold=0.0))]
-    pub fn is_not_futur(&mut self, threshold: f64) -> Self {
-        self.inner.is_not_futur(threshold);
+    #[pyo3(signature = (threshold=0.0, reference_date=None))]
+    pub fn is_not_futur(&mut self, threshold: f64, reference_date: Option<NaiveDate>) -> Self {
+        self.inner.is_not_futur(reference_date, threshold);
         self.clone()
     }
 
-    /// Check that all dates are not in the past (after today).
+    /// Check that all dates are not in the past (after the reference date).
     ///
     /// Args:
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///     reference_date (datetime.date | None): Date compared against, today when None.
     ///
     /// Returns:
     ///     DateColumnBuilder: Self for method chaining.
-    #[pyo3(signature = (threshold=0.0))]
-    pub fn is_not_past(&mut self, threshold: f64) -> Self {
-        self.inner.is_not_past(threshold);
+    #[pyo3(signature = (threshold=0.0, reference_date=None))]
+    pub fn is_not_past(&mut self, threshold: f64, reference_date: Option<NaiveDate>) -> Self {
+        self.inner.is_not_past(reference_date, threshold);
         self.clone()
     }
 
@@ -164,6 +287,98 @@ impl DateColumnBuilder {
         self.inner.is_weekend(threshold);
         self.clone()
     }
+
+    /// Check that dates fall on the first day of their month.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_month_start(&mut self, threshold: f64) -> Self {
+        self.inner.is_month_start(threshold);
+        self.clone()
+    }
+
+    /// Check that dates fall on the last day of their month.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_month_end(&mut self, threshold: f64) -> Self {
+        self.inner.is_month_end(threshold);
+        self.clone()
+    }
+
+    /// Check that dates fall on a given day of their month.
+    ///
+    /// Args:
+    ///     day (int): The expected day of the month (1-31).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (day, threshold=0.0))]
+    pub fn is_day_of_month(&mut self, day: u32, threshold: f64) -> Self {
+        self.inner.is_day_of_month(day, threshold);
+        self.clone()
+    }
+
+    /// Check that dates fall within a range, both bounds included.
+    ///
+    /// Args:
+    ///     start (tuple[int, int, int]): First allowed date as (year, month, day).
+    ///     end (tuple[int, int, int]): Last allowed date as (year, month, day).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If a bound is not a valid date or start is after end.
+    #[pyo3(signature = (start, end, threshold=0.0))]
+    pub fn between(
+        &mut self,
+        start: (usize, u32, u32),
+        end: (usize, u32, u32),
+        threshold: f64,
+    ) -> PyResult<Self> {
+        self.inner
+            .between(start, end, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that the full years elapsed between each date and a reference date are within a range.
+    ///
+    /// Args:
+    ///     min_years (int): Minimum age in years, inclusive.
+    ///     max_years (int): Maximum age in years, inclusive.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///     reference_date (datetime.date | None): Date ages are computed at, today when None.
+    ///
+    /// Returns:
+    ///     DateColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If min_years is greater than max_years.
+    #[pyo3(signature = (min_years, max_years, threshold=0.0, reference_date=None))]
+    pub fn age_between(
+        &mut self,
+        min_years: u32,
+        max_years: u32,
+        threshold: f64,
+        reference_date: Option<NaiveDate>,
+    ) -> PyResult<Self> {
+        self.inner
+            .age_between(min_years, max_years, reference_date, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
 }
 
 /// Creates a builder for defining rules on a date column.
@@ -33,6 +33,48 @@ impl IntegerColumnBuilder {
         self.clone()
     }
 
+    /// Skip the column when it is missing from the file, instead of failing the table.
+    ///
+    /// Args:
+    ///     optional (bool): Whether the column may be missing (default: True).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (optional=true))]
+    pub fn with_optional(&mut self, optional: bool) -> Self {
+        self.inner = self.inner.clone().with_optional(optional);
+        self.clone()
+    }
+
+    /// Read the column from a file column named differently, results keep the column name.
+    ///
+    /// Args:
+    ///     source_name (str): Name of the column in the file.
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    pub fn with_source_name(&mut self, source_name: String) -> Self {
+        self.inner = self.inner.clone().with_source_name(Some(source_name));
+        self.clone()
+    }
+
+    /// Label the last added rule, its results carry this human readable name.
+    ///
+    /// Args:
+    ///     label (str): Name reported for the rule, e.g. "Customer name too short".
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If no rule was added yet.
+    pub fn with_label(&mut self, label: String) -> PyResult<Self> {
+        self.inner
+            .with_label(label)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Add a not-null constraint.
     ///
     /// Args:
@@ -46,6 +88,67 @@ impl IntegerColumnBuilder {
         self.clone()
     }
 
+    /// Fail when every value of the column is null.
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    pub fn not_all_null(&mut self) -> Self {
+        self.inner.not_all_null();
+        self.clone()
+    }
+
+    /// Fail when the column holds less than two distinct values.
+    ///
+    /// Distinct values are estimated with the hashes used by the uniqueness check.
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    pub fn not_all_same(&mut self) -> Self {
+        self.inner.not_all_same();
+        self.clone()
+    }
+
+    /// Fail when the share of null values is outside min_ratio and max_ratio.
+    ///
+    /// Catches both too many nulls and suspiciously none, the observed ratio is reported.
+    ///
+    /// Args:
+    ///     min_ratio (float): Minimum share of null values (0.0 to 1.0).
+    ///     max_ratio (float): Maximum share of null values (0.0 to 1.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the bounds are outside [0, 1] or min_ratio > max_ratio.
+    pub fn null_ratio_between(&mut self, min_ratio: f64, max_ratio: f64) -> PyResult<Self> {
+        self.inner
+            .null_ratio_between(min_ratio, max_ratio)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Fail when the estimated number of distinct values is outside min and max.
+    ///
+    /// The count is exact up to 1024 distinct values and estimated within about 3% above,
+    /// the estimate is reported.
+    ///
+    /// Args:
+    ///     min (int): Minimum number of distinct values.
+    ///     max (int): Maximum number of distinct values.
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If min > max.
+    pub fn distinct_count_between(&mut self, min: usize, max: usize) -> PyResult<Self> {
+        self.inner
+            .distinct_count_between(min, max)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Add uniqueness constraint.
     ///
     /// Args:
@@ -59,6 +162,21 @@ impl IntegerColumnBuilder {
         self.clone()
     }
 
+    /// Add uniqueness constraint within each group of rows sharing the same key.
+    ///
+    /// Args:
+    ///     group_column (str): Column holding the group key, compared as text. Rows with a
+    ///         null key are skipped.
+    ///     threshold (float): Maximum percentage of duplicate values allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (group_column, threshold=0.0))]
+    pub fn is_unique_within(&mut self, group_column: &str, threshold: f64) -> Self {
+        self.inner.is_unique_within(group_column, threshold);
+        self.clone()
+    }
+
     /// Set numeric range (both min and max).
     ///
     /// Args:
@@ -74,6 +192,21 @@ impl IntegerColumnBuilder {
         self.clone()
     }
 
+    /// Set numeric range excluding both bounds, e.g. a probability in (0, 1).
+    ///
+    /// Args:
+    ///     min (int): Values must be strictly greater.
+    ///     max (int): Values must be strictly lower.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (min, max, threshold=0.0))]
+    pub fn between_exclusive(&mut self, min: i64, max: i64, threshold: f64) -> Self {
+        self.inner.between_exclusive(min, max, threshold);
+        self.clone()
+    }
+
     /// Set minimum value.
     ///
     /// Args:
@@ -102,6 +235,67 @@ impl IntegerColumnBuilder {
         self.clone()
     }
 
+    /// Check if value is in a set of allowed values.
+    ///
+    /// Args:
+    ///     members (list[int]): Allowed values.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (members, threshold=0.0))]
+    pub fn is_in(&mut self, members: Vec<i64>, threshold: f64) -> Self {
+        self.inner.is_in(members, threshold);
+        self.clone()
+    }
+
+    /// Check that every value equals a constant.
+    ///
+    /// Args:
+    ///     value (int): Expected value.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (value, threshold=0.0))]
+    pub fn equals(&mut self, value: i64, threshold: f64) -> Self {
+        self.inner.equals(value, threshold);
+        self.clone()
+    }
+
+    /// Check if value is outside a set of forbidden values.
+    ///
+    /// Args:
+    ///     members (list[int]): Forbidden values.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (members, threshold=0.0))]
+    pub fn not_in(&mut self, members: Vec<i64>, threshold: f64) -> Self {
+        self.inner.not_in(members, threshold);
+        self.clone()
+    }
+
+    /// Check that values are a multiple of divisor.
+    ///
+    /// Args:
+    ///     divisor (int): The divisor, cannot be zero.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If divisor is zero.
+    #[pyo3(signature = (divisor, threshold=0.0))]
+    pub fn is_multiple_of(&mut self, divisor: i64, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .is_multiple_of(divisor, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Check if values are positive (> 0).
     ///
     /// Args:
@@ -207,6 +401,55 @@ impl IntegerColumnBuilder {
         self.inner.mean_variance(threshold, max_variance_percent);
         self.clone()
     }
+
+    /// Check that a quantile of the column stays at or below a bound.
+    ///
+    /// The quantile is approximated and reported with the rule result.
+    ///
+    /// Args:
+    ///     quantile (float): Quantile to check, between 0.0 and 1.0 (0.99 for the 99th percentile).
+    ///     bound (float): Maximum value allowed for the quantile.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If quantile is outside [0, 1].
+    #[pyo3(signature = (quantile, bound, threshold=0.0))]
+    pub fn percentile_below(
+        &mut self,
+        quantile: f64,
+        bound: f64,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        self.inner
+            .percentile_below(quantile, bound, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that values lie within `num_std` standard deviations of the mean.
+    ///
+    /// The mean and standard deviation are computed over the whole column and
+    /// reported in `RuleResult.observed`. A constant column has no outlier.
+    ///
+    /// Args:
+    ///     num_std (float): Maximum z-score allowed.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     IntegerColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If num_std is not positive.
+    #[pyo3(signature = (num_std, threshold=0.0))]
+    pub fn no_outliers_beyond(&mut self, num_std: f64, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .no_outliers_beyond(num_std, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
 }
 
 /// Creates a builder for defining rules on an integer column.
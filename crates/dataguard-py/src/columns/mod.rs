@@ -1,9 +1,13 @@
+pub mod boolean_builder;
 pub mod date_builder;
 pub mod float_builder;
 pub mod integer_builder;
 pub mod string_builder;
+pub mod timestamp_builder;
 
+pub use boolean_builder::{boolean_column, BooleanColumnBuilder};
 pub use date_builder::{date_column, DateColumnBuilder};
 pub use float_builder::{float_column, FloatColumnBuilder};
 pub use integer_builder::{integer_column, IntegerColumnBuilder};
 pub use string_builder::{string_column, StringColumnBuilder};
+pub use timestamp_builder::{timestamp_column, TimestampColumnBuilder};
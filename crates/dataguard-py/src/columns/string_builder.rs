@@ -1,4 +1,8 @@
-use dataguard_core::{errors::RuleError, StringColumnBuilder as CoreStringColumnBuilder};
+use dataguard_core::{
+    errors::RuleError,
+    rules::{parse_normalize_mode, NormalizeMode},
+    StringColumnBuilder as CoreStringColumnBuilder,
+};
 use pyo3::prelude::*;
 
 /// Python wrapper for StringColumnBuilder from dataguard-core.
@@ -33,6 +37,48 @@ impl StringColumnBuilder {
         self.clone()
     }
 
+    /// Skip the column when it is missing from the file, instead of failing the table.
+    ///
+    /// Args:
+    ///     optional (bool): Whether the column may be missing (default: True).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (optional=true))]
+    pub fn with_optional(&mut self, optional: bool) -> Self {
+        self.inner = self.inner.clone().with_optional(optional);
+        self.clone()
+    }
+
+    /// Read the column from a file column named differently, results keep the column name.
+    ///
+    /// Args:
+    ///     source_name (str): Name of the column in the file.
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    pub fn with_source_name(&mut self, source_name: String) -> Self {
+        self.inner = self.inner.clone().with_source_name(Some(source_name));
+        self.clone()
+    }
+
+    /// Label the last added rule, its results carry this human readable name.
+    ///
+    /// Args:
+    ///     label (str): Name reported for the rule, e.g. "Customer name too short".
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If no rule was added yet.
+    pub fn with_label(&mut self, label: String) -> PyResult<Self> {
+        self.inner
+            .with_label(label)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Add a not-null constraint.
     ///
     /// Args:
@@ -46,16 +92,102 @@ impl StringColumnBuilder {
         self.clone()
     }
 
+    /// Fail when every value of the column is null.
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    pub fn not_all_null(&mut self) -> Self {
+        self.inner.not_all_null();
+        self.clone()
+    }
+
+    /// Fail when the column holds less than two distinct values.
+    ///
+    /// Distinct values are estimated with the hashes used by the uniqueness check.
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    pub fn not_all_same(&mut self) -> Self {
+        self.inner.not_all_same();
+        self.clone()
+    }
+
+    /// Fail when the share of null values is outside min_ratio and max_ratio.
+    ///
+    /// Catches both too many nulls and suspiciously none, the observed ratio is reported.
+    ///
+    /// Args:
+    ///     min_ratio (float): Minimum share of null values (0.0 to 1.0).
+    ///     max_ratio (float): Maximum share of null values (0.0 to 1.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the bounds are outside [0, 1] or min_ratio > max_ratio.
+    pub fn null_ratio_between(&mut self, min_ratio: f64, max_ratio: f64) -> PyResult<Self> {
+        self.inner
+            .null_ratio_between(min_ratio, max_ratio)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Fail when the estimated number of distinct values is outside min and max.
+    ///
+    /// The count is exact up to 1024 distinct values and estimated within about 3% above,
+    /// the estimate is reported.
+    ///
+    /// Args:
+    ///     min (int): Minimum number of distinct values.
+    ///     max (int): Maximum number of distinct values.
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If min > max.
+    pub fn distinct_count_between(&mut self, min: usize, max: usize) -> PyResult<Self> {
+        self.inner
+            .distinct_count_between(min, max)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Add uniqueness constraint.
     ///
     /// Args:
     ///     threshold (float): Maximum percentage of duplicate values allowed (default: 0.0).
+    ///     normalize (str | None): Applied to values before they are compared: "lowercase",
+    ///         "trim" or "trim_lowercase". Raw values are compared when None.
     ///
     /// Returns:
     ///     StringColumnBuilder: Self for method chaining.
-    #[pyo3(signature = (threshold=0.0))]
-    pub fn is_unique(&mut self, threshold: f64) -> Self {
-        self.inner.is_unique(threshold);
+    ///
+    /// Raises:
+    ///     ValueError: If the normalization is unknown.
+    #[pyo3(signature = (threshold=0.0, normalize=None))]
+    pub fn is_unique(&mut self, threshold: f64, normalize: Option<String>) -> PyResult<Self> {
+        let normalize = match normalize {
+            Some(name) => parse_normalize_mode(&name)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
+            None => NormalizeMode::None,
+        };
+        self.inner.is_unique(normalize, threshold);
+        Ok(self.clone())
+    }
+
+    /// Add uniqueness constraint within each group of rows sharing the same key.
+    ///
+    /// Args:
+    ///     group_column (str): Column holding the group key, compared as text. Rows with a
+    ///         null key are skipped.
+    ///     threshold (float): Maximum percentage of duplicate values allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (group_column, threshold=0.0))]
+    pub fn is_unique_within(&mut self, group_column: &str, threshold: f64) -> Self {
+        self.inner.is_unique_within(group_column, threshold);
         self.clone()
     }
 
@@ -74,17 +206,37 @@ impl StringColumnBuilder {
         self.clone()
     }
 
+    /// Set length constraints measured in UTF-16 code units.
+    ///
+    /// Useful when the data is consumed by a system that counts UTF-16 units
+    /// (JavaScript, Java, databases with UTF-16 storage). Characters outside
+    /// the BMP, such as most emoji, count as 2.
+    ///
+    /// Args:
+    ///     min (int): Minimum length in UTF-16 code units.
+    ///     max (int): Maximum length in UTF-16 code units.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (min, max, threshold=0.0))]
+    pub fn with_utf16_length_between(&mut self, min: usize, max: usize, threshold: f64) -> Self {
+        self.inner.with_utf16_length_between(min, max, threshold);
+        self.clone()
+    }
+
     /// Set minimum length.
     ///
     /// Args:
     ///     min (int): Minimum length.
+    ///     count_bytes (bool): Count UTF-8 bytes instead of characters (default: False).
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
     ///
     /// Returns:
     ///     StringColumnBuilder: Self for method chaining.
-    #[pyo3(signature = (min, threshold=0.0))]
-    pub fn with_min_length(&mut self, min: usize, threshold: f64) -> Self {
-        self.inner.with_min_length(min, threshold);
+    #[pyo3(signature = (min, count_bytes=false, threshold=0.0))]
+    pub fn with_min_length(&mut self, min: usize, count_bytes: bool, threshold: f64) -> Self {
+        self.inner.with_min_length(min, count_bytes, threshold);
         self.clone()
     }
 
@@ -92,13 +244,14 @@ impl StringColumnBuilder {
     ///
     /// Args:
     ///     max (int): Maximum length.
+    ///     count_bytes (bool): Count UTF-8 bytes instead of characters (default: False).
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
     ///
     /// Returns:
     ///     StringColumnBuilder: Self for method chaining.
-    #[pyo3(signature = (max, threshold=0.0))]
-    pub fn with_max_length(&mut self, max: usize, threshold: f64) -> Self {
-        self.inner.with_max_length(max, threshold);
+    #[pyo3(signature = (max, count_bytes=false, threshold=0.0))]
+    pub fn with_max_length(&mut self, max: usize, count_bytes: bool, threshold: f64) -> Self {
+        self.inner.with_max_length(max, count_bytes, threshold);
         self.clone()
     }
 
@@ -130,11 +283,116 @@ impl StringColumnBuilder {
         self.clone()
     }
 
+    /// Check that no value is in a set of forbidden values, nulls pass.
+    ///
+    /// Args:
+    ///     members (list[str]): List of forbidden values.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (members, threshold=0.0))]
+    pub fn is_not_in(&mut self, members: Vec<String>, threshold: f64) -> Self {
+        self.inner.is_not_in(members, threshold);
+        self.clone()
+    }
+
+    /// Check that every value equals a constant.
+    ///
+    /// Args:
+    ///     value (str): Expected value, compared exactly.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (value, threshold=0.0))]
+    pub fn equals(&mut self, value: String, threshold: f64) -> Self {
+        self.inner.equals(value, threshold);
+        self.clone()
+    }
+
+    /// Check that values begin with a prefix.
+    ///
+    /// Args:
+    ///     prefix (str): Expected prefix, must not be empty.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (prefix, threshold=0.0))]
+    pub fn with_prefix(&mut self, prefix: String, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .with_prefix(prefix, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that values end with a suffix.
+    ///
+    /// Args:
+    ///     suffix (str): Expected suffix, must not be empty.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (suffix, threshold=0.0))]
+    pub fn with_suffix(&mut self, suffix: String, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .with_suffix(suffix, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that values contain a substring.
+    ///
+    /// Args:
+    ///     substring (str): Required substring, must not be empty.
+    ///     flag (str | None): `"i"` for a case-insensitive match (default: None).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (substring, flag=None, threshold=0.0))]
+    pub fn contains(
+        &mut self,
+        substring: String,
+        flag: Option<String>,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        self.inner
+            .contains(substring, flag, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that values do not contain a substring.
+    ///
+    /// Args:
+    ///     substring (str): Banned substring, must not be empty.
+    ///     flag (str | None): `"i"` for a case-insensitive match (default: None).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (substring, flag=None, threshold=0.0))]
+    pub fn not_contains(
+        &mut self,
+        substring: String,
+        flag: Option<String>,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        self.inner
+            .not_contains(substring, flag, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Match against a regex pattern.
     ///
     /// Args:
     ///     pattern (str): Regular expression pattern.
-    ///     flags (str | None): Optional regex flags (default: None).
+    ///     flags (str | None): Optional regex flags, any of `i` (ignore case), `m` (multi-line),
+    ///         `s` (`.` matches a newline) and `u` (Unicode classes) (default: None).
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
     ///
     /// Returns:
@@ -152,6 +410,29 @@ impl StringColumnBuilder {
         Ok(self.clone())
     }
 
+    /// Match against any of several regex patterns, a value fails if it matches none.
+    ///
+    /// Args:
+    ///     patterns (list[str]): Regular expression patterns, at least one.
+    ///     flags (str | None): Optional regex flags applied to every pattern, see
+    ///         `with_regex` (default: None).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (patterns, flags=None, threshold=0.0))]
+    pub fn matches_any_of(
+        &mut self,
+        patterns: Vec<String>,
+        flags: Option<String>,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        self.inner
+            .matches_any_of(patterns, flags, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
     /// Check if string contains only numeric characters.
     ///
     /// Args:
@@ -200,33 +481,92 @@ impl StringColumnBuilder {
     /// Check if string is lowercase.
     ///
     /// Args:
+    ///     unicode (bool): Accept any lowercase letter, such as accented ones, instead of
+    ///         ASCII letters only (default: False).
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
     ///
     /// Returns:
     ///     StringColumnBuilder: Self for method chaining.
-    #[pyo3(signature = (threshold=0.0))]
-    pub fn is_lowercase(&mut self, threshold: f64) -> PyResult<Self> {
-        self.inner
-            .is_lowercase(threshold)
-            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-        Ok(self.clone())
+    #[pyo3(signature = (unicode=false, threshold=0.0))]
+    pub fn is_lowercase(&mut self, unicode: bool, threshold: f64) -> Self {
+        self.inner.is_lowercase(unicode, threshold);
+        self.clone()
     }
 
     /// Check if string is uppercase.
     ///
     /// Args:
+    ///     unicode (bool): Accept any uppercase letter, such as accented ones, instead of
+    ///         ASCII letters only (default: False).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (unicode=false, threshold=0.0))]
+    pub fn is_uppercase(&mut self, unicode: bool, threshold: f64) -> Self {
+        self.inner.is_uppercase(unicode, threshold);
+        self.clone()
+    }
+
+    /// Check that values have no leading or trailing whitespace.
+    ///
+    /// Args:
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
     ///
     /// Returns:
     ///     StringColumnBuilder: Self for method chaining.
     #[pyo3(signature = (threshold=0.0))]
-    pub fn is_uppercase(&mut self, threshold: f64) -> PyResult<Self> {
+    pub fn has_no_surrounding_whitespace(&mut self, threshold: f64) -> Self {
+        self.inner.has_no_surrounding_whitespace(threshold);
+        self.clone()
+    }
+
+    /// Check that values contain no whitespace at all.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn has_no_whitespace(&mut self, threshold: f64) -> Self {
+        self.inner.has_no_whitespace(threshold);
+        self.clone()
+    }
+
+    /// Check that values only use characters of `allowed`, e.g. "ACGT" for DNA sequences.
+    ///
+    /// Args:
+    ///     allowed (str): Characters values may use.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (allowed, threshold=0.0))]
+    pub fn contains_only(&mut self, allowed: String, threshold: f64) -> PyResult<Self> {
         self.inner
-            .is_uppercase(threshold)
+            .contains_only(allowed, threshold)
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
         Ok(self.clone())
     }
 
+    /// Check that values share the column's dominant length.
+    ///
+    /// Lengths are counted over the whole column, the dominant (most frequent)
+    /// length is reported with the results.
+    ///
+    /// Args:
+    ///     tolerance (int): Accepted distance in characters from the dominant length (default: 0).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (tolerance=0, threshold=0.0))]
+    pub fn consistent_length(&mut self, tolerance: usize, threshold: f64) -> Self {
+        self.inner.consistent_length(tolerance, threshold);
+        self.clone()
+    }
+
     /// Check if string is a valid URL.
     ///
     /// Args:
@@ -245,14 +585,16 @@ impl StringColumnBuilder {
     /// Check if string is a valid email.
     ///
     /// Args:
+    ///     strict (bool): Also require hostname labels in the domain and no leading, trailing
+    ///         or repeated dot in the local part (default: False). The domain is not resolved.
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
     ///
     /// Returns:
     ///     StringColumnBuilder: Self for method chaining.
-    #[pyo3(signature = (threshold=0.0))]
-    pub fn is_email(&mut self, threshold: f64) -> PyResult<Self> {
+    #[pyo3(signature = (strict=false, threshold=0.0))]
+    pub fn is_email(&mut self, strict: bool, threshold: f64) -> PyResult<Self> {
         self.inner
-            .is_email(threshold)
+            .is_email(strict, threshold)
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
         Ok(self.clone())
     }
@@ -271,6 +613,187 @@ impl StringColumnBuilder {
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
         Ok(self.clone())
     }
+
+    /// Check if string is a valid IPv4 or IPv6 address.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_ip(&mut self, threshold: f64) -> Self {
+        self.inner.is_ip(threshold);
+        self.clone()
+    }
+
+    /// Check if string is a valid IPv4 address.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_ipv4(&mut self, threshold: f64) -> Self {
+        self.inner.is_ipv4(threshold);
+        self.clone()
+    }
+
+    /// Check if string is a valid IPv6 address.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_ipv6(&mut self, threshold: f64) -> Self {
+        self.inner.is_ipv6(threshold);
+        self.clone()
+    }
+
+    /// Check if string is an IP address within one of the allowed CIDR blocks.
+    ///
+    /// Values that are not IP addresses count as violations.
+    ///
+    /// Args:
+    ///     cidrs (list[str]): Allowed blocks, such as ["10.0.0.0/8", "fd00::/8"].
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If a block is malformed or the list is empty.
+    #[pyo3(signature = (cidrs, threshold=0.0))]
+    pub fn ip_in_cidr(&mut self, cidrs: Vec<String>, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .ip_in_cidr(cidrs, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check if string is a parseable JSON document.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_json(&mut self, threshold: f64) -> Self {
+        self.inner.is_json(threshold);
+        self.clone()
+    }
+
+    /// Check if string is a JSON document holding an object at the top level.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_json_object(&mut self, threshold: f64) -> Self {
+        self.inner.is_json_object(threshold);
+        self.clone()
+    }
+
+    /// Check if string is a JSON document holding an array at the top level.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_json_array(&mut self, threshold: f64) -> Self {
+        self.inner.is_json_array(threshold);
+        self.clone()
+    }
+
+    /// Check if string is a structurally valid card number.
+    ///
+    /// Spaces and dashes are ignored, the 12 to 19 digits must pass the Luhn checksum.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_credit_card(&mut self, threshold: f64) -> Self {
+        self.inner.is_credit_card(threshold);
+        self.clone()
+    }
+
+    /// Check if string is a structurally valid IBAN.
+    ///
+    /// Spaces are ignored, the value must pass the ISO 7064 mod-97 check.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_iban(&mut self, threshold: f64) -> Self {
+        self.inner.is_iban(threshold);
+        self.clone()
+    }
+
+    /// Check if string is standard base64.
+    ///
+    /// Args:
+    ///     strict_padding (bool): Require the `=` padding to a multiple of 4 characters
+    ///         (default: True).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (strict_padding=true, threshold=0.0))]
+    pub fn is_base64(&mut self, strict_padding: bool, threshold: f64) -> Self {
+        self.inner.is_base64(strict_padding, threshold);
+        self.clone()
+    }
+
+    /// Check if string is an even number of hexadecimal digits.
+    ///
+    /// Args:
+    ///     allow_prefix (bool): Accept a leading `0x` (default: False).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (allow_prefix=false, threshold=0.0))]
+    pub fn is_hex(&mut self, allow_prefix: bool, threshold: f64) -> Self {
+        self.inner.is_hex(allow_prefix, threshold);
+        self.clone()
+    }
+
+    /// Check if string is a valid phone number.
+    ///
+    /// Numbers are parsed against the full numbering plan metadata, expect this rule to be
+    /// much slower than a regex on large columns.
+    ///
+    /// Args:
+    ///     region (str | None): ISO 3166 country code such as "FR", national formats are
+    ///         then accepted and numbers must belong to it. International format only when None.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     StringColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the region is not a known country code.
+    #[pyo3(signature = (region=None, threshold=0.0))]
+    pub fn is_phone(&mut self, region: Option<String>, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .is_phone(region.as_deref(), threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
 }
 
 /// Creates a builder for defining rules on a string column.
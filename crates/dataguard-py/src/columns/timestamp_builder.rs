@@ -0,0 +1,303 @@
+use chrono::NaiveDate;
+use dataguard_core::{
+    columns::timestamp_builder::TimestampColumnBuilder as CoreTimestampColumnBuilder,
+    errors::RuleError,
+};
+use pyo3::prelude::*;
+
+/// Python wrapper for TimestampColumnBuilder from dataguard-core.
+///
+/// A builder for defining validation rules on timestamp columns, at millisecond precision.
+#[pyclass(name = "TimestampColumnBuilder")]
+#[derive(Clone)]
+pub struct TimestampColumnBuilder {
+    inner: CoreTimestampColumnBuilder,
+}
+
+impl TimestampColumnBuilder {
+    /// Convert this Python builder to a core ColumnBuilder trait object
+    pub fn to_core_column_builder(
+        &self,
+    ) -> Result<Box<dyn dataguard_core::columns::ColumnBuilder>, RuleError> {
+        Ok(Box::new(self.inner.clone()))
+    }
+}
+
+#[pymethods]
+impl TimestampColumnBuilder {
+    /// Set the type checking threshold.
+    ///
+    /// Args:
+    ///     threshold (float): The threshold for type checking (0.0 to 1.0).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    pub fn with_type_threshold(&mut self, threshold: f64) -> Self {
+        self.inner = self.inner.clone().with_type_threshold(threshold);
+        self.clone()
+    }
+
+    /// Skip the column when it is missing from the file, instead of failing the table.
+    ///
+    /// Args:
+    ///     optional (bool): Whether the column may be missing (default: True).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (optional=true))]
+    pub fn with_optional(&mut self, optional: bool) -> Self {
+        self.inner = self.inner.clone().with_optional(optional);
+        self.clone()
+    }
+
+    /// Read the column from a file column named differently, results keep the column name.
+    ///
+    /// Args:
+    ///     source_name (str): Name of the column in the file.
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    pub fn with_source_name(&mut self, source_name: String) -> Self {
+        self.inner = self.inner.clone().with_source_name(Some(source_name));
+        self.clone()
+    }
+
+    /// Label the last added rule, its results carry this human readable name.
+    ///
+    /// Args:
+    ///     label (str): Name reported for the rule, e.g. "Customer name too short".
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If no rule was added yet.
+    pub fn with_label(&mut self, label: String) -> PyResult<Self> {
+        self.inner
+            .with_label(label)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Get the timestamp format string.
+    ///
+    /// Returns:
+    ///     str: The timestamp format string.
+    pub fn get_format(&self) -> String {
+        self.inner.get_format()
+    }
+
+    /// Add a not-null constraint.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of null values allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_not_null(&mut self, threshold: f64) -> Self {
+        self.inner.is_not_null(threshold);
+        self.clone()
+    }
+
+    /// Fail when every value of the column is null.
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    pub fn not_all_null(&mut self) -> Self {
+        self.inner.not_all_null();
+        self.clone()
+    }
+
+    /// Fail when the column holds less than two distinct values.
+    ///
+    /// Distinct values are estimated with the hashes used by the uniqueness check.
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    pub fn not_all_same(&mut self) -> Self {
+        self.inner.not_all_same();
+        self.clone()
+    }
+
+    /// Fail when the share of null values is outside min_ratio and max_ratio.
+    ///
+    /// Catches both too many nulls and suspiciously none, the observed ratio is reported.
+    ///
+    /// Args:
+    ///     min_ratio (float): Minimum share of null values (0.0 to 1.0).
+    ///     max_ratio (float): Maximum share of null values (0.0 to 1.0).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the bounds are outside [0, 1] or min_ratio > max_ratio.
+    pub fn null_ratio_between(&mut self, min_ratio: f64, max_ratio: f64) -> PyResult<Self> {
+        self.inner
+            .null_ratio_between(min_ratio, max_ratio)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Fail when the estimated number of distinct values is outside min and max.
+    ///
+    /// The count is exact up to 1024 distinct values and estimated within about 3% above,
+    /// the estimate is reported.
+    ///
+    /// Args:
+    ///     min (int): Minimum number of distinct values.
+    ///     max (int): Maximum number of distinct values.
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If min > max.
+    pub fn distinct_count_between(&mut self, min: usize, max: usize) -> PyResult<Self> {
+        self.inner
+            .distinct_count_between(min, max)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Add uniqueness constraint.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of duplicate values allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_unique(&mut self, threshold: f64) -> Self {
+        self.inner.is_unique(threshold);
+        self.clone()
+    }
+
+    /// Add uniqueness constraint within each group of rows sharing the same key.
+    ///
+    /// Args:
+    ///     group_column (str): Column holding the group key, compared as text. Rows with a
+    ///         null key are skipped.
+    ///     threshold (float): Maximum percentage of duplicate values allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (group_column, threshold=0.0))]
+    pub fn is_unique_within(&mut self, group_column: &str, threshold: f64) -> Self {
+        self.inner.is_unique_within(group_column, threshold);
+        self.clone()
+    }
+
+    /// Set a limit - the timestamp should be strictly before the given one.
+    ///
+    /// Args:
+    ///     timestamp (str): Bound as "YYYY-MM-DD HH:MM:SS", a bare date is read as midnight.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the timestamp cannot be parsed.
+    #[pyo3(signature = (timestamp, threshold=0.0))]
+    pub fn is_before(&mut self, timestamp: &str, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .is_before(timestamp, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Set a limit - the timestamp should be strictly after the given one.
+    ///
+    /// Args:
+    ///     timestamp (str): Bound as "YYYY-MM-DD HH:MM:SS", a bare date is read as midnight.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the timestamp cannot be parsed.
+    #[pyo3(signature = (timestamp, threshold=0.0))]
+    pub fn is_after(&mut self, timestamp: &str, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .is_after(timestamp, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that all timestamps are not in the future (before the reference date).
+    ///
+    /// Set the reference to the logical date of the dataset for reproducible batch jobs.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///     reference_date (datetime.date | None): Timestamps are compared against this date at
+    ///         midnight, the current local time when None.
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0, reference_date=None))]
+    pub fn is_not_futur(&mut self, threshold: f64, reference_date: Option<NaiveDate>) -> Self {
+        self.inner.is_not_futur(reference_date, threshold);
+        self.clone()
+    }
+
+    /// Check that all timestamps are not in the past (after the reference date).
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///     reference_date (datetime.date | None): Timestamps are compared against this date at
+    ///         midnight, the current local time when None.
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0, reference_date=None))]
+    pub fn is_not_past(&mut self, threshold: f64, reference_date: Option<NaiveDate>) -> Self {
+        self.inner.is_not_past(reference_date, threshold);
+        self.clone()
+    }
+
+    /// Check that the time of day falls within `[start_hour, end_hour)`.
+    ///
+    /// A window with start_hour greater than end_hour wraps around midnight.
+    ///
+    /// Args:
+    ///     start_hour (int): First hour of the window, inclusive.
+    ///     end_hour (int): Last hour of the window, exclusive.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     TimestampColumnBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the hours are equal or greater than 24.
+    #[pyo3(signature = (start_hour, end_hour, threshold=0.0))]
+    pub fn time_between(
+        &mut self,
+        start_hour: u32,
+        end_hour: u32,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        self.inner
+            .time_between(start_hour, end_hour, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+}
+
+/// Creates a builder for defining rules on a timestamp column.
+///
+/// Args:
+///     name (str): The name of the column.
+///     format (str): The timestamp format string (e.g., "%Y-%m-%d %H:%M:%S").
+///
+/// Returns:
+///     TimestampColumnBuilder: A builder object for chaining rules.
+#[pyfunction]
+pub fn timestamp_column(name: String, format: String) -> TimestampColumnBuilder {
+    TimestampColumnBuilder {
+        inner: CoreTimestampColumnBuilder::new(name, format),
+    }
+}
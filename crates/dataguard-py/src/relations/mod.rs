@@ -1,5 +1,5 @@
 use dataguard_core::{
-    columns::relation_builder::RelationBuilder as CoreRelationBuilder,
+    columns::{relation_builder::RelationBuilder as CoreRelationBuilder, ConditionalRule},
     utils::operator::CompOperator,
 };
 use pyo3::prelude::*;
@@ -42,17 +42,25 @@ impl RelationBuilder {
     ///         - "gt" or ">": Greater than
     ///         - "gte" or ">=": Greater than or equal
     ///         - "eq" or "=": Equal
+    ///         - "neq" or "!=": Not equal
     ///         - "lt" or "<": Less than
     ///         - "lte" or "<=": Less than or equal
     ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///     max_gap_days (int, optional): Largest number of days allowed between both dates,
+    ///         rows further apart also count as violations (default: None).
     ///
     /// Returns:
     ///     RelationBuilder: Self for method chaining.
-    #[pyo3(signature = (operator, threshold=0.0))]
-    pub fn date_comparaison(&mut self, operator: &str, threshold: f64) -> PyResult<Self> {
+    #[pyo3(signature = (operator, threshold=0.0, max_gap_days=None))]
+    pub fn date_comparaison(
+        &mut self,
+        operator: &str,
+        threshold: f64,
+        max_gap_days: Option<u32>,
+    ) -> PyResult<Self> {
         let op = CompOperator::try_from(operator)
             .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-        self.inner.date_comparaison(op, threshold);
+        self.inner.date_comparaison(op, max_gap_days, threshold);
         Ok(self.clone())
     }
 
@@ -63,6 +71,165 @@ impl RelationBuilder {
         self.inner.numeric_comparaison(op, threshold);
         Ok(self.clone())
     }
+
+    /// Compare two string columns row by row, rows with a null side are skipped.
+    ///
+    /// Args:
+    ///     operator (str): "eq" or "=" for equality, "neq" or "!=" for inequality.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     RelationBuilder: Self for method chaining.
+    #[pyo3(signature = (operator, threshold=0.0))]
+    pub fn string_comparaison(&mut self, operator: &str, threshold: f64) -> PyResult<Self> {
+        let op = CompOperator::try_from(operator)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        self.inner.string_comparaison(op, threshold);
+        Ok(self.clone())
+    }
+
+    /// Check that the first column plus the addends equals the second column.
+    ///
+    /// Rows where any of the columns is null are skipped and counted apart.
+    ///
+    /// Args:
+    ///     addends (list[str]): Columns added to the first column (default: []).
+    ///     tolerance (float): Maximum absolute difference allowed (default: 0.0).
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     RelationBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If tolerance is negative.
+    #[pyo3(signature = (addends=vec![], tolerance=0.0, threshold=0.0))]
+    pub fn sum_equals(
+        &mut self,
+        addends: Vec<String>,
+        tolerance: f64,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        self.inner
+            .sum_equals(addends, tolerance, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check the second column on rows where the first column equals a value.
+    ///
+    /// The first column is compared as text. Rows where it is null do not match the
+    /// condition and are not checked.
+    ///
+    /// Args:
+    ///     when_equals (str): Value of the first column enabling the check, e.g. "shipped".
+    ///     then (str): Check on the second column, "is_not_null", "is_null" or "between".
+    ///     min (float | None): Lower bound, inclusive, required by "between".
+    ///     max (float | None): Upper bound, inclusive, required by "between".
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     RelationBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If the check is unknown, or the bounds are missing or inverted.
+    #[pyo3(signature = (when_equals, then, min=None, max=None, threshold=0.0))]
+    pub fn conditional(
+        &mut self,
+        when_equals: String,
+        then: &str,
+        min: Option<f64>,
+        max: Option<f64>,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        let then_rule = match (then, min, max) {
+            ("is_not_null", _, _) => ConditionalRule::NotNull,
+            ("is_null", _, _) => ConditionalRule::IsNull,
+            ("between", Some(min), Some(max)) => ConditionalRule::Between { min, max },
+            ("between", _, _) => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "between requires both min and max",
+                ))
+            }
+            (other, _, _) => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Unknown conditional check '{}', expected is_not_null, is_null or between",
+                    other
+                )))
+            }
+        };
+        self.inner
+            .conditional(when_equals, then_rule, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that the Pearson correlation of two numeric columns is at least a floor.
+    ///
+    /// The coefficient is computed over all rows where both values are present and
+    /// reported in the result. When it is below the floor, every compared row fails.
+    ///
+    /// Args:
+    ///     min_coefficient (float): Lowest accepted coefficient, between -1 and 1.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     RelationBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If min_coefficient is not between -1 and 1.
+    #[pyo3(signature = (min_coefficient, threshold=0.0))]
+    pub fn correlation(&mut self, min_coefficient: f64, threshold: f64) -> PyResult<Self> {
+        self.inner
+            .correlation(min_coefficient, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that the seconds elapsed from the first timestamp column to the second are
+    /// within a range.
+    ///
+    /// Rows where either timestamp is null or could not be parsed are skipped and
+    /// reported apart.
+    ///
+    /// Args:
+    ///     min_secs (int): Shortest accepted duration, in seconds.
+    ///     max_secs (int): Longest accepted duration, in seconds.
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     RelationBuilder: Self for method chaining.
+    ///
+    /// Raises:
+    ///     ValueError: If min_secs is greater than max_secs.
+    #[pyo3(signature = (min_secs, max_secs, threshold=0.0))]
+    pub fn duration_between(
+        &mut self,
+        min_secs: i64,
+        max_secs: i64,
+        threshold: f64,
+    ) -> PyResult<Self> {
+        self.inner
+            .duration_between(min_secs, max_secs, threshold)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        Ok(self.clone())
+    }
+
+    /// Check that every value of the first column is found among the values of the second.
+    ///
+    /// Both columns are collected over the whole table before being compared. Rows holding
+    /// a missing value fail, null values are not checked. The first row of each missing
+    /// value is sampled.
+    ///
+    /// Args:
+    ///     threshold (float): Maximum percentage of violations allowed (default: 0.0).
+    ///
+    /// Returns:
+    ///     RelationBuilder: Self for method chaining.
+    #[pyo3(signature = (threshold=0.0))]
+    pub fn is_subset_of(&mut self, threshold: f64) -> Self {
+        self.inner.is_subset_of(threshold);
+        self.clone()
+    }
 }
 
 /// Creates a builder for defining validation rules between two columns.
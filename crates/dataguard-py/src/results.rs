@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use dataguard_core::{RuleResult as CoreRuleResult, ValidationResult as CoreValidationResult};
+use pyo3::prelude::*;
+
+/// Python wrapper for ValidationResult from dataguard-core.
+///
+/// Holds the outcome of validating a table, rule by rule.
+#[pyclass(name = "ValidationResult")]
+#[derive(Clone)]
+pub struct ValidationResult {
+    inner: CoreValidationResult,
+}
+
+impl From<CoreValidationResult> for ValidationResult {
+    fn from(inner: CoreValidationResult) -> Self {
+        Self { inner }
+    }
+}
+
+/// Wrap a map of core rule results, keyed by column or relation name
+fn wrap_results(
+    results: HashMap<String, Vec<&CoreRuleResult>>,
+) -> HashMap<String, Vec<RuleResult>> {
+    results
+        .into_iter()
+        .map(|(name, rules)| {
+            let rules = rules
+                .into_iter()
+                .map(|r| RuleResult { inner: r.clone() })
+                .collect();
+            (name, rules)
+        })
+        .collect()
+}
+
+#[pymethods]
+impl ValidationResult {
+    /// str: Name of the table.
+    #[getter]
+    pub fn table_name(&self) -> String {
+        self.inner.table_name.clone()
+    }
+
+    /// int: Total number of rows processed.
+    #[getter]
+    pub fn total_rows(&self) -> usize {
+        self.inner.total_rows
+    }
+
+    /// tuple[int, int]: Number of passed rules and total number of rules.
+    #[getter]
+    pub fn passed(&self) -> (u8, u8) {
+        self.inner.is_passed()
+    }
+
+    /// str | None: Error raised while validating the table, if any.
+    #[getter]
+    pub fn error_message(&self) -> Option<String> {
+        self.inner.error_message.clone()
+    }
+
+    /// float: Seconds spent reading the file.
+    #[getter]
+    pub fn read_duration(&self) -> f64 {
+        self.inner.read_duration.as_secs_f64()
+    }
+
+    /// float: Seconds spent running the rules.
+    #[getter]
+    pub fn validation_duration(&self) -> f64 {
+        self.inner.validation_duration.as_secs_f64()
+    }
+
+    /// float: Rows validated per second, over both reading and validation.
+    #[getter]
+    pub fn rows_per_second(&self) -> f64 {
+        self.inner.rows_per_second()
+    }
+
+    /// list[str]: Configured columns not found in the file.
+    #[getter]
+    pub fn missing_columns(&self) -> Vec<String> {
+        self.inner.get_missing_columns().to_vec()
+    }
+
+    /// list[str]: File columns that are not configured, only set in strict schema mode.
+    #[getter]
+    pub fn unexpected_columns(&self) -> Vec<String> {
+        self.inner.get_unexpected_columns().to_vec()
+    }
+
+    /// Results of the column rules.
+    ///
+    /// Returns:
+    ///     dict[str, list[RuleResult]]: Rule results keyed by column name.
+    pub fn column_results(&self) -> HashMap<String, Vec<RuleResult>> {
+        wrap_results(self.inner.get_column_results())
+    }
+
+    /// Results of the relation rules.
+    ///
+    /// Returns:
+    ///     dict[str, list[RuleResult]]: Rule results keyed by `"left | right"`.
+    pub fn relation_results(&self) -> HashMap<String, Vec<RuleResult>> {
+        wrap_results(self.inner.get_relation_results())
+    }
+
+    fn __repr__(&self) -> String {
+        let (passed, total) = self.inner.is_passed();
+        format!(
+            "ValidationResult(table_name={:?}, total_rows={}, passed={}/{})",
+            self.inner.table_name, self.inner.total_rows, passed, total
+        )
+    }
+}
+
+/// Python wrapper for RuleResult from dataguard-core.
+///
+/// The outcome of a single rule.
+#[pyclass(name = "RuleResult")]
+#[derive(Clone)]
+pub struct RuleResult {
+    inner: CoreRuleResult,
+}
+
+#[pymethods]
+impl RuleResult {
+    /// str: Name of the rule.
+    #[getter]
+    pub fn rule_name(&self) -> String {
+        self.inner.rule_name.clone()
+    }
+
+    /// str | None: Human readable name given to the rule with `with_label`.
+    #[getter]
+    pub fn label(&self) -> Option<String> {
+        self.inner.label.clone()
+    }
+
+    /// int: Number of rows violating the rule.
+    #[getter]
+    pub fn error_count(&self) -> usize {
+        self.inner.error_count
+    }
+
+    /// float: Percentage of rows violating the rule.
+    #[getter]
+    pub fn error_percentage(&self) -> f64 {
+        self.inner.error_percentage
+    }
+
+    /// float: Maximum percentage of violations allowed.
+    #[getter]
+    pub fn threshold(&self) -> f64 {
+        self.inner.threshold
+    }
+
+    /// bool: Whether the violations stayed within the threshold.
+    #[getter]
+    pub fn passed(&self) -> bool {
+        self.inner.pass
+    }
+
+    /// str | None: Error raised while running the rule, if any.
+    #[getter]
+    pub fn error_message(&self) -> Option<String> {
+        self.inner.error_message.clone()
+    }
+
+    /// list[tuple[int, str]]: First failing `(row, value)` pairs.
+    #[getter]
+    pub fn samples(&self) -> Vec<(usize, String)> {
+        self.inner.samples.clone()
+    }
+
+    /// dict[str, float]: Column level values computed by the rule, such as a quantile.
+    #[getter]
+    pub fn observed(&self) -> HashMap<String, f64> {
+        self.inner.observed.iter().cloned().collect()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RuleResult(rule_name={:?}, error_count={}, error_percentage={:.2}, passed={})",
+            self.inner.rule_name,
+            self.inner.error_count,
+            self.inner.error_percentage,
+            if self.inner.pass { "True" } else { "False" }
+        )
+    }
+}
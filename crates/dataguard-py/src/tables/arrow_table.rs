@@ -0,0 +1,155 @@
+use std::ffi::CStr;
+
+use arrow::{
+    error::ArrowError,
+    ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream},
+    record_batch::{RecordBatch, RecordBatchReader},
+};
+use dataguard_core::{ArrowTable as CoreArrowTable, Table as CoreTable};
+use pyo3::{
+    exceptions::{PyIOError, PyTypeError},
+    prelude::*,
+    types::{PyAny, PyCapsule},
+};
+
+use crate::columns::{
+    BooleanColumnBuilder, DateColumnBuilder, FloatColumnBuilder, IntegerColumnBuilder,
+    StringColumnBuilder, TimestampColumnBuilder,
+};
+use crate::relations::RelationBuilder;
+use crate::results::ValidationResult;
+
+const STREAM_CAPSULE: &CStr = c"arrow_array_stream";
+
+/// Python wrapper for ArrowTable from dataguard-core.
+///
+/// An ArrowTable validates data already in memory, without writing it to disk first.
+#[pyclass(name = "ArrowTable")]
+pub struct ArrowTable {
+    pub(crate) inner: CoreArrowTable,
+}
+
+#[pymethods]
+impl ArrowTable {
+    /// Create a new ArrowTable from in-memory data.
+    ///
+    /// Any object exporting an Arrow stream through the Arrow PyCapsule interface
+    /// (`__arrow_c_stream__`) is accepted: a pyarrow Table or RecordBatchReader, a
+    /// polars DataFrame, or a pandas DataFrame (pandas >= 2.2).
+    ///
+    /// Columns keep their native Arrow types: strings are `utf8`, integers `int64`,
+    /// floats `float64`, dates `date32` and booleans `bool`. Narrower types of the same
+    /// family (`int32`, `float32`, `large_utf8`, `utf8_view`, ...) are widened.
+    ///
+    /// Args:
+    ///     data: Object implementing `__arrow_c_stream__`.
+    ///     table_name (str): Name identifier for this table.
+    ///     strict_schema (bool): Also report data columns that are not configured (default: False).
+    ///
+    /// Returns:
+    ///     ArrowTable: A new ArrowTable instance.
+    #[new]
+    #[pyo3(signature = (data, table_name, strict_schema=false))]
+    pub fn new(data: Bound<'_, PyAny>, table_name: String, strict_schema: bool) -> PyResult<Self> {
+        if !data.hasattr("__arrow_c_stream__")? {
+            return Err(PyTypeError::new_err(format!(
+                "Expected an object implementing __arrow_c_stream__, got {}",
+                data.get_type().name()?
+            )));
+        }
+        let capsule = data.call_method0("__arrow_c_stream__")?;
+        let capsule = capsule.cast::<PyCapsule>()?;
+        let pointer = capsule.pointer_checked(Some(STREAM_CAPSULE))?;
+        // Move the stream out of the capsule, its destructor then sees a released stream
+        let stream = unsafe {
+            FFI_ArrowArrayStream::from_raw(pointer.as_ptr() as *mut FFI_ArrowArrayStream)
+        };
+        let reader = ArrowArrayStreamReader::try_new(stream)
+            .map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let schema = reader.schema();
+        let batches = reader
+            .collect::<Result<Vec<RecordBatch>, ArrowError>>()
+            .map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let inner =
+            CoreArrowTable::new(table_name, schema, batches).with_strict_schema(strict_schema);
+        Ok(Self { inner })
+    }
+
+    /// Prepare the table with column rules and relations.
+    ///
+    /// This method compiles the column builders and relations into executable rules.
+    ///
+    /// Args:
+    ///     columns (list): List of column builders (StringColumnBuilder, IntegerColumnBuilder, etc.)
+    ///     relations (list): Optional list of relation builders (default: [])
+    ///
+    /// Returns:
+    ///     None
+    #[pyo3(signature = (columns, relations=vec![]))]
+    pub fn prepare(
+        &mut self,
+        columns: Vec<Bound<'_, PyAny>>,
+        relations: Vec<Py<RelationBuilder>>,
+    ) -> PyResult<()> {
+        Python::attach(|py| {
+            // Convert Python column builders to Rust ColumnBuilder trait objects
+            let mut core_columns: Vec<Box<dyn dataguard_core::columns::ColumnBuilder>> = Vec::new();
+
+            for py_col in columns {
+                let builder: Box<dyn dataguard_core::columns::ColumnBuilder> =
+                    if let Ok(col) = py_col.extract::<StringColumnBuilder>() {
+                        col.to_core_column_builder()
+                            .map_err(|e| PyIOError::new_err(e.to_string()))?
+                    } else if let Ok(col) = py_col.extract::<IntegerColumnBuilder>() {
+                        col.to_core_column_builder()
+                            .map_err(|e| PyIOError::new_err(e.to_string()))?
+                    } else if let Ok(col) = py_col.extract::<FloatColumnBuilder>() {
+                        col.to_core_column_builder()
+                            .map_err(|e| PyIOError::new_err(e.to_string()))?
+                    } else if let Ok(col) = py_col.extract::<DateColumnBuilder>() {
+                        col.to_core_column_builder()
+                            .map_err(|e| PyIOError::new_err(e.to_string()))?
+                    } else if let Ok(col) = py_col.extract::<TimestampColumnBuilder>() {
+                        col.to_core_column_builder()
+                            .map_err(|e| PyIOError::new_err(e.to_string()))?
+                    } else if let Ok(col) = py_col.extract::<BooleanColumnBuilder>() {
+                        col.to_core_column_builder()
+                            .map_err(|e| PyIOError::new_err(e.to_string()))?
+                    } else {
+                        return Err(PyIOError::new_err(format!(
+                            "Invalid column type: {:?}",
+                            py_col
+                        )));
+                    };
+                core_columns.push(builder);
+            }
+
+            // Convert Python relation builders to Rust RelationBuilder
+            let core_relations: Vec<dataguard_core::columns::relation_builder::RelationBuilder> =
+                relations
+                    .into_iter()
+                    .map(|py_rel| {
+                        let rel = py_rel.borrow(py);
+                        rel.to_core_relation_builder()
+                    })
+                    .collect();
+
+            // Call prepare on the core table
+            self.inner
+                .prepare(core_columns, core_relations)
+                .map_err(|e| PyIOError::new_err(e.to_string()))
+        })
+    }
+
+    /// Validate the data against the prepared rules.
+    ///
+    /// Returns:
+    ///     ValidationResult: The table results, with per-rule details.
+    pub fn validate(&mut self) -> PyResult<ValidationResult> {
+        let result = self
+            .inner
+            .validate()
+            .map_err(|e| PyIOError::new_err(e.to_string()))?;
+        Ok(ValidationResult::from(result))
+    }
+}
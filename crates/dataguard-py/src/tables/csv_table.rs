@@ -2,9 +2,11 @@ use dataguard_core::{CsvTable as CoreCsvTable, Table as CoreTable};
 use pyo3::{exceptions::PyIOError, prelude::*, types::PyAny};
 
 use crate::columns::{
-    DateColumnBuilder, FloatColumnBuilder, IntegerColumnBuilder, StringColumnBuilder,
+    BooleanColumnBuilder, DateColumnBuilder, FloatColumnBuilder, IntegerColumnBuilder,
+    StringColumnBuilder, TimestampColumnBuilder,
 };
 use crate::relations::RelationBuilder;
+use crate::results::ValidationResult;
 
 /// Python wrapper for CsvTable from dataguard-core.
 ///
@@ -21,13 +23,16 @@ impl CsvTable {
     /// Args:
     ///     path (str): Path to the CSV file.
     ///     table_name (str): Name identifier for this table.
+    ///     strict_schema (bool): Also report file columns that are not configured (default: False).
     ///
     /// Returns:
     ///     CsvTable: A new CsvTable instance.
     #[new]
-    pub fn new(path: String, table_name: String) -> PyResult<Self> {
-        let inner =
-            CoreCsvTable::new(path, table_name).map_err(|e| PyIOError::new_err(e.to_string()))?;
+    #[pyo3(signature = (path, table_name, strict_schema=false))]
+    pub fn new(path: String, table_name: String, strict_schema: bool) -> PyResult<Self> {
+        let inner = CoreCsvTable::new(path, table_name)
+            .map_err(|e| PyIOError::new_err(e.to_string()))?
+            .with_strict_schema(strict_schema);
         Ok(Self { inner })
     }
 
@@ -65,6 +70,12 @@ impl CsvTable {
                     } else if let Ok(col) = py_col.extract::<DateColumnBuilder>() {
                         col.to_core_column_builder()
                             .map_err(|e| PyIOError::new_err(e.to_string()))?
+                    } else if let Ok(col) = py_col.extract::<TimestampColumnBuilder>() {
+                        col.to_core_column_builder()
+                            .map_err(|e| PyIOError::new_err(e.to_string()))?
+                    } else if let Ok(col) = py_col.extract::<BooleanColumnBuilder>() {
+                        col.to_core_column_builder()
+                            .map_err(|e| PyIOError::new_err(e.to_string()))?
                     } else {
                         return Err(PyIOError::new_err(format!(
                             "Invalid column type: {:?}",
@@ -94,23 +105,12 @@ impl CsvTable {
     /// Validate the CSV file against the prepared rules.
     ///
     /// Returns:
-    ///     dict: A dictionary containing validation results with keys:
-    ///         - 'table_name': Name of the table
-    ///         - 'total_rows': Total number of rows processed
-    ///         - 'passed': Tuple of (passed_rules, total_rules)
-    pub fn validate(&mut self) -> PyResult<Py<PyAny>> {
+    ///     ValidationResult: The table results, with per-rule details.
+    pub fn validate(&mut self) -> PyResult<ValidationResult> {
         let result = self
             .inner
             .validate()
             .map_err(|e| PyIOError::new_err(e.to_string()))?;
-
-        Python::attach(|py| {
-            let dict = pyo3::types::PyDict::new(py);
-            dict.set_item("table_name", &result.table_name)?;
-            dict.set_item("total_rows", result.total_rows)?;
-            let (passed, total) = result.is_passed();
-            dict.set_item("passed", (passed, total))?;
-            Ok(dict.into())
-        })
+        Ok(ValidationResult::from(result))
     }
 }
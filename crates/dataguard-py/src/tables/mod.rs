@@ -1,5 +1,7 @@
+pub mod arrow_table;
 pub mod csv_table;
 pub mod parquet_table;
 
+pub use arrow_table::ArrowTable;
 pub use csv_table::CsvTable;
 pub use parquet_table::ParquetTable;
@@ -0,0 +1,60 @@
+import dataguard
+import pandas as pd
+import pyarrow as pa
+import pytest
+
+
+def test_arrow_table_from_pyarrow():
+    data = pa.table(
+        {
+            "name": ["alice", "bo", "charlie", None],
+            "age": pa.array([25, 150, 40, 30], type=pa.int32()),
+        }
+    )
+
+    name = dataguard.string_column("name").with_min_length(3).is_not_null()
+    age = dataguard.integer_column("age").between(0, 120)
+    table = dataguard.ArrowTable(data, "users")
+    table.prepare([name, age])
+
+    result = table.validate()
+    assert result.total_rows == 4
+    rules = {
+        (column, rule.rule_name): rule
+        for column, column_rules in result.column_results().items()
+        for rule in column_rules
+    }
+    assert rules[("name", "WithMinLength")].error_count == 1
+    assert rules[("name", "NullCheck")].error_count == 1
+    # int32 values are widened to int64
+    assert rules[("age", "Between")].error_count == 1
+
+
+def test_arrow_table_statistical_rules():
+    data = pa.table({"age": pa.array([30] * 9 + [300], type=pa.int32())})
+
+    age = dataguard.integer_column("age").no_outliers_beyond(2.0)
+    table = dataguard.ArrowTable(data, "users")
+    table.prepare([age])
+
+    result = table.validate()
+    rules = {rule.rule_name: rule for rule in result.column_results()["age"]}
+    # Statistical rules run on typed columns, which skip the type check
+    assert rules["NoOutliersBeyond"].error_count == 1
+
+def test_arrow_table_from_pandas():
+    df = pd.DataFrame({"name": ["alice", "bo"]})
+    if not hasattr(df, "__arrow_c_stream__"):
+        pytest.skip("pandas >= 2.2 is needed to export Arrow streams")
+
+    table = dataguard.ArrowTable(df, "users")
+    table.prepare([dataguard.string_column("name").with_min_length(3)])
+
+    result = table.validate()
+    assert result.total_rows == 2
+    assert result.passed == (0, 1)
+
+
+def test_arrow_table_rejects_other_objects():
+    with pytest.raises(TypeError, match="__arrow_c_stream__"):
+        dataguard.ArrowTable({"name": ["alice"]}, "users")
@@ -22,7 +22,7 @@ def test_unicity(tmp_path):
     result = table.validate()
 
     # Check that the validation failed (passed[0] < passed[1])
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total, f"Expected validation to fail but got {passed}/{total}"
 
 
@@ -60,7 +60,7 @@ def test_unicity_all_unique(tmp_path):
     result = table.validate()
 
     # Should pass all rules
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed == total, f"Expected all rules to pass but got {passed}/{total}"
 
 
@@ -79,5 +79,48 @@ def test_unicity_all_duplicates(tmp_path):
     result = table.validate()
 
     # Should fail validation
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total, f"Expected validation to fail but got {passed}/{total}"
+
+
+def test_structured_results(tmp_path):
+    data = {"name": ["alice", "bo", "charlie", "al"]}
+
+    csv_path = tmp_path / "test.csv"
+    pd.DataFrame(data).to_csv(csv_path, index=False)
+
+    col = dataguard.string_column("name").with_min_length(3)
+
+    table = dataguard.CsvTable(str(csv_path), "test_table")
+    table.prepare([col])
+
+    result = table.validate()
+
+    assert result.table_name == "test_table"
+    assert result.total_rows == 4
+    assert result.relation_results() == {}
+    rules = result.column_results()["name"]
+    failed = [rule for rule in rules if not rule.passed]
+    assert len(failed) == 1
+    assert failed[0].error_count == 2
+    assert failed[0].error_percentage == 50.0
+
+
+def test_unique_within_group(tmp_path):
+    data = {
+        "tenant_id": [1, 2, 1, 1],
+        "email": ["a@x.com", "a@x.com", "b@x.com", "a@x.com"],
+    }
+    # "a@x.com" is repeated across tenants, but only once within tenant 1
+
+    csv_path = tmp_path / "test.csv"
+    pd.DataFrame(data).to_csv(csv_path, index=False)
+
+    col = dataguard.string_column("email").is_unique_within("tenant_id")
+
+    table = dataguard.CsvTable(str(csv_path), "test_table")
+    table.prepare([col])
+
+    result = table.validate()
+    rules = {rule.rule_name: rule for rule in result.column_results()["email"]}
+    assert rules["UniqueWithinGroup"].error_count == 1
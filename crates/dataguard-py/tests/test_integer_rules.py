@@ -16,7 +16,7 @@ def test_between_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     # Should fail because there are violations
     assert passed < total
 
@@ -34,7 +34,7 @@ def test_min_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -51,7 +51,7 @@ def test_max_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -68,7 +68,7 @@ def test_is_positive_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -85,7 +85,7 @@ def test_is_negative_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -102,7 +102,7 @@ def test_is_non_positive_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -119,7 +119,7 @@ def test_is_non_negative_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -136,7 +136,7 @@ def test_is_monotonically_increasing_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -153,5 +153,5 @@ def test_is_monotonically_decreasing_integer(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
@@ -15,7 +15,7 @@ def test_is_numeric(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -30,7 +30,7 @@ def test_is_alpha(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -45,7 +45,7 @@ def test_is_alphanumeric(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -62,7 +62,7 @@ def test_is_lowercase(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -79,10 +79,39 @@ def test_is_uppercase(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
+def test_is_uppercase_unicode(tmp_path):
+    data = {"name_col": ["ÉLODIE", "JOSÉ", "ÅSA", "Zoë"]}
+    csv_path = tmp_path / "test.csv"
+    pd.DataFrame(data).to_csv(csv_path, index=False)
+
+    col = dataguard.string_column("name_col").is_uppercase(unicode=True)
+    table = dataguard.CsvTable(str(csv_path), "test_table")
+    table.prepare([col])
+
+    result = table.validate()
+    rules = {rule.rule_name: rule for rule in result.column_results()["name_col"]}
+    # Only "Zoë" fails, accented capitals are uppercase
+    assert rules["IsUpperCase"].error_count == 1
+
+
+def test_has_no_surrounding_whitespace(tmp_path):
+    data = {"name_col": ["alice", " bob", "carol ", "dan smith"]}
+    csv_path = tmp_path / "test.csv"
+    pd.DataFrame(data).to_csv(csv_path, index=False)
+
+    col = dataguard.string_column("name_col").has_no_surrounding_whitespace()
+    table = dataguard.CsvTable(str(csv_path), "test_table")
+    table.prepare([col])
+
+    result = table.validate()
+    rules = {rule.rule_name: rule for rule in result.column_results()["name_col"]}
+    assert rules["NoSurroundingWhitespace"].error_count == 2
+
+
 def test_with_length_between(tmp_path):
     data = {"col": ["abc", "abcd", "abcde", "ab", "abcdef", "", None]}
     # Violations: "ab" (too short), "abcdef" (too long), "" (too short), None (null).
@@ -94,7 +123,7 @@ def test_with_length_between(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -109,7 +138,7 @@ def test_with_min_length(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -124,7 +153,7 @@ def test_with_max_length(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -139,7 +168,7 @@ def test_with_regex(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -167,7 +196,7 @@ def test_is_url(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -196,10 +225,37 @@ def test_is_email(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
+def test_is_email_strict(tmp_path):
+    data = {
+        "email_col": [
+            "test@example.com",
+            "first.last@sub.example.co.uk",
+            "a@b",  # no TLD
+            "user@domain..com",  # empty label
+            "user@-domain.com",  # label starting with a hyphen
+            "first..last@example.com",  # repeated dot
+        ]
+    }
+    csv_path = tmp_path / "test.csv"
+    pd.DataFrame(data).to_csv(csv_path, index=False)
+
+    lenient = dataguard.string_column("email_col").is_email()
+    table = dataguard.CsvTable(str(csv_path), "test_table")
+    table.prepare([lenient])
+    rules = {rule.rule_name: rule for rule in table.validate().column_results()["email_col"]}
+    assert rules["IsEmail"].error_count == 1
+
+    strict = dataguard.string_column("email_col").is_email(strict=True)
+    table = dataguard.CsvTable(str(csv_path), "test_table")
+    table.prepare([strict])
+    rules = {rule.rule_name: rule for rule in table.validate().column_results()["email_col"]}
+    assert rules["IsEmail"].error_count == 4
+
+
 def test_is_uuid(tmp_path):
     data = {
         "uuid_col": [
@@ -224,7 +280,7 @@ def test_is_uuid(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
 
 
@@ -251,5 +307,5 @@ def test_is_in_check(tmp_path):
     table.prepare([col])
 
     result = table.validate()
-    passed, total = result["passed"]
+    passed, total = result.passed
     assert passed < total
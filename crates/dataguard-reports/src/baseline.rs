@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Error;
+
+use dataguard_core::{RuleResult, ValidationResult};
+
+/// Identifies a rule across runs: its table, the column, relation, group or foreign key it
+/// runs on, `None` for table-level rules, and its name
+type RuleKey = (String, Option<String>, String);
+
+/// Error count of one rule, an entry of the baseline file
+#[derive(Serialize, Deserialize)]
+struct BaselineEntry {
+    table: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    column: Option<String>,
+    rule: String,
+    error_count: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    rules: Vec<BaselineEntry>,
+}
+
+/// A rule whose error count exceeds the accepted baseline
+#[derive(Debug, PartialEq)]
+pub struct Regression {
+    pub table: String,
+    pub column: Option<String>,
+    pub rule: String,
+    /// Errors accepted by the baseline, 0 for rules it does not hold
+    pub baseline: usize,
+    pub error_count: usize,
+}
+
+impl Regression {
+    /// Errors above the baseline
+    pub fn excess(&self) -> usize {
+        self.error_count - self.baseline
+    }
+}
+
+/// Error counts accepted per rule, recorded by `--write-baseline`.
+///
+/// Later runs only fail on the rules whose error count grew past the recorded one, known
+/// issues of legacy data are accepted until they get worse.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    counts: BTreeMap<RuleKey, usize>,
+}
+
+impl Baseline {
+    /// Record the error count of every rule of `results`. Informative rules, whose name
+    /// ends with `Skipped`, are left out.
+    pub fn from_results(results: &[ValidationResult]) -> Self {
+        let mut counts = BTreeMap::new();
+        for result in results {
+            for_each_rule(result, |key, rule| {
+                counts.insert(key, rule.error_count);
+            });
+        }
+        Self { counts }
+    }
+
+    pub fn to_json(&self) -> Result<String, Error> {
+        let rules = self
+            .counts
+            .iter()
+            .map(|((table, column, rule), error_count)| BaselineEntry {
+                table: table.clone(),
+                column: column.clone(),
+                rule: rule.clone(),
+                error_count: *error_count,
+            })
+            .collect();
+        serde_json::to_string_pretty(&BaselineFile { rules })
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        let file: BaselineFile = serde_json::from_str(json)?;
+        let counts = file
+            .rules
+            .into_iter()
+            .map(|entry| ((entry.table, entry.column, entry.rule), entry.error_count))
+            .collect();
+        Ok(Self { counts })
+    }
+
+    /// Failed rules of `results` holding more errors than the baseline accepts, in table,
+    /// column and rule order. Rules passing their threshold are never regressions, nor
+    /// are rules whose errors stayed at or under their baseline.
+    pub fn regressions(&self, results: &[ValidationResult]) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for result in results {
+            for_each_rule(result, |key, rule| {
+                let baseline = self.counts.get(&key).copied().unwrap_or(0);
+                if !rule.pass && rule.error_count > baseline {
+                    let (table, column, rule_name) = key;
+                    regressions.push(Regression {
+                        table,
+                        column,
+                        rule: rule_name,
+                        baseline,
+                        error_count: rule.error_count,
+                    });
+                }
+            });
+        }
+        regressions
+            .sort_by(|a, b| (&a.table, &a.column, &a.rule).cmp(&(&b.table, &b.column, &b.rule)));
+        regressions
+    }
+}
+
+/// Call `f` with the key of every rule of `result`
+fn for_each_rule(result: &ValidationResult, mut f: impl FnMut(RuleKey, &RuleResult)) {
+    let table = &result.table_name;
+    for rule in result.get_table_results() {
+        f((table.clone(), None, rule.rule_name.clone()), rule);
+    }
+    let named = result
+        .get_column_results()
+        .into_iter()
+        .chain(result.get_relation_results())
+        .flat_map(|(name, rules)| rules.into_iter().map(move |rule| (name.clone(), rule)))
+        .chain(
+            result
+                .get_group_results()
+                .iter()
+                .map(|g| (g.name.clone(), &g.result)),
+        )
+        .chain(
+            result
+                .get_foreign_key_results()
+                .iter()
+                .map(|fk| (fk.name.clone(), &fk.result)),
+        );
+    for (name, rule) in named {
+        if rule.rule_name.ends_with("Skipped") {
+            continue;
+        }
+        f((table.clone(), Some(name), rule.rule_name.clone()), rule);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn users(email_errors: usize, name_errors: usize) -> ValidationResult {
+        let mut users = ValidationResult::new("users".to_string(), 100);
+        users.add_column_result(
+            "email".to_string(),
+            vec![RuleResult::new(
+                "IsEmail".to_string(),
+                email_errors,
+                email_errors as f64,
+                0.0,
+                None,
+                email_errors == 0,
+            )],
+        );
+        users.add_column_result(
+            "name".to_string(),
+            vec![RuleResult::new(
+                "NullCheck".to_string(),
+                name_errors,
+                name_errors as f64,
+                5.0,
+                None,
+                name_errors <= 5,
+            )],
+        );
+        users.add_table_result(RuleResult::new(
+            "MinRows".to_string(),
+            20,
+            16.67,
+            0.0,
+            None,
+            false,
+        ));
+        users
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline::from_results(&[users(10, 0)]);
+        let json = baseline.to_json().unwrap();
+        assert!(json.contains(r#""column": "email""#));
+        assert_eq!(Baseline::from_json(&json).unwrap(), baseline);
+        assert!(Baseline::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_baseline_regressions() {
+        let baseline = Baseline::from_results(&[users(10, 0)]);
+        // Known issues do not fail the run
+        assert!(baseline.regressions(&[users(10, 0)]).is_empty());
+        // A rule within its threshold is not a regression, even above its baseline
+        assert!(baseline.regressions(&[users(4, 3)]).is_empty());
+
+        let regressions = baseline.regressions(&[users(12, 8)]);
+        assert_eq!(
+            regressions,
+            vec![
+                Regression {
+                    table: "users".to_string(),
+                    column: Some("email".to_string()),
+                    rule: "IsEmail".to_string(),
+                    baseline: 10,
+                    error_count: 12,
+                },
+                Regression {
+                    table: "users".to_string(),
+                    column: Some("name".to_string()),
+                    rule: "NullCheck".to_string(),
+                    baseline: 0,
+                    error_count: 8,
+                },
+            ]
+        );
+        assert_eq!(regressions[0].excess(), 2);
+
+        // Rules missing from the baseline accept no error
+        let regressions = Baseline::default().regressions(&[users(0, 0)]);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].rule, "MinRows");
+        assert_eq!(regressions[0].column, None);
+    }
+}
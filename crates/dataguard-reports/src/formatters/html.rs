@@ -0,0 +1,238 @@
+use std::cell::Cell;
+
+use chrono::Local;
+
+use dataguard_core::{RuleResult, ValidationResult};
+
+use crate::{formatters::TABLE_TARGET, utils::numbers::format_observed, Reporter};
+
+/// Inlined so the report is a single file that can be mailed or attached as is
+const STYLE: &str = "body{font-family:-apple-system,'Segoe UI',Helvetica,Arial,sans-serif;margin:2rem;color:#24292f}\
+header{border-bottom:1px solid #d0d7de;margin-bottom:1rem}\
+.summary span{display:inline-block;margin-right:1.5rem;font-weight:600}\
+.pass{color:#1a7f37}.fail{color:#cf222e}\
+details{border:1px solid #d0d7de;border-radius:6px;margin-bottom:1rem;padding:.5rem 1rem}\
+summary{cursor:pointer;font-size:1.1rem;font-weight:600}\
+summary small{font-weight:400;color:#57606a;margin-left:.5rem}\
+table{border-collapse:collapse;width:100%;margin-top:.75rem}\
+th,td{text-align:left;padding:.35rem .6rem;border-bottom:1px solid #d0d7de}\
+td.num{text-align:right}\
+tr.pass{background:#dafbe1}tr.fail{background:#ffebe9}\
+.error{color:#cf222e;margin:.5rem 0}";
+
+/// Report validation results as a self-contained HTML page.
+///
+/// Each table is a collapsible section, failing tables are expanded by default.
+pub struct HtmlFormatter {
+    version: String,
+    timestamp: String,
+    timestamp_compact: String,
+    tables: Vec<String>,
+    // `on_complete` only borrows the formatter
+    summary: Cell<Option<(usize, usize)>>,
+}
+
+impl HtmlFormatter {
+    pub fn new(version: String) -> Self {
+        let now = Local::now();
+        let timestamp = now.format("%Y-%m-%d %H:%M:%S").to_string();
+        let timestamp_compact = now.format("%Y%m%d-%H%M%S").to_string();
+        Self {
+            version,
+            timestamp,
+            timestamp_compact,
+            tables: Vec::new(),
+            summary: Cell::new(None),
+        }
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        html.push_str("<meta charset=\"utf-8\">\n<title>DataGuard validation report</title>\n");
+        html.push_str(&format!("<style>{}</style>\n</head>\n<body>\n", STYLE));
+        html.push_str(&format!(
+            "<header>\n<h1>DataGuard validation report</h1>\n<p>DataGuard v{} - {}</p>\n",
+            escape(&self.version),
+            self.timestamp
+        ));
+        if let Some((passed, failed)) = self.summary.get() {
+            html.push_str(&format!(
+                "<p class=\"summary\"><span class=\"pass\">{} passed</span><span class=\"fail\">{} failed</span></p>\n",
+                passed, failed
+            ));
+        }
+        html.push_str("</header>\n");
+        for table in &self.tables {
+            html.push_str(table);
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    pub fn get_timestamp_compact(&self) -> &str {
+        &self.timestamp_compact
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn status_class(pass: bool) -> &'static str {
+    if pass {
+        "pass"
+    } else {
+        "fail"
+    }
+}
+
+fn rule_row(target: &str, rule: &RuleResult) -> String {
+    let mut status = if rule.pass { "PASS" } else { "FAIL" }.to_string();
+    if !rule.observed.is_empty() {
+        status.push_str(&format!(" (observed {})", format_observed(&rule.observed)));
+    }
+    let (errors, percentage) = match &rule.error_message {
+        Some(msg) => {
+            status.push_str(&format!(" ({})", msg));
+            ("-".to_string(), "-".to_string())
+        }
+        None => (
+            rule.error_count.to_string(),
+            format!("{:.2}%", rule.error_percentage),
+        ),
+    };
+    format!(
+        "<tr class=\"{}\"><td>{}</td><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{:.2}%</td><td>{}</td></tr>\n",
+        status_class(rule.pass),
+        escape(target),
+        escape(rule.display_name()),
+        errors,
+        percentage,
+        rule.threshold,
+        escape(&status)
+    )
+}
+
+fn schema_row(column: &str, rule_name: &str) -> String {
+    format!(
+        "<tr class=\"fail\"><td>{}</td><td>{}</td><td class=\"num\">-</td><td class=\"num\">-</td><td class=\"num\">-</td><td>FAIL</td></tr>\n",
+        escape(column),
+        rule_name
+    )
+}
+
+impl Reporter for HtmlFormatter {
+    fn on_start(&self) {}
+
+    fn on_loading(&self) {}
+
+    fn on_table_load(&self, _current: usize, _total: usize, _name: &str) {}
+
+    fn on_validation_start(&self) {}
+
+    fn on_table_result(&mut self, result: &ValidationResult) {
+        let (pass, total) = result.is_passed();
+        let passed = pass == total;
+        let mut html = format!(
+            "<details{}>\n<summary class=\"{}\">{} <small>{} rows{}{}, {}/{} rules passed</small></summary>\n",
+            if passed { "" } else { " open" },
+            status_class(passed),
+            escape(&result.table_name),
+            result.total_rows,
+            if result.sampled { " (sample)" } else { "" },
+            if result.aborted {
+                " (aborted at the error limit)"
+            } else {
+                ""
+            },
+            pass,
+            total
+        );
+        if let Some(msg) = &result.error_message {
+            html.push_str(&format!("<p class=\"error\">{}</p>\n", escape(msg)));
+        }
+        html.push_str("<table>\n<thead><tr><th>Column</th><th>Rule</th><th>Errors</th><th>Percentage</th><th>Threshold</th><th>Status</th></tr></thead>\n<tbody>\n");
+
+        for column in result.get_missing_columns() {
+            html.push_str(&schema_row(column, "MissingColumn"));
+        }
+        for column in result.get_unexpected_columns() {
+            html.push_str(&schema_row(column, "UnexpectedColumn"));
+        }
+
+        for rule in result.get_table_results() {
+            html.push_str(&rule_row(TABLE_TARGET, rule));
+        }
+
+        // Results are stored in hash maps, sort them so reports are stable between runs
+        let mut columns: Vec<_> = result.get_column_results().into_iter().collect();
+        columns.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut relations: Vec<_> = result.get_relation_results().into_iter().collect();
+        relations.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (name, rules) in columns.iter().chain(relations.iter()) {
+            for rule in rules {
+                html.push_str(&rule_row(name, rule));
+            }
+        }
+        for g in result.get_group_results() {
+            html.push_str(&rule_row(&g.name, &g.result));
+        }
+        for fk in result.get_foreign_key_results() {
+            html.push_str(&rule_row(&fk.name, &fk.result));
+        }
+        html.push_str("</tbody>\n</table>\n</details>\n");
+        self.tables.push(html);
+    }
+
+    fn on_complete(&self, passed: usize, failed: usize) {
+        self.summary.set(Some((passed, failed)));
+    }
+
+    fn on_waiting(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_report() {
+        let mut result = ValidationResult::new("users".to_string(), 10);
+        result.add_column_result(
+            "name".to_string(),
+            vec![
+                RuleResult::new("IsUnique".to_string(), 0, 0.0, 0.0, None, true),
+                RuleResult::new("WithRegex".to_string(), 3, 30.0, 10.0, None, false),
+            ],
+        );
+        result.add_schema_errors(vec!["<email>".to_string()], vec![]);
+
+        let mut formatter = HtmlFormatter::new("0.1.0".to_string());
+        formatter.on_table_result(&result);
+        formatter.on_complete(0, 1);
+        let html = formatter.to_html();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html
+            .contains("<span class=\"pass\">0 passed</span><span class=\"fail\">1 failed</span>"));
+        assert!(html.contains("<details open>\n<summary class=\"fail\">users <small>10 rows, 1/3 rules passed</small>"));
+        assert!(html.contains("<tr class=\"fail\"><td>name</td><td>WithRegex</td><td class=\"num\">3</td><td class=\"num\">30.00%</td>"));
+        assert!(html.contains("<tr class=\"pass\"><td>name</td><td>IsUnique</td>"));
+        assert!(html.contains("<td>&lt;email&gt;</td><td>MissingColumn</td>"));
+        // Self-contained, no external stylesheet or script
+        assert!(!html.contains("<link"));
+        assert!(!html.contains("<script"));
+    }
+}
@@ -1,7 +1,11 @@
+use std::collections::BTreeMap;
+
 use chrono::Local;
 use serde::{Deserialize, Serialize};
 use serde_json::Error;
 
+use dataguard_core::{ColumnProfile, RuleResult, ValueCounts};
+
 use crate::Reporter;
 
 #[derive(Serialize, Deserialize)]
@@ -20,10 +24,47 @@ struct TableFormatter {
     name: String,
     n_rows: usize,
     pass: bool,
+    /// Seconds spent reading the file
+    #[serde(default)]
+    read_duration: f64,
+    /// Seconds spent running the rules
+    #[serde(default)]
+    validation_duration: f64,
+    #[serde(default)]
+    rows_per_second: f64,
+    /// Only the first rows were validated, see `--sample`
+    #[serde(default)]
+    sampled: bool,
+    /// Validation stopped at the error limit, see `--max-errors`
+    #[serde(default)]
+    aborted: bool,
+    /// How the file was read, see `--mode`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    read_mode: Option<String>,
+    /// Why the file was not read with the requested mode
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    read_note: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    missing_columns: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    unexpected_columns: Vec<String>,
+    /// Checks on the table as a whole, such as its row count
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    table_rules: Vec<RuleFormatter>,
     #[serde(skip_serializing_if = "Option::is_none")]
     columns: Option<Vec<ColumnFomatter>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     relations: Option<Vec<RelationFormatter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    groups: Option<Vec<GroupFormatter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foreign_keys: Option<Vec<ForeignKeyFormatter>>,
+    /// Column summaries, see `--profile`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    profile: Vec<ProfileFormatter>,
+    /// Most frequent values of columns, see `--value-counts`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    value_counts: Vec<ValueCountsFormatter>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -38,15 +79,138 @@ struct RelationFormatter {
     rules: Vec<RuleFormatter>,
 }
 
+#[derive(Serialize, Deserialize)]
+struct GroupFormatter {
+    name: String,
+    rule: RuleFormatter,
+    mismatches: Vec<GroupMismatchFormatter>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct GroupMismatchFormatter {
+    group: String,
+    expected: f64,
+    actual: f64,
+    delta: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ForeignKeyFormatter {
+    name: String,
+    rule: RuleFormatter,
+    orphans: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ProfileFormatter {
+    name: String,
+    null_count: usize,
+    distinct: usize,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    min: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    max: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    mean: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    min_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    max_length: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ValueCountsFormatter {
+    name: String,
+    values: Vec<ValueCountFormatter>,
+    other: usize,
+    null_count: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ValueCountFormatter {
+    value: String,
+    count: usize,
+    percentage: f64,
+}
+
+impl ValueCountsFormatter {
+    fn new(counts: &ValueCounts, total_rows: usize) -> Self {
+        ValueCountsFormatter {
+            name: counts.name.clone(),
+            values: counts
+                .values
+                .iter()
+                .map(|(value, count)| ValueCountFormatter {
+                    value: value.clone(),
+                    count: *count,
+                    percentage: ValueCounts::percentage(*count, total_rows),
+                })
+                .collect(),
+            other: counts.other,
+            null_count: counts.null_count,
+        }
+    }
+}
+
+impl From<&ColumnProfile> for ProfileFormatter {
+    fn from(p: &ColumnProfile) -> Self {
+        ProfileFormatter {
+            name: p.name.clone(),
+            null_count: p.null_count,
+            distinct: p.distinct,
+            min: p.min,
+            max: p.max,
+            mean: p.mean,
+            min_length: p.min_length,
+            max_length: p.max_length,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 struct RuleFormatter {
     name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
     errors: usize,
     error_percent: f64,
     threshold: f64,
     pass: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     error_message: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    samples: Vec<SampleFormatter>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    observed: BTreeMap<String, f64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SampleFormatter {
+    row: usize,
+    value: String,
+}
+
+impl From<&RuleResult> for RuleFormatter {
+    fn from(r: &RuleResult) -> Self {
+        RuleFormatter {
+            name: r.rule_name.clone(),
+            label: r.label.clone(),
+            errors: r.error_count,
+            error_percent: r.error_percentage,
+            error_message: r.error_message.to_owned(),
+            threshold: r.threshold,
+            pass: r.pass,
+            samples: r
+                .samples
+                .iter()
+                .map(|(row, value)| SampleFormatter {
+                    row: *row,
+                    value: value.clone(),
+                })
+                .collect(),
+            observed: r.observed.iter().cloned().collect(),
+        }
+    }
 }
 
 impl JsonFormatter {
@@ -94,17 +258,8 @@ impl Reporter for JsonFormatter {
                     .get_column_results()
                     .into_iter()
                     .map(|(n, c)| {
-                        let rules: Vec<RuleFormatter> = c
-                            .into_iter()
-                            .map(|r| RuleFormatter {
-                                name: r.rule_name.clone(),
-                                errors: r.error_count,
-                                error_percent: r.error_percentage,
-                                error_message: r.error_message.to_owned(),
-                                threshold: r.threshold,
-                                pass: r.pass,
-                            })
-                            .collect();
+                        let rules: Vec<RuleFormatter> =
+                            c.into_iter().map(RuleFormatter::from).collect();
                         ColumnFomatter { name: n, rules }
                     })
                     .collect(),
@@ -122,31 +277,87 @@ impl Reporter for JsonFormatter {
                     relation_results
                         .into_iter()
                         .map(|(n, c)| {
-                            let rules: Vec<RuleFormatter> = c
-                                .into_iter()
-                                .map(|r| RuleFormatter {
-                                    name: r.rule_name.clone(),
-                                    errors: r.error_count,
-                                    error_percent: r.error_percentage,
-                                    error_message: r.error_message.to_owned(),
-                                    threshold: r.threshold,
-                                    pass: r.pass,
-                                })
-                                .collect();
+                            let rules: Vec<RuleFormatter> =
+                                c.into_iter().map(RuleFormatter::from).collect();
                             RelationFormatter { name: n, rules }
                         })
                         .collect(),
                 )
             }
         };
+        let groups = if self.brief || result.get_group_results().is_empty() {
+            None
+        } else {
+            Some(
+                result
+                    .get_group_results()
+                    .iter()
+                    .map(|g| GroupFormatter {
+                        name: g.name.clone(),
+                        rule: RuleFormatter::from(&g.result),
+                        mismatches: g
+                            .mismatches
+                            .iter()
+                            .map(|m| GroupMismatchFormatter {
+                                group: m.group.clone(),
+                                expected: m.expected,
+                                actual: m.actual,
+                                delta: m.delta(),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+            )
+        };
+        let foreign_keys = if self.brief || result.get_foreign_key_results().is_empty() {
+            None
+        } else {
+            Some(
+                result
+                    .get_foreign_key_results()
+                    .iter()
+                    .map(|fk| ForeignKeyFormatter {
+                        name: fk.name.clone(),
+                        rule: RuleFormatter::from(&fk.result),
+                        orphans: fk.orphans.clone(),
+                    })
+                    .collect(),
+            )
+        };
         let (pass, total) = result.is_passed();
         let pass = pass == total;
 
         let table = TableFormatter {
             name,
             n_rows,
+            read_duration: result.read_duration.as_secs_f64(),
+            validation_duration: result.validation_duration.as_secs_f64(),
+            rows_per_second: result.rows_per_second(),
+            sampled: result.sampled,
+            aborted: result.aborted,
+            read_mode: result.read_mode.map(|mode| mode.to_string()),
+            read_note: result.read_note.clone(),
+            missing_columns: result.get_missing_columns().to_vec(),
+            unexpected_columns: result.get_unexpected_columns().to_vec(),
+            table_rules: result
+                .get_table_results()
+                .iter()
+                .map(RuleFormatter::from)
+                .collect(),
             columns,
             relations,
+            groups,
+            foreign_keys,
+            profile: result
+                .get_profiles()
+                .iter()
+                .map(ProfileFormatter::from)
+                .collect(),
+            value_counts: result
+                .get_value_counts()
+                .iter()
+                .map(|counts| ValueCountsFormatter::new(counts, result.total_rows))
+                .collect(),
             pass,
         };
         self.tables.push(table);
@@ -156,3 +367,78 @@ impl Reporter for JsonFormatter {
 
     fn on_waiting(&self) {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dataguard_core::ValidationResult;
+
+    #[test]
+    fn test_json_profile() {
+        let mut result = ValidationResult::new("users".to_string(), 4);
+        result.add_profiles(vec![ColumnProfile {
+            name: "age".to_string(),
+            null_count: 1,
+            distinct: 3,
+            min: Some(25.0),
+            max: Some(41.0),
+            mean: Some(32.0),
+            ..Default::default()
+        }]);
+
+        let mut formatter = JsonFormatter::new("0.1.0".to_string(), true);
+        formatter.on_table_result(&result);
+        let json: serde_json::Value = serde_json::from_str(&formatter.to_json().unwrap()).unwrap();
+
+        let profile = &json["tables"][0]["profile"][0];
+        assert_eq!(profile["name"], "age");
+        assert_eq!(profile["null_count"], 1);
+        assert_eq!(profile["mean"], 32.0);
+        // Length bounds only apply to string columns
+        assert!(profile.get("min_length").is_none());
+    }
+
+    #[test]
+    fn test_json_value_counts() {
+        let mut result = ValidationResult::new("orders".to_string(), 8);
+        result.add_value_counts(vec![ValueCounts {
+            name: "status".to_string(),
+            values: vec![("active".to_string(), 4), ("closed".to_string(), 2)],
+            other: 1,
+            null_count: 1,
+        }]);
+
+        let mut formatter = JsonFormatter::new("0.1.0".to_string(), true);
+        formatter.on_table_result(&result);
+        let json: serde_json::Value = serde_json::from_str(&formatter.to_json().unwrap()).unwrap();
+
+        let counts = &json["tables"][0]["value_counts"][0];
+        assert_eq!(counts["name"], "status");
+        assert_eq!(counts["values"][0]["value"], "active");
+        assert_eq!(counts["values"][0]["count"], 4);
+        assert_eq!(counts["values"][0]["percentage"], 50.0);
+        assert_eq!(counts["other"], 1);
+        assert_eq!(counts["null_count"], 1);
+        // The profile is only reported when asked for
+        assert!(json["tables"][0].get("profile").is_none());
+    }
+
+    #[test]
+    fn test_json_rule_label() {
+        let mut result = ValidationResult::new("customers".to_string(), 10);
+        let mut labelled = RuleResult::new("WithMinLength".to_string(), 2, 20.0, 0.0, None, false);
+        labelled.label = Some("Customer name too short".to_string());
+        let plain = RuleResult::new("NullCheck".to_string(), 0, 0.0, 0.0, None, true);
+        result.add_column_result("name".to_string(), vec![labelled, plain]);
+
+        let mut formatter = JsonFormatter::new("0.1.0".to_string(), false);
+        formatter.on_table_result(&result);
+        let json: serde_json::Value = serde_json::from_str(&formatter.to_json().unwrap()).unwrap();
+
+        let rules = &json["tables"][0]["columns"][0]["rules"];
+        assert_eq!(rules[0]["name"], "WithMinLength");
+        assert_eq!(rules[0]["label"], "Customer name too short");
+        assert_eq!(rules[1]["name"], "NullCheck");
+        assert!(rules[1].get("label").is_none());
+    }
+}
@@ -0,0 +1,239 @@
+use chrono::Local;
+
+use dataguard_core::{RuleResult, ValidationResult};
+
+use crate::{formatters::TABLE_TARGET, utils::numbers::format_observed, Reporter};
+
+/// Report validation results as JUnit XML, for CI test panels.
+///
+/// Each table is a `<testsuite>` and each rule a `<testcase>`, failing rules
+/// carry a `<failure>` element with their error count and percentage.
+pub struct JUnitFormatter {
+    version: String,
+    timestamp: String,
+    timestamp_compact: String,
+    suites: Vec<TestSuite>,
+}
+
+struct TestSuite {
+    name: String,
+    cases: Vec<TestCase>,
+}
+
+struct TestCase {
+    classname: String,
+    name: String,
+    failure: Option<String>,
+}
+
+impl TestCase {
+    fn new(table: &str, target: &str, rule: &RuleResult) -> Self {
+        let failure = if let Some(msg) = &rule.error_message {
+            Some(msg.clone())
+        } else if !rule.pass {
+            let mut msg = format!(
+                "{} errors ({:.2}%) exceed threshold of {:.2}%",
+                rule.error_count, rule.error_percentage, rule.threshold
+            );
+            if !rule.observed.is_empty() {
+                msg.push_str(&format!(", observed {}", format_observed(&rule.observed)));
+            }
+            Some(msg)
+        } else {
+            None
+        };
+        Self {
+            classname: format!("{}.{}", table, target),
+            name: rule.rule_name.clone(),
+            failure,
+        }
+    }
+
+    fn schema_error(table: &str, column: &str, name: &str, message: &str) -> Self {
+        Self {
+            classname: format!("{}.{}", table, column),
+            name: name.to_string(),
+            failure: Some(message.to_string()),
+        }
+    }
+}
+
+impl JUnitFormatter {
+    pub fn new(version: String) -> Self {
+        let now = Local::now();
+        let timestamp = now.format("%Y-%m-%dT%H:%M:%S").to_string();
+        let timestamp_compact = now.format("%Y%m%d-%H%M%S").to_string();
+        Self {
+            version,
+            timestamp,
+            timestamp_compact,
+            suites: Vec::new(),
+        }
+    }
+
+    pub fn to_xml(&self) -> String {
+        let tests: usize = self.suites.iter().map(|s| s.cases.len()).sum();
+        let failures: usize = self.suites.iter().map(TestSuite::failures).sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites name=\"dataguard {}\" tests=\"{}\" failures=\"{}\" timestamp=\"{}\">\n",
+            escape(&self.version),
+            tests,
+            failures,
+            self.timestamp
+        ));
+        for suite in &self.suites {
+            xml.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+                escape(&suite.name),
+                suite.cases.len(),
+                suite.failures()
+            ));
+            for case in &suite.cases {
+                let attrs = format!(
+                    "classname=\"{}\" name=\"{}\"",
+                    escape(&case.classname),
+                    escape(&case.name)
+                );
+                match &case.failure {
+                    Some(msg) => {
+                        xml.push_str(&format!("    <testcase {}>\n", attrs));
+                        xml.push_str(&format!("      <failure message=\"{}\"/>\n", escape(msg)));
+                        xml.push_str("    </testcase>\n");
+                    }
+                    None => xml.push_str(&format!("    <testcase {}/>\n", attrs)),
+                }
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    pub fn get_timestamp_compact(&self) -> &str {
+        &self.timestamp_compact
+    }
+}
+
+impl TestSuite {
+    fn failures(&self) -> usize {
+        self.cases.iter().filter(|c| c.failure.is_some()).count()
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+impl Reporter for JUnitFormatter {
+    fn on_start(&self) {}
+
+    fn on_loading(&self) {}
+
+    fn on_table_load(&self, _current: usize, _total: usize, _name: &str) {}
+
+    fn on_validation_start(&self) {}
+
+    fn on_table_result(&mut self, result: &ValidationResult) {
+        let table = result.table_name.as_str();
+        let mut cases = Vec::new();
+
+        for column in result.get_missing_columns() {
+            cases.push(TestCase::schema_error(
+                table,
+                column,
+                "MissingColumn",
+                "configured column not found in file",
+            ));
+        }
+        for column in result.get_unexpected_columns() {
+            cases.push(TestCase::schema_error(
+                table,
+                column,
+                "UnexpectedColumn",
+                "file column not configured",
+            ));
+        }
+
+        cases.extend(
+            result
+                .get_table_results()
+                .iter()
+                .map(|r| TestCase::new(table, TABLE_TARGET, r)),
+        );
+
+        // Results are stored in hash maps, sort them so reports are stable between runs
+        let mut columns: Vec<_> = result.get_column_results().into_iter().collect();
+        columns.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut relations: Vec<_> = result.get_relation_results().into_iter().collect();
+        relations.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (name, rules) in columns.iter().chain(relations.iter()) {
+            cases.extend(rules.iter().map(|r| TestCase::new(table, name, r)));
+        }
+        for g in result.get_group_results() {
+            cases.push(TestCase::new(table, &g.name, &g.result));
+        }
+        for fk in result.get_foreign_key_results() {
+            cases.push(TestCase::new(table, &fk.name, &fk.result));
+        }
+
+        self.suites.push(TestSuite {
+            name: table.to_string(),
+            cases,
+        });
+    }
+
+    fn on_complete(&self, _passed: usize, _failed: usize) {}
+
+    fn on_waiting(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_junit_failure_and_escaping() {
+        let mut result = ValidationResult::new("users".to_string(), 10);
+        result.add_column_result(
+            "name".to_string(),
+            vec![
+                RuleResult::new("IsUnique".to_string(), 0, 0.0, 0.0, None, true),
+                RuleResult::new("WithRegex".to_string(), 3, 30.0, 10.0, None, false),
+            ],
+        );
+        result.add_column_result(
+            "a<b".to_string(),
+            vec![RuleResult::new(
+                "NullCheck".to_string(),
+                1,
+                10.0,
+                20.0,
+                None,
+                true,
+            )],
+        );
+
+        let mut formatter = JUnitFormatter::new("0.1.0".to_string());
+        formatter.on_table_result(&result);
+        let xml = formatter.to_xml();
+
+        assert!(xml.contains("<testsuite name=\"users\" tests=\"3\" failures=\"1\">"));
+        assert!(xml.contains("<testcase classname=\"users.a&lt;b\" name=\"NullCheck\"/>"));
+        assert!(xml.contains("<testcase classname=\"users.name\" name=\"IsUnique\"/>"));
+        assert!(xml.contains("<failure message=\"3 errors (30.00%) exceed threshold of 10.00%\"/>"));
+    }
+}
@@ -0,0 +1,233 @@
+use std::cell::Cell;
+
+use chrono::Local;
+
+use dataguard_core::{RuleResult, ValidationResult};
+
+use crate::{formatters::TABLE_TARGET, utils::numbers::format_observed, Reporter};
+
+/// Report validation results as GitHub-flavored Markdown.
+///
+/// Each table gets a `## Table` heading followed by one row per rule.
+pub struct MarkdownFormatter {
+    version: String,
+    timestamp: String,
+    timestamp_compact: String,
+    tables: Vec<String>,
+    // `on_complete` only borrows the formatter
+    summary: Cell<Option<(usize, usize)>>,
+}
+
+impl MarkdownFormatter {
+    pub fn new(version: String) -> Self {
+        let now = Local::now();
+        let timestamp = now.format("%Y-%m-%d %H:%M:%S").to_string();
+        let timestamp_compact = now.format("%Y%m%d-%H%M%S").to_string();
+        Self {
+            version,
+            timestamp,
+            timestamp_compact,
+            tables: Vec::new(),
+            summary: Cell::new(None),
+        }
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!(
+            "# DataGuard validation report\n\n_DataGuard v{} - {}_\n",
+            self.version, self.timestamp
+        );
+        for table in &self.tables {
+            md.push('\n');
+            md.push_str(table);
+        }
+        if let Some((passed, failed)) = self.summary.get() {
+            md.push_str(&format!(
+                "\n**Result: {} failed, {} passed**\n",
+                failed, passed
+            ));
+        }
+        md
+    }
+
+    pub fn get_timestamp_compact(&self) -> &str {
+        &self.timestamp_compact
+    }
+}
+
+/// Escape the characters breaking a Markdown table cell
+fn escape(s: &str) -> String {
+    s.replace('|', "\\|").replace('\n', " ")
+}
+
+fn rule_row(target: &str, rule: &RuleResult) -> String {
+    let mut status = if rule.pass { "PASS" } else { "FAIL" }.to_string();
+    if !rule.observed.is_empty() {
+        status.push_str(&format!(" (observed {})", format_observed(&rule.observed)));
+    }
+    match &rule.error_message {
+        Some(msg) => format!(
+            "| {} | {} | - | - | {} ({}) |\n",
+            escape(target),
+            escape(rule.display_name()),
+            status,
+            escape(msg)
+        ),
+        None => format!(
+            "| {} | {} | {} | {:.2}% | {} |\n",
+            escape(target),
+            escape(rule.display_name()),
+            rule.error_count,
+            rule.error_percentage,
+            status
+        ),
+    }
+}
+
+impl Reporter for MarkdownFormatter {
+    fn on_start(&self) {}
+
+    fn on_loading(&self) {}
+
+    fn on_table_load(&self, _current: usize, _total: usize, _name: &str) {}
+
+    fn on_validation_start(&self) {}
+
+    fn on_table_result(&mut self, result: &ValidationResult) {
+        let (pass, total) = result.is_passed();
+        let status = if pass == total { "PASS" } else { "FAIL" };
+        let mut md = format!(
+            "## {}\n\n{} rows{}{}, {}/{} rules passed: **{}**\n\n",
+            result.table_name,
+            result.total_rows,
+            if result.sampled { " (sample)" } else { "" },
+            if result.aborted {
+                " (aborted at the error limit)"
+            } else {
+                ""
+            },
+            pass,
+            total,
+            status
+        );
+        if let Some(msg) = &result.error_message {
+            md.push_str(&format!("> {}\n\n", escape(msg)));
+        }
+        md.push_str("| Column | Rule | Errors | Percentage | Status |\n");
+        md.push_str("| --- | --- | ---: | ---: | --- |\n");
+
+        for column in result.get_missing_columns() {
+            md.push_str(&format!(
+                "| {} | MissingColumn | - | - | FAIL |\n",
+                escape(column)
+            ));
+        }
+        for column in result.get_unexpected_columns() {
+            md.push_str(&format!(
+                "| {} | UnexpectedColumn | - | - | FAIL |\n",
+                escape(column)
+            ));
+        }
+
+        for rule in result.get_table_results() {
+            md.push_str(&rule_row(TABLE_TARGET, rule));
+        }
+
+        // Results are stored in hash maps, sort them so reports are stable between runs
+        let mut columns: Vec<_> = result.get_column_results().into_iter().collect();
+        columns.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut relations: Vec<_> = result.get_relation_results().into_iter().collect();
+        relations.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (name, rules) in columns.iter().chain(relations.iter()) {
+            for rule in rules {
+                md.push_str(&rule_row(name, rule));
+            }
+        }
+        for g in result.get_group_results() {
+            md.push_str(&rule_row(&g.name, &g.result));
+        }
+        for fk in result.get_foreign_key_results() {
+            md.push_str(&rule_row(&fk.name, &fk.result));
+        }
+        self.tables.push(md);
+    }
+
+    fn on_complete(&self, passed: usize, failed: usize) {
+        self.summary.set(Some((passed, failed)));
+    }
+
+    fn on_waiting(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_table() {
+        let mut result = ValidationResult::new("users".to_string(), 10);
+        result.add_column_result(
+            "name".to_string(),
+            vec![RuleResult::new(
+                "WithRegex".to_string(),
+                3,
+                30.0,
+                10.0,
+                None,
+                false,
+            )],
+        );
+
+        let mut formatter = MarkdownFormatter::new("0.1.0".to_string());
+        formatter.on_table_result(&result);
+        formatter.on_complete(0, 1);
+        let md = formatter.to_markdown();
+
+        assert!(md.contains("## users\n"));
+        assert!(md.contains("10 rows, 0/1 rules passed"));
+        assert!(md.contains("| name | WithRegex | 3 | 30.00% | FAIL |\n"));
+        assert!(md.ends_with("**Result: 1 failed, 0 passed**\n"));
+    }
+
+    #[test]
+    fn test_markdown_schema_errors() {
+        let mut result = ValidationResult::new("users".to_string(), 10);
+        result.add_schema_errors(vec!["email".to_string()], vec!["extra".to_string()]);
+
+        let mut formatter = MarkdownFormatter::new("0.1.0".to_string());
+        formatter.on_table_result(&result);
+        let md = formatter.to_markdown();
+
+        assert!(md.contains("0/2 rules passed: **FAIL**"));
+        assert!(md.contains("| email | MissingColumn | - | - | FAIL |\n"));
+        assert!(md.contains("| extra | UnexpectedColumn | - | - | FAIL |\n"));
+    }
+
+    #[test]
+    fn test_markdown_sample_note() {
+        let mut result = ValidationResult::new("users".to_string(), 10);
+        result.sampled = true;
+
+        let mut formatter = MarkdownFormatter::new("0.1.0".to_string());
+        formatter.on_table_result(&result);
+        assert!(formatter
+            .to_markdown()
+            .contains("10 rows (sample), 0/0 rules passed"));
+    }
+
+    #[test]
+    fn test_markdown_table_results() {
+        let mut result = ValidationResult::new("export".to_string(), 3);
+        result.add_table_result(
+            RuleResult::new("MinRows".to_string(), 997, 99.7, 0.0, None, false)
+                .with_observed(vec![("rows".to_string(), 3.0)]),
+        );
+
+        let mut formatter = MarkdownFormatter::new("0.1.0".to_string());
+        formatter.on_table_result(&result);
+        let md = formatter.to_markdown();
+        assert!(md.contains("3 rows, 0/1 rules passed: **FAIL**"));
+        assert!(md.contains("| (table) | MinRows | 997 | 99.70% | FAIL"));
+    }
+}
@@ -1,2 +1,10 @@
+pub mod html;
 pub mod json;
+pub mod junit;
+pub mod markdown;
+pub mod sarif;
 pub mod stdout;
+pub mod summary;
+
+/// Target shown for checks on the table as a whole, such as its row count
+pub(crate) const TABLE_TARGET: &str = "(table)";
@@ -0,0 +1,219 @@
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::Local;
+use serde_json::{json, Error, Value};
+
+use dataguard_core::{RuleResult, ValidationResult};
+
+use crate::{formatters::TABLE_TARGET, utils::numbers::format_observed, Reporter};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Report validation results as SARIF 2.1.0, for code-scanning dashboards.
+///
+/// Only rules with errors are reported: `error` when the threshold is exceeded,
+/// `warning` when the errors stay within it. Results point to the table file.
+pub struct SarifFormatter {
+    version: String,
+    timestamp_compact: String,
+    /// File path of each table, keyed by table name
+    paths: HashMap<String, String>,
+    rule_ids: BTreeSet<String>,
+    results: Vec<Value>,
+}
+
+impl SarifFormatter {
+    pub fn new(version: String, paths: HashMap<String, String>) -> Self {
+        let timestamp_compact = Local::now().format("%Y%m%d-%H%M%S").to_string();
+        Self {
+            version,
+            timestamp_compact,
+            paths,
+            rule_ids: BTreeSet::new(),
+            results: Vec::new(),
+        }
+    }
+
+    pub fn to_sarif(&self) -> Result<String, Error> {
+        let rules: Vec<Value> = self.rule_ids.iter().map(|id| json!({ "id": id })).collect();
+        let sarif = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "dataguard",
+                        "version": self.version,
+                        "rules": rules,
+                    }
+                },
+                "results": self.results,
+            }]
+        });
+        serde_json::to_string_pretty(&sarif)
+    }
+
+    pub fn get_timestamp_compact(&self) -> &str {
+        &self.timestamp_compact
+    }
+
+    fn push_result(
+        &mut self,
+        table: &str,
+        target: &str,
+        rule_name: &str,
+        level: &str,
+        text: String,
+    ) {
+        let rule_id = rule_id(rule_name);
+        let mut location = json!({
+            "logicalLocations": [{
+                "name": target,
+                "fullyQualifiedName": format!("{}.{}", table, target),
+            }]
+        });
+        if let Some(path) = self.paths.get(table) {
+            location["physicalLocation"] = json!({ "artifactLocation": { "uri": path } });
+        }
+        self.results.push(json!({
+            "ruleId": rule_id,
+            "level": level,
+            "message": { "text": text },
+            "locations": [location],
+        }));
+        self.rule_ids.insert(rule_id);
+    }
+
+    fn push_rule(&mut self, table: &str, target: &str, rule: &RuleResult) {
+        let (level, mut text) = match &rule.error_message {
+            Some(msg) => ("error", format!("{}.{}: {}", table, target, msg)),
+            None if rule.error_count == 0 => return,
+            None => (
+                if rule.pass { "warning" } else { "error" },
+                format!(
+                    "{}.{}: {} errors ({:.2}%) for {}, threshold {:.2}%",
+                    table,
+                    target,
+                    rule.error_count,
+                    rule.error_percentage,
+                    rule.rule_name,
+                    rule.threshold
+                ),
+            ),
+        };
+        if !rule.observed.is_empty() {
+            text.push_str(&format!(", observed {}", format_observed(&rule.observed)));
+        }
+        self.push_result(table, target, &rule.rule_name, level, text);
+    }
+}
+
+/// `WithRegex` becomes `dataguard/with_regex`
+fn rule_id(rule_name: &str) -> String {
+    let mut id = String::from("dataguard/");
+    for (i, c) in rule_name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                id.push('_');
+            }
+            id.extend(c.to_lowercase());
+        } else {
+            id.push(c);
+        }
+    }
+    id
+}
+
+impl Reporter for SarifFormatter {
+    fn on_start(&self) {}
+
+    fn on_loading(&self) {}
+
+    fn on_table_load(&self, _current: usize, _total: usize, _name: &str) {}
+
+    fn on_validation_start(&self) {}
+
+    fn on_table_result(&mut self, result: &ValidationResult) {
+        let table = result.table_name.as_str();
+        if let Some(msg) = &result.error_message {
+            let text = format!("{}: {}", table, msg);
+            self.push_result(table, table, "ValidationError", "error", text);
+        }
+        for column in result.get_missing_columns() {
+            let text = format!("{}.{}: configured column not found in file", table, column);
+            self.push_result(table, column, "MissingColumn", "error", text);
+        }
+        for column in result.get_unexpected_columns() {
+            let text = format!("{}.{}: file column not configured", table, column);
+            self.push_result(table, column, "UnexpectedColumn", "error", text);
+        }
+
+        for rule in result.get_table_results() {
+            self.push_rule(table, TABLE_TARGET, rule);
+        }
+
+        // Results are stored in hash maps, sort them so reports are stable between runs
+        let mut columns: Vec<_> = result.get_column_results().into_iter().collect();
+        columns.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut relations: Vec<_> = result.get_relation_results().into_iter().collect();
+        relations.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (name, rules) in columns.iter().chain(relations.iter()) {
+            for rule in rules {
+                self.push_rule(table, name, rule);
+            }
+        }
+        for g in result.get_group_results() {
+            self.push_rule(table, &g.name, &g.result);
+        }
+        for fk in result.get_foreign_key_results() {
+            self.push_rule(table, &fk.name, &fk.result);
+        }
+    }
+
+    fn on_complete(&self, _passed: usize, _failed: usize) {}
+
+    fn on_waiting(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sarif_levels_and_locations() {
+        let mut result = ValidationResult::new("users".to_string(), 10);
+        result.add_column_result(
+            "name".to_string(),
+            vec![
+                RuleResult::new("IsUnique".to_string(), 0, 0.0, 0.0, None, true),
+                RuleResult::new("WithRegex".to_string(), 3, 30.0, 10.0, None, false),
+                RuleResult::new("NullCheck".to_string(), 1, 10.0, 20.0, None, true),
+            ],
+        );
+
+        let paths = HashMap::from([("users".to_string(), "data/users.csv".to_string())]);
+        let mut formatter = SarifFormatter::new("0.1.0".to_string(), paths);
+        formatter.on_table_result(&result);
+        let sarif: Value = serde_json::from_str(&formatter.to_sarif().unwrap()).unwrap();
+
+        let run = &sarif["runs"][0];
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+        let results = run["results"].as_array().unwrap();
+        // Passing rules without errors are not reported
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "dataguard/with_regex");
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(
+            results[0]["message"]["text"],
+            "users.name: 3 errors (30.00%) for WithRegex, threshold 10.00%"
+        );
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "data/users.csv"
+        );
+        assert_eq!(results[1]["ruleId"], "dataguard/null_check");
+        assert_eq!(results[1]["level"], "warning");
+    }
+}
@@ -1,6 +1,9 @@
-use dataguard_core::ValidationResult;
+use dataguard_core::{ColumnProfile, ValidationResult, ValueCounts};
 
-use crate::{utils::numbers::format_numbers, Reporter};
+use crate::{
+    utils::numbers::{format_numbers, format_observed},
+    Reporter,
+};
 
 const MAX_LEN: usize = 30;
 
@@ -40,36 +43,87 @@ impl StdOutFormatter {
             let failed = total - passed;
             format!("FAILED: {}/{} rules valid", failed, total)
         };
-        let rows_formatted = format_numbers(result.total_rows);
+        let mut rows_formatted = format_numbers(result.total_rows);
+        if result.sampled {
+            rows_formatted.push_str(", sample");
+        }
+        if result.aborted {
+            rows_formatted.push_str(", aborted at the error limit");
+        }
 
         let table_res = format!(
             "{} ({} rows) - \n{}",
             result.table_name, rows_formatted, status
         );
         println!("\n{}", table_res);
+        // Profiles are asked for explicitly, they are shown in brief mode too
+        self.print_profiles(result);
+        self.print_value_counts(result);
         // If in brief mode, we simply print the above line and stop early
         if self.brief {
             return;
         }
 
+        let missing = result.get_missing_columns();
+        let unexpected = result.get_unexpected_columns();
+        if !missing.is_empty() || !unexpected.is_empty() {
+            println!("  Schema errors:");
+            for column in missing {
+                println!("    {}: missing from file", column);
+            }
+            for column in unexpected {
+                println!("    {}: not configured", column);
+            }
+        }
+
+        let table_results = result.get_table_results();
+        if !table_results.is_empty() {
+            println!("  Table results:");
+            for rule in table_results {
+                let dots = ".".repeat(MAX_LEN.saturating_sub(rule.display_name().len()));
+                let count_str = format_numbers(rule.error_count);
+                let pass_msg = if rule.pass { "PASS" } else { "FAIL" };
+                println!(
+                    "    {} {} {:>6} ({:05.2}%) {}",
+                    rule.display_name(),
+                    dots,
+                    count_str,
+                    rule.error_percentage,
+                    pass_msg
+                );
+                if !rule.observed.is_empty() {
+                    println!("      observed: {}", format_observed(&rule.observed));
+                }
+            }
+        }
+
         println!("  Column results:");
 
         for (column_name, rule_results) in result.get_column_results() {
             println!("    {}:", column_name);
 
             for rule in rule_results {
-                let dots = ".".repeat(MAX_LEN - rule.rule_name.len());
+                let dots = ".".repeat(MAX_LEN.saturating_sub(rule.display_name().len()));
                 let count_str = format_numbers(rule.error_count);
                 let pass_msg = if rule.pass { "PASS" } else { "FAIL" };
-                if rule.error_message.is_some() {
-                    let msg = rule.error_message.as_ref().unwrap();
+                if let Some(msg) = &rule.error_message {
                     println!("      {}", msg);
                 } else {
                     println!(
                         "      {} {} {:>6} ({:05.2}%) {}",
-                        rule.rule_name, dots, count_str, rule.error_percentage, pass_msg
+                        rule.display_name(),
+                        dots,
+                        count_str,
+                        rule.error_percentage,
+                        pass_msg
                     );
                 }
+                if !rule.observed.is_empty() {
+                    println!("        observed: {}", format_observed(&rule.observed));
+                }
+                for (row, value) in &rule.samples {
+                    println!("        row {}: {:?}", format_numbers(*row), value);
+                }
             }
         }
 
@@ -80,19 +134,129 @@ impl StdOutFormatter {
                 println!("    {}:", column_name);
 
                 for rule in relation_results {
-                    let dots = ".".repeat(MAX_LEN - rule.rule_name.len());
+                    let dots = ".".repeat(MAX_LEN.saturating_sub(rule.display_name().len()));
                     let count_str = format_numbers(rule.error_count);
                     println!(
                         "      {} {} {:>6} ({:.2}%)",
-                        rule.rule_name, dots, count_str, rule.error_percentage
+                        rule.display_name(),
+                        dots,
+                        count_str,
+                        rule.error_percentage
+                    );
+                    if !rule.observed.is_empty() {
+                        println!("        observed: {}", format_observed(&rule.observed));
+                    }
+                    for (row, value) in &rule.samples {
+                        println!("        row {}: {:?}", format_numbers(*row), value);
+                    }
+                }
+            }
+        }
+
+        let group_results = result.get_group_results();
+        if !group_results.is_empty() {
+            println!("\n  Group results:");
+            for group in group_results {
+                println!("    {}:", group.name);
+                let rule = &group.result;
+                let dots = ".".repeat(MAX_LEN.saturating_sub(rule.display_name().len()));
+                let count_str = format_numbers(rule.error_count);
+                let pass_msg = if rule.pass { "PASS" } else { "FAIL" };
+                println!(
+                    "      {} {} {:>6} ({:05.2}%) {}",
+                    rule.display_name(),
+                    dots,
+                    count_str,
+                    rule.error_percentage,
+                    pass_msg
+                );
+                for mismatch in &group.mismatches {
+                    println!(
+                        "        {}: expected {}, got {} (delta {:+})",
+                        mismatch.group,
+                        mismatch.expected,
+                        mismatch.actual,
+                        mismatch.delta()
                     );
                 }
             }
         }
 
+        let foreign_key_results = result.get_foreign_key_results();
+        if !foreign_key_results.is_empty() {
+            println!("\n  Foreign key results:");
+            for foreign_key in foreign_key_results {
+                println!("    {}:", foreign_key.name);
+                let rule = &foreign_key.result;
+                let dots = ".".repeat(MAX_LEN.saturating_sub(rule.display_name().len()));
+                let count_str = format_numbers(rule.error_count);
+                let pass_msg = if rule.pass { "PASS" } else { "FAIL" };
+                println!(
+                    "      {} {} {:>6} ({:05.2}%) {}",
+                    rule.display_name(),
+                    dots,
+                    count_str,
+                    rule.error_percentage,
+                    pass_msg
+                );
+                if !foreign_key.orphans.is_empty() {
+                    println!("        orphans: {}", foreign_key.orphans.join(", "));
+                }
+            }
+        }
+
         if let Some(error_msg) = &result.error_message {
             println!("  Error: {}", error_msg);
         }
+
+        let read_mode = match (result.read_mode, &result.read_note) {
+            (Some(mode), Some(note)) => format!(" ({}, {})", mode, note),
+            (Some(mode), None) => format!(" ({})", mode),
+            (None, _) => String::new(),
+        };
+        println!(
+            "\n  Read{} in {:.2}s, validated in {:.2}s ({} rows/s)",
+            read_mode,
+            result.read_duration.as_secs_f64(),
+            result.validation_duration.as_secs_f64(),
+            format_numbers(result.rows_per_second() as usize)
+        );
+    }
+
+    pub fn print_profiles(&self, result: &ValidationResult) {
+        let profiles = result.get_profiles();
+        if profiles.is_empty() {
+            return;
+        }
+        println!("  Profile:");
+        for profile in profiles {
+            println!("    {}: {}", profile.name, format_profile(profile));
+        }
+    }
+
+    pub fn print_value_counts(&self, result: &ValidationResult) {
+        let value_counts = result.get_value_counts();
+        if value_counts.is_empty() {
+            return;
+        }
+        println!("  Value counts:");
+        for counts in value_counts {
+            println!("    {}:", counts.name);
+            let other = [("(other)", counts.other), ("(null)", counts.null_count)];
+            let lines = counts
+                .values
+                .iter()
+                .map(|(value, count)| (value.as_str(), *count))
+                .chain(other.into_iter().filter(|(_, count)| *count > 0));
+            for (value, count) in lines {
+                println!(
+                    "      {}: {} ({:.2}%)",
+                    value,
+                    format_numbers(count),
+                    ValueCounts::percentage(count, result.total_rows)
+                );
+            }
+        }
     }
 
     pub fn print_complete(&self, passed: usize, failed: usize) {
@@ -108,6 +272,24 @@ impl StdOutFormatter {
     }
 }
 
+/// One line summary of a column profile, numeric and length details when known
+fn format_profile(profile: &ColumnProfile) -> String {
+    let mut parts = vec![
+        format!("nulls {}", format_numbers(profile.null_count)),
+        format!("distinct ~{}", format_numbers(profile.distinct)),
+    ];
+    if let (Some(min), Some(max)) = (profile.min, profile.max) {
+        parts.push(format!("min {}, max {}", min, max));
+    }
+    if let Some(mean) = profile.mean {
+        parts.push(format!("mean {:.2}", mean));
+    }
+    if let (Some(min), Some(max)) = (profile.min_length, profile.max_length) {
+        parts.push(format!("length {}..{}", min, max));
+    }
+    parts.join(", ")
+}
+
 impl Reporter for StdOutFormatter {
     fn on_start(&self) {
         let i = "=".repeat(self.intro_len);
@@ -0,0 +1,106 @@
+use dataguard_core::ValidationResult;
+
+use crate::Reporter;
+
+/// Print one line per table and the overall total, for dashboards and CI gates.
+///
+/// Loading and validation steps are not printed, nor the results of each rule.
+#[derive(Default)]
+pub struct SummaryFormatter {
+    errors: usize,
+    rows: usize,
+}
+
+impl SummaryFormatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// `table_name: PASSED/FAILED (N errors / M rows)`, or the error stopping the table
+fn table_line(result: &ValidationResult) -> String {
+    let (pass, total) = result.is_passed();
+    let status = if pass == total { "PASSED" } else { "FAILED" };
+    match &result.error_message {
+        Some(msg) => format!("{}: {} ({})", result.table_name, status, msg),
+        None => format!(
+            "{}: {} ({} errors / {} rows{}{})",
+            result.table_name,
+            status,
+            result.error_count(),
+            result.total_rows,
+            if result.sampled { ", sample" } else { "" },
+            if result.aborted {
+                ", aborted at the error limit"
+            } else {
+                ""
+            }
+        ),
+    }
+}
+
+fn total_line(passed: usize, failed: usize, errors: usize, rows: usize) -> String {
+    format!(
+        "Total: {} failed, {} passed ({} errors / {} rows)",
+        failed, passed, errors, rows
+    )
+}
+
+impl Reporter for SummaryFormatter {
+    fn on_start(&self) {}
+
+    fn on_loading(&self) {}
+
+    fn on_table_load(&self, _current: usize, _total: usize, _name: &str) {}
+
+    fn on_validation_start(&self) {}
+
+    fn on_table_result(&mut self, result: &ValidationResult) {
+        self.errors += result.error_count();
+        self.rows += result.total_rows;
+        println!("{}", table_line(result));
+    }
+
+    fn on_complete(&self, passed: usize, failed: usize) {
+        println!("{}", total_line(passed, failed, self.errors, self.rows));
+    }
+
+    fn on_waiting(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dataguard_core::RuleResult;
+
+    #[test]
+    fn test_summary_lines() {
+        let mut result = ValidationResult::new("users".to_string(), 1_500);
+        result.add_column_result(
+            "name".to_string(),
+            vec![
+                RuleResult::new("WithRegex".to_string(), 3, 0.2, 10.0, None, true),
+                RuleResult::new("NullCheck".to_string(), 4, 0.27, 0.0, None, false),
+            ],
+        );
+        assert_eq!(table_line(&result), "users: FAILED (7 errors / 1500 rows)");
+
+        let passed = ValidationResult::new("orders".to_string(), 10);
+        assert_eq!(table_line(&passed), "orders: PASSED (0 errors / 10 rows)");
+
+        let mut aborted = ValidationResult::new("logs".to_string(), 200);
+        aborted.aborted = true;
+        assert_eq!(
+            table_line(&aborted),
+            "logs: FAILED (0 errors / 200 rows, aborted at the error limit)"
+        );
+
+        let errored = ValidationResult::from_error("events".to_string(), "IO error".to_string());
+        assert_eq!(table_line(&errored), "events: FAILED (IO error)");
+
+        assert_eq!(
+            total_line(1, 2, 7, 1_510),
+            "Total: 2 failed, 1 passed (7 errors / 1510 rows)"
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{Local, SecondsFormat};
+use serde::Serialize;
+use serde_json::Error;
+
+use dataguard_core::ValidationResult;
+
+/// Summary of one table for one run, a line of the history log
+#[derive(Serialize)]
+struct HistoryEntry<'a> {
+    timestamp: &'a str,
+    table: &'a str,
+    total_rows: usize,
+    total_errors: usize,
+    pass: bool,
+    /// Why the table could not be validated, see `--continue-on-error`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+/// One compact JSON line per table of `results`, stamped with the current time.
+/// Every line ends with a newline, so the text can be appended to a JSONL log as is.
+pub fn history_lines(results: &[ValidationResult]) -> Result<String, Error> {
+    let timestamp = Local::now().to_rfc3339_opts(SecondsFormat::Secs, false);
+    lines_at(&timestamp, results)
+}
+
+fn lines_at(timestamp: &str, results: &[ValidationResult]) -> Result<String, Error> {
+    let mut lines = String::new();
+    for result in results {
+        let (pass, total) = result.is_passed();
+        let entry = HistoryEntry {
+            timestamp,
+            table: &result.table_name,
+            total_rows: result.total_rows,
+            total_errors: result.error_count(),
+            pass: pass == total,
+            error: result.error_message.as_deref(),
+        };
+        lines.push_str(&serde_json::to_string(&entry)?);
+        lines.push('\n');
+    }
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dataguard_core::RuleResult;
+
+    #[test]
+    fn test_history_lines() {
+        let mut users = ValidationResult::new("users".to_string(), 100);
+        users.add_column_result(
+            "email".to_string(),
+            vec![RuleResult::new(
+                "NullCheck".to_string(),
+                3,
+                3.0,
+                0.0,
+                None,
+                false,
+            )],
+        );
+        let events = ValidationResult::from_error("events".to_string(), "IO error".to_string());
+
+        let lines = lines_at("2026-10-16T09:30:00+02:00", &[users, events]).unwrap();
+        let lines: Vec<&str> = lines.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                r#"{"timestamp":"2026-10-16T09:30:00+02:00","table":"users","total_rows":100,"total_errors":3,"pass":false}"#,
+                r#"{"timestamp":"2026-10-16T09:30:00+02:00","table":"events","total_rows":0,"total_errors":0,"pass":false,"error":"IO error"}"#,
+            ]
+        );
+        assert!(lines_at("", &[]).unwrap().is_empty());
+    }
+}
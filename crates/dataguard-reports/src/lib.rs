@@ -1,8 +1,15 @@
+pub mod baseline;
 pub mod formatters;
+pub mod history;
 pub mod utils;
 
+pub use baseline::{Baseline, Regression};
 use dataguard_core::ValidationResult;
-pub use formatters::{json::JsonFormatter, stdout::StdOutFormatter};
+pub use formatters::{
+    html::HtmlFormatter, json::JsonFormatter, junit::JUnitFormatter, markdown::MarkdownFormatter,
+    sarif::SarifFormatter, stdout::StdOutFormatter, summary::SummaryFormatter,
+};
+pub use history::history_lines;
 
 pub trait Reporter {
     fn on_start(&self);
@@ -7,9 +7,24 @@ pub fn format_numbers(n: usize) -> String {
     }
 }
 
+/// Format the values computed by a rule as `name=value` pairs
+pub fn format_observed(observed: &[(String, f64)]) -> String {
+    observed
+        .iter()
+        .map(|(name, value)| format!("{}={:.4}", name, value))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[cfg(test)]
 mod test {
-    use crate::utils::numbers::format_numbers;
+    use crate::utils::numbers::{format_numbers, format_observed};
+
+    #[test]
+    fn test_format_observed() {
+        let observed = vec![("mean".to_string(), 12.5), ("std_dev".to_string(), 2.0)];
+        assert_eq!(format_observed(&observed), "mean=12.5000, std_dev=2.0000");
+    }
 
     #[test]
     fn test_format_b() {
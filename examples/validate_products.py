@@ -77,10 +77,10 @@ def main():
     result = table.validate()
 
     print("Validation Results:")
-    print(f"  Table: {result['table_name']}")
-    print(f"  Total rows: {result['total_rows']}")
+    print(f"  Table: {result.table_name}")
+    print(f"  Total rows: {result.total_rows}")
 
-    passed, total = result["passed"]
+    passed, total = result.passed
     print(f"  Rules: {passed}/{total} passed")
 
     if passed == total:
@@ -14,12 +14,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut name_column = StringColumnBuilder::new("Name".to_string());
     name_column
         .is_not_null(0.0)
-        .with_min_length(3, 0.0)
-        .with_max_length(100, 0.0);
+        .with_min_length(3, false, 0.0)
+        .with_max_length(100, false, 0.0);
 
     // Description: string with min length
     let mut description_column = StringColumnBuilder::new("Description".to_string());
-    description_column.with_min_length(1, 0.0);
+    description_column.with_min_length(1, false, 0.0);
 
     // Currency: string with allowed values
     let mut currency_column = StringColumnBuilder::new("Currency".to_string());